@@ -0,0 +1 @@
+if (!a in b) {}
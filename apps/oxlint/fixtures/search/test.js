@@ -0,0 +1,5 @@
+console.log("a");
+console.error(a, b);
+if (a === a || a === b) {
+  console.warn();
+}
@@ -0,0 +1,2 @@
+import { foo, notFoo } from "@/foo";
+import { bar, notBar } from "@/bar";
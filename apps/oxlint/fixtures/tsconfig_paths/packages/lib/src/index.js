@@ -0,0 +1 @@
+import { util, notUtil } from "~/util";
@@ -2,19 +2,24 @@ use std::ffi::OsString;
 
 use bpaf::{doc::Style, Bpaf};
 
+pub const DEFAULT_IGNORE_PATH: &str = ".eslintignore";
+
 pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
-    ("Disables excluding of files from .eslintignore files, ", Style::Text),
+    ("Disables excluding of files from .gitignore, .ignore and .eslintignore files, ", Style::Text),
     ("--ignore-path", Style::Literal),
-    (" flags and ", Style::Text),
+    (" flags, ", Style::Text),
     ("--ignore-pattern", Style::Literal),
-    (" flags", Style::Text),
+    (" flags and ", Style::Text),
+    ("ignorePatterns", Style::Literal),
+    (" in the configuration file", Style::Text),
 ];
 
 /// Ignore Files
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
-    /// Specify the file to use as your .eslintignore
-    #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
+    /// Specify the file to use as your .eslintignore.
+    /// `.gitignore` and `.ignore` files are respected as well, unless `--no-ignore` is passed
+    #[bpaf(argument("PATH"), fallback(DEFAULT_IGNORE_PATH.into()), hide_usage)]
     pub ignore_path: OsString,
 
     /// Specify patterns of files to ignore (in addition to those in .eslintignore)
@@ -1,10 +1,10 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
+use oxc_diagnostics::{ColorTheme, GraphicalReporterOptions};
 use oxc_linter::{AllowWarnDeny, FixKind};
 
 use super::{
-    expand_glob,
     ignore::{ignore_options, IgnoreOptions},
     misc_options, validate_paths, MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
 };
@@ -33,15 +33,25 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the extended documentation of a diagnostic code,
+    /// e.g. `oxc(parse::unterminated-string)` or `eslint(no-debugger)`
+    #[bpaf(argument("CODE"), hide_usage)]
+    pub explain: Option<String>,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
-    /// Single file, single path or list of paths
-    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE), map(expand_glob))]
+    /// Single file, single path or list of paths.
+    /// Glob patterns such as `"src/**/*.{ts,tsx}"` are expanded by oxlint,
+    /// and patterns starting with `!` exclude the matched files
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
 }
 
@@ -68,6 +78,11 @@ pub struct BasicOptions {
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
+
+    /// Do not resolve symlinks to their real path when resolving imports for import plugin,
+    /// same as Node.js's `--preserve-symlinks`
+    #[bpaf(switch, hide_usage)]
+    pub preserve_symlinks: bool,
 }
 
 // This is formatted according to
@@ -129,6 +144,10 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions.
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Only apply fixes of these rules, e.g. `no-debugger,no-unused-vars`
+    #[bpaf(argument("RULES"), many, hide_usage)]
+    pub fix_rules: Vec<String>,
 }
 
 impl FixOptions {
@@ -156,6 +175,17 @@ impl FixOptions {
     pub fn is_enabled(&self) -> bool {
         self.fix || self.fix_suggestions || self.fix_dangerously
     }
+
+    /// Rules given to `--fix-rules`, which can be repeated or separated by commas.
+    pub fn fix_rules(&self) -> Vec<String> {
+        self.fix_rules
+            .iter()
+            .flat_map(|rules| rules.split(','))
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
 }
 
 /// Handle Warnings
@@ -178,9 +208,41 @@ pub struct WarningOptions {
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, json, ndjson, unix, checkstyle, github)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Print statistics of the run: violations and fixes of each rule and the slowest files
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// Write statistics of the run as JSON to this file, for tracking them over time
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stats_json: Option<PathBuf>,
+
+    /// Number of lines of source code shown before and after each diagnostic
+    #[bpaf(argument("INT"), fallback(1), hide_usage)]
+    pub context_lines: usize,
+
+    /// Draw diagnostics with ASCII characters instead of unicode box drawing characters
+    #[bpaf(switch, hide_usage)]
+    pub ascii: bool,
+
+    /// Color theme of diagnostics (auto, rgb, ansi, none).
+    /// Defaults to the `OXLINT_THEME` environment variable, `NO_COLOR` disables the colors of `auto`
+    #[bpaf(argument("THEME"), hide_usage)]
+    pub theme: Option<ColorTheme>,
+}
+
+impl OutputOptions {
+    /// Options of the default output format.
+    pub fn graphical_reporter_options(&self) -> GraphicalReporterOptions {
+        let theme = self
+            .theme
+            .or_else(|| std::env::var("OXLINT_THEME").ok()?.parse().ok())
+            .unwrap_or_default();
+        GraphicalReporterOptions { context_lines: self.context_lines, ascii: self.ascii, theme }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -190,6 +252,8 @@ pub enum OutputFormat {
     /// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-a-notice-message>
     Github,
     Json,
+    /// One JSON object per line and per diagnostic, written as soon as each file is linted
+    Ndjson,
     Unix,
     Checkstyle,
 }
@@ -200,6 +264,7 @@ impl FromStr for OutputFormat {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::Ndjson),
             "default" => Ok(Self::Default),
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
@@ -209,6 +274,19 @@ impl FromStr for OutputFormat {
     }
 }
 
+/// Standard Input
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint code read from standard input instead of files
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the code read from standard input, the file does not need to exist.
+    /// The source type and ignore files are resolved as if the file existed at this path
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
 /// Enable Plugins
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Clone, Bpaf)]
@@ -265,6 +343,10 @@ pub struct EnablePlugins {
     /// Enable the node plugin and detect node usage problems
     #[bpaf(switch, hide_usage)]
     pub node_plugin: bool,
+
+    /// Enable the JSON plugin and lint package.json and tsconfig.json files
+    #[bpaf(switch, hide_usage)]
+    pub json_plugin: bool,
 }
 
 #[cfg(test)]
@@ -300,6 +382,7 @@ mod warning_options {
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
+    use oxc_diagnostics::{ColorTheme, GraphicalReporterOptions};
     use oxc_linter::AllowWarnDeny;
 
     use super::{lint_command, LintCommand, OutputFormat};
@@ -315,9 +398,26 @@ mod lint_options {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
+        assert!(options.explain.is_none());
+        assert!(!options.stdin_options.stdin);
         assert_eq!(options.output_options.format, OutputFormat::Default);
     }
 
+    #[test]
+    fn stdin() {
+        let options = get_lint_options("--stdin --stdin-filename src/foo.ts");
+        assert!(options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, Some(PathBuf::from("src/foo.ts")));
+        assert!(options.paths.is_empty());
+    }
+
+    #[test]
+    fn explain() {
+        let options = get_lint_options("--explain oxc(parse::unterminated-string)");
+        assert_eq!(options.explain, Some("oxc(parse::unterminated-string)".to_string()));
+        assert!(options.paths.is_empty());
+    }
+
     #[test]
     #[allow(clippy::similar_names)]
     fn multiple_paths() {
@@ -339,27 +439,6 @@ mod lint_options {
         assert_eq!(options.paths, [file_foo, file_bar, file_baz]);
     }
 
-    #[cfg(target_os = "windows")]
-    #[test]
-    #[allow(clippy::similar_names)]
-    fn wildcard_expansion() {
-        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
-        let file_foo = temp_dir.path().join("foo.js");
-        File::create(&file_foo).expect("Could not create foo.js temp file");
-        let file_bar = temp_dir.path().join("bar.js");
-        File::create(&file_bar).expect("Could not create bar.js temp file");
-        let file_baz = temp_dir.path().join("baz");
-        File::create(&file_baz).expect("Could not create baz temp file");
-
-        let js_files_wildcard = temp_dir.path().join("*.js");
-        let options = get_lint_options(
-            js_files_wildcard.to_str().expect("could not get js files wildcard path"),
-        );
-        assert!(options.paths.contains(&file_foo));
-        assert!(options.paths.contains(&file_bar));
-        assert!(!options.paths.contains(&file_baz));
-    }
-
     #[test]
     fn no_parent_path() {
         match lint_command().run_inner(&["../parent_dir"]) {
@@ -379,6 +458,30 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn graphical_reporter_options() {
+        let options = get_lint_options("--context-lines 3 --ascii --theme ansi test.js");
+        assert_eq!(
+            options.output_options.graphical_reporter_options(),
+            GraphicalReporterOptions { context_lines: 3, ascii: true, theme: ColorTheme::Ansi }
+        );
+        assert!(lint_command().run_inner(&["--theme", "dark", "test.js"]).is_err());
+    }
+
+    #[test]
+    fn stats() {
+        let options = get_lint_options("--stats --stats-json stats.json test.js");
+        assert!(options.output_options.stats);
+        assert_eq!(options.output_options.stats_json, Some(PathBuf::from("stats.json")));
+    }
+
+    #[test]
+    fn fix_rules() {
+        let options =
+            get_lint_options("--fix --fix-rules no-debugger,no-var --fix-rules eqeqeq test.js");
+        assert_eq!(options.fix_options.fix_rules(), ["no-debugger", "no-var", "eqeqeq"]);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -399,6 +502,9 @@ mod lint_options {
         let options = get_lint_options("-f json");
         assert_eq!(options.output_options.format, OutputFormat::Json);
         assert!(options.paths.is_empty());
+
+        let options = get_lint_options("--format ndjson");
+        assert_eq!(options.output_options.format, OutputFormat::Ndjson);
     }
 
     #[test]
@@ -410,6 +516,14 @@ mod lint_options {
         ));
     }
 
+    #[test]
+    fn preserve_symlinks() {
+        let options = get_lint_options(".");
+        assert!(!options.basic_options.preserve_symlinks);
+        let options = get_lint_options("--import-plugin --preserve-symlinks .");
+        assert!(options.basic_options.preserve_symlinks);
+    }
+
     #[test]
     fn list_rules() {
         let options = get_lint_options("--rules");
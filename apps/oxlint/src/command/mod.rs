@@ -1,13 +1,15 @@
 mod ignore;
 mod lint;
+mod search;
 
 use std::path::PathBuf;
 
 use bpaf::Bpaf;
 
 pub use self::{
-    ignore::IgnoreOptions,
-    lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    ignore::{IgnoreOptions, DEFAULT_IGNORE_PATH},
+    lint::{lint_command, LintCommand, OutputFormat, OutputOptions, StdinOptions, WarningOptions},
+    search::{search_command, SearchCommand},
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -25,6 +27,10 @@ pub struct MiscOptions {
     /// Number of threads to use. Set to 1 for using only 1 CPU core
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+
+    /// Print the paths that are not linted and why they are skipped
+    #[bpaf(switch, hide_usage)]
+    pub verbose: bool,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -38,31 +44,6 @@ fn validate_paths(paths: &Vec<PathBuf>) -> bool {
 
 const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
 
-#[cfg(target_os = "windows")]
-#[allow(clippy::needless_pass_by_value)]
-fn expand_glob(paths: Vec<PathBuf>) -> Vec<PathBuf> {
-    let match_options = glob::MatchOptions {
-        case_sensitive: true,
-        require_literal_separator: false,
-        require_literal_leading_dot: false,
-    };
-
-    paths
-        .iter()
-        .filter_map(|path| path.to_str())
-        .filter_map(|path| glob::glob_with(path, match_options).ok())
-        .flatten()
-        .filter_map(Result::ok)
-        .collect()
-}
-
-#[cfg(not(target_os = "windows"))]
-#[allow(clippy::needless_pass_by_value)]
-fn expand_glob(paths: Vec<PathBuf>) -> Vec<PathBuf> {
-    // no-op on any os other than windows, since they expand globs
-    paths
-}
-
 #[cfg(test)]
 mod misc_options {
     use super::{lint::lint_command, MiscOptions};
@@ -76,6 +57,13 @@ mod misc_options {
     fn default() {
         let options = get_misc_options(".");
         assert!(options.threads.is_none());
+        assert!(!options.verbose);
+    }
+
+    #[test]
+    fn verbose() {
+        let options = get_misc_options("--verbose .");
+        assert!(options.verbose);
     }
 
     #[test]
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    ignore::{ignore_options, IgnoreOptions},
+    validate_paths, PATHS_ERROR_MESSAGE, VERSION,
+};
+
+/// Structural search and replace, e.g. `oxlint search 'console.$METHOD($$$ARGS)' src`
+///
+/// The pattern is an expression in which `$NAME` matches any expression or property name,
+/// and `$$$NAME` matches any number of arguments or array elements.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("search"), version(VERSION))]
+pub struct SearchCommand {
+    /// Replace matches with TEMPLATE, which may contain the pattern's metavariables,
+    /// and print the changes as a diff
+    #[bpaf(argument("TEMPLATE"))]
+    pub rewrite: Option<String>,
+
+    /// Write the changes made by `--rewrite` to the files instead of printing a diff
+    #[bpaf(switch)]
+    pub fix: bool,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Pattern to search for
+    #[bpaf(positional("PATTERN"))]
+    pub pattern: String,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
+    pub paths: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::search_command;
+
+    #[test]
+    fn search() {
+        let args = ["search", "console.$METHOD($$$ARGS)", "src"].map(ToString::to_string);
+        let command = search_command().run_inner(args.as_slice()).unwrap();
+        assert_eq!(command.pattern, "console.$METHOD($$$ARGS)");
+        assert_eq!(command.paths, [PathBuf::from("src")]);
+        assert!(command.rewrite.is_none());
+        assert!(!command.fix);
+    }
+
+    #[test]
+    fn rewrite() {
+        let args = ["--rewrite", "logger.$METHOD($$$ARGS)", "--fix", "console.$METHOD($$$ARGS)"]
+            .map(ToString::to_string);
+        let command = search_command().run_inner(args.as_slice()).unwrap();
+        assert_eq!(command.rewrite.as_deref(), Some("logger.$METHOD($$$ARGS)"));
+        assert!(command.fix);
+        assert!(command.paths.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::path::{Component, Path, PathBuf};
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Positional `PATH` arguments, which can be paths, glob patterns, or negated glob patterns
+/// starting with `!`.
+///
+/// Globs are expanded by walking their base directory instead of relying on the shell, so they
+/// behave the same on every platform and respect the ignore files:
+///
+/// * `oxlint "src/**/*.{ts,tsx}"` lints the TypeScript files in `src`.
+/// * `oxlint src "!**/*.gen.ts"` lints `src`, except for generated files.
+pub struct PathArgs {
+    /// Existing paths, and the base directory of each glob pattern.
+    roots: Vec<PathBuf>,
+    /// Paths which are linted entirely, i.e. everything except for the glob base directories.
+    paths: Vec<PathBuf>,
+    include: Option<GlobSet>,
+    exclude: Option<(GlobSet, Vec<String>)>,
+}
+
+impl PathArgs {
+    /// # Errors
+    ///
+    /// * a glob pattern is invalid
+    pub fn new(args: Vec<PathBuf>) -> Result<Self, globset::Error> {
+        let mut roots = vec![];
+        let mut paths = vec![];
+        let mut include = GlobSetBuilder::new();
+        let mut include_count = 0;
+        let mut exclude = GlobSetBuilder::new();
+        let mut exclude_patterns = vec![];
+
+        for arg in args {
+            let pattern = arg.to_string_lossy();
+            if let Some(pattern) = pattern.strip_prefix('!') {
+                let pattern = Self::normalize(pattern);
+                exclude.add(Self::glob(&pattern)?);
+                exclude_patterns.push(pattern);
+            } else if Self::is_glob(&arg) {
+                let pattern = Self::normalize(&pattern);
+                include.add(Self::glob(&pattern)?);
+                include_count += 1;
+                roots.push(Self::base_dir(Path::new(&pattern)));
+            } else {
+                roots.push(arg.clone());
+                paths.push(arg);
+            }
+        }
+
+        let include = (include_count > 0).then(|| include.build()).transpose()?;
+        let exclude = if exclude_patterns.is_empty() {
+            None
+        } else {
+            Some((exclude.build()?, exclude_patterns))
+        };
+        Ok(Self { roots, paths, include, exclude })
+    }
+
+    /// Paths to walk, an empty list means the current working directory.
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Whether any glob pattern was provided, and the walked paths need to be filtered with
+    /// [PathArgs::is_match].
+    pub fn has_globs(&self) -> bool {
+        self.include.is_some() || self.exclude.is_some()
+    }
+
+    /// Whether `path` is matched by the provided paths and glob patterns.
+    /// Relative `path`s are matched against patterns relative to `cwd`.
+    pub fn is_match(&self, path: &Path, cwd: &Path) -> bool {
+        if self.excluded_by(path, cwd).is_some() {
+            return false;
+        }
+        let Some(include) = &self.include else { return true };
+        self.paths.iter().any(|p| path.starts_with(p))
+            || include.is_match(Self::relative_path(path, cwd))
+    }
+
+    /// The negated pattern which excludes `path`.
+    pub fn excluded_by(&self, path: &Path, cwd: &Path) -> Option<&str> {
+        let (exclude, patterns) = self.exclude.as_ref()?;
+        let index = exclude.matches(Self::relative_path(path, cwd)).into_iter().next()?;
+        Some(patterns[index].as_str())
+    }
+
+    fn is_glob(path: &Path) -> bool {
+        path.to_string_lossy().contains(['*', '?', '[', '{']) && !path.exists()
+    }
+
+    fn glob(pattern: &str) -> Result<Glob, globset::Error> {
+        GlobBuilder::new(pattern).literal_separator(true).build()
+    }
+
+    /// Strip the leading `./`, walked paths are matched without it.
+    fn normalize(pattern: &str) -> String {
+        pattern.strip_prefix("./").unwrap_or(pattern).to_string()
+    }
+
+    /// The leading components of `pattern` which do not contain any glob syntax.
+    fn base_dir(pattern: &Path) -> PathBuf {
+        let base = pattern
+            .components()
+            .take_while(|c| !Self::is_glob(Path::new(c.as_os_str())))
+            .collect::<PathBuf>();
+        if base.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            base
+        }
+    }
+
+    fn relative_path<'p>(path: &'p Path, cwd: &Path) -> &'p Path {
+        let path = if path.is_absolute() { path.strip_prefix(cwd).unwrap_or(path) } else { path };
+        let mut components = path.components();
+        if components.clone().next() == Some(Component::CurDir) {
+            components.next();
+        }
+        components.as_path()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::PathArgs;
+
+    fn path_args(args: &[&str]) -> PathArgs {
+        PathArgs::new(args.iter().map(PathBuf::from).collect()).unwrap()
+    }
+
+    #[test]
+    fn paths() {
+        let args = path_args(&["src", "fixtures"]);
+        assert!(!args.has_globs());
+        assert_eq!(args.roots(), [PathBuf::from("src"), PathBuf::from("fixtures")]);
+    }
+
+    #[test]
+    fn globs() {
+        let cwd = Path::new("/project");
+        let args = path_args(&["src/**/*.{ts,tsx}", "*.js"]);
+        assert!(args.has_globs());
+        assert_eq!(args.roots(), [PathBuf::from("src"), PathBuf::from(".")]);
+        assert!(args.is_match(Path::new("src/a/b.ts"), cwd));
+        assert!(args.is_match(Path::new("src/c.tsx"), cwd));
+        assert!(args.is_match(Path::new("./foo.js"), cwd));
+        assert!(args.is_match(Path::new("/project/foo.js"), cwd));
+        assert!(!args.is_match(Path::new("src/foo.js"), cwd));
+        assert!(!args.is_match(Path::new("lib/foo.ts"), cwd));
+    }
+
+    #[test]
+    fn negated_globs() {
+        let cwd = Path::new("/project");
+        let args = path_args(&["src", "!**/*.gen.ts"]);
+        assert!(args.has_globs());
+        assert_eq!(args.roots(), [PathBuf::from("src")]);
+        assert!(args.is_match(Path::new("src/a.ts"), cwd));
+        assert!(!args.is_match(Path::new("src/a.gen.ts"), cwd));
+        assert_eq!(args.excluded_by(Path::new("src/a.gen.ts"), cwd), Some("**/*.gen.ts"));
+    }
+
+    #[test]
+    fn invalid_glob() {
+        assert!(PathArgs::new(vec![PathBuf::from("src/{a")]).is_err());
+    }
+}
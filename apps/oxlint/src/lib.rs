@@ -1,12 +1,15 @@
 mod command;
+mod globs;
 mod lint;
 mod result;
 mod runner;
+mod search;
 mod walk;
 
 pub use crate::{
     command::*,
     lint::LintRunner,
-    result::{CliRunResult, LintResult},
+    result::{CliRunResult, LintResult, SearchResult},
     runner::Runner,
+    search::SearchRunner,
 };
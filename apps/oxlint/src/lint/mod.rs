@@ -1,27 +1,38 @@
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    fs,
+    io::{BufWriter, Read},
+    path::PathBuf,
+    time::Instant,
+};
 
-use ignore::gitignore::Gitignore;
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
 use oxc_linter::{
     partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    LINT_JSON_EXT,
 };
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
     command::{LintCommand, OutputFormat, OutputOptions, WarningOptions},
-    walk::{Extensions, Walk},
+    globs::PathArgs,
+    walk::{Extensions, SkipReason, SkipReasons, Walk},
     CliRunResult, LintResult, MiscOptions, Runner,
 };
 
+/// Displayed path of code read from standard input when `--stdin-filename` is not provided.
+const STDIN_FILENAME: &str = "<stdin>.js";
+
 pub struct LintRunner {
     options: LintCommand,
+    /// Used instead of reading standard input, for tests.
+    stdin_source_text: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options }
+        Self { options, stdin_source_text: None }
     }
 
     fn run(self) -> CliRunResult {
@@ -31,6 +42,16 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if let Some(code) = &self.options.explain {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            if Linter::explain(&mut stdout, code) {
+                return CliRunResult::None;
+            }
+            return CliRunResult::InvalidOptions {
+                message: format!("Unknown diagnostic code `{code}`."),
+            };
+        }
+
         let LintCommand {
             paths,
             filter,
@@ -40,60 +61,40 @@ impl Runner for LintRunner {
             fix_options,
             enable_plugins,
             output_options,
+            stdin_options,
             misc_options,
             ..
         } = self.options;
 
-        let mut paths = paths;
-        let provided_path_count = paths.len();
         let now = Instant::now();
+        let cwd = std::env::current_dir().unwrap().into_boxed_path();
 
-        // The ignore crate whitelists explicit paths, but priority
-        // should be given to the ignore file. Many users lint
-        // automatically and pass a list of changed files explicitly.
-        // To accommodate this, unless `--no-ignore` is passed,
-        // pre-filter the paths.
-        if !paths.is_empty() && !ignore_options.no_ignore {
-            let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
-            paths.retain(|p| if p.is_dir() { true } else { !ignore.matched(p, false).is_ignore() });
-        }
-
-        if paths.is_empty() {
-            // If explicit paths were provided, but all have been
-            // filtered, return early.
-            if provided_path_count > 0 {
-                return CliRunResult::LintResult(LintResult {
-                    duration: now.elapsed(),
-                    deny_warnings: warning_options.deny_warnings,
-                    ..LintResult::default()
-                });
-            }
-
-            if let Ok(cwd) = env::current_dir() {
-                paths.push(cwd);
-            } else {
+        // Source text read from standard input, linted as if it was the content of `--stdin-filename`.
+        let stdin = if stdin_options.stdin {
+            if !paths.is_empty() {
                 return CliRunResult::InvalidOptions {
-                    message: "Failed to get current working directory.".to_string(),
+                    message: "`--stdin` cannot be used together with paths.".to_string(),
                 };
             }
-        }
-
-        let extensions = VALID_EXTENSIONS
-            .iter()
-            .chain(LINT_PARTIAL_LOADER_EXT.iter())
-            .copied()
-            .collect::<Vec<&'static str>>();
-
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
-
-        let number_of_files = paths.len();
+            let source_text = match self.stdin_source_text.map_or_else(Self::read_stdin, Ok) {
+                Ok(source_text) => source_text,
+                Err(err) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to read from standard input: {err}"),
+                    };
+                }
+            };
+            let filename = stdin_options.stdin_filename.unwrap_or_else(|| STDIN_FILENAME.into());
+            Some((cwd.join(filename).into_boxed_path(), source_text))
+        } else {
+            None
+        };
 
-        let cwd = std::env::current_dir().unwrap().into_boxed_path();
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(basic_options.config)
             .with_fix(fix_options.fix_kind())
+            .with_fix_rules(fix_options.fix_rules())
             .with_react_plugin(enable_plugins.react_plugin)
             .with_unicorn_plugin(enable_plugins.unicorn_plugin)
             .with_typescript_plugin(enable_plugins.typescript_plugin)
@@ -106,7 +107,8 @@ impl Runner for LintRunner {
             .with_nextjs_plugin(enable_plugins.nextjs_plugin)
             .with_react_perf_plugin(enable_plugins.react_perf_plugin)
             .with_promise_plugin(enable_plugins.promise_plugin)
-            .with_node_plugin(enable_plugins.node_plugin);
+            .with_node_plugin(enable_plugins.node_plugin)
+            .with_json_plugin(enable_plugins.json_plugin);
 
         let linter = match Linter::from_options(lint_options) {
             Ok(lint_service) => lint_service,
@@ -120,10 +122,123 @@ impl Runner for LintRunner {
             }
         };
 
-        let tsconfig = basic_options.tsconfig;
+        // `ignorePatterns` from the configuration file are handled like `--ignore-pattern`.
+        let mut ignore_options = ignore_options;
+        ignore_options.ignore_pattern.extend(linter.ignore_patterns().iter().cloned());
+
+        let mut skipped = vec![];
+        let paths = if let Some((path, _)) = &stdin {
+            let relative_path = path.strip_prefix(&cwd).unwrap_or(path);
+            if let Some(reason) = SkipReasons::new(&ignore_options).find(relative_path, false) {
+                if misc_options.verbose {
+                    Self::print_skipped(&[(relative_path.to_path_buf(), reason)]);
+                }
+                return CliRunResult::LintResult(LintResult {
+                    duration: now.elapsed(),
+                    deny_warnings: warning_options.deny_warnings,
+                    ..LintResult::default()
+                });
+            }
+            vec![path.clone()]
+        } else {
+            let path_args = match PathArgs::new(paths) {
+                Ok(path_args) => path_args,
+                Err(err) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Invalid glob pattern: {err}"),
+                    };
+                }
+            };
+            let mut paths = path_args.roots().to_vec();
+            let provided_path_count = paths.len();
+
+            // The ignore crate whitelists explicit paths, but priority
+            // should be given to the ignore files. Many users lint
+            // automatically and pass a list of changed files explicitly.
+            // To accommodate this, unless `--no-ignore` is passed,
+            // pre-filter the paths.
+            if !paths.is_empty() && !ignore_options.no_ignore {
+                let mut reasons = SkipReasons::new(&ignore_options);
+                paths.retain(|p| {
+                    if p.is_dir() {
+                        return true;
+                    }
+                    match reasons.find(p, false) {
+                        Some(reason) => {
+                            skipped.push((p.clone(), reason));
+                            false
+                        }
+                        None => true,
+                    }
+                });
+            }
+
+            if paths.is_empty() {
+                // If explicit paths were provided, but all have been
+                // filtered, return early.
+                if provided_path_count > 0 {
+                    if misc_options.verbose {
+                        Self::print_skipped(&skipped);
+                    }
+                    return CliRunResult::LintResult(LintResult {
+                        duration: now.elapsed(),
+                        deny_warnings: warning_options.deny_warnings,
+                        ..LintResult::default()
+                    });
+                }
+
+                paths.push(cwd.to_path_buf());
+            }
+
+            let json_extensions = if enable_plugins.json_plugin { LINT_JSON_EXT } else { &[] };
+            let extensions = VALID_EXTENSIONS
+                .iter()
+                .chain(LINT_PARTIAL_LOADER_EXT.iter())
+                .chain(json_extensions.iter())
+                .copied()
+                .collect::<Vec<&'static str>>();
+
+            let walk = Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions));
+            let mut paths = if misc_options.verbose {
+                let (paths, walk_skipped) = walk.paths_and_skipped();
+                skipped.extend(walk_skipped);
+                paths
+            } else {
+                walk.paths()
+            };
+
+            if path_args.has_globs() {
+                paths.retain(|path| {
+                    if let Some(pattern) = path_args.excluded_by(path, &cwd) {
+                        skipped.push((
+                            path.to_path_buf(),
+                            SkipReason::IgnorePattern(format!("!{pattern}")),
+                        ));
+                        return false;
+                    }
+                    path_args.is_match(path, &cwd)
+                });
+                // The base directories of glob patterns can overlap with the other paths.
+                paths.sort_unstable();
+                paths.dedup();
+            }
+            paths
+        };
+
+        if misc_options.verbose {
+            Self::print_skipped(&skipped);
+        }
+
+        let number_of_files = paths.len();
+
+        // Make the tsconfig path absolute, the resolver matches `paths` and project references
+        // against absolute paths of the importing files.
+        let tsconfig =
+            basic_options
+                .tsconfig
+                .map(|path| if path.is_relative() { cwd.join(path) } else { path });
         if let Some(path) = tsconfig.as_ref() {
             if !path.is_file() {
-                let path = if path.is_relative() { cwd.join(path) } else { path.clone() };
                 return CliRunResult::InvalidOptions {
                     message: format!(
                         "The tsconfig file {path:?} does not exist, Please provide a valid tsconfig file.",
@@ -132,7 +247,14 @@ impl Runner for LintRunner {
             }
         }
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let mut options = LintServiceOptions::new(cwd, paths)
+            .with_preserve_symlinks(basic_options.preserve_symlinks);
+        if let Some(tsconfig) = tsconfig {
+            options = options.with_tsconfig(tsconfig);
+        }
+        if let Some((path, source_text)) = stdin {
+            options = options.with_source_text(path, source_text);
+        }
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
@@ -147,6 +269,15 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        let statistics = lint_service.statistics();
+        if let Some(path) = &output_options.stats_json {
+            if let Err(err) = fs::write(path, statistics.to_json()) {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to write statistics to {path:?}: {err}"),
+                };
+            }
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
@@ -156,11 +287,32 @@ impl Runner for LintRunner {
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
             print_summary: matches!(output_options.format, OutputFormat::Default),
+            statistics: output_options.stats.then_some(statistics),
         })
     }
 }
 
 impl LintRunner {
+    /// Use `source_text` as the content of standard input for `--stdin`.
+    #[must_use]
+    pub fn with_stdin_source_text(mut self, source_text: String) -> Self {
+        self.stdin_source_text = Some(source_text);
+        self
+    }
+
+    fn read_stdin() -> std::io::Result<String> {
+        let mut source_text = String::new();
+        std::io::stdin().read_to_string(&mut source_text)?;
+        Ok(source_text)
+    }
+
+    #[allow(clippy::print_stderr)]
+    fn print_skipped(skipped: &[(PathBuf, SkipReason)]) {
+        for (path, reason) in skipped {
+            eprintln!("Skipped {}: {reason}", path.display());
+        }
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -172,8 +324,12 @@ impl LintRunner {
             .with_max_warnings(warning_options.max_warnings);
 
         match output_options.format {
-            OutputFormat::Default => {}
+            OutputFormat::Default => {
+                diagnostic_service
+                    .set_graphical_reporter(output_options.graphical_reporter_options());
+            }
             OutputFormat::Json => diagnostic_service.set_json_reporter(),
+            OutputFormat::Ndjson => diagnostic_service.set_ndjson_reporter(),
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
@@ -197,6 +353,16 @@ mod test {
         }
     }
 
+    fn test_stdin(args: &[&str], source_text: &str) -> LintResult {
+        let mut new_args = vec!["--silent", "--stdin"];
+        new_args.extend(args);
+        let options = lint_command().run_inner(new_args.as_slice()).unwrap();
+        match LintRunner::new(options).with_stdin_source_text(source_text.to_string()).run() {
+            CliRunResult::LintResult(lint_result) => lint_result,
+            other => panic!("{other:?}"),
+        }
+    }
+
     fn test_invalid_options(args: &[&str]) -> String {
         let mut new_args = vec!["--quiet"];
         new_args.extend(args);
@@ -237,6 +403,77 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn stats() {
+        let args = &["--stats", "fixtures/linter"];
+        let statistics = test(args).statistics.unwrap();
+        assert_eq!(statistics.number_of_files, 2);
+        assert_eq!(statistics.number_of_violations(), 2);
+        assert_eq!(statistics.rules["eslint(no-debugger)"].violations, 1);
+        assert_eq!(statistics.rules["eslint(use-isnan)"].violations, 1);
+        assert_eq!(statistics.number_of_fixes(), 0);
+        assert_eq!(statistics.slowest_files.len(), 2);
+    }
+
+    #[test]
+    fn stats_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.json");
+        let args = &["--stats-json", path.to_str().unwrap(), "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert!(result.statistics.is_none());
+        let json = std::fs::read_to_string(path).unwrap();
+        assert!(json.contains(r#""numberOfFiles": 1"#));
+        assert!(json.contains(r#""eslint(no-debugger)": {"#));
+        assert!(json.contains(r#""slowestFiles": ["#));
+    }
+
+    #[test]
+    fn quiet_still_counts_warnings() {
+        let args = &["--quiet", "-W", "correctness", "-D", "eqeqeq", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn stdin() {
+        let result = test_stdin(&[], "debugger;");
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn stdin_filename() {
+        // The file does not exist, its extension enables TypeScript parsing.
+        let args = &["--stdin-filename", "fixtures/linter/unsaved.ts"];
+        let result = test_stdin(args, "export const x: number = 1; debugger;");
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn stdin_filename_ignored() {
+        let args = &["--stdin-filename", "foo.ts", "--ignore-pattern", "*.ts"];
+        let result = test_stdin(args, "debugger;");
+        assert_eq!(result.number_of_files, 0);
+        assert_eq!(result.number_of_warnings, 0);
+    }
+
+    #[test]
+    fn stdin_with_paths() {
+        let options = lint_command().run_inner(&["--stdin", "fixtures/linter"]).unwrap();
+        match LintRunner::new(options).with_stdin_source_text(String::new()).run() {
+            CliRunResult::InvalidOptions { message } => {
+                assert_eq!(message, "`--stdin` cannot be used together with paths.");
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
     #[test]
     fn multi_files() {
         let args = &["fixtures/linter/debugger.js", "fixtures/linter/nan.js"];
@@ -291,6 +528,84 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn ignore_file() {
+        let args = &["fixtures/ignore_file"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn ignore_file_explicit_path() {
+        let args = &["fixtures/ignore_file/ignored.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+        assert_eq!(result.number_of_warnings, 0);
+    }
+
+    #[test]
+    fn ignore_file_no_ignore_dir() {
+        let args = &["--no-ignore", "fixtures/ignore_file"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn config_ignore_patterns() {
+        let args = &["-c", "fixtures/ignore_patterns/eslintrc.json", "fixtures/ignore_patterns"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn verbose() {
+        let args = &["--verbose", "fixtures/ignore_file"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn glob() {
+        let args = &["fixtures/linter/*.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn glob_braces() {
+        let args = &["fixtures/**/{debugger,nan}.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn negated_glob() {
+        let args = &["fixtures/linter", "!**/nan.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn glob_and_negated_glob() {
+        let args = &["fixtures/linter/*.js", "!fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn invalid_glob() {
+        let message = test_invalid_options(&["fixtures/{linter"]);
+        assert!(message.starts_with("Invalid glob pattern"), "{message}");
+    }
+
     #[test]
     fn filter_allow_all() {
         let args = &["-A", "all", "fixtures/linter"];
@@ -419,6 +734,30 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn invalid_rule_config() {
+        let message = test_invalid_options(&[
+            "-c",
+            "fixtures/invalid_rule_config/eslintrc.json",
+            "fixtures/invalid_rule_config/test.js",
+        ]);
+        assert!(
+            message.contains("Invalid configuration of rule `eslint/no-restricted-imports`"),
+            "{message}"
+        );
+        assert!(message.contains("eslintrc.json:4:"), "{message}");
+    }
+
+    #[test]
+    fn renamed_rule() {
+        let args = &["-c", "fixtures/renamed_rule/eslintrc.json", "fixtures/renamed_rule/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        // The rule is deprecated, but still enabled by its old name.
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
     #[test]
     fn typescript_eslint() {
         let args = &[
@@ -464,6 +803,37 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn lint_markdown_file() {
+        let args = &["fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_html_file() {
+        let args = &["fixtures/html/debugger.html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_json_files() {
+        let args = &["--json-plugin", "fixtures/json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = &["fixtures/json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+    }
+
     #[test]
     fn lint_astro_file() {
         let args = &["fixtures/astro/debugger.astro"];
@@ -492,6 +862,25 @@ mod test {
             .contains("oxc/tsconfig.json\" does not exist, Please provide a valid tsconfig file."));
     }
 
+    #[test]
+    fn test_tsconfig_paths_and_references() {
+        // `@/*` is declared in an extended tsconfig with a fallback location,
+        // `~/*` is declared in a referenced project with its own `baseUrl`.
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-D",
+            "named",
+            "--tsconfig",
+            "fixtures/tsconfig_paths/tsconfig.json",
+            "fixtures/tsconfig_paths",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 5);
+        assert_eq!(result.number_of_errors, 3);
+    }
+
     #[test]
     fn test_enable_vitest_plugin() {
         let args = &[
@@ -7,12 +7,17 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxlint::{CliRunResult, LintRunner, Runner};
+use oxlint::{CliRunResult, LintRunner, Runner, SearchRunner};
 
 fn main() -> CliRunResult {
     init_tracing();
     init_miette();
 
+    if std::env::args().nth(1).is_some_and(|arg| arg == "search") {
+        let command = oxlint::search_command().run();
+        return SearchRunner::new(command).run();
+    }
+
     let command = oxlint::lint_command().run();
     command.handle_threads();
     LintRunner::new(command).run()
@@ -4,6 +4,8 @@ use std::{
     time::Duration,
 };
 
+use oxc_linter::LintStatistics;
+
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
@@ -11,6 +13,7 @@ pub enum CliRunResult {
     PathNotFound { paths: Vec<PathBuf> },
     LintResult(LintResult),
     FormatResult(FormatResult),
+    SearchResult(SearchResult),
     TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
 }
 
@@ -24,6 +27,15 @@ pub struct LintResult {
     pub max_warnings_exceeded: bool,
     pub deny_warnings: bool,
     pub print_summary: bool,
+    /// Printed with `--stats`.
+    pub statistics: Option<LintStatistics>,
+}
+
+#[derive(Debug)]
+pub struct SearchResult {
+    pub duration: Duration,
+    pub number_of_files: usize,
+    pub number_of_matches: usize,
 }
 
 #[derive(Debug)]
@@ -54,7 +66,12 @@ impl Termination for CliRunResult {
                 max_warnings_exceeded,
                 deny_warnings,
                 print_summary,
+                statistics,
             }) => {
+                if let Some(statistics) = statistics {
+                    Self::print_statistics(&statistics);
+                }
+
                 if print_summary {
                     let threads = rayon::current_num_threads();
                     let number_of_diagnostics = number_of_warnings + number_of_errors;
@@ -96,6 +113,15 @@ impl Termination for CliRunResult {
                 );
                 ExitCode::from(0)
             }
+            Self::SearchResult(SearchResult { duration, number_of_files, number_of_matches }) => {
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_files == 1 { "" } else { "s" };
+                let es = if number_of_matches == 1 { "" } else { "es" };
+                println!(
+                    "Found {number_of_matches} match{es} in {time} on {number_of_files} file{s}."
+                );
+                ExitCode::from(0)
+            }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
                 let time = Self::get_execution_time(&duration);
                 println!("Finished in {time}.");
@@ -112,6 +138,26 @@ impl Termination for CliRunResult {
 }
 
 impl CliRunResult {
+    #[allow(clippy::print_stdout)]
+    fn print_statistics(statistics: &LintStatistics) {
+        println!();
+        println!("Rules:");
+        if statistics.rules.is_empty() {
+            println!("  No violations found.");
+        }
+        for (code, rule) in &statistics.rules {
+            let s = if rule.violations == 1 { "" } else { "s" };
+            let es = if rule.fixes == 1 { "" } else { "es" };
+            println!("  {code}: {} violation{s}, {} fix{es}", rule.violations, rule.fixes);
+        }
+
+        println!("Slowest files:");
+        for file in &statistics.slowest_files {
+            let ms = file.duration.as_secs_f64() * 1000.0;
+            println!("  {ms:.2}ms {}", file.path.display());
+        }
+    }
+
     fn get_execution_time(duration: &Duration) -> String {
         let ms = duration.as_millis();
         if ms < 1000 {
@@ -0,0 +1,186 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use oxc_allocator::Allocator;
+use oxc_codemod::{apply_edits, unified_diff, Edit, Pattern};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
+use rayon::prelude::*;
+
+use crate::{
+    command::SearchCommand,
+    walk::{Extensions, Walk},
+    CliRunResult, Runner, SearchResult,
+};
+
+pub struct SearchRunner {
+    options: SearchCommand,
+}
+
+/// Matches of the pattern in one file.
+struct FileMatches {
+    path: Box<Path>,
+    /// `(line, column, first line of matched source text)`
+    matches: Vec<(usize, usize, String)>,
+    /// Source text before and after `--rewrite`
+    rewrite: Option<(String, String)>,
+}
+
+impl Runner for SearchRunner {
+    type Options = SearchCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    #[allow(clippy::print_stderr)]
+    fn run(self) -> CliRunResult {
+        let SearchCommand { rewrite, fix, ignore_options, pattern, mut paths } = self.options;
+
+        let now = Instant::now();
+
+        let pattern = match Pattern::new(&pattern) {
+            Ok(pattern) => pattern,
+            Err(errors) => {
+                let message = errors.first().map(ToString::to_string).unwrap_or_default();
+                return CliRunResult::InvalidOptions {
+                    message: format!("Invalid pattern `{pattern}`: {message}"),
+                };
+            }
+        };
+
+        let not_found = paths.iter().filter(|path| !path.exists()).cloned().collect::<Vec<_>>();
+        if !not_found.is_empty() {
+            return CliRunResult::PathNotFound { paths: not_found };
+        }
+        if paths.is_empty() {
+            paths.push(PathBuf::from("."));
+        }
+
+        let extensions = Extensions(VALID_EXTENSIONS.to_vec());
+        let paths = Walk::new(&paths, &ignore_options).with_extensions(extensions).paths();
+        let number_of_files = paths.len();
+
+        let results = paths
+            .into_par_iter()
+            .filter_map(|path| Self::search_file(path, &pattern, rewrite.as_deref()))
+            .collect::<Vec<_>>();
+
+        let mut stdout = BufWriter::new(std::io::stdout());
+        let mut number_of_matches = 0;
+        for FileMatches { path, matches, rewrite } in results {
+            number_of_matches += matches.len();
+            let display_path = path.to_string_lossy();
+            for (line, column, text) in matches {
+                writeln!(stdout, "{display_path}:{line}:{column}: {text}").unwrap();
+            }
+            let Some((source_text, rewritten)) = rewrite else { continue };
+            if fix {
+                if let Err(err) = fs::write(&path, rewritten) {
+                    eprintln!("Failed to write {display_path}: {err}");
+                }
+            } else {
+                write!(stdout, "{}", unified_diff(&display_path, &source_text, &rewritten))
+                    .unwrap();
+            }
+        }
+        stdout.flush().unwrap();
+
+        CliRunResult::SearchResult(SearchResult {
+            duration: now.elapsed(),
+            number_of_files,
+            number_of_matches,
+        })
+    }
+}
+
+impl SearchRunner {
+    fn search_file(
+        path: Box<Path>,
+        pattern: &Pattern,
+        rewrite: Option<&str>,
+    ) -> Option<FileMatches> {
+        let source_type = SourceType::from_path(&path).ok()?;
+        let source_text = fs::read_to_string(&path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if ret.panicked {
+            return None;
+        }
+
+        let found = pattern.find(&ret.program, &source_text);
+        if found.is_empty() {
+            return None;
+        }
+        let matches = found
+            .iter()
+            .map(|m| {
+                let (line, column) = Self::line_column(&source_text, m.span);
+                let text = m.span.source_text(&source_text).lines().next().unwrap_or_default();
+                (line, column, text.to_string())
+            })
+            .collect();
+        let rewrite = rewrite.map(|template| {
+            let edits = found.iter().map(|m| Edit::new(m.span, m.substitute(template))).collect();
+            let rewritten = apply_edits(&source_text, edits);
+            (source_text.clone(), rewritten)
+        });
+        Some(FileMatches { path, matches, rewrite })
+    }
+
+    /// 1-indexed line and column of the start of `span`.
+    fn line_column(source_text: &str, span: Span) -> (usize, usize) {
+        let before = &source_text[..span.start as usize];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map_or(before.len(), |index| before.len() - index - 1) + 1;
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SearchRunner;
+    use crate::{search_command, CliRunResult, Runner, SearchResult};
+
+    fn test(args: &[&str]) -> SearchResult {
+        let options = search_command().run_inner(args).unwrap();
+        match SearchRunner::new(options).run() {
+            CliRunResult::SearchResult(search_result) => search_result,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn search() {
+        let result = test(&["console.$METHOD($$$ARGS)", "fixtures/search"]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_matches, 3);
+    }
+
+    #[test]
+    fn search_metavariable_must_match_same_text() {
+        let result = test(&["$A === $A", "fixtures/search"]);
+        assert_eq!(result.number_of_matches, 1);
+    }
+
+    #[test]
+    fn rewrite() {
+        let result = test(&[
+            "--rewrite",
+            "logger.$METHOD($$$ARGS)",
+            "console.$METHOD($$$ARGS)",
+            "fixtures/search",
+        ]);
+        assert_eq!(result.number_of_matches, 3);
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let options = search_command().run_inner(&["console.log("]).unwrap();
+        assert!(matches!(SearchRunner::new(options).run(), CliRunResult::InvalidOptions { .. }));
+    }
+}
@@ -1,12 +1,19 @@
 use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    fmt,
     path::{Path, PathBuf},
     sync::mpsc,
 };
 
-use ignore::{overrides::OverrideBuilder, DirEntry};
+use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
+    overrides::OverrideBuilder,
+    DirEntry, Match,
+};
 use oxc_span::VALID_EXTENSIONS;
 
-use crate::IgnoreOptions;
+use crate::{command::DEFAULT_IGNORE_PATH, IgnoreOptions};
 
 #[derive(Clone)]
 pub struct Extensions(pub Vec<&'static str>);
@@ -17,21 +24,57 @@ impl Default for Extensions {
     }
 }
 
+/// Why a path was not linted, reported under `--verbose`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The file extension is not supported, or the file is minified.
+    Unsupported,
+    /// Hidden files and directories are never linted.
+    Hidden,
+    /// Matched by `--ignore-pattern` or `ignorePatterns` of the configuration file.
+    IgnorePattern(String),
+    /// Matched by a line of an ignore file such as `.gitignore`, `.ignore` or `--ignore-path`.
+    IgnoreFile { path: PathBuf, pattern: String },
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "unsupported file type"),
+            Self::Hidden => write!(f, "hidden file"),
+            Self::IgnorePattern(pattern) => write!(f, "matched ignore pattern `{pattern}`"),
+            Self::IgnoreFile { path, pattern } => {
+                write!(f, "matched `{pattern}` in {}", path.display())
+            }
+        }
+    }
+}
+
 pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    options: IgnoreOptions,
+}
+
+#[derive(Default)]
+struct Collected {
+    paths: Vec<Box<Path>>,
+    /// Visited directories, used for finding skipped paths.
+    dirs: Vec<PathBuf>,
+    /// Visited files that are not linted.
+    unsupported: Vec<PathBuf>,
 }
 
 struct WalkBuilder {
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+    sender: mpsc::Sender<Collected>,
     extensions: Extensions,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
         Box::new(WalkCollector {
-            paths: vec![],
+            collected: Collected::default(),
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
         })
@@ -39,15 +82,15 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
 }
 
 struct WalkCollector {
-    paths: Vec<Box<Path>>,
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+    collected: Collected,
+    sender: mpsc::Sender<Collected>,
     extensions: Extensions,
 }
 
 impl Drop for WalkCollector {
     fn drop(&mut self) {
-        let paths = std::mem::take(&mut self.paths);
-        self.sender.send(paths).unwrap();
+        let collected = std::mem::take(&mut self.collected);
+        self.sender.send(collected).unwrap();
     }
 }
 
@@ -55,10 +98,12 @@ impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if entry.file_type().is_some_and(|ft| !ft.is_dir())
-                    && Walk::is_wanted_entry(&entry, &self.extensions)
-                {
-                    self.paths.push(entry.path().to_path_buf().into_boxed_path());
+                if entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                    self.collected.dirs.push(entry.path().to_path_buf());
+                } else if Walk::is_wanted_entry(&entry, &self.extensions) {
+                    self.collected.paths.push(entry.path().to_path_buf().into_boxed_path());
+                } else {
+                    self.collected.unsupported.push(entry.path().to_path_buf());
                 }
                 ignore::WalkState::Continue
             }
@@ -87,7 +132,12 @@ impl Walk {
         }
 
         if !options.no_ignore {
-            inner.add_custom_ignore_filename(&options.ignore_path);
+            // `.eslintignore` is looked up in every directory, while `--ignore-path` points to a single file.
+            if options.ignore_path == DEFAULT_IGNORE_PATH {
+                inner.add_custom_ignore_filename(&options.ignore_path);
+            } else {
+                inner.add_ignore(&options.ignore_path);
+            }
 
             if !options.ignore_pattern.is_empty() {
                 let mut override_builder = OverrideBuilder::new(Path::new("/"));
@@ -104,17 +154,73 @@ impl Walk {
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
-        let inner =
-            inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        let inner = inner
+            .ignore(!options.no_ignore)
+            .git_ignore(!options.no_ignore)
+            .git_exclude(!options.no_ignore)
+            .git_global(false)
+            // Respect `.gitignore` outside of git repositories as well.
+            .require_git(false)
+            .follow_links(options.symlinks)
+            .build_parallel();
+        Self { inner, extensions: Extensions::default(), options: options.clone() }
     }
 
     pub fn paths(self) -> Vec<Box<Path>> {
-        let (sender, receiver) = mpsc::channel::<Vec<Box<Path>>>();
+        self.collect().paths
+    }
+
+    /// Paths to lint, along with the paths that were skipped and why.
+    ///
+    /// Skipped paths are found by listing every visited directory, which makes the walk slower.
+    pub fn paths_and_skipped(self) -> (Vec<Box<Path>>, Vec<(PathBuf, SkipReason)>) {
+        let options = self.options.clone();
+        let Collected { paths, dirs, unsupported } = self.collect();
+
+        let visited = paths
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(dirs.iter().map(PathBuf::as_path))
+            .chain(unsupported.iter().map(PathBuf::as_path))
+            .collect::<HashSet<&Path>>();
+
+        let mut reasons = SkipReasons::new(&options);
+        let mut skipped = unsupported
+            .iter()
+            .map(|path| (path.clone(), SkipReason::Unsupported))
+            .collect::<Vec<_>>();
+        for dir in &dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else { continue };
+            for entry in entries.filter_map(Result::ok) {
+                let path = dir.join(entry.file_name());
+                if visited.contains(path.as_path()) {
+                    continue;
+                }
+                let is_dir = entry.file_type().is_ok_and(|ft| ft.is_dir());
+                let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+                let reason =
+                    reasons.find(&path, is_dir).or_else(|| is_hidden.then_some(SkipReason::Hidden));
+                if let Some(reason) = reason {
+                    skipped.push((path, reason));
+                }
+            }
+        }
+        skipped.sort_by(|a, b| a.0.cmp(&b.0));
+
+        (paths, skipped)
+    }
+
+    fn collect(self) -> Collected {
+        let (sender, receiver) = mpsc::channel::<Collected>();
         let mut builder = WalkBuilder { sender, extensions: self.extensions };
         self.inner.visit(&mut builder);
         drop(builder);
-        receiver.into_iter().flatten().collect()
+        receiver.into_iter().fold(Collected::default(), |mut acc, collected| {
+            acc.paths.extend(collected.paths);
+            acc.dirs.extend(collected.dirs);
+            acc.unsupported.extend(collected.unsupported);
+            acc
+        })
     }
 
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
@@ -137,11 +243,90 @@ impl Walk {
     }
 }
 
+/// Matches paths against the ignore files and patterns used by [Walk].
+///
+/// Used for explaining why the walker skipped a path, and for applying the ignore rules to paths
+/// which the walker does not filter, such as files passed explicitly.
+pub struct SkipReasons<'o> {
+    options: &'o IgnoreOptions,
+    cwd: PathBuf,
+    patterns: Option<Gitignore>,
+    /// Ignore files by directory.
+    ignore_files: HashMap<PathBuf, Vec<Gitignore>>,
+}
+
+impl<'o> SkipReasons<'o> {
+    pub fn new(options: &'o IgnoreOptions) -> Self {
+        let patterns = (!options.ignore_pattern.is_empty())
+            .then(|| {
+                let mut builder = GitignoreBuilder::new(Path::new("/"));
+                for pattern in &options.ignore_pattern {
+                    let _ = builder.add_line(None, pattern);
+                }
+                builder.build().ok()
+            })
+            .flatten();
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Self { options, cwd, patterns, ignore_files: HashMap::default() }
+    }
+
+    /// The rule that excludes `path`, or `None` if it is not ignored.
+    pub fn find(&mut self, path: &Path, is_dir: bool) -> Option<SkipReason> {
+        if self.options.no_ignore {
+            return None;
+        }
+        let path = self.cwd.join(path);
+        let path = path.as_path();
+        if let Some(Match::Ignore(glob)) = self.patterns.as_ref().map(|p| p.matched(path, is_dir)) {
+            return Some(SkipReason::IgnorePattern(glob.original().to_string()));
+        }
+        if self.options.ignore_path != DEFAULT_IGNORE_PATH {
+            let (ignore, _err) = Gitignore::new(self.cwd.join(&self.options.ignore_path));
+            if let Match::Ignore(glob) = ignore.matched(path, is_dir) {
+                return Some(Self::ignore_file_reason(glob));
+            }
+        }
+        // The closest ignore file wins, custom ignore files take precedence over `.ignore` over `.gitignore`.
+        for dir in path.ancestors().skip(1) {
+            for ignore in self.ignore_files_in(dir) {
+                match ignore.matched(path, is_dir) {
+                    Match::Ignore(glob) => return Some(Self::ignore_file_reason(glob)),
+                    Match::Whitelist(_) => return None,
+                    Match::None => {}
+                }
+            }
+        }
+        None
+    }
+
+    fn ignore_files_in(&mut self, dir: &Path) -> &[Gitignore] {
+        let custom_ignore_file =
+            (self.options.ignore_path == DEFAULT_IGNORE_PATH).then_some(&self.options.ignore_path);
+        self.ignore_files.entry(dir.to_path_buf()).or_insert_with(|| {
+            custom_ignore_file
+                .map(AsRef::as_ref)
+                .into_iter()
+                .chain([".ignore".as_ref(), ".gitignore".as_ref()])
+                .map(|name: &OsStr| dir.join(name))
+                .filter(|path| path.is_file())
+                .map(|path| Gitignore::new(path).0)
+                .collect()
+        })
+    }
+
+    fn ignore_file_reason(glob: &ignore::gitignore::Glob) -> SkipReason {
+        SkipReason::IgnoreFile {
+            path: glob.from().map(Path::to_path_buf).unwrap_or_default(),
+            pattern: glob.original().to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::{env, ffi::OsString};
 
-    use super::{Extensions, Walk};
+    use super::{Extensions, SkipReason, Walk};
     use crate::IgnoreOptions;
 
     #[test]
@@ -165,4 +350,43 @@ mod test {
 
         assert_eq!(paths, vec!["bar.vue", "foo.js"]);
     }
+
+    #[test]
+    fn test_walk_skipped() {
+        let fixture = env::current_dir().unwrap().join("fixtures/ignore_file");
+        let fixtures = vec![fixture.clone()];
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec![],
+            symlinks: false,
+        };
+
+        let (paths, skipped) = Walk::new(&fixtures, &ignore_options).paths_and_skipped();
+        let paths = paths
+            .iter()
+            .map(|path| path.strip_prefix(&fixture).unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        let skipped = skipped
+            .into_iter()
+            .map(|(path, reason)| {
+                (path.strip_prefix(&fixture).unwrap().to_string_lossy().to_string(), reason)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(paths, vec!["test.js"]);
+        assert_eq!(
+            skipped,
+            vec![
+                (".ignore".to_string(), SkipReason::Hidden),
+                (
+                    "ignored.js".to_string(),
+                    SkipReason::IgnoreFile {
+                        path: fixture.join(".ignore"),
+                        pattern: "ignored.js".to_string()
+                    }
+                ),
+            ]
+        );
+    }
 }
@@ -5,14 +5,49 @@ use oxc_ast::{ast::Program, Trivias};
 use oxc_codegen::{CodeGenerator, CodegenOptions, CommentOptions};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_mangler::{MangleOptions, Mangler};
-use oxc_minifier::{CompressOptions, Compressor};
+use oxc_minifier::{CompressOptions, Compressor, KnownGlobals};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{ScopeTree, SemanticBuilder, SemanticBuilderReturn, SymbolTable};
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 
+/// Options of every stage of [`Compiler`], a stage set to `None` is skipped.
+#[derive(Debug, Clone)]
+pub struct CompilerOptions {
+    pub parse: ParseOptions,
+
+    /// Report the syntax errors found by semantic analysis.
+    pub check_semantic_error: bool,
+
+    pub transform: Option<TransformOptions>,
+
+    pub compress: Option<CompressOptions>,
+
+    pub mangle: Option<MangleOptions>,
+
+    pub codegen: Option<CodegenOptions>,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            parse: ParseOptions::default(),
+            check_semantic_error: true,
+            transform: Some(TransformOptions::default()),
+            compress: None,
+            mangle: None,
+            codegen: Some(CodegenOptions::default()),
+        }
+    }
+}
+
+/// Compiler driver which runs parse → semantic → transform → compress → mangle → codegen with
+/// [`CompilerOptions`].
+///
+/// Implement [`CompilerInterface`] instead to hook into the stages.
 #[derive(Default)]
 pub struct Compiler {
+    options: CompilerOptions,
     printed: String,
     errors: Vec<OxcDiagnostic>,
 }
@@ -22,12 +57,44 @@ impl CompilerInterface for Compiler {
         self.errors.extend(errors);
     }
 
+    fn parse_options(&self) -> ParseOptions {
+        self.options.parse
+    }
+
+    fn transform_options(&self) -> Option<TransformOptions> {
+        self.options.transform.clone()
+    }
+
+    fn compress_options(&self) -> Option<CompressOptions> {
+        self.options.compress.clone()
+    }
+
+    fn mangle_options(&self) -> Option<MangleOptions> {
+        self.options.mangle.clone()
+    }
+
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        self.options.codegen
+    }
+
+    fn check_semantic_error(&self) -> bool {
+        self.options.check_semantic_error
+    }
+
     fn after_codegen(&mut self, printed: String) {
         self.printed = printed;
     }
 }
 
 impl Compiler {
+    pub fn new(options: CompilerOptions) -> Self {
+        Self { options, printed: String::new(), errors: vec![] }
+    }
+
+    pub fn options(&self) -> &CompilerOptions {
+        &self.options
+    }
+
     /// # Errors
     ///
     /// * A list of [OxcDiagnostic].
@@ -44,6 +111,23 @@ impl Compiler {
             Err(mem::take(&mut self.errors))
         }
     }
+
+    /// Compile several files with the same options, in order.
+    ///
+    /// The source type of each file is detected by [`SourceType::detect`]. Every file is
+    /// compiled with its own [`Allocator`], so the memory of a file is freed before the next one.
+    pub fn execute_files<'a, I>(&mut self, files: I) -> Vec<Result<String, Vec<OxcDiagnostic>>>
+    where
+        I: IntoIterator<Item = (&'a Path, &'a str)>,
+    {
+        files
+            .into_iter()
+            .map(|(source_path, source_text)| {
+                let source_type = SourceType::detect(source_path, source_text);
+                self.execute(source_text, source_type, source_path)
+            })
+            .collect()
+    }
 }
 
 pub trait CompilerInterface {
@@ -130,7 +214,9 @@ pub trait CompilerInterface {
 
         /* Transform */
 
-        if let Some(options) = self.transform_options() {
+        let transform_options = self.transform_options();
+        let targets = transform_options.as_ref().and_then(|options| options.targets.clone());
+        if let Some(options) = transform_options {
             let mut transformer_return = self.transform(
                 options,
                 &allocator,
@@ -155,7 +241,12 @@ pub trait CompilerInterface {
 
         /* Compress */
 
-        if let Some(options) = self.compress_options() {
+        if let Some(mut options) = self.compress_options() {
+            // The platform of the transform targets is known, unless the globals are configured
+            if let Some(targets) = targets.filter(|_| options.known_globals.is_empty()) {
+                options.known_globals =
+                    KnownGlobals::for_engines(targets.keys().map(String::as_str));
+            }
             self.compress(&allocator, &mut program, options);
         }
 
@@ -0,0 +1,345 @@
+//! Fuzz targets of the parser and the code generator.
+//!
+//! Each target takes the raw input of a fuzzer and panics when it finds a bug, so that they can
+//! be wrapped by `cargo fuzz` (see `fuzz/` in the repository) or any other fuzzing engine:
+//!
+//! ```ignore
+//! #![no_main]
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| oxc::fuzz::parse_bytes(data));
+//! ```
+//!
+//! * [`parse_bytes`] parses arbitrary source text, which must never panic or exhaust memory.
+//! * [`round_trip`] builds an arbitrary AST and checks it with
+//!   [`assert_program_roundtrip`](crate::testing::assert_program_roundtrip).
+//! * [`differential`] compares which source texts are accepted by the parser and by a
+//!   [`ReferenceParser`].
+
+use arbitrary::{Result, Unstructured};
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::{SourceType, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+};
+
+use crate::testing::assert_program_roundtrip;
+
+/// Inputs longer than this are skipped, larger inputs rarely find new bugs and slow fuzzing down.
+pub const MAX_SOURCE_LEN: usize = 64 * 1024;
+
+/// Maximum bytes allocated for the AST per byte of source text, more than this is reported as a
+/// memory blowup.
+pub const MAX_ALLOCATION_FACTOR: usize = 256;
+
+/// Maximum nesting of the expressions and statements generated by [`round_trip`].
+const MAX_DEPTH: u32 = 8;
+
+const IDENTIFIERS: [&str; 4] = ["a", "b", "c", "d"];
+
+/// Parse `data` as source text, the first byte selects the source type.
+///
+/// # Panics
+///
+/// * The parser panics.
+/// * The parser bails out without reporting an error.
+/// * The AST takes more than [`MAX_ALLOCATION_FACTOR`] bytes per byte of source text.
+pub fn parse_bytes(data: &[u8]) {
+    let Some((source_type, source_text)) = split_input(data) else { return };
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, source_text, source_type);
+    assert!(!ret.panicked || !ret.errors.is_empty(), "Parser bailed out without an error");
+    let allocated = allocator.allocated_bytes();
+    assert!(
+        allocated <= (source_text.len() + 1) * MAX_ALLOCATION_FACTOR,
+        "Parser allocated {allocated} bytes for {} bytes of source text",
+        source_text.len()
+    );
+}
+
+/// Build an arbitrary AST from `data`, print it, then parse and print the output again.
+///
+/// # Panics
+///
+/// * Any of [`assert_program_roundtrip`].
+pub fn round_trip(data: &[u8]) {
+    let allocator = Allocator::default();
+    let mut generator = AstGenerator::new(&allocator, Unstructured::new(data));
+    // Running out of data still generates a valid AST, only errors such as overflows are skipped.
+    let Ok(program) = generator.program() else { return };
+    assert_program_roundtrip(&program);
+}
+
+/// A parser to compare against in [`differential`], e.g. a JavaScript engine or a previous
+/// version of the parser.
+pub trait ReferenceParser {
+    /// Whether `source_text` is syntactically valid, or `None` if the reference parser does not
+    /// support `source_type`.
+    fn accepts(&self, source_text: &str, source_type: SourceType) -> Option<bool>;
+}
+
+impl<F: Fn(&str, SourceType) -> Option<bool>> ReferenceParser for F {
+    fn accepts(&self, source_text: &str, source_type: SourceType) -> Option<bool> {
+        self(source_text, source_type)
+    }
+}
+
+/// Parse `data` like [`parse_bytes`], and compare whether it is accepted with `reference`.
+///
+/// # Panics
+///
+/// * Any of [`parse_bytes`].
+/// * The parser reports errors for source text `reference` accepts, or the other way around.
+pub fn differential<R: ReferenceParser>(data: &[u8], reference: &R) {
+    let Some((source_type, source_text)) = split_input(data) else { return };
+    let Some(expected) = reference.accepts(source_text, source_type) else { return };
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, source_text, source_type);
+    assert!(!ret.panicked || !ret.errors.is_empty(), "Parser bailed out without an error");
+    assert_eq!(
+        ret.errors.is_empty(),
+        expected,
+        "Parser disagrees with the reference parser on {source_type:?}: {source_text}\n{:?}",
+        ret.errors
+    );
+}
+
+fn split_input(data: &[u8]) -> Option<(SourceType, &str)> {
+    let (&first, rest) = data.split_first()?;
+    if rest.len() > MAX_SOURCE_LEN {
+        return None;
+    }
+    let source_type = match first % 6 {
+        0 => SourceType::js().with_script(true),
+        1 => SourceType::js().with_module(true),
+        2 => SourceType::jsx(),
+        3 => SourceType::ts(),
+        4 => SourceType::tsx(),
+        _ => SourceType::d_ts(),
+    };
+    Some((source_type, std::str::from_utf8(rest).ok()?))
+}
+
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> ParserReturn<'a> {
+    Parser::new(allocator, source_text, source_type).parse()
+}
+
+/// Builds ASTs of modules out of unstructured data.
+///
+/// The ASTs only contain valid code, e.g. no `delete` of identifiers, which is an error in strict
+/// mode, and no optional chains outside of a `ChainExpression`. Parentheses are left to codegen.
+struct AstGenerator<'a, 'u> {
+    ast: AstBuilder<'a>,
+    u: Unstructured<'u>,
+    depth: u32,
+}
+
+impl<'a, 'u> AstGenerator<'a, 'u> {
+    fn new(allocator: &'a Allocator, u: Unstructured<'u>) -> Self {
+        Self { ast: AstBuilder::new(allocator), u, depth: 0 }
+    }
+
+    fn program(&mut self) -> Result<Program<'a>> {
+        let body = self.statements()?;
+        let source_type = SourceType::js().with_module(true);
+        Ok(self.ast.program(SPAN, source_type, None, self.ast.vec(), body))
+    }
+
+    fn statements(&mut self) -> Result<Vec<'a, Statement<'a>>> {
+        let mut body = self.ast.vec();
+        for _ in 0..self.u.choose_index(8)? {
+            body.push(self.statement()?);
+        }
+        Ok(body)
+    }
+
+    fn statement(&mut self) -> Result<Statement<'a>> {
+        self.depth += 1;
+        let choice = if self.depth > MAX_DEPTH { 0 } else { self.u.choose_index(5)? };
+        let statement = match choice {
+            0 => self.ast.statement_expression(SPAN, self.expression()?),
+            1 => {
+                let kind = VariableDeclarationKind::Var;
+                let id = self.ast.binding_pattern(
+                    self.ast.binding_pattern_kind_binding_identifier(SPAN, self.identifier()?),
+                    None::<oxc_allocator::Box<'a, TSTypeAnnotation<'a>>>,
+                    false,
+                );
+                let init = if self.u.arbitrary()? { Some(self.expression()?) } else { None };
+                let declarator = self.ast.variable_declarator(SPAN, kind, id, init, false);
+                let declarations = self.ast.vec1(declarator);
+                self.ast.statement_declaration(self.ast.declaration_variable(
+                    SPAN,
+                    kind,
+                    declarations,
+                    false,
+                ))
+            }
+            2 => {
+                let test = self.expression()?;
+                let consequent = self.statement()?;
+                let alternate = if self.u.arbitrary()? { Some(self.statement()?) } else { None };
+                self.ast.statement_if(SPAN, test, consequent, alternate)
+            }
+            3 => {
+                let test = self.expression()?;
+                let body = self.statement()?;
+                self.ast.statement_while(SPAN, test, body)
+            }
+            _ => {
+                let body = self.statements()?;
+                self.ast.statement_block(SPAN, body)
+            }
+        };
+        self.depth -= 1;
+        Ok(statement)
+    }
+
+    fn expression(&mut self) -> Result<Expression<'a>> {
+        self.depth += 1;
+        let choice = if self.depth > MAX_DEPTH {
+            self.u.choose_index(4)?
+        } else {
+            self.u.choose_index(13)?
+        };
+        let expression = match choice {
+            0 => self.ast.expression_identifier_reference(SPAN, self.identifier()?),
+            1 => {
+                let value = self.u.arbitrary::<u32>()?;
+                let raw = self.ast.str(&value.to_string());
+                self.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(value),
+                    raw,
+                    NumberBase::Decimal,
+                )
+            }
+            2 => {
+                let value = self.u.arbitrary::<&str>()?;
+                self.ast.expression_string_literal(SPAN, self.ast.atom(value))
+            }
+            3 => match self.u.choose_index(3)? {
+                0 => self.ast.expression_boolean_literal(SPAN, true),
+                1 => self.ast.expression_boolean_literal(SPAN, false),
+                _ => self.ast.expression_null_literal(SPAN),
+            },
+            4 => {
+                let operator = *self.u.choose(&[
+                    UnaryOperator::UnaryNegation,
+                    UnaryOperator::UnaryPlus,
+                    UnaryOperator::LogicalNot,
+                    UnaryOperator::BitwiseNot,
+                    UnaryOperator::Typeof,
+                    UnaryOperator::Void,
+                ])?;
+                self.ast.expression_unary(SPAN, operator, self.expression()?)
+            }
+            5 => {
+                let operator = *self.u.choose(&[
+                    BinaryOperator::Equality,
+                    BinaryOperator::Inequality,
+                    BinaryOperator::StrictEquality,
+                    BinaryOperator::StrictInequality,
+                    BinaryOperator::LessThan,
+                    BinaryOperator::LessEqualThan,
+                    BinaryOperator::GreaterThan,
+                    BinaryOperator::GreaterEqualThan,
+                    BinaryOperator::ShiftLeft,
+                    BinaryOperator::ShiftRight,
+                    BinaryOperator::ShiftRightZeroFill,
+                    BinaryOperator::Addition,
+                    BinaryOperator::Subtraction,
+                    BinaryOperator::Multiplication,
+                    BinaryOperator::Division,
+                    BinaryOperator::Remainder,
+                    BinaryOperator::BitwiseOR,
+                    BinaryOperator::BitwiseXOR,
+                    BinaryOperator::BitwiseAnd,
+                    BinaryOperator::In,
+                    BinaryOperator::Instanceof,
+                    BinaryOperator::Exponential,
+                ])?;
+                let left = self.expression()?;
+                let right = self.expression()?;
+                self.ast.expression_binary(SPAN, left, operator, right)
+            }
+            6 => {
+                let operator = *self.u.choose(&[
+                    LogicalOperator::Or,
+                    LogicalOperator::And,
+                    LogicalOperator::Coalesce,
+                ])?;
+                let left = self.expression()?;
+                let right = self.expression()?;
+                self.ast.expression_logical(SPAN, left, operator, right)
+            }
+            7 => {
+                let test = self.expression()?;
+                let consequent = self.expression()?;
+                let alternate = self.expression()?;
+                self.ast.expression_conditional(SPAN, test, consequent, alternate)
+            }
+            8 => {
+                let operator = *self.u.choose(&[
+                    AssignmentOperator::Assign,
+                    AssignmentOperator::Addition,
+                    AssignmentOperator::Exponential,
+                    AssignmentOperator::LogicalAnd,
+                    AssignmentOperator::LogicalOr,
+                    AssignmentOperator::LogicalNullish,
+                ])?;
+                let left = self.ast.assignment_target_simple(
+                    self.ast
+                        .simple_assignment_target_identifier_reference(SPAN, self.identifier()?),
+                );
+                self.ast.expression_assignment(SPAN, operator, left, self.expression()?)
+            }
+            9 => {
+                let callee = self.expression()?;
+                let mut arguments = self.ast.vec();
+                for _ in 0..self.u.choose_index(3)? {
+                    arguments.push(self.ast.argument_expression(self.expression()?));
+                }
+                self.ast.expression_call(
+                    SPAN,
+                    callee,
+                    None::<oxc_allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+                    arguments,
+                    false,
+                )
+            }
+            10 => {
+                let object = self.expression()?;
+                let property = self.ast.identifier_name(SPAN, self.identifier()?);
+                self.ast.expression_member(
+                    self.ast.member_expression_static(SPAN, object, property, false),
+                )
+            }
+            11 => {
+                let mut elements = self.ast.vec();
+                for _ in 0..self.u.choose_index(3)? {
+                    elements.push(self.ast.array_expression_element_expression(self.expression()?));
+                }
+                self.ast.expression_array(SPAN, elements, None)
+            }
+            _ => {
+                let mut expressions = self.ast.vec();
+                for _ in 0..2 {
+                    expressions.push(self.expression()?);
+                }
+                self.ast.expression_sequence(SPAN, expressions)
+            }
+        };
+        self.depth -= 1;
+        Ok(expression)
+    }
+
+    fn identifier(&mut self) -> Result<&'static str> {
+        self.u.choose(&IDENTIFIERS).copied()
+    }
+}
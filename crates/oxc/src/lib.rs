@@ -5,8 +5,14 @@
 #[cfg(feature = "full")]
 mod compiler;
 
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+
+#[cfg(feature = "codegen")]
+pub mod testing;
+
 #[cfg(feature = "full")]
-pub use compiler::{Compiler, CompilerInterface};
+pub use compiler::{Compiler, CompilerInterface, CompilerOptions};
 
 pub mod allocator {
     #[doc(inline)]
@@ -0,0 +1,66 @@
+//! Assertions for tests of code which produces ASTs, e.g. transforms.
+//!
+//! ```ignore
+//! let allocator = Allocator::default();
+//! let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+//! my_transform(&allocator, &mut program);
+//! oxc::testing::assert_program_roundtrip(&program);
+//! ```
+
+use std::hash::{DefaultHasher, Hasher};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_codegen::CodeGenerator;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{cmp::ContentEq, hash::ContentHash, SourceType};
+
+/// Parse `source_text`, then check it with [`assert_program_roundtrip`].
+///
+/// # Panics
+///
+/// * `source_text` does not parse.
+/// * Any of [`assert_program_roundtrip`].
+pub fn assert_roundtrip(source_text: &str, source_type: SourceType) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).with_options(options()).parse();
+    assert!(ret.errors.is_empty(), "Source text does not parse: {source_text}\n{:?}", ret.errors);
+    assert_program_roundtrip(&ret.program);
+}
+
+/// Print `program` with codegen and parse the output again, which must result in the same AST.
+///
+/// ASTs are compared with [`ContentEq`] and [`ContentHash`], ignoring spans and semantic IDs.
+/// The printed code is reparsed without `ParenthesizedExpression`s, `program` should not contain
+/// them either, e.g. by parsing with [`ParseOptions::preserve_parens`] disabled.
+///
+/// # Panics
+///
+/// * The printed code does not parse.
+/// * The AST of the printed code differs from `program`.
+/// * The printed code changes after being parsed and printed again.
+pub fn assert_program_roundtrip(program: &Program) {
+    let source_text = CodeGenerator::new().build(program).source_text;
+    let allocator = Allocator::default();
+    let ret =
+        Parser::new(&allocator, &source_text, program.source_type).with_options(options()).parse();
+    assert!(ret.errors.is_empty(), "Printed code does not parse: {source_text}\n{:?}", ret.errors);
+    assert!(program.content_eq(&ret.program), "AST changed after printing: {source_text}");
+    assert_eq!(hash(program), hash(&ret.program), "AST hash changed after printing: {source_text}");
+    let reprinted = CodeGenerator::new().build(&ret.program).source_text;
+    assert_eq!(source_text, reprinted, "Printed code is not stable");
+}
+
+fn options() -> ParseOptions {
+    ParseOptions {
+        allow_return_outside_function: true,
+        preserve_parens: false,
+        ..ParseOptions::default()
+    }
+}
+
+fn hash(program: &Program) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    program.content_hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,31 @@
+#![allow(clippy::print_stdout)]
+use std::{env, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_api_extractor::ApiExtractor;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+// Instruction:
+// * create a `test.ts`
+// * `just example api_extractor`
+
+fn main() -> Result<(), String> {
+    let name = env::args().nth(1).unwrap_or_else(|| "test.ts".to_string());
+    let path = Path::new(&name);
+    let source_text = std::fs::read_to_string(path).map_err(|_| format!("Missing '{name}'"))?;
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+
+    for error in ret.errors {
+        let error = error.with_source_code(source_text.clone());
+        println!("{error:?}");
+        println!("Parsed with Errors.");
+    }
+
+    let report = ApiExtractor::new(&source_text).build(&ret.program);
+    println!("{}", report.to_json());
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{ApiExport, ApiExportKind, ApiReport};
+
+/// Kind and declared type of a top level declaration.
+type Declared = (ApiExportKind, Option<String>);
+
+/// Summarizes the exported bindings of a module into an [`ApiReport`].
+///
+/// ```ignore
+/// let report = ApiExtractor::new(source_text).build(&program);
+/// println!("{}", report.to_json());
+/// ```
+pub struct ApiExtractor<'a> {
+    source_text: &'a str,
+
+    /// Top level declarations by name. Overloaded functions and merged declarations, e.g. an
+    /// interface and a namespace of the same name, have several entries.
+    declarations: FxHashMap<Atom<'a>, Vec<Declared>>,
+
+    /// Imported bindings by local name, with the module they are imported from.
+    imports: FxHashMap<Atom<'a>, Atom<'a>>,
+
+    /// Names of exported local bindings, to report each of them once.
+    exported_names: FxHashSet<String>,
+
+    exports: Vec<ApiExport>,
+}
+
+impl<'a> ApiExtractor<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self {
+            source_text,
+            declarations: FxHashMap::default(),
+            imports: FxHashMap::default(),
+            exported_names: FxHashSet::default(),
+            exports: vec![],
+        }
+    }
+
+    pub fn build(mut self, program: &Program<'a>) -> ApiReport {
+        // Exports can refer to declarations which follow them
+        for stmt in &program.body {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    for specifier in decl.specifiers.iter().flatten() {
+                        self.imports
+                            .insert(specifier.local().name.clone(), decl.source.value.clone());
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        self.declare(declaration);
+                    }
+                }
+                _ => {
+                    if let Some(declaration) = stmt.as_declaration() {
+                        self.declare(declaration);
+                    }
+                }
+            }
+        }
+
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => self.export_named(decl),
+                Statement::ExportDefaultDeclaration(decl) => self.export_default(decl),
+                Statement::ExportAllDeclaration(decl) => {
+                    let name =
+                        decl.exported.as_ref().map_or_else(|| "*".into(), ModuleExportName::name);
+                    self.exports.push(ApiExport {
+                        name: name.to_string(),
+                        kind: ApiExportKind::ReExport,
+                        r#type: None,
+                        type_only: decl.export_kind.is_type(),
+                        source: Some(decl.source.value.to_string()),
+                    });
+                }
+                Statement::TSExportAssignment(assignment) => {
+                    if let Expression::Identifier(ident) = &assignment.expression {
+                        self.export_local(&ident.name, "export=", false);
+                    } else {
+                        self.export("export=", (ApiExportKind::Value, None), false);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Overloads keep their order
+        self.exports.sort_by(|a, b| a.name.cmp(&b.name));
+        ApiReport { exports: self.exports }
+    }
+
+    fn declare(&mut self, declaration: &Declaration<'a>) {
+        match declaration {
+            Declaration::VariableDeclaration(decl) => {
+                let kind = match decl.kind {
+                    VariableDeclarationKind::Var => ApiExportKind::Var,
+                    VariableDeclarationKind::Let => ApiExportKind::Let,
+                    VariableDeclarationKind::Const => ApiExportKind::Const,
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing => {
+                        ApiExportKind::Using
+                    }
+                };
+                for declarator in &decl.declarations {
+                    // The type of a destructuring pattern is not the type of its bindings
+                    let r#type = match (&declarator.id.kind, &declarator.id.type_annotation) {
+                        (BindingPatternKind::BindingIdentifier(_), Some(annotation)) => {
+                            Some(self.text(annotation.type_annotation.span()).to_string())
+                        }
+                        _ => None,
+                    };
+                    let declarations = &mut self.declarations;
+                    declarator.id.bound_names(&mut |ident| {
+                        declarations
+                            .entry(ident.name.clone())
+                            .or_default()
+                            .push((kind, r#type.clone()));
+                    });
+                }
+            }
+            Declaration::FunctionDeclaration(func) => {
+                let Some(id) = &func.id else { return };
+                let declared = self.declarations.entry(id.name.clone()).or_default();
+                // The implementation of an overloaded function is not part of its signatures
+                if func.body.is_some()
+                    && declared.iter().any(|(kind, _)| *kind == ApiExportKind::Function)
+                {
+                    return;
+                }
+                let signature = self.function_signature(func);
+                self.declare_name(&id.name, ApiExportKind::Function, Some(signature));
+            }
+            Declaration::ClassDeclaration(class) => {
+                let Some(id) = &class.id else { return };
+                let heritage = self.definition(id.span, class.body.span.start);
+                self.declare_name(&id.name, ApiExportKind::Class, heritage);
+            }
+            Declaration::TSTypeAliasDeclaration(decl) => {
+                let definition = self.definition(decl.id.span, decl.span.end);
+                self.declare_name(&decl.id.name, ApiExportKind::TypeAlias, definition);
+            }
+            Declaration::TSInterfaceDeclaration(decl) => {
+                let definition = self.definition(decl.id.span, decl.span.end);
+                self.declare_name(&decl.id.name, ApiExportKind::Interface, definition);
+            }
+            Declaration::TSEnumDeclaration(decl) => {
+                self.declare_name(&decl.id.name, ApiExportKind::Enum, None);
+            }
+            Declaration::TSModuleDeclaration(decl) => {
+                // `declare module "foo" {}` declares a module, not a binding
+                if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+                    self.declare_name(&id.name, ApiExportKind::Namespace, None);
+                }
+            }
+            Declaration::TSImportEqualsDeclaration(_) => {}
+        }
+    }
+
+    fn declare_name(&mut self, name: &Atom<'a>, kind: ApiExportKind, r#type: Option<String>) {
+        self.declarations.entry(name.clone()).or_default().push((kind, r#type));
+    }
+
+    fn export_named(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        let type_only = decl.export_kind.is_type();
+        if let Some(declaration) = &decl.declaration {
+            for name in declared_names(declaration) {
+                self.export_local(&name, &name, type_only);
+            }
+        }
+        for specifier in &decl.specifiers {
+            let name = specifier.exported.name();
+            let type_only = type_only || specifier.export_kind.is_type();
+            if let Some(source) = &decl.source {
+                self.exports.push(ApiExport {
+                    name: name.to_string(),
+                    kind: ApiExportKind::ReExport,
+                    r#type: None,
+                    type_only,
+                    source: Some(source.value.to_string()),
+                });
+            } else {
+                self.export_local(&specifier.local.name(), &name, type_only);
+            }
+        }
+    }
+
+    fn export_default(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        let declared = match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                (ApiExportKind::Function, Some(self.function_signature(func)))
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                let heritage = match &class.id {
+                    Some(id) => self.definition(id.span, class.body.span.start),
+                    None => {
+                        self.definition(Span::empty(class.span.start + 5), class.body.span.start)
+                    }
+                };
+                (ApiExportKind::Class, heritage)
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                (ApiExportKind::Interface, self.definition(decl.id.span, decl.span.end))
+            }
+            ExportDefaultDeclarationKind::Identifier(ident) => {
+                self.export_local(&ident.name, "default", false);
+                return;
+            }
+            _ => (ApiExportKind::Value, None),
+        };
+        self.export("default", declared, false);
+    }
+
+    /// Export the local binding `local` as `name`.
+    fn export_local(&mut self, local: &str, name: &str, type_only: bool) {
+        if !self.exported_names.insert(name.to_string()) {
+            return;
+        }
+        if let Some(declared) = self.declarations.get(local) {
+            for declared in declared.clone() {
+                self.export(name, declared, type_only);
+            }
+        } else if let Some(source) = self.imports.get(local) {
+            let source = source.to_string();
+            self.exports.push(ApiExport {
+                name: name.to_string(),
+                kind: ApiExportKind::ReExport,
+                r#type: None,
+                type_only,
+                source: Some(source),
+            });
+        } else {
+            self.export(name, (ApiExportKind::Unknown, None), type_only);
+        }
+    }
+
+    fn export(&mut self, name: &str, (kind, r#type): Declared, type_only: bool) {
+        self.exports.push(ApiExport {
+            name: name.to_string(),
+            kind,
+            r#type,
+            type_only,
+            source: None,
+        });
+    }
+
+    /// `<T>(a: T): T`
+    fn function_signature(&self, func: &Function<'a>) -> String {
+        let mut signature = String::new();
+        if let Some(type_parameters) = &func.type_parameters {
+            signature.push_str(self.text(type_parameters.span));
+        }
+        signature.push_str(self.text(func.params.span));
+        if let Some(return_type) = &func.return_type {
+            signature.push_str(": ");
+            signature.push_str(self.text(return_type.type_annotation.span()));
+        }
+        signature
+    }
+
+    /// The text between the name of a declaration and `end`, e.g. `<T> = T[]` for
+    /// `type Foo<T> = T[];`, or `None` if it is empty.
+    fn definition(&self, name: Span, end: u32) -> Option<String> {
+        let text = self.text(Span::new(name.end, end)).trim();
+        let text = text.strip_suffix(';').unwrap_or(text).trim_end();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
+    fn text(&self, span: Span) -> &'a str {
+        span.source_text(self.source_text)
+    }
+}
+
+/// Names of the bindings declared by `declaration`.
+fn declared_names<'a>(declaration: &Declaration<'a>) -> Vec<Atom<'a>> {
+    let mut names = vec![];
+    match declaration {
+        Declaration::TSTypeAliasDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSInterfaceDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSEnumDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSModuleDeclaration(decl) => {
+            if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+                names.push(id.name.clone());
+            }
+        }
+        _ => declaration.bound_names(&mut |ident| names.push(ident.name.clone())),
+    }
+    names
+}
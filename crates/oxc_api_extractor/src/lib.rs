@@ -0,0 +1,16 @@
+//! Public API surface of a module.
+//!
+//! [`ApiExtractor`] walks the top level statements of a module and summarizes its exported
+//! bindings, with their kinds and declared types, into an [`ApiReport`].
+//!
+//! The report is serialized to JSON with [`ApiReport::to_json`]. Exports are sorted by name and
+//! types are kept as written, so libraries can commit the report and diff their public API
+//! surface in CI.
+
+mod extractor;
+mod report;
+
+pub use crate::{
+    extractor::ApiExtractor,
+    report::{ApiExport, ApiExportKind, ApiReport},
+};
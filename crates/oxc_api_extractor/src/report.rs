@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+/// Exported bindings of a module, sorted by their exported names.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiReport {
+    pub exports: Vec<ApiExport>,
+}
+
+impl ApiReport {
+    /// Serialize the report as pretty printed JSON.
+    ///
+    /// # Panics
+    ///
+    /// Never, all fields of the report can be serialized.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+/// An exported binding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiExport {
+    /// The exported name, `default` for default exports, `*` for `export * from "mod"` and
+    /// `export=` for `export = value`.
+    pub name: String,
+
+    pub kind: ApiExportKind,
+
+    /// The declared type of the binding, as written in the source:
+    /// * the type annotation of a variable, e.g. `number` for `export let a: number`
+    /// * the signature of a function, e.g. `<T>(a: T): T`
+    /// * the text following the name of a class, interface or type alias, e.g.
+    ///   `<T> extends Base` for `export class Foo<T> extends Base {}`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// `export type { Foo }`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub type_only: bool,
+
+    /// The module specifier of a re-export, e.g. `./foo` for `export { foo } from "./foo"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiExportKind {
+    Var,
+    Let,
+    Const,
+    /// `using` and `await using` declarations
+    Using,
+    Function,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    Namespace,
+    /// An expression, e.g. `export default 1 + 1`
+    Value,
+    /// A binding exported from another module, e.g. `export { foo } from "./foo"` or
+    /// `import { foo } from "./foo"; export { foo }`
+    ReExport,
+    /// A binding without a top level declaration, e.g. a global
+    Unknown,
+}
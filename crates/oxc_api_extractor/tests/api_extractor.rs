@@ -0,0 +1,182 @@
+use oxc_allocator::Allocator;
+use oxc_api_extractor::{ApiExportKind, ApiExtractor, ApiReport};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn extract(source_text: &str) -> ApiReport {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    ApiExtractor::new(source_text).build(&ret.program)
+}
+
+/// `(name, kind, type)` of each export
+fn summary(report: &ApiReport) -> Vec<(&str, ApiExportKind, Option<&str>)> {
+    report
+        .exports
+        .iter()
+        .map(|export| (export.name.as_str(), export.kind, export.r#type.as_deref()))
+        .collect()
+}
+
+#[test]
+fn declarations() {
+    let report = extract(
+        "
+        export const a: number = 1, b = 2;
+        export let { c, d } = obj;
+        export var e: string[];
+        export function f<T>(x: T, y?: number): T { return x }
+        export class G<T> extends Base<T> implements I {}
+        export interface I { x: number }
+        export type T<U> = U | null;
+        export enum E { A }
+        export namespace N {}
+        export abstract class H {}
+        ",
+    );
+    assert_eq!(
+        summary(&report),
+        [
+            ("E", ApiExportKind::Enum, None),
+            ("G", ApiExportKind::Class, Some("<T> extends Base<T> implements I")),
+            ("H", ApiExportKind::Class, None),
+            ("I", ApiExportKind::Interface, Some("{ x: number }")),
+            ("N", ApiExportKind::Namespace, None),
+            ("T", ApiExportKind::TypeAlias, Some("<U> = U | null")),
+            ("a", ApiExportKind::Const, Some("number")),
+            ("b", ApiExportKind::Const, None),
+            ("c", ApiExportKind::Let, None),
+            ("d", ApiExportKind::Let, None),
+            ("e", ApiExportKind::Var, Some("string[]")),
+            ("f", ApiExportKind::Function, Some("<T>(x: T, y?: number): T")),
+        ]
+    );
+}
+
+#[test]
+fn overloads() {
+    let report = extract(
+        "
+        export function f(x: string): string;
+        export function f(x: number): number;
+        export function f(x: any) { return x }
+        function g(): void {}
+        export { g };
+        ",
+    );
+    assert_eq!(
+        summary(&report),
+        [
+            ("f", ApiExportKind::Function, Some("(x: string): string")),
+            ("f", ApiExportKind::Function, Some("(x: number): number")),
+            ("g", ApiExportKind::Function, Some("(): void")),
+        ]
+    );
+}
+
+#[test]
+fn specifiers() {
+    let report = extract(
+        "
+        import { x } from './x';
+        import type { Y } from './y';
+        const a = 1;
+        interface B {}
+        export { a, a as aa, x, b };
+        export type { B, Y };
+        export { z as default } from './z';
+        export * from './all';
+        export * as ns from './ns';
+        ",
+    );
+    assert_eq!(
+        summary(&report),
+        [
+            ("*", ApiExportKind::ReExport, None),
+            ("B", ApiExportKind::Interface, Some("{}")),
+            ("Y", ApiExportKind::ReExport, None),
+            ("a", ApiExportKind::Const, None),
+            ("aa", ApiExportKind::Const, None),
+            ("b", ApiExportKind::Unknown, None),
+            ("default", ApiExportKind::ReExport, None),
+            ("ns", ApiExportKind::ReExport, None),
+            ("x", ApiExportKind::ReExport, None),
+        ]
+    );
+    let sources: Vec<_> = report.exports.iter().map(|export| export.source.as_deref()).collect();
+    assert_eq!(
+        sources,
+        [
+            Some("./all"),
+            None,
+            Some("./y"),
+            None,
+            None,
+            None,
+            Some("./z"),
+            Some("./ns"),
+            Some("./x")
+        ]
+    );
+    let type_only: Vec<_> = report
+        .exports
+        .iter()
+        .filter(|export| export.type_only)
+        .map(|export| &export.name)
+        .collect();
+    assert_eq!(type_only, ["B", "Y"]);
+}
+
+#[test]
+fn default_exports() {
+    assert_eq!(
+        summary(&extract("export default function (a: number): void {}")),
+        [("default", ApiExportKind::Function, Some("(a: number): void"))]
+    );
+    assert_eq!(
+        summary(&extract("export default class extends Base {}")),
+        [("default", ApiExportKind::Class, Some("extends Base"))]
+    );
+    assert_eq!(
+        summary(&extract("const a: string = ''; export default a;")),
+        [("default", ApiExportKind::Const, Some("string"))]
+    );
+    assert_eq!(
+        summary(&extract("export default 1 + 1;")),
+        [("default", ApiExportKind::Value, None)]
+    );
+    assert_eq!(
+        summary(&extract("declare function f(): void; export = f;")),
+        [("export=", ApiExportKind::Function, Some("(): void"))]
+    );
+}
+
+#[test]
+fn json() {
+    let report = extract(
+        "
+        export const a: number = 1;
+        export type { B } from './b';
+        ",
+    );
+    assert_eq!(
+        report.to_json(),
+        r#"{
+  "exports": [
+    {
+      "name": "B",
+      "kind": "reExport",
+      "typeOnly": true,
+      "source": "./b"
+    },
+    {
+      "name": "a",
+      "kind": "const",
+      "type": "number"
+    }
+  ]
+}"#
+    );
+}
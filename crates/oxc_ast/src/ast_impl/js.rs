@@ -604,6 +604,39 @@ impl<'a> CallExpression<'a> {
             _ => None,
         }
     }
+
+    /// The function of an immediately invoked function expression, e.g. `(function () {})()`,
+    /// `(() => {})()` or `(function () {}).call(this)`.
+    pub fn iife_function(&self) -> Option<&Expression<'a>> {
+        let callee = match self.callee.without_parentheses() {
+            Expression::StaticMemberExpression(member)
+                if matches!(member.property.name.as_str(), "call" | "apply") =>
+            {
+                member.object.without_parentheses()
+            }
+            callee => callee,
+        };
+        callee.is_function().then_some(callee)
+    }
+
+    /// The target, key and descriptor of `Object.defineProperty(target, key, descriptor)`.
+    ///
+    /// Only matches the static member `Object.defineProperty`, not e.g.
+    /// `Object['defineProperty']`, and no spread arguments.
+    pub fn object_define_property(
+        &self,
+    ) -> Option<(&Expression<'a>, &Expression<'a>, &Expression<'a>)> {
+        let Expression::StaticMemberExpression(callee) = &self.callee else { return None };
+        if !(callee.object.is_specific_id("Object") && callee.property.name == "defineProperty") {
+            return None;
+        }
+        match self.arguments.as_slice() {
+            [target, key, descriptor] => {
+                Some((target.as_expression()?, key.as_expression()?, descriptor.as_expression()?))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Argument<'_> {
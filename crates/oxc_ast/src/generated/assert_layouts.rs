@@ -131,6 +131,9 @@ const _: () = {
 
     assert!(size_of::<PropertyKind>() == 1usize);
     assert!(align_of::<PropertyKind>() == 1usize);
+    assert!(PropertyKind::Init as u8 == 0u8);
+    assert!(PropertyKind::Get as u8 == 1u8);
+    assert!(PropertyKind::Set as u8 == 2u8);
 
     assert!(size_of::<TemplateLiteral>() == 72usize);
     assert!(align_of::<TemplateLiteral>() == 8usize);
@@ -367,6 +370,11 @@ const _: () = {
 
     assert!(size_of::<VariableDeclarationKind>() == 1usize);
     assert!(align_of::<VariableDeclarationKind>() == 1usize);
+    assert!(VariableDeclarationKind::Var as u8 == 0u8);
+    assert!(VariableDeclarationKind::Const as u8 == 1u8);
+    assert!(VariableDeclarationKind::Let as u8 == 2u8);
+    assert!(VariableDeclarationKind::Using as u8 == 3u8);
+    assert!(VariableDeclarationKind::AwaitUsing as u8 == 4u8);
 
     assert!(size_of::<VariableDeclarator>() == 72usize);
     assert!(align_of::<VariableDeclarator>() == 8usize);
@@ -561,6 +569,10 @@ const _: () = {
 
     assert!(size_of::<FunctionType>() == 1usize);
     assert!(align_of::<FunctionType>() == 1usize);
+    assert!(FunctionType::FunctionDeclaration as u8 == 0u8);
+    assert!(FunctionType::FunctionExpression as u8 == 1u8);
+    assert!(FunctionType::TSDeclareFunction as u8 == 2u8);
+    assert!(FunctionType::TSEmptyBodyFunctionExpression as u8 == 3u8);
 
     assert!(size_of::<FormalParameters>() == 56usize);
     assert!(align_of::<FormalParameters>() == 8usize);
@@ -580,6 +592,10 @@ const _: () = {
 
     assert!(size_of::<FormalParameterKind>() == 1usize);
     assert!(align_of::<FormalParameterKind>() == 1usize);
+    assert!(FormalParameterKind::FormalParameter as u8 == 0u8);
+    assert!(FormalParameterKind::UniqueFormalParameters as u8 == 1u8);
+    assert!(FormalParameterKind::ArrowFormalParameters as u8 == 2u8);
+    assert!(FormalParameterKind::Signature as u8 == 3u8);
 
     assert!(size_of::<FunctionBody>() == 72usize);
     assert!(align_of::<FunctionBody>() == 8usize);
@@ -621,6 +637,8 @@ const _: () = {
 
     assert!(size_of::<ClassType>() == 1usize);
     assert!(align_of::<ClassType>() == 1usize);
+    assert!(ClassType::ClassDeclaration as u8 == 0u8);
+    assert!(ClassType::ClassExpression as u8 == 1u8);
 
     assert!(size_of::<ClassBody>() == 40usize);
     assert!(align_of::<ClassBody>() == 8usize);
@@ -646,6 +664,8 @@ const _: () = {
 
     assert!(size_of::<MethodDefinitionType>() == 1usize);
     assert!(align_of::<MethodDefinitionType>() == 1usize);
+    assert!(MethodDefinitionType::MethodDefinition as u8 == 0u8);
+    assert!(MethodDefinitionType::TSAbstractMethodDefinition as u8 == 1u8);
 
     assert!(size_of::<PropertyDefinition>() == 104usize);
     assert!(align_of::<PropertyDefinition>() == 8usize);
@@ -666,9 +686,15 @@ const _: () = {
 
     assert!(size_of::<PropertyDefinitionType>() == 1usize);
     assert!(align_of::<PropertyDefinitionType>() == 1usize);
+    assert!(PropertyDefinitionType::PropertyDefinition as u8 == 0u8);
+    assert!(PropertyDefinitionType::TSAbstractPropertyDefinition as u8 == 1u8);
 
     assert!(size_of::<MethodDefinitionKind>() == 1usize);
     assert!(align_of::<MethodDefinitionKind>() == 1usize);
+    assert!(MethodDefinitionKind::Constructor as u8 == 0u8);
+    assert!(MethodDefinitionKind::Method as u8 == 1u8);
+    assert!(MethodDefinitionKind::Get as u8 == 2u8);
+    assert!(MethodDefinitionKind::Set as u8 == 3u8);
 
     assert!(size_of::<PrivateIdentifier>() == 24usize);
     assert!(align_of::<PrivateIdentifier>() == 8usize);
@@ -686,6 +712,8 @@ const _: () = {
 
     assert!(size_of::<AccessorPropertyType>() == 1usize);
     assert!(align_of::<AccessorPropertyType>() == 1usize);
+    assert!(AccessorPropertyType::AccessorProperty as u8 == 0u8);
+    assert!(AccessorPropertyType::TSAbstractAccessorProperty as u8 == 1u8);
 
     assert!(size_of::<AccessorProperty>() == 104usize);
     assert!(align_of::<AccessorProperty>() == 8usize);
@@ -857,6 +885,9 @@ const _: () = {
 
     assert!(size_of::<TSTypeOperatorOperator>() == 1usize);
     assert!(align_of::<TSTypeOperatorOperator>() == 1usize);
+    assert!(TSTypeOperatorOperator::Keyof as u8 == 0u8);
+    assert!(TSTypeOperatorOperator::Unique as u8 == 1u8);
+    assert!(TSTypeOperatorOperator::Readonly as u8 == 2u8);
 
     assert!(size_of::<TSArrayType>() == 24usize);
     assert!(align_of::<TSArrayType>() == 8usize);
@@ -996,6 +1027,9 @@ const _: () = {
 
     assert!(size_of::<TSAccessibility>() == 1usize);
     assert!(align_of::<TSAccessibility>() == 1usize);
+    assert!(TSAccessibility::Private as u8 == 0u8);
+    assert!(TSAccessibility::Protected as u8 == 1u8);
+    assert!(TSAccessibility::Public as u8 == 2u8);
 
     assert!(size_of::<TSClassImplements>() == 32usize);
     assert!(align_of::<TSClassImplements>() == 8usize);
@@ -1047,6 +1081,9 @@ const _: () = {
 
     assert!(size_of::<TSMethodSignatureKind>() == 1usize);
     assert!(align_of::<TSMethodSignatureKind>() == 1usize);
+    assert!(TSMethodSignatureKind::Method as u8 == 0u8);
+    assert!(TSMethodSignatureKind::Get as u8 == 1u8);
+    assert!(TSMethodSignatureKind::Set as u8 == 2u8);
 
     assert!(size_of::<TSMethodSignature>() == 72usize);
     assert!(align_of::<TSMethodSignature>() == 8usize);
@@ -1102,6 +1139,9 @@ const _: () = {
 
     assert!(size_of::<TSModuleDeclarationKind>() == 1usize);
     assert!(align_of::<TSModuleDeclarationKind>() == 1usize);
+    assert!(TSModuleDeclarationKind::Global as u8 == 0u8);
+    assert!(TSModuleDeclarationKind::Module as u8 == 1u8);
+    assert!(TSModuleDeclarationKind::Namespace as u8 == 2u8);
 
     assert!(size_of::<TSModuleDeclarationName>() == 32usize);
     assert!(align_of::<TSModuleDeclarationName>() == 8usize);
@@ -1186,6 +1226,10 @@ const _: () = {
 
     assert!(size_of::<TSMappedTypeModifierOperator>() == 1usize);
     assert!(align_of::<TSMappedTypeModifierOperator>() == 1usize);
+    assert!(TSMappedTypeModifierOperator::True as u8 == 0u8);
+    assert!(TSMappedTypeModifierOperator::Plus as u8 == 1u8);
+    assert!(TSMappedTypeModifierOperator::Minus as u8 == 2u8);
+    assert!(TSMappedTypeModifierOperator::None as u8 == 3u8);
 
     assert!(size_of::<TSTemplateLiteralType>() == 72usize);
     assert!(align_of::<TSTemplateLiteralType>() == 8usize);
@@ -1254,6 +1298,8 @@ const _: () = {
 
     assert!(size_of::<ImportOrExportKind>() == 1usize);
     assert!(align_of::<ImportOrExportKind>() == 1usize);
+    assert!(ImportOrExportKind::Value as u8 == 0u8);
+    assert!(ImportOrExportKind::Type as u8 == 1u8);
 
     assert!(size_of::<JSDocNullableType>() == 32usize);
     assert!(align_of::<JSDocNullableType>() == 8usize);
@@ -1376,24 +1422,83 @@ const _: () = {
 
     assert!(size_of::<NumberBase>() == 1usize);
     assert!(align_of::<NumberBase>() == 1usize);
+    assert!(NumberBase::Float as u8 == 0u8);
+    assert!(NumberBase::Decimal as u8 == 1u8);
+    assert!(NumberBase::Binary as u8 == 2u8);
+    assert!(NumberBase::Octal as u8 == 3u8);
+    assert!(NumberBase::Hex as u8 == 4u8);
 
     assert!(size_of::<BigintBase>() == 1usize);
     assert!(align_of::<BigintBase>() == 1usize);
+    assert!(BigintBase::Decimal as u8 == 0u8);
+    assert!(BigintBase::Binary as u8 == 1u8);
+    assert!(BigintBase::Octal as u8 == 2u8);
+    assert!(BigintBase::Hex as u8 == 3u8);
 
     assert!(size_of::<AssignmentOperator>() == 1usize);
     assert!(align_of::<AssignmentOperator>() == 1usize);
+    assert!(AssignmentOperator::Assign as u8 == 0u8);
+    assert!(AssignmentOperator::Addition as u8 == 1u8);
+    assert!(AssignmentOperator::Subtraction as u8 == 2u8);
+    assert!(AssignmentOperator::Multiplication as u8 == 3u8);
+    assert!(AssignmentOperator::Division as u8 == 4u8);
+    assert!(AssignmentOperator::Remainder as u8 == 5u8);
+    assert!(AssignmentOperator::ShiftLeft as u8 == 6u8);
+    assert!(AssignmentOperator::ShiftRight as u8 == 7u8);
+    assert!(AssignmentOperator::ShiftRightZeroFill as u8 == 8u8);
+    assert!(AssignmentOperator::BitwiseOR as u8 == 9u8);
+    assert!(AssignmentOperator::BitwiseXOR as u8 == 10u8);
+    assert!(AssignmentOperator::BitwiseAnd as u8 == 11u8);
+    assert!(AssignmentOperator::LogicalAnd as u8 == 12u8);
+    assert!(AssignmentOperator::LogicalOr as u8 == 13u8);
+    assert!(AssignmentOperator::LogicalNullish as u8 == 14u8);
+    assert!(AssignmentOperator::Exponential as u8 == 15u8);
 
     assert!(size_of::<BinaryOperator>() == 1usize);
     assert!(align_of::<BinaryOperator>() == 1usize);
+    assert!(BinaryOperator::Equality as u8 == 0u8);
+    assert!(BinaryOperator::Inequality as u8 == 1u8);
+    assert!(BinaryOperator::StrictEquality as u8 == 2u8);
+    assert!(BinaryOperator::StrictInequality as u8 == 3u8);
+    assert!(BinaryOperator::LessThan as u8 == 4u8);
+    assert!(BinaryOperator::LessEqualThan as u8 == 5u8);
+    assert!(BinaryOperator::GreaterThan as u8 == 6u8);
+    assert!(BinaryOperator::GreaterEqualThan as u8 == 7u8);
+    assert!(BinaryOperator::ShiftLeft as u8 == 8u8);
+    assert!(BinaryOperator::ShiftRight as u8 == 9u8);
+    assert!(BinaryOperator::ShiftRightZeroFill as u8 == 10u8);
+    assert!(BinaryOperator::Addition as u8 == 11u8);
+    assert!(BinaryOperator::Subtraction as u8 == 12u8);
+    assert!(BinaryOperator::Multiplication as u8 == 13u8);
+    assert!(BinaryOperator::Division as u8 == 14u8);
+    assert!(BinaryOperator::Remainder as u8 == 15u8);
+    assert!(BinaryOperator::BitwiseOR as u8 == 16u8);
+    assert!(BinaryOperator::BitwiseXOR as u8 == 17u8);
+    assert!(BinaryOperator::BitwiseAnd as u8 == 18u8);
+    assert!(BinaryOperator::In as u8 == 19u8);
+    assert!(BinaryOperator::Instanceof as u8 == 20u8);
+    assert!(BinaryOperator::Exponential as u8 == 21u8);
 
     assert!(size_of::<LogicalOperator>() == 1usize);
     assert!(align_of::<LogicalOperator>() == 1usize);
+    assert!(LogicalOperator::Or as u8 == 0u8);
+    assert!(LogicalOperator::And as u8 == 1u8);
+    assert!(LogicalOperator::Coalesce as u8 == 2u8);
 
     assert!(size_of::<UnaryOperator>() == 1usize);
     assert!(align_of::<UnaryOperator>() == 1usize);
+    assert!(UnaryOperator::UnaryNegation as u8 == 0u8);
+    assert!(UnaryOperator::UnaryPlus as u8 == 1u8);
+    assert!(UnaryOperator::LogicalNot as u8 == 2u8);
+    assert!(UnaryOperator::BitwiseNot as u8 == 3u8);
+    assert!(UnaryOperator::Typeof as u8 == 4u8);
+    assert!(UnaryOperator::Void as u8 == 5u8);
+    assert!(UnaryOperator::Delete as u8 == 6u8);
 
     assert!(size_of::<UpdateOperator>() == 1usize);
     assert!(align_of::<UpdateOperator>() == 1usize);
+    assert!(UpdateOperator::Increment as u8 == 0u8);
+    assert!(UpdateOperator::Decrement as u8 == 1u8);
 
     assert!(size_of::<Span>() == 8usize);
     assert!(align_of::<Span>() == 4usize);
@@ -1405,12 +1510,20 @@ const _: () = {
 
     assert!(size_of::<Language>() == 1usize);
     assert!(align_of::<Language>() == 1usize);
+    assert!(Language::JavaScript as u8 == 0u8);
+    assert!(Language::TypeScript as u8 == 1u8);
+    assert!(Language::TypeScriptDefinition as u8 == 2u8);
 
     assert!(size_of::<ModuleKind>() == 1usize);
     assert!(align_of::<ModuleKind>() == 1usize);
+    assert!(ModuleKind::Script as u8 == 0u8);
+    assert!(ModuleKind::Module as u8 == 1u8);
+    assert!(ModuleKind::Unambiguous as u8 == 2u8);
 
     assert!(size_of::<LanguageVariant>() == 1usize);
     assert!(align_of::<LanguageVariant>() == 1usize);
+    assert!(LanguageVariant::Standard as u8 == 0u8);
+    assert!(LanguageVariant::Jsx as u8 == 1u8);
 
     assert!(size_of::<RegularExpression>() == 72usize);
     assert!(align_of::<RegularExpression>() == 8usize);
@@ -1455,6 +1568,10 @@ const _: () = {
 
     assert!(size_of::<BoundaryAssertionKind>() == 1usize);
     assert!(align_of::<BoundaryAssertionKind>() == 1usize);
+    assert!(BoundaryAssertionKind::Start as u8 == 0u8);
+    assert!(BoundaryAssertionKind::End as u8 == 1u8);
+    assert!(BoundaryAssertionKind::Boundary as u8 == 2u8);
+    assert!(BoundaryAssertionKind::NegativeBoundary as u8 == 3u8);
 
     assert!(size_of::<LookAroundAssertion>() == 56usize);
     assert!(align_of::<LookAroundAssertion>() == 8usize);
@@ -1464,6 +1581,10 @@ const _: () = {
 
     assert!(size_of::<LookAroundAssertionKind>() == 1usize);
     assert!(align_of::<LookAroundAssertionKind>() == 1usize);
+    assert!(LookAroundAssertionKind::Lookahead as u8 == 0u8);
+    assert!(LookAroundAssertionKind::NegativeLookahead as u8 == 1u8);
+    assert!(LookAroundAssertionKind::Lookbehind as u8 == 2u8);
+    assert!(LookAroundAssertionKind::NegativeLookbehind as u8 == 3u8);
 
     assert!(size_of::<Quantifier>() == 64usize);
     assert!(align_of::<Quantifier>() == 8usize);
@@ -1481,6 +1602,14 @@ const _: () = {
 
     assert!(size_of::<CharacterKind>() == 1usize);
     assert!(align_of::<CharacterKind>() == 1usize);
+    assert!(CharacterKind::ControlLetter as u8 == 0u8);
+    assert!(CharacterKind::HexadecimalEscape as u8 == 1u8);
+    assert!(CharacterKind::Identifier as u8 == 2u8);
+    assert!(CharacterKind::Null as u8 == 3u8);
+    assert!(CharacterKind::Octal as u8 == 4u8);
+    assert!(CharacterKind::SingleEscape as u8 == 5u8);
+    assert!(CharacterKind::Symbol as u8 == 6u8);
+    assert!(CharacterKind::UnicodeEscape as u8 == 7u8);
 
     assert!(size_of::<CharacterClassEscape>() == 12usize);
     assert!(align_of::<CharacterClassEscape>() == 4usize);
@@ -1489,6 +1618,12 @@ const _: () = {
 
     assert!(size_of::<CharacterClassEscapeKind>() == 1usize);
     assert!(align_of::<CharacterClassEscapeKind>() == 1usize);
+    assert!(CharacterClassEscapeKind::D as u8 == 0u8);
+    assert!(CharacterClassEscapeKind::NegativeD as u8 == 1u8);
+    assert!(CharacterClassEscapeKind::S as u8 == 2u8);
+    assert!(CharacterClassEscapeKind::NegativeS as u8 == 3u8);
+    assert!(CharacterClassEscapeKind::W as u8 == 4u8);
+    assert!(CharacterClassEscapeKind::NegativeW as u8 == 5u8);
 
     assert!(size_of::<UnicodePropertyEscape>() == 48usize);
     assert!(align_of::<UnicodePropertyEscape>() == 8usize);
@@ -1511,6 +1646,9 @@ const _: () = {
 
     assert!(size_of::<CharacterClassContentsKind>() == 1usize);
     assert!(align_of::<CharacterClassContentsKind>() == 1usize);
+    assert!(CharacterClassContentsKind::Union as u8 == 0u8);
+    assert!(CharacterClassContentsKind::Intersection as u8 == 1u8);
+    assert!(CharacterClassContentsKind::Subtraction as u8 == 2u8);
 
     assert!(size_of::<CharacterClassContents>() == 24usize);
     assert!(align_of::<CharacterClassContents>() == 8usize);
@@ -1685,6 +1823,9 @@ const _: () = {
 
     assert!(size_of::<PropertyKind>() == 1usize);
     assert!(align_of::<PropertyKind>() == 1usize);
+    assert!(PropertyKind::Init as u8 == 0u8);
+    assert!(PropertyKind::Get as u8 == 1u8);
+    assert!(PropertyKind::Set as u8 == 2u8);
 
     assert!(size_of::<TemplateLiteral>() == 40usize);
     assert!(align_of::<TemplateLiteral>() == 4usize);
@@ -1921,6 +2062,11 @@ const _: () = {
 
     assert!(size_of::<VariableDeclarationKind>() == 1usize);
     assert!(align_of::<VariableDeclarationKind>() == 1usize);
+    assert!(VariableDeclarationKind::Var as u8 == 0u8);
+    assert!(VariableDeclarationKind::Const as u8 == 1u8);
+    assert!(VariableDeclarationKind::Let as u8 == 2u8);
+    assert!(VariableDeclarationKind::Using as u8 == 3u8);
+    assert!(VariableDeclarationKind::AwaitUsing as u8 == 4u8);
 
     assert!(size_of::<VariableDeclarator>() == 40usize);
     assert!(align_of::<VariableDeclarator>() == 4usize);
@@ -2115,6 +2261,10 @@ const _: () = {
 
     assert!(size_of::<FunctionType>() == 1usize);
     assert!(align_of::<FunctionType>() == 1usize);
+    assert!(FunctionType::FunctionDeclaration as u8 == 0u8);
+    assert!(FunctionType::FunctionExpression as u8 == 1u8);
+    assert!(FunctionType::TSDeclareFunction as u8 == 2u8);
+    assert!(FunctionType::TSEmptyBodyFunctionExpression as u8 == 3u8);
 
     assert!(size_of::<FormalParameters>() == 32usize);
     assert!(align_of::<FormalParameters>() == 4usize);
@@ -2134,6 +2284,10 @@ const _: () = {
 
     assert!(size_of::<FormalParameterKind>() == 1usize);
     assert!(align_of::<FormalParameterKind>() == 1usize);
+    assert!(FormalParameterKind::FormalParameter as u8 == 0u8);
+    assert!(FormalParameterKind::UniqueFormalParameters as u8 == 1u8);
+    assert!(FormalParameterKind::ArrowFormalParameters as u8 == 2u8);
+    assert!(FormalParameterKind::Signature as u8 == 3u8);
 
     assert!(size_of::<FunctionBody>() == 40usize);
     assert!(align_of::<FunctionBody>() == 4usize);
@@ -2175,6 +2329,8 @@ const _: () = {
 
     assert!(size_of::<ClassType>() == 1usize);
     assert!(align_of::<ClassType>() == 1usize);
+    assert!(ClassType::ClassDeclaration as u8 == 0u8);
+    assert!(ClassType::ClassExpression as u8 == 1u8);
 
     assert!(size_of::<ClassBody>() == 24usize);
     assert!(align_of::<ClassBody>() == 4usize);
@@ -2200,6 +2356,8 @@ const _: () = {
 
     assert!(size_of::<MethodDefinitionType>() == 1usize);
     assert!(align_of::<MethodDefinitionType>() == 1usize);
+    assert!(MethodDefinitionType::MethodDefinition as u8 == 0u8);
+    assert!(MethodDefinitionType::TSAbstractMethodDefinition as u8 == 1u8);
 
     assert!(size_of::<PropertyDefinition>() == 60usize);
     assert!(align_of::<PropertyDefinition>() == 4usize);
@@ -2220,9 +2378,15 @@ const _: () = {
 
     assert!(size_of::<PropertyDefinitionType>() == 1usize);
     assert!(align_of::<PropertyDefinitionType>() == 1usize);
+    assert!(PropertyDefinitionType::PropertyDefinition as u8 == 0u8);
+    assert!(PropertyDefinitionType::TSAbstractPropertyDefinition as u8 == 1u8);
 
     assert!(size_of::<MethodDefinitionKind>() == 1usize);
     assert!(align_of::<MethodDefinitionKind>() == 1usize);
+    assert!(MethodDefinitionKind::Constructor as u8 == 0u8);
+    assert!(MethodDefinitionKind::Method as u8 == 1u8);
+    assert!(MethodDefinitionKind::Get as u8 == 2u8);
+    assert!(MethodDefinitionKind::Set as u8 == 3u8);
 
     assert!(size_of::<PrivateIdentifier>() == 16usize);
     assert!(align_of::<PrivateIdentifier>() == 4usize);
@@ -2240,6 +2404,8 @@ const _: () = {
 
     assert!(size_of::<AccessorPropertyType>() == 1usize);
     assert!(align_of::<AccessorPropertyType>() == 1usize);
+    assert!(AccessorPropertyType::AccessorProperty as u8 == 0u8);
+    assert!(AccessorPropertyType::TSAbstractAccessorProperty as u8 == 1u8);
 
     assert!(size_of::<AccessorProperty>() == 56usize);
     assert!(align_of::<AccessorProperty>() == 4usize);
@@ -2411,6 +2577,9 @@ const _: () = {
 
     assert!(size_of::<TSTypeOperatorOperator>() == 1usize);
     assert!(align_of::<TSTypeOperatorOperator>() == 1usize);
+    assert!(TSTypeOperatorOperator::Keyof as u8 == 0u8);
+    assert!(TSTypeOperatorOperator::Unique as u8 == 1u8);
+    assert!(TSTypeOperatorOperator::Readonly as u8 == 2u8);
 
     assert!(size_of::<TSArrayType>() == 16usize);
     assert!(align_of::<TSArrayType>() == 4usize);
@@ -2550,6 +2719,9 @@ const _: () = {
 
     assert!(size_of::<TSAccessibility>() == 1usize);
     assert!(align_of::<TSAccessibility>() == 1usize);
+    assert!(TSAccessibility::Private as u8 == 0u8);
+    assert!(TSAccessibility::Protected as u8 == 1u8);
+    assert!(TSAccessibility::Public as u8 == 2u8);
 
     assert!(size_of::<TSClassImplements>() == 20usize);
     assert!(align_of::<TSClassImplements>() == 4usize);
@@ -2601,6 +2773,9 @@ const _: () = {
 
     assert!(size_of::<TSMethodSignatureKind>() == 1usize);
     assert!(align_of::<TSMethodSignatureKind>() == 1usize);
+    assert!(TSMethodSignatureKind::Method as u8 == 0u8);
+    assert!(TSMethodSignatureKind::Get as u8 == 1u8);
+    assert!(TSMethodSignatureKind::Set as u8 == 2u8);
 
     assert!(size_of::<TSMethodSignature>() == 40usize);
     assert!(align_of::<TSMethodSignature>() == 4usize);
@@ -2656,6 +2831,9 @@ const _: () = {
 
     assert!(size_of::<TSModuleDeclarationKind>() == 1usize);
     assert!(align_of::<TSModuleDeclarationKind>() == 1usize);
+    assert!(TSModuleDeclarationKind::Global as u8 == 0u8);
+    assert!(TSModuleDeclarationKind::Module as u8 == 1u8);
+    assert!(TSModuleDeclarationKind::Namespace as u8 == 2u8);
 
     assert!(size_of::<TSModuleDeclarationName>() == 20usize);
     assert!(align_of::<TSModuleDeclarationName>() == 4usize);
@@ -2740,6 +2918,10 @@ const _: () = {
 
     assert!(size_of::<TSMappedTypeModifierOperator>() == 1usize);
     assert!(align_of::<TSMappedTypeModifierOperator>() == 1usize);
+    assert!(TSMappedTypeModifierOperator::True as u8 == 0u8);
+    assert!(TSMappedTypeModifierOperator::Plus as u8 == 1u8);
+    assert!(TSMappedTypeModifierOperator::Minus as u8 == 2u8);
+    assert!(TSMappedTypeModifierOperator::None as u8 == 3u8);
 
     assert!(size_of::<TSTemplateLiteralType>() == 40usize);
     assert!(align_of::<TSTemplateLiteralType>() == 4usize);
@@ -2808,6 +2990,8 @@ const _: () = {
 
     assert!(size_of::<ImportOrExportKind>() == 1usize);
     assert!(align_of::<ImportOrExportKind>() == 1usize);
+    assert!(ImportOrExportKind::Value as u8 == 0u8);
+    assert!(ImportOrExportKind::Type as u8 == 1u8);
 
     assert!(size_of::<JSDocNullableType>() == 20usize);
     assert!(align_of::<JSDocNullableType>() == 4usize);
@@ -2930,24 +3114,83 @@ const _: () = {
 
     assert!(size_of::<NumberBase>() == 1usize);
     assert!(align_of::<NumberBase>() == 1usize);
+    assert!(NumberBase::Float as u8 == 0u8);
+    assert!(NumberBase::Decimal as u8 == 1u8);
+    assert!(NumberBase::Binary as u8 == 2u8);
+    assert!(NumberBase::Octal as u8 == 3u8);
+    assert!(NumberBase::Hex as u8 == 4u8);
 
     assert!(size_of::<BigintBase>() == 1usize);
     assert!(align_of::<BigintBase>() == 1usize);
+    assert!(BigintBase::Decimal as u8 == 0u8);
+    assert!(BigintBase::Binary as u8 == 1u8);
+    assert!(BigintBase::Octal as u8 == 2u8);
+    assert!(BigintBase::Hex as u8 == 3u8);
 
     assert!(size_of::<AssignmentOperator>() == 1usize);
     assert!(align_of::<AssignmentOperator>() == 1usize);
+    assert!(AssignmentOperator::Assign as u8 == 0u8);
+    assert!(AssignmentOperator::Addition as u8 == 1u8);
+    assert!(AssignmentOperator::Subtraction as u8 == 2u8);
+    assert!(AssignmentOperator::Multiplication as u8 == 3u8);
+    assert!(AssignmentOperator::Division as u8 == 4u8);
+    assert!(AssignmentOperator::Remainder as u8 == 5u8);
+    assert!(AssignmentOperator::ShiftLeft as u8 == 6u8);
+    assert!(AssignmentOperator::ShiftRight as u8 == 7u8);
+    assert!(AssignmentOperator::ShiftRightZeroFill as u8 == 8u8);
+    assert!(AssignmentOperator::BitwiseOR as u8 == 9u8);
+    assert!(AssignmentOperator::BitwiseXOR as u8 == 10u8);
+    assert!(AssignmentOperator::BitwiseAnd as u8 == 11u8);
+    assert!(AssignmentOperator::LogicalAnd as u8 == 12u8);
+    assert!(AssignmentOperator::LogicalOr as u8 == 13u8);
+    assert!(AssignmentOperator::LogicalNullish as u8 == 14u8);
+    assert!(AssignmentOperator::Exponential as u8 == 15u8);
 
     assert!(size_of::<BinaryOperator>() == 1usize);
     assert!(align_of::<BinaryOperator>() == 1usize);
+    assert!(BinaryOperator::Equality as u8 == 0u8);
+    assert!(BinaryOperator::Inequality as u8 == 1u8);
+    assert!(BinaryOperator::StrictEquality as u8 == 2u8);
+    assert!(BinaryOperator::StrictInequality as u8 == 3u8);
+    assert!(BinaryOperator::LessThan as u8 == 4u8);
+    assert!(BinaryOperator::LessEqualThan as u8 == 5u8);
+    assert!(BinaryOperator::GreaterThan as u8 == 6u8);
+    assert!(BinaryOperator::GreaterEqualThan as u8 == 7u8);
+    assert!(BinaryOperator::ShiftLeft as u8 == 8u8);
+    assert!(BinaryOperator::ShiftRight as u8 == 9u8);
+    assert!(BinaryOperator::ShiftRightZeroFill as u8 == 10u8);
+    assert!(BinaryOperator::Addition as u8 == 11u8);
+    assert!(BinaryOperator::Subtraction as u8 == 12u8);
+    assert!(BinaryOperator::Multiplication as u8 == 13u8);
+    assert!(BinaryOperator::Division as u8 == 14u8);
+    assert!(BinaryOperator::Remainder as u8 == 15u8);
+    assert!(BinaryOperator::BitwiseOR as u8 == 16u8);
+    assert!(BinaryOperator::BitwiseXOR as u8 == 17u8);
+    assert!(BinaryOperator::BitwiseAnd as u8 == 18u8);
+    assert!(BinaryOperator::In as u8 == 19u8);
+    assert!(BinaryOperator::Instanceof as u8 == 20u8);
+    assert!(BinaryOperator::Exponential as u8 == 21u8);
 
     assert!(size_of::<LogicalOperator>() == 1usize);
     assert!(align_of::<LogicalOperator>() == 1usize);
+    assert!(LogicalOperator::Or as u8 == 0u8);
+    assert!(LogicalOperator::And as u8 == 1u8);
+    assert!(LogicalOperator::Coalesce as u8 == 2u8);
 
     assert!(size_of::<UnaryOperator>() == 1usize);
     assert!(align_of::<UnaryOperator>() == 1usize);
+    assert!(UnaryOperator::UnaryNegation as u8 == 0u8);
+    assert!(UnaryOperator::UnaryPlus as u8 == 1u8);
+    assert!(UnaryOperator::LogicalNot as u8 == 2u8);
+    assert!(UnaryOperator::BitwiseNot as u8 == 3u8);
+    assert!(UnaryOperator::Typeof as u8 == 4u8);
+    assert!(UnaryOperator::Void as u8 == 5u8);
+    assert!(UnaryOperator::Delete as u8 == 6u8);
 
     assert!(size_of::<UpdateOperator>() == 1usize);
     assert!(align_of::<UpdateOperator>() == 1usize);
+    assert!(UpdateOperator::Increment as u8 == 0u8);
+    assert!(UpdateOperator::Decrement as u8 == 1u8);
 
     assert!(size_of::<Span>() == 8usize);
     assert!(align_of::<Span>() == 4usize);
@@ -2959,12 +3202,20 @@ const _: () = {
 
     assert!(size_of::<Language>() == 1usize);
     assert!(align_of::<Language>() == 1usize);
+    assert!(Language::JavaScript as u8 == 0u8);
+    assert!(Language::TypeScript as u8 == 1u8);
+    assert!(Language::TypeScriptDefinition as u8 == 2u8);
 
     assert!(size_of::<ModuleKind>() == 1usize);
     assert!(align_of::<ModuleKind>() == 1usize);
+    assert!(ModuleKind::Script as u8 == 0u8);
+    assert!(ModuleKind::Module as u8 == 1u8);
+    assert!(ModuleKind::Unambiguous as u8 == 2u8);
 
     assert!(size_of::<LanguageVariant>() == 1usize);
     assert!(align_of::<LanguageVariant>() == 1usize);
+    assert!(LanguageVariant::Standard as u8 == 0u8);
+    assert!(LanguageVariant::Jsx as u8 == 1u8);
 
     assert!(size_of::<RegularExpression>() == 56usize);
     assert!(align_of::<RegularExpression>() == 4usize);
@@ -3009,6 +3260,10 @@ const _: () = {
 
     assert!(size_of::<BoundaryAssertionKind>() == 1usize);
     assert!(align_of::<BoundaryAssertionKind>() == 1usize);
+    assert!(BoundaryAssertionKind::Start as u8 == 0u8);
+    assert!(BoundaryAssertionKind::End as u8 == 1u8);
+    assert!(BoundaryAssertionKind::Boundary as u8 == 2u8);
+    assert!(BoundaryAssertionKind::NegativeBoundary as u8 == 3u8);
 
     assert!(size_of::<LookAroundAssertion>() == 36usize);
     assert!(align_of::<LookAroundAssertion>() == 4usize);
@@ -3018,6 +3273,10 @@ const _: () = {
 
     assert!(size_of::<LookAroundAssertionKind>() == 1usize);
     assert!(align_of::<LookAroundAssertionKind>() == 1usize);
+    assert!(LookAroundAssertionKind::Lookahead as u8 == 0u8);
+    assert!(LookAroundAssertionKind::NegativeLookahead as u8 == 1u8);
+    assert!(LookAroundAssertionKind::Lookbehind as u8 == 2u8);
+    assert!(LookAroundAssertionKind::NegativeLookbehind as u8 == 3u8);
 
     assert!(size_of::<Quantifier>() == 56usize);
     assert!(align_of::<Quantifier>() == 8usize);
@@ -3035,6 +3294,14 @@ const _: () = {
 
     assert!(size_of::<CharacterKind>() == 1usize);
     assert!(align_of::<CharacterKind>() == 1usize);
+    assert!(CharacterKind::ControlLetter as u8 == 0u8);
+    assert!(CharacterKind::HexadecimalEscape as u8 == 1u8);
+    assert!(CharacterKind::Identifier as u8 == 2u8);
+    assert!(CharacterKind::Null as u8 == 3u8);
+    assert!(CharacterKind::Octal as u8 == 4u8);
+    assert!(CharacterKind::SingleEscape as u8 == 5u8);
+    assert!(CharacterKind::Symbol as u8 == 6u8);
+    assert!(CharacterKind::UnicodeEscape as u8 == 7u8);
 
     assert!(size_of::<CharacterClassEscape>() == 12usize);
     assert!(align_of::<CharacterClassEscape>() == 4usize);
@@ -3043,6 +3310,12 @@ const _: () = {
 
     assert!(size_of::<CharacterClassEscapeKind>() == 1usize);
     assert!(align_of::<CharacterClassEscapeKind>() == 1usize);
+    assert!(CharacterClassEscapeKind::D as u8 == 0u8);
+    assert!(CharacterClassEscapeKind::NegativeD as u8 == 1u8);
+    assert!(CharacterClassEscapeKind::S as u8 == 2u8);
+    assert!(CharacterClassEscapeKind::NegativeS as u8 == 3u8);
+    assert!(CharacterClassEscapeKind::W as u8 == 4u8);
+    assert!(CharacterClassEscapeKind::NegativeW as u8 == 5u8);
 
     assert!(size_of::<UnicodePropertyEscape>() == 28usize);
     assert!(align_of::<UnicodePropertyEscape>() == 4usize);
@@ -3065,6 +3338,9 @@ const _: () = {
 
     assert!(size_of::<CharacterClassContentsKind>() == 1usize);
     assert!(align_of::<CharacterClassContentsKind>() == 1usize);
+    assert!(CharacterClassContentsKind::Union as u8 == 0u8);
+    assert!(CharacterClassContentsKind::Intersection as u8 == 1u8);
+    assert!(CharacterClassContentsKind::Subtraction as u8 == 2u8);
 
     assert!(size_of::<CharacterClassContents>() == 20usize);
     assert!(align_of::<CharacterClassContents>() == 4usize);
@@ -0,0 +1,3159 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/estree.rs`
+
+#![allow(clippy::match_same_arms)]
+
+#[allow(unused_imports)]
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::js::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::jsx::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::literal::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::ts::*;
+
+impl Serialize for BooleanLiteral {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BooleanLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl Serialize for NullLiteral {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "NullLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for NumericLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "NumericLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("raw", &self.raw)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BigIntLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BigIntLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("raw", &self.raw)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for RegExpLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "RegExpLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("regex", &self.regex)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for RegExp<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("pattern", &self.pattern)?;
+        map.serialize_entry("flags", &self.flags)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for RegExpPattern<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Raw(it) => serializer.serialize_newtype_variant("RegExpPattern", 0, "Raw", it),
+            Self::Invalid(it) => {
+                serializer.serialize_newtype_variant("RegExpPattern", 1, "Invalid", it)
+            }
+            Self::Pattern(it) => {
+                serializer.serialize_newtype_variant("RegExpPattern", 2, "Pattern", it)
+            }
+        }
+    }
+}
+
+impl Serialize for EmptyObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("EmptyObject")
+    }
+}
+
+impl<'a> Serialize for StringLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "StringLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Program<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Program")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("sourceType", &self.source_type)?;
+        map.serialize_entry("hashbang", &self.hashbang)?;
+        map.serialize_entry("directives", &self.directives)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Expression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for IdentifierName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Identifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for IdentifierReference<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Identifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BindingIdentifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Identifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for LabelIdentifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Identifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl Serialize for ThisExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ThisExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ArrayExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ArrayExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("elements", &self.elements)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ArrayExpressionElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::SpreadElement(it) => it.serialize(serializer),
+            Self::Elision(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ObjectExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ObjectExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("properties", &self.properties)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ObjectPropertyKind<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ObjectProperty(it) => it.serialize(serializer),
+            Self::SpreadProperty(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ObjectProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ObjectProperty")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("init", &self.init)?;
+        map.serialize_entry("method", &self.method)?;
+        map.serialize_entry("shorthand", &self.shorthand)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for PropertyKey<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::StaticIdentifier(it) => it.serialize(serializer),
+            Self::PrivateIdentifier(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for PropertyKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Init => serializer.serialize_unit_variant("PropertyKind", 0, "init"),
+            Self::Get => serializer.serialize_unit_variant("PropertyKind", 1, "get"),
+            Self::Set => serializer.serialize_unit_variant("PropertyKind", 2, "set"),
+        }
+    }
+}
+
+impl<'a> Serialize for TemplateLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TemplateLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("quasis", &self.quasis)?;
+        map.serialize_entry("expressions", &self.expressions)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TaggedTemplateExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TaggedTemplateExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("tag", &self.tag)?;
+        map.serialize_entry("quasi", &self.quasi)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TemplateElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TemplateElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("tail", &self.tail)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TemplateElementValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("raw", &self.raw)?;
+        map.serialize_entry("cooked", &self.cooked)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for MemberExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ComputedMemberExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ComputedMemberExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for StaticMemberExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "StaticMemberExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("property", &self.property)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for PrivateFieldExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "PrivateFieldExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("field", &self.field)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for CallExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "CallExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("callee", &self.callee)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("arguments", &self.arguments)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for NewExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "NewExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("callee", &self.callee)?;
+        map.serialize_entry("arguments", &self.arguments)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for MetaProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "MetaProperty")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("meta", &self.meta)?;
+        map.serialize_entry("property", &self.property)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SpreadElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "SpreadElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Argument<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::SpreadElement(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for UpdateExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "UpdateExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("prefix", &self.prefix)?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for UnaryExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "UnaryExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BinaryExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BinaryExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for PrivateInExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "PrivateInExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for LogicalExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "LogicalExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ConditionalExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ConditionalExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("consequent", &self.consequent)?;
+        map.serialize_entry("alternate", &self.alternate)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AssignmentExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AssignmentExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AssignmentTarget<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+            Self::ArrayAssignmentTarget(it) => it.serialize(serializer),
+            Self::ObjectAssignmentTarget(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for SimpleAssignmentTarget<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetPattern<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ArrayAssignmentTarget(it) => it.serialize(serializer),
+            Self::ObjectAssignmentTarget(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetRest<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "RestElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.target)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetMaybeDefault<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AssignmentTargetWithDefault(it) => it.serialize(serializer),
+            Self::AssignmentTargetIdentifier(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+            Self::ArrayAssignmentTarget(it) => it.serialize(serializer),
+            Self::ObjectAssignmentTarget(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetWithDefault<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AssignmentTargetWithDefault")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("binding", &self.binding)?;
+        map.serialize_entry("init", &self.init)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(it) => it.serialize(serializer),
+            Self::AssignmentTargetPropertyProperty(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetPropertyIdentifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AssignmentTargetPropertyIdentifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("binding", &self.binding)?;
+        map.serialize_entry("init", &self.init)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AssignmentTargetPropertyProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AssignmentTargetPropertyProperty")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("binding", &self.binding)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SequenceExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "SequenceExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expressions", &self.expressions)?;
+        map.end()
+    }
+}
+
+impl Serialize for Super {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Super")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for AwaitExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AwaitExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ChainExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ChainExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ChainElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ParenthesizedExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ParenthesizedExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Statement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BlockStatement(it) => it.serialize(serializer),
+            Self::BreakStatement(it) => it.serialize(serializer),
+            Self::ContinueStatement(it) => it.serialize(serializer),
+            Self::DebuggerStatement(it) => it.serialize(serializer),
+            Self::DoWhileStatement(it) => it.serialize(serializer),
+            Self::EmptyStatement(it) => it.serialize(serializer),
+            Self::ExpressionStatement(it) => it.serialize(serializer),
+            Self::ForInStatement(it) => it.serialize(serializer),
+            Self::ForOfStatement(it) => it.serialize(serializer),
+            Self::ForStatement(it) => it.serialize(serializer),
+            Self::IfStatement(it) => it.serialize(serializer),
+            Self::LabeledStatement(it) => it.serialize(serializer),
+            Self::ReturnStatement(it) => it.serialize(serializer),
+            Self::SwitchStatement(it) => it.serialize(serializer),
+            Self::ThrowStatement(it) => it.serialize(serializer),
+            Self::TryStatement(it) => it.serialize(serializer),
+            Self::WhileStatement(it) => it.serialize(serializer),
+            Self::WithStatement(it) => it.serialize(serializer),
+            Self::VariableDeclaration(it) => it.serialize(serializer),
+            Self::FunctionDeclaration(it) => it.serialize(serializer),
+            Self::ClassDeclaration(it) => it.serialize(serializer),
+            Self::TSTypeAliasDeclaration(it) => it.serialize(serializer),
+            Self::TSInterfaceDeclaration(it) => it.serialize(serializer),
+            Self::TSEnumDeclaration(it) => it.serialize(serializer),
+            Self::TSModuleDeclaration(it) => it.serialize(serializer),
+            Self::TSImportEqualsDeclaration(it) => it.serialize(serializer),
+            Self::ImportDeclaration(it) => it.serialize(serializer),
+            Self::ExportAllDeclaration(it) => it.serialize(serializer),
+            Self::ExportDefaultDeclaration(it) => it.serialize(serializer),
+            Self::ExportNamedDeclaration(it) => it.serialize(serializer),
+            Self::TSExportAssignment(it) => it.serialize(serializer),
+            Self::TSNamespaceExportDeclaration(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for Directive<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Directive")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("directive", &self.directive)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Hashbang<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Hashbang")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BlockStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BlockStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Declaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::VariableDeclaration(it) => it.serialize(serializer),
+            Self::FunctionDeclaration(it) => it.serialize(serializer),
+            Self::ClassDeclaration(it) => it.serialize(serializer),
+            Self::TSTypeAliasDeclaration(it) => it.serialize(serializer),
+            Self::TSInterfaceDeclaration(it) => it.serialize(serializer),
+            Self::TSEnumDeclaration(it) => it.serialize(serializer),
+            Self::TSModuleDeclaration(it) => it.serialize(serializer),
+            Self::TSImportEqualsDeclaration(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for VariableDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "VariableDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("declarations", &self.declarations)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl Serialize for VariableDeclarationKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Var => serializer.serialize_unit_variant("VariableDeclarationKind", 0, "var"),
+            Self::Const => serializer.serialize_unit_variant("VariableDeclarationKind", 1, "const"),
+            Self::Let => serializer.serialize_unit_variant("VariableDeclarationKind", 2, "let"),
+            Self::Using => serializer.serialize_unit_variant("VariableDeclarationKind", 3, "using"),
+            Self::AwaitUsing => {
+                serializer.serialize_unit_variant("VariableDeclarationKind", 4, "await using")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for VariableDeclarator<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "VariableDeclarator")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("init", &self.init)?;
+        map.serialize_entry("definite", &self.definite)?;
+        map.end()
+    }
+}
+
+impl Serialize for EmptyStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "EmptyStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ExpressionStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ExpressionStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for IfStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "IfStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("consequent", &self.consequent)?;
+        map.serialize_entry("alternate", &self.alternate)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for DoWhileStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "DoWhileStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("test", &self.test)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for WhileStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "WhileStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ForStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ForStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("init", &self.init)?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("update", &self.update)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ForStatementInit<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::VariableDeclaration(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ForInStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ForInStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ForStatementLeft<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::VariableDeclaration(it) => it.serialize(serializer),
+            Self::AssignmentTargetIdentifier(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+            Self::ArrayAssignmentTarget(it) => it.serialize(serializer),
+            Self::ObjectAssignmentTarget(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ForOfStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ForOfStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("await", &self.r#await)?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ContinueStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ContinueStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("label", &self.label)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BreakStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BreakStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("label", &self.label)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ReturnStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ReturnStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for WithStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "WithStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SwitchStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "SwitchStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("discriminant", &self.discriminant)?;
+        map.serialize_entry("cases", &self.cases)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SwitchCase<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "SwitchCase")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("test", &self.test)?;
+        map.serialize_entry("consequent", &self.consequent)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for LabeledStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "LabeledStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("label", &self.label)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ThrowStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ThrowStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TryStatement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TryStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("block", &self.block)?;
+        map.serialize_entry("handler", &self.handler)?;
+        map.serialize_entry("finalizer", &self.finalizer)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for CatchClause<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "CatchClause")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("param", &self.param)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for CatchParameter<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "CatchParameter")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("pattern", &self.pattern)?;
+        map.end()
+    }
+}
+
+impl Serialize for DebuggerStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "DebuggerStatement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BindingPattern<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        self.kind.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BindingPatternKind<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BindingIdentifier(it) => it.serialize(serializer),
+            Self::ObjectPattern(it) => it.serialize(serializer),
+            Self::ArrayPattern(it) => it.serialize(serializer),
+            Self::AssignmentPattern(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for AssignmentPattern<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "AssignmentPattern")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BindingProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "BindingProperty")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("shorthand", &self.shorthand)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for BindingRestElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "RestElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Function<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("generator", &self.generator)?;
+        map.serialize_entry("async", &self.r#async)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("thisParam", &self.this_param)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for FunctionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::FunctionDeclaration => {
+                serializer.serialize_unit_variant("FunctionType", 0, "FunctionDeclaration")
+            }
+            Self::FunctionExpression => {
+                serializer.serialize_unit_variant("FunctionType", 1, "FunctionExpression")
+            }
+            Self::TSDeclareFunction => {
+                serializer.serialize_unit_variant("FunctionType", 2, "TSDeclareFunction")
+            }
+            Self::TSEmptyBodyFunctionExpression => serializer.serialize_unit_variant(
+                "FunctionType",
+                3,
+                "TSEmptyBodyFunctionExpression",
+            ),
+        }
+    }
+}
+
+impl<'a> Serialize for FormalParameter<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "FormalParameter")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("decorators", &self.decorators)?;
+        map.serialize_entry("pattern", &self.pattern)?;
+        map.serialize_entry("accessibility", &self.accessibility)?;
+        map.serialize_entry("readonly", &self.readonly)?;
+        map.serialize_entry("override", &self.r#override)?;
+        map.end()
+    }
+}
+
+impl Serialize for FormalParameterKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::FormalParameter => {
+                serializer.serialize_unit_variant("FormalParameterKind", 0, "FormalParameter")
+            }
+            Self::UniqueFormalParameters => serializer.serialize_unit_variant(
+                "FormalParameterKind",
+                1,
+                "UniqueFormalParameters",
+            ),
+            Self::ArrowFormalParameters => {
+                serializer.serialize_unit_variant("FormalParameterKind", 2, "ArrowFormalParameters")
+            }
+            Self::Signature => {
+                serializer.serialize_unit_variant("FormalParameterKind", 3, "Signature")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for FunctionBody<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "FunctionBody")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("directives", &self.directives)?;
+        map.serialize_entry("statements", &self.statements)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ArrowFunctionExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ArrowFunctionExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("async", &self.r#async)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for YieldExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "YieldExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("delegate", &self.delegate)?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Class<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("decorators", &self.decorators)?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("superClass", &self.super_class)?;
+        map.serialize_entry("superTypeParameters", &self.super_type_parameters)?;
+        map.serialize_entry("implements", &self.implements)?;
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("abstract", &self.r#abstract)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl Serialize for ClassType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ClassDeclaration => {
+                serializer.serialize_unit_variant("ClassType", 0, "ClassDeclaration")
+            }
+            Self::ClassExpression => {
+                serializer.serialize_unit_variant("ClassType", 1, "ClassExpression")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for ClassBody<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ClassBody")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ClassElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::StaticBlock(it) => it.serialize(serializer),
+            Self::MethodDefinition(it) => it.serialize(serializer),
+            Self::PropertyDefinition(it) => it.serialize(serializer),
+            Self::AccessorProperty(it) => it.serialize(serializer),
+            Self::TSIndexSignature(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for MethodDefinition<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("decorators", &self.decorators)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("static", &self.r#static)?;
+        map.serialize_entry("override", &self.r#override)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.serialize_entry("accessibility", &self.accessibility)?;
+        map.end()
+    }
+}
+
+impl Serialize for MethodDefinitionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::MethodDefinition => {
+                serializer.serialize_unit_variant("MethodDefinitionType", 0, "MethodDefinition")
+            }
+            Self::TSAbstractMethodDefinition => serializer.serialize_unit_variant(
+                "MethodDefinitionType",
+                1,
+                "TSAbstractMethodDefinition",
+            ),
+        }
+    }
+}
+
+impl<'a> Serialize for PropertyDefinition<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("decorators", &self.decorators)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("static", &self.r#static)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.serialize_entry("override", &self.r#override)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.serialize_entry("definite", &self.definite)?;
+        map.serialize_entry("readonly", &self.readonly)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("accessibility", &self.accessibility)?;
+        map.end()
+    }
+}
+
+impl Serialize for PropertyDefinitionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::PropertyDefinition => {
+                serializer.serialize_unit_variant("PropertyDefinitionType", 0, "PropertyDefinition")
+            }
+            Self::TSAbstractPropertyDefinition => serializer.serialize_unit_variant(
+                "PropertyDefinitionType",
+                1,
+                "TSAbstractPropertyDefinition",
+            ),
+        }
+    }
+}
+
+impl Serialize for MethodDefinitionKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Constructor => {
+                serializer.serialize_unit_variant("MethodDefinitionKind", 0, "constructor")
+            }
+            Self::Method => serializer.serialize_unit_variant("MethodDefinitionKind", 1, "method"),
+            Self::Get => serializer.serialize_unit_variant("MethodDefinitionKind", 2, "get"),
+            Self::Set => serializer.serialize_unit_variant("MethodDefinitionKind", 3, "set"),
+        }
+    }
+}
+
+impl<'a> Serialize for PrivateIdentifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "PrivateIdentifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for StaticBlock<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "StaticBlock")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ModuleDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ImportDeclaration(it) => it.serialize(serializer),
+            Self::ExportAllDeclaration(it) => it.serialize(serializer),
+            Self::ExportDefaultDeclaration(it) => it.serialize(serializer),
+            Self::ExportNamedDeclaration(it) => it.serialize(serializer),
+            Self::TSExportAssignment(it) => it.serialize(serializer),
+            Self::TSNamespaceExportDeclaration(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for AccessorPropertyType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AccessorProperty => {
+                serializer.serialize_unit_variant("AccessorPropertyType", 0, "AccessorProperty")
+            }
+            Self::TSAbstractAccessorProperty => serializer.serialize_unit_variant(
+                "AccessorPropertyType",
+                1,
+                "TSAbstractAccessorProperty",
+            ),
+        }
+    }
+}
+
+impl<'a> Serialize for AccessorProperty<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("decorators", &self.decorators)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("static", &self.r#static)?;
+        map.serialize_entry("definite", &self.definite)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("accessibility", &self.accessibility)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("source", &self.source)?;
+        map.serialize_entry("arguments", &self.arguments)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("specifiers", &self.specifiers)?;
+        map.serialize_entry("source", &self.source)?;
+        map.serialize_entry("withClause", &self.with_clause)?;
+        map.serialize_entry("importKind", &self.import_kind)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportDeclarationSpecifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ImportSpecifier(it) => it.serialize(serializer),
+            Self::ImportDefaultSpecifier(it) => it.serialize(serializer),
+            Self::ImportNamespaceSpecifier(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ImportSpecifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportSpecifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("imported", &self.imported)?;
+        map.serialize_entry("local", &self.local)?;
+        map.serialize_entry("importKind", &self.import_kind)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportDefaultSpecifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportDefaultSpecifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("local", &self.local)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportNamespaceSpecifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportNamespaceSpecifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("local", &self.local)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for WithClause<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "WithClause")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("attributesKeyword", &self.attributes_keyword)?;
+        map.serialize_entry("withEntries", &self.with_entries)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportAttribute<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ImportAttribute")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ImportAttributeKey<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ExportNamedDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ExportNamedDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("declaration", &self.declaration)?;
+        map.serialize_entry("specifiers", &self.specifiers)?;
+        map.serialize_entry("source", &self.source)?;
+        map.serialize_entry("exportKind", &self.export_kind)?;
+        map.serialize_entry("withClause", &self.with_clause)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ExportDefaultDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ExportDefaultDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("declaration", &self.declaration)?;
+        map.serialize_entry("exported", &self.exported)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ExportAllDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ExportAllDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("exported", &self.exported)?;
+        map.serialize_entry("source", &self.source)?;
+        map.serialize_entry("withClause", &self.with_clause)?;
+        map.serialize_entry("exportKind", &self.export_kind)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ExportSpecifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "ExportSpecifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("local", &self.local)?;
+        map.serialize_entry("exported", &self.exported)?;
+        map.serialize_entry("exportKind", &self.export_kind)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ExportDefaultDeclarationKind<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::FunctionDeclaration(it) => it.serialize(serializer),
+            Self::ClassDeclaration(it) => it.serialize(serializer),
+            Self::TSInterfaceDeclaration(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for ModuleExportName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::IdentifierName(it) => it.serialize(serializer),
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSThisParameter<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSThisParameter")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("thisSpan", &self.this_span)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSEnumDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSEnumDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("members", &self.members)?;
+        map.serialize_entry("const", &self.r#const)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSEnumMember<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSEnumMember")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("initializer", &self.initializer)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSEnumMemberName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::StaticIdentifier(it) => it.serialize(serializer),
+            Self::StaticStringLiteral(it) => it.serialize(serializer),
+            Self::StaticTemplateLiteral(it) => it.serialize(serializer),
+            Self::StaticNumericLiteral(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSTypeAnnotation<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeAnnotation")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSLiteralType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSLiteralType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("literal", &self.literal)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TSAnyKeyword(it) => it.serialize(serializer),
+            Self::TSBigIntKeyword(it) => it.serialize(serializer),
+            Self::TSBooleanKeyword(it) => it.serialize(serializer),
+            Self::TSIntrinsicKeyword(it) => it.serialize(serializer),
+            Self::TSNeverKeyword(it) => it.serialize(serializer),
+            Self::TSNullKeyword(it) => it.serialize(serializer),
+            Self::TSNumberKeyword(it) => it.serialize(serializer),
+            Self::TSObjectKeyword(it) => it.serialize(serializer),
+            Self::TSStringKeyword(it) => it.serialize(serializer),
+            Self::TSSymbolKeyword(it) => it.serialize(serializer),
+            Self::TSUndefinedKeyword(it) => it.serialize(serializer),
+            Self::TSUnknownKeyword(it) => it.serialize(serializer),
+            Self::TSVoidKeyword(it) => it.serialize(serializer),
+            Self::TSArrayType(it) => it.serialize(serializer),
+            Self::TSConditionalType(it) => it.serialize(serializer),
+            Self::TSConstructorType(it) => it.serialize(serializer),
+            Self::TSFunctionType(it) => it.serialize(serializer),
+            Self::TSImportType(it) => it.serialize(serializer),
+            Self::TSIndexedAccessType(it) => it.serialize(serializer),
+            Self::TSInferType(it) => it.serialize(serializer),
+            Self::TSIntersectionType(it) => it.serialize(serializer),
+            Self::TSLiteralType(it) => it.serialize(serializer),
+            Self::TSMappedType(it) => it.serialize(serializer),
+            Self::TSNamedTupleMember(it) => it.serialize(serializer),
+            Self::TSQualifiedName(it) => it.serialize(serializer),
+            Self::TSTemplateLiteralType(it) => it.serialize(serializer),
+            Self::TSThisType(it) => it.serialize(serializer),
+            Self::TSTupleType(it) => it.serialize(serializer),
+            Self::TSTypeLiteral(it) => it.serialize(serializer),
+            Self::TSTypeOperatorType(it) => it.serialize(serializer),
+            Self::TSTypePredicate(it) => it.serialize(serializer),
+            Self::TSTypeQuery(it) => it.serialize(serializer),
+            Self::TSTypeReference(it) => it.serialize(serializer),
+            Self::TSUnionType(it) => it.serialize(serializer),
+            Self::TSParenthesizedType(it) => it.serialize(serializer),
+            Self::JSDocNullableType(it) => it.serialize(serializer),
+            Self::JSDocNonNullableType(it) => it.serialize(serializer),
+            Self::JSDocUnknownType(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSConditionalType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSConditionalType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("checkType", &self.check_type)?;
+        map.serialize_entry("extendsType", &self.extends_type)?;
+        map.serialize_entry("trueType", &self.true_type)?;
+        map.serialize_entry("falseType", &self.false_type)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSUnionType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSUnionType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("types", &self.types)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSIntersectionType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSIntersectionType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("types", &self.types)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSParenthesizedType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSParenthesizedType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeOperator<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeOperator")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("operator", &self.operator)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl Serialize for TSTypeOperatorOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Keyof => serializer.serialize_unit_variant("TSTypeOperatorOperator", 0, "keyof"),
+            Self::Unique => {
+                serializer.serialize_unit_variant("TSTypeOperatorOperator", 1, "unique")
+            }
+            Self::Readonly => {
+                serializer.serialize_unit_variant("TSTypeOperatorOperator", 2, "readonly")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for TSArrayType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSArrayType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("elementType", &self.element_type)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSIndexedAccessType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSIndexedAccessType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("objectType", &self.object_type)?;
+        map.serialize_entry("indexType", &self.index_type)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTupleType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTupleType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("elementTypes", &self.element_types)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSNamedTupleMember<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNamedTupleMember")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("elementType", &self.element_type)?;
+        map.serialize_entry("label", &self.label)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSOptionalType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSOptionalType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSRestType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSRestType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTupleElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TSOptionalType(it) => it.serialize(serializer),
+            Self::TSRestType(it) => it.serialize(serializer),
+            Self::TSAnyKeyword(it) => it.serialize(serializer),
+            Self::TSBigIntKeyword(it) => it.serialize(serializer),
+            Self::TSBooleanKeyword(it) => it.serialize(serializer),
+            Self::TSIntrinsicKeyword(it) => it.serialize(serializer),
+            Self::TSNeverKeyword(it) => it.serialize(serializer),
+            Self::TSNullKeyword(it) => it.serialize(serializer),
+            Self::TSNumberKeyword(it) => it.serialize(serializer),
+            Self::TSObjectKeyword(it) => it.serialize(serializer),
+            Self::TSStringKeyword(it) => it.serialize(serializer),
+            Self::TSSymbolKeyword(it) => it.serialize(serializer),
+            Self::TSUndefinedKeyword(it) => it.serialize(serializer),
+            Self::TSUnknownKeyword(it) => it.serialize(serializer),
+            Self::TSVoidKeyword(it) => it.serialize(serializer),
+            Self::TSArrayType(it) => it.serialize(serializer),
+            Self::TSConditionalType(it) => it.serialize(serializer),
+            Self::TSConstructorType(it) => it.serialize(serializer),
+            Self::TSFunctionType(it) => it.serialize(serializer),
+            Self::TSImportType(it) => it.serialize(serializer),
+            Self::TSIndexedAccessType(it) => it.serialize(serializer),
+            Self::TSInferType(it) => it.serialize(serializer),
+            Self::TSIntersectionType(it) => it.serialize(serializer),
+            Self::TSLiteralType(it) => it.serialize(serializer),
+            Self::TSMappedType(it) => it.serialize(serializer),
+            Self::TSNamedTupleMember(it) => it.serialize(serializer),
+            Self::TSQualifiedName(it) => it.serialize(serializer),
+            Self::TSTemplateLiteralType(it) => it.serialize(serializer),
+            Self::TSThisType(it) => it.serialize(serializer),
+            Self::TSTupleType(it) => it.serialize(serializer),
+            Self::TSTypeLiteral(it) => it.serialize(serializer),
+            Self::TSTypeOperatorType(it) => it.serialize(serializer),
+            Self::TSTypePredicate(it) => it.serialize(serializer),
+            Self::TSTypeQuery(it) => it.serialize(serializer),
+            Self::TSTypeReference(it) => it.serialize(serializer),
+            Self::TSUnionType(it) => it.serialize(serializer),
+            Self::TSParenthesizedType(it) => it.serialize(serializer),
+            Self::JSDocNullableType(it) => it.serialize(serializer),
+            Self::JSDocNonNullableType(it) => it.serialize(serializer),
+            Self::JSDocUnknownType(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for TSAnyKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSAnyKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSStringKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSStringKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSBooleanKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSBooleanKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSNumberKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNumberKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSNeverKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNeverKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSIntrinsicKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSIntrinsicKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSUnknownKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSUnknownKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSNullKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNullKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSUndefinedKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSUndefinedKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSVoidKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSVoidKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSSymbolKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSSymbolKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSThisType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSThisType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSObjectKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSObjectKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for TSBigIntKeyword {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSBigIntKeyword")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeReference<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeReference")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeName", &self.type_name)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::QualifiedName(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSQualifiedName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSQualifiedName")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("left", &self.left)?;
+        map.serialize_entry("right", &self.right)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeParameterInstantiation<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeParameterInstantiation")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("params", &self.params)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeParameter<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeParameter")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("constraint", &self.constraint)?;
+        map.serialize_entry("default", &self.default)?;
+        map.serialize_entry("in", &self.r#in)?;
+        map.serialize_entry("out", &self.out)?;
+        map.serialize_entry("const", &self.r#const)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeParameterDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeParameterDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("params", &self.params)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeAliasDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeAliasDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl Serialize for TSAccessibility {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Private => serializer.serialize_unit_variant("TSAccessibility", 0, "private"),
+            Self::Protected => serializer.serialize_unit_variant("TSAccessibility", 1, "protected"),
+            Self::Public => serializer.serialize_unit_variant("TSAccessibility", 2, "public"),
+        }
+    }
+}
+
+impl<'a> Serialize for TSClassImplements<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSClassImplements")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSInterfaceDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSInterfaceDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("extends", &self.extends)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSInterfaceBody<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSInterfaceBody")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSPropertySignature<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSPropertySignature")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.serialize_entry("readonly", &self.readonly)?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSSignature<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TSIndexSignature(it) => it.serialize(serializer),
+            Self::TSPropertySignature(it) => it.serialize(serializer),
+            Self::TSCallSignatureDeclaration(it) => it.serialize(serializer),
+            Self::TSConstructSignatureDeclaration(it) => it.serialize(serializer),
+            Self::TSMethodSignature(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSIndexSignature<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSIndexSignature")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("parameters", &self.parameters)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("readonly", &self.readonly)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSCallSignatureDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSCallSignatureDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("thisParam", &self.this_param)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl Serialize for TSMethodSignatureKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Method => serializer.serialize_unit_variant("TSMethodSignatureKind", 0, "method"),
+            Self::Get => serializer.serialize_unit_variant("TSMethodSignatureKind", 1, "get"),
+            Self::Set => serializer.serialize_unit_variant("TSMethodSignatureKind", 2, "set"),
+        }
+    }
+}
+
+impl<'a> Serialize for TSMethodSignature<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSMethodSignature")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("key", &self.key)?;
+        map.serialize_entry("computed", &self.computed)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("thisParam", &self.this_param)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSConstructSignatureDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSConstructSignatureDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSIndexSignatureName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Identifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSInterfaceHeritage<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSInterfaceHeritage")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypePredicate<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypePredicate")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("parameterName", &self.parameter_name)?;
+        map.serialize_entry("asserts", &self.asserts)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypePredicateName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::This(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSModuleDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSModuleDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("body", &self.body)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("declare", &self.declare)?;
+        map.end()
+    }
+}
+
+impl Serialize for TSModuleDeclarationKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Global => {
+                serializer.serialize_unit_variant("TSModuleDeclarationKind", 0, "global")
+            }
+            Self::Module => {
+                serializer.serialize_unit_variant("TSModuleDeclarationKind", 1, "module")
+            }
+            Self::Namespace => {
+                serializer.serialize_unit_variant("TSModuleDeclarationKind", 2, "namespace")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for TSModuleDeclarationName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSModuleDeclarationBody<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TSModuleDeclaration(it) => it.serialize(serializer),
+            Self::TSModuleBlock(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSTypeLiteral<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeLiteral")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("members", &self.members)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSInferType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSInferType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeParameter", &self.type_parameter)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeQuery<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeQuery")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("exprName", &self.expr_name)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeQueryExprName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TSImportType(it) => it.serialize(serializer),
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::QualifiedName(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSImportType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSImportType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("isTypeOf", &self.is_type_of)?;
+        map.serialize_entry("parameter", &self.parameter)?;
+        map.serialize_entry("qualifier", &self.qualifier)?;
+        map.serialize_entry("attributes", &self.attributes)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSImportAttributes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSImportAttributes")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("attributesKeyword", &self.attributes_keyword)?;
+        map.serialize_entry("elements", &self.elements)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSImportAttribute<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSImportAttribute")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSImportAttributeName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSFunctionType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSFunctionType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("thisParam", &self.this_param)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSConstructorType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSConstructorType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("abstract", &self.r#abstract)?;
+        map.serialize_entry("params", &self.params)?;
+        map.serialize_entry("returnType", &self.return_type)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSMappedType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSMappedType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeParameter", &self.type_parameter)?;
+        map.serialize_entry("nameType", &self.name_type)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("optional", &self.optional)?;
+        map.serialize_entry("readonly", &self.readonly)?;
+        map.end()
+    }
+}
+
+impl Serialize for TSMappedTypeModifierOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::True => {
+                serializer.serialize_unit_variant("TSMappedTypeModifierOperator", 0, "true")
+            }
+            Self::Plus => serializer.serialize_unit_variant("TSMappedTypeModifierOperator", 1, "+"),
+            Self::Minus => {
+                serializer.serialize_unit_variant("TSMappedTypeModifierOperator", 2, "-")
+            }
+            Self::None => {
+                serializer.serialize_unit_variant("TSMappedTypeModifierOperator", 3, "none")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for TSTemplateLiteralType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTemplateLiteralType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("quasis", &self.quasis)?;
+        map.serialize_entry("types", &self.types)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSAsExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSAsExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSSatisfiesExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSSatisfiesExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSTypeAssertion<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSTypeAssertion")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSImportEqualsDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSImportEqualsDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("moduleReference", &self.module_reference)?;
+        map.serialize_entry("importKind", &self.import_kind)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSModuleReference<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ExternalModuleReference(it) => it.serialize(serializer),
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::QualifiedName(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for TSExternalModuleReference<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSExternalModuleReference")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSNonNullExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNonNullExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Decorator<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "Decorator")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSExportAssignment<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSExportAssignment")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSNamespaceExportDeclaration<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSNamespaceExportDeclaration")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("id", &self.id)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for TSInstantiationExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "TSInstantiationExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl Serialize for ImportOrExportKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value => serializer.serialize_unit_variant("ImportOrExportKind", 0, "value"),
+            Self::Type => serializer.serialize_unit_variant("ImportOrExportKind", 1, "type"),
+        }
+    }
+}
+
+impl<'a> Serialize for JSDocNullableType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSDocNullableType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("postfix", &self.postfix)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSDocNonNullableType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSDocNonNullableType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("typeAnnotation", &self.type_annotation)?;
+        map.serialize_entry("postfix", &self.postfix)?;
+        map.end()
+    }
+}
+
+impl Serialize for JSDocUnknownType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSDocUnknownType")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("openingElement", &self.opening_element)?;
+        map.serialize_entry("closingElement", &self.closing_element)?;
+        map.serialize_entry("children", &self.children)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXOpeningElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXOpeningElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("selfClosing", &self.self_closing)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("attributes", &self.attributes)?;
+        map.serialize_entry("typeParameters", &self.type_parameters)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXClosingElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXClosingElement")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXFragment<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXFragment")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("openingFragment", &self.opening_fragment)?;
+        map.serialize_entry("closingFragment", &self.closing_fragment)?;
+        map.serialize_entry("children", &self.children)?;
+        map.end()
+    }
+}
+
+impl Serialize for JSXOpeningFragment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXOpeningFragment")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl Serialize for JSXClosingFragment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXClosingFragment")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXElementName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::NamespacedName(it) => it.serialize(serializer),
+            Self::MemberExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXNamespacedName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXNamespacedName")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("namespace", &self.namespace)?;
+        map.serialize_entry("property", &self.property)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXMemberExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXMemberExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("object", &self.object)?;
+        map.serialize_entry("property", &self.property)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXMemberExpressionObject<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::IdentifierReference(it) => it.serialize(serializer),
+            Self::MemberExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXExpressionContainer<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXExpressionContainer")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::EmptyExpression(it) => it.serialize(serializer),
+            Self::BooleanLiteral(it) => it.serialize(serializer),
+            Self::NullLiteral(it) => it.serialize(serializer),
+            Self::NumericLiteral(it) => it.serialize(serializer),
+            Self::BigIntLiteral(it) => it.serialize(serializer),
+            Self::RegExpLiteral(it) => it.serialize(serializer),
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::TemplateLiteral(it) => it.serialize(serializer),
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::MetaProperty(it) => it.serialize(serializer),
+            Self::Super(it) => it.serialize(serializer),
+            Self::ArrayExpression(it) => it.serialize(serializer),
+            Self::ArrowFunctionExpression(it) => it.serialize(serializer),
+            Self::AssignmentExpression(it) => it.serialize(serializer),
+            Self::AwaitExpression(it) => it.serialize(serializer),
+            Self::BinaryExpression(it) => it.serialize(serializer),
+            Self::CallExpression(it) => it.serialize(serializer),
+            Self::ChainExpression(it) => it.serialize(serializer),
+            Self::ClassExpression(it) => it.serialize(serializer),
+            Self::ConditionalExpression(it) => it.serialize(serializer),
+            Self::FunctionExpression(it) => it.serialize(serializer),
+            Self::ImportExpression(it) => it.serialize(serializer),
+            Self::LogicalExpression(it) => it.serialize(serializer),
+            Self::NewExpression(it) => it.serialize(serializer),
+            Self::ObjectExpression(it) => it.serialize(serializer),
+            Self::ParenthesizedExpression(it) => it.serialize(serializer),
+            Self::SequenceExpression(it) => it.serialize(serializer),
+            Self::TaggedTemplateExpression(it) => it.serialize(serializer),
+            Self::ThisExpression(it) => it.serialize(serializer),
+            Self::UnaryExpression(it) => it.serialize(serializer),
+            Self::UpdateExpression(it) => it.serialize(serializer),
+            Self::YieldExpression(it) => it.serialize(serializer),
+            Self::PrivateInExpression(it) => it.serialize(serializer),
+            Self::JSXElement(it) => it.serialize(serializer),
+            Self::JSXFragment(it) => it.serialize(serializer),
+            Self::TSAsExpression(it) => it.serialize(serializer),
+            Self::TSSatisfiesExpression(it) => it.serialize(serializer),
+            Self::TSTypeAssertion(it) => it.serialize(serializer),
+            Self::TSNonNullExpression(it) => it.serialize(serializer),
+            Self::TSInstantiationExpression(it) => it.serialize(serializer),
+            Self::ComputedMemberExpression(it) => it.serialize(serializer),
+            Self::StaticMemberExpression(it) => it.serialize(serializer),
+            Self::PrivateFieldExpression(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for JSXEmptyExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXEmptyExpression")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXAttributeItem<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Attribute(it) => it.serialize(serializer),
+            Self::SpreadAttribute(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXAttribute<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXAttribute")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXSpreadAttribute<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXSpreadAttribute")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("argument", &self.argument)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXAttributeName<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Identifier(it) => it.serialize(serializer),
+            Self::NamespacedName(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXAttributeValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::StringLiteral(it) => it.serialize(serializer),
+            Self::ExpressionContainer(it) => it.serialize(serializer),
+            Self::Element(it) => it.serialize(serializer),
+            Self::Fragment(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXIdentifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXIdentifier")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXChild<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Text(it) => it.serialize(serializer),
+            Self::Element(it) => it.serialize(serializer),
+            Self::Fragment(it) => it.serialize(serializer),
+            Self::ExpressionContainer(it) => it.serialize(serializer),
+            Self::Spread(it) => it.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for JSXSpreadChild<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXSpreadChild")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("expression", &self.expression)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for JSXText<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "JSXText")?;
+        self.span.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
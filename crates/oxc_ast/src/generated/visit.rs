@@ -3822,7 +3822,7 @@ pub mod walk {
         let kind = AstKind::TSEnumDeclaration(visitor.alloc(it));
         visitor.enter_node(kind);
         visitor.visit_binding_identifier(&it.id);
-        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        visitor.enter_scope(ScopeFlags::TsEnum, &it.scope_id);
         visitor.visit_ts_enum_members(&it.members);
         visitor.leave_scope();
         visitor.leave_node(kind);
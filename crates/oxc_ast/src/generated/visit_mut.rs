@@ -4037,7 +4037,7 @@ pub mod walk_mut {
         let kind = AstType::TSEnumDeclaration;
         visitor.enter_node(kind);
         visitor.visit_binding_identifier(&mut it.id);
-        visitor.enter_scope(ScopeFlags::empty(), &it.scope_id);
+        visitor.enter_scope(ScopeFlags::TsEnum, &it.scope_id);
         visitor.visit_ts_enum_members(&mut it.members);
         visitor.leave_scope();
         visitor.leave_node(kind);
@@ -32,6 +32,7 @@ pub mod ast;
 mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
+mod pragma;
 pub mod precedence;
 pub mod syntax_directed_operations;
 mod trivia;
@@ -44,6 +45,8 @@ mod generated {
     pub mod derive_clone_in;
     pub mod derive_content_eq;
     pub mod derive_content_hash;
+    #[cfg(feature = "serialize")]
+    pub mod derive_estree;
     pub mod derive_get_span;
     pub mod derive_get_span_mut;
     pub mod visit;
@@ -60,6 +63,7 @@ pub use num_bigint::BigUint;
 pub use crate::{
     ast_builder::AstBuilder,
     ast_kind::{AstKind, AstType},
+    pragma::{Directives, JsxRuntimePragma, Pragmas},
     trivia::{Comment, CommentKind, SortedComments, Trivias},
     visit::{Visit, VisitMut},
 };
@@ -0,0 +1,98 @@
+//! Directives and comment pragmas which affect how a file is compiled.
+
+use crate::{ast::Program, Trivias};
+
+/// Module level directives, e.g. `"use strict"`.
+///
+/// Only directives in the [`Program`] prologue are considered. `"use client"` and
+/// `"use server"` are the React Server Components directives.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Directives {
+    pub use_strict: bool,
+    pub use_client: bool,
+    pub use_server: bool,
+}
+
+impl Directives {
+    pub fn from_program(program: &Program) -> Self {
+        let mut directives = Self::default();
+        for directive in &program.directives {
+            match directive.directive.as_str() {
+                "use strict" => directives.use_strict = true,
+                "use client" => directives.use_client = true,
+                "use server" => directives.use_server = true,
+                _ => {}
+            }
+        }
+        directives
+    }
+}
+
+/// Value of a `@jsxRuntime` pragma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsxRuntimePragma {
+    Classic,
+    Automatic,
+}
+
+/// JSX pragmas found in comments.
+///
+/// * `@jsx React.createElement`
+/// * `@jsxFrag React.Fragment`
+/// * `@jsxImportSource react`
+/// * `@jsxRuntime classic` / `@jsxRuntime automatic`
+///
+/// The comment does not need to be a jsdoc. If a pragma occurs more than once, the last one wins.
+///
+/// This behavior is aligned with babel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pragmas<'a> {
+    pub jsx: Option<&'a str>,
+    pub jsx_frag: Option<&'a str>,
+    pub jsx_import_source: Option<&'a str>,
+    pub jsx_runtime: Option<JsxRuntimePragma>,
+}
+
+impl<'a> Pragmas<'a> {
+    pub fn from_comments(trivias: &Trivias, source_text: &'a str) -> Self {
+        let mut pragmas = Self::default();
+        for comment in trivias.comments() {
+            let mut comment = comment.span.source_text(source_text).trim_start();
+            // strip leading jsdoc comment `*` and then whitespaces
+            while let Some(cur_comment) = comment.strip_prefix('*') {
+                comment = cur_comment.trim_start();
+            }
+            // strip leading `@`
+            let Some(comment) = comment.strip_prefix('@') else { continue };
+
+            // read jsxRuntime
+            if let Some(runtime) = comment.strip_prefix("jsxRuntime").map(str::trim) {
+                match runtime {
+                    "classic" => pragmas.jsx_runtime = Some(JsxRuntimePragma::Classic),
+                    "automatic" => pragmas.jsx_runtime = Some(JsxRuntimePragma::Automatic),
+                    _ => {}
+                }
+                continue;
+            }
+
+            // read jsxImportSource
+            if let Some(import_source) = comment.strip_prefix("jsxImportSource").map(str::trim) {
+                pragmas.jsx_import_source = Some(import_source);
+                continue;
+            }
+
+            // read jsxFrag
+            if let Some(pragma_frag) = comment.strip_prefix("jsxFrag").map(str::trim) {
+                pragmas.jsx_frag = Some(pragma_frag);
+                continue;
+            }
+
+            // Put this condition at the end to avoid breaking @jsxXX
+            // read jsx
+            if let Some(pragma) = comment.strip_prefix("jsx").map(str::trim) {
+                pragmas.jsx = Some(pragma);
+            }
+        }
+        pragmas
+    }
+}
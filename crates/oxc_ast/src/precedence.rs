@@ -1,12 +1,20 @@
 use oxc_syntax::precedence::{GetPrecedence, Precedence};
 
 use crate::ast::{
-    match_member_expression, AssignmentExpression, AwaitExpression, BinaryExpression,
-    CallExpression, ComputedMemberExpression, ConditionalExpression, Expression, ImportExpression,
-    LogicalExpression, MemberExpression, NewExpression, PrivateFieldExpression, SequenceExpression,
-    StaticMemberExpression, TSTypeAssertion, UnaryExpression, UpdateExpression, YieldExpression,
+    match_member_expression, ArrowFunctionExpression, AssignmentExpression, AwaitExpression,
+    BinaryExpression, CallExpression, ChainExpression, ComputedMemberExpression,
+    ConditionalExpression, Expression, ImportExpression, LogicalExpression, MemberExpression,
+    NewExpression, ParenthesizedExpression, PrivateFieldExpression, PrivateInExpression,
+    SequenceExpression, StaticMemberExpression, TSAsExpression, TSNonNullExpression,
+    TSSatisfiesExpression, TSTypeAssertion, TaggedTemplateExpression, UnaryExpression,
+    UpdateExpression, YieldExpression,
 };
 
+/// Precedence of an expression.
+///
+/// Primary expressions (literals, identifiers, `this`, array and object literals, functions,
+/// classes, templates and JSX) bind tighter than any operator, and are given
+/// [`Precedence::Member`].
 impl<'a> GetPrecedence for Expression<'a> {
     fn precedence(&self) -> Precedence {
         match self {
@@ -21,8 +29,19 @@ impl<'a> GetPrecedence for Expression<'a> {
             Self::AwaitExpression(expr) => expr.precedence(),
             Self::NewExpression(expr) => expr.precedence(),
             Self::CallExpression(expr) => expr.precedence(),
+            Self::ImportExpression(expr) => expr.precedence(),
+            Self::TaggedTemplateExpression(expr) => expr.precedence(),
+            Self::ChainExpression(expr) => expr.precedence(),
+            Self::ArrowFunctionExpression(expr) => expr.precedence(),
+            Self::PrivateInExpression(expr) => expr.precedence(),
+            Self::ParenthesizedExpression(expr) => expr.precedence(),
+            Self::TSAsExpression(expr) => expr.precedence(),
+            Self::TSSatisfiesExpression(expr) => expr.precedence(),
+            Self::TSTypeAssertion(expr) => expr.precedence(),
+            Self::TSNonNullExpression(expr) => expr.precedence(),
+            Self::TSInstantiationExpression(expr) => expr.expression.precedence(),
             match_member_expression!(Self) => self.to_member_expression().precedence(),
-            _ => panic!("All cases should be covered"),
+            _ => Precedence::Member,
         }
     }
 }
@@ -33,6 +52,12 @@ impl<'a> GetPrecedence for SequenceExpression<'a> {
     }
 }
 
+impl<'a> GetPrecedence for ArrowFunctionExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Assign
+    }
+}
+
 impl<'a> GetPrecedence for YieldExpression<'a> {
     fn precedence(&self) -> Precedence {
         Precedence::Yield
@@ -63,6 +88,12 @@ impl<'a> GetPrecedence for BinaryExpression<'a> {
     }
 }
 
+impl<'a> GetPrecedence for PrivateInExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Compare
+    }
+}
+
 impl<'a> GetPrecedence for UnaryExpression<'a> {
     fn precedence(&self) -> Precedence {
         Precedence::Prefix
@@ -97,12 +128,24 @@ impl<'a> GetPrecedence for ImportExpression<'a> {
     }
 }
 
+impl<'a> GetPrecedence for TaggedTemplateExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Call
+    }
+}
+
 impl<'a> GetPrecedence for NewExpression<'a> {
     fn precedence(&self) -> Precedence {
         Precedence::Call
     }
 }
 
+impl<'a> GetPrecedence for ChainExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Member
+    }
+}
+
 impl<'a> GetPrecedence for MemberExpression<'a> {
     fn precedence(&self) -> Precedence {
         Precedence::Member
@@ -132,3 +175,27 @@ impl<'a> GetPrecedence for TSTypeAssertion<'a> {
         Precedence::Lowest
     }
 }
+
+impl<'a> GetPrecedence for ParenthesizedExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Member
+    }
+}
+
+impl<'a> GetPrecedence for TSAsExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Compare
+    }
+}
+
+impl<'a> GetPrecedence for TSSatisfiesExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Compare
+    }
+}
+
+impl<'a> GetPrecedence for TSNonNullExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Postfix
+    }
+}
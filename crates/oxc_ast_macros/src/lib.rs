@@ -68,17 +68,21 @@ fn assert_generated_derives(attrs: &[syn::Attribute]) -> TokenStream2 {
 
     // NOTE: At this level we don't care if a trait is derived multiple times, It is the
     // responsibility of the `ast_tools` to raise errors for those.
-    let assertion =
-        attrs.iter().filter(|attr| attr.path().is_ident("generate_derive")).flat_map(parse).map(
-            |derive| {
-                let (abs_derive, generics) = abs_trait(&derive);
-                quote! {{
-                    // NOTE: these are wrapped in a scope to avoid the need for unique identifiers.
-                    trait AssertionTrait: #abs_derive #generics {}
-                    impl<T: #derive #generics> AssertionTrait for T {}
-                }}
-            },
-        );
+    let assertion = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("generate_derive"))
+        .flat_map(parse)
+        // `ESTree` generates `serde::Serialize` implementations, which only exist with the
+        // `serialize` feature of the crate.
+        .filter(|derive| derive != "ESTree")
+        .map(|derive| {
+            let (abs_derive, generics) = abs_trait(&derive);
+            quote! {{
+                // NOTE: these are wrapped in a scope to avoid the need for unique identifiers.
+                trait AssertionTrait: #abs_derive #generics {}
+                impl<T: #derive #generics> AssertionTrait for T {}
+            }}
+        });
     quote!(const _: () = { #(#assertion)* };)
 }
 
@@ -141,6 +145,16 @@ fn assert_generated_derives(attrs: &[syn::Attribute]) -> TokenStream2 {
 /// This attribute is only used by `CloneIn` derive.
 /// `struct` fields marked with this attribute at cloning will use the `Default::default()` value instead of `CloneIn::clone_in` to initialize.
 ///
+/// ## `#[content_hash(skip)]`
+///
+/// This attribute is only used by `ContentHash` derive.
+/// `struct` fields marked with this attribute are not hashed, e.g. spans and semantic IDs, which differ between nodes of equal content.
+///
+/// ## `#[serde(...)]`
+///
+/// This attribute is used by `ESTree` derive, which generates `serde::Serialize` implementations behind the `serialize` feature.
+/// It accepts the `tag`, `rename`, `rename_all` and `untagged` arguments on types, and `flatten`, `skip` and `rename` on fields and variants, with the same meaning as in `serde_derive`.
+///
 /// # Mocked attributes:
 ///
 /// These are just here to remove the need for boilerplate `#[cfg_attr(...)]`. If their actual trait is derived they would consume these, Otherwise, Our mock attributes will prevent compile errors.
@@ -176,7 +190,10 @@ pub fn ast(_args: TokenStream, input: TokenStream) -> TokenStream {
 /// The only purpose is to allow the occurrence of helper attributes used with the `tasks/ast_tools`.
 ///
 /// Read [`macro@ast`] for further details.
-#[proc_macro_derive(Ast, attributes(scope, visit, span, generate_derive, clone_in, serde, tsify))]
+#[proc_macro_derive(
+    Ast,
+    attributes(scope, visit, span, generate_derive, clone_in, content_hash, serde, tsify)
+)]
 pub fn ast_derive(_item: TokenStream) -> TokenStream {
     TokenStream::new()
 }
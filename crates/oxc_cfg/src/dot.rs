@@ -17,6 +17,16 @@ pub trait DisplayDot {
     fn display_dot(&self) -> String;
 }
 
+impl ControlFlowGraph {
+    /// Render this graph in the Graphviz DOT format, e.g. for `dot -Tsvg`.
+    ///
+    /// Basic blocks are labeled with their instructions, and edges with their [`EdgeType`].
+    /// Edges from unreachable blocks are dotted.
+    pub fn to_dot(&self) -> String {
+        self.display_dot()
+    }
+}
+
 impl DisplayDot for ControlFlowGraph {
     fn display_dot(&self) -> String {
         format!(
@@ -0,0 +1,68 @@
+use std::fmt::Write;
+
+use oxc_index::Idx;
+use oxc_span::Span;
+use oxc_syntax::node::AstNodeId;
+use petgraph::{visit::EdgeRef, Direction};
+
+use crate::{ControlFlowGraph, DisplayDot};
+
+impl ControlFlowGraph {
+    /// Serialize the basic blocks of this graph as compact JSON, e.g.
+    ///
+    /// ```json
+    /// {"blocks":[{"id":1,"unreachable":false,"instructions":[{"kind":"statement","node":4,"span":[0,6]}],"successors":[{"target":2,"kind":"Normal"}]}]}
+    /// ```
+    ///
+    /// `span_of` maps the AST node of each instruction to its span, for referencing the source
+    /// text, e.g. `|id| semantic.nodes().kind(id).span()`.
+    pub fn to_json<F: Fn(AstNodeId) -> Span>(&self, span_of: F) -> String {
+        let mut json = String::from(r#"{"blocks":["#);
+        for (i, node) in self.graph.node_indices().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let basic_block = self.basic_block(node);
+            write!(
+                json,
+                r#"{{"id":{},"unreachable":{},"instructions":["#,
+                self.graph[node], basic_block.unreachable
+            )
+            .unwrap();
+            for (i, instruction) in basic_block.instructions().iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write!(json, r#"{{"kind":"{}""#, instruction.display_dot()).unwrap();
+                if let Some(node_id) = instruction.node_id {
+                    let span = span_of(node_id);
+                    write!(
+                        json,
+                        r#","node":{},"span":[{},{}]"#,
+                        node_id.index(),
+                        span.start,
+                        span.end
+                    )
+                    .unwrap();
+                }
+                json.push('}');
+            }
+            json.push_str(r#"],"successors":["#);
+            for (i, edge) in self.graph.edges_directed(node, Direction::Outgoing).enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write!(
+                    json,
+                    r#"{{"target":{},"kind":"{:?}"}}"#,
+                    self.graph[edge.target()],
+                    edge.weight()
+                )
+                .unwrap();
+            }
+            json.push_str("]}");
+        }
+        json.push_str("]}");
+        json
+    }
+}
@@ -1,5 +1,6 @@
 mod builder;
 mod dot;
+mod json;
 pub mod visit;
 
 use itertools::Itertools;
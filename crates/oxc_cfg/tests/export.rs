@@ -0,0 +1,53 @@
+use oxc_cfg::{
+    ControlFlowGraph, ControlFlowGraphBuilder, EdgeType, ErrorEdgeKind, ReturnInstructionKind,
+};
+use oxc_span::Span;
+use oxc_syntax::node::AstNodeId;
+
+/// ```js
+/// a;
+/// return b;
+/// ```
+fn build() -> ControlFlowGraph {
+    let mut cfg = ControlFlowGraphBuilder::default();
+    let error = cfg.attach_error_harness(ErrorEdgeKind::Implicit);
+
+    let a = cfg.new_basic_block_normal();
+    cfg.push_statement(AstNodeId::new(1));
+
+    let b = cfg.new_basic_block_normal();
+    cfg.add_edge(a, b, EdgeType::Normal);
+    cfg.push_return(ReturnInstructionKind::NotImplicitUndefined, AstNodeId::new(2));
+
+    cfg.release_error_harness(error);
+    cfg.build()
+}
+
+#[test]
+fn to_dot() {
+    let dot = build().to_dot();
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.contains(r#"1 [ label = "statement" ]"#), "{dot}");
+    assert!(dot.contains(r#"2 [ label = "return <value>" ]"#), "{dot}");
+    assert!(dot.contains(r#"1 -> 2 [ label = "Normal" ]"#), "{dot}");
+    assert!(dot.contains(r#"1 -> 0 [ label = "Error(Implicit)" ]"#), "{dot}");
+}
+
+#[test]
+fn to_json() {
+    let json =
+        build()
+            .to_json(|id| if id == AstNodeId::new(1) { Span::new(0, 2) } else { Span::new(3, 12) });
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"blocks":["#,
+            r#"{"id":0,"unreachable":false,"instructions":[],"successors":[]},"#,
+            r#"{"id":1,"unreachable":false,"instructions":[{"kind":"statement","node":1,"span":[0,2]}],"#,
+            r#""successors":[{"target":2,"kind":"Normal"},{"target":0,"kind":"Error(Implicit)"}]},"#,
+            r#"{"id":2,"unreachable":false,"instructions":[{"kind":"return <value>","node":2,"span":[3,12]}],"#,
+            r#""successors":[{"target":0,"kind":"Error(Implicit)"}]}"#,
+            r#"]}"#
+        )
+    );
+}
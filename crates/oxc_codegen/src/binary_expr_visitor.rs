@@ -5,7 +5,7 @@ use std::ops::Not;
 
 use oxc_ast::ast::{BinaryExpression, Expression, LogicalExpression};
 use oxc_syntax::{
-    operator::{BinaryOperator, LogicalOperator},
+    operator::{BinaryOperator, BinaryishOperator, LogicalOperator},
     precedence::{GetPrecedence, Precedence},
 };
 
@@ -43,12 +43,6 @@ impl<'a> Binaryish<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum BinaryishOperator {
-    Binary(BinaryOperator),
-    Logical(LogicalOperator),
-}
-
 impl Gen for BinaryishOperator {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
@@ -58,24 +52,6 @@ impl Gen for BinaryishOperator {
     }
 }
 
-impl GetPrecedence for BinaryishOperator {
-    fn precedence(&self) -> Precedence {
-        match self {
-            Self::Binary(op) => op.precedence(),
-            Self::Logical(op) => op.precedence(),
-        }
-    }
-}
-
-impl BinaryishOperator {
-    pub fn lower_precedence(self) -> Precedence {
-        match self {
-            Self::Binary(op) => op.lower_precedence(),
-            Self::Logical(op) => op.lower_precedence(),
-        }
-    }
-}
-
 #[derive(Clone, Copy)]
 pub struct BinaryExpressionVisitor<'a> {
     pub e: Binaryish<'a>,
@@ -148,16 +124,7 @@ impl<'a> BinaryExpressionVisitor<'a> {
             self.ctx &= Context::FORBID_IN.not();
         }
 
-        self.left_precedence = self.operator.lower_precedence();
-        self.right_precedence = self.operator.lower_precedence();
-
-        if self.operator.precedence().is_right_associative() {
-            self.left_precedence = self.operator.precedence();
-        }
-
-        if self.operator.precedence().is_left_associative() {
-            self.right_precedence = self.operator.precedence();
-        }
+        (self.left_precedence, self.right_precedence) = self.operator.operand_precedences();
 
         match self.operator {
             BinaryishOperator::Logical(LogicalOperator::Coalesce) => {
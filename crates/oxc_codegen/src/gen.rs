@@ -7,13 +7,13 @@ use oxc_span::GetSpan;
 use oxc_syntax::{
     identifier::{LS, PS},
     keyword::is_reserved_keyword_or_global_object,
-    operator::{BinaryOperator, LogicalOperator, UnaryOperator},
+    operator::{BinaryOperator, BinaryishOperator, LogicalOperator, UnaryOperator},
     precedence::{GetPrecedence, Precedence},
 };
 
 use crate::{
     annotation_comment::AnnotationKind,
-    binary_expr_visitor::{BinaryExpressionVisitor, Binaryish, BinaryishOperator},
+    binary_expr_visitor::{BinaryExpressionVisitor, Binaryish},
     Codegen, Context, Operator,
 };
 
@@ -75,7 +75,18 @@ impl<'a> Gen for Directive<'a> {
         // So here should print original `directive` value, the `expression` value is escaped str.
         // See https://github.com/babel/babel/blob/main/packages/babel-generator/src/generators/base.ts#L64
         p.wrap_quote(|p, _| {
-            p.print_str(self.directive.as_str());
+            let directive = self.directive.as_str();
+            if p.options.ascii_only && !directive.is_ascii() {
+                for c in directive.chars() {
+                    if c.is_ascii() {
+                        p.print_char(c as u8);
+                    } else {
+                        print_unicode_escape(c, p);
+                    }
+                }
+            } else {
+                p.print_str(directive);
+            }
         });
         p.print_char(b';');
         p.print_soft_newline();
@@ -601,7 +612,14 @@ impl<'a> Gen for VariableDeclaration<'a> {
 
 impl<'a> Gen for VariableDeclarator<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        self.id.gen(p, ctx);
+        if self.definite && !p.options.strip_types {
+            // `let x!: number`, the `!` is printed between the name and the type annotation.
+            self.id.kind.gen(p, ctx);
+            p.print_char(b'!');
+            p.print_type_annotation(self.id.type_annotation.as_deref(), ctx);
+        } else {
+            self.id.gen(p, ctx);
+        }
         if let Some(init) = &self.init {
             p.print_soft_space();
             p.print_equal();
@@ -638,7 +656,7 @@ impl<'a> Gen for Function<'a> {
                 type_parameters.gen(p, ctx);
             }
             p.print_char(b'(');
-            if let Some(this_param) = &self.this_param {
+            if let Some(this_param) = self.this_param.as_ref().filter(|_| !p.options.strip_types) {
                 this_param.gen(p, ctx);
                 if !self.params.is_empty() || self.params.rest.is_some() {
                     p.print_str(",");
@@ -647,10 +665,7 @@ impl<'a> Gen for Function<'a> {
             }
             self.params.gen(p, ctx);
             p.print_char(b')');
-            if let Some(return_type) = &self.return_type {
-                p.print_str(": ");
-                return_type.gen(p, ctx);
-            }
+            p.print_type_annotation(self.return_type.as_deref(), ctx);
             if let Some(body) = &self.body {
                 p.print_soft_space();
                 body.gen(p, ctx);
@@ -682,6 +697,9 @@ impl<'a> Gen for FormalParameter<'a> {
         if let Some(accessibility) = self.accessibility {
             accessibility.gen(p, ctx);
         }
+        if self.r#override {
+            p.print_str("override ");
+        }
         if self.readonly {
             p.print_str("readonly ");
         }
@@ -1149,7 +1167,6 @@ impl<'a> Gen for NumericLiteral<'a> {
     }
 }
 
-// TODO: refactor this with less allocations
 // <https://github.com/evanw/esbuild/blob/360d47230813e67d0312ad754cad2b6ee09b151b/internal/js_printer/js_printer.go#L3472>
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 fn print_non_negative_float(value: f64, p: &Codegen) -> String {
@@ -1157,59 +1174,52 @@ fn print_non_negative_float(value: f64, p: &Codegen) -> String {
     if value < 1000.0 && value.fract() == 0.0 {
         return value.to_js_string();
     }
-    let mut result = format!("{value:e}");
-    let chars = result.as_bytes();
-    let len = chars.len();
-    let dot = chars.iter().position(|&c| c == b'.');
-    let u8_to_string = |num: &[u8]| {
-        // SAFETY: criteria of `from_utf8_unchecked`.are met.
-        unsafe { String::from_utf8_unchecked(num.to_vec()) }
-    };
 
-    if dot == Some(1) && chars[0] == b'0' {
+    // Shortest string which round-trips, e.g. "0.001", "1000", "1e+21"
+    let mut result = value.to_js_string();
+
+    // Simplify the exponent
+    // "1e+21" => "1e21"
+    if let Some(e) = result.find('e') {
+        if result.as_bytes()[e + 1] == b'+' {
+            result.remove(e + 1);
+        }
+        return result;
+    }
+
+    if result.starts_with("0.") {
         // Strip off the leading zero when minifying
         // "0.5" => ".5"
-        let stripped_result = &chars[1..];
-        // after stripping the leading zero, the after dot position will be start from 1
-        let after_dot = 1;
+        let (result, after_dot) =
+            if p.options.minify { (&result[1..], 1) } else { (result.as_str(), 2) };
 
         // Try using an exponent
         // "0.001" => "1e-3"
-        if stripped_result[after_dot] == b'0' {
-            let mut i = after_dot + 1;
-            while stripped_result[i] == b'0' {
-                i += 1;
-            }
-            let remaining = &stripped_result[i..];
-            let exponent = format!("-{}", remaining.len() - after_dot + i);
-
+        let digits = result[after_dot..].trim_start_matches('0');
+        let zeros = result.len() - after_dot - digits.len();
+        if zeros > 0 {
+            let exponent = format!("-{}", zeros + digits.len());
             // Only switch if it's actually shorter
-            if stripped_result.len() > remaining.len() + 1 + exponent.len() {
-                result = format!("{}e{}", u8_to_string(remaining), exponent);
-            } else {
-                result = u8_to_string(stripped_result);
+            if result.len() > digits.len() + 1 + exponent.len() {
+                return format!("{digits}e{exponent}");
             }
-        } else {
-            result = u8_to_string(stripped_result);
         }
-    } else if chars[len - 1] == b'0' {
+        return result.to_string();
+    }
+
+    if result.ends_with('0') {
         // Simplify numbers ending with "0" by trying to use an exponent
         // "1000" => "1e3"
-        let mut i = len - 1;
-        while i > 0 && chars[i - 1] == b'0' {
-            i -= 1;
-        }
-        let remaining = &chars[0..i];
-        let exponent = format!("{}", chars.len() - i);
-
+        let digits = result.trim_end_matches('0');
+        let exponent = (result.len() - digits.len()).to_string();
         // Only switch if it's actually shorter
-        if chars.len() > remaining.len() + 1 + exponent.len() {
-            result = format!("{}e{}", u8_to_string(remaining), exponent);
-        } else {
-            result = u8_to_string(chars);
+        if result.len() > digits.len() + 1 + exponent.len() {
+            result = format!("{digits}e{exponent}");
         }
     }
 
+    // Use hex for large integers when it is shorter
+    // "1000000000001" => "0xe8d4a51001"
     if p.options.minify && value.fract() == 0.0 {
         let value = value as u64;
         if (1_000_000_000_000..=0xFFFF_FFFF_FFFF_F800).contains(&value) {
@@ -1253,12 +1263,12 @@ impl<'a> Gen for RegExpLiteral<'a> {
 }
 
 fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
-    let mut chars = s.chars().peekable();
+    let mut chars = s.char_indices().peekable();
 
-    while let Some(c) = chars.next() {
+    while let Some((i, c)) = chars.next() {
         match c {
             '\x00' => {
-                if chars.peek().is_some_and(|&next| next.is_ascii_digit()) {
+                if chars.peek().is_some_and(|&(_, next)| next.is_ascii_digit()) {
                     p.print_str("\\x00");
                 } else {
                     p.print_str("\\0");
@@ -1313,7 +1323,7 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
                 }
             }
             '$' => {
-                if chars.peek().is_some_and(|&next| next == '{') {
+                if chars.peek().is_some_and(|&(_, next)| next == '{') {
                     p.print_str("\\$");
                 } else {
                     p.print_str("$");
@@ -1327,6 +1337,15 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
             '\u{a0}' => {
                 p.print_str("\\xA0");
             }
+            // Byte order mark
+            '\u{feff}' => {
+                p.print_str("\\uFEFF");
+            }
+            // Avoid closing an inline `<script>` element
+            '<' if s[i + 1..].get(..7).is_some_and(|t| t.eq_ignore_ascii_case("/script")) => {
+                p.print_str("<\\");
+            }
+            _ if p.options.ascii_only && !c.is_ascii() => print_unicode_escape(c, p),
             _ => {
                 p.print_str(c.encode_utf8([0; 4].as_mut()));
             }
@@ -1334,13 +1353,37 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
     }
 }
 
+fn print_unicode_escape(c: char, p: &mut Codegen) {
+    let escape = if c > '\u{ffff}' {
+        format!("\\u{{{:X}}}", u32::from(c))
+    } else {
+        format!("\\u{:04X}", u32::from(c))
+    };
+    p.print_str(&escape);
+}
+
+/// The quote which needs the fewest escapes in `s`, preferring `preferred` on ties.
+fn best_quote(s: &str, preferred: u8) -> u8 {
+    let (single, double) = s.bytes().fold((0, 0), |(single, double), b| match b {
+        b'\'' => (single + 1, double),
+        b'"' => (single, double + 1),
+        _ => (single, double),
+    });
+    match preferred {
+        b'"' if double > single => b'\'',
+        b'\'' if single > double => b'"',
+        _ => preferred,
+    }
+}
+
 impl<'a> Gen for StringLiteral<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span.start);
         let s = self.value.as_str();
-        p.wrap_quote(|p, quote| {
-            print_unquoted_str(s, quote, p);
-        });
+        let quote = if p.options.minify { best_quote(s, p.quote) } else { p.quote };
+        p.print_char(quote);
+        print_unquoted_str(s, quote, p);
+        p.print_char(quote);
     }
 }
 
@@ -1622,11 +1665,7 @@ impl<'a> GenExpr for ArrowFunctionExpression<'a> {
             p.print_char(b'(');
             self.params.gen(p, ctx);
             p.print_char(b')');
-            if let Some(return_type) = &self.return_type {
-                p.print_str(":");
-                p.print_soft_space();
-                return_type.gen(p, ctx);
-            }
+            p.print_type_annotation(self.return_type.as_deref(), ctx);
             p.print_soft_space();
             p.print_str("=>");
             p.print_soft_space();
@@ -2019,22 +2058,46 @@ impl<'a> GenExpr for ImportExpression<'a> {
 
 impl<'a> Gen for TemplateLiteral<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_char(b'`');
-        let mut expressions = self.expressions.iter();
+        print_template_literal(self, /* escape_script */ true, p);
+    }
+}
 
-        for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span.start);
-            p.print_str(quasi.value.raw.as_str());
+/// The raw value of the quasis of a tagged template is observable, so `</script` is only escaped
+/// in untagged templates.
+fn print_template_literal(literal: &TemplateLiteral, escape_script: bool, p: &mut Codegen) {
+    p.print_char(b'`');
+    let mut expressions = literal.expressions.iter();
 
-            if let Some(expr) = expressions.next() {
-                p.print_str("${");
-                p.print_expression(expr);
-                p.print_char(b'}');
-            }
+    for quasi in &literal.quasis {
+        p.add_source_mapping(quasi.span.start);
+        let raw = quasi.value.raw.as_str();
+        if escape_script {
+            print_quasi_escaping_script(raw, p);
+        } else {
+            p.print_str(raw);
         }
 
-        p.print_char(b'`');
+        if let Some(expr) = expressions.next() {
+            p.print_str("${");
+            p.print_expression(expr);
+            p.print_char(b'}');
+        }
+    }
+
+    p.print_char(b'`');
+}
+
+/// Avoid closing an inline `<script>` element
+fn print_quasi_escaping_script(raw: &str, p: &mut Codegen) {
+    let mut start = 0;
+    for (i, _) in raw.match_indices("</") {
+        if raw[i + 2..].get(..6).is_some_and(|t| t.eq_ignore_ascii_case("script")) {
+            p.print_str(&raw[start..=i]);
+            p.print_char(b'\\');
+            start = i + 1;
+        }
     }
+    p.print_str(&raw[start..]);
 }
 
 impl<'a> Gen for TaggedTemplateExpression<'a> {
@@ -2044,7 +2107,7 @@ impl<'a> Gen for TaggedTemplateExpression<'a> {
         if let Some(type_parameters) = &self.type_parameters {
             type_parameters.gen(p, ctx);
         }
-        self.quasi.gen(p, ctx);
+        print_template_literal(&self.quasi, /* escape_script */ false, p);
     }
 }
 
@@ -2195,7 +2258,7 @@ impl<'a> Gen for Class<'a> {
                     super_type_parameters.gen(p, ctx);
                 }
             }
-            if let Some(implements) = self.implements.as_ref() {
+            if let Some(implements) = self.implements.as_ref().filter(|_| !p.options.strip_types) {
                 p.print_str(" implements ");
                 p.print_list(implements, ctx);
             }
@@ -2487,6 +2550,9 @@ impl<'a> Gen for MethodDefinition<'a> {
         if self.r#static {
             p.print_str("static ");
         }
+        if self.r#override {
+            p.print_str("override ");
+        }
 
         match &self.kind {
             MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
@@ -2522,11 +2588,7 @@ impl<'a> Gen for MethodDefinition<'a> {
         p.print_char(b'(');
         self.value.params.gen(p, ctx);
         p.print_char(b')');
-        if let Some(return_type) = &self.value.return_type {
-            p.print_colon();
-            p.print_soft_space();
-            return_type.gen(p, ctx);
-        }
+        p.print_type_annotation(self.value.return_type.as_deref(), ctx);
         if let Some(body) = &self.value.body {
             p.print_soft_space();
             body.gen(p, ctx);
@@ -2552,6 +2614,9 @@ impl<'a> Gen for PropertyDefinition<'a> {
         if self.r#static {
             p.print_str("static ");
         }
+        if self.r#override {
+            p.print_str("override ");
+        }
         if self.readonly {
             p.print_str("readonly ");
         }
@@ -2562,14 +2627,13 @@ impl<'a> Gen for PropertyDefinition<'a> {
         if self.computed {
             p.print_char(b']');
         }
-        if self.optional {
+        if self.optional && !p.options.strip_types {
             p.print_str("?");
         }
-        if let Some(type_annotation) = &self.type_annotation {
-            p.print_colon();
-            p.print_soft_space();
-            type_annotation.gen(p, ctx);
+        if self.definite && !p.options.strip_types {
+            p.print_char(b'!');
         }
+        p.print_type_annotation(self.type_annotation.as_deref(), ctx);
         if let Some(value) = &self.value {
             p.print_soft_space();
             p.print_equal();
@@ -2583,12 +2647,13 @@ impl<'a> Gen for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span.start);
         self.decorators.gen(p, ctx);
-        if self.r#type.is_abstract() {
-            p.print_str("abstract ");
-        }
+        // Accessibility modifiers must precede `abstract`.
         if let Some(accessibility) = &self.accessibility {
             accessibility.gen(p, ctx);
         }
+        if self.r#type.is_abstract() {
+            p.print_str("abstract ");
+        }
         if self.r#static {
             p.print_str("static ");
         }
@@ -2603,11 +2668,10 @@ impl<'a> Gen for AccessorProperty<'a> {
         if self.computed {
             p.print_char(b']');
         }
-        if let Some(type_annotation) = &self.type_annotation {
-            p.print_colon();
-            p.print_soft_space();
-            type_annotation.gen(p, ctx);
+        if self.definite && !p.options.strip_types {
+            p.print_char(b'!');
         }
+        p.print_type_annotation(self.type_annotation.as_deref(), ctx);
         if let Some(value) = &self.value {
             p.print_soft_space();
             p.print_equal();
@@ -2627,20 +2691,22 @@ impl<'a> Gen for PrivateIdentifier<'a> {
 
 impl<'a> Gen for BindingPattern<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        match &self.kind {
+        self.kind.gen(p, ctx);
+        if self.optional && !p.options.strip_types {
+            p.print_str("?");
+        }
+        p.print_type_annotation(self.type_annotation.as_deref(), ctx);
+    }
+}
+
+impl<'a> Gen for BindingPatternKind<'a> {
+    fn gen(&self, p: &mut Codegen, ctx: Context) {
+        match self {
             BindingPatternKind::BindingIdentifier(ident) => ident.gen(p, ctx),
             BindingPatternKind::ObjectPattern(pattern) => pattern.gen(p, ctx),
             BindingPatternKind::ArrayPattern(pattern) => pattern.gen(p, ctx),
             BindingPatternKind::AssignmentPattern(pattern) => pattern.gen(p, ctx),
         }
-        if self.optional {
-            p.print_str("?");
-        }
-        if let Some(type_annotation) = &self.type_annotation {
-            p.print_colon();
-            p.print_soft_space();
-            type_annotation.gen(p, ctx);
-        }
     }
 }
 
@@ -2794,6 +2860,9 @@ impl<'a> Gen for TSClassImplements<'a> {
 
 impl<'a> Gen for TSTypeParameterDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
+        if p.options.strip_types {
+            return;
+        }
         p.print_str("<");
         p.print_list(&self.params, ctx);
         p.print_str(">");
@@ -3135,6 +3204,12 @@ impl<'a> Gen for TSTypeParameter<'a> {
         if self.r#const {
             p.print_str("const ");
         }
+        if self.r#in {
+            p.print_str("in ");
+        }
+        if self.out {
+            p.print_str("out ");
+        }
         self.name.gen(p, ctx);
         if let Some(constraint) = &self.constraint {
             p.print_str(" extends ");
@@ -3373,6 +3448,9 @@ impl<'a> Gen for TSImportAttributeName<'a> {
 
 impl<'a> Gen for TSTypeParameterInstantiation<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
+        if p.options.strip_types {
+            return;
+        }
         p.print_str("<");
         p.print_list(&self.params, ctx);
         p.print_str(">");
@@ -3527,6 +3605,9 @@ impl<'a> Gen for TSTypeAliasDeclaration<'a> {
 
 impl<'a> Gen for TSInterfaceDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
+        if self.declare {
+            p.print_str("declare ");
+        }
         p.print_str("interface");
         p.print_hard_space();
         self.id.gen(p, ctx);
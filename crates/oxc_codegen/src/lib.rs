@@ -13,7 +13,10 @@ mod sourcemap_builder;
 use std::{borrow::Cow, collections::hash_map::Entry, ops::Range};
 
 use oxc_ast::{
-    ast::{BindingIdentifier, BlockStatement, Expression, IdentifierReference, Program, Statement},
+    ast::{
+        BindingIdentifier, BlockStatement, Expression, IdentifierReference, Program, Statement,
+        TSTypeAnnotation,
+    },
     Comment, Trivias,
 };
 use oxc_mangler::Mangler;
@@ -38,13 +41,48 @@ pub use crate::{
 /// Code generator without whitespace removal.
 pub type CodeGenerator<'a> = Codegen<'a>;
 
-#[derive(Default, Clone, Copy)]
+/// Output is byte-identical across runs and platforms for the same AST and options.
+#[derive(Debug, Default, Clone, Copy)]
 pub struct CodegenOptions {
     /// Use single quotes instead of double quotes.
     pub single_quote: bool,
 
     /// Remove whitespace.
+    ///
+    /// String literals use the quote which needs the fewest escapes.
     pub minify: bool,
+
+    /// Escape non-ASCII characters in string literals, e.g. `"π"` is printed as `"\u03C0"`.
+    pub ascii_only: bool,
+
+    /// Line ending of printed newlines. Newlines copied from the source text, e.g. in comments
+    /// and template literals, are kept as is.
+    pub line_ending: LineEnding,
+
+    /// Do not print type annotations, type parameters and type arguments of values, e.g. the
+    /// `: number` of `let x: number`, and `this` parameters.
+    ///
+    /// For ASTs which keep type annotations for analysis after being transformed to JavaScript,
+    /// such as with the transformer's `preserve_type_annotations` option.
+    pub strip_types: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+impl LineEnding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
 }
 
 #[derive(Default, Clone, Copy)]
@@ -262,7 +300,7 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_soft_newline(&mut self) {
         if !self.options.minify {
-            self.print_char(b'\n');
+            self.print_str(self.options.line_ending.as_str());
         }
     }
 
@@ -324,7 +362,8 @@ impl<'a> Codegen<'a> {
         if self.options.minify {
             self.needs_semicolon = true;
         } else {
-            self.print_str(";\n");
+            self.print_semicolon();
+            self.print_soft_newline();
         }
     }
 
@@ -346,6 +385,17 @@ impl<'a> Codegen<'a> {
         self.print_char(b':');
     }
 
+    /// Print the type annotation `: T` of a value, unless [`CodegenOptions::strip_types`] is set.
+    fn print_type_annotation(&mut self, type_annotation: Option<&TSTypeAnnotation>, ctx: Context) {
+        if let Some(type_annotation) = type_annotation {
+            if !self.options.strip_types {
+                self.print_colon();
+                self.print_soft_space();
+                type_annotation.gen(self, ctx);
+            }
+        }
+    }
+
     #[inline]
     fn print_equal(&mut self) {
         self.print_char(b'=');
@@ -0,0 +1,69 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions, CommentOptions, LineEnding};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn codegen(source_text: &str, options: CodegenOptions) -> String {
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    CodeGenerator::new()
+        .enable_comment(
+            source_text,
+            ret.trivias,
+            CommentOptions { preserve_annotate_comments: true },
+        )
+        .with_options(options)
+        .build(&ret.program)
+        .source_text
+}
+
+const SOURCES: &[&str] = &[
+    "const o = { z: 1, a: 2, [k]: 3, 10: 4, 2: 5, ...rest, m() {}, get g() { return 1 } };",
+    "x = [0.1 + 0.2, 1e21, 5e-7, 123456789012345680000, 0x10, 1_000, .5, 2 ** 53, -0];",
+    "class A { static #p = 1; b = 2; a() { return /*#__PURE__*/ f(); } }",
+    "import { b, a } from 'm'; export { a, b as c }; export default function () {}",
+    "label: for (const k in o) { if (k) continue label; else break; }",
+    "const s = `a${b}c\n`, t = 'single', u = \"double\";",
+];
+
+#[test]
+fn repeated_runs() {
+    for options in [
+        CodegenOptions::default(),
+        CodegenOptions { minify: true, ..CodegenOptions::default() },
+        CodegenOptions { single_quote: true, ..CodegenOptions::default() },
+        CodegenOptions { line_ending: LineEnding::Crlf, ..CodegenOptions::default() },
+    ] {
+        for source_text in SOURCES {
+            let expected = codegen(source_text, options);
+            for _ in 0..3 {
+                assert_eq!(codegen(source_text, options), expected, "for source {source_text:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn crlf() {
+    let options = CodegenOptions { line_ending: LineEnding::Crlf, ..CodegenOptions::default() };
+    assert_eq!(
+        codegen("function f() { return 1 }", options),
+        "function f() {\r\n\treturn 1;\r\n}\r\n"
+    );
+    // Newlines in template literals are part of the value
+    assert_eq!(codegen("x = `a\nb`", options), "x = `a\nb`;\r\n");
+
+    for source_text in SOURCES {
+        let lf = codegen(source_text, CodegenOptions::default());
+        let crlf = codegen(source_text, options);
+        assert_eq!(crlf.replace("\r\n", "\n"), lf, "for source {source_text:?}");
+    }
+}
+
+#[test]
+fn crlf_minify() {
+    let options =
+        CodegenOptions { minify: true, line_ending: LineEnding::Crlf, ..CodegenOptions::default() };
+    assert_eq!(codegen("function f() { return 1 }", options), "function f(){return 1}");
+}
@@ -1,9 +1,10 @@
 //! Tests ported from [esbuild](https://github.com/evanw/esbuild/blob/main/internal/js_printer/js_printer_test.go#L164)
 
-use crate::tester::{test, test_minify};
+use oxc_codegen::CodegenOptions;
+
+use crate::tester::{test, test_minify, test_options};
 
 #[test]
-#[ignore]
 fn test_number() {
     // Check "1eN"
     test("x = 1e-100", "x = 1e-100;\n");
@@ -28,7 +29,7 @@ fn test_number() {
     test_minify("x = 1e2", "x=100;");
     test_minify("x = 1e3", "x=1e3;");
     test_minify("x = 1e4", "x=1e4;");
-    // test_minify("x = 1e100", "x=1e100;");
+    test_minify("x = 1e100", "x=1e100;");
 
     // Check "12eN"
     test("x = 12e-100", "x = 12e-100;\n");
@@ -115,13 +116,13 @@ fn test_number() {
     // test("x = 0xFFFF_FFFF_FFFF_F000", "x = 18446744073709548e3;\n");
     // test("x = 0xFFFF_FFFF_FFFF_F800", "x = 1844674407370955e4;\n");
     // test("x = 0xFFFF_FFFF_FFFF_FFFF", "x = 18446744073709552e3;\n");
-    // test_minify("x = 999999999999", "x=999999999999;");
-    // test_minify("x = 1000000000001", "x=0xe8d4a51001;");
-    // test_minify("x = 0x0FFF_FFFF_FFFF_FF80", "x=0xfffffffffffff80;");
-    // test_minify("x = 0x1000_0000_0000_0000", "x=1152921504606847e3;");
-    // test_minify("x = 0xFFFF_FFFF_FFFF_F000", "x=0xfffffffffffff000;");
-    // test_minify("x = 0xFFFF_FFFF_FFFF_F800", "x=1844674407370955e4;");
-    // test_minify("x = 0xFFFF_FFFF_FFFF_FFFF", "x=18446744073709552e3;");
+    test_minify("x = 999999999999", "x=999999999999;");
+    test_minify("x = 1000000000001", "x=0xe8d4a51001;");
+    test_minify("x = 0x0FFF_FFFF_FFFF_FF80", "x=0xfffffffffffff80;");
+    test_minify("x = 0x1000_0000_0000_0000", "x=1152921504606847e3;");
+    test_minify("x = 0xFFFF_FFFF_FFFF_F000", "x=0xfffffffffffff000;");
+    test_minify("x = 0xFFFF_FFFF_FFFF_F800", "x=1844674407370955e4;");
+    test_minify("x = 0xFFFF_FFFF_FFFF_FFFF", "x=18446744073709552e3;");
 
     // Check printing a space in between a number and a subsequent "."
     test_minify("x = 0.0001 .y", "x=1e-4.y;");
@@ -829,6 +830,8 @@ fn test_es5() {
 
 #[test]
 fn test_ascii_only() {
+    let ascii_only = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+
     test("let π = 'π'", "let π = \"π\";\n");
     test("let π_ = 'π'", "let π_ = \"π\";\n");
     test("let _π = 'π'", "let _π = \"π\";\n");
@@ -848,7 +851,7 @@ fn test_ascii_only() {
     test("var \\u{10000}", "var 𐀀;\n");
     // testASCII(t, "var 𐀀", "var \\u{10000};\n");
     // testASCII(t, "var \\u{10000}", "var \\u{10000};\n");
-    // testTargetASCII(t, 2015, "'𐀀'", "\"\\u{10000}\";\n");
+    test_options("'𐀀'", "\"\\u{10000}\";\n", ascii_only);
     // testTargetASCII(t, 5, "'𐀀'", "\"\\uD800\\uDC00\";\n");
     // testTargetASCII(t, 2015, "x.𐀀", "x[\"\\u{10000}\"];\n");
     // testTargetASCII(t, 5, "x.𐀀", "x[\"\\uD800\\uDC00\"];\n");
@@ -866,9 +869,9 @@ fn test_ascii_only() {
     // );
 
     // These characters should always be escaped
-    // test( "let x = '\u2028'", "let x = \"\\u2028\";\n");
-    // test( "let x = '\u2029'", "let x = \"\\u2029\";\n");
-    // test( "let x = '\uFEFF'", "let x = \"\\uFEFF\";\n");
+    test("let x = '\u{2028}'", "let x = \"\\u2028\";\n");
+    test("let x = '\u{2029}'", "let x = \"\\u2029\";\n");
+    test("let x = '\u{FEFF}'", "let x = \"\\uFEFF\";\n");
 
     // There should still be a space before "extends"
     // testASCII(t, "class 𐀀 extends π {}", "class \\u{10000} extends \\u03C0 {\n}\n");
@@ -1,4 +1,5 @@
 #![allow(clippy::missing_panics_doc)]
+pub mod determinism;
 pub mod esbuild;
 pub mod pure_comments;
 pub mod tester;
@@ -45,3 +45,14 @@ pub fn test_minify(source_text: &str, expected: &str) {
         "\nfor minify source {source_text}\nexpect {expected}\ngot    {result:?}"
     );
 }
+
+pub fn test_options(source_text: &str, expected: &str, options: CodegenOptions) {
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new().with_options(options).build(&ret.program).source_text;
+    assert_eq!(
+        result, expected,
+        "\nfor source {source_text:?}\nexpect {expected:?}\ngot    {result:?}"
+    );
+}
@@ -52,6 +52,12 @@ fn ts() {
         "d = x satisfies y;",
         "export @x declare abstract class C {}",
         "div<T>``",
+        "let x!: number;",
+        "class A {a!: string; public accessor b!: number}",
+        "class B extends A {override m() {} public static override readonly p = 1}",
+        "class B extends A {constructor(private override x) {}}",
+        "declare interface I {}",
+        "type F<in T, out U, in out V> = T;",
     ];
 
     let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
@@ -63,3 +69,36 @@ fn ts() {
         insta::assert_snapshot!("ts", snapshot);
     });
 }
+
+#[test]
+fn strip_types() {
+    let source_text = "let x: number = 1;
+function f<T>(this: A, a?: T): T {
+\treturn a;
+}
+const g = <T,>(a: T): T => a;
+new Map<string, number>();
+class A<T> extends B<T> implements C {
+\tx!: T;
+}";
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_typescript(true).with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { strip_types: true, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .source_text;
+    assert_eq!(
+        result,
+        "let x = 1;
+function f(a) {
+\treturn a;
+}
+const g = (a) => a;
+new Map();
+class A extends B {
+\tx;
+}
+"
+    );
+}
@@ -1,4 +1,6 @@
-use crate::tester::{test, test_minify, test_without_source};
+use oxc_codegen::CodegenOptions;
+
+use crate::tester::{test, test_minify, test_options, test_without_source};
 
 #[test]
 fn module_decl() {
@@ -254,3 +256,28 @@ fn equality() {
     test_minify("a, b == c , d", "a,b==c,d;");
     test_minify("(a, b) == (c , d)", "(a,b)==(c,d);");
 }
+
+#[test]
+fn string_quotes() {
+    test("let x = 'a\"b'", "let x = \"a\\\"b\";\n");
+    test_minify("let x = 'a\"b'", "let x='a\"b';");
+    test_minify("let x = \"a'b\"", "let x=\"a'b\";");
+    test_minify("let x = 'a\"b\\'c\"'", "let x='a\"b\\'c\"';");
+    test_minify("let x = 'a\"b\\''", "let x=\"a\\\"b'\";");
+    test_minify("import x from 'a\"b'", "import x from 'a\"b';");
+}
+
+#[test]
+fn string_escapes() {
+    test("x = '</script>'", "x = \"<\\/script>\";\n");
+    test("x = '</SCRIPT'", "x = \"<\\/SCRIPT\";\n");
+    test("x = '</scrip'", "x = \"</scrip\";\n");
+    test("x = `</script>`", "x = `<\\/script>`;\n");
+    test("x = `${y}</SCRIPT${z}</script`", "x = `${y}<\\/SCRIPT${z}<\\/script`;\n");
+    test("x = String.raw`</script>`", "x = String.raw`</script>`;\n");
+    test("x = '\\u03C0'", "x = \"π\";\n");
+
+    let ascii_only = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options("x = 'π'", "x = \"\\u03C0\";\n", ascii_only);
+    test_options("x = '\\u{1F408}'", "x = \"\\u{1F408}\";\n", ascii_only);
+}
@@ -0,0 +1,15 @@
+use similar::TextDiff;
+
+/// Create a unified diff of `old` and `new`, with 3 lines of context, in the format of `git diff`.
+///
+/// Returns an empty string if `old` and `new` are the same.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string()
+}
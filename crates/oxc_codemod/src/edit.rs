@@ -0,0 +1,67 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, AstBuilder};
+use oxc_codegen::CodeGenerator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+/// Replacement of the source text in `span`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn new<S: Into<String>>(span: Span, replacement: S) -> Self {
+        Self { span, replacement: replacement.into() }
+    }
+}
+
+/// Apply `edits` to `source_text`.
+///
+/// Edits are applied in order of their position in the source text.
+/// An edit which overlaps a previous edit is skipped.
+pub fn apply_edits(source_text: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+
+    let mut output = String::with_capacity(source_text.len());
+    let mut last_end = 0;
+    for edit in edits {
+        let Span { start, end, .. } = edit.span;
+        if start < last_end {
+            continue;
+        }
+        output.push_str(&source_text[last_end as usize..start as usize]);
+        output.push_str(&edit.replacement);
+        last_end = end;
+    }
+    output.push_str(&source_text[last_end as usize..]);
+    output
+}
+
+/// Parse `source_text`, mutate the AST with `f`, and print the AST with [`oxc_codegen`].
+///
+/// Unlike span-based [`Edit`]s, the whole file is reprinted, so formatting and comments are not
+/// preserved. Prefer [`Edit`]s for small changes.
+///
+/// # Errors
+///
+/// Returns the parser's errors if `source_text` has syntax errors.
+pub fn rewrite_ast<F>(
+    source_text: &str,
+    source_type: SourceType,
+    f: F,
+) -> Result<String, Vec<OxcDiagnostic>>
+where
+    F: for<'a> FnOnce(&mut Program<'a>, AstBuilder<'a>),
+{
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+    let mut program = ret.program;
+    f(&mut program, AstBuilder::new(&allocator));
+    Ok(CodeGenerator::new().build(&program).source_text)
+}
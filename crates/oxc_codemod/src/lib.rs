@@ -0,0 +1,32 @@
+//! Codemods: automated refactorings of JavaScript and TypeScript source code.
+//!
+//! A codemod runs in 4 steps:
+//!
+//! 1. Parse the source text with [`oxc_parser`].
+//! 2. Match AST nodes against a [`Pattern`], e.g. `console.log($$$ARGS)`.
+//! 3. Edit the source text, either with span-based [`Edit`]s (which preserve formatting and comments),
+//!    or by mutating the AST and printing it with [`oxc_codegen`] (see [`rewrite_ast`]).
+//! 4. Print a minimal [`unified_diff`] of the changes.
+//!
+//! Rules are usually written in a TOML [`Script`]:
+//!
+//! ```toml
+//! [[rule]]
+//! name = "console-log-to-logger"
+//! pattern = "console.log($$$ARGS)"
+//! replace = "logger.debug($$$ARGS)"
+//! ```
+//!
+//! and run with `oxc codemod script.toml src/` (requires the `cli` feature).
+
+mod diff;
+mod edit;
+mod pattern;
+mod script;
+
+pub use crate::{
+    diff::unified_diff,
+    edit::{apply_edits, rewrite_ast, Edit},
+    pattern::{Match, Pattern},
+    script::{CodemodReturn, Report, Rule, Script},
+};
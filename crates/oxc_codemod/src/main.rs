@@ -0,0 +1,122 @@
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+//! `oxc codemod script.toml src/`
+//!
+//! Prints the changes as a diff, or writes them to the files with `--write`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use bpaf::Bpaf;
+use ignore::WalkBuilder;
+use oxc_codemod::{unified_diff, Script};
+use oxc_span::SourceType;
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options)]
+enum Command {
+    /// Run a codemod script on files
+    #[bpaf(command)]
+    Codemod {
+        /// Write changes to files instead of printing a diff
+        #[bpaf(switch)]
+        write: bool,
+        /// TOML file with the codemod rules
+        #[bpaf(positional("SCRIPT"))]
+        script: PathBuf,
+        /// Files and directories to run the codemod on. Defaults to the current directory
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let Command::Codemod { write, script, mut paths } = command().run();
+
+    let script = match fs::read_to_string(&script) {
+        Ok(source_text) => Script::from_toml(&source_text),
+        Err(err) => {
+            eprintln!("Failed to read {}: {err}", script.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let script = match script {
+        Ok(script) => script,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if paths.is_empty() {
+        paths.push(PathBuf::from("."));
+    }
+    let mut walk = WalkBuilder::new(&paths[0]);
+    for path in &paths[1..] {
+        walk.add(path);
+    }
+
+    let mut success = true;
+    for entry in walk.build().filter_map(Result::ok) {
+        if entry.file_type().map_or(true, |file_type| file_type.is_dir()) {
+            continue;
+        }
+        let path = entry.path();
+        let Ok(source_type) = SourceType::from_path(path) else { continue };
+        success &= run(&script, path, source_type, write);
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Run `script` on one file. Returns `false` if the file could not be processed.
+fn run(script: &Script, path: &Path, source_type: SourceType, write: bool) -> bool {
+    let source_text = match fs::read_to_string(path) {
+        Ok(source_text) => source_text,
+        Err(err) => {
+            eprintln!("Failed to read {}: {err}", path.display());
+            return false;
+        }
+    };
+    let ret = match script.run(&source_text, source_type) {
+        Ok(ret) => ret,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{}: {error}", path.display());
+            }
+            return false;
+        }
+    };
+
+    for report in &ret.reports {
+        let (line, column) = line_column(&ret.source_text, report.span.start);
+        println!("{}:{line}:{column}: {}", path.display(), report.rule);
+    }
+
+    if ret.source_text == source_text {
+        return true;
+    }
+    if write {
+        if let Err(err) = fs::write(path, &ret.source_text) {
+            eprintln!("Failed to write {}: {err}", path.display());
+            return false;
+        }
+    } else {
+        print!("{}", unified_diff(&path.to_string_lossy(), &source_text, &ret.source_text));
+    }
+    true
+}
+
+/// 1-indexed line and column of `offset`.
+fn line_column(source_text: &str, offset: u32) -> (usize, usize) {
+    let before = &source_text[..offset as usize];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rfind('\n').map_or(before.len(), |index| before.len() - index - 1) + 1;
+    (line, column)
+}
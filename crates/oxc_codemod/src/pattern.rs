@@ -0,0 +1,382 @@
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{cmp::ContentEq, GetSpan, SourceType, Span};
+use rustc_hash::FxHashMap;
+
+/// A code pattern to search for, e.g. `console.log($$$ARGS)`.
+///
+/// A pattern is a JavaScript or TypeScript expression, and matches any expression with the same
+/// structure. Spans, whitespace, comments and parentheses are ignored.
+///
+/// Identifiers made of a `$` followed by uppercase letters, digits and `_` are metavariables:
+///
+/// * `$NAME` matches any expression, or any property name in `a.$NAME`.
+/// * `$$$NAME` matches any number of arguments or array elements, e.g. `f($A, $$$REST)`.
+///
+/// If a metavariable is used more than once, all its occurrences must match the same source text,
+/// e.g. `$A === $A` matches `x === x` but not `x === y`.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source_text: String,
+}
+
+impl Pattern {
+    /// # Errors
+    ///
+    /// Returns parser errors if `source_text` is not a valid expression.
+    pub fn new(source_text: &str) -> Result<Self, Vec<OxcDiagnostic>> {
+        let allocator = Allocator::default();
+        Self::parse(&allocator, source_text)?;
+        Ok(Self { source_text: source_text.to_string() })
+    }
+
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    fn parse<'p>(
+        allocator: &'p Allocator,
+        source_text: &'p str,
+    ) -> Result<Expression<'p>, Vec<OxcDiagnostic>> {
+        Parser::new(allocator, source_text, SourceType::tsx()).parse_expression()
+    }
+
+    /// Find all expressions in `program` which match this pattern.
+    ///
+    /// Expressions inside a match are not searched, so matches never overlap.
+    pub fn find<'a>(&self, program: &Program<'a>, source_text: &'a str) -> Vec<Match<'a>> {
+        let allocator = Allocator::default();
+        // Pattern was already checked to be valid in `Pattern::new`
+        let Ok(pattern) = Self::parse(&allocator, &self.source_text) else { return vec![] };
+        let mut finder = Finder { pattern: &pattern, source_text, matches: vec![] };
+        finder.visit_program(program);
+        finder.matches
+    }
+}
+
+/// An expression matching a [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub span: Span,
+    /// Source text matched by each metavariable, keyed by name without the `$`s.
+    pub captures: FxHashMap<String, &'a str>,
+}
+
+impl<'a> Match<'a> {
+    /// Replace metavariables in `template` with the source text they matched.
+    ///
+    /// Metavariables which did not occur in the pattern are left as is.
+    pub fn substitute(&self, template: &str) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(index) = rest.find('$') {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+            let prefix_len = if rest.starts_with("$$$") { 3 } else { 1 };
+            let name_len =
+                rest[prefix_len..].bytes().take_while(|&b| is_metavariable_byte(b)).count();
+            let end = prefix_len + name_len;
+            match Metavariable::parse(&rest[..end]).and_then(|var| self.captures.get(var.name)) {
+                Some(capture) => output.push_str(capture),
+                None => output.push_str(&rest[..end.max(1)]),
+            }
+            rest = &rest[end.max(1)..];
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+struct Metavariable<'n> {
+    name: &'n str,
+    /// `$$$NAME`
+    multiple: bool,
+}
+
+impl<'n> Metavariable<'n> {
+    fn parse(name: &'n str) -> Option<Self> {
+        let (name, multiple) = match name.strip_prefix("$$$") {
+            Some(name) => (name, true),
+            None => (name.strip_prefix('$')?, false),
+        };
+        let is_valid = name.bytes().next().is_some_and(|b| !b.is_ascii_digit())
+            && name.bytes().all(is_metavariable_byte);
+        is_valid.then_some(Self { name, multiple })
+    }
+}
+
+fn is_metavariable_byte(b: u8) -> bool {
+    b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_'
+}
+
+struct Finder<'p, 'a> {
+    pattern: &'p Expression<'p>,
+    source_text: &'a str,
+    matches: Vec<Match<'a>>,
+}
+
+impl<'p, 'a> Visit<'a> for Finder<'p, 'a> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        let mut matcher = Matcher::new(self.source_text);
+        if matcher.expression(self.pattern, expr) {
+            self.matches.push(Match { span: expr.span(), captures: matcher.captures });
+            return;
+        }
+        walk::walk_expression(self, expr);
+    }
+}
+
+/// Structural comparison of a pattern and an AST node, which records metavariable captures.
+struct Matcher<'a> {
+    source_text: &'a str,
+    captures: FxHashMap<String, &'a str>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(source_text: &'a str) -> Self {
+        Self { source_text, captures: FxHashMap::default() }
+    }
+
+    fn capture(&mut self, name: &str, text: &'a str) -> bool {
+        if let Some(captured) = self.captures.get(name) {
+            *captured == text
+        } else {
+            self.captures.insert(name.to_string(), text);
+            true
+        }
+    }
+
+    fn expression(&mut self, pattern: &Expression<'_>, node: &Expression<'a>) -> bool {
+        let (pattern, node) = (pattern.without_parentheses(), node.without_parentheses());
+
+        if let Expression::Identifier(ident) = pattern {
+            if let Some(var) = Metavariable::parse(&ident.name) {
+                if !var.multiple {
+                    return self.capture(var.name, node.span().source_text(self.source_text));
+                }
+            }
+        }
+
+        if let (Some(pattern), Some(node)) =
+            (pattern.as_member_expression(), node.as_member_expression())
+        {
+            return self.member_expression(pattern, node);
+        }
+
+        match (pattern, node) {
+            (Expression::Identifier(pattern), Expression::Identifier(node)) => {
+                pattern.name == node.name
+            }
+            (Expression::CallExpression(pattern), Expression::CallExpression(node)) => {
+                self.call_expression(pattern, node)
+            }
+            (Expression::NewExpression(pattern), Expression::NewExpression(node)) => {
+                self.expression(&pattern.callee, &node.callee)
+                    && self.list(&pattern.arguments, &node.arguments, Self::argument)
+            }
+            (Expression::ChainExpression(pattern), Expression::ChainExpression(node)) => {
+                match (&pattern.expression, &node.expression) {
+                    (ChainElement::CallExpression(pattern), ChainElement::CallExpression(node)) => {
+                        self.call_expression(pattern, node)
+                    }
+                    (pattern, node) => {
+                        match (pattern.as_member_expression(), node.as_member_expression()) {
+                            (Some(pattern), Some(node)) => self.member_expression(pattern, node),
+                            _ => false,
+                        }
+                    }
+                }
+            }
+            (Expression::UnaryExpression(pattern), Expression::UnaryExpression(node)) => {
+                pattern.operator == node.operator
+                    && self.expression(&pattern.argument, &node.argument)
+            }
+            (Expression::BinaryExpression(pattern), Expression::BinaryExpression(node)) => {
+                pattern.operator == node.operator
+                    && self.expression(&pattern.left, &node.left)
+                    && self.expression(&pattern.right, &node.right)
+            }
+            (Expression::LogicalExpression(pattern), Expression::LogicalExpression(node)) => {
+                pattern.operator == node.operator
+                    && self.expression(&pattern.left, &node.left)
+                    && self.expression(&pattern.right, &node.right)
+            }
+            (
+                Expression::ConditionalExpression(pattern),
+                Expression::ConditionalExpression(node),
+            ) => {
+                self.expression(&pattern.test, &node.test)
+                    && self.expression(&pattern.consequent, &node.consequent)
+                    && self.expression(&pattern.alternate, &node.alternate)
+            }
+            (Expression::AwaitExpression(pattern), Expression::AwaitExpression(node)) => {
+                self.expression(&pattern.argument, &node.argument)
+            }
+            (Expression::ArrayExpression(pattern), Expression::ArrayExpression(node)) => {
+                self.list(&pattern.elements, &node.elements, Self::array_element)
+            }
+            (Expression::SequenceExpression(pattern), Expression::SequenceExpression(node)) => {
+                self.list(&pattern.expressions, &node.expressions, Self::expression)
+            }
+            // Other expressions can't contain metavariables, so compare them as is
+            (pattern, node) => ContentEq::content_eq(pattern, node),
+        }
+    }
+
+    fn member_expression(
+        &mut self,
+        pattern: &MemberExpression<'_>,
+        node: &MemberExpression<'a>,
+    ) -> bool {
+        if pattern.optional() != node.optional() {
+            return false;
+        }
+        match (pattern, node) {
+            (
+                MemberExpression::StaticMemberExpression(pattern),
+                MemberExpression::StaticMemberExpression(node),
+            ) => {
+                self.property_name(&pattern.property, &node.property)
+                    && self.expression(&pattern.object, &node.object)
+            }
+            (
+                MemberExpression::ComputedMemberExpression(pattern),
+                MemberExpression::ComputedMemberExpression(node),
+            ) => {
+                self.expression(&pattern.object, &node.object)
+                    && self.expression(&pattern.expression, &node.expression)
+            }
+            (
+                MemberExpression::PrivateFieldExpression(pattern),
+                MemberExpression::PrivateFieldExpression(node),
+            ) => {
+                pattern.field.name == node.field.name
+                    && self.expression(&pattern.object, &node.object)
+            }
+            _ => false,
+        }
+    }
+
+    fn property_name(&mut self, pattern: &IdentifierName<'_>, node: &IdentifierName<'a>) -> bool {
+        match Metavariable::parse(&pattern.name) {
+            Some(var) if !var.multiple => {
+                self.capture(var.name, node.span.source_text(self.source_text))
+            }
+            _ => pattern.name == node.name,
+        }
+    }
+
+    fn call_expression(&mut self, pattern: &CallExpression<'_>, node: &CallExpression<'a>) -> bool {
+        pattern.optional == node.optional
+            && self.expression(&pattern.callee, &node.callee)
+            && self.list(&pattern.arguments, &node.arguments, Self::argument)
+    }
+
+    fn argument(&mut self, pattern: &Argument<'_>, node: &Argument<'a>) -> bool {
+        match (pattern, node) {
+            (Argument::SpreadElement(pattern), Argument::SpreadElement(node)) => {
+                self.expression(&pattern.argument, &node.argument)
+            }
+            (Argument::SpreadElement(_), _) | (_, Argument::SpreadElement(_)) => false,
+            _ => self.expression(pattern.to_expression(), node.to_expression()),
+        }
+    }
+
+    fn array_element(
+        &mut self,
+        pattern: &ArrayExpressionElement<'_>,
+        node: &ArrayExpressionElement<'a>,
+    ) -> bool {
+        match (pattern, node) {
+            (
+                ArrayExpressionElement::SpreadElement(pattern),
+                ArrayExpressionElement::SpreadElement(node),
+            ) => self.expression(&pattern.argument, &node.argument),
+            (ArrayExpressionElement::Elision(_), ArrayExpressionElement::Elision(_)) => true,
+            (ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_), _)
+            | (_, ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_)) => {
+                false
+            }
+            _ => self.expression(pattern.to_expression(), node.to_expression()),
+        }
+    }
+
+    /// Match a list of nodes, where the pattern may contain a `$$$NAME` metavariable.
+    fn list<P, N>(
+        &mut self,
+        patterns: &[P],
+        nodes: &[N],
+        item: fn(&mut Self, &P, &N) -> bool,
+    ) -> bool
+    where
+        P: ListItem,
+        N: GetSpan,
+    {
+        let Some(index) =
+            patterns.iter().position(|pattern| pattern.multiple_metavariable().is_some())
+        else {
+            return patterns.len() == nodes.len()
+                && patterns.iter().zip(nodes).all(|(pattern, node)| item(self, pattern, node));
+        };
+
+        let (before, after) = (&patterns[..index], &patterns[index + 1..]);
+        if nodes.len() < before.len() + after.len() {
+            return false;
+        }
+        let after_start = nodes.len() - after.len();
+        let captured = &nodes[before.len()..after_start];
+        let text = match (captured.first(), captured.last()) {
+            (Some(first), Some(last)) => {
+                &self.source_text[first.span().start as usize..last.span().end as usize]
+            }
+            _ => "",
+        };
+        let name = patterns[index].multiple_metavariable().unwrap();
+
+        before.iter().zip(nodes).all(|(pattern, node)| item(self, pattern, node))
+            && after
+                .iter()
+                .zip(&nodes[after_start..])
+                .all(|(pattern, node)| item(self, pattern, node))
+            && self.capture(name, text)
+    }
+}
+
+/// An item in a list which may be a `$$$NAME` metavariable.
+trait ListItem {
+    fn multiple_metavariable(&self) -> Option<&str>;
+}
+
+fn multiple_metavariable<'n>(ident: &'n IdentifierReference<'_>) -> Option<&'n str> {
+    Metavariable::parse(&ident.name).filter(|var| var.multiple).map(|var| var.name)
+}
+
+impl<'p> ListItem for Expression<'p> {
+    fn multiple_metavariable(&self) -> Option<&str> {
+        match self {
+            Expression::Identifier(ident) => multiple_metavariable(ident),
+            _ => None,
+        }
+    }
+}
+
+impl<'p> ListItem for Argument<'p> {
+    fn multiple_metavariable(&self) -> Option<&str> {
+        match self {
+            Argument::Identifier(ident) => multiple_metavariable(ident),
+            _ => None,
+        }
+    }
+}
+
+impl<'p> ListItem for ArrayExpressionElement<'p> {
+    fn multiple_metavariable(&self) -> Option<&str> {
+        match self {
+            ArrayExpressionElement::Identifier(ident) => multiple_metavariable(ident),
+            _ => None,
+        }
+    }
+}
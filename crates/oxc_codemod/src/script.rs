@@ -0,0 +1,154 @@
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+use serde::Deserialize;
+
+use crate::{apply_edits, Edit, Pattern};
+
+/// A list of codemod rules, usually loaded from a TOML file:
+///
+/// ```toml
+/// [[rule]]
+/// name = "console-log-to-logger"
+/// pattern = "console.log($$$ARGS)"
+/// replace = "logger.debug($$$ARGS)"
+///
+/// # Rules without `replace` only report matches
+/// [[rule]]
+/// name = "no-eval"
+/// pattern = "eval($CODE)"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Script {
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub name: String,
+    pub pattern: Pattern,
+    /// Replacement for matched expressions, which may contain the pattern's metavariables.
+    pub replace: Option<String>,
+}
+
+/// Result of running a [`Script`] on a file.
+#[derive(Debug)]
+pub struct CodemodReturn {
+    pub source_text: String,
+    /// Matches of rules without `replace`, in the returned source text.
+    pub reports: Vec<Report>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub rule: String,
+    pub span: Span,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScriptConfig {
+    #[serde(default, rename = "rule")]
+    rules: Vec<RuleConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConfig {
+    name: String,
+    pattern: String,
+    replace: Option<String>,
+}
+
+impl Script {
+    /// # Errors
+    ///
+    /// * The script is not valid TOML, or has unknown fields.
+    /// * A rule's pattern is not a valid expression.
+    pub fn from_toml(source_text: &str) -> Result<Self, OxcDiagnostic> {
+        let config: ScriptConfig = toml::from_str(source_text)
+            .map_err(|err| OxcDiagnostic::error(format!("Invalid codemod script: {err}")))?;
+        let rules = config
+            .rules
+            .into_iter()
+            .map(|RuleConfig { name, pattern, replace }| {
+                let pattern = Pattern::new(&pattern).map_err(|errors| {
+                    let message = errors.first().map(ToString::to_string).unwrap_or_default();
+                    OxcDiagnostic::error(format!("Invalid pattern in rule `{name}`: {message}"))
+                })?;
+                Ok(Rule { name, pattern, replace })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Run the script on a file.
+    ///
+    /// Rules with `replace` are applied one after another, each to the output of the previous one.
+    /// Rules without `replace` are then matched against the final source text.
+    ///
+    /// # Errors
+    ///
+    /// * `source_text` has syntax errors.
+    /// * A rule's replacement produced invalid code.
+    pub fn run(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Result<CodemodReturn, Vec<OxcDiagnostic>> {
+        let mut source_text = source_text.to_string();
+        let mut previous_rule: Option<&str> = None;
+
+        for rule in &self.rules {
+            let Some(replace) = &rule.replace else { continue };
+            let edits = {
+                let allocator = Allocator::default();
+                let ret = Parser::new(&allocator, &source_text, source_type).parse();
+                if !ret.errors.is_empty() {
+                    return Err(Self::parse_errors(previous_rule, ret.errors));
+                }
+                rule.pattern
+                    .find(&ret.program, &source_text)
+                    .iter()
+                    .map(|m| Edit::new(m.span, m.substitute(replace)))
+                    .collect::<Vec<_>>()
+            };
+            if edits.is_empty() {
+                continue;
+            }
+            source_text = apply_edits(&source_text, edits);
+            previous_rule = Some(rule.name.as_str());
+        }
+
+        let reports = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            if !ret.errors.is_empty() {
+                return Err(Self::parse_errors(previous_rule, ret.errors));
+            }
+            self.rules
+                .iter()
+                .filter(|rule| rule.replace.is_none())
+                .flat_map(|rule| {
+                    rule.pattern
+                        .find(&ret.program, &source_text)
+                        .into_iter()
+                        .map(move |m| Report { rule: rule.name.clone(), span: m.span })
+                })
+                .collect()
+        };
+
+        Ok(CodemodReturn { source_text, reports })
+    }
+
+    fn parse_errors(rule: Option<&str>, errors: Vec<OxcDiagnostic>) -> Vec<OxcDiagnostic> {
+        match rule {
+            Some(rule) => {
+                vec![OxcDiagnostic::error(format!("Codemod rule `{rule}` produced invalid code"))
+                    .with_help(errors.first().map(ToString::to_string).unwrap_or_default())]
+            }
+            None => errors,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use oxc_allocator::Allocator;
+use oxc_codemod::{apply_edits, rewrite_ast, unified_diff, Edit, Pattern, Script};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+fn find(pattern: &str, source_text: &str) -> Vec<(String, Vec<(String, String)>)> {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let pattern = Pattern::new(pattern).unwrap();
+    pattern
+        .find(&program, source_text)
+        .into_iter()
+        .map(|m| {
+            let mut captures = m
+                .captures
+                .iter()
+                .map(|(name, text)| (name.clone(), (*text).to_string()))
+                .collect::<Vec<_>>();
+            captures.sort();
+            (m.span.source_text(source_text).to_string(), captures)
+        })
+        .collect()
+}
+
+fn run(script: &str, source_text: &str) -> String {
+    let script = Script::from_toml(script).unwrap();
+    script.run(source_text, SourceType::default()).unwrap().source_text
+}
+
+#[test]
+fn pattern() {
+    assert_eq!(
+        find("console.log($A)", "console.log(x); console.log(y, z); console.warn(x);"),
+        [("console.log(x)".to_string(), vec![("A".to_string(), "x".to_string())])]
+    );
+    assert_eq!(
+        find("a.b", "a.b; a?.b; a[b]; (a).b; a.b.c"),
+        ["a.b", "(a).b", "a.b"].map(|s| (s.to_string(), vec![]))
+    );
+    assert_eq!(find("foo(1, 'x')", "foo(1, \"x\"); foo( 1 , 'x' ); foo(2, 'x')").len(), 2);
+    assert_eq!(find("$A === $A", "x === x; x === y").len(), 1);
+    assert_eq!(find("$A ? $B : $C", "a ? b : c").len(), 1);
+    assert_eq!(
+        find("console.$METHOD($$$ARGS)", "console.log(1); console.error(); foo.log(1)"),
+        [
+            (
+                "console.log(1)".to_string(),
+                vec![
+                    ("ARGS".to_string(), "1".to_string()),
+                    ("METHOD".to_string(), "log".to_string())
+                ]
+            ),
+            (
+                "console.error()".to_string(),
+                vec![
+                    ("ARGS".to_string(), String::new()),
+                    ("METHOD".to_string(), "error".to_string())
+                ]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn pattern_multiple() {
+    assert_eq!(
+        find("f($A, $$$REST)", "f(); f(1); f(1, 2, 3)"),
+        [
+            (
+                "f(1)".to_string(),
+                vec![("A".to_string(), "1".to_string()), ("REST".to_string(), String::new())]
+            ),
+            (
+                "f(1, 2, 3)".to_string(),
+                vec![("A".to_string(), "1".to_string()), ("REST".to_string(), "2, 3".to_string())]
+            ),
+        ]
+    );
+    assert_eq!(find("[$$$X, last]", "[last]; [1, 2, last]; [1, 2]").len(), 2);
+}
+
+#[test]
+fn invalid_pattern() {
+    assert!(Pattern::new("console.log(").is_err());
+    assert!(Script::from_toml("[[rule]]\nname = 'x'\npattern = 'a +'").is_err());
+    assert!(Script::from_toml("[[rule]]\nname = 'x'\npattern = 'a'\nunknown = 1").is_err());
+}
+
+#[test]
+fn script() {
+    let script = r#"
+        [[rule]]
+        name = "console-log-to-logger"
+        pattern = "console.log($$$ARGS)"
+        replace = "logger.debug($$$ARGS)"
+
+        [[rule]]
+        name = "double-negation"
+        pattern = "!!$A"
+        replace = "Boolean($A)"
+    "#;
+    assert_eq!(
+        run(script, "// keep\nconsole.log(!!a, b);\nconsole.log( /* empty */ );\n"),
+        "// keep\nlogger.debug(Boolean(a), b);\nlogger.debug();\n"
+    );
+
+    let ret = Script::from_toml("[[rule]]\nname = 'no-eval'\npattern = 'eval($CODE)'")
+        .unwrap()
+        .run("let x = eval('1');", SourceType::default())
+        .unwrap();
+    assert_eq!(ret.source_text, "let x = eval('1');");
+    assert_eq!(ret.reports.len(), 1);
+    assert_eq!(ret.reports[0].rule, "no-eval");
+    assert_eq!(ret.reports[0].span, Span::new(8, 17));
+}
+
+#[test]
+fn invalid_replacement() {
+    let script = "[[rule]]\nname = 'broken'\npattern = 'a'\nreplace = 'a +'";
+    let errors = Script::from_toml(script).unwrap().run("a;", SourceType::default()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "Codemod rule `broken` produced invalid code");
+}
+
+#[test]
+fn edits() {
+    let edits = vec![
+        Edit::new(Span::new(4, 5), "y"),
+        Edit::new(Span::new(0, 3), "let"),
+        // Overlaps the previous edit
+        Edit::new(Span::new(2, 5), "z"),
+    ];
+    assert_eq!(apply_edits("var x = 1;", edits), "let y = 1;");
+}
+
+#[test]
+fn ast() {
+    let output = rewrite_ast("let a = 1;", SourceType::default(), |program, ast| {
+        program.body.push(ast.statement_empty(oxc_span::SPAN));
+    })
+    .unwrap();
+    assert_eq!(output, "let a = 1;\n;\n");
+}
+
+#[test]
+fn diff() {
+    assert_eq!(unified_diff("a.js", "a\n", "a\n"), "");
+    assert_eq!(
+        unified_diff("a.js", "a\nb\n", "a\nc\n"),
+        "--- a/a.js\n+++ b/a.js\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n"
+    );
+}
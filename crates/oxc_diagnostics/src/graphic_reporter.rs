@@ -380,22 +380,35 @@ impl GraphicalReportHandler {
         parent_src: Option<&dyn SourceCode>,
     ) -> fmt::Result {
         if let Some(related) = diagnostic.related() {
-            let mut inner_renderer = self.clone();
-            // Re-enable the printing of nested cause chains for related errors
-            inner_renderer.with_cause_chain = true;
-            writeln!(f)?;
+            let (notes, related): (Vec<_>, Vec<_>) = related.partition(|rel| {
+                rel.severity() == Some(Severity::Advice)
+                    && rel.labels().map_or(true, |mut labels| labels.next().is_none())
+            });
+            // Notes are rendered like the help message, directly below it.
+            for note in notes {
+                let width = self.termwidth.saturating_sub(4);
+                let initial_indent = "  note: ".style(self.theme.styles.help).to_string();
+                let mut opts = textwrap::Options::new(width)
+                    .initial_indent(&initial_indent)
+                    .subsequent_indent("        ")
+                    .break_words(self.break_words);
+                if let Some(word_separator) = self.word_separator {
+                    opts = opts.word_separator(word_separator);
+                }
+                if let Some(word_splitter) = self.word_splitter.clone() {
+                    opts = opts.word_splitter(word_splitter);
+                }
+                writeln!(f, "{}", self.wrap(&note.to_string(), opts))?;
+            }
+            // Related diagnostics are rendered in the same layout as the parent diagnostic,
+            // with their labels resolved against the parent source unless they have their own.
             for rel in related {
-                match rel.severity() {
-                    Some(Severity::Error) | None => write!(f, "Error: ")?,
-                    Some(Severity::Warning) => write!(f, "Warning: ")?,
-                    Some(Severity::Advice) => write!(f, "Advice: ")?,
-                };
-                inner_renderer.render_header(f, rel)?;
-                inner_renderer.render_causes(f, rel)?;
+                writeln!(f)?;
+                self.render_causes(f, rel)?;
                 let src = rel.source_code().or(parent_src);
-                inner_renderer.render_snippets(f, rel, src)?;
-                inner_renderer.render_footer(f, rel)?;
-                inner_renderer.render_related(f, rel, src)?;
+                self.render_snippets(f, rel, src)?;
+                self.render_footer(f, rel)?;
+                self.render_related(f, rel, src)?;
             }
         }
         Ok(())
@@ -1321,3 +1334,36 @@ impl FancySpan {
         self.span.len()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use miette::NamedSource;
+
+    use super::GraphicalReportHandler;
+    use crate::{GraphicalTheme, LabeledSpan, OxcDiagnostic};
+
+    #[test]
+    fn render_related_and_notes() {
+        let source = "let a = 1;\nlet a = 2;\n";
+        let diagnostic = OxcDiagnostic::error("Identifier `a` has already been declared")
+            .with_label(LabeledSpan::new_with_span(Some("redeclared here".into()), (15, 1)))
+            .with_help("Rename one of the variables")
+            .and_related(
+                OxcDiagnostic::advice("`a` was first declared here")
+                    .with_label(LabeledSpan::new_with_span(None, (4, 1))),
+            )
+            .and_note("`let` declarations cannot be redeclared in the same scope");
+        let error = diagnostic.with_source_code(NamedSource::new("test.js", source));
+
+        let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
+        let mut output = String::new();
+        handler.render_report(&mut output, error.as_ref()).unwrap();
+
+        let help = output.find("help: Rename one of the variables").unwrap();
+        let note = output.find("note: `let` declarations cannot be redeclared").unwrap();
+        let related = output.find("☞ `a` was first declared here").unwrap();
+        assert!(help < note && note < related, "{output}");
+        assert!(output.contains("╭─[test.js:2:5]"), "{output}");
+        assert!(output.contains("╭─[test.js:1:5]"), "{output}");
+    }
+}
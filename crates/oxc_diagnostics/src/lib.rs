@@ -15,6 +15,7 @@ use std::{
 pub use crate::{
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
+    reporter::{ColorTheme, GraphicalReporterOptions},
     service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
 };
 
@@ -24,7 +25,7 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{LabeledSpan, NamedSource};
+pub use miette::{LabeledSpan, NamedSource, SourceSpan};
 
 #[derive(Debug, Clone)]
 #[must_use]
@@ -70,6 +71,13 @@ impl fmt::Display for OxcCode {
     }
 }
 
+/// A replacement of the source text covered by `span`, which fixes the diagnostic.
+#[derive(Debug, Clone)]
+pub struct OxcFix {
+    pub span: SourceSpan,
+    pub content: Cow<'static, str>,
+}
+
 #[derive(Debug, Clone)]
 pub struct OxcDiagnosticInner {
     pub message: Cow<'static, str>,
@@ -78,6 +86,11 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Secondary diagnostics pointing at other locations, such as "first declared here",
+    /// and notes without labels.
+    pub related: Option<Vec<OxcDiagnostic>>,
+    /// A fix which was not applied, e.g. because it is unsafe or conflicts with another fix.
+    pub fix: Option<OxcFix>,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -112,6 +125,14 @@ impl Diagnostic for OxcDiagnostic {
     fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
         self.url.as_ref().map(Box::new).map(|c| c as Box<dyn Display>)
     }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.related
+            .as_ref()
+            .map(|related| related.iter().map(|d| d as &dyn Diagnostic))
+            .map(Box::new)
+            .map(|b| b as Box<dyn Iterator<Item = &'a dyn Diagnostic>>)
+    }
 }
 
 impl OxcDiagnostic {
@@ -124,6 +145,8 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                related: None,
+                fix: None,
             }),
         }
     }
@@ -137,10 +160,17 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                related: None,
+                fix: None,
             }),
         }
     }
 
+    /// Secondary information, e.g. for [`OxcDiagnostic::and_related`].
+    pub fn advice<T: Into<Cow<'static, str>>>(message: T) -> Self {
+        Self::error(message).with_severity(Severity::Advice)
+    }
+
     #[inline]
     pub fn with_error_code<T: Into<Cow<'static, str>>, U: Into<Cow<'static, str>>>(
         self,
@@ -218,12 +248,148 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Attach secondary diagnostics, replacing the existing ones.
+    /// See [`OxcDiagnostic::and_related`].
+    pub fn with_related<T: IntoIterator<Item = OxcDiagnostic>>(mut self, related: T) -> Self {
+        self.inner.related = Some(related.into_iter().collect());
+        self
+    }
+
+    /// Attach a secondary diagnostic, usually an [`OxcDiagnostic::advice`] labeling another
+    /// location of the same source, such as where a redeclared variable was first declared.
+    pub fn and_related(mut self, related: OxcDiagnostic) -> Self {
+        let mut all_related = self.inner.related.unwrap_or_default();
+        all_related.push(related);
+        self.inner.related = Some(all_related);
+        self
+    }
+
+    /// Attach a note, which is rendered as `note: ...` after the help message.
+    pub fn and_note<T: Into<Cow<'static, str>>>(self, note: T) -> Self {
+        self.and_related(Self::advice(note))
+    }
+
     pub fn with_url<S: Into<Cow<'static, str>>>(mut self, url: S) -> Self {
         self.inner.url = Some(url.into());
         self
     }
 
+    pub fn with_fix(mut self, fix: OxcFix) -> Self {
+        self.inner.fix = Some(fix);
+        self
+    }
+
+    /// Move the labels, related diagnostics and fix by `offset` bytes, e.g. for diagnostics of
+    /// a script embedded in a larger file.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        if offset == 0 {
+            return self;
+        }
+        for label in self.inner.labels.iter_mut().flatten() {
+            let text = label.label().map(ToString::to_string);
+            let span = SourceSpan::new((label.offset() + offset).into(), label.len());
+            *label = if label.primary() {
+                LabeledSpan::new_primary_with_span(text, span)
+            } else {
+                LabeledSpan::new_with_span(text, span)
+            };
+        }
+        if let Some(related) = self.inner.related.take() {
+            self.inner.related =
+                Some(related.into_iter().map(|related| related.with_offset(offset)).collect());
+        }
+        if let Some(fix) = &mut self.inner.fix {
+            fix.span = SourceSpan::new((fix.span.offset() + offset).into(), fix.span.len());
+        }
+        self
+    }
+
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from, either by [`Error::from`] or
+    /// [`OxcDiagnostic::with_source_code`].
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<WithSourceCode>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
+    }
+}
+
+/// Same as miette's `WithSourceCode`, but allows downcasting an [`Error`] back to the
+/// [`OxcDiagnostic`].
+struct WithSourceCode {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl fmt::Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use miette::Diagnostic;
+
+    use crate::{LabeledSpan, OxcDiagnostic, OxcFix, SourceSpan};
+
+    #[test]
+    fn with_offset() {
+        let diagnostic = OxcDiagnostic::error("error")
+            .with_labels([
+                LabeledSpan::new_primary_with_span(Some("here".into()), (1, 2)),
+                LabeledSpan::new_with_span(None, (5, 1)),
+            ])
+            .with_fix(OxcFix { span: SourceSpan::new(1.into(), 2), content: Cow::Borrowed("") })
+            .with_offset(10);
+
+        let labels = diagnostic.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(labels[0], LabeledSpan::new_primary_with_span(Some("here".into()), (11, 2)));
+        assert_eq!(labels[1], LabeledSpan::new_with_span(None, (15, 1)));
+        assert_eq!(diagnostic.fix.as_ref().unwrap().span, SourceSpan::new(11.into(), 2));
     }
 }
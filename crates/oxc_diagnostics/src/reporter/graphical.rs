@@ -1,7 +1,61 @@
-use std::io::{BufWriter, ErrorKind, Stdout, Write};
+use std::{
+    io::{BufWriter, ErrorKind, Stdout, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use miette::ThemeCharacters;
 
 use super::{writer, DiagnosticReporter};
-use crate::{Error, GraphicalReportHandler};
+use crate::{graphical_theme::ThemeStyles, Error, GraphicalReportHandler, GraphicalTheme};
+
+/// Options of the [`GraphicalReporter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphicalReporterOptions {
+    /// Number of lines of source code shown before and after each label.
+    pub context_lines: usize,
+
+    /// Draw with ASCII characters instead of unicode box drawing characters, e.g. for CI logs.
+    pub ascii: bool,
+
+    pub theme: ColorTheme,
+}
+
+impl Default for GraphicalReporterOptions {
+    fn default() -> Self {
+        Self { context_lines: 1, ascii: false, theme: ColorTheme::Auto }
+    }
+}
+
+/// Colors of the [`GraphicalReporter`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorTheme {
+    /// RGB colors when writing to a terminal, no colors when `NO_COLOR` is set.
+    ///
+    /// ASCII characters are drawn when not writing to a terminal.
+    #[default]
+    Auto,
+    /// RGB colors.
+    Rgb,
+    /// The 16 ANSI colors, which follow the color scheme of the terminal.
+    Ansi,
+    /// No colors.
+    None,
+}
+
+impl FromStr for ColorTheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "rgb" => Ok(Self::Rgb),
+            "ansi" => Ok(Self::Ansi),
+            "none" => Ok(Self::None),
+            _ => Err(format!("'{s}' is not a known theme")),
+        }
+    }
+}
 
 pub struct GraphicalReporter {
     handler: GraphicalReportHandler,
@@ -14,6 +68,27 @@ impl Default for GraphicalReporter {
     }
 }
 
+impl GraphicalReporter {
+    pub fn new(options: GraphicalReporterOptions) -> Self {
+        let mut theme = match options.theme {
+            ColorTheme::Auto => GraphicalTheme::default(),
+            ColorTheme::Rgb => GraphicalTheme::unicode(),
+            ColorTheme::Ansi => GraphicalTheme {
+                characters: ThemeCharacters::unicode(),
+                styles: ThemeStyles::ansi(),
+            },
+            ColorTheme::None => GraphicalTheme::unicode_nocolor(),
+        };
+        if options.ascii {
+            theme.characters = ThemeCharacters::ascii();
+        }
+        let handler = GraphicalReportHandler::new()
+            .with_theme(theme)
+            .with_context_lines(options.context_lines);
+        Self { handler, writer: writer() }
+    }
+}
+
 impl DiagnosticReporter for GraphicalReporter {
     fn finish(&mut self) {
         self.writer
@@ -48,4 +123,54 @@ impl DiagnosticReporter for GraphicalReporter {
         self.handler.render_report(&mut output, error.as_ref()).unwrap();
         Some(output)
     }
+
+    fn render_file_summary(
+        &mut self,
+        path: &Path,
+        warnings_count: usize,
+        errors_count: usize,
+    ) -> Option<String> {
+        Some(format!(
+            "\n{}: {warnings_count} warning{} and {errors_count} error{}.\n",
+            path.display(),
+            if warnings_count == 1 { "" } else { "s" },
+            if errors_count == 1 { "" } else { "s" }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use miette::NamedSource;
+
+    use super::{ColorTheme, GraphicalReporter, GraphicalReporterOptions};
+    use crate::{reporter::DiagnosticReporter, LabeledSpan, OxcDiagnostic};
+
+    fn render(options: GraphicalReporterOptions) -> String {
+        let source = "a;\nb;\ndebugger;\nc;\nd;\n";
+        let error = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_label(LabeledSpan::new_with_span(None, (6, 8)))
+            .with_source_code(NamedSource::new("test.js", source));
+        GraphicalReporter::new(options).render_error(error).unwrap()
+    }
+
+    #[test]
+    fn options() {
+        let options = GraphicalReporterOptions { theme: ColorTheme::None, ..Default::default() };
+        let output = render(options);
+        assert!(output.contains("╭─[test.js:3:1]"), "{output}");
+        assert!(output.contains("│ b;") && output.contains("│ c;"), "{output}");
+        assert!(!output.contains("a;") && !output.contains("d;"), "{output}");
+
+        let output = render(GraphicalReporterOptions { ascii: true, context_lines: 2, ..options });
+        assert!(output.contains(",-[test.js:3:1]"), "{output}");
+        assert!(output.contains("| a;") && output.contains("| d;"), "{output}");
+        assert!(!output.contains('│'), "{output}");
+
+        let output = render(GraphicalReporterOptions { context_lines: 0, ..options });
+        assert!(!output.contains("b;") && !output.contains("c;"), "{output}");
+
+        let output = render(GraphicalReporterOptions { theme: ColorTheme::Ansi, ..options });
+        assert!(output.contains('\u{1b}'), "{output}");
+    }
 }
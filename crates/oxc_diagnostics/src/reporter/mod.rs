@@ -2,13 +2,21 @@ mod checkstyle;
 mod github;
 mod graphical;
 mod json;
+mod ndjson;
 mod unix;
 
-use std::io::{BufWriter, Stdout};
+use std::{
+    io::{BufWriter, Stdout},
+    path::Path,
+};
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    graphical::{ColorTheme, GraphicalReporter, GraphicalReporterOptions},
+    json::JsonReporter,
+    ndjson::NdjsonReporter,
+    unix::UnixReporter,
 };
 use crate::{Error, Severity};
 
@@ -22,6 +30,15 @@ pub trait DiagnosticReporter {
     fn finish(&mut self);
     fn render_diagnostics(&mut self, s: &[u8]);
     fn render_error(&mut self, error: Error) -> Option<String>;
+    /// Rendered after the diagnostics of each file which has any output.
+    fn render_file_summary(
+        &mut self,
+        _path: &Path,
+        _warnings_count: usize,
+        _errors_count: usize,
+    ) -> Option<String> {
+        None
+    }
 }
 
 struct Info {
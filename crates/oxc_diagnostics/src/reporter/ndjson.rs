@@ -0,0 +1,168 @@
+use std::io::{BufWriter, Stdout, Write};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode, SourceSpan};
+use serde_json::{json, Value};
+
+use super::{writer, DiagnosticReporter};
+use crate::{Error, OxcDiagnostic, OxcFix, Severity};
+
+/// Newline delimited JSON, one object per diagnostic.
+///
+/// Diagnostics are written as soon as a file finishes, so consumers can process them
+/// without waiting for the whole run.
+pub struct NdjsonReporter {
+    writer: BufWriter<Stdout>,
+}
+
+impl Default for NdjsonReporter {
+    fn default() -> Self {
+        Self { writer: writer() }
+    }
+}
+
+impl DiagnosticReporter for NdjsonReporter {
+    fn finish(&mut self) {
+        self.writer.flush().unwrap();
+    }
+
+    fn render_diagnostics(&mut self, s: &[u8]) {
+        self.writer.write_all(s).unwrap();
+        self.writer.flush().unwrap();
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        Some(format_ndjson(&error))
+    }
+}
+
+fn format_ndjson(error: &Error) -> String {
+    let fix = OxcDiagnostic::from_error(error).and_then(|diagnostic| diagnostic.fix.as_ref());
+    let object = format_diagnostic(error.as_ref(), None, fix);
+    format!("{object}\n")
+}
+
+/// Related diagnostics are written as nested records of the same shape, falling back to the
+/// source code of the diagnostic they belong to.
+fn format_diagnostic(
+    diagnostic: &dyn Diagnostic,
+    parent_source: Option<&dyn SourceCode>,
+    fix: Option<&OxcFix>,
+) -> Value {
+    let source = diagnostic.source_code().or(parent_source);
+    let filename = source
+        .zip(diagnostic.labels().and_then(|mut labels| labels.next()))
+        .and_then(|(source, label)| source.read_span(label.inner(), 0, 0).ok())
+        .and_then(|span| span.name().map(ToString::to_string));
+    let severity = match diagnostic.severity() {
+        Some(Severity::Error) | None => "error",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "advice",
+    };
+    let labels = diagnostic
+        .labels()
+        .map(|labels| labels.map(|label| format_label(&label, source)).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let fix =
+        fix.map(|fix| json!({ "span": format_span(fix.span, source), "content": fix.content }));
+    let related = diagnostic
+        .related()
+        .map(|related| {
+            related.map(|related| format_diagnostic(related, source, None)).collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    json!({
+        "filename": filename,
+        "severity": severity,
+        "code": diagnostic.code().map(|code| code.to_string()),
+        "message": diagnostic.to_string(),
+        "help": diagnostic.help().map(|help| help.to_string()),
+        "url": diagnostic.url().map(|url| url.to_string()),
+        "labels": labels,
+        "fix": fix,
+        "related": related,
+    })
+}
+
+fn format_label(label: &LabeledSpan, source: Option<&dyn SourceCode>) -> Value {
+    json!({ "label": label.label(), "span": format_span(*label.inner(), source) })
+}
+
+/// Offsets are in bytes, lines and columns are 1-based.
+fn format_span(span: SourceSpan, source: Option<&dyn SourceCode>) -> Value {
+    let (line, column) = source
+        .and_then(|source| source.read_span(&span, 0, 0).ok())
+        .map_or((None, None), |content| (Some(content.line() + 1), Some(content.column() + 1)));
+    json!({
+        "offset": span.offset(),
+        "length": span.len(),
+        "line": line,
+        "column": column,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use miette::{NamedSource, SourceSpan};
+    use serde_json::{json, Value};
+
+    use super::format_ndjson;
+    use crate::{LabeledSpan, OxcDiagnostic, OxcFix};
+
+    #[test]
+    fn format() {
+        let error = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(LabeledSpan::new_with_span(None, (11, 8)))
+            .with_fix(OxcFix { span: SourceSpan::new(11.into(), 9), content: Cow::Borrowed("") })
+            .with_source_code(NamedSource::new("test.js", "let a = 1;\ndebugger;\n"));
+
+        let output = format_ndjson(&error);
+        assert!(output.ends_with('\n') && output.lines().count() == 1);
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "filename": "test.js",
+                "severity": "warning",
+                "code": "eslint(no-debugger)",
+                "message": "`debugger` statement is not allowed",
+                "help": null,
+                "url": null,
+                "labels": [{ "label": null, "span": { "offset": 11, "length": 8, "line": 2, "column": 1 } }],
+                "fix": { "span": { "offset": 11, "length": 9, "line": 2, "column": 1 }, "content": "" },
+                "related": [],
+            })
+        );
+    }
+
+    #[test]
+    fn format_related() {
+        let error = OxcDiagnostic::error("Identifier `a` has already been declared")
+            .with_label(LabeledSpan::new_with_span(Some("redeclared here".into()), (15, 1)))
+            .and_related(
+                OxcDiagnostic::advice("`a` was first declared here")
+                    .with_label(LabeledSpan::new_with_span(None, (4, 1))),
+            )
+            .with_source_code(NamedSource::new("test.js", "let a = 1;\nlet a = 2;\n"));
+
+        let output = format_ndjson(&error);
+        assert!(output.ends_with('\n') && output.lines().count() == 1);
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            value["related"],
+            json!([{
+                "filename": "test.js",
+                "severity": "advice",
+                "code": null,
+                "message": "`a` was first declared here",
+                "help": null,
+                "url": null,
+                "labels": [{ "label": null, "span": { "offset": 4, "length": 1, "line": 1, "column": 5 } }],
+                "fix": null,
+                "related": [],
+            }])
+        );
+    }
+}
@@ -1,13 +1,15 @@
 use std::{
     cell::Cell,
+    collections::HashSet,
+    fmt::Write,
     path::{Path, PathBuf},
     sync::{mpsc, Arc},
 };
 
 use crate::{
     reporter::{
-        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter,
+        GraphicalReporterOptions, JsonReporter, NdjsonReporter, UnixReporter,
     },
     Error, NamedSource, OxcDiagnostic, Severity,
 };
@@ -56,10 +58,18 @@ impl Default for DiagnosticService {
 }
 
 impl DiagnosticService {
+    pub fn set_graphical_reporter(&mut self, options: GraphicalReporterOptions) {
+        self.reporter = Box::new(GraphicalReporter::new(options));
+    }
+
     pub fn set_json_reporter(&mut self) {
         self.reporter = Box::<JsonReporter>::default();
     }
 
+    pub fn set_ndjson_reporter(&mut self) {
+        self.reporter = Box::<NdjsonReporter>::default();
+    }
+
     pub fn set_unix_reporter(&mut self) {
         self.reporter = Box::<UnixReporter>::default();
     }
@@ -125,7 +135,9 @@ impl DiagnosticService {
     pub fn run(&mut self) {
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
             let mut output = String::new();
-            for diagnostic in diagnostics {
+            let mut file_warnings_count = 0;
+            let mut file_errors_count = 0;
+            for diagnostic in Self::dedup(diagnostics) {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
@@ -133,10 +145,12 @@ impl DiagnosticService {
                     if is_warning {
                         let warnings_count = self.warnings_count() + 1;
                         self.warnings_count.set(warnings_count);
+                        file_warnings_count += 1;
                     }
                     if is_error {
                         let errors_count = self.errors_count() + 1;
                         self.errors_count.set(errors_count);
+                        file_errors_count += 1;
                     }
                     // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
                     // Note that it does not disable ALL diagnostics, only Warning diagnostics
@@ -164,9 +178,82 @@ impl DiagnosticService {
                     output.push_str(&err_str);
                 }
             }
+            if !output.is_empty() {
+                if let Some(summary) =
+                    self.reporter.render_file_summary(&path, file_warnings_count, file_errors_count)
+                {
+                    output.push_str(&summary);
+                }
+            }
             self.reporter.render_diagnostics(output.as_bytes());
         }
 
         self.reporter.finish();
     }
+
+    /// Remove identical diagnostics of a file, which are commonly produced after fix passes
+    /// or when the same rule is reached through multiple config overrides.
+    fn dedup(diagnostics: Vec<Error>) -> Vec<Error> {
+        if diagnostics.len() < 2 {
+            return diagnostics;
+        }
+        let mut seen = HashSet::with_capacity(diagnostics.len());
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| seen.insert(Self::dedup_key(diagnostic)))
+            .collect()
+    }
+
+    fn dedup_key(diagnostic: &Error) -> String {
+        let mut key = format!("{:?}", diagnostic.severity());
+        if let Some(code) = diagnostic.code() {
+            write!(key, "|{code}").unwrap();
+        }
+        write!(key, "|{diagnostic}").unwrap();
+        if let Some(labels) = diagnostic.labels() {
+            for label in labels {
+                write!(key, "|{}:{}:{:?}", label.offset(), label.len(), label.label()).unwrap();
+            }
+        }
+        key
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::DiagnosticService;
+    use crate::{LabeledSpan, NamedSource, OxcDiagnostic};
+
+    #[test]
+    fn dedup() {
+        let mut service = DiagnosticService::default().with_silent(true);
+        let diagnostic = || {
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(LabeledSpan::new_with_span(None, (0, 8)))
+        };
+        let diagnostics = vec![
+            diagnostic(),
+            diagnostic(),
+            diagnostic().with_label(LabeledSpan::new_with_span(None, (10, 8))),
+            diagnostic().with_severity(crate::Severity::Error),
+        ];
+        let (path, errors) = DiagnosticService::wrap_diagnostics(
+            &PathBuf::from("test.js"),
+            "debugger; debugger;",
+            diagnostics,
+        );
+        // Identical diagnostics of different files are kept.
+        let other =
+            diagnostic().with_source_code(NamedSource::new("other.js", "debugger;".to_string()));
+        service.sender().send(Some((path, errors))).unwrap();
+        service.sender().send(Some((PathBuf::from("other.js"), vec![other]))).unwrap();
+        service.sender().send(None).unwrap();
+        service.run();
+
+        assert_eq!(service.warnings_count(), 3);
+        assert_eq!(service.errors_count(), 1);
+    }
 }
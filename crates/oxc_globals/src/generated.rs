@@ -1,6 +1,12 @@
-use phf::{phf_map, Map};
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/javascript_globals/template.hbs`
+// and run `just javascript-globals`.
 
-pub static GLOBALS: Map<&'static str, Map<&'static str, bool>> = phf_map! {
+use phf::phf_map;
+
+use crate::Globals;
+
+pub static GLOBALS: phf::Map<&'static str, Globals> = phf_map! {
     "builtin" => phf_map! {
         "AggregateError" => false,
         "Array" => false,
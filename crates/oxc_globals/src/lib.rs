@@ -0,0 +1,43 @@
+//! Global variables of JavaScript environments
+//!
+//! Generated from the [`globals`](https://github.com/sindresorhus/globals) npm package by
+//! `just javascript-globals`. Environments are named after
+//! [ESLint's environments](https://eslint.org/docs/v8.x/use/configure/language-options#specifying-environments),
+//! e.g. `browser`, `node` or `es2024`, and `builtin` contains the globals of the ECMAScript
+//! specification.
+
+mod generated;
+
+pub use crate::generated::GLOBALS;
+
+/// Global variables of an environment. The value is `true` if the variable is writable, and
+/// `false` if it should be considered read-only.
+pub type Globals = phf::Map<&'static str, bool>;
+
+/// Global variables of the environment `name`, or `None` if the environment is unknown.
+pub fn env(name: &str) -> Option<&'static Globals> {
+    GLOBALS.get(name)
+}
+
+/// Global variables of the ECMAScript specification, such as `Object`, `NaN` or `undefined`.
+pub fn builtin() -> &'static Globals {
+    &GLOBALS["builtin"]
+}
+
+/// Whether `name` is a global variable of the ECMAScript specification.
+pub fn is_builtin(name: &str) -> bool {
+    builtin().contains_key(name)
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn globals() {
+        assert!(super::is_builtin("Array"));
+        assert!(super::is_builtin("undefined"));
+        assert!(!super::is_builtin("window"));
+        assert_eq!(super::env("browser").and_then(|env| env.get("window")), Some(&false));
+        assert_eq!(super::env("worker").and_then(|env| env.get("onmessage")), Some(&true));
+        assert!(super::env("unknown").is_none());
+    }
+}
@@ -112,6 +112,14 @@
 //!
 //! Yes, but only if you turn on the `serialize` feature.
 //!
+//! #### Can indices have a niche, so `Option<I>` is no bigger than `I`?
+//!
+//! Yes, with the `nonmax` feature. It implements `Idx` for the `NonMax*` types
+//! from the [`nonmax`](https://crates.io/crates/nonmax) crate, and adds the
+//! `define_nonmax_u32_index_type!` macro for newtypes around `NonMaxU32`.
+//! `NonMaxOps` adds checked and saturating arithmetic, and `AtomicNonMaxU32`
+//! allows allocating indices from multiple threads.
+//!
 //! #### What features are planned?
 //!
 //! Planned is a bit strong but here are the things I would find useful.
@@ -125,7 +133,6 @@
 //! - Allow use of indices for string types (the primary benefit here would
 //!   probably be the ability to e.g. use u32 without too much pain rather than
 //!   mixing up indices from different strings -- but you never know!)
-//! - Allow index types such as NonZeroU32 and such, if it can be done sanely.
 //! - ...
 //!
 #![allow(clippy::inline_always)]
@@ -153,8 +160,12 @@ mod idxslice;
 mod indexing;
 pub use idxslice::{IndexBox, IndexSlice};
 pub use indexing::{IdxRangeBounds, IdxSliceIndex};
+#[cfg(feature = "nonmax")]
+pub use nonmax_impl::{AtomicNonMaxU32, NonMaxOps};
 #[cfg(feature = "rayon")]
 pub use rayon_impl::*;
+#[cfg(feature = "nonmax")]
+mod nonmax_impl;
 #[cfg(feature = "rayon")]
 mod rayon_impl;
 
@@ -762,6 +773,12 @@ pub fn __max_check_fail(u: usize, max: usize) -> ! {
     panic!("index_vec index overflow: {} is outside the range [0, {})", u, max,)
 }
 
+#[cfg(feature = "nonmax")]
+#[doc(hidden)]
+pub mod __private {
+    pub use nonmax::NonMaxU32;
+}
+
 #[cfg(feature = "serialize")]
 impl<I: Idx, T: serde::ser::Serialize> serde::ser::Serialize for IndexVec<I, T> {
     fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
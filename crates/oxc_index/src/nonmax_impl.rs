@@ -0,0 +1,289 @@
+//! Index types backed by the [`nonmax`] crate.
+//!
+//! `NonMax*` integers reserve their maximum value as a niche, so `Option<I>` is the same size as
+//! `I`. This makes them a good fit for IDs which are stored in large `IndexVec`s, often wrapped in
+//! an `Option` (e.g. a parent ID).
+
+use core::{
+    fmt,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use nonmax::{NonMaxU16, NonMaxU32, NonMaxU8, NonMaxUsize};
+
+use crate::Idx;
+
+macro_rules! impl_idx_for_nonmax {
+    ($($nonmax:ident = $raw:ident),* $(,)?) => {
+        $(
+            impl Idx for $nonmax {
+                #[inline]
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_usize(idx: usize) -> Self {
+                    assert!(idx < <$raw>::MAX as usize);
+                    // SAFETY: We just checked `idx` is valid for `NonMax*`
+                    unsafe { Self::new_unchecked(idx as $raw) }
+                }
+
+                #[inline]
+                fn index(self) -> usize {
+                    self.get() as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_idx_for_nonmax!(NonMaxU8 = u8, NonMaxU16 = u16, NonMaxU32 = u32, NonMaxUsize = usize);
+
+/// Checked and saturating arithmetic for `NonMax*` integers.
+///
+/// The largest value a `NonMax*` integer can hold is one less than the maximum of its primitive
+/// type, e.g. `u32::MAX - 1` for [`NonMaxU32`].
+pub trait NonMaxOps: Sized {
+    /// The primitive integer type.
+    type Primitive;
+
+    /// Add `rhs`, returning `None` if the result is the maximum of the primitive type or overflows.
+    fn checked_add(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Subtract `rhs`, returning `None` if the result underflows.
+    fn checked_sub(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Add `rhs`, saturating at the largest value which can be held.
+    #[must_use]
+    fn saturating_add(self, rhs: Self::Primitive) -> Self;
+
+    /// Subtract `rhs`, saturating at zero.
+    #[must_use]
+    fn saturating_sub(self, rhs: Self::Primitive) -> Self;
+}
+
+macro_rules! impl_nonmax_ops {
+    ($($nonmax:ident = $raw:ident),* $(,)?) => {
+        $(
+            impl NonMaxOps for $nonmax {
+                type Primitive = $raw;
+
+                #[inline]
+                fn checked_add(self, rhs: $raw) -> Option<Self> {
+                    self.get().checked_add(rhs).and_then(Self::new)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: $raw) -> Option<Self> {
+                    // Result of subtraction is always less than `self`, so can't be `MAX`
+                    self.get().checked_sub(rhs).and_then(Self::new)
+                }
+
+                #[inline]
+                fn saturating_add(self, rhs: $raw) -> Self {
+                    let value = self.get().saturating_add(rhs).min(<$raw>::MAX - 1);
+                    // SAFETY: `value` is at most `MAX - 1`
+                    unsafe { Self::new_unchecked(value) }
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: $raw) -> Self {
+                    // SAFETY: Result of subtraction is at most `self`, which is not `MAX`
+                    unsafe { Self::new_unchecked(self.get().saturating_sub(rhs)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_nonmax_ops!(NonMaxU8 = u8, NonMaxU16 = u16, NonMaxU32 = u32, NonMaxUsize = usize);
+
+/// A [`NonMaxU32`] which can be shared between threads.
+///
+/// Useful for allocating IDs from multiple threads without a lock, via [`fetch_add`].
+///
+/// [`fetch_add`]: AtomicNonMaxU32::fetch_add
+#[repr(transparent)]
+pub struct AtomicNonMaxU32(AtomicU32);
+
+impl AtomicNonMaxU32 {
+    /// Create a new `AtomicNonMaxU32`.
+    #[inline]
+    pub const fn new(value: NonMaxU32) -> Self {
+        Self(AtomicU32::new(value.get()))
+    }
+
+    /// Load the value.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> NonMaxU32 {
+        // SAFETY: Only `NonMaxU32` values are ever stored
+        unsafe { NonMaxU32::new_unchecked(self.0.load(order)) }
+    }
+
+    /// Store a value.
+    #[inline]
+    pub fn store(&self, value: NonMaxU32, order: Ordering) {
+        self.0.store(value.get(), order);
+    }
+
+    /// Store a value, returning the previous value.
+    #[inline]
+    pub fn swap(&self, value: NonMaxU32, order: Ordering) -> NonMaxU32 {
+        // SAFETY: Only `NonMaxU32` values are ever stored
+        unsafe { NonMaxU32::new_unchecked(self.0.swap(value.get(), order)) }
+    }
+
+    /// Add `value`, returning the previous value.
+    ///
+    /// Returns `None` and leaves the value unchanged if the result would not be a valid
+    /// [`NonMaxU32`].
+    #[inline]
+    pub fn fetch_add(&self, value: u32, order: Ordering) -> Option<NonMaxU32> {
+        let load_order = match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order,
+        };
+        self.0
+            .fetch_update(order, load_order, |current| {
+                current.checked_add(value).filter(|&next| next != u32::MAX)
+            })
+            .ok()
+            // SAFETY: Only `NonMaxU32` values are ever stored
+            .map(|previous| unsafe { NonMaxU32::new_unchecked(previous) })
+    }
+
+    /// Consume the atomic, returning the contained value.
+    #[inline]
+    pub fn into_inner(self) -> NonMaxU32 {
+        // SAFETY: Only `NonMaxU32` values are ever stored
+        unsafe { NonMaxU32::new_unchecked(self.0.into_inner()) }
+    }
+}
+
+impl Default for AtomicNonMaxU32 {
+    fn default() -> Self {
+        Self(AtomicU32::new(0))
+    }
+}
+
+impl From<NonMaxU32> for AtomicNonMaxU32 {
+    fn from(value: NonMaxU32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for AtomicNonMaxU32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+/// Generate the boilerplate for a newtyped index struct wrapping a [`NonMaxU32`], for use with
+/// `IndexVec`.
+///
+/// Unlike [`define_index_type!`], the generated type has a niche, so `Option<MyIndex>` is 4 bytes.
+///
+/// The generated type implements [`Idx`], and has `const` `new` and `new_unchecked` constructors
+/// which take a `u32`. With the `serialize` feature enabled, it (de)serializes as a `u32`.
+///
+/// ```rust
+/// oxc_index::define_nonmax_u32_index_type! {
+///     /// My index.
+///     pub struct MyIndex;
+/// }
+///
+/// # fn main() {
+/// use oxc_index::{Idx, IndexVec};
+///
+/// assert_eq!(std::mem::size_of::<Option<MyIndex>>(), 4);
+///
+/// let mut vec = IndexVec::<MyIndex, &str>::new();
+/// let idx = vec.push("foo");
+/// assert_eq!(idx, MyIndex::new(0));
+/// assert_eq!(vec[idx], "foo");
+/// # }
+/// ```
+///
+/// [`define_index_type!`]: crate::define_index_type
+#[macro_export]
+macro_rules! define_nonmax_u32_index_type {
+    ($(#[$attrs:meta])* $v:vis struct $type:ident;) => {
+        $(#[$attrs])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $v struct $type($crate::__private::NonMaxU32);
+
+        impl $type {
+            #[doc = concat!("Create `", stringify!($type), "` from `u32`.")]
+            ///
+            /// # Panics
+            /// Panics if `idx` is `u32::MAX`.
+            #[inline]
+            #[must_use]
+            pub const fn new(idx: u32) -> Self {
+                // We could use `NonMaxU32::new(idx).unwrap()` but `Option::unwrap` is not a const
+                // function and we want this function to be
+                assert!(idx != u32::MAX);
+                // SAFETY: We have checked that `idx` is not `u32::MAX`
+                unsafe { Self::new_unchecked(idx) }
+            }
+
+            #[doc = concat!("Create `", stringify!($type), "` from `u32` unchecked.")]
+            ///
+            /// # SAFETY
+            /// `idx` must not be `u32::MAX`.
+            #[inline]
+            #[must_use]
+            #[allow(clippy::missing_safety_doc, clippy::unnecessary_safety_comment)]
+            pub const unsafe fn new_unchecked(idx: u32) -> Self {
+                // SAFETY: Caller must ensure `idx` is not `u32::MAX`
+                Self($crate::__private::NonMaxU32::new_unchecked(idx))
+            }
+        }
+
+        impl $crate::Idx for $type {
+            #[inline]
+            fn from_usize(idx: usize) -> Self {
+                Self(<$crate::__private::NonMaxU32 as $crate::Idx>::from_usize(idx))
+            }
+
+            #[inline]
+            fn index(self) -> usize {
+                $crate::Idx::index(self.0)
+            }
+        }
+
+        $crate::__internal_maybe_nonmax_index_impl_serde!($type);
+    };
+}
+
+#[cfg(feature = "serialize")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __internal_maybe_nonmax_index_impl_serde {
+    ($type:ident) => {
+        impl serde::ser::Serialize for $type {
+            fn serialize<S: serde::ser::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.0.get())
+            }
+        }
+
+        impl<'de> serde::de::Deserialize<'de> for $type {
+            fn deserialize<D: serde::de::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let idx = <u32 as serde::de::Deserialize>::deserialize(deserializer)?;
+                $crate::__private::NonMaxU32::new(idx)
+                    .map(Self)
+                    .ok_or_else(|| serde::de::Error::custom("index must not be `u32::MAX`"))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serialize"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __internal_maybe_nonmax_index_impl_serde {
+    ($type:ident) => {};
+}
@@ -555,3 +555,90 @@ fn test_splits() {
     assert!(v.split_first_mut().is_none());
     assert!(v.split_last_mut().is_none());
 }
+
+#[cfg(feature = "nonmax")]
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct NonMaxIdx;
+}
+
+#[test]
+#[cfg(feature = "nonmax")]
+fn test_nonmax() {
+    use core::mem::size_of;
+    use oxc_index::Idx;
+
+    assert_eq!(size_of::<Option<NonMaxIdx>>(), size_of::<u32>());
+    assert_eq!(NonMaxIdx::new(3).index(), 3);
+    assert_eq!(NonMaxIdx::from_usize(3), NonMaxIdx::new(3));
+
+    let mut v: IndexVec<NonMaxIdx, Option<NonMaxIdx>> = IndexVec::new();
+    let a = v.push(None);
+    let b = v.push(Some(a));
+    assert_eq!(v[b], Some(NonMaxIdx::new(0)));
+
+    let v: IndexVec<nonmax::NonMaxU8, u8> = index_vec![0, 1, 2];
+    assert_eq!(v[nonmax::NonMaxU8::new(2).unwrap()], 2);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "nonmax")]
+fn test_nonmax_max() {
+    let _ = NonMaxIdx::new(u32::MAX);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "nonmax")]
+fn test_nonmax_from_usize_max() {
+    use oxc_index::Idx;
+    let _ = NonMaxIdx::from_usize(u32::MAX as usize);
+}
+
+#[test]
+#[cfg(feature = "nonmax")]
+fn test_nonmax_ops() {
+    use nonmax::NonMaxU8;
+    use oxc_index::NonMaxOps;
+
+    let n = |v| NonMaxU8::new(v).unwrap();
+    assert_eq!(n(1).checked_add(2), Some(n(3)));
+    assert_eq!(n(253).checked_add(1), Some(n(254)));
+    assert_eq!(n(254).checked_add(1), None);
+    assert_eq!(n(254).checked_add(2), None);
+    assert_eq!(n(3).checked_sub(1), Some(n(2)));
+    assert_eq!(n(0).checked_sub(1), None);
+    assert_eq!(n(250).saturating_add(10), n(254));
+    assert_eq!(n(1).saturating_add(2), n(3));
+    assert_eq!(n(3).saturating_sub(5), n(0));
+    assert_eq!(n(3).saturating_sub(1), n(2));
+}
+
+#[test]
+#[cfg(feature = "nonmax")]
+fn test_atomic_nonmax() {
+    use core::sync::atomic::Ordering;
+    use nonmax::NonMaxU32;
+    use oxc_index::AtomicNonMaxU32;
+
+    let n = |v| NonMaxU32::new(v).unwrap();
+    let atomic = AtomicNonMaxU32::default();
+    assert_eq!(atomic.fetch_add(1, Ordering::Relaxed), Some(n(0)));
+    assert_eq!(atomic.fetch_add(2, Ordering::AcqRel), Some(n(1)));
+    assert_eq!(atomic.load(Ordering::Relaxed), n(3));
+    assert_eq!(atomic.swap(n(u32::MAX - 1), Ordering::Relaxed), n(3));
+    assert_eq!(atomic.fetch_add(1, Ordering::Relaxed), None);
+    assert_eq!(atomic.into_inner(), n(u32::MAX - 1));
+
+    let atomic = AtomicNonMaxU32::new(n(0));
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    atomic.fetch_add(1, Ordering::Relaxed).unwrap();
+                }
+            });
+        }
+    });
+    assert_eq!(atomic.load(Ordering::Relaxed), n(400));
+}
@@ -99,9 +99,10 @@ impl<'a> IsolatedDeclarations<'a> {
             property.computed,
             property.r#static,
             false,
-            property.r#override,
+            // `override` and `!` are not emitted in declaration files.
+            false,
             property.optional,
-            property.definite,
+            false,
             property.readonly,
             type_annotations,
             self.transform_accessibility(property.accessibility),
@@ -143,7 +144,7 @@ impl<'a> IsolatedDeclarations<'a> {
             definition.kind,
             definition.computed,
             definition.r#static,
-            definition.r#override,
+            false,
             definition.optional,
             self.transform_accessibility(definition.accessibility),
         )
@@ -154,7 +155,6 @@ impl<'a> IsolatedDeclarations<'a> {
         r#type: PropertyDefinitionType,
         key: PropertyKey<'a>,
         r#static: bool,
-        r#override: bool,
         accessibility: Option<TSAccessibility>,
     ) -> ClassElement<'a> {
         self.ast.class_element_property_definition(
@@ -166,7 +166,7 @@ impl<'a> IsolatedDeclarations<'a> {
             false,
             r#static,
             false,
-            r#override,
+            false,
             false,
             false,
             false,
@@ -194,7 +194,7 @@ impl<'a> IsolatedDeclarations<'a> {
             false,
             false,
             false,
-            param.r#override,
+            false,
             param.pattern.optional,
             false,
             param.readonly,
@@ -219,7 +219,6 @@ impl<'a> IsolatedDeclarations<'a> {
                     // SAFETY: `ast.copy` is unsound! We need to fix.
                     unsafe { self.ast.copy(&method.key) },
                     method.r#static,
-                    method.r#override,
                     self.transform_accessibility(method.accessibility),
                 )
             }
@@ -469,7 +468,7 @@ impl<'a> IsolatedDeclarations<'a> {
                         None,
                         property.computed,
                         property.r#static,
-                        property.definite,
+                        false,
                         // SAFETY: `ast.copy` is unsound! We need to fix.
                         unsafe { self.ast.copy(&property.type_annotation) },
                         property.accessibility,
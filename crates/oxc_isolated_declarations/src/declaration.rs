@@ -109,7 +109,7 @@ impl<'a> IsolatedDeclarations<'a> {
             },
         );
 
-        Some(self.ast.variable_declarator(decl.span, decl.kind, id, init, decl.definite))
+        Some(self.ast.variable_declarator(decl.span, decl.kind, id, init, false))
     }
 
     pub fn transform_using_declaration(
@@ -9,10 +9,7 @@ use log::debug;
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{Error, NamedSource, Severity};
 use oxc_linter::{
-    partial_loader::{
-        AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
-        LINT_PARTIAL_LOADER_EXT,
-    },
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
     FixKind, Linter,
 };
 use oxc_parser::{ParseOptions, Parser};
@@ -238,18 +235,6 @@ impl IsolatedLintHandler {
         Some((source_type, source_text))
     }
 
-    fn may_need_extract_js_content<'a>(
-        source_text: &'a str,
-        ext: &str,
-    ) -> Option<Vec<JavaScriptSource<'a>>> {
-        match ext {
-            "vue" => Some(VuePartialLoader::new(source_text).parse()),
-            "astro" => Some(AstroPartialLoader::new(source_text).parse()),
-            "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
-            _ => None,
-        }
-    }
-
     fn lint_path(
         linter: &Linter,
         path: &Path,
@@ -258,8 +243,8 @@ impl IsolatedLintHandler {
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
         let (source_type, original_source_text) =
             Self::get_source_type_and_text(path, source_text, ext)?;
-        let javascript_sources = Self::may_need_extract_js_content(&original_source_text, ext)
-            .unwrap_or_else(|| {
+        let javascript_sources =
+            PartialLoader::parse(ext, &original_source_text).unwrap_or_else(|| {
                 vec![JavaScriptSource { source_text: &original_source_text, source_type, start: 0 }]
             });
 
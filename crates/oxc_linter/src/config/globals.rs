@@ -39,6 +39,14 @@ impl OxlintGlobals {
     {
         self.0.get(name).is_some_and(|value| *value != GlobalValue::Off)
     }
+
+    pub fn get<Q>(&self, name: &Q) -> Option<GlobalValue>
+    where
+        String: borrow::Borrow<Q>,
+        Q: ?Sized + Eq + hash::Hash,
+    {
+        self.0.get(name).copied()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
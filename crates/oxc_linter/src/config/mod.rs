@@ -2,21 +2,34 @@ mod env;
 mod globals;
 mod rules;
 mod settings;
+mod spans;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+use oxc_span::Span;
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 pub use self::{
     env::OxlintEnv,
-    globals::OxlintGlobals,
+    globals::{GlobalValue, OxlintGlobals},
     rules::OxlintRules,
-    settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
+    settings::{
+        import::{NodeResolverSettings, TypeScriptResolverSettings},
+        jest::{JestPluginSettings, TestFramework},
+        jsdoc::JSDocPluginSettings,
+        react::JSXRuntime,
+        OxlintSettings,
+    },
+};
+use self::{
+    rules::ESLintRule,
+    spans::{json_pointer, JsonSpans},
 };
 use crate::{
+    fixer::{Fix, Message},
     rules::RuleEnum,
     utils::{is_jest_rule_adapted_to_vitest, read_to_string},
     AllowWarnDeny, RuleWithSeverity,
@@ -63,6 +76,19 @@ pub struct OxlintConfig {
     pub(crate) env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub(crate) globals: OxlintGlobals,
+    /// Globs of files and directories to skip, in the same format as `.gitignore` and
+    /// `--ignore-pattern`, e.g. `["dist/", "**/*.generated.ts"]`.
+    #[serde(rename = "ignorePatterns")]
+    pub(crate) ignore_patterns: Vec<String>,
+    /// Path of the configuration file, for diagnostics pointing into it.
+    #[serde(skip)]
+    path: PathBuf,
+    /// Source text of the configuration file, including comments.
+    #[serde(skip)]
+    source_text: String,
+    /// Spans of the values in the configuration file.
+    #[serde(skip)]
+    spans: JsonSpans,
 }
 
 impl OxlintConfig {
@@ -70,9 +96,10 @@ impl OxlintConfig {
     ///
     /// * Parse Failure
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
-        let mut string = read_to_string(path).map_err(|e| {
+        let source_text = read_to_string(path).map_err(|e| {
             OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
         })?;
+        let mut string = source_text.clone();
 
         // jsonc support
         json_strip_comments::strip(&mut string).map_err(|err| {
@@ -94,13 +121,44 @@ impl OxlintConfig {
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
+        let mut config = Self::deserialize(&json).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
+        config.path = path.to_path_buf();
+        config.spans = JsonSpans::new(&source_text);
+        config.source_text = source_text;
 
         Ok(config)
     }
 
+    pub fn ignore_patterns(&self) -> &[String] {
+        &self.ignore_patterns
+    }
+
+    /// Path of the configuration file, empty for the default configuration.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    /// Warnings for rules configured by a name they were renamed from, with fixes renaming them.
+    pub(crate) fn deprecated_rules(&self) -> Vec<Message<'static>> {
+        self.spans
+            .keys("/rules")
+            .filter_map(|key| {
+                let new_key = rules::renamed_rule_key(&key)?;
+                let span = self.spans.get(&json_pointer("/rules", &key))?.key?;
+                let diagnostic = OxcDiagnostic::warn(format!("Rule `{key}` is deprecated"))
+                    .with_help(format!("It was renamed to `{new_key}`"))
+                    .with_label(span);
+                Some(Message::new(diagnostic, Some(Fix::new(format!("\"{new_key}\""), span))))
+            })
+            .collect()
+    }
+
     #[allow(clippy::option_if_let_else)]
     pub fn override_rules(
         &self,
@@ -174,6 +232,68 @@ impl OxlintConfig {
             rules_for_override.replace(rule);
         }
     }
+
+    /// Check the options of the configured rules with [`RuleEnum::validate_json`].
+    ///
+    /// # Errors
+    ///
+    /// * Invalid rule options, labeled with their location in the configuration file
+    pub(crate) fn validate_rules(&self, all_rules: &[RuleEnum]) -> Result<(), Error> {
+        let mut errors = vec![];
+        for rule_config in self.rules.iter().filter(|r| r.severity.is_warn_deny()) {
+            let Some(config) = &rule_config.config else { continue };
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule_config.rule_name, &rule_config.plugin_name);
+            let Some(rule) =
+                all_rules.iter().find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+            else {
+                continue;
+            };
+            errors.extend(rule.validate_json(config).into_iter().map(|error| (rule_config, error)));
+        }
+        let diagnostic = errors
+            .into_iter()
+            .map(|(rule_config, error)| {
+                let diagnostic = OxcDiagnostic::error(format!(
+                    "Invalid configuration of rule `{}/{}`: {}",
+                    rule_config.plugin_name, rule_config.rule_name, error.message
+                ));
+                match self.rule_option_span(rule_config, &error.pointer) {
+                    Some(span) => diagnostic.with_label(span),
+                    None => diagnostic,
+                }
+            })
+            .reduce(OxcDiagnostic::and_related);
+        match diagnostic {
+            Some(diagnostic) => Err(diagnostic.with_source_code(NamedSource::new(
+                self.path.to_string_lossy(),
+                self.source_text.clone(),
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// The span of the option at `pointer` in the configuration of `rule_config`, e.g.
+    /// `"("` for `/0/regex` in `"rule": ["error", { "regex": "(" }]`.
+    fn rule_option_span(&self, rule_config: &ESLintRule, pointer: &str) -> Option<Span> {
+        let key = self
+            .spans
+            .keys("/rules")
+            .filter(|key| {
+                let (plugin_name, rule_name) = rules::parse_rule_key(key);
+                plugin_name == rule_config.plugin_name && rule_name == rule_config.rule_name
+            })
+            .last()?;
+        let rule_pointer = json_pointer("/rules", &key);
+        // The options follow the severity, e.g. `["error", { "regex": "(" }]`.
+        let option_pointer = pointer.strip_prefix('/').and_then(|pointer| {
+            let (index, rest) = pointer.find('/').map_or((pointer, ""), |i| pointer.split_at(i));
+            let index = index.parse::<usize>().ok()?;
+            Some(format!("{rule_pointer}/{}{rest}", index + 1))
+        });
+        let span = self.spans.nearest(option_pointer.as_deref().unwrap_or(&rule_pointer))?;
+        Some(span.value)
+    }
 }
 
 fn transform_rule_and_plugin_name<'a>(
@@ -191,12 +311,13 @@ fn transform_rule_and_plugin_name<'a>(
 mod test {
     use std::env;
 
+    use oxc_diagnostics::OxcDiagnostic;
     use oxc_span::CompactStr;
     use rustc_hash::FxHashSet;
     use serde::Deserialize;
 
     use super::OxlintConfig;
-    use crate::rules::RULES;
+    use crate::{fixer::Fixer, rules::RULES};
 
     #[test]
     fn test_from_file() {
@@ -205,6 +326,33 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_validate_rules() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/invalid_rule_config.json");
+        let config = OxlintConfig::from_file(&fixture_path).unwrap();
+        let error = config.validate_rules(&RULES).unwrap_err();
+        let diagnostic = OxcDiagnostic::from_error(&error).unwrap();
+        assert_eq!(
+            diagnostic.message,
+            "Invalid configuration of rule `eslint/no-restricted-imports`: Invalid regular expression: unclosed group"
+        );
+        let label = &diagnostic.labels.as_ref().unwrap()[0];
+        assert_eq!(&config.source_text[label.offset()..label.offset() + label.len()], r#""(""#);
+    }
+
+    #[test]
+    fn test_deprecated_rules() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/renamed_rules.json");
+        let config = OxlintConfig::from_file(&fixture_path).unwrap();
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-unsafe-negation"));
+        let messages = config.deprecated_rules();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].error.message, "Rule `no-negated-in-lhs` is deprecated");
+        let fixed_code = Fixer::new(config.source_text(), messages).fix().fixed_code;
+        assert!(fixed_code.contains(r#""no-unsafe-negation": "error""#));
+        assert!(fixed_code.contains(r#""unicorn/no-array-reduce": "warn""#));
+    }
+
     #[test]
     fn test_deserialize() {
         let config = OxlintConfig::deserialize(&serde_json::json!({
@@ -233,11 +381,12 @@ mod test {
                 },
             },
             "env": { "browser": true, },
-            "globals": { "foo": "readonly", }
+            "globals": { "foo": "readonly", },
+            "ignorePatterns": ["dist/"]
         }));
         assert!(config.is_ok());
 
-        let OxlintConfig { rules, settings, env, globals } = config.unwrap();
+        let OxlintConfig { rules, settings, env, globals, ignore_patterns, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(
             settings.jsx_a11y.polymorphic_prop_name.as_ref().map(CompactStr::as_str),
@@ -245,6 +394,7 @@ mod test {
         );
         assert_eq!(env.iter().count(), 1);
         assert!(globals.is_enabled("foo"));
+        assert_eq!(ignore_patterns, ["dist/"]);
     }
 
     #[test]
@@ -95,8 +95,31 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+/// Rules which were renamed, as `(plugin name, old name, new name)`. The old names are aliases
+/// of the new rules, and are reported as deprecated in configuration files.
+const RENAMED_RULES: &[(&str, &str, &str)] = &[
+    ("eslint", "no-native-reassign", "no-global-assign"),
+    ("eslint", "no-negated-in-lhs", "no-unsafe-negation"),
+    ("eslint", "no-new-symbol", "no-new-native-nonconstructor"),
+    ("unicorn", "no-array-instanceof", "no-instanceof-array"),
+    ("unicorn", "no-reduce", "no-array-reduce"),
+    ("unicorn", "prefer-dataset", "prefer-dom-node-dataset"),
+    ("unicorn", "prefer-flat-map", "prefer-array-flat-map"),
+    ("unicorn", "prefer-node-append", "prefer-dom-node-append"),
+    ("unicorn", "prefer-node-remove", "prefer-dom-node-remove"),
+    ("unicorn", "prefer-replace-all", "prefer-string-replace-all"),
+    ("unicorn", "prefer-starts-ends-with", "prefer-string-starts-ends-with"),
+    ("unicorn", "prefer-text-content", "prefer-dom-node-text-content"),
+    ("unicorn", "prefer-trim-start-end", "prefer-string-trim-start-end"),
+];
+
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
+        if let Some((plugin_name, _, new_name)) =
+            RENAMED_RULES.iter().find(|(_, old_name, _)| *old_name == name)
+        {
+            return ((*plugin_name).to_string(), (*new_name).to_string());
+        }
         return (
             RULES
                 .iter()
@@ -119,10 +142,30 @@ fn parse_rule_key(name: &str) -> (String, String) {
         "deepscan" => ("oxc", rule_name),
         _ => (plugin_name, rule_name),
     };
+    let rule_name = RENAMED_RULES
+        .iter()
+        .find(|(plugin_name, old_name, _)| {
+            *plugin_name == oxlint_plugin_name && *old_name == rule_name
+        })
+        .map_or(rule_name, |(_, _, new_name)| new_name);
 
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
+/// The key of a renamed rule with its new name, e.g. `unicorn/no-array-reduce` for
+/// `unicorn/no-reduce`, or `None` if the rule was not renamed.
+pub(super) fn renamed_rule_key(key: &str) -> Option<String> {
+    let (prefix, rule_name) = match key.rsplit_once('/') {
+        Some((prefix, rule_name)) => (Some(prefix), rule_name),
+        None => (None, key),
+    };
+    let (_, new_name) = parse_rule_key(key);
+    (new_name != rule_name).then(|| match prefix {
+        Some(prefix) => format!("{prefix}/{new_name}"),
+        None => new_name,
+    })
+}
+
 fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
@@ -178,7 +221,7 @@ fn failed_to_parse_rule_value(value: &str, err: &str) -> OxcDiagnostic {
 mod test {
     use serde::Deserialize;
 
-    use super::OxlintRules;
+    use super::{parse_rule_key, renamed_rule_key, OxlintRules};
 
     #[test]
     fn test_parse_rules() {
@@ -221,4 +264,28 @@ mod test {
         let rules = OxlintRules::default();
         assert!(rules.is_empty());
     }
+
+    #[test]
+    fn test_renamed_rules() {
+        assert_eq!(
+            parse_rule_key("no-negated-in-lhs"),
+            ("eslint".into(), "no-unsafe-negation".into())
+        );
+        assert_eq!(
+            parse_rule_key("unicorn/no-reduce"),
+            ("unicorn".into(), "no-array-reduce".into())
+        );
+        assert_eq!(parse_rule_key("foo/no-reduce"), ("foo".into(), "no-reduce".into()));
+
+        assert_eq!(
+            renamed_rule_key("no-new-symbol").as_deref(),
+            Some("no-new-native-nonconstructor")
+        );
+        assert_eq!(
+            renamed_rule_key("eslint/no-native-reassign").as_deref(),
+            Some("eslint/no-global-assign")
+        );
+        assert_eq!(renamed_rule_key("unicorn/no-array-reduce"), None);
+        assert_eq!(renamed_rule_key("@next/next/google-font-display"), None);
+    }
 }
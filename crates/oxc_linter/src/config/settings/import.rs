@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// <https://github.com/import-js/eslint-plugin-import#resolvers>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct ImportResolverSettings {
+    #[serde(default)]
+    pub node: NodeResolverSettings,
+
+    #[serde(default)]
+    pub typescript: TypeScriptResolverSettings,
+}
+
+// <https://github.com/import-js/eslint-plugin-import/tree/main/resolvers/node>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct NodeResolverSettings {
+    /// File extensions to try, e.g. `[".js", ".jsx"]`, instead of all extensions supported by oxlint
+    #[serde(default)]
+    pub extensions: Vec<String>,
+
+    /// Directories to resolve modules from, `["node_modules"]` by default
+    #[serde(default, rename = "moduleDirectory")]
+    pub module_directory: Vec<String>,
+}
+
+// <https://github.com/import-js/eslint-import-resolver-typescript#configuration>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct TypeScriptResolverSettings {
+    /// Path to the `tsconfig.json` used for resolving paths, used when `--tsconfig` is not given
+    pub project: Option<PathBuf>,
+
+    /// Conditions to match in the `exports` and `imports` fields of `package.json`, in order,
+    /// `["module", "require"]` by default
+    #[serde(default, rename = "conditionNames")]
+    pub condition_names: Vec<String>,
+
+    /// Extensions to try instead of the extension of an import, e.g. `{ ".js": [".ts", ".js"] }`
+    /// for TypeScript projects using `NodeNext` resolution
+    #[serde(default, rename = "extensionAlias")]
+    pub extension_alias: FxHashMap<String, Vec<String>>,
+
+    /// Fields of `package.json` to resolve the entry point of a package from, in order,
+    /// `["main"]` by default
+    #[serde(default, rename = "mainFields")]
+    pub main_fields: Vec<String>,
+}
@@ -0,0 +1,28 @@
+use oxc_span::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// <https://github.com/jest-community/eslint-plugin-jest#shared-settings>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct JestPluginSettings {
+    /// Test framework used by all test files, instead of detecting it per file from imports
+    pub framework: Option<TestFramework>,
+    /// Package test globals may be imported from, in addition to `@jest/globals` and `vitest`
+    #[serde(rename = "globalPackage")]
+    pub global_package: Option<CompactStr>,
+}
+
+impl JestPluginSettings {
+    /// Whether test globals imported from `source` should be treated as Jest / Vitest globals.
+    pub fn is_global_package(&self, source: &str) -> bool {
+        matches!(source, "@jest/globals" | "vitest")
+            || self.global_package.as_ref().is_some_and(|package| package == source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TestFramework {
+    Jest,
+    Vitest,
+}
@@ -1,13 +1,17 @@
+pub mod import;
+pub mod jest;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
-mod react;
+mod node;
+pub mod react;
 
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
+    import::ImportResolverSettings, jest::JestPluginSettings, jsdoc::JSDocPluginSettings,
+    jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings, node::NodePluginSettings,
     react::ReactPluginSettings,
 };
 
@@ -26,6 +30,16 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
+
+    #[serde(default)]
+    pub jest: JestPluginSettings,
+
+    #[serde(default)]
+    pub node: NodePluginSettings,
+
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    pub import_resolver: ImportResolverSettings,
 }
 
 #[cfg(test)]
@@ -36,7 +50,10 @@ mod test {
     use serde::Deserialize;
 
     use super::OxlintSettings;
-    use crate::config::settings::react::ComponentAttrs;
+    use crate::config::settings::{
+        jest::TestFramework,
+        react::{ComponentAttrs, JSXRuntime},
+    };
 
     fn as_attrs<S: Into<CompactStr>, I: IntoIterator<Item = S>>(
         attrs: I,
@@ -57,6 +74,10 @@ mod test {
             "next": {
                 "rootDir": "app"
             },
+            "jest": {
+                "framework": "vitest",
+                "globalPackage": "bun:test"
+            },
             "react": {
                 "formComponents": [
                     "CustomForm",
@@ -67,7 +88,24 @@ mod test {
                     "Hyperlink",
                     {"name": "MyLink", "linkAttribute": "to"},
                     {"name": "Link", "linkAttribute": ["to", "href"]},
-                ]
+                ],
+                "version": "16.14.0",
+                "pragma": "Preact",
+                "runtime": "automatic"
+            },
+            "node": {
+                "version": ">=18.0.0"
+            },
+            "import/resolver": {
+                "node": {
+                    "extensions": [".js", ".jsx"]
+                },
+                "typescript": {
+                    "project": "tsconfig.app.json",
+                    "conditionNames": ["types", "import", "node"],
+                    "extensionAlias": { ".js": [".ts", ".js"] },
+                    "mainFields": ["types", "module", "main"]
+                }
             }
         }))
         .unwrap();
@@ -75,6 +113,9 @@ mod test {
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".into()));
         assert_eq!(settings.jsx_a11y.components.get("Link"), Some(&"Anchor".into()));
         assert!(settings.next.get_root_dirs().contains(&"app".to_string()));
+        assert_eq!(settings.jest.framework, Some(TestFramework::Vitest));
+        assert!(settings.jest.is_global_package("bun:test"));
+        assert!(settings.jest.is_global_package("@jest/globals"));
         assert_eq!(
             settings.react.get_form_component_attrs("CustomForm").unwrap(),
             as_attrs::<CompactStr, _>(vec![])
@@ -92,6 +133,21 @@ mod test {
             as_attrs(["to", "href"])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert!(settings.react.is_version_at_least(16, 8, 0));
+        assert!(!settings.react.is_version_at_least(17, 0, 0));
+        assert_eq!(settings.react.pragma(), "Preact");
+        assert_eq!(settings.react.fragment(), "Fragment");
+        assert_eq!(settings.react.runtime, JSXRuntime::Automatic);
+        assert_eq!(settings.node.version, Some(">=18.0.0".into()));
+        assert_eq!(settings.import_resolver.node.extensions, vec![".js", ".jsx"]);
+        assert_eq!(
+            settings.import_resolver.typescript.project.as_deref(),
+            Some(std::path::Path::new("tsconfig.app.json"))
+        );
+        let typescript = &settings.import_resolver.typescript;
+        assert_eq!(typescript.condition_names, vec!["types", "import", "node"]);
+        assert_eq!(typescript.extension_alias.get(".js"), Some(&vec![".ts".into(), ".js".into()]));
+        assert_eq!(typescript.main_fields, vec!["types", "module", "main"]);
     }
 
     #[test]
@@ -99,5 +155,12 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.jest.framework.is_none());
+        assert!(!settings.jest.is_global_package("bun:test"));
+        assert!(settings.react.is_version_at_least(18, 0, 0));
+        assert_eq!(settings.react.pragma(), "React");
+        assert_eq!(settings.react.runtime, JSXRuntime::Classic);
+        assert!(settings.import_resolver.node.extensions.is_empty());
+        assert!(settings.import_resolver.typescript.condition_names.is_empty());
     }
 }
@@ -0,0 +1,10 @@
+use oxc_span::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// <https://github.com/eslint-community/eslint-plugin-n#-shared-settings>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct NodePluginSettings {
+    /// Supported Node.js versions as a semver range, e.g. `">=18.0.0"`
+    pub version: Option<CompactStr>,
+}
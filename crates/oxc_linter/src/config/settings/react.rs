@@ -14,6 +14,20 @@ pub struct ReactPluginSettings {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+
+    /// React version, e.g. `"16.14.0"`. The latest version is assumed for `"detect"`
+    version: Option<CompactStr>,
+
+    /// Name of the object JSX is compiled to calls of, `React` by default
+    pragma: Option<CompactStr>,
+
+    /// Name of the fragment component, `Fragment` by default
+    fragment: Option<CompactStr>,
+
+    /// JSX transform, `React` does not need to be in scope for the `automatic` runtime,
+    /// which compiles `<a />` to `_jsx("a")` instead of `React.createElement("a")`
+    #[serde(default)]
+    pub runtime: JSXRuntime,
     // TODO: More properties should be added
 }
 
@@ -26,6 +40,40 @@ impl ReactPluginSettings {
     pub fn get_link_component_attrs(&self, name: &str) -> Option<ComponentAttrs<'_>> {
         get_component_attrs_by_name(&self.link_components, name)
     }
+
+    pub fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React")
+    }
+
+    pub fn fragment(&self) -> &str {
+        self.fragment.as_deref().unwrap_or("Fragment")
+    }
+
+    /// Whether the configured React version is at least `major.minor.patch`.
+    /// Always `true` when the version is not configured or cannot be parsed.
+    pub fn is_version_at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.version
+            .as_deref()
+            .and_then(parse_version)
+            .map_or(true, |version| version >= (major, minor, patch))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JSXRuntime {
+    #[default]
+    Classic,
+    Automatic,
+}
+
+/// Parse a version such as `"16"`, `"16.14"` or `"16.14.0"`.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.').map(str::parse::<u32>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    Some((major, minor, patch))
 }
 
 // Deserialize helper types
@@ -0,0 +1,112 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+use crate::utils::json_properties;
+
+/// Location of a value in a JSON file, and of its key if it is the value of an object property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonSpan {
+    pub key: Option<Span>,
+    pub value: Span,
+}
+
+/// Spans of every value of a JSON or JSONC file, by
+/// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901), e.g. `/rules/eqeqeq/1`.
+///
+/// `serde_json` does not keep track of spans, so configuration files are parsed a second time
+/// with [`Parser::parse_json`] for diagnostics and fixes pointing into them.
+#[derive(Debug, Default)]
+pub struct JsonSpans {
+    /// In document order. Configuration files are small, a linear search is fast enough.
+    spans: Vec<(String, JsonSpan)>,
+}
+
+impl JsonSpans {
+    /// Values of invalid JSON are recorded as far as they could be parsed.
+    pub fn new(source_text: &str) -> Self {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse_json();
+        let mut spans = Self::default();
+        if let Some(Statement::ExpressionStatement(statement)) = ret.program.body.first() {
+            spans.record("", None, &statement.expression);
+        }
+        spans
+    }
+
+    fn record(&mut self, pointer: &str, key: Option<Span>, value: &Expression) {
+        self.spans.push((pointer.to_string(), JsonSpan { key, value: value.span() }));
+        match value {
+            Expression::ObjectExpression(object) => {
+                for (key, property) in json_properties(object) {
+                    let pointer = json_pointer(pointer, key);
+                    self.record(&pointer, Some(property.key.span()), &property.value);
+                }
+            }
+            Expression::ArrayExpression(array) => {
+                for (index, element) in array.elements.iter().enumerate() {
+                    let Some(element) = element.as_expression() else { continue };
+                    self.record(&format!("{pointer}/{index}"), None, element);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The span of the value at `pointer`. The last of duplicate keys wins, as in `JSON.parse`.
+    pub fn get(&self, pointer: &str) -> Option<JsonSpan> {
+        self.spans.iter().rev().find(|(p, _)| p == pointer).map(|(_, span)| *span)
+    }
+
+    /// The span of the value at `pointer`, or of its innermost ancestor if it does not exist,
+    /// e.g. the rule's options if an option is missing.
+    pub fn nearest(&self, mut pointer: &str) -> Option<JsonSpan> {
+        loop {
+            if let Some(span) = self.get(pointer) {
+                return Some(span);
+            }
+            pointer = &pointer[..pointer.rfind('/')?];
+        }
+    }
+
+    /// The keys of the object at `pointer`, unescaped and in document order.
+    pub fn keys<'s>(&'s self, pointer: &'s str) -> impl Iterator<Item = String> + 's {
+        self.spans.iter().filter_map(move |(p, _)| {
+            let token = p.strip_prefix(pointer)?.strip_prefix('/')?;
+            (!token.contains('/')).then(|| token.replace("~1", "/").replace("~0", "~"))
+        })
+    }
+}
+
+/// The JSON pointer to the property `key` of the object at `pointer`.
+pub fn json_pointer(pointer: &str, key: &str) -> String {
+    format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{json_pointer, JsonSpans};
+
+    #[test]
+    fn test_json_spans() {
+        let source_text = r#"{
+  // comment
+  "rules": {
+    "eqeqeq": ["error", "always"],
+    "a/b": "off",
+  }
+}"#;
+        let spans = JsonSpans::new(source_text);
+        let text = |span: oxc_span::Span| span.source_text(source_text);
+
+        let eqeqeq = spans.get("/rules/eqeqeq").unwrap();
+        assert_eq!(text(eqeqeq.key.unwrap()), r#""eqeqeq""#);
+        assert_eq!(text(eqeqeq.value), r#"["error", "always"]"#);
+        assert_eq!(text(spans.get("/rules/eqeqeq/1").unwrap().value), r#""always""#);
+        assert_eq!(text(spans.get(&json_pointer("/rules", "a/b")).unwrap().value), r#""off""#);
+        assert!(spans.get("/rules/eqeqeq/2").is_none());
+        assert_eq!(spans.nearest("/rules/eqeqeq/2/a"), Some(eqeqeq));
+        assert_eq!(spans.keys("/rules").collect::<Vec<_>>(), ["eqeqeq", "a/b"]);
+    }
+}
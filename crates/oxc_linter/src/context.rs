@@ -10,10 +10,10 @@ use oxc_syntax::module_record::ModuleRecord;
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
 use crate::{
-    config::OxlintRules,
+    config::{GlobalValue, OxlintRules},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{FixKind, Message, RuleFix, RuleFixer},
-    javascript_globals::GLOBALS,
+    inline_config::InlineGlobals,
     AllowWarnDeny, FrameworkFlags, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
 
@@ -29,6 +29,9 @@ pub struct LintContext<'a> {
 
     disable_directives: Rc<DisableDirectives<'a>>,
 
+    /// Globals and environments declared by `/* global */` and `/* eslint-env */` comments.
+    inline_globals: Rc<InlineGlobals>,
+
     /// Whether or not to apply code fixes during linting. Defaults to
     /// [`FixKind::None`] (no fixing).
     ///
@@ -61,7 +64,8 @@ pub struct LintContext<'a> {
 }
 
 impl<'a> LintContext<'a> {
-    const WEBSITE_BASE_URL: &'static str = "https://oxc.rs/docs/guide/usage/linter/rules";
+    pub(crate) const WEBSITE_BASE_URL: &'static str =
+        "https://oxc.rs/docs/guide/usage/linter/rules";
 
     /// # Panics
     /// If `semantic.cfg()` is `None`.
@@ -77,10 +81,12 @@ impl<'a> LintContext<'a> {
         let disable_directives =
             DisableDirectivesBuilder::new(semantic.source_text(), semantic.trivias().clone())
                 .build();
+        let inline_globals = InlineGlobals::new(&semantic);
         Self {
             semantic,
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
             disable_directives: Rc::new(disable_directives),
+            inline_globals: Rc::new(inline_globals),
             fix: FixKind::None,
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
@@ -194,12 +200,14 @@ impl<'a> LintContext<'a> {
         &self.eslint_config.rules
     }
 
+    /// Whether `var` is a global variable of the builtin environment, or of an environment turned
+    /// on by the configuration file or by a `/* eslint-env */` comment.
     pub fn env_contains_var(&self, var: &str) -> bool {
-        if GLOBALS["builtin"].contains_key(var) {
+        if oxc_globals::is_builtin(var) {
             return true;
         }
-        for env in self.env().iter() {
-            if let Some(env) = GLOBALS.get(env) {
+        for env in self.env().iter().chain(self.inline_globals.envs()) {
+            if let Some(env) = oxc_globals::env(env) {
                 if env.contains_key(var) {
                     return true;
                 }
@@ -208,6 +216,27 @@ impl<'a> LintContext<'a> {
         false
     }
 
+    /// Classify an unresolved reference to `name`: returns the [`GlobalValue`] of the global
+    /// variable it refers to, or `None` if it is not declared.
+    ///
+    /// Global variables are declared by `/* global */` comments, the `globals` of the
+    /// configuration file, and environments. Declarations set to `"off"` take precedence over
+    /// environments, e.g. `{ "env": { "es6": true }, "globals": { "Promise": "off" } }`.
+    pub fn global_value(&self, name: &str) -> Option<GlobalValue> {
+        let value = self.inline_globals.get(name).or_else(|| self.globals().get(name));
+        match value {
+            Some(GlobalValue::Off) => None,
+            Some(value) => Some(value),
+            None => {
+                let writable = std::iter::once("builtin")
+                    .chain(self.env().iter())
+                    .chain(self.inline_globals.envs())
+                    .find_map(|env| oxc_globals::env(env)?.get(name).copied())?;
+                Some(GlobalValue::from(writable))
+            }
+        }
+    }
+
     /* Diagnostics */
 
     pub fn into_message(self) -> Vec<Message<'a>> {
@@ -397,7 +426,7 @@ impl<'a> LintContext<'a> {
 }
 
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub(crate) fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
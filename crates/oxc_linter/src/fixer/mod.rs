@@ -3,8 +3,10 @@ mod fix;
 use std::borrow::Cow;
 
 pub use fix::{CompositeFix, Fix, FixKind, RuleFix};
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Expression, AstBuilder};
 use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, OxcFix, SourceSpan};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -118,6 +120,33 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         self.new_fix(CompositeFix::Single(fix), message)
     }
 
+    /// Replace the text covered by `target` with an expression built by `build`.
+    ///
+    /// See [`RuleFixer::print_expression`].
+    pub fn replace_with_expression<F>(&self, target: Span, build: F) -> RuleFix<'a>
+    where
+        F: for<'b> FnOnce(AstBuilder<'b>) -> Expression<'b>,
+    {
+        self.replace(target, self.print_expression(build))
+    }
+
+    /// Print an expression built by `build` with [`RuleFixer::codegen`].
+    ///
+    /// The expression is built with an [`AstBuilder`] instead of being pieced together from
+    /// strings, so that precedence is taken care of when printing it. Nodes of the linted program
+    /// can be reused by copying them into the builder's allocator with
+    /// [`CloneIn`](oxc_allocator::CloneIn), e.g. `expr.clone_in(ast.allocator)`.
+    pub fn print_expression<F>(&self, build: F) -> String
+    where
+        F: for<'b> FnOnce(AstBuilder<'b>) -> Expression<'b>,
+    {
+        let allocator = Allocator::default();
+        let expr = build(AstBuilder::new(&allocator));
+        let mut codegen = self.codegen();
+        codegen.print_expression(&expr);
+        codegen.into_source_text()
+    }
+
     /// Creates a fix command that inserts text before the given node.
     #[inline]
     pub fn insert_text_before<T: GetSpan, S: Into<Cow<'a, str>>>(
@@ -236,12 +265,34 @@ impl<'a> Message<'a> {
         };
         Self { error, span: Span::new(start, end), fix, fixed: false }
     }
+
+    /// Move the message by `offset` bytes, for scripts extracted by the
+    /// [`PartialLoader`](crate::partial_loader::PartialLoader) from a larger file.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    pub(crate) fn with_offset(mut self, offset: usize) -> Self {
+        self.error = self.error.with_offset(offset);
+        let offset = offset as u32;
+        let move_span = |span: Span| Span::new(span.start + offset, span.end + offset);
+        self.span = move_span(self.span);
+        if let Some(fix) = &mut self.fix {
+            fix.span = move_span(fix.span);
+        }
+        self
+    }
 }
 
 impl From<Message<'_>> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        match message.fix {
+            // Keep fixes which were not applied for reporters that output them.
+            Some(Fix { content, span }) if !message.fixed => message.error.with_fix(OxcFix {
+                span: SourceSpan::new((span.start as usize).into(), span.size() as usize),
+                content: Cow::Owned(content.into_owned()),
+            }),
+            _ => message.error,
+        }
     }
 }
 
@@ -3,6 +3,8 @@ use std::{hash, path::Path};
 use bitflags::bitflags;
 use oxc_semantic::ModuleRecord;
 
+use crate::config::JestPluginSettings;
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct FrameworkFlags: u32 {
@@ -90,6 +92,12 @@ pub(crate) fn has_vitest_imports(module_record: &ModuleRecord) -> bool {
     module_record.import_entries.iter().any(|entry| entry.module_request.name() == "vitest")
 }
 
-pub(crate) fn has_jest_imports(module_record: &ModuleRecord) -> bool {
-    module_record.import_entries.iter().any(|entry| entry.module_request.name() == "@jest/globals")
+pub(crate) fn has_jest_imports(
+    module_record: &ModuleRecord,
+    settings: &JestPluginSettings,
+) -> bool {
+    module_record.import_entries.iter().any(|entry| {
+        let source = entry.module_request.name();
+        source != "vitest" && settings.is_global_package(source)
+    })
 }
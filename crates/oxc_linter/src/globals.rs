@@ -199,6 +199,112 @@ pub const VALID_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
   "treeitem"
 };
 
+/// ARIA roles of widgets which users interact with, a subset of [`VALID_ARIA_ROLES`].
+/// Roles which are neither interactive nor `presentation`, `none` or `generic` are non-interactive.
+/// Reference: <https://www.w3.org/TR/wai-aria/#widget_roles>
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isInteractiveRole.js>
+pub const INTERACTIVE_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
+  "button",
+  "checkbox",
+  "columnheader",
+  "combobox",
+  "doc-backlink",
+  "doc-biblioref",
+  "doc-glossref",
+  "doc-noteref",
+  "grid",
+  "gridcell",
+  "link",
+  "listbox",
+  "menu",
+  "menubar",
+  "menuitem",
+  "menuitemcheckbox",
+  "menuitemradio",
+  "option",
+  "radio",
+  "radiogroup",
+  "row",
+  "rowheader",
+  "scrollbar",
+  "searchbox",
+  "slider",
+  "spinbutton",
+  "switch",
+  "tab",
+  "tablist",
+  "textbox",
+  "toolbar",
+  "tree",
+  "treegrid",
+  "treeitem"
+};
+
+/// HTML elements with an implicit non-interactive ARIA role.
+/// Reference: <https://www.w3.org/TR/html-aria/#docconformance>
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isNonInteractiveElement.js>
+pub const NON_INTERACTIVE_HTML_ELEMENTS: phf::Set<&'static str> = phf_set! {
+  "address",
+  "article",
+  "aside",
+  "blockquote",
+  "body",
+  "caption",
+  "code",
+  "dd",
+  "del",
+  "dfn",
+  "dialog",
+  "dir",
+  "dl",
+  "dt",
+  "em",
+  "fieldset",
+  "figcaption",
+  "figure",
+  "footer",
+  "form",
+  "h1",
+  "h2",
+  "h3",
+  "h4",
+  "h5",
+  "h6",
+  "header",
+  "hr",
+  "html",
+  "img",
+  "ins",
+  "legend",
+  "li",
+  "main",
+  "mark",
+  "marquee",
+  "menu",
+  "meter",
+  "nav",
+  "ol",
+  "optgroup",
+  "output",
+  "p",
+  "pre",
+  "progress",
+  "ruby",
+  "section",
+  "strong",
+  "sub",
+  "sup",
+  "table",
+  "tbody",
+  "td",
+  "tfoot",
+  "th",
+  "thead",
+  "time",
+  "tr",
+  "ul"
+};
+
 pub const HTML_TAG: phf::Set<&'static str> = phf_set! {
     "a",
     "abbr",
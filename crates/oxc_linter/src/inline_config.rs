@@ -0,0 +1,372 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{
+    config::{GlobalValue, OxlintRules},
+    rules::RULES,
+    AllowWarnDeny, RuleWithSeverity,
+};
+
+/// A rule configured by a `/* eslint rule-name: ["error", { ... }] */` or
+/// `/* oxlint rule-name: ["error", { ... }] */` comment
+#[derive(Debug)]
+pub struct InlineRuleConfig {
+    /// Span of the code the configuration applies to, from the comment to the end of its
+    /// enclosing block or of the file
+    pub span: Span,
+    /// The configured rule, `None` if it is turned off
+    pub rule: Option<RuleWithSeverity>,
+    rule_name: &'static str,
+    plugin_name: &'static str,
+}
+
+impl InlineRuleConfig {
+    fn is_same_rule(&self, rule: &RuleWithSeverity) -> bool {
+        self.rule_name == rule.name() && self.plugin_name == rule.plugin_name()
+    }
+}
+
+/// All inline rule configurations of a file, in source order
+#[derive(Debug, Default)]
+pub struct InlineConfigs(Vec<InlineRuleConfig>);
+
+impl InlineConfigs {
+    pub fn new(semantic: &Semantic) -> Self {
+        let source_text = semantic.source_text();
+        let mut configs = vec![];
+        for comment in semantic.trivias().comments() {
+            // Like ESLint, configuration comments must be block comments
+            if !comment.kind.is_multi_line() {
+                continue;
+            }
+            let text = comment.span.source_text(source_text).trim_start();
+            let Some(text) = text.strip_prefix("eslint").or_else(|| text.strip_prefix("oxlint"))
+            else {
+                continue;
+            };
+            // `eslint-disable`, `eslint-env` etc. are not configuration comments
+            if !text.starts_with(char::is_whitespace) {
+                continue;
+            }
+            // `/* eslint rule-name: "off" -- description */`
+            let text = text.split_terminator("--").next().unwrap_or_default();
+            let Some(rules) = parse_rules(text) else {
+                continue;
+            };
+            let span = Span::new(comment.span.end, enclosing_block_end(semantic, comment.span));
+            for rule_config in rules.iter() {
+                let Some(rule) = RULES.iter().find(|rule| {
+                    rule.name() == rule_config.rule_name
+                        && rule.plugin_name() == rule_config.plugin_name
+                }) else {
+                    continue;
+                };
+                let configured = match rule_config.severity {
+                    AllowWarnDeny::Allow => None,
+                    severity => {
+                        let config = rule_config.config.clone().unwrap_or_default();
+                        Some(RuleWithSeverity::new(rule.read_json(config), severity))
+                    }
+                };
+                configs.push(InlineRuleConfig {
+                    span,
+                    rule: configured,
+                    rule_name: rule.name(),
+                    plugin_name: rule.plugin_name(),
+                });
+            }
+        }
+        Self(configs)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &InlineRuleConfig)> {
+        self.0.iter().enumerate()
+    }
+
+    /// Whether a diagnostic at `span`, reported by `rule` with the configuration of the
+    /// inline config at `index` (or of the configuration file if `None`), should be kept.
+    pub fn is_configured_at(
+        &self,
+        rule: &RuleWithSeverity,
+        index: Option<usize>,
+        span: Span,
+    ) -> bool {
+        let start = match index {
+            Some(index) => {
+                if !contains(self.0[index].span, span) {
+                    return false;
+                }
+                index + 1
+            }
+            None => 0,
+        };
+        // Configurations later in the file take precedence
+        !self.0[start..]
+            .iter()
+            .any(|config| config.is_same_rule(rule) && contains(config.span, span))
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// End of the innermost block, function body or file containing `span`
+fn enclosing_block_end(semantic: &Semantic, span: Span) -> u32 {
+    semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::Program(_) => Some(node.kind().span()),
+            _ => None,
+        })
+        .filter(|block| contains(*block, span))
+        .min_by_key(Span::size)
+        .map_or(span.end, |block| block.end)
+}
+
+/// Parse `rule-name: "error", other-rule: ["warn", { option: true }]`.
+///
+/// Like ESLint, keys of objects do not need to be quoted, and strings can be single quoted.
+fn parse_rules(text: &str) -> Option<OxlintRules> {
+    let json = format!("{{{}}}", to_json(text));
+    let value = serde_json::from_str::<serde_json::Value>(&json).ok()?;
+    OxlintRules::deserialize(&value).ok()
+}
+
+/// Quote unquoted object keys and convert single quoted strings into JSON strings.
+fn to_json(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 16);
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                let quote = c;
+                json.push('"');
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            // `'\''` is `"'"` in JSON
+                            match chars.next() {
+                                Some((_, '\'')) => json.push('\''),
+                                Some((_, c)) => {
+                                    json.push('\\');
+                                    json.push(c);
+                                }
+                                None => {}
+                            }
+                        }
+                        c if c == quote => break,
+                        '"' => json.push_str("\\\""),
+                        c => json.push(c),
+                    }
+                }
+                json.push('"');
+            }
+            c if is_key_char(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !is_key_char(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &text[start..end];
+                if text[end..].trim_start().starts_with(':') {
+                    json.push('"');
+                    json.push_str(word);
+                    json.push('"');
+                } else {
+                    json.push_str(word);
+                }
+            }
+            c => json.push(c),
+        }
+    }
+    json
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-' | '/' | '@' | '.')
+}
+
+/// Global variables declared by `/* global a, b: writable */` comments and environments turned on
+/// by `/* eslint-env browser, node */` comments, for the whole file
+#[derive(Debug, Default)]
+pub struct InlineGlobals {
+    globals: FxHashMap<String, GlobalValue>,
+    envs: Vec<String>,
+}
+
+impl InlineGlobals {
+    pub fn new(semantic: &Semantic) -> Self {
+        let source_text = semantic.source_text();
+        let mut inline_globals = Self::default();
+        for comment in semantic.trivias().comments() {
+            if !comment.kind.is_multi_line() {
+                continue;
+            }
+            let text = comment.span.source_text(source_text).trim_start();
+            let (text, is_env) = if let Some(text) = text.strip_prefix("eslint-env") {
+                (text, true)
+            } else if let Some(text) =
+                text.strip_prefix("globals").or_else(|| text.strip_prefix("global"))
+            {
+                (text, false)
+            } else {
+                continue;
+            };
+            if !text.starts_with(char::is_whitespace) {
+                continue;
+            }
+            // `/* global a -- description */`
+            let text = text.split_terminator("--").next().unwrap_or_default();
+            for (name, value) in parse_list(text) {
+                if is_env {
+                    inline_globals.envs.push(name.to_string());
+                    continue;
+                }
+                // `a` is `readonly`, `a: true` is `writable`
+                let value = match value {
+                    None | Some("false") => Some(GlobalValue::Readonly),
+                    Some("true") => Some(GlobalValue::Writeable),
+                    Some(value) => GlobalValue::try_from(value).ok(),
+                };
+                if let Some(value) = value {
+                    inline_globals.globals.insert(name.to_string(), value);
+                }
+            }
+        }
+        inline_globals
+    }
+
+    /// Value of the global variable `name`, if it is declared by a `/* global */` comment.
+    /// Later comments take precedence.
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        self.globals.get(name).copied()
+    }
+
+    /// Environments turned on by `/* eslint-env */` comments.
+    pub fn envs(&self) -> impl Iterator<Item = &str> + '_ {
+        self.envs.iter().map(String::as_str)
+    }
+}
+
+/// Parse `a, b: writable c:false` into names with optional values.
+///
+/// Like ESLint, items are separated by commas or whitespace, and there can be whitespace around
+/// the colons.
+fn parse_list(text: &str) -> Vec<(&str, Option<&str>)> {
+    let mut items: Vec<(&str, Option<&str>)> = vec![];
+    let mut expects_value = false;
+    for word in text.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+        for (i, part) in word.split(':').enumerate() {
+            // `a:` or `:b`
+            let is_separated = i > 0 || expects_value;
+            expects_value = false;
+            if part.is_empty() {
+                expects_value = true;
+                continue;
+            }
+            match items.last_mut() {
+                Some((_, value @ None)) if is_separated => *value = Some(part),
+                _ => items.push((part, None)),
+            }
+        }
+    }
+    items
+}
+
+#[test]
+fn test_parse_rules() {
+    let rules =
+        parse_rules(r#" no-console: ["error", { allow: ['warn', "info"] }], eqeqeq: 0 "#).unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].rule_name, "no-console");
+    assert_eq!(rules[0].severity, AllowWarnDeny::Deny);
+    assert_eq!(rules[0].config, Some(serde_json::json!([{ "allow": ["warn", "info"] }])));
+    assert_eq!(rules[1].rule_name, "eqeqeq");
+    assert_eq!(rules[1].severity, AllowWarnDeny::Allow);
+
+    let rules = parse_rules(r" react/jsx-key: 'warn' ").unwrap();
+    assert_eq!((rules[0].plugin_name.as_str(), rules[0].rule_name.as_str()), ("react", "jsx-key"));
+
+    assert_eq!(to_json(r#"{ message: 'it\'s "quoted"' }"#), r#"{ "message": "it's \"quoted\"" }"#);
+    assert!(parse_rules("no-console").is_none());
+}
+
+#[test]
+fn test_parse_list() {
+    assert_eq!(
+        parse_list(" a, b:writable c : false,d :true "),
+        [("a", None), ("b", Some("writable")), ("c", Some("false")), ("d", Some("true")),]
+    );
+    assert_eq!(parse_list(" browser,node "), [("browser", None), ("node", None)]);
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    for prefix in ["eslint", "oxlint"] {
+        let pass = vec![
+            format!("/* {prefix} no-console: 'off' */ console.log(foo);"),
+            format!("/* {prefix} no-console: 0 -- logging is fine here */ console.log(foo);"),
+            format!(
+                "/* {prefix} no-console: ['error', {{ allow: ['warn'] }}] */ console.warn(foo);"
+            ),
+            format!(
+                "
+            function foo() {{
+                /* {prefix} no-console: 'off' */
+                console.log(foo);
+            }}
+            "
+            ),
+            // Later configurations take precedence
+            format!(
+                "
+            /* {prefix} no-console: 'error' */
+            /* {prefix} no-console: 'off' */
+            console.log(foo);
+            "
+            ),
+            // Unknown rules are ignored
+            format!("/* {prefix} no-such-rule: 'error', no-console: 'off' */ console.log(foo);"),
+        ];
+
+        let fail = vec![
+            format!("console.log(foo); /* {prefix} no-console: 'off' */"),
+            format!(
+                "/* {prefix} no-console: ['error', {{ allow: ['warn'] }}] */ console.log(foo);"
+            ),
+            format!(
+                "
+            function foo() {{
+                /* {prefix} no-console: 'off' */
+            }}
+            console.log(foo);
+            "
+            ),
+            format!(
+                "
+            /* {prefix} no-console: 'off' */
+            /* {prefix} no-console: 'error' */
+            console.log(foo);
+            "
+            ),
+            // Only block comments configure rules
+            format!("// {prefix} no-console: 'off'\nconsole.log(foo);"),
+            format!("/* {prefix}-env no-console: 'off' */ console.log(foo);"),
+        ];
+
+        Tester::new("no-console", pass, fail).test();
+    }
+}
@@ -10,11 +10,13 @@ mod disable_directives;
 mod fixer;
 mod frameworks;
 mod globals;
-mod javascript_globals;
+mod inline_config;
 mod options;
 mod rule;
 mod rules;
+mod selector;
 mod service;
+mod statistics;
 mod utils;
 
 pub mod partial_loader;
@@ -24,6 +26,7 @@ use std::{io::Write, path::Path, rc::Rc, sync::Arc};
 
 use oxc_diagnostics::Error;
 use oxc_semantic::{AstNode, Semantic};
+use oxc_span::GetSpan;
 
 pub use crate::{
     config::OxlintConfig,
@@ -31,13 +34,19 @@ pub use crate::{
     fixer::FixKind,
     frameworks::FrameworkFlags,
     options::{AllowWarnDeny, LintOptions},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    rule::{
+        ConfigurationError, RuleCategory, RuleExample, RuleFixMeta, RuleMeta, RuleWithSeverity,
+    },
+    service::{LintService, LintServiceOptions, LINT_JSON_EXT},
+    statistics::{FileStatistics, LintStatistics, RuleStatistics},
 };
 use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    config::{OxlintEnv, OxlintGlobals, OxlintSettings, TestFramework},
+    context::plugin_name_to_prefix,
     fixer::{Fixer, Message},
-    rules::RuleEnum,
+    inline_config::InlineConfigs,
+    rules::{RuleEnum, RULES},
+    service::is_json_path,
     table::RuleTable,
 };
 
@@ -105,6 +114,20 @@ impl Linter {
         &self.options
     }
 
+    /// `ignorePatterns` of the configuration file.
+    pub fn ignore_patterns(&self) -> &[String] {
+        self.eslint_config.ignore_patterns()
+    }
+
+    /// `settings` of the configuration file.
+    pub(crate) fn settings(&self) -> &OxlintSettings {
+        &self.eslint_config.settings
+    }
+
+    pub(crate) fn eslint_config(&self) -> &OxlintConfig {
+        &self.eslint_config
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
@@ -113,31 +136,49 @@ impl Linter {
     pub fn run<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> Vec<Message<'a>> {
         let ctx = self.create_ctx(path, semantic);
         let semantic = Rc::clone(ctx.semantic());
-
-        let rules = self
-            .rules
-            .iter()
-            .filter(|rule| rule.should_run(&ctx))
-            .map(|rule| (rule, self.ctx_for_rule(&ctx, rule)))
-            .collect::<Vec<_>>();
-
-        for (rule, ctx) in &rules {
+        let inline_configs = InlineConfigs::new(&semantic);
+        // Rules of the JSON plugin only lint JSON files, and other rules do not lint them.
+        let is_json = is_json_path(path);
+
+        // Rules configured by `/* eslint rule-name: "error" */` comments run in addition to the
+        // rules of the configuration file, and only report diagnostics in the code they apply to.
+        let rules =
+            self.rules
+                .iter()
+                .map(|rule| (rule, None))
+                .chain(inline_configs.iter().filter_map(|(index, config)| {
+                    config.rule.as_ref().map(|rule| (rule, Some(index)))
+                }))
+                .filter(|(rule, _)| (rule.plugin_name() == "json") == is_json)
+                .filter(|(rule, _)| rule.should_run(&ctx))
+                .map(|(rule, index)| (rule, index, self.ctx_for_rule(&ctx, rule)))
+                .collect::<Vec<_>>();
+
+        for (rule, _, ctx) in &rules {
             rule.run_once(ctx);
         }
 
         for symbol in semantic.symbols().iter() {
-            for (rule, ctx) in &rules {
+            for (rule, _, ctx) in &rules {
                 rule.run_on_symbol(symbol, ctx);
             }
         }
 
         for node in semantic.nodes().iter() {
-            for (rule, ctx) in &rules {
+            for (rule, _, ctx) in &rules {
                 rule.run(node, ctx);
             }
         }
 
-        rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>()
+        rules
+            .into_iter()
+            .flat_map(|(rule, index, ctx)| {
+                let inline_configs = &inline_configs;
+                ctx.into_message().into_iter().filter(move |message| {
+                    inline_configs.is_configured_at(rule, index, message.span())
+                })
+            })
+            .collect::<Vec<_>>()
     }
 
     /// # Panics
@@ -150,6 +191,47 @@ impl Linter {
         writeln!(writer, "Total: {}", table.total).unwrap();
     }
 
+    /// Print the extended documentation of a diagnostic code, such as
+    /// `oxc(parse::unterminated-string)`, `semantic::redeclaration` or `eslint(no-debugger)`.
+    /// Returns `false` if the code is unknown.
+    ///
+    /// # Panics
+    pub fn explain<W: Write>(writer: &mut W, code: &str) -> bool {
+        let code = code.trim();
+        let (scope, name) = match code.strip_suffix(')').and_then(|code| code.split_once('(')) {
+            Some((scope, name)) => (Some(scope), name),
+            None => (None, code),
+        };
+
+        if matches!(scope, None | Some("oxc")) {
+            let explanation = oxc_parser::explain_error_code(name)
+                .or_else(|| oxc_semantic::explain_error_code(name));
+            if let Some(explanation) = explanation {
+                writeln!(writer, "oxc({name})\n\n{explanation}").unwrap();
+                return true;
+            }
+        }
+
+        let mut found = false;
+        for rule in RULES.iter().filter(|rule| {
+            rule.name() == name
+                && scope.map_or(true, |scope| {
+                    scope == rule.plugin_name()
+                        || scope == plugin_name_to_prefix(rule.plugin_name())
+                })
+        }) {
+            let plugin_name = rule.plugin_name();
+            writeln!(writer, "{}({name})\n", plugin_name_to_prefix(plugin_name)).unwrap();
+            if let Some(documentation) = rule.documentation() {
+                writeln!(writer, "{}", documentation.trim()).unwrap();
+            }
+            writeln!(writer, "\nSee {}/{plugin_name}/{name}.html", LintContext::WEBSITE_BASE_URL)
+                .unwrap();
+            found = true;
+        }
+        found
+    }
+
     fn create_ctx<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> LintContext<'a> {
         let mut ctx = LintContext::new(path.to_path_buf().into_boxed_path(), semantic)
             .with_fix(self.options.fix)
@@ -159,13 +241,23 @@ impl Linter {
         // set file-specific jest/vitest flags
         if self.options.plugins.jest || self.options.plugins.vitest {
             let mut test_flags = FrameworkFlags::empty();
+            let settings = &self.eslint_config.settings.jest;
 
-            if frameworks::has_vitest_imports(ctx.module_record()) {
-                test_flags.set(FrameworkFlags::Vitest, true);
-            } else if frameworks::is_jestlike_file(path)
-                || frameworks::has_jest_imports(ctx.module_record())
+            let has_vitest_imports = frameworks::has_vitest_imports(ctx.module_record());
+            if has_vitest_imports
+                || frameworks::is_jestlike_file(path)
+                || frameworks::has_jest_imports(ctx.module_record(), settings)
             {
-                test_flags.set(FrameworkFlags::Jest, true);
+                // `settings.jest.framework` takes precedence over imports
+                let framework = settings.framework.unwrap_or(if has_vitest_imports {
+                    TestFramework::Vitest
+                } else {
+                    TestFramework::Jest
+                });
+                match framework {
+                    TestFramework::Jest => test_flags.set(FrameworkFlags::Jest, true),
+                    TestFramework::Vitest => test_flags.set(FrameworkFlags::Vitest, true),
+                }
             }
 
             ctx = ctx.and_frameworks(test_flags);
@@ -183,7 +275,15 @@ impl Linter {
         #[cfg(not(debug_assertions))]
         let ctx = ctx.clone();
 
-        ctx.with_plugin_name(plugin_name).with_rule_name(rule_name).with_severity(rule.severity)
+        let ctx = ctx
+            .with_plugin_name(plugin_name)
+            .with_rule_name(rule_name)
+            .with_severity(rule.severity);
+        if self.options.applies_fixes_of(plugin_name, rule_name) {
+            ctx
+        } else {
+            ctx.with_fix(FixKind::None)
+        }
     }
 
     fn map_jest(&self, plugin_name: &'static str, rule_name: &str) -> &'static str {
@@ -209,6 +309,64 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn explain() {
+        for code in [
+            "oxc(parse::unterminated-string)",
+            "parse::unterminated-string",
+            "oxc(semantic::redeclaration)",
+            "eslint(no-debugger)",
+            "no-debugger",
+            "eslint-plugin-react(jsx-key)",
+            "react(jsx-key)",
+        ] {
+            let mut writer = Vec::new();
+            assert!(Linter::explain(&mut writer, code), "{code}");
+            assert!(!writer.is_empty());
+        }
+
+        for code in ["oxc(no-debugger)", "eslint(parse::unterminated-string)", "unknown-code"] {
+            assert!(!Linter::explain(&mut Vec::new(), code), "{code}");
+        }
+    }
+
+    #[test]
+    fn fix_rules() {
+        use std::{path::Path, rc::Rc};
+
+        use oxc_allocator::Allocator;
+        use oxc_parser::Parser;
+        use oxc_semantic::SemanticBuilder;
+        use oxc_span::SourceType;
+
+        use super::{AllowWarnDeny, FixKind, LintOptions};
+
+        let source_text = "debugger;";
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+        let semantic =
+            Rc::new(SemanticBuilder::new(source_text).with_cfg(true).build(&program).semantic);
+
+        for (fix_rules, is_fixed) in [
+            (vec![], true),
+            (vec!["no-debugger"], true),
+            (vec!["eslint/no-debugger"], true),
+            (vec!["no-var", "no-debugger"], true),
+            (vec!["no-var"], false),
+            (vec!["react/no-debugger"], false),
+        ] {
+            let options = LintOptions::default()
+                .with_filter(vec![(AllowWarnDeny::Deny, "no-debugger".into())])
+                .with_fix(FixKind::SafeFix)
+                .with_fix_rules(fix_rules.iter().map(ToString::to_string).collect());
+            let messages = Linter::from_options(options)
+                .unwrap()
+                .run(Path::new("test.js"), Rc::clone(&semantic));
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].fix.is_some(), is_fixed, "{fix_rules:?}");
+        }
+    }
+
     #[test]
     fn test_schema_json() {
         use std::fs;
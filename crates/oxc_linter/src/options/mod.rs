@@ -24,6 +24,10 @@ pub struct LintOptions {
     /// The kind represents the riskiest fix that the linter can apply.
     pub fix: FixKind,
 
+    /// Rules whose fixes are applied, e.g. `no-debugger` or `eslint/no-debugger`.
+    /// Fixes of all rules are applied when empty.
+    pub fix_rules: Vec<String>,
+
     pub plugins: LintPluginOptions,
 
     pub framework_hints: FrameworkFlags,
@@ -35,6 +39,7 @@ impl Default for LintOptions {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
             fix: FixKind::None,
+            fix_rules: vec![],
             plugins: LintPluginOptions::default(),
             framework_hints: FrameworkFlags::default(),
         }
@@ -72,6 +77,23 @@ impl LintOptions {
         self
     }
 
+    /// Only apply the fixes of `rules`, e.g. to apply a reviewed set of fixes in CI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxc_linter::{LintOptions, FixKind};
+    ///
+    /// LintOptions::default()
+    ///     .with_fix(FixKind::SafeFix)
+    ///     .with_fix_rules(vec!["no-debugger".into(), "unicorn/prefer-array-flat".into()]);
+    /// ```
+    #[must_use]
+    pub fn with_fix_rules(mut self, rules: Vec<String>) -> Self {
+        self.fix_rules = rules;
+        self
+    }
+
     #[must_use]
     pub fn with_react_plugin(mut self, yes: bool) -> Self {
         self.plugins.react = yes;
@@ -149,12 +171,31 @@ impl LintOptions {
         self.plugins.node = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_json_plugin(mut self, yes: bool) -> Self {
+        self.plugins.json = yes;
+        self
+    }
 }
 
 impl LintOptions {
+    /// Whether fixes of the rule `plugin_name/rule_name` are applied, see [`Self::fix_rules`].
+    pub(crate) fn applies_fixes_of(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.fix_rules.is_empty()
+            || self.fix_rules.iter().any(|name| match name.split_once('/') {
+                // `jsx-a11y/alt-text` is a rule of the `jsx_a11y` plugin
+                Some((plugin, name)) => {
+                    name == rule_name && plugin.replace('-', "_") == plugin_name
+                }
+                None => name == rule_name,
+            })
+    }
+
     /// # Errors
     ///
-    /// * Returns `Err` if there are any errors parsing the configuration file.
+    /// * Returns `Err` if there are any errors parsing the configuration file, or if the
+    ///   options of a configured rule are invalid.
     pub fn derive_rules_and_config(&self) -> Result<(Vec<RuleWithSeverity>, OxlintConfig), Error> {
         let config =
             self.config_path.as_ref().map(|path| OxlintConfig::from_file(path)).transpose()?;
@@ -209,6 +250,7 @@ impl LintOptions {
 
         if let Some(config) = &config {
             config.override_rules(&mut rules, &all_rules);
+            config.validate_rules(&all_rules)?;
         }
 
         let mut rules = rules.into_iter().collect::<Vec<_>>();
@@ -246,6 +288,7 @@ impl LintOptions {
                 "eslint" | "tree_shaking" => true,
                 "promise" => self.plugins.promise,
                 "node" => self.plugins.node,
+                "json" => self.plugins.json,
                 name => panic!("Unhandled plugin: {name}"),
             })
             .cloned()
@@ -18,6 +18,8 @@ pub struct LintPluginOptions {
     pub react_perf: bool,
     pub promise: bool,
     pub node: bool,
+    /// Lint `.json` and `.jsonc` files, such as `package.json` and `tsconfig.json`.
+    pub json: bool,
 }
 
 impl Default for LintPluginOptions {
@@ -36,6 +38,7 @@ impl Default for LintPluginOptions {
             react_perf: false,
             promise: false,
             node: false,
+            json: false,
         }
     }
 }
@@ -58,6 +61,7 @@ impl LintPluginOptions {
             react_perf: false,
             promise: false,
             node: false,
+            json: false,
         }
     }
 
@@ -78,6 +82,7 @@ impl LintPluginOptions {
             react_perf: true,
             promise: true,
             node: true,
+            json: true,
         }
     }
 }
@@ -104,6 +109,7 @@ impl<S: AsRef<str>> FromIterator<(S, bool)> for LintPluginOptions {
                 "react-perf" => options.react_perf = enabled,
                 "promise" => options.promise = enabled,
                 "node" => options.node = enabled,
+                "json" => options.json = enabled,
                 _ => { /* ignored */ }
             }
         }
@@ -135,6 +141,7 @@ mod test {
                 && self.react_perf == other.react_perf
                 && self.promise == other.promise
                 && self.node == other.node
+                && self.json == other.json
         }
     }
 
@@ -167,6 +174,7 @@ mod test {
             react_perf: false,
             promise: false,
             node: false,
+            json: false,
         };
         assert_eq!(plugins, expected);
     }
@@ -0,0 +1,133 @@
+use memchr::memmem::Finder;
+use oxc_span::SourceType;
+
+use super::{find_script_closing_angle, JavaScriptSource, SCRIPT_END, SCRIPT_START};
+
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        self.parse_scripts()
+    }
+
+    /// Every inline `<script>` of the file, excluding external scripts (`<script src="a.js">`)
+    /// and data blocks such as `<script type="application/json">`.
+    /// <https://html.spec.whatwg.org/multipage/scripting.html#the-script-element>
+    fn parse_scripts(&self) -> Vec<JavaScriptSource<'a>> {
+        let script_start_finder = Finder::new(SCRIPT_START);
+        let script_end_finder = Finder::new(SCRIPT_END);
+
+        let mut results = vec![];
+        let mut pointer = 0;
+        loop {
+            // find opening "<script"
+            let Some(offset) = script_start_finder.find(self.source_text[pointer..].as_bytes())
+            else {
+                break;
+            };
+            pointer += offset + SCRIPT_START.len();
+
+            // find closing ">"
+            let Some(offset) = find_script_closing_angle(self.source_text, pointer) else {
+                break;
+            };
+            let attributes = &self.source_text[pointer..pointer + offset];
+            pointer += offset + 1;
+            let js_start = pointer;
+
+            // find "</script>"
+            let Some(offset) = script_end_finder.find(self.source_text[pointer..].as_bytes())
+            else {
+                break;
+            };
+            let js_end = pointer + offset;
+            pointer += offset + SCRIPT_END.len();
+
+            if find_attribute(attributes, "src").is_some() {
+                continue;
+            }
+            let is_module = match find_attribute(attributes, "type") {
+                None | Some("" | "text/javascript" | "application/javascript") => false,
+                Some("module") => true,
+                Some(_) => continue,
+            };
+            let is_ts = find_attribute(attributes, "lang").is_some_and(|lang| lang == "ts");
+
+            let source_text = &self.source_text[js_start..js_end];
+            let source_type = SourceType::default().with_module(is_module).with_typescript(is_ts);
+            results.push(JavaScriptSource::new(source_text, source_type, js_start));
+        }
+        results
+    }
+}
+
+/// Find the value of the attribute `name`, e.g. `module` for `type` in `<script type="module">`.
+/// Returns an empty string for attributes without a value, e.g. `async` in `<script async>`.
+fn find_attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    attributes.match_indices(name).find_map(|(index, _)| {
+        if !attributes[..index].ends_with(char::is_whitespace) {
+            return None;
+        }
+        let rest = attributes[index + name.len()..].trim_start();
+        let Some(value) = rest.strip_prefix('=') else {
+            let is_end_of_name = rest.is_empty() || rest.starts_with(char::is_whitespace);
+            return is_end_of_name.then_some("");
+        };
+        let value = value.trim_start();
+        match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].split(quote).next(),
+            _ => value.split(char::is_whitespace).next(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_attribute, HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <html>
+          <head>
+            <script src="a.js"></script>
+            <script>
+              console.log("hi");
+            </script>
+            <script type="application/json">{ "a": 1 }</script>
+          </head>
+          <body>
+            <script type="module" lang="ts">
+              const a: number = 1;
+            </script>
+          </body>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), r#"console.log("hi");"#);
+        assert!(!sources[0].source_type.is_module());
+        assert_eq!(sources[1].source_text.trim(), "const a: number = 1;");
+        assert!(sources[1].source_type.is_module() && sources[1].source_type.is_typescript());
+        assert_eq!(&source_text[sources[1].start..].trim_start()[..5], "const");
+    }
+
+    #[test]
+    fn test_find_attribute() {
+        assert_eq!(find_attribute(r#" type="module""#, "type"), Some("module"));
+        assert_eq!(find_attribute(" type='module' async", "async"), Some(""));
+        assert_eq!(find_attribute(" type = module", "type"), Some("module"));
+        assert_eq!(find_attribute(r#" data-src="a.js""#, "src"), None);
+    }
+}
@@ -0,0 +1,141 @@
+use oxc_span::SourceType;
+
+use super::JavaScriptSource;
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        self.parse_code_blocks()
+    }
+
+    /// Fenced code blocks with a JavaScript or TypeScript info string, e.g. ```` ```ts ````.
+    /// Code blocks which are not closed end at the end of the file.
+    /// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+    fn parse_code_blocks(&self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        // The opening fence, source type and start of the current code block
+        let mut code_block: Option<(&str, Option<SourceType>, usize)> = None;
+        let mut pointer = 0;
+        for line in self.source_text.split_inclusive('\n') {
+            let line_start = pointer;
+            pointer += line.len();
+            let trimmed = line.trim();
+            match code_block {
+                None => {
+                    let Some(fence) = Self::fence(trimmed) else { continue };
+                    let info = &trimmed[fence.len()..];
+                    // The info string of a backtick fence cannot contain backticks.
+                    if fence.starts_with('`') && info.contains('`') {
+                        continue;
+                    }
+                    code_block = Some((fence, Self::source_type(info), pointer));
+                }
+                Some((fence, source_type, js_start)) => {
+                    let is_closing_fence = Self::fence(trimmed).is_some_and(|closing| {
+                        closing.len() == trimmed.len()
+                            && closing.starts_with(&fence[..1])
+                            && closing.len() >= fence.len()
+                    });
+                    if !is_closing_fence {
+                        continue;
+                    }
+                    if let Some(source_type) = source_type {
+                        let source_text = &self.source_text[js_start..line_start];
+                        results.push(JavaScriptSource::new(source_text, source_type, js_start));
+                    }
+                    code_block = None;
+                }
+            }
+        }
+        if let Some((_, Some(source_type), js_start)) = code_block {
+            let source_text = &self.source_text[js_start..];
+            results.push(JavaScriptSource::new(source_text, source_type, js_start));
+        }
+        results
+    }
+
+    /// A code fence is a sequence of at least three backticks or tildes.
+    fn fence(line: &str) -> Option<&str> {
+        let c = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+        let len = line.len() - line.trim_start_matches(c).len();
+        (len >= 3).then(|| &line[..len])
+    }
+
+    /// The language is the first word of the info string,
+    /// e.g. `tsx` for ```` ```tsx title="a.tsx" ````.
+    fn source_type(info: &str) -> Option<SourceType> {
+        let lang = info
+            .trim_start()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()
+            .unwrap_or_default();
+        let ext = match lang {
+            "javascript" => "js",
+            "typescript" => "ts",
+            lang => lang,
+        };
+        SourceType::from_path(format!("code.{ext}")).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = r#"
+# Hello
+
+```js
+console.log("hi");
+```
+
+```json
+{ "a": 1 }
+```
+
+  ```tsx title="a.tsx"
+  const a: JSX.Element = <div />;
+  ```
+"#;
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "console.log(\"hi\");\n");
+        assert_eq!(&source_text[sources[0].start..sources[0].start + 5], "conso");
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text.trim(), "const a: JSX.Element = <div />;");
+        assert!(sources[1].source_type.is_typescript() && sources[1].source_type.is_jsx());
+    }
+
+    #[test]
+    fn test_parse_markdown_nested_fence() {
+        let source_text = "````md\n```js\na\n```\n````\n~~~typescript\nlet b: number;\n~~~~\n";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "let b: number;\n");
+        assert!(sources[0].source_type.is_typescript());
+    }
+
+    #[test]
+    fn test_parse_markdown_unclosed() {
+        let source_text = "```ts\nlet a;\n";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "let a;\n");
+    }
+}
@@ -1,15 +1,21 @@
 mod astro;
+mod html;
+mod markdown;
 mod svelte;
 mod vue;
 
 use oxc_span::SourceType;
 
-pub use self::{astro::AstroPartialLoader, svelte::SveltePartialLoader, vue::VuePartialLoader};
+pub use self::{
+    astro::AstroPartialLoader, html::HtmlPartialLoader, markdown::MarkdownPartialLoader,
+    svelte::SveltePartialLoader, vue::VuePartialLoader,
+};
 
 const SCRIPT_START: &str = "<script";
 const SCRIPT_END: &str = "</script>";
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] =
+    &["vue", "astro", "svelte", "md", "mdx", "html", "htm"];
 
 #[derive(Debug, Clone, Copy)]
 pub struct JavaScriptSource<'a> {
@@ -36,6 +42,8 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "md" | "mdx" => Some(MarkdownPartialLoader::new(source_text).parse()),
+            "html" | "htm" => Some(HtmlPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
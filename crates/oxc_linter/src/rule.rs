@@ -15,6 +15,13 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Check the eslint json configuration for invalid options, such as regular expressions
+    /// which do not compile. [`Rule::from_configuration`] ignores invalid options, these errors
+    /// are reported with the location of the option in the configuration file instead.
+    fn validate_configuration(_value: &serde_json::Value) -> Vec<ConfigurationError> {
+        vec![]
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -36,6 +43,35 @@ pub trait Rule: Sized + Default + fmt::Debug {
     }
 }
 
+/// An invalid option in the configuration of a rule, see [`Rule::validate_configuration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigurationError {
+    /// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the invalid option in
+    /// the rule's configuration without the severity, e.g. `/0/patterns/1/regex`.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl ConfigurationError {
+    pub fn new<P: Into<String>, M: Into<String>>(pointer: P, message: M) -> Self {
+        Self { pointer: pointer.into(), message: message.into() }
+    }
+}
+
+/// Options of a lint rule, usually derived with `#[derive(RuleConfig)]` from `oxc_macros`.
+///
+/// ```ignore
+/// impl Rule for MaxParams {
+///     fn from_configuration(value: serde_json::Value) -> Self {
+///         Self(Box::new(MaxParamsConfig::from_configuration(value)))
+///     }
+/// }
+/// ```
+pub trait RuleConfig: Sized + Default {
+    /// Initialize from the rule's eslint json configuration, without the severity.
+    fn from_configuration(value: serde_json::Value) -> Self;
+}
+
 pub trait RuleMeta {
     const NAME: &'static str;
 
@@ -44,11 +80,27 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// Code examples from the "Examples of **incorrect** code" and "Examples of **correct**
+    /// code" sections of the documentation.
+    const EXAMPLES: &'static [RuleExample] = &[];
+
     fn documentation() -> Option<&'static str> {
         None
     }
 }
 
+/// A code block from a rule's documentation.
+///
+/// Incorrect examples must be reported by the rule with its default configuration, and correct
+/// examples must not be. Code blocks tagged with `ignore` (e.g. ` ```js ignore`) are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleExample {
+    pub incorrect: bool,
+    /// Language of the code block, one of `js`, `jsx`, `ts` or `tsx`.
+    pub lang: &'static str,
+    pub code: &'static str,
+}
+
 /// Rule categories defined by rust-clippy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RuleCategory {
@@ -256,8 +308,11 @@ impl RuleWithSeverity {
 #[cfg(test)]
 mod test {
     use markdown::{to_html_with_options, Options};
+    use oxc_macros::RuleConfig;
+    use serde_json::json;
 
-    use crate::rules::RULES;
+    use super::RuleConfig;
+    use crate::{rules::RULES, tester::Tester};
 
     #[test]
     fn ensure_documentation() {
@@ -275,4 +330,70 @@ mod test {
             assert!(!html.is_empty());
         }
     }
+
+    #[test]
+    fn ensure_documentation_examples() {
+        let mut failures = vec![];
+        for rule in RULES.iter() {
+            if rule.examples().is_empty() {
+                continue;
+            }
+            let plugin = rule.plugin_name();
+            let mut tester = Tester::new(rule.name(), Vec::<&str>::new(), vec![])
+                .with_import_plugin(plugin == "import")
+                .with_jest_plugin(plugin == "jest")
+                .with_vitest_plugin(plugin == "vitest")
+                .with_jsx_a11y_plugin(plugin == "jsx_a11y")
+                .with_nextjs_plugin(plugin == "nextjs")
+                .with_react_perf_plugin(plugin == "react_perf")
+                .with_node_plugin(plugin == "node")
+                .with_json_plugin(plugin == "json");
+            for example in tester.test_examples(rule.examples()) {
+                let expected = if example.incorrect { "reported" } else { "not reported" };
+                failures.push(format!(
+                    "{}/{}: expected example to be {expected}:\n{}",
+                    rule.plugin_name(),
+                    rule.name(),
+                    example.code
+                ));
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+    }
+
+    #[derive(Debug, PartialEq, Eq, RuleConfig)]
+    struct TestConfig {
+        /// Maximum count.
+        #[rule_config(shorthand)]
+        max: usize,
+        #[rule_config(alias = "ignore_case")]
+        ignore_case: bool,
+        #[rule_config(rename = "typeof")]
+        type_of: bool,
+    }
+
+    impl Default for TestConfig {
+        fn default() -> Self {
+            Self { max: 3, ignore_case: false, type_of: false }
+        }
+    }
+
+    #[test]
+    fn derive_rule_config() {
+        let config = TestConfig::from_configuration;
+        assert_eq!(config(json!([])), TestConfig::default());
+        assert_eq!(config(json!([5])), TestConfig { max: 5, ..TestConfig::default() });
+        assert_eq!(
+            config(json!([{ "max": 1, "ignoreCase": true, "typeof": true }])),
+            TestConfig { max: 1, ignore_case: true, type_of: true }
+        );
+        assert_eq!(
+            config(json!([{ "ignore_case": true, "max": "invalid" }])),
+            TestConfig { ignore_case: true, ..TestConfig::default() }
+        );
+
+        let schema = serde_json::to_value(schemars::schema_for!(TestConfig)).unwrap();
+        assert_eq!(schema["properties"]["max"]["description"], "Maximum count.");
+        assert_eq!(schema["properties"]["typeof"]["type"], "boolean");
+    }
 }
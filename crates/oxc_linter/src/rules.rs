@@ -72,6 +72,7 @@ mod eslint {
     pub mod no_eval;
     pub mod no_ex_assign;
     pub mod no_extra_boolean_cast;
+    pub mod no_extra_label;
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
@@ -94,6 +95,8 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
@@ -364,6 +367,7 @@ mod jsx_a11y {
     pub mod html_has_lang;
     pub mod iframe_has_title;
     pub mod img_redundant_alt;
+    pub mod interactive_supports_focus;
     pub mod label_has_associated_control;
     pub mod lang;
     pub mod media_has_caption;
@@ -372,6 +376,7 @@ mod jsx_a11y {
     pub mod no_aria_hidden_on_focusable;
     pub mod no_autofocus;
     pub mod no_distracting_elements;
+    pub mod no_noninteractive_element_interactions;
     pub mod no_redundant_roles;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
@@ -480,6 +485,12 @@ mod node {
     pub mod no_exports_assign;
 }
 
+mod json {
+    pub mod no_duplicate_dependencies;
+    pub mod valid_semver_ranges;
+    pub mod valid_tsconfig;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::constructor_super,
@@ -529,6 +540,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_eval,
     eslint::no_ex_assign,
     eslint::no_extra_boolean_cast,
+    eslint::no_extra_label,
     eslint::no_eq_null,
     eslint::no_fallthrough,
     eslint::no_func_assign,
@@ -583,6 +595,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::prefer_exponentiation_operator,
     eslint::prefer_numeric_literals,
     eslint::no_constructor_return,
@@ -831,6 +845,8 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::no_distracting_elements,
     jsx_a11y::role_supports_aria_props,
     jsx_a11y::autocomplete_valid,
+    jsx_a11y::interactive_supports_focus,
+    jsx_a11y::no_noninteractive_element_interactions,
     oxc::approx_constant,
     oxc::bad_array_method_on_arguments,
     oxc::bad_bitwise_operator,
@@ -909,4 +925,7 @@ oxc_macros::declare_all_lint_rules! {
     vitest::no_conditional_tests,
     vitest::require_local_test_context_for_concurrent_snapshots,
     node::no_exports_assign,
+    json::no_duplicate_dependencies,
+    json::valid_semver_ranges,
+    json::valid_tsconfig,
 }
@@ -121,7 +121,7 @@ declare_oxc_lint!(
     /// Foo.prototype.bar = function bar() {};
     /// ```
     ///
-    /// Examples of **correct* code for this rule:
+    /// Examples of **correct** code for this rule:
     ///
     /// ```javascript
     /// /*oxlint func-names: "error" */
@@ -1,9 +1,11 @@
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
-use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+};
 
 fn max_lines_diagnostic(count: usize, max: usize, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("File has too many lines ({count})."))
@@ -14,8 +16,9 @@ fn max_lines_diagnostic(count: usize, max: usize, span: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MaxLines(Box<MaxLinesConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, RuleConfig)]
 pub struct MaxLinesConfig {
+    #[rule_config(shorthand)]
     max: usize,
     skip_blank_lines: bool,
     skip_comments: bool,
@@ -51,31 +54,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for MaxLines {
-    fn from_configuration(value: Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxLinesConfig { max, skip_comments: false, skip_blank_lines: false }))
-        } else {
-            let max = config
-                .and_then(|config| config.get("max"))
-                .and_then(Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(300, |v| usize::try_from(v).unwrap_or(300));
-            let skip_comments = config
-                .and_then(|config| config.get("skipComments"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-            let skip_blank_lines = config
-                .and_then(|config| config.get("skipBlankLines"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-
-            Self(Box::new(MaxLinesConfig { max, skip_blank_lines, skip_comments }))
-        }
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(MaxLinesConfig::from_configuration(value)))
     }
 
     #[allow(clippy::cast_possible_truncation)]
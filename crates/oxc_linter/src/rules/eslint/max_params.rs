@@ -1,10 +1,13 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
-use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn max_params_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(x0.to_string())
@@ -17,8 +20,9 @@ fn max_params_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MaxParams(Box<MaxParamsConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, RuleConfig)]
 pub struct MaxParamsConfig {
+    #[rule_config(shorthand)]
     max: usize,
 }
 
@@ -54,23 +58,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for MaxParams {
-    fn from_configuration(value: Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxParamsConfig { max }))
-        } else {
-            let max = config
-                .and_then(|config| config.get("max"))
-                .and_then(Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(3, |v| usize::try_from(v).unwrap_or(3));
-
-            Self(Box::new(MaxParamsConfig { max }))
-        }
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(MaxParamsConfig::from_configuration(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -1,11 +1,15 @@
 use itertools::Itertools;
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn no_extra_double_negation_cast_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Redundant double negation")
@@ -19,7 +23,7 @@ fn no_extra_boolean_cast_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoExtraBooleanCast {
     pub enforce_for_logical_operands: bool,
 }
@@ -52,13 +56,7 @@ declare_oxc_lint!(
 
 impl Rule for NoExtraBooleanCast {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            enforce_for_logical_operands: value
-                .get(0)
-                .and_then(|x| x.get("enforceForLogicalOperands"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
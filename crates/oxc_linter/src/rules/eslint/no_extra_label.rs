@@ -0,0 +1,186 @@
+use oxc_ast::{
+    ast::{BreakStatement, ContinueStatement, LabelIdentifier},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_extra_label_diagnostic(label_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Disallow unnecessary labels")
+        .with_help(format!("This label '{label_name}' is unnecessary."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraLabel;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary labels
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a loop contains no nested loops or switches, labeling the loop is unnecessary.
+    /// Labels on `break` and `continue` statements which jump to the nearest enclosing loop or
+    /// switch can be removed without changing the behavior of the code, and only make it harder
+    /// to read.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// A: while (a) {
+    ///     break A;
+    /// }
+    ///
+    /// B: for (let i = 0; i < 10; ++i) {
+    ///     continue B;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// while (a) {
+    ///     break;
+    /// }
+    ///
+    /// A: while (a) {
+    ///     while (b) {
+    ///         break A;
+    ///     }
+    /// }
+    /// ```
+    NoExtraLabel,
+    style,
+    fix
+);
+
+impl Rule for NoExtraLabel {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BreakStatement(BreakStatement { label: Some(label), span }) => {
+                check_jump(node, label, *span, "break", ctx);
+            }
+            AstKind::ContinueStatement(ContinueStatement { label: Some(label), span }) => {
+                check_jump(node, label, *span, "continue", ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_jump<'a>(
+    node: &AstNode<'a>,
+    label: &LabelIdentifier<'a>,
+    span: Span,
+    keyword: &str,
+    ctx: &LintContext<'a>,
+) {
+    let Some(label_id) = ctx.semantic().labels().get_target(node.id()) else {
+        return;
+    };
+    let Some(target_id) = innermost_breakable(node, ctx) else {
+        return;
+    };
+    // `A: B: while (a) { break A; }`
+    let is_same_target = ctx
+        .nodes()
+        .ancestors(target_id)
+        .skip(1)
+        .map_while(|id| matches!(ctx.nodes().kind(id), AstKind::LabeledStatement(_)).then_some(id))
+        .any(|id| id == label_id);
+    if !is_same_target {
+        return;
+    }
+
+    let diagnostic = no_extra_label_diagnostic(label.name.as_str(), label.span);
+    // `break A` -> `break`
+    #[allow(clippy::cast_possible_truncation)]
+    let fix_span = Span::new(span.start + keyword.len() as u32, label.span.end);
+    if ctx.semantic().trivias().has_comments_between(fix_span) {
+        ctx.diagnostic(diagnostic);
+    } else {
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(fix_span));
+    }
+}
+
+/// The nearest enclosing loop or `switch` of `node` in the current function.
+///
+/// Like ESLint, `continue A` is not reported inside a `switch`, even though an unlabeled
+/// `continue` would skip the `switch` and target the same loop.
+fn innermost_breakable(node: &AstNode, ctx: &LintContext) -> Option<AstNodeId> {
+    for id in ctx.nodes().ancestors(node.id()).skip(1) {
+        match ctx.nodes().kind(id) {
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::StaticBlock(_) => {
+                return None;
+            }
+            kind if kind.is_iteration_statement() => return Some(id),
+            AstKind::SwitchStatement(_) => return Some(id),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "A: break A;",
+        "A: { if (a) break A; }",
+        "A: { while (b) { break A; } }",
+        "A: { switch (b) { case 0: break A; } }",
+        "A: while (a) { while (b) { break; } break; }",
+        "A: while (a) { while (b) { break A; } }",
+        "A: while (a) { while (b) { continue A; } }",
+        "A: while (a) { switch (b) { case 0: break A; } }",
+        "A: while (a) { switch (b) { case 0: continue A; } }",
+        "A: switch (a) { case 0: while (b) { break A; } }",
+        "A: switch (a) { case 0: switch (b) { case 0: break A; } }",
+        "A: for (;;) { while (b) { break A; } }",
+        "A: do { switch (b) { case 0: break A; break; } } while (a);",
+        "A: for (a in obj) { while (b) { break A; } }",
+        "A: for (a of ary) { switch (b) { case 0: break A; } }",
+    ];
+
+    let fail = vec![
+        "A: while (a) break A;",
+        "A: while (a) { B: { continue A; } }",
+        "X: while (x) { A: while (a) { B: { break A; break B; continue X; } } }",
+        "A: do { break A; } while (a);",
+        "A: for (;;) { break A; }",
+        "A: for (a in obj) { break A; }",
+        "A: for (a of ary) { break A; }",
+        "A: switch (a) { case 0: break A; }",
+        "X: while (x) { A: switch (a) { case 0: break A; } }",
+        "X: switch (a) { case 0: A: while (b) break A; }",
+        "A: B: while (a) { break A; }",
+        "A: while (a) break/**/ A;",
+        "A: while (a) break /**/A;",
+        "A: while (a) break A/**/;",
+    ];
+
+    let fix = vec![
+        ("A: while (a) break A;", "A: while (a) break;"),
+        ("A: while (a) { B: { continue A; } }", "A: while (a) { B: { continue; } }"),
+        (
+            "X: while (x) { A: while (a) { B: { break A; break B; continue X; } } }",
+            "X: while (x) { A: while (a) { B: { break; break B; continue X; } } }",
+        ),
+        ("A: do { break A; } while (a);", "A: do { break; } while (a);"),
+        ("A: switch (a) { case 0: break A; }", "A: switch (a) { case 0: break; }"),
+        ("A: B: while (a) { break A; }", "A: B: while (a) { break; }"),
+        ("A: while (a) break/**/ A;", "A: while (a) break/**/ A;"),
+        ("A: while (a) break A/**/;", "A: while (a) break/**/;"),
+    ];
+
+    Tester::new(NoExtraLabel::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
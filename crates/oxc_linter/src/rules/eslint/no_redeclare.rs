@@ -3,11 +3,14 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
 use oxc_syntax::symbol::SymbolId;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+};
 
 fn no_redeclare_diagnostic(id_name: &str, decl_span: Span, re_decl_span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{id_name}' is already defined.")).with_labels([
@@ -25,8 +28,9 @@ fn no_redeclare_as_builtin_in_diagnostic(builtin_name: &str, span: Span) -> OxcD
     )
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoRedeclare {
+    #[rule_config(rename = "builtinGlobals")]
     built_in_globals: bool,
 }
 
@@ -50,13 +54,7 @@ declare_oxc_lint!(
 
 impl Rule for NoRedeclare {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let built_in_globals = value
-            .get(0)
-            .and_then(|config| config.get("builtinGlobals"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { built_in_globals }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
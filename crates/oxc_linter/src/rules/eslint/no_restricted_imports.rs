@@ -0,0 +1,676 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_ast::{ast::ImportDeclarationSpecifier, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::{ConfigurationError, Rule},
+    AstNode,
+};
+
+fn with_custom_message(text: String, message: Option<&str>) -> OxcDiagnostic {
+    match message {
+        Some(message) => OxcDiagnostic::warn(format!("{text} {message}")),
+        None => OxcDiagnostic::warn(text),
+    }
+}
+
+fn restricted_source_diagnostic(
+    source: &str,
+    by_pattern: bool,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let by_pattern = if by_pattern { " by a pattern" } else { "" };
+    with_custom_message(
+        format!("'{source}' import is restricted from being used{by_pattern}."),
+        message,
+    )
+    .with_label(span)
+}
+
+fn restricted_import_name_diagnostic(
+    import_name: &str,
+    source: &str,
+    by_pattern: bool,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let by_pattern = if by_pattern { " from being used by a pattern" } else { "" };
+    with_custom_message(
+        format!("'{import_name}' import from '{source}' is restricted{by_pattern}."),
+        message,
+    )
+    .with_label(span)
+}
+
+fn not_allowed_import_name_diagnostic(
+    import_name: &str,
+    source: &str,
+    allowed: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    with_custom_message(
+        format!("'{import_name}' import from '{source}' is restricted because only '{allowed}' import(s) is/are allowed."),
+        message,
+    )
+    .with_label(span)
+}
+
+fn restricted_everything_diagnostic(
+    import_names: &str,
+    source: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    with_custom_message(
+        format!("* import is invalid because '{import_names}' from '{source}' is restricted."),
+        message,
+    )
+    .with_label(span)
+}
+
+fn not_allowed_everything_diagnostic(
+    allowed: &str,
+    source: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    with_custom_message(
+        format!("* import is invalid because only '{allowed}' from '{source}' is/are allowed."),
+        message,
+    )
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPattern>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: CompactStr,
+    message: Option<CompactStr>,
+    import_names: ImportNames,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPattern {
+    matcher: PatternMatcher,
+    message: Option<CompactStr>,
+    import_names: ImportNames,
+}
+
+#[derive(Debug, Clone)]
+enum PatternMatcher {
+    /// `gitignore` style patterns, e.g. `["lodash/*", "!lodash/pick"]`
+    Group(Gitignore),
+    Regex(Regex),
+}
+
+impl PatternMatcher {
+    fn is_match(&self, source: &str) -> bool {
+        match self {
+            // Absolute paths are matched relative to the root, as `ignore` only accepts relative paths
+            Self::Group(gitignore) => gitignore
+                .matched_path_or_any_parents(source.trim_start_matches('/'), false)
+                .is_ignore(),
+            Self::Regex(regex) => regex.is_match(source),
+        }
+    }
+}
+
+/// Names which may or may not be imported from a restricted source.
+/// When all are empty, the source may not be imported at all.
+#[derive(Debug, Default, Clone)]
+struct ImportNames {
+    restricted: Option<Vec<CompactStr>>,
+    allowed: Option<Vec<CompactStr>>,
+    /// Only for patterns
+    restricted_pattern: Option<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified modules when loaded by `import` or re-exported by `export ... from`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some imports might not make sense in a particular environment, e.g. Node.js' `fs` in browser code.
+    /// Other modules may be deprecated, or have a preferred replacement within the project.
+    ///
+    /// ### Example
+    ///
+    /// With the following options:
+    /// ```json
+    /// "no-restricted-imports": ["error", {
+    ///     "paths": [
+    ///         "fs",
+    ///         { "name": "lodash", "importNames": ["pick"], "message": "Use `pick` from `utils` instead." }
+    ///     ],
+    ///     "patterns": [
+    ///         { "group": ["internal/*", "!internal/public"], "message": "Import from `internal/public` instead." }
+    ///     ]
+    /// }]
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /*oxlint no-restricted-imports: ["error", { "paths": ["fs", { "name": "lodash", "importNames": ["pick"] }], "patterns": ["internal/*", "!internal/public"] }] */
+    /// import fs from 'fs';
+    /// import { pick } from 'lodash';
+    /// export * from 'internal/helpers';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { merge } from 'lodash';
+    /// import { helpers } from 'internal/public';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// Each of `paths` is a module name, or an object with:
+    /// * `name`: the module name
+    /// * `message`: a custom message appended to the diagnostic
+    /// * `importNames`: names which may not be imported from the module, instead of the whole module
+    /// * `allowImportNames`: the only names which may be imported from the module
+    ///
+    /// Each of `patterns` is a [gitignore style](https://git-scm.com/docs/gitignore#_pattern_format)
+    /// pattern, or an object with:
+    /// * `group`: gitignore style patterns, or `regex`: a regular expression matching module names
+    /// * `caseSensitive`: whether patterns are matched case-sensitively, `false` by default
+    /// * `message`, `importNames` and `allowImportNames`, like for `paths`
+    /// * `importNamePattern`: a regular expression matching names which may not be imported
+    ///
+    /// The modules may also be given directly, e.g. `["error", "fs", { "name": "lodash" }]`.
+    NoRestrictedImports,
+    restriction,
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: Value) -> Self {
+        let Value::Array(options) = value else {
+            return Self::default();
+        };
+        let mut config = NoRestrictedImportsConfig::default();
+
+        match options.first().and_then(Value::as_object) {
+            // "no-restricted-imports": ["error", { "paths": [...], "patterns": [...] }]
+            Some(obj) if obj.contains_key("paths") || obj.contains_key("patterns") => {
+                if let Some(paths) = obj.get("paths").and_then(Value::as_array) {
+                    config.paths = paths.iter().filter_map(RestrictedPath::from_value).collect();
+                }
+                if let Some(patterns) = obj.get("patterns").and_then(Value::as_array) {
+                    config.patterns = RestrictedPattern::from_values(patterns);
+                }
+            }
+            // "no-restricted-imports": ["error", "fs", { "name": "lodash" }]
+            _ => {
+                config.paths = options.iter().filter_map(RestrictedPath::from_value).collect();
+            }
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn validate_configuration(value: &Value) -> Vec<ConfigurationError> {
+        let Some(patterns) = value.pointer("/0/patterns").and_then(Value::as_array) else {
+            return vec![];
+        };
+        let mut errors = vec![];
+        for (i, pattern) in patterns.iter().enumerate() {
+            let pointer = format!("/0/patterns/{i}");
+            match pattern {
+                Value::String(line) => {
+                    if let Some(err) = glob_error(line) {
+                        errors.push(ConfigurationError::new(pointer, err));
+                    }
+                }
+                Value::Object(obj) => {
+                    if let Some(regex) = obj.get("regex").and_then(Value::as_str) {
+                        if let Err(err) = Regex::new(regex) {
+                            errors.push(ConfigurationError::new(
+                                format!("{pointer}/regex"),
+                                regex_error(&err),
+                            ));
+                        }
+                    }
+                    let group = obj.get("group").and_then(Value::as_array).into_iter().flatten();
+                    for (j, line) in group.enumerate() {
+                        let Some(err) = line.as_str().and_then(glob_error) else { continue };
+                        errors.push(ConfigurationError::new(format!("{pointer}/group/{j}"), err));
+                    }
+                    if let Some(regex) = obj.get("importNamePattern").and_then(Value::as_str) {
+                        if let Err(err) = Regex::new(regex) {
+                            errors.push(ConfigurationError::new(
+                                format!("{pointer}/importNamePattern"),
+                                regex_error(&err),
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        errors
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (source, span, imported) = match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let imported = decl
+                    .specifiers
+                    .iter()
+                    .flatten()
+                    .map(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            (CompactStr::from(specifier.imported.name().as_str()), specifier.span)
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            (CompactStr::new_const("default"), specifier.span)
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            (CompactStr::new_const("*"), specifier.span)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (&decl.source, decl.span, imported)
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else {
+                    return;
+                };
+                let imported = decl
+                    .specifiers
+                    .iter()
+                    .map(|specifier| {
+                        (CompactStr::from(specifier.local.name().as_str()), specifier.span)
+                    })
+                    .collect::<Vec<_>>();
+                (source, decl.span, imported)
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                (&decl.source, decl.span, vec![(CompactStr::new_const("*"), decl.span)])
+            }
+            _ => return,
+        };
+        let source = source.value.as_str();
+
+        for path in self.paths.iter().filter(|path| path.name == source) {
+            path.import_names.check(source, span, &imported, false, path.message.as_deref(), ctx);
+        }
+        for pattern in self.patterns.iter().filter(|pattern| pattern.matcher.is_match(source)) {
+            pattern.import_names.check(
+                source,
+                span,
+                &imported,
+                true,
+                pattern.message.as_deref(),
+                ctx,
+            );
+        }
+    }
+
+    fn should_run(&self, _ctx: &LintContext) -> bool {
+        !self.paths.is_empty() || !self.patterns.is_empty()
+    }
+}
+
+impl RestrictedPath {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(name) => Some(Self {
+                name: name.as_str().into(),
+                message: None,
+                import_names: ImportNames::default(),
+            }),
+            Value::Object(obj) => Some(Self {
+                name: obj.get("name").and_then(Value::as_str)?.into(),
+                message: obj.get("message").and_then(Value::as_str).map(Into::into),
+                import_names: ImportNames {
+                    restricted: to_names(obj.get("importNames")),
+                    allowed: to_names(obj.get("allowImportNames")),
+                    restricted_pattern: None,
+                },
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl RestrictedPattern {
+    fn from_values(values: &[Value]) -> Vec<Self> {
+        // String patterns are a single group, e.g. `"patterns": ["lodash/*", "!lodash/pick"]`
+        let lines = values.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+        let mut patterns = Vec::with_capacity(values.len());
+        if let Some(gitignore) = build_gitignore(&lines, false) {
+            patterns.push(Self {
+                matcher: PatternMatcher::Group(gitignore),
+                message: None,
+                import_names: ImportNames::default(),
+            });
+        }
+        patterns.extend(values.iter().filter_map(Value::as_object).filter_map(|obj| {
+            let case_sensitive =
+                obj.get("caseSensitive").and_then(Value::as_bool).unwrap_or_default();
+            let matcher = if let Some(regex) = obj.get("regex").and_then(Value::as_str) {
+                PatternMatcher::Regex(build_regex(regex, case_sensitive)?)
+            } else {
+                let group = obj.get("group").and_then(Value::as_array)?;
+                let lines = group.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+                PatternMatcher::Group(build_gitignore(&lines, case_sensitive)?)
+            };
+            Some(Self {
+                matcher,
+                message: obj.get("message").and_then(Value::as_str).map(Into::into),
+                import_names: ImportNames {
+                    restricted: to_names(obj.get("importNames")),
+                    allowed: to_names(obj.get("allowImportNames")),
+                    restricted_pattern: obj
+                        .get("importNamePattern")
+                        .and_then(Value::as_str)
+                        .and_then(|pattern| Regex::new(pattern).ok()),
+                },
+            })
+        }));
+        patterns
+    }
+}
+
+impl ImportNames {
+    fn is_empty(&self) -> bool {
+        self.restricted.is_none() && self.allowed.is_none() && self.restricted_pattern.is_none()
+    }
+
+    fn check(
+        &self,
+        source: &str,
+        span: Span,
+        imported: &[(CompactStr, Span)],
+        by_pattern: bool,
+        message: Option<&str>,
+        ctx: &LintContext,
+    ) {
+        if self.is_empty() {
+            ctx.diagnostic(restricted_source_diagnostic(source, by_pattern, message, span));
+            return;
+        }
+
+        for (name, name_span) in imported {
+            let span = *name_span;
+            if name == "*" {
+                if let Some(restricted) = &self.restricted {
+                    ctx.diagnostic(restricted_everything_diagnostic(
+                        &restricted.join(", "),
+                        source,
+                        message,
+                        span,
+                    ));
+                } else if let Some(allowed) = &self.allowed {
+                    ctx.diagnostic(not_allowed_everything_diagnostic(
+                        &allowed.join(", "),
+                        source,
+                        message,
+                        span,
+                    ));
+                } else {
+                    ctx.diagnostic(restricted_source_diagnostic(source, by_pattern, message, span));
+                }
+                continue;
+            }
+
+            if self.restricted.as_ref().is_some_and(|restricted| restricted.contains(name))
+                || self.restricted_pattern.as_ref().is_some_and(|pattern| pattern.is_match(name))
+            {
+                ctx.diagnostic(restricted_import_name_diagnostic(
+                    name, source, by_pattern, message, span,
+                ));
+            } else if let Some(allowed) =
+                self.allowed.as_ref().filter(|allowed| !allowed.contains(name))
+            {
+                ctx.diagnostic(not_allowed_import_name_diagnostic(
+                    name,
+                    source,
+                    &allowed.join(", "),
+                    message,
+                    span,
+                ));
+            }
+        }
+    }
+}
+
+fn to_names(value: Option<&Value>) -> Option<Vec<CompactStr>> {
+    value
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).map(Into::into).collect())
+}
+
+fn build_gitignore(lines: &[&str], case_sensitive: bool) -> Option<Gitignore> {
+    if lines.is_empty() {
+        return None;
+    }
+    let mut builder = GitignoreBuilder::new("");
+    builder.case_insensitive(!case_sensitive).ok()?;
+    for line in lines {
+        builder.add_line(None, line).ok()?;
+    }
+    builder.build().ok()
+}
+
+fn build_regex(pattern: &str, case_sensitive: bool) -> Option<Regex> {
+    RegexBuilder::new(pattern).case_insensitive(!case_sensitive).build().ok()
+}
+
+/// The error of an invalid `.gitignore` pattern, e.g. `a/[b` with an unclosed character class.
+fn glob_error(line: &str) -> Option<String> {
+    GitignoreBuilder::new("").add_line(None, line).err().map(|err| err.to_string())
+}
+
+/// The reason of an invalid regular expression, e.g. `unclosed group` for `(`.
+fn regex_error(err: &regex::Error) -> String {
+    // Syntax errors repeat the pattern with a caret before the reason on the last line.
+    let err = err.to_string();
+    let reason = err.lines().last().unwrap_or_default();
+    format!("Invalid regular expression: {}", reason.trim_start_matches("error: "))
+}
+
+#[test]
+fn test_validate_configuration() {
+    let errors = NoRestrictedImports::validate_configuration(&serde_json::json!([{
+        "paths": ["fs"],
+        "patterns": ["lodash/*", { "regex": "(", "importNamePattern": "^foo" }, { "group": ["a/[b"] }],
+    }]));
+    assert_eq!(
+        errors.iter().map(|err| err.pointer.as_str()).collect::<Vec<_>>(),
+        ["/0/patterns/1/regex", "/0/patterns/2/group/0"]
+    );
+    assert_eq!(errors[0].message, "Invalid regular expression: unclosed group");
+
+    let errors = NoRestrictedImports::validate_configuration(&serde_json::json!(["fs"]));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(serde_json::json!(["osx"]))),
+        ("import fs from 'fs';", Some(serde_json::json!(["crypto"]))),
+        ("import path from 'path';", Some(serde_json::json!(["crypto", "stream", "os"]))),
+        ("import async from 'async';", Some(serde_json::json!(["fs"]))),
+        ("import 'foo'", Some(serde_json::json!(["crypto"]))),
+        ("import 'foo/bar';", Some(serde_json::json!(["foo"]))),
+        (
+            "import withPaths from 'foo/bar';",
+            Some(serde_json::json!([{ "paths": ["foo", "bar"] }])),
+        ),
+        (
+            "import withPatterns from 'foo/bar';",
+            Some(serde_json::json!([{ "patterns": ["foo/c*"] }])),
+        ),
+        (
+            "import withGitignores from 'foo/bar';",
+            Some(serde_json::json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import withPatternsCaseSensitive from 'foo';",
+            Some(
+                serde_json::json!([{ "patterns": [{ "group": ["FOO"], "caseSensitive": true }] }]),
+            ),
+        ),
+        (
+            "import withRegex from 'foo/bar';",
+            Some(serde_json::json!([{ "patterns": [{ "regex": "foo/baz" }] }])),
+        ),
+        (
+            "import AllowedObject from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import { AllowedObject } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import { 'AllowedObject' as bar } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        (
+            "export { bar } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import { AllowedObject } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "allowImportNames": ["AllowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import { Foo } from '../../my/relative-module';",
+            Some(
+                serde_json::json!([{ "patterns": [{ "group": ["**/my/relative-module"], "importNamePattern": "^Bar" }] }]),
+            ),
+        ),
+        ("export const foo = 1;", Some(serde_json::json!(["foo"]))),
+        ("import foo from 'foo';", Some(serde_json::json!([{ "patterns": [] }]))),
+    ];
+
+    let fail = vec![
+        ("import \"fs\"", Some(serde_json::json!(["fs"]))),
+        ("import os from \"os\";", Some(serde_json::json!(["fs", "crypto ", "stream", "os"]))),
+        ("import \"foo/bar\";", Some(serde_json::json!(["foo/bar"]))),
+        ("import withPaths from \"foo/bar\";", Some(serde_json::json!([{ "paths": ["foo/bar"] }]))),
+        (
+            "import withPatterns from \"foo/bar\";",
+            Some(serde_json::json!([{ "patterns": ["foo"] }])),
+        ),
+        (
+            "import withPatterns from \"foo/bar\";",
+            Some(serde_json::json!([{ "patterns": ["bar"] }])),
+        ),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                serde_json::json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use foo/bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatternsCaseInsensitive from 'foo';",
+            Some(serde_json::json!([{ "patterns": [{ "group": ["FOO"] }] }])),
+        ),
+        (
+            "import withRegex from 'foo/baz';",
+            Some(serde_json::json!([{ "patterns": [{ "regex": "foo/ba[zr]" }] }])),
+        ),
+        ("export * from \"fs\";", Some(serde_json::json!(["fs"]))),
+        ("export { a } from \"fs\";", Some(serde_json::json!(["fs"]))),
+        (
+            "import withGitignores from \"foo/bar\";",
+            Some(serde_json::json!([{ "patterns": ["foo/*", "!foo/baz"] }])),
+        ),
+        (
+            "import DisallowedObject from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["default"], "message": "Please import the default import of 'foo' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import { DisallowedObject } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"], "message": "Please import 'DisallowedObject' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "export { DisallowedObject as AllowedObject } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import * as All from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject", "DisallowedObjectTwo"] }] }]),
+            ),
+        ),
+        (
+            "import { AllowedObject, DisallowedObject } from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "allowImportNames": ["AllowedObject"] }] }]),
+            ),
+        ),
+        (
+            "export * from \"foo\";",
+            Some(
+                serde_json::json!([{ "paths": [{ "name": "foo", "allowImportNames": ["AllowedObject"] }] }]),
+            ),
+        ),
+        (
+            "import { Bar } from '../../my/relative-module';",
+            Some(
+                serde_json::json!([{ "patterns": [{ "group": ["**/my/relative-module"], "importNamePattern": "^Bar" }] }]),
+            ),
+        ),
+        (
+            "import { Foo } from 'lodash/foo';",
+            Some(
+                serde_json::json!([{ "patterns": [{ "group": ["lodash/*"], "importNames": ["Foo"] }] }]),
+            ),
+        ),
+        (
+            "import foo from 'foo';",
+            Some(serde_json::json!(["foo", { "name": "foo", "message": "Use bar instead." }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,215 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::{ConfigurationError, Rule},
+    selector::Selector,
+    AstNode,
+};
+
+fn no_restricted_syntax_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message.to_string()).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    message: CompactStr,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified syntax, using
+    /// [selectors](https://eslint.org/docs/latest/extend/selectors) to match nodes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them.
+    /// Some projects restrict the use of certain constructs, such as `with` statements or `for...in` loops.
+    ///
+    /// Selectors match [ESTree](https://github.com/estree/estree) node types, attributes such as
+    /// `[operator="=="]` or `[callee.name=/^set/]`, and the descendant (`A B`) and child (`A > B`)
+    /// combinators. Other selector syntax is not supported, and invalid selectors are ignored.
+    ///
+    /// ### Example
+    ///
+    /// With the following options:
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///     "error",
+    ///     "WithStatement",
+    ///     { "selector": "CallExpression[callee.name='setTimeout']", "message": "Use the scheduler instead." }
+    /// ]
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /*oxlint no-restricted-syntax: ["error", "WithStatement", "CallExpression[callee.name='setTimeout']"] */
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    /// setTimeout(run, 100);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// me.dontMess();
+    /// scheduler.postTask(run, { delay: 100 });
+    /// ```
+    NoRestrictedSyntax,
+    restriction,
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let Value::Array(options) = value else {
+            return Self::default();
+        };
+        let restrictions = options
+            .iter()
+            .filter_map(|option| {
+                // "no-restricted-syntax": ["error", "WithStatement"]
+                // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                let (source, message) = match option {
+                    Value::String(source) => (source.as_str(), None),
+                    Value::Object(obj) => (
+                        obj.get("selector").and_then(Value::as_str)?,
+                        obj.get("message").and_then(Value::as_str),
+                    ),
+                    _ => return None,
+                };
+                let selector = Selector::parse(source).ok()?;
+                let message = message.map_or_else(
+                    || CompactStr::from(format!("Using '{source}' is not allowed.")),
+                    CompactStr::from,
+                );
+                Some(RestrictedSyntax { selector, message })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedSyntaxConfig { restrictions }))
+    }
+
+    fn validate_configuration(value: &Value) -> Vec<ConfigurationError> {
+        let Value::Array(options) = value else {
+            return vec![];
+        };
+        let mut errors = vec![];
+        for (i, option) in options.iter().enumerate() {
+            let (pointer, source) = match option {
+                Value::String(source) => (format!("/{i}"), source.as_str()),
+                Value::Object(obj) => {
+                    let Some(source) = obj.get("selector").and_then(Value::as_str) else {
+                        continue;
+                    };
+                    (format!("/{i}/selector"), source)
+                }
+                _ => continue,
+            };
+            if let Err(err) = Selector::parse(source) {
+                errors.push(ConfigurationError::new(pointer, err.message.to_string()));
+            }
+        }
+        errors
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restriction in &self.restrictions {
+            if restriction.selector.matches(node, ctx.nodes()) {
+                ctx.diagnostic(no_restricted_syntax_diagnostic(
+                    &restriction.message,
+                    node.kind().span(),
+                ));
+            }
+        }
+    }
+
+    fn should_run(&self, _ctx: &LintContext) -> bool {
+        !self.restrictions.is_empty()
+    }
+}
+
+#[test]
+fn test_validate_configuration() {
+    let errors = NoRestrictedSyntax::validate_configuration(&serde_json::json!([
+        "WithStatement",
+        ":not(WithStatement)",
+        { "selector": "A ~ B", "message": "..." },
+    ]));
+    assert_eq!(
+        errors.iter().map(|err| err.pointer.as_str()).collect::<Vec<_>>(),
+        ["/1", "/2/selector"]
+    );
+    assert_eq!(errors[0].message, "Invalid selector `:not(WithStatement)` at offset 0");
+
+    let errors = NoRestrictedSyntax::validate_configuration(&serde_json::json!(["WithStatement"]));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(serde_json::json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(serde_json::json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(serde_json::json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(serde_json::json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(serde_json::json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(serde_json::json!(["BreakStatement[label]"]))),
+        (
+            "function foo(bar, baz) {}",
+            Some(serde_json::json!(["FunctionDeclaration[params.length>2]"])),
+        ),
+        ("var foo = 42;", Some(serde_json::json!([{ "selector": "ConditionalExpression" }]))),
+        ("a == b", Some(serde_json::json!(["BinaryExpression[operator='===']"]))),
+        ("a.setTimeout(f)", Some(serde_json::json!(["CallExpression[callee.name='setTimeout']"]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(serde_json::json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(serde_json::json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(serde_json::json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(serde_json::json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(serde_json::json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(serde_json::json!(["ArrowFunctionExpression > BlockStatement"]))),
+        (
+            "var foo = 41;",
+            Some(
+                serde_json::json!([{ "selector": "VariableDeclaration", "message": "Avoid variables." }]),
+            ),
+        ),
+        ("with (me) { dontMess(); }", Some(serde_json::json!(["WithStatement, ForInStatement"]))),
+        ("a == b", Some(serde_json::json!(["BinaryExpression[operator='==']"]))),
+        ("setTimeout(f, 100)", Some(serde_json::json!(["CallExpression[callee.name=/^set/]"]))),
+        (
+            "function f() { if (a) { return; } }",
+            Some(serde_json::json!(["FunctionDeclaration ReturnStatement"])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
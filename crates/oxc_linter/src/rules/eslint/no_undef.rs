@@ -1,10 +1,14 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn no_undef_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow the use of undeclared variables.")
@@ -12,9 +16,10 @@ fn no_undef_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoUndef {
     #[allow(dead_code)]
+    #[rule_config(rename = "typeof")]
     type_of: bool,
 }
 
@@ -32,18 +37,25 @@ declare_oxc_lint!(
     /// var foo = someFunction();
     /// var bar = a + 1;
     /// ```
+    ///
+    /// Variables are not reported where they are guarded by a `typeof` check, e.g.
+    /// `if (typeof a !== "undefined") { a(); }`.
+    ///
+    /// Global variables are declared by the `globals` and `env` of the configuration file, or by
+    /// comments in the file:
+    /// ```javascript
+    /// /* global someFunction, a: writable */
+    /// /* eslint-env browser, node */
+    /// var foo = someFunction(window, process);
+    /// a = 1;
+    /// ```
     NoUndef,
     nursery
 );
 
 impl Rule for NoUndef {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let type_of = value
-            .get(0)
-            .and_then(|config| config.get("typeof"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { type_of }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -54,16 +66,17 @@ impl Rule for NoUndef {
                 let reference = symbol_table.get_reference(reference_id);
                 let name = ctx.semantic().reference_name(reference);
 
-                if ctx.env_contains_var(name) {
+                if ctx.global_value(name).is_some() {
                     continue;
                 }
 
-                if ctx.globals().is_enabled(name) {
+                let node = ctx.nodes().get_node(reference.node_id());
+                if !self.type_of && has_typeof_operator(node, ctx) {
                     continue;
                 }
 
-                let node = ctx.nodes().get_node(reference.node_id());
-                if !self.type_of && has_typeof_operator(node, ctx) {
+                // `if (typeof a !== "undefined") { a(); }`
+                if ctx.semantic().is_defined_at(node.id(), name) {
                     continue;
                 }
 
@@ -87,16 +100,16 @@ fn test() {
 
     let pass = vec![
         "var a = 1, b = 2; a;",
-        // "/*global b*/ function f() { b; }",
+        "/*global b*/ function f() { b; }",
         // { code: "function f() { b; }", globals: { b: false } },
-        // "/*global b a:false*/  a;  function f() { b; a; }",
+        "/*global b a:false*/  a;  function f() { b; a; }",
         "function a(){}  a();",
         "function f(b) { b; }",
         "var a; a = 1; a++;",
         "var a; function f() { a = 1; }",
-        // "/*global b:true*/ b++;",
-        // "/*eslint-env browser*/ window;",
-        // "/*eslint-env node*/ require(\"a\");",
+        "/*global b:true*/ b++;",
+        "/*eslint-env browser*/ window;",
+        "/*eslint-env node*/ require(\"a\");",
         "Object; isNaN();",
         "toString()",
         "hasOwnProperty()",
@@ -126,11 +139,11 @@ fn test() {
         // "customElements;",
         // "PromiseRejectionEvent;",
         "(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }",
-        // "/*global b:false*/ function f() { b = 1; }",
+        "/*global b:false*/ function f() { b = 1; }",
         // { code: "function f() { b = 1; }", globals: { b: false } },
-        // "/*global b:false*/ function f() { b++; }",
-        // "/*global b*/ b = 1;",
-        // "/*global b:false*/ var b = 1;",
+        "/*global b:false*/ function f() { b++; }",
+        "/*global b*/ b = 1;",
+        "/*global b:false*/ var b = 1;",
         "Array = 1;",
         "class A { constructor() { new.target; } }",
         // {
@@ -157,7 +170,11 @@ fn test() {
         "class C { static { function a() {} a; } }",
         "class C { static { a; function a() {} } }",
         "String;Array;Boolean;",
-        "function resolve<T>(path: string): T { return { path } as T; }"
+        "function resolve<T>(path: string): T { return { path } as T; }",
+        "if (typeof a !== 'undefined') { a(); }",
+        "typeof window !== 'undefined' && window.foo",
+        "typeof a === 'function' ? a() : null",
+        "if (typeof a === 'undefined') {} else { a(); }",
     ];
 
     let fail = vec![
@@ -186,6 +203,10 @@ fn test() {
         "class C { static { let a; } [a]; }",
         "class C { static { function a() {} } [a]; }",
         "class C { static { var a; } } a;",
+        "if (typeof a === 'undefined') { a(); }",
+        "/*global a*/ b;",
+        "/*eslint-env node*/ window;",
+        "/*globala*/ a;",
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
@@ -198,8 +219,30 @@ fn test() {
 
     Tester::new(NoUndef::NAME, pass, fail).test();
 
-    let pass = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "readonly" } })))];
-    let fail = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "off" } })))];
+    let pass = vec![
+        ("foo", None, Some(serde_json::json!({ "globals": { "foo": "readonly" } }))),
+        ("function f() { b; }", None, Some(serde_json::json!({ "globals": { "b": false } }))),
+        (
+            "var {bacon, ...others} = stuff; foo(others)",
+            None,
+            Some(serde_json::json!({ "globals": { "stuff": false, "foo": false } })),
+        ),
+        ("/*global foo: off*/ /*global foo*/ foo", None, None),
+        ("URLSearchParams;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("Intl;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("IntersectionObserver;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("Credential;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("requestIdleCallback;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("customElements;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("PromiseRejectionEvent;", None, Some(serde_json::json!({ "env": { "browser": true } }))),
+        ("importScripts();", None, Some(serde_json::json!({ "env": { "worker": true } }))),
+    ];
+    let fail = vec![
+        ("foo", None, Some(serde_json::json!({ "globals": { "foo": "off" } }))),
+        ("Promise;", None, Some(serde_json::json!({ "globals": { "Promise": "off" } }))),
+        ("/*global foo: off*/ foo", None, Some(serde_json::json!({ "globals": { "foo": true } }))),
+        ("window;", None, Some(serde_json::json!({ "env": { "node": true } }))),
+    ];
 
     Tester::new(NoUndef::NAME, pass, fail).test();
 }
@@ -1,13 +1,20 @@
 use oxc_ast::{
-    ast::{match_assignment_target_pattern, Argument, AssignmentTarget, Expression},
+    ast::{
+        match_assignment_target_pattern, match_member_expression, Argument, AssignmentTarget,
+        ChainElement, Expression,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
 use oxc_syntax::operator::LogicalOperator;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn no_unsafe_optional_chaining_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unsafe usage of optional chaining")
@@ -21,7 +28,7 @@ fn no_unsafe_arithmetic_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoUnsafeOptionalChaining {
     /// Disallow arithmetic operations on optional chaining expressions (Default false).
     /// If this is true, this rule warns arithmetic operations on optional chaining expressions, which possibly result in NaN.
@@ -54,73 +61,67 @@ declare_oxc_lint!(
 
 impl Rule for NoUnsafeOptionalChaining {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            disallow_arithmetic_operators: value
-                .get(0)
-                .and_then(|v| v.get("disallowArithmeticOperators"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(expr) if !expr.optional => {
-                Self::check_unsafe_usage(&expr.callee, ctx);
+                Self::check_unsafe_usage(node, &expr.callee, ctx);
             }
             AstKind::MemberExpression(expr) if !expr.optional() => {
-                Self::check_unsafe_usage(expr.object(), ctx);
+                Self::check_unsafe_usage(node, expr.object(), ctx);
             }
             AstKind::TaggedTemplateExpression(expr) => {
-                Self::check_unsafe_usage(&expr.tag, ctx);
+                Self::check_unsafe_usage(node, &expr.tag, ctx);
             }
             AstKind::NewExpression(expr) => {
-                Self::check_unsafe_usage(&expr.callee, ctx);
+                Self::check_unsafe_usage(node, &expr.callee, ctx);
             }
             AstKind::AssignmentExpression(expr) => {
                 if matches!(expr.left, match_assignment_target_pattern!(AssignmentTarget)) {
-                    Self::check_unsafe_usage(&expr.right, ctx);
+                    Self::check_unsafe_usage(node, &expr.right, ctx);
                 }
                 if expr.operator.is_arithmetic() {
-                    self.check_unsafe_arithmetic(&expr.right, ctx);
+                    self.check_unsafe_arithmetic(node, &expr.right, ctx);
                 }
             }
             AstKind::BinaryExpression(expr) => match expr.operator {
-                op if op.is_relational() => Self::check_unsafe_usage(&expr.right, ctx),
+                op if op.is_relational() => Self::check_unsafe_usage(node, &expr.right, ctx),
                 op if op.is_arithmetic() => {
-                    self.check_unsafe_arithmetic(&expr.left, ctx);
-                    self.check_unsafe_arithmetic(&expr.right, ctx);
+                    self.check_unsafe_arithmetic(node, &expr.left, ctx);
+                    self.check_unsafe_arithmetic(node, &expr.right, ctx);
                 }
                 _ => {}
             },
             AstKind::UnaryExpression(expr) if expr.operator.is_arithmetic() => {
-                self.check_unsafe_arithmetic(&expr.argument, ctx);
+                self.check_unsafe_arithmetic(node, &expr.argument, ctx);
             }
             AstKind::ForOfStatement(stmt) => {
-                Self::check_unsafe_usage(&stmt.right, ctx);
+                Self::check_unsafe_usage(node, &stmt.right, ctx);
             }
             AstKind::WithStatement(stmt) => {
-                Self::check_unsafe_usage(&stmt.object, ctx);
+                Self::check_unsafe_usage(node, &stmt.object, ctx);
             }
             AstKind::Class(class) => {
                 if let Some(expr) = &class.super_class {
-                    Self::check_unsafe_usage(expr, ctx);
+                    Self::check_unsafe_usage(node, expr, ctx);
                 }
             }
             AstKind::AssignmentPattern(pat) if pat.left.kind.is_destructuring_pattern() => {
-                Self::check_unsafe_usage(&pat.right, ctx);
+                Self::check_unsafe_usage(node, &pat.right, ctx);
             }
             AstKind::Argument(Argument::SpreadElement(elem)) => {
-                Self::check_unsafe_usage(&elem.argument, ctx);
+                Self::check_unsafe_usage(node, &elem.argument, ctx);
             }
             AstKind::VariableDeclarator(decl) if decl.id.kind.is_destructuring_pattern() => {
                 if let Some(expr) = &decl.init {
-                    Self::check_unsafe_usage(expr, ctx);
+                    Self::check_unsafe_usage(node, expr, ctx);
                 }
             }
             AstKind::AssignmentTargetWithDefault(target) => {
                 if matches!(target.binding, match_assignment_target_pattern!(AssignmentTarget)) {
-                    Self::check_unsafe_usage(&target.init, ctx);
+                    Self::check_unsafe_usage(node, &target.init, ctx);
                 }
             }
             _ => {}
@@ -135,17 +136,23 @@ enum ErrorType {
 }
 
 impl NoUnsafeOptionalChaining {
-    fn check_unsafe_usage<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) {
-        Self::check_undefined_short_circuit(expr, ErrorType::Usage, ctx);
+    fn check_unsafe_usage<'a>(node: &AstNode<'a>, expr: &Expression<'a>, ctx: &LintContext<'a>) {
+        Self::check_undefined_short_circuit(node, expr, ErrorType::Usage, ctx);
     }
 
-    fn check_unsafe_arithmetic<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) {
+    fn check_unsafe_arithmetic<'a>(
+        &self,
+        node: &AstNode<'a>,
+        expr: &Expression<'a>,
+        ctx: &LintContext<'a>,
+    ) {
         if self.disallow_arithmetic_operators {
-            Self::check_undefined_short_circuit(expr, ErrorType::Arithmetic, ctx);
+            Self::check_undefined_short_circuit(node, expr, ErrorType::Arithmetic, ctx);
         }
     }
 
     fn check_undefined_short_circuit<'a>(
+        node: &AstNode<'a>,
         expr: &Expression<'a>,
         error_type: ErrorType,
         ctx: &LintContext<'a>,
@@ -153,26 +160,33 @@ impl NoUnsafeOptionalChaining {
         match expr.get_inner_expression() {
             Expression::LogicalExpression(expr) => match expr.operator {
                 LogicalOperator::Or | LogicalOperator::Coalesce => {
-                    Self::check_undefined_short_circuit(&expr.right, error_type, ctx);
+                    // `obj?.foo ?? undefined`
+                    if ctx.semantic().nullability(&expr.right).is_nullish() {
+                        Self::check_undefined_short_circuit(node, &expr.left, error_type, ctx);
+                    }
+                    Self::check_undefined_short_circuit(node, &expr.right, error_type, ctx);
                 }
                 LogicalOperator::And => {
-                    Self::check_undefined_short_circuit(&expr.left, error_type, ctx);
-                    Self::check_undefined_short_circuit(&expr.right, error_type, ctx);
+                    Self::check_undefined_short_circuit(node, &expr.left, error_type, ctx);
+                    Self::check_undefined_short_circuit(node, &expr.right, error_type, ctx);
                 }
             },
             Expression::AwaitExpression(expr) => {
-                Self::check_undefined_short_circuit(&expr.argument, error_type, ctx);
+                Self::check_undefined_short_circuit(node, &expr.argument, error_type, ctx);
             }
             Expression::ConditionalExpression(expr) => {
-                Self::check_undefined_short_circuit(&expr.consequent, error_type, ctx);
-                Self::check_undefined_short_circuit(&expr.alternate, error_type, ctx);
+                Self::check_undefined_short_circuit(node, &expr.consequent, error_type, ctx);
+                Self::check_undefined_short_circuit(node, &expr.alternate, error_type, ctx);
             }
             Expression::SequenceExpression(expr) => {
                 if let Some(expr) = expr.expressions.iter().last() {
-                    Self::check_undefined_short_circuit(expr, error_type, ctx);
+                    Self::check_undefined_short_circuit(node, expr, error_type, ctx);
                 }
             }
             Expression::ChainExpression(expr) => {
+                if !can_short_circuit(node, &expr.expression, ctx) {
+                    return;
+                }
                 match error_type {
                     ErrorType::Usage => {
                         ctx.diagnostic(no_unsafe_optional_chaining_diagnostic(expr.span));
@@ -187,6 +201,56 @@ impl NoUnsafeOptionalChaining {
     }
 }
 
+/// Whether any optional part of the chain can be `null` or `undefined`, e.g. `foo?.bar` is
+/// not short-circuited inside of `if (foo) {}`.
+fn can_short_circuit<'a>(
+    node: &AstNode<'a>,
+    chain: &ChainElement<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let mut expr = match chain {
+        ChainElement::CallExpression(call) => {
+            if call.optional {
+                return true;
+            }
+            &call.callee
+        }
+        match_member_expression!(ChainElement) => {
+            let member = chain.to_member_expression();
+            if member.optional() && !is_non_nullish(node, member.object(), ctx) {
+                return true;
+            }
+            member.object()
+        }
+    };
+    loop {
+        match expr.get_inner_expression() {
+            Expression::CallExpression(call) => {
+                if call.optional {
+                    return true;
+                }
+                expr = &call.callee;
+            }
+            object @ match_member_expression!(Expression) => {
+                let member = object.to_member_expression();
+                if member.optional() && !is_non_nullish(node, member.object(), ctx) {
+                    return true;
+                }
+                expr = member.object();
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn is_non_nullish<'a>(node: &AstNode<'a>, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    let semantic = ctx.semantic();
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => semantic.is_non_nullish_at(node.id(), ident),
+        expr => semantic.nullability(expr).is_non_nullish(),
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -255,6 +319,12 @@ fn test() {
             None,
         ),
         ("obj?.foo - bar;", Some(serde_json::json!([{}]))),
+        ("if (obj) { (obj?.foo).bar; }", None),
+        ("if (obj != null) { const { foo } = obj?.bar; }", None),
+        ("obj && (obj?.foo).bar", None),
+        ("typeof obj === 'function' && new (obj?.foo)()", None),
+        ("const obj = {}; (obj?.foo).bar;", None),
+        ("function f(obj) { if (!obj) { return; } else { (obj?.foo).bar; } }", None),
         (
             "obj?.foo - bar;",
             Some(serde_json::json!([{
@@ -268,6 +338,10 @@ fn test() {
         ("with (obj?.foo) {};", None),
         ("async function foo() { with ( await obj?.foo) {}; }", None),
         ("(foo ? obj?.foo : obj?.bar).bar", None),
+        ("(obj?.foo ?? undefined).bar", None),
+        ("(obj?.foo || null).bar", None),
+        ("if (obj) {} (obj?.foo).bar", None),
+        ("if (obj) { (obj?.foo?.bar).baz }", None),
     ];
 
     Tester::new(NoUnsafeOptionalChaining::NAME, pass, fail).test_and_snapshot();
@@ -44,7 +44,7 @@ impl Rule for NoUnusedLabels {
         if ctx.file_path().extension().is_some_and(|ext| ext == "svelte") {
             return;
         }
-        for id in ctx.semantic().unused_labels() {
+        for id in ctx.semantic().labels().unused_labels() {
             let node = ctx.semantic().nodes().get_node(*id);
             let AstKind::LabeledStatement(stmt) = node.kind() else {
                 continue;
@@ -138,7 +138,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```js
+    /// ```js ignore
     /// /*eslint no-unused-vars: "error"*/
     ///
     /// var x = 10;
@@ -165,7 +165,7 @@ declare_oxc_lint!(
     /// }
     /// ```
     ///
-    /// ```ts
+    /// ```ts ignore
     /// export const x = 1;
     /// const y = 1;
     /// export { y };
@@ -230,7 +230,7 @@ impl NoUnusedVars {
 
         // Order matters. We want to call cheap/high "yield" functions first.
         let is_exported = symbol.is_exported();
-        let is_used = is_exported || symbol.has_usages(self);
+        let is_used = is_exported || symbol.is_used_dynamically() || symbol.has_usages(self);
 
         match (is_used, is_ignored) {
             (true, true) => {
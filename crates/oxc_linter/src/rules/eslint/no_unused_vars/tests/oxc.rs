@@ -770,6 +770,42 @@ fn test_arguments() {
         .test_and_snapshot();
 }
 
+#[test]
+fn test_mapped_arguments() {
+    // `arguments` aliases the parameters of non-strict functions with simple parameters
+    let pass = vec![
+        "function foo(a) { return arguments[0] } foo()",
+        "function foo(a) { return () => arguments[0] } foo()",
+    ];
+    let fail = vec![
+        "function foo(a) { 'use strict'; return arguments[0] } foo()",
+        "function foo(a = 1) { return arguments[0] } foo()",
+        "function foo(a) { return function () { return arguments[0] } } foo()",
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail)
+        .change_rule_path("no_unused_vars.cjs")
+        .with_snapshot_suffix("oxc-mapped-arguments")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_direct_eval() {
+    // Direct `eval` can read all bindings of the scopes it is in
+    let pass = vec![
+        "function foo() { let a = 1; eval('a') } foo()",
+        "function foo() { let a = 1; (() => eval('a'))() } foo()",
+    ];
+    let fail = vec![
+        "function foo() { let a = 1; eval?.('a') } foo()",
+        "function foo() { let a = 1 } function bar() { eval('a') } foo(); bar()",
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail)
+        .with_snapshot_suffix("oxc-direct-eval")
+        .test_and_snapshot();
+}
+
 #[test]
 fn test_enums() {
     let pass = vec![
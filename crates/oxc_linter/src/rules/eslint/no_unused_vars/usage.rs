@@ -64,6 +64,17 @@ impl<'s, 'a> Symbol<'s, 'a> {
         self.flags().contains(SymbolFlags::TypeAlias)
     }
 
+    /// Check if this [`Symbol`] can be used without being referenced by name:
+    /// - bindings of scopes containing a direct `eval` can be read by the evaluated code
+    /// - parameters of a function with a mapped `arguments` object can be read through it,
+    ///   e.g. `function foo(a) { return arguments[0] }`
+    pub fn is_used_dynamically(&self) -> bool {
+        let scope_flags = self.scopes().get_flags(self.scope_id());
+        scope_flags.contains_direct_eval()
+            || (scope_flags.has_mapped_arguments()
+                && matches!(self.declaration().kind(), AstKind::FormalParameter(_)))
+    }
+
     /// Check if this [`Symbol`] has an [`Reference`]s that are considered a usage.
     pub fn has_usages(&self, options: &NoUnusedVars) -> bool {
         // Use symbol flags to skip the usage checks we are certain don't need
@@ -5,16 +5,20 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn sort_vars_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Variable declarations should be sorted").with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct SortVars {
     ignore_case: bool,
 }
@@ -49,13 +53,7 @@ declare_oxc_lint!(
 
 impl Rule for SortVars {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let ignore_case = value
-            .get(0)
-            .and_then(|v| v.get("ignoreCase"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { ignore_case }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
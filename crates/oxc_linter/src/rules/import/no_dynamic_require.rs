@@ -1,9 +1,13 @@
 use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn no_dnyamic_require_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected a literal string or immutable template literal")
@@ -11,7 +15,7 @@ fn no_dnyamic_require_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoDynamicRequire {
     esmodule: bool,
 }
@@ -45,13 +49,7 @@ declare_oxc_lint!(
 
 impl Rule for NoDynamicRequire {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let esmodule = value
-            .get(0)
-            .and_then(|config| config.get("esmodule"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { esmodule }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -1,13 +1,13 @@
 use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_index::Idx;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
 use rustc_hash::FxHashMap;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{Rule, RuleConfig},
     utils::{collect_possible_jest_call_node, PossibleJestNode},
 };
 
@@ -17,7 +17,7 @@ fn exceeded_max_assertion(x0: usize, x1: usize, span2: Span) -> OxcDiagnostic {
         .with_label(span2)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, RuleConfig)]
 pub struct MaxExpects {
     pub max: usize,
 }
@@ -66,14 +66,7 @@ declare_oxc_lint!(
 
 impl Rule for MaxExpects {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
-            .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
-
-        Self { max }
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -0,0 +1,115 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_package_json, json_properties, json_value},
+};
+
+fn no_duplicate_dependencies_diagnostic(first: Span, second: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Dependency `{name}` is declared more than once"))
+        .with_help("Remove one of the declarations, only one of them is installed.")
+        .with_labels([first.label("first declared here"), second.label("declared again here")])
+}
+
+/// Dependencies of these fields are installed, a package can only be installed once.
+const INSTALLED_DEPENDENCIES: [&str; 3] =
+    ["dependencies", "devDependencies", "optionalDependencies"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateDependencies;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow declaring a dependency of a `package.json` more than once, in the same field or
+    /// in more than one of `dependencies`, `devDependencies` and `optionalDependencies`.
+    ///
+    /// A dependency may be declared in `peerDependencies` and in one of the other fields, so it
+    /// is installed for development.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Only one of the declarations is used by package managers, which one depends on the
+    /// package manager, so the other version range is silently ignored.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "dependencies": { "react": "^18.0.0" },
+    ///   "devDependencies": { "react": "^18.2.0" }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "peerDependencies": { "react": "^18.0.0" },
+    ///   "devDependencies": { "react": "^18.2.0" }
+    /// }
+    /// ```
+    NoDuplicateDependencies,
+    correctness
+);
+
+impl Rule for NoDuplicateDependencies {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(Expression::ObjectExpression(package)) = json_value(ctx) else { return };
+        let mut installed = FxHashMap::default();
+        for (field, dependencies) in json_properties(package) {
+            let is_installed = INSTALLED_DEPENDENCIES.contains(&field);
+            if !is_installed && field != "peerDependencies" {
+                continue;
+            }
+            let Expression::ObjectExpression(dependencies) = &dependencies.value else {
+                continue;
+            };
+            let mut declared = FxHashMap::default();
+            for (name, dependency) in json_properties(dependencies) {
+                let span = dependency.key.span();
+                if let Some(first) = declared.insert(name, span) {
+                    ctx.diagnostic(no_duplicate_dependencies_diagnostic(first, span, name));
+                } else if is_installed {
+                    if let Some(first) = installed.insert(name, span) {
+                        ctx.diagnostic(no_duplicate_dependencies_diagnostic(first, span, name));
+                    }
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_package_json(ctx.file_path())
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "name": "a" }"#,
+        r#"{ "dependencies": { "a": "^1.0.0", "b": "^1.0.0" } }"#,
+        r#"{ "peerDependencies": { "a": "^1.0.0" }, "devDependencies": { "a": "^1.2.0" } }"#,
+        r#"{ "dependencies": { "a": "^1.0.0" }, "resolutions": { "a": "1.2.0" } }"#,
+        r#"{ "dependencies": { "a": "^1.0.0" }, "bundleDependencies": ["a"] }"#,
+    ];
+
+    let fail = vec![
+        r#"{ "dependencies": { "a": "1", "a": "2" } }"#,
+        r#"{ "dependencies": { "a": "1" }, "devDependencies": { "a": "2" } }"#,
+        r#"{ "optionalDependencies": { "a": "1" }, "dependencies": { "a": "2" } }"#,
+        r#"{ "peerDependencies": { "a": "1", "a": "2" } }"#,
+    ];
+
+    Tester::new(NoDuplicateDependencies::NAME, pass, fail)
+        .change_rule_path("package.json")
+        .with_json_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,198 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_package_json, json_properties, json_value},
+};
+
+fn invalid_range_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid version range for dependency `{name}`"))
+        .with_help("Use a semver range such as `^1.2.3`, a dist-tag such as `latest`, or a protocol such as `npm:`, `workspace:` or `file:`.")
+        .with_label(span)
+}
+
+fn not_a_string_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Version range for dependency `{name}` must be a string"))
+        .with_label(span)
+}
+
+const DEPENDENCIES: [&str; 4] =
+    ["dependencies", "devDependencies", "optionalDependencies", "peerDependencies"];
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidSemverRanges;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require the version ranges of the dependencies of a `package.json` to be valid
+    /// [semver ranges](https://github.com/npm/node-semver#ranges).
+    ///
+    /// Dist-tags such as `latest`, protocols such as `npm:`, `workspace:` and `file:`, URLs,
+    /// local paths and GitHub repositories (`owner/repo`) are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Package managers fail to install a dependency with an invalid version range, or treat it
+    /// as a dist-tag which does not exist.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "dependencies": { "a": "^1.2.3.4", "b": ">= 1.x <" }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "dependencies": { "a": "^1.2.3", "b": ">=1.2.0 <2 || 3.x", "c": "workspace:*" }
+    /// }
+    /// ```
+    ValidSemverRanges,
+    correctness
+);
+
+impl Rule for ValidSemverRanges {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(Expression::ObjectExpression(package)) = json_value(ctx) else { return };
+        for (field, dependencies) in json_properties(package) {
+            if !DEPENDENCIES.contains(&field) {
+                continue;
+            }
+            let Expression::ObjectExpression(dependencies) = &dependencies.value else {
+                continue;
+            };
+            for (name, dependency) in json_properties(dependencies) {
+                match &dependency.value {
+                    Expression::StringLiteral(range) => {
+                        if !is_valid_dependency_specifier(range.value.as_str()) {
+                            ctx.diagnostic(invalid_range_diagnostic(range.span, name));
+                        }
+                    }
+                    value => ctx.diagnostic(not_a_string_diagnostic(value.span(), name)),
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_package_json(ctx.file_path())
+    }
+}
+
+fn is_valid_dependency_specifier(specifier: &str) -> bool {
+    let specifier = specifier.trim();
+    // `npm:`, `workspace:`, `file:`, `git+https:` and other protocols and URLs
+    let has_protocol = specifier.split_once(':').is_some_and(|(protocol, _)| {
+        protocol.starts_with(|c: char| c.is_ascii_alphabetic())
+            && protocol.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    });
+    // Local paths and GitHub repositories, e.g. `./a` or `owner/repo#main`
+    let is_path = specifier.contains('/');
+    // Dist-tags, e.g. `latest` or `next`
+    let is_tag = specifier.starts_with(|c: char| c.is_ascii_alphabetic())
+        && specifier.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    has_protocol || is_path || is_tag || is_valid_range(specifier)
+}
+
+/// <https://github.com/npm/node-semver#range-grammar>
+fn is_valid_range(range_set: &str) -> bool {
+    range_set.split("||").all(|range| {
+        let range = range.trim();
+        if let Some((from, to)) = range.split_once(" - ") {
+            return is_partial(from.trim()) && is_partial(to.trim());
+        }
+        let mut comparators = range.split_whitespace();
+        while let Some(comparator) = comparators.next() {
+            let is_valid = if is_operator(comparator) {
+                // Whitespace is allowed after the operator, e.g. `>= 1.2.3`
+                comparators.next().is_some_and(is_partial)
+            } else {
+                is_comparator(comparator)
+            };
+            if !is_valid {
+                return false;
+            }
+        }
+        true
+    })
+}
+
+fn is_operator(s: &str) -> bool {
+    matches!(s, "<" | ">" | "<=" | ">=" | "=" | "~" | "~>" | "^")
+}
+
+fn is_comparator(comparator: &str) -> bool {
+    let version = ["<=", ">=", "~>", "<", ">", "=", "~", "^"]
+        .iter()
+        .find_map(|operator| comparator.strip_prefix(operator))
+        .unwrap_or(comparator);
+    is_partial(version)
+}
+
+/// A version which may be partial or contain wildcards, e.g. `1`, `1.x` or `1.2.3-beta.1`.
+fn is_partial(version: &str) -> bool {
+    let version = version.strip_prefix(['v', '=']).unwrap_or(version);
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (version, pre_release) = match version.split_once('-') {
+        Some((version, pre_release)) => (version, Some(pre_release)),
+        None => (version, None),
+    };
+    let parts = version.split('.').collect::<Vec<_>>();
+    let is_valid_part = |part: &&str| {
+        matches!(*part, "x" | "X" | "*")
+            || (!part.is_empty()
+                && part.chars().all(|c| c.is_ascii_digit())
+                && (*part == "0" || !part.starts_with('0')))
+    };
+    let is_valid_identifiers = |identifiers: &str| {
+        identifiers.split('.').all(|identifier| {
+            !identifier.is_empty()
+                && identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+    };
+    (1..=3).contains(&parts.len())
+        && parts.iter().all(is_valid_part)
+        && (parts.len() == 3 || (pre_release.is_none() && build.is_none()))
+        && pre_release.map_or(true, is_valid_identifiers)
+        && build.map_or(true, is_valid_identifiers)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "name": "a", "version": "1.0.0" }"#,
+        r#"{ "dependencies": { "a": "^1.2.3", "b": "~1.2", "c": "1.x", "d": "*", "e": "" } }"#,
+        r#"{ "dependencies": { "a": ">=1.2.3 <2.0.0", "b": ">= 1.2.3 < 2", "c": "1 || 2" } }"#,
+        r#"{ "dependencies": { "a": "1.2.3 - 2.3.4", "b": "1.2.3-beta.1+build.5", "c": "v1.2.3" } }"#,
+        r#"{ "devDependencies": { "a": "latest", "b": "next", "c": "owner/repo#main" } }"#,
+        r#"{ "dependencies": { "a": "npm:b@^1.0.0", "b": "workspace:*", "c": "file:../c" } }"#,
+        r#"{ "dependencies": { "a": "git+https://github.com/a/a.git", "b": "./b" } }"#,
+        r#"{ "scripts": { "build": 1 } }"#,
+    ];
+
+    let fail = vec![
+        r#"{ "dependencies": { "a": "^1.2.3.4" } }"#,
+        r#"{ "devDependencies": { "a": ">= 1.x <" } }"#,
+        r#"{ "peerDependencies": { "a": "01.2.3" } }"#,
+        r#"{ "optionalDependencies": { "a": "1.2-beta" } }"#,
+        r#"{ "dependencies": { "a": 1 } }"#,
+    ];
+
+    Tester::new(ValidSemverRanges::NAME, pass, fail)
+        .change_rule_path("package.json")
+        .with_json_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,162 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_tsconfig_json, json_properties, json_value},
+};
+
+fn unknown_option_diagnostic(span: Span, option: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unknown tsconfig option `{option}`"))
+        .with_help("Compiler options such as `strict` must be set in `compilerOptions`.")
+        .with_label(span)
+}
+
+fn invalid_type_diagnostic(span: Span, option: &str, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{option}` must be {expected}")).with_label(span)
+}
+
+fn empty_files_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The `files` list is empty")
+        .with_help("Add files to `files`, or select the files of the project with `include`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidTsconfig;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Check the top-level options of `tsconfig.json` and `jsconfig.json` files:
+    /// * options must be known, compiler options must be set in `compilerOptions`,
+    /// * options must have the right type, e.g. `include` must be an array of strings,
+    /// * the `files` list must not be empty, unless `include` or `references` is set.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript ignores unknown top-level options, so a misplaced compiler option silently
+    /// has no effect. Options with the wrong type and an empty `files` list fail the build.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "strict": true,
+    ///   "include": "src"
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "compilerOptions": { "strict": true },
+    ///   "include": ["src"]
+    /// }
+    /// ```
+    ValidTsconfig,
+    correctness
+);
+
+impl Rule for ValidTsconfig {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(Expression::ObjectExpression(tsconfig)) = json_value(ctx) else { return };
+        let mut empty_files = None;
+        let mut has_include_or_references = false;
+        for (option, property) in json_properties(tsconfig) {
+            let value = &property.value;
+            let (is_valid, expected) = match option {
+                "$schema" => (is_string(value), "a string"),
+                "compileOnSave" => (matches!(value, Expression::BooleanLiteral(_)), "a boolean"),
+                "compilerOptions"
+                | "typeAcquisition"
+                | "watchOptions"
+                | "buildOptions"
+                | "ts-node"
+                | "vueCompilerOptions"
+                | "angularCompilerOptions" => {
+                    (matches!(value, Expression::ObjectExpression(_)), "an object")
+                }
+                "extends" => (
+                    is_string(value) || is_array_of(value, is_string),
+                    "a string or an array of strings",
+                ),
+                "files" | "include" | "exclude" => {
+                    (is_array_of(value, is_string), "an array of strings")
+                }
+                "references" => {
+                    (is_array_of(value, is_reference), "an array of objects with a `path` string")
+                }
+                _ => {
+                    ctx.diagnostic(unknown_option_diagnostic(property.key.span(), option));
+                    continue;
+                }
+            };
+            if !is_valid {
+                ctx.diagnostic(invalid_type_diagnostic(value.span(), option, expected));
+                continue;
+            }
+            match (option, value) {
+                ("files", Expression::ArrayExpression(files)) if files.elements.is_empty() => {
+                    empty_files = Some(files.span);
+                }
+                ("include" | "references", _) => has_include_or_references = true,
+                _ => {}
+            }
+        }
+        if let Some(span) = empty_files.filter(|_| !has_include_or_references) {
+            ctx.diagnostic(empty_files_diagnostic(span));
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_tsconfig_json(ctx.file_path())
+    }
+}
+
+fn is_string(value: &Expression) -> bool {
+    matches!(value, Expression::StringLiteral(_))
+}
+
+fn is_array_of(value: &Expression, is_element: fn(&Expression) -> bool) -> bool {
+    let Expression::ArrayExpression(array) = value else { return false };
+    array.elements.iter().all(|element| element.as_expression().is_some_and(is_element))
+}
+
+/// `{ "path": "../a" }`
+fn is_reference(value: &Expression) -> bool {
+    let Expression::ObjectExpression(reference) = value else { return false };
+    json_properties(reference).any(|(key, property)| key == "path" && is_string(&property.value))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "compilerOptions": { "strict": true }, "include": ["src"] }"#,
+        r#"{ "$schema": "https://json.schemastore.org/tsconfig", "extends": "./base.json" }"#,
+        r#"{ "extends": ["./a.json", "./b.json"], "exclude": ["dist"], "compileOnSave": false }"#,
+        r#"{ "files": [], "references": [{ "path": "./a" }, { "path": "./b" }] }"#,
+        r#"{ "files": [], "include": ["src"] }"#,
+        r#"{ "watchOptions": {}, "typeAcquisition": {}, "ts-node": {} }"#,
+        "// comment\n{\n  \"compilerOptions\": {\n    \"strict\": true, // comment\n  },\n}",
+    ];
+
+    let fail = vec![
+        r#"{ "strict": true }"#,
+        r#"{ "include": "src" }"#,
+        r#"{ "compilerOptions": [] }"#,
+        r#"{ "references": [{ "name": "a" }] }"#,
+        r#"{ "files": [] }"#,
+    ];
+
+    Tester::new(ValidTsconfig::NAME, pass, fail)
+        .change_rule_path("tsconfig.json")
+        .with_json_plugin(true)
+        .test_and_snapshot();
+}
@@ -122,12 +122,12 @@ declare_oxc_lint!(
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```jsx
-    /// <img src="flower.jpg" alt="A close-up of a white daisy" />
+    /// <img src="flower.jpg" />
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```jsx
-    /// <img src="flower.jpg" />
+    /// <img src="flower.jpg" alt="A close-up of a white daisy" />
     /// ```
     AltText,
     correctness
@@ -103,7 +103,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <div role="button"></div>     <!-- Good: "button" is a valid ARIA role -->
     /// <div role={role}></div>       <!-- Good: role is a variable & cannot be determined until runtime. -->
     /// <div></div>                   <!-- Good: No ARIA role -->
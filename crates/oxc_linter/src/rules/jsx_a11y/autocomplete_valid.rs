@@ -61,7 +61,7 @@ impl std::ops::Deref for AutocompleteValid {
 
 impl std::default::Default for AutocompleteValidConfig {
     fn default() -> Self {
-        Self { input_components: FxHashSet::from_iter([CompactStr::new("input")]) }
+        Self { input_components: FxHashSet::from_iter([CompactStr::new_const("input")]) }
     }
 }
 
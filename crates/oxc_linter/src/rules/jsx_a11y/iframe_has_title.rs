@@ -52,7 +52,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <iframe title="This is a unique title" />
     /// <iframe title={uniqueTitle} />
     /// ```
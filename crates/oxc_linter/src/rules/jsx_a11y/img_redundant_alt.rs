@@ -44,7 +44,7 @@ const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
 impl Default for ImgRedundantAltConfig {
     fn default() -> Self {
         Self {
-            types_to_validate: vec![CompactStr::new("img")],
+            types_to_validate: vec![CompactStr::new_const("img")],
             redundant_words: Self::union(&REDUNDANT_WORDS).unwrap(),
         }
     }
@@ -89,7 +89,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <img src="foo" alt="Foo eating a sandwich." />
     /// <img src="bar" aria-hidden alt="Picture of me taking a photo of an image" /> // Will pass because it is hidden.
     /// <img src="baz" alt={`Baz taking a ${photo}`} /> // This is valid since photo is a variable name.
@@ -0,0 +1,228 @@
+use oxc_ast::{
+    ast::{Expression, JSXAttributeValue},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_explicit_role, get_prop_value, has_jsx_prop,
+        has_jsx_prop_ignore_case, is_hidden_from_screen_reader, is_interactive_element,
+        is_interactive_role, is_presentation_role,
+    },
+    AstNode,
+};
+
+fn interactive_supports_focus_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be focusable."))
+        .with_help("Add a `tabIndex` attribute to the element, e.g. `tabIndex={-1}`.")
+        .with_label(span)
+}
+
+fn interactive_supports_focus_tabbable_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be tabbable."))
+        .with_help("Add `tabIndex={0}` to the element.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct InteractiveSupportsFocus(Box<InteractiveSupportsFocusConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct InteractiveSupportsFocusConfig {
+    /// Roles which must be reachable with the tab key, not only focusable
+    tabbable: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for InteractiveSupportsFocus {
+    type Target = InteractiveSupportsFocusConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that elements with an interactive role and interaction handlers
+    /// (mouse or key press) are focusable.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Elements with an interactive role and interaction handlers must be focusable,
+    /// otherwise users who navigate with a keyboard cannot reach them.
+    /// Native interactive elements such as `<button>` are focusable by default.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <span onClick={submitForm} role="button">Submit</span>;
+    /// <a onClick={showNextPage} role="button">Next page</a>;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div aria-hidden onClick={() => void 0} />;
+    /// <span onClick={doSomething} tabIndex="0" role="button">Click me!</span>;
+    /// <span onClick={doSomething} tabIndex="-1" role="menuitem">Click me too!</span>;
+    /// <button onClick={submitForm}>Submit</button>;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "tabbable": ["button", "checkbox", "link", "searchbox", "spinbutton", "switch", "textbox"] }
+    /// ```
+    ///
+    /// Elements with one of the `tabbable` roles must have `tabIndex={0}`, so they can be reached
+    /// with the tab key.
+    InteractiveSupportsFocus,
+    correctness
+);
+
+const INTERACTIVE_HANDLERS: [&str; 6] =
+    ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"];
+
+impl Rule for InteractiveSupportsFocus {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let tabbable = value
+            .get(0)
+            .and_then(|options| options.get("tabbable"))
+            .and_then(serde_json::Value::as_array)
+            .map(|roles| {
+                roles.iter().filter_map(serde_json::Value::as_str).map(Into::into).collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(InteractiveSupportsFocusConfig { tabbable }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        // Check only native DOM elements or custom component via settings
+        let Some(element_type) = get_element_type(ctx, jsx_opening_el) else {
+            return;
+        };
+        if !HTML_TAG.contains(&element_type) {
+            return;
+        }
+
+        if !INTERACTIVE_HANDLERS
+            .iter()
+            .any(|handler| has_jsx_prop(jsx_opening_el, handler).is_some())
+        {
+            return;
+        }
+
+        if is_hidden_from_screen_reader(ctx, jsx_opening_el)
+            || is_presentation_role(jsx_opening_el)
+            || !is_interactive_role(jsx_opening_el)
+            || is_interactive_element(&element_type, jsx_opening_el)
+        {
+            return;
+        }
+
+        if has_jsx_prop(jsx_opening_el, "disabled").is_some()
+            || has_jsx_prop_ignore_case(jsx_opening_el, "aria-disabled")
+                .is_some_and(|attr| is_true(get_prop_value(attr)))
+        {
+            return;
+        }
+
+        if has_jsx_prop_ignore_case(jsx_opening_el, "tabIndex").is_some() {
+            return;
+        }
+
+        let Some(role) = get_explicit_role(jsx_opening_el) else {
+            return;
+        };
+        let span = jsx_opening_el.span;
+        if self.tabbable.iter().any(|tabbable| tabbable == role) {
+            ctx.diagnostic(interactive_supports_focus_tabbable_diagnostic(span, role));
+        } else {
+            ctx.diagnostic(interactive_supports_focus_diagnostic(span, role));
+        }
+    }
+}
+
+/// `aria-disabled`, `aria-disabled="true"` or `aria-disabled={true}`
+fn is_true(value: Option<&JSXAttributeValue>) -> bool {
+    match value {
+        None => true,
+        Some(JSXAttributeValue::StringLiteral(s)) => s.value == "true",
+        Some(JSXAttributeValue::ExpressionContainer(container)) => container
+            .expression
+            .as_expression()
+            .and_then(Expression::get_boolean_value)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let tabbable = Some(serde_json::json!([{
+        "tabbable": ["button", "checkbox", "link", "searchbox", "spinbutton", "switch", "textbox"]
+    }]));
+
+    let pass = vec![
+        (r"<div />", None, None, None),
+        (r"<div aria-hidden onClick={() => void 0} />", None, None, None),
+        (r"<div aria-hidden={true} onClick={() => void 0} />", None, None, None),
+        (r"<div onClick={() => void 0} />", None, None, None),
+        (r#"<div onClick={() => void 0} role="presentation" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="article" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex="0" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" tabIndex={-1} />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" aria-disabled="true" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" disabled />"#, None, None, None),
+        (r#"<div onKeyUp={() => void 0} role="menuitem" tabIndex="-1" />"#, None, None, None),
+        (r#"<button onClick={() => void 0} role="button" />"#, None, None, None),
+        (r##"<a href="#" onClick={() => void 0} role="button" />"##, None, None, None),
+        (r#"<input type="text" onClick={() => void 0} role="textbox" />"#, None, None, None),
+        (r#"<div onFocus={() => void 0} role="button" />"#, None, None, None),
+        (r#"<Foo onClick={() => void 0} role="button" />"#, None, None, None),
+        (
+            r#"<span onClick={doSomething} tabIndex="0" role="button">Click me!</span>"#,
+            tabbable.clone(),
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (r#"<div onClick={() => void 0} role="button" />"#, None, None, None),
+        (r#"<span onClick={submitForm} role="button">Submit</span>"#, None, None, None),
+        (r#"<a onClick={showNextPage} role="button">Next page</a>"#, None, None, None),
+        (r#"<div onKeyDown={() => void 0} role="menuitem" />"#, None, None, None),
+        (r#"<div onMouseUp={() => void 0} role="foo checkbox" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" aria-disabled="false" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" />"#, tabbable.clone(), None, None),
+        (r#"<div onClick={() => void 0} role="menuitem" />"#, tabbable, None, None),
+        (
+            r#"<Box onClick={() => void 0} role="button" />"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": {
+                    "components": {
+                        "Box": "div",
+                    }
+                } }
+            })),
+            None,
+        ),
+    ];
+
+    Tester::new(InteractiveSupportsFocus::NAME, pass, fail).test_and_snapshot();
+}
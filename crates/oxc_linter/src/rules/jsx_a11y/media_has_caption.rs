@@ -55,7 +55,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <audio><track kind="captions" src="caption_file.vtt" /></audio>
     /// <video><track kind="captions" src="caption_file.vtt" /></video>
     /// ```
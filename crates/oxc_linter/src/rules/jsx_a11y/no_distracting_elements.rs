@@ -44,7 +44,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <div />
     /// <Marquee />
     /// <Blink />
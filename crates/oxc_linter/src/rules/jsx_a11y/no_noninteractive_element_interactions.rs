@@ -0,0 +1,231 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop, has_jsx_prop_ignore_case,
+        is_hidden_from_screen_reader, is_interactive_element, is_interactive_role,
+        is_non_interactive_element, is_non_interactive_role, is_presentation_role,
+    },
+    AstNode,
+};
+
+fn no_noninteractive_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Non-interactive elements should not be assigned mouse or keyboard event listeners.")
+        .with_help("Use an interactive element such as `<button>` instead, or give the element an interactive role.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNoninteractiveElementInteractions(Box<NoNoninteractiveElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementInteractionsConfig {
+    handlers: Vec<CompactStr>,
+    /// Handlers which are allowed on specific elements, e.g. `{ "li": ["onClick"] }`
+    exceptions: FxHashMap<CompactStr, Vec<CompactStr>>,
+}
+
+impl std::ops::Deref for NoNoninteractiveElementInteractions {
+    type Target = NoNoninteractiveElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoNoninteractiveElementInteractionsConfig {
+    fn default() -> Self {
+        Self {
+            handlers: ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"]
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            exceptions: FxHashMap::default(),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that non-interactive, visible elements (such as `<main>`, `<li>` or
+    /// `<div role="article">`) do not have mouse or keyboard event listeners.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive elements are not focusable and are not announced as interactive by
+    /// assistive technologies, so users who rely on them cannot discover or trigger the handlers.
+    /// Content which users interact with should use an interactive element or role.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <li onClick={() => void 0} />;
+    /// <div onClick={() => void 0} role="listitem" />;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => void 0} role="button" />;
+    /// <div onClick={() => void 0} role="presentation" />;
+    /// <input type="text" onClick={() => void 0} />;
+    /// <button onClick={() => void 0} className="foo" />;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "handlers": ["onClick", "onKeyDown"], "li": ["onClick"] }
+    /// ```
+    ///
+    /// `handlers` overrides the checked event handlers, which default to
+    /// `onClick`, `onMouseDown`, `onMouseUp`, `onKeyPress`, `onKeyDown` and `onKeyUp`.
+    /// Any other key is an element name, mapped to handlers which are allowed on that element.
+    NoNoninteractiveElementInteractions,
+    correctness
+);
+
+impl Rule for NoNoninteractiveElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = NoNoninteractiveElementInteractionsConfig::default();
+        let Some(options) = value.get(0).and_then(serde_json::Value::as_object) else {
+            return Self(Box::new(config));
+        };
+
+        let to_names = |value: &serde_json::Value| -> Vec<CompactStr> {
+            value
+                .as_array()
+                .map(|names| {
+                    names.iter().filter_map(serde_json::Value::as_str).map(Into::into).collect()
+                })
+                .unwrap_or_default()
+        };
+        for (key, value) in options {
+            if key == "handlers" {
+                config.handlers = to_names(value);
+            } else {
+                config.exceptions.insert(key.as_str().into(), to_names(value));
+            }
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        // Check only native DOM elements or custom component via settings
+        let Some(element_type) = get_element_type(ctx, jsx_opening_el) else {
+            return;
+        };
+        if !HTML_TAG.contains(&element_type) {
+            return;
+        }
+
+        let allowed = self.exceptions.get(element_type.as_ref());
+        if !self.handlers.iter().any(|handler| {
+            has_jsx_prop(jsx_opening_el, handler).is_some()
+                && !allowed.is_some_and(|allowed| allowed.contains(handler))
+        }) {
+            return;
+        }
+
+        if has_jsx_prop_ignore_case(jsx_opening_el, "contentEditable")
+            .and_then(get_string_literal_prop_value)
+            .is_some_and(|value| value == "true")
+        {
+            return;
+        }
+
+        if is_hidden_from_screen_reader(ctx, jsx_opening_el)
+            || is_presentation_role(jsx_opening_el)
+            || is_interactive_element(&element_type, jsx_opening_el)
+            || is_interactive_role(jsx_opening_el)
+        {
+            return;
+        }
+
+        if !is_non_interactive_element(&element_type, jsx_opening_el)
+            && !is_non_interactive_role(jsx_opening_el)
+        {
+            return;
+        }
+
+        ctx.diagnostic(no_noninteractive_element_interactions_diagnostic(jsx_opening_el.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />", None, None, None),
+        (r"<div onClick={() => void 0} />", None, None, None),
+        (r#"<div onClick={() => void 0} role="button" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="presentation" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="none" />"#, None, None, None),
+        (r"<div onClick={() => void 0} role={undefined} />", None, None, None),
+        (r#"<li onClick={() => void 0} role="menuitem" />"#, None, None, None),
+        (r"<li onClick={() => void 0} aria-hidden />", None, None, None),
+        (r#"<li onClick={() => void 0} contentEditable="true" />"#, None, None, None),
+        (r"<li onFocus={() => void 0} />", None, None, None),
+        (r#"<input type="text" onClick={() => void 0} />"#, None, None, None),
+        (r#"<button onClick={() => void 0} className="foo" />"#, None, None, None),
+        (r#"<a href="http://x.y.z" onClick={() => void 0} />"#, None, None, None),
+        (r"<img usemap={map} onClick={() => void 0} />", None, None, None),
+        (r"<Foo onClick={() => void 0} />", None, None, None),
+        (
+            r"<li onClick={() => void 0} />",
+            Some(serde_json::json!([{ "li": ["onClick"] }])),
+            None,
+            None,
+        ),
+        (
+            r"<li onClick={() => void 0} />",
+            Some(serde_json::json!([{ "handlers": ["onKeyDown"] }])),
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (r"<li onClick={() => void 0} />", None, None, None),
+        (r"<main onClick={() => void 0} />", None, None, None),
+        (r"<h1 onKeyDown={() => void 0} />", None, None, None),
+        (r"<img onMouseUp={() => void 0} />", None, None, None),
+        (r#"<div onClick={() => void 0} role="listitem" />"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="foo article" />"#, None, None, None),
+        (r#"<section onClick={() => void 0} contentEditable="false" />"#, None, None, None),
+        (
+            r"<li onKeyUp={() => void 0} />",
+            Some(serde_json::json!([{ "li": ["onClick"] }])),
+            None,
+            None,
+        ),
+        (
+            r"<Item onClick={() => void 0} />",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": {
+                    "components": {
+                        "Item": "li",
+                    }
+                } }
+            })),
+            None,
+        ),
+    ];
+
+    Tester::new(NoNoninteractiveElementInteractions::NAME, pass, fail).test_and_snapshot();
+}
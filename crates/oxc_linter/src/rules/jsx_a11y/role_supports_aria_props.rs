@@ -35,7 +35,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <ul role="radiogroup" aria-required "aria-labelledby"="foo">
     ///     <li tabIndex="-1" role="radio" aria-checked="false">Rainbow Trout</li>
     ///     <li tabIndex="-1" role="radio" aria-checked="false">Brook Trout</li>
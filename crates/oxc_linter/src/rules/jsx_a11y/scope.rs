@@ -38,7 +38,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <th scope="col" />
     /// <th scope={scope} />
     /// ```
@@ -39,7 +39,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <span tabIndex="0">foo</span>
     /// <span tabIndex="-1">bar</span>
     /// ```
@@ -39,7 +39,7 @@ declare_oxc_lint!(
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
-    /// ```ts
+    /// ```ts ignore
     /// function f(a: number, b: number): number {
     ///     if (a == 0) {
     ///         return 1
@@ -33,7 +33,7 @@ impl Default for CatchOrReturnConfig {
         Self {
             allow_finally: false,
             allow_then: false,
-            termination_method: vec![CompactStr::new("catch")],
+            termination_method: vec![CompactStr::new_const("catch")],
         }
     }
 }
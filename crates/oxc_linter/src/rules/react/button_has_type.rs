@@ -61,7 +61,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <button type="button" />
     /// <button type="submit" />
     /// ```
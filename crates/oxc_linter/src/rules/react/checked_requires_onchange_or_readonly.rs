@@ -50,7 +50,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <input type="checkbox" checked onChange={() => {}} />
     /// <input type="checkbox" checked readOnly />
     /// <input type="checkbox" checked onChange readOnly />
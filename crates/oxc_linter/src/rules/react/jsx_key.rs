@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{Expression, JSXAttributeItem, JSXAttributeName, JSXElement, JSXFragment, Statement},
+    ast::{
+        BindingIdentifier, Expression, JSXAttributeItem, JSXAttributeName, JSXElement, JSXFragment,
+        Statement, VariableDeclarator,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -41,6 +44,9 @@ declare_oxc_lint!(
     /// ```jsx
     /// [1, 2, 3].map(x => <App />);
     /// [1, 2, 3]?.map(x => <BabelEslintApp />)
+    ///
+    /// const renderItem = (x) => <App />;
+    /// [1, 2, 3].map(renderItem);
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -176,9 +182,15 @@ fn is_in_array_or_iter<'a, 'b>(
                 if is_outside_containing_function {
                     return None;
                 }
+                // `const renderItem = (item) => <App />; items.map(renderItem);`
+                if let AstKind::VariableDeclarator(declarator) = parent.kind() {
+                    let binding = declarator.id.get_binding_identifier()?;
+                    return find_iterator_using_callback(binding, ctx)
+                        .map(InsideArrayOrIterator::Iterator);
+                }
                 is_outside_containing_function = true;
             }
-            AstKind::Function(_) => {
+            AstKind::Function(func) => {
                 let parent = ctx.nodes().parent_node(parent.id())?;
 
                 if let AstKind::ObjectProperty(_) = parent.kind() {
@@ -187,8 +199,27 @@ fn is_in_array_or_iter<'a, 'b>(
                 if is_outside_containing_function {
                     return None;
                 }
+                // `function renderItem(item) { return <App /> } items.map(renderItem);`
+                let binding = match parent.kind() {
+                    AstKind::VariableDeclarator(declarator) => {
+                        declarator.id.get_binding_identifier()
+                    }
+                    _ if func.is_declaration() => func.id.as_ref(),
+                    _ => None,
+                };
+                if let Some(binding) = binding {
+                    return find_iterator_using_callback(binding, ctx)
+                        .map(InsideArrayOrIterator::Iterator);
+                }
                 is_outside_containing_function = true;
             }
+            // `items.map((item) => { const element = <App />; return element; })`
+            AstKind::VariableDeclarator(declarator) => {
+                if !is_returned_variable(declarator, ctx) {
+                    return None;
+                }
+                is_explicit_return = true;
+            }
             AstKind::ArrayExpression(_) => {
                 if is_outside_containing_function {
                     return None;
@@ -222,6 +253,36 @@ fn is_in_array_or_iter<'a, 'b>(
     }
 }
 
+/// Find an iterator method call which `binding` is passed to as the callback,
+/// e.g. `items.map(renderItem)`, and return the span of the method name.
+fn find_iterator_using_callback(binding: &BindingIdentifier, ctx: &LintContext) -> Option<Span> {
+    let symbol_id = binding.symbol_id.get()?;
+    ctx.semantic().symbol_references(symbol_id).find_map(|reference| {
+        let AstKind::Argument(_) = ctx.nodes().parent_kind(reference.node_id())? else {
+            return None;
+        };
+        let argument = ctx.nodes().parent_node(reference.node_id())?;
+        let AstKind::CallExpression(call) = ctx.nodes().parent_kind(argument.id())? else {
+            return None;
+        };
+        let member = call.callee.without_parentheses().as_member_expression()?;
+        let (span, name) = member.static_property_info()?;
+        TARGET_METHODS.contains(name).then_some(span)
+    })
+}
+
+/// Whether the variable declared by `declarator` is returned, e.g. `return element;`.
+fn is_returned_variable(declarator: &VariableDeclarator, ctx: &LintContext) -> bool {
+    let Some(symbol_id) =
+        declarator.id.get_binding_identifier().and_then(|binding| binding.symbol_id.get())
+    else {
+        return false;
+    };
+    ctx.semantic().symbol_references(symbol_id).any(|reference| {
+        matches!(ctx.nodes().parent_kind(reference.node_id()), Some(AstKind::ReturnStatement(_)))
+    })
+}
+
 fn check_jsx_element<'a>(node: &AstNode<'a>, jsx_elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
     if let Some(outer) = is_in_array_or_iter(node, ctx) {
         if is_within_children_to_array(node, ctx) {
@@ -318,6 +379,12 @@ fn test() {
         r"var App = () => <div />;",
         r"[1, 2, 3].map(function(x) { return; });",
         r"foo(() => <div />);",
+        r"const renderItem = (x) => <App key={x} />; [1, 2, 3].map(renderItem);",
+        r"function renderItem(x) { return <App key={x} />; } [1, 2, 3].map(renderItem);",
+        r"const renderItem = (x) => <App />; foo(renderItem);",
+        r"function Component() { return <App />; } [1, 2, 3].map(Component.bind(null));",
+        r"[1, 2, 3].map(x => { const el = <App key={x} />; return el; });",
+        r"[1, 2, 3].map(x => { const el = <App />; return <div key={x}>{el}</div>; });",
         r"foo(() => <></>);",
         r"<></>;",
         r"<App {...{}} />;",
@@ -601,6 +668,11 @@ fn test() {
                   );
                 };
           ",
+        r"const renderItem = (x) => <App />; [1, 2, 3].map(renderItem);",
+        r"const renderItem = function (x) { return <App />; }; Array.from([1, 2, 3], renderItem);",
+        r"function renderItem(x) { return x ? <App /> : <></>; } [1, 2, 3].map(renderItem);",
+        r"[1, 2, 3].map(x => { const el = <App />; return el; });",
+        r"[1, 2, 3].map(x => { const el = x ? <App /> : <OtherApp key={x} />; return el; });",
         r"foo.Children.toArray([1, 2 ,3].map(x => <App />));",
         r"
         import Act from 'react';
@@ -44,7 +44,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <>foo <div></div></>
     /// <div>foo</div>
     /// ```
@@ -66,7 +66,7 @@ impl Rule for JsxNoUselessFragment {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
-                if !is_jsx_fragment(&jsx_elem.opening_element) {
+                if !is_jsx_fragment(&jsx_elem.opening_element, ctx) {
                     return;
                 }
                 self.check_element(node, jsx_elem, ctx);
@@ -165,12 +165,13 @@ fn is_html_element(elem_name: &JSXElementName) -> bool {
     ident.name.starts_with(char::is_lowercase)
 }
 
-fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
+fn is_jsx_fragment(elem: &JSXOpeningElement, ctx: &LintContext) -> bool {
+    let settings = &ctx.settings().react;
     match &elem.name {
-        JSXElementName::IdentifierReference(ident) => ident.name == "Fragment",
+        JSXElementName::IdentifierReference(ident) => ident.name == settings.fragment(),
         JSXElementName::MemberExpression(mem_expr) => {
             if let JSXMemberExpressionObject::IdentifierReference(ident) = &mem_expr.object {
-                ident.name == "React" && mem_expr.property.name == "Fragment"
+                ident.name == settings.pragma() && mem_expr.property.name == settings.fragment()
             } else {
                 false
             }
@@ -225,19 +226,19 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        (r"<><Foo /><Bar /></>", None),
-        (r"<>foo<div /></>", None),
-        (r"<> <div /></>", None),
-        (r#"<>{"moo"} </>"#, None),
-        (r"<NotFragment />", None),
-        (r"<React.NotFragment />", None),
-        (r"<NotReact.Fragment />", None),
-        (r"<Foo><><div /><div /></></Foo>", None),
-        (r#"<div p={<>{"a"}{"b"}</>} />"#, None),
-        (r"<Fragment key={item.id}>{item.value}</Fragment>", None),
-        (r"<Fooo content={<>eeee ee eeeeeee eeeeeeee</>} />", None),
-        (r"<>{foos.map(foo => foo)}</>", None),
-        (r"<>{moo}</>", Some(json!([{ "allowExpressions": true }]))),
+        (r"<><Foo /><Bar /></>", None, None),
+        (r"<>foo<div /></>", None, None),
+        (r"<> <div /></>", None, None),
+        (r#"<>{"moo"} </>"#, None, None),
+        (r"<NotFragment />", None, None),
+        (r"<React.NotFragment />", None, None),
+        (r"<NotReact.Fragment />", None, None),
+        (r"<Foo><><div /><div /></></Foo>", None, None),
+        (r#"<div p={<>{"a"}{"b"}</>} />"#, None, None),
+        (r"<Fragment key={item.id}>{item.value}</Fragment>", None, None),
+        (r"<Fooo content={<>eeee ee eeeeeee eeeeeeee</>} />", None, None),
+        (r"<>{foos.map(foo => foo)}</>", None, None),
+        (r"<>{moo}</>", Some(json!([{ "allowExpressions": true }])), None),
         (
             r"
         <>
@@ -245,17 +246,23 @@ fn test() {
         </>
         ",
             Some(json!([{ "allowExpressions": true }])),
+            None,
+        ),
+        (r"{1 && <>{1}</>}", Some(json!([{"allowExpressions": true}])), None),
+        (
+            r"<React.Fragment><Foo /></React.Fragment>",
+            None,
+            Some(json!({ "settings": { "react": { "pragma": "Preact" } } })),
         ),
-        (r"{1 && <>{1}</>}", Some(json!([{"allowExpressions": true}]))),
     ];
 
     let fail = vec![
-        (r"<></>", None),
-        (r"<>{}</>", None),
-        (r"<p>moo<>foo</></p>", None),
-        (r"<>{meow}</>", None),
-        (r"<p><>{meow}</></p>", None),
-        (r"<><div/></>", None),
+        (r"<></>", None, None),
+        (r"<>{}</>", None, None),
+        (r"<p>moo<>foo</></p>", None, None),
+        (r"<>{meow}</>", None, None),
+        (r"<p><>{meow}</></p>", None, None),
+        (r"<><div/></>", None, None),
         (
             r"
             <>
@@ -263,8 +270,9 @@ fn test() {
             </>
         ",
             None,
+            None,
         ),
-        (r"<Fragment />", None),
+        (r"<Fragment />", None, None),
         (
             r"
                 <React.Fragment>
@@ -272,11 +280,12 @@ fn test() {
                 </React.Fragment>
             ",
             None,
+            None,
         ),
-        (r"<Eeee><>foo</></Eeee>", None),
-        (r"<div><>foo</></div>", None),
-        (r#"<div><>{"a"}{"b"}</></div>"#, None),
-        (r#"<div><>{"a"}{"b"}</></div>"#, None),
+        (r"<Eeee><>foo</></Eeee>", None, None),
+        (r"<div><>foo</></div>", None, None),
+        (r#"<div><>{"a"}{"b"}</></div>"#, None, None),
+        (r#"<div><>{"a"}{"b"}</></div>"#, None, None),
         (
             r#"
             <section>
@@ -285,8 +294,9 @@ fn test() {
               <>{"a"}{"b"}</>
             </section>"#,
             None,
+            None,
         ),
-        (r#"<div><Fragment>{"a"}{"b"}</Fragment></div>"#, None),
+        (r#"<div><Fragment>{"a"}{"b"}</Fragment></div>"#, None, None),
         (
             r"
             <section>
@@ -298,8 +308,9 @@ fn test() {
             </section>
             ",
             None,
+            None,
         ),
-        (r#"<div>a <>{""}{""}</> a</div>"#, None),
+        (r#"<div>a <>{""}{""}</> a</div>"#, None, None),
         (
             r"
             const Comp = () => (
@@ -309,8 +320,9 @@ fn test() {
             );
         ",
             None,
+            None,
         ),
-        (r"<><Foo>{moo}</Foo></>", None),
+        (r"<><Foo>{moo}</Foo></>", None, None),
     ];
 
     Tester::new(JsxNoUselessFragment::NAME, pass, fail).test_and_snapshot();
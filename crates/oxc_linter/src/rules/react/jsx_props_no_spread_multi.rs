@@ -47,7 +47,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <App myAttr="1" {...props} />
     /// <App {...props} myAttr="1" />
     /// ```
@@ -38,7 +38,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <div>Children</div>
     /// <MyComponent>Children</MyComponent>
     ///
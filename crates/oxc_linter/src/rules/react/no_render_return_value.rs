@@ -48,7 +48,10 @@ impl Rule for NoRenderReturnValue {
         let Expression::Identifier(ident) = member_expr.object() else {
             return;
         };
-        if ident.name == "ReactDOM" {
+        // `React.render` was moved to `ReactDOM.render` in React 0.14, and removed in React 15
+        let is_render_object = ident.name == "ReactDOM"
+            || (ident.name == "React" && !ctx.settings().react.is_version_at_least(15, 0, 0));
+        if is_render_object {
             if let Some((property_span, property_name)) = member_expr.static_property_info() {
                 if property_name == "render" {
                     if let Some(parent_node) = ctx.nodes().parent_node(node.id()) {
@@ -92,28 +95,30 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("ReactDOM.render(<div />, document.body);", None),
+        ("ReactDOM.render(<div />, document.body);", None, None),
         (
             "
         	        let node;
         	        ReactDOM.render(<div ref={ref => node = ref}/>, document.body);
         	      ",
             None,
+            None,
         ),
-        ("ReactDOM.render(<div ref={ref => this.node = ref}/>, document.body);", None),
-        ("React.render(<div ref={ref => this.node = ref}/>, document.body);", None),
-        ("React.render(<div ref={ref => this.node = ref}/>, document.body);", None),
-        ("var foo = React.render(<div />, root);", None),
-        ("var foo = render(<div />, root)", None),
-        ("var foo = ReactDom.renderder(<div />, root)", None),
+        ("ReactDOM.render(<div ref={ref => this.node = ref}/>, document.body);", None, None),
+        ("React.render(<div ref={ref => this.node = ref}/>, document.body);", None, None),
+        ("React.render(<div ref={ref => this.node = ref}/>, document.body);", None, None),
+        ("var foo = React.render(<div />, root);", None, None),
+        ("var foo = render(<div />, root)", None, None),
+        ("var foo = ReactDom.renderder(<div />, root)", None, None),
         (
             "export const foo = () => ({ destroy: ({ dom }) => { ReactDOM.unmountComponentAtNode(dom); } });",
             None,
+            None,
         ),
     ];
 
     let fail = vec![
-        ("var Hello = ReactDOM.render(<div />, document.body);", None),
+        ("var Hello = ReactDOM.render(<div />, document.body);", None, None),
         (
             "
         	        var o = {
@@ -121,6 +126,7 @@ fn test() {
         	        };
         	      ",
             None,
+            None,
         ),
         (
             "
@@ -129,15 +135,22 @@ fn test() {
         	        }
         	      ",
             None,
+            None,
+        ),
+        ("var render = (a, b) => ReactDOM.render(a, b)", None, None),
+        ("this.o = ReactDOM.render(<div />, document.body);", None, None),
+        ("var v; v = ReactDOM.render(<div />, document.body);", None, None),
+        ("var inst = ReactDOM.render(<div />, document.body);", None, None),
+        (
+            "var inst = React.render(<div />, document.body);",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "version": "0.14.0" } } })),
+        ),
+        (
+            "var inst = React.render(<div />, document.body);",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "version": "0.13.0" } } })),
         ),
-        ("var render = (a, b) => ReactDOM.render(a, b)", None),
-        ("this.o = ReactDOM.render(<div />, document.body);", None),
-        ("var v; v = ReactDOM.render(<div />, document.body);", None),
-        ("var inst = ReactDOM.render(<div />, document.body);", None),
-        // This rule is only supported for react versions >= 15.0.0, so the following are not supported.
-        // See https://github.com/oxc-project/oxc/pull/1042#discussion_r1369762147
-        // ("var inst = React.render(<div />, document.body);", None),
-        // ("var inst = React.render(<div />, document.body);", None),
     ];
 
     Tester::new(NoRenderReturnValue::NAME, pass, fail).test_and_snapshot();
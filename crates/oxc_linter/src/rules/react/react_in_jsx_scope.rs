@@ -3,11 +3,11 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{config::JSXRuntime, context::LintContext, rule::Rule, AstNode};
 
-fn react_in_jsx_scope_diagnostic(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("'React' must be in scope when using JSX")
-        .with_help("When using JSX, `<a />` expands to `React.createElement(\"a\")`. Therefore the `React` variable must be in scope.")
+fn react_in_jsx_scope_diagnostic(span: Span, pragma: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{pragma}' must be in scope when using JSX"))
+        .with_help(format!("When using JSX, `<a />` expands to `{pragma}.createElement(\"a\")`. Therefore the `{pragma}` variable must be in scope."))
         .with_label(span)
 }
 
@@ -37,6 +37,9 @@ declare_oxc_lint!(
     /// var a = <a />;
     ///
     /// ```
+    ///
+    /// The name of the variable can be changed with `settings.react.pragma`. This rule does
+    /// not apply when `settings.react.runtime` is `"automatic"`.
     ReactInJsxScope,
     suspicious
 );
@@ -49,18 +52,18 @@ impl Rule for ReactInJsxScope {
             _ => return,
         };
         let scope = ctx.scopes();
-        let react_name = "React";
+        let react_name = ctx.settings().react.pragma();
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
             return;
         }
 
         if scope.find_binding(node.scope_id(), react_name).is_none() {
-            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span));
+            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span, react_name));
         }
     }
 
     fn should_run(&self, ctx: &LintContext) -> bool {
-        ctx.source_type().is_jsx()
+        ctx.source_type().is_jsx() && ctx.settings().react.runtime == JSXRuntime::Classic
     }
 }
 
@@ -69,13 +72,13 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("var React, App; <App />;", None),
-        ("var React; <img />;", None),
-        ("var React; <>fragment</>;", None),
-        ("var React; <x-gif />;", None),
-        ("var React, App, a=1; <App attr={a} />;", None),
-        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None),
-        ("var React, App; <App />;", None),
+        ("var React, App; <App />;", None, None),
+        ("var React; <img />;", None, None),
+        ("var React; <>fragment</>;", None, None),
+        ("var React; <x-gif />;", None, None),
+        ("var React, App, a=1; <App attr={a} />;", None, None),
+        ("var React, App, a=1; function elem() { return <App attr={a} />; }", None, None),
+        ("var React, App; <App />;", None, None),
         (
             "
 			        import React from 'react/addons';
@@ -89,16 +92,32 @@ fn test() {
 			        export default Button;
 			      ",
             None,
+            None,
+        ),
+        ("var React, a = <img />;", None, None),
+        (
+            "var Preact, a = <img />;",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "pragma": "Preact" } } })),
+        ),
+        (
+            "var a = <img />;",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "runtime": "automatic" } } })),
         ),
-        ("var React, a = <img />;", None),
     ];
 
     let fail = vec![
-        ("var App, a = <App />;", None),
-        ("var a = <App />;", None),
-        ("var a = <img />;", None),
-        ("var a = <>fragment</>;", None),
-        ("var Foo, a = <img />;", None),
+        ("var App, a = <App />;", None, None),
+        ("var a = <App />;", None, None),
+        ("var a = <img />;", None, None),
+        ("var a = <>fragment</>;", None, None),
+        ("var Foo, a = <img />;", None, None),
+        (
+            "var React, a = <img />;",
+            None,
+            Some(serde_json::json!({ "settings": { "react": { "pragma": "Preact" } } })),
+        ),
     ];
 
     Tester::new(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
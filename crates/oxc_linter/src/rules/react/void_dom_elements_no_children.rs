@@ -42,7 +42,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```jsx
+    /// ```jsx ignore
     /// <div>Children</div>
     /// <div children='Children' />
     /// <div dangerouslySetInnerHTML={{ __html: 'HTML' }} />
@@ -1,10 +1,13 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_macros::declare_oxc_lint;
+use oxc_macros::{declare_oxc_lint, RuleConfig};
 use oxc_span::Span;
-use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{Rule, RuleConfig},
+    AstNode,
+};
 
 fn no_explicit_any_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected any. Specify a different type.")
@@ -12,7 +15,7 @@ fn no_explicit_any_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, RuleConfig)]
 pub struct NoExplicitAny {
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     ///
@@ -57,7 +60,7 @@ declare_oxc_lint!(
     ///
     /// Examples of **correct** code for this rule:
     ///
-    /// ```typescript
+    /// ```typescript ignore
     /// const age: number = 17;
     /// const ages: number[] = [17];
     /// const ages: Array<number> = [17];
@@ -104,14 +107,8 @@ impl Rule for NoExplicitAny {
         }
     }
 
-    fn from_configuration(value: Value) -> Self {
-        let Some(cfg) = value.get(0) else {
-            return Self::default();
-        };
-        let fix_to_unknown = cfg.get("fixToUnknown").and_then(Value::as_bool).unwrap_or(false);
-        let ignore_rest_args = cfg.get("ignoreRestArgs").and_then(Value::as_bool).unwrap_or(false);
-
-        Self { fix_to_unknown, ignore_rest_args }
+    fn from_configuration(value: serde_json::Value) -> Self {
+        <Self as RuleConfig>::from_configuration(value)
     }
 
     fn should_run(&self, ctx: &LintContext) -> bool {
@@ -75,7 +75,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```js
+    /// ```js ignore
     /// function doBar(bar) {
     /// 	return bar === 'bar';
     /// }
@@ -36,7 +36,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = '\xA9';
     /// const foo = '\uD834';
     /// const foo = '\u{1D306}';
@@ -39,7 +39,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// export default class Foo {}
     /// export default function foo () {}
     ///
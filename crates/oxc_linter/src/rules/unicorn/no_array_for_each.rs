@@ -1,10 +1,17 @@
+use oxc_allocator::CloneIn;
 use oxc_ast::{
-    ast::{match_member_expression, Expression},
-    AstKind,
+    ast::{
+        match_member_expression, Argument, ArrowFunctionExpression, BindingPatternKind,
+        CallExpression, Expression, ExpressionStatement, Function, MemberExpression,
+        ReturnStatement, TSTypeParameterInstantiation, VariableDeclaration,
+    },
+    visit::walk::walk_variable_declaration,
+    AstKind, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span, SPAN};
 use phf::phf_set;
 
 use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
@@ -46,9 +53,12 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// The fix replaces `forEach` statements with a `for…of` loop when the callback is an arrow
+    /// function with simple parameters, which does not `return` or declare `var` variables.
     NoArrayForEach,
     restriction,
-    pending
+    conditional_fix
 );
 
 impl Rule for NoArrayForEach {
@@ -90,11 +100,143 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span));
+            let diagnostic = no_array_for_each_diagnostic(span);
+            let Some((stmt, callback)) = fixable_statement(node, call_expr, member_expr, ctx)
+            else {
+                ctx.diagnostic(diagnostic);
+                return;
+            };
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                let object = object.without_parentheses();
+                let params = &callback.params.items;
+                let element = fixer.source_range(params[0].span);
+                let (binding, iterable) = if let Some(index) = params.get(1) {
+                    // `(element, index) => {}` -> `for (const [index, element] of array.entries()) {}`
+                    let binding = format!("[{}, {element}]", fixer.source_range(index.span));
+                    let iterable = fixer.print_expression(|ast| {
+                        let callee = ast.member_expression_static(
+                            SPAN,
+                            object.clone_in(ast.allocator),
+                            ast.identifier_name(SPAN, "entries"),
+                            false,
+                        );
+                        ast.expression_call(
+                            SPAN,
+                            Expression::from(callee),
+                            Option::<TSTypeParameterInstantiation>::None,
+                            ast.vec(),
+                            false,
+                        )
+                    });
+                    (binding, iterable)
+                } else {
+                    let iterable = fixer.print_expression(|ast| object.clone_in(ast.allocator));
+                    (element.to_string(), iterable)
+                };
+                let body = match callback.get_expression() {
+                    Some(expr) => format!("{};", fixer.source_range(expr.span())),
+                    None => fixer.source_range(callback.body.span).to_string(),
+                };
+                fixer.replace(stmt.span, format!("for (const {binding} of {iterable}) {body}"))
+            });
         }
     }
 }
 
+/// The statement `array.forEach(callback);` and its callback, if the statement can be replaced
+/// with a `for…of` loop without changing its behavior.
+fn fixable_statement<'a, 'c>(
+    node: &AstNode<'a>,
+    call_expr: &'c CallExpression<'a>,
+    member_expr: &'c MemberExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a ExpressionStatement<'a>, &'c ArrowFunctionExpression<'a>)> {
+    if call_expr.optional || member_expr.optional() {
+        return None;
+    }
+    let parent = ctx.nodes().parent_node(node.id())?;
+    let AstKind::ExpressionStatement(stmt) = parent.kind() else {
+        return None;
+    };
+    // The body of `() => array.forEach(callback)` is an expression, not a statement
+    let function =
+        ctx.nodes().parent_node(parent.id()).and_then(|body| ctx.nodes().parent_kind(body.id()));
+    if matches!(function, Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression) {
+        return None;
+    }
+    let [Argument::ArrowFunctionExpression(callback)] = call_expr.arguments.as_slice() else {
+        return None;
+    };
+    // `for (const element of a, b)` is a syntax error
+    let object = member_expr.object().without_parentheses();
+    if matches!(object, Expression::SequenceExpression(_)) {
+        return None;
+    }
+    if callback.r#async
+        || callback.type_parameters.is_some()
+        || callback.return_type.is_some()
+        || callback.params.rest.is_some()
+        || !matches!(callback.params.items.len(), 1 | 2)
+    {
+        return None;
+    }
+
+    // Only `element` or `element, index` without types or default values, which are not used
+    // by the array expression, e.g. `element.children.forEach(element => {})`
+    let object_text = ctx.source_range(object.span());
+    for param in &callback.params.items {
+        let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+            return None;
+        };
+        if param.pattern.type_annotation.is_some()
+            || !param.decorators.is_empty()
+            || object_text.contains(ident.name.as_str())
+        {
+            return None;
+        }
+    }
+
+    if let Some(expr) = callback.get_expression() {
+        // The expression would be parsed as a declaration at the start of a statement
+        let text = ctx.source_range(expr.span());
+        if ["function", "async", "class", "let"].iter().any(|keyword| text.starts_with(keyword)) {
+            return None;
+        }
+    } else {
+        let mut finder = UnsafeStatementFinder { found: false };
+        finder.visit_function_body(&callback.body);
+        if finder.found {
+            return None;
+        }
+    }
+
+    Some((stmt, &**callback))
+}
+
+/// Finds `return` statements and `var` declarations of a callback, which would behave
+/// differently in the body of a loop.
+struct UnsafeStatementFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for UnsafeStatementFinder {
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind.is_var() {
+            self.found = true;
+        } else {
+            walk_variable_declaration(self, decl);
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+}
+
 pub const IGNORED_OBJECTS: phf::Set<&'static str> = phf_set! {
     "Children",
     "r",
@@ -126,7 +268,51 @@ fn test() {
         r"foo.forEach(function element(element, element1) {})",
         r"this._listeners.forEach((listener: () => void) => listener());",
         r"return foo.forEach(element => {bar(element)});",
+        r"array.forEach(element => bar(element));",
+        r"array.forEach((element, index) => { bar(element, index); });",
+        r"(a || b).forEach(element => bar(element));",
+        r"array.forEach(element => { if (element) { return; } bar(element); });",
+        r"array.forEach(element => { var x = element; });",
+        r"element.children.forEach(element => bar(element));",
+    ];
+
+    let fix = vec![
+        (r"array.forEach(element => bar(element));", r"for (const element of array) bar(element);"),
+        (
+            r"array.forEach((element, index) => { bar(element, index); });",
+            r"for (const [index, element] of array.entries()) { bar(element, index); }",
+        ),
+        (
+            r"(a || b).forEach(element => bar(element));",
+            r"for (const element of a || b) bar(element);",
+        ),
+        (
+            r"foo.bar.forEach(element => { const x = element; baz(x); });",
+            r"for (const element of foo.bar) { const x = element; baz(x); }",
+        ),
+        (
+            r"array.forEach((arrayInArray) => arrayInArray.forEach(element => bar(element)));",
+            r"for (const arrayInArray of array) arrayInArray.forEach(element => bar(element));",
+        ),
+        // `return` and `var` behave differently in a loop
+        (
+            r"array.forEach(element => { if (element) { return; } bar(element); });",
+            r"array.forEach(element => { if (element) { return; } bar(element); });",
+        ),
+        (
+            r"array.forEach(element => { var x = element; });",
+            r"array.forEach(element => { var x = element; });",
+        ),
+        (
+            r"element.children.forEach(element => bar(element));",
+            r"element.children.forEach(element => bar(element));",
+        ),
+        (r"foo.forEach?.(element => bar(element))", r"foo.forEach?.(element => bar(element))"),
+        (
+            r"return foo.forEach(element => {bar(element)});",
+            r"return foo.forEach(element => {bar(element)});",
+        ),
     ];
 
-    Tester::new(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayForEach::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -31,7 +31,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = '\u001B';
     /// const foo = `\u001B${bar}`;
     /// ```
@@ -38,7 +38,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// el.removeEventListener('click', handler);
     /// el.removeEventListener('click', handler.bind(this));
     /// ```
@@ -39,7 +39,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = i > 5 ? (i < 100 ? true : false) : true;
     /// const foo = i > 5 ? (i < 100 ? true : false) : (i < 100 ? true : false);
     /// ```
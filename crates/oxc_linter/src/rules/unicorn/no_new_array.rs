@@ -31,7 +31,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const array = Array.from({ length: 42 });
     /// const array = [42];
     /// ```
@@ -44,7 +44,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// async function good() {
     ///     const foo = await promise;
     ///     const promise = Promise.resolve(nonPromise);
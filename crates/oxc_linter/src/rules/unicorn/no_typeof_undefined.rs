@@ -29,7 +29,7 @@ declare_oxc_lint!(
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// typeof foo === 'undefined';
     /// ```
     ///
@@ -36,7 +36,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const bar = getBar();
     /// const foo = bar ? bar.baz : baz;
     ///
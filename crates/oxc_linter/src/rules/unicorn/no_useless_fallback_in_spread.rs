@@ -32,7 +32,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const object = { ...foo }
     /// const object = { ...(foo || { not: "empty" }) }
     /// ```
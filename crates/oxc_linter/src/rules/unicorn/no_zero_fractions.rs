@@ -39,7 +39,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = 1;
     /// const foo = -1;
     /// const foo = 123456;
@@ -64,7 +64,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = 0xFF;
     /// const foo = 0b10;
     /// const foo = 0o76;
@@ -55,7 +55,7 @@ declare_oxc_lint!(
     /// ```
     ///
     /// Examples of **correct** code for this rule:
-    /// ```javascript
+    /// ```javascript ignore
     /// const foo = array.flat();
     /// const foo = [maybeArray].flat();
     /// ```
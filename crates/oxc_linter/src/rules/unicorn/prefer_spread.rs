@@ -1,13 +1,20 @@
+use oxc_allocator::CloneIn;
 use oxc_ast::{
     ast::{match_member_expression, Expression},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, Span, SPAN};
 use phf::phf_set;
 
-use crate::{ast_util, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+    AstNode,
+};
 
 fn prefer_spread_diagnostic(span: Span, bad_method: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Prefer the spread operator (`...`) over {bad_method}"))
@@ -42,7 +49,7 @@ declare_oxc_lint!(
     /// ```
     PreferSpread,
     style,
-    conditional_fix
+    conditional_fix_suggestion
 );
 
 impl Rule for PreferSpread {
@@ -83,7 +90,11 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic(prefer_spread_diagnostic(call_expr.span, "Array.from()"));
+                // Not a fix, as array-like objects which are not iterable cannot be spread
+                ctx.diagnostic_with_suggestion(
+                    prefer_spread_diagnostic(call_expr.span, "Array.from()"),
+                    |fixer| spread_into_array(fixer, call_expr.span, expr.without_parentheses()),
+                );
             }
             // `array.concat()`
             "concat" => {
@@ -127,7 +138,11 @@ impl Rule for PreferSpread {
                     }
                 }
 
-                ctx.diagnostic(prefer_spread_diagnostic(call_expr.span, "array.slice()"));
+                // Not a fix, as the object may be a string or a typed array
+                ctx.diagnostic_with_suggestion(
+                    prefer_spread_diagnostic(call_expr.span, "array.slice()"),
+                    |fixer| spread_into_array(fixer, call_expr.span, member_expr_obj),
+                );
             }
             // `array.toSpliced()`
             "toSpliced" => {
@@ -142,7 +157,13 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic(prefer_spread_diagnostic(call_expr.span, "array.toSpliced()"));
+                ctx.diagnostic_with_suggestion(
+                    prefer_spread_diagnostic(call_expr.span, "array.toSpliced()"),
+                    |fixer| {
+                        let object = member_expr.object().without_parentheses();
+                        spread_into_array(fixer, call_expr.span, object)
+                    },
+                );
             }
             // `string.split()`
             "split" => {
@@ -177,6 +198,19 @@ impl Rule for PreferSpread {
     }
 }
 
+/// Replace `span` with `[...expr]`
+fn spread_into_array<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    span: Span,
+    expr: &Expression<'a>,
+) -> RuleFix<'a> {
+    fixer.replace_with_expression(span, |ast| {
+        let element =
+            ast.array_expression_element_spread_element(SPAN, expr.clone_in(ast.allocator));
+        ast.expression_array(SPAN, ast.vec1(element), None)
+    })
+}
+
 const IGNORED_SLICE_CALLEE: phf::Set<&'static str> = phf_set! {
     "arrayBuffer",
     "blob",
@@ -455,8 +489,16 @@ fn test() {
 
     let expect_fix = vec![
         // `Array.from()`
+        ("const x = Array.from(set);", "const x = [...set];", None),
+        ("Array.from(set).map(() => {});", "[...set].map(() => {});", None),
+        ("(Array).from((0, foo))", "[...(0, foo)]", None),
+        ("Array.from(a ? b : c)", "[...a ? b : c]", None),
         // `array.slice()`
+        ("const copy = array.slice()", "const copy = [...array]", None),
+        ("array.slice(0)", "[...array]", None),
+        ("(( (( (( array )).slice ))() ))", "(( [...array] ))", None),
         // `array.toSpliced()`
+        ("const copy = array.toSpliced()", "const copy = [...array]", None),
         // `string.split()`
         (r#""🦄".split("")"#, r#"[..."🦄"]"#, None),
         (r#""foo bar baz".split("")"#, r#"[..."foo bar baz"]"#, None),
@@ -1,7 +1,12 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_allocator::CloneIn;
+use oxc_ast::{
+    ast::{Argument, Expression, MemberExpression, TSTypeParameterInstantiation},
+    AstBuilder, AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{Span, SPAN};
+use oxc_syntax::{number::NumberBase, operator::UnaryOperator};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -49,14 +54,129 @@ impl Rule for PreferStringSlice {
         };
 
         if let MemberExpression::StaticMemberExpression(v) = member_expr {
-            if !matches!(v.property.name.as_str(), "substr" | "substring") {
+            let method_name = v.property.name.as_str();
+            if !matches!(method_name, "substr" | "substring") {
                 return;
             }
-            ctx.diagnostic_with_fix(
-                prefer_string_slice_diagnostic(v.property.span, v.property.name.as_str()),
-                |fixer| fixer.replace(v.property.span, "slice"),
-            );
+            let diagnostic = prefer_string_slice_diagnostic(v.property.span, method_name);
+            let Some(arguments) = slice_arguments(method_name, &call_expr.arguments) else {
+                ctx.diagnostic(diagnostic);
+                return;
+            };
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                // Only `substr(...)` is replaced, the object is kept as written
+                let span = Span::new(v.property.span.start, call_expr.span.end);
+                fixer.replace_with_expression(span, |ast| {
+                    let arguments =
+                        ast.vec_from_iter(arguments.iter().map(|argument| argument.build(ast)));
+                    ast.expression_call(
+                        SPAN,
+                        ast.expression_identifier_reference(SPAN, "slice"),
+                        Option::<TSTypeParameterInstantiation>::None,
+                        arguments,
+                        call_expr.optional,
+                    )
+                })
+            });
+        }
+    }
+}
+
+/// An argument of the `slice()` call which replaces `substr()` or `substring()`.
+enum SliceArgument<'s, 'a> {
+    /// An argument of the original call
+    Original(&'s Expression<'a>),
+    Number(f64),
+    /// `Math.max(0, argument)`
+    NonNegative(&'s Expression<'a>),
+}
+
+impl<'s, 'a> SliceArgument<'s, 'a> {
+    fn build<'b>(&self, ast: AstBuilder<'b>) -> Argument<'b> {
+        let expr = match self {
+            Self::Original(expr) => expr.clone_in(ast.allocator),
+            Self::Number(value) => number_literal(ast, *value),
+            Self::NonNegative(expr) => {
+                let callee = ast.member_expression_static(
+                    SPAN,
+                    ast.expression_identifier_reference(SPAN, "Math"),
+                    ast.identifier_name(SPAN, "max"),
+                    false,
+                );
+                let arguments = ast.vec_from_iter([
+                    Argument::from(number_literal(ast, 0.0)),
+                    Argument::from(expr.clone_in(ast.allocator)),
+                ]);
+                ast.expression_call(
+                    SPAN,
+                    Expression::from(callee),
+                    Option::<TSTypeParameterInstantiation>::None,
+                    arguments,
+                    false,
+                )
+            }
+        };
+        Argument::from(expr)
+    }
+}
+
+fn number_literal(ast: AstBuilder<'_>, value: f64) -> Expression<'_> {
+    ast.expression_numeric_literal(SPAN, value, "", NumberBase::Decimal)
+}
+
+/// Arguments of a `slice()` call which is equivalent to calling `method_name` with `arguments`,
+/// or `None` if they cannot be determined without knowing the values of the arguments.
+fn slice_arguments<'s, 'a>(
+    method_name: &str,
+    arguments: &'s [Argument<'a>],
+) -> Option<Vec<SliceArgument<'s, 'a>>> {
+    use SliceArgument::{NonNegative, Number, Original};
+
+    let arguments = arguments
+        .iter()
+        .map(|argument| argument.as_expression().map(Expression::without_parentheses))
+        .collect::<Option<Vec<_>>>()?;
+    let arguments = match (method_name, arguments.as_slice()) {
+        (_, []) => vec![],
+        ("substr", [start]) => vec![Original(start)],
+        ("substr", [start, length]) => match (number_value(start), number_value(length)) {
+            (Some(start), Some(length)) if start >= 0.0 && length >= 0.0 => {
+                vec![Number(start), Number(start + length)]
+            }
+            (Some(start), _) if start == 0.0 => vec![Number(0.0), NonNegative(length)],
+            _ => return None,
+        },
+        // Negative arguments of `substring()` are treated as `0`
+        ("substring", [start]) => match number_value(start) {
+            Some(value) if value >= 0.0 => vec![Original(start)],
+            Some(_) => vec![Number(0.0)],
+            None => vec![NonNegative(start)],
+        },
+        // `substring()` swaps its arguments if `start` is greater than `end`
+        ("substring", [start, end]) => match (number_value(start), number_value(end)) {
+            (Some(start), Some(end)) => {
+                vec![Number(start.min(end).max(0.0)), Number(start.max(end).max(0.0))]
+            }
+            (Some(start), None) if start == 0.0 => vec![Number(0.0), NonNegative(end)],
+            (None, Some(end)) if end == 0.0 => vec![Number(0.0), NonNegative(start)],
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(arguments)
+}
+
+/// The value of a number literal, e.g. `1` or `-1`
+fn number_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumericLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match unary.argument.without_parentheses() {
+                Expression::NumericLiteral(lit) => Some(-lit.value),
+                _ => None,
+            }
         }
+        _ => None,
     }
 }
 
@@ -139,6 +259,19 @@ fn test() {
         ("foo.bar?.baz?.substr()", "foo.bar?.baz?.slice()"),
         ("foo.bar?.baz.substring()", "foo.bar?.baz.slice()"),
         ("foo.bar.baz?.substr()", "foo.bar.baz?.slice()"),
+        (r#""foo".substr(1)"#, r#""foo".slice(1)"#),
+        (r#""foo".substr(1, 2)"#, r#""foo".slice(1, 3)"#),
+        (r#""foo".substr(0, -1)"#, r#""foo".slice(0, Math.max(0, -1))"#),
+        (r"foo.substr(0, (100, 1))", r"foo.slice(0, Math.max(0, (100, 1)))"),
+        (r#""foo".substring(2, 1)"#, r#""foo".slice(1, 2)"#),
+        (r#""foo".substring(-1, 2)"#, r#""foo".slice(0, 2)"#),
+        (r"foo.substring(start)", r"foo.slice(Math.max(0, start))"),
+        (r#""foo".substring(length, 0)"#, r#""foo".slice(0, Math.max(0, length))"#),
+        (r"foo.substring(0, await 1)", r"foo.slice(0, Math.max(0, await 1))"),
+        // Not fixable without knowing the values of the arguments
+        (r"foo.substr(start, length)", r"foo.substr(start, length)"),
+        (r"foo.substring(start, end)", r"foo.substring(start, end)"),
+        (r"foo.substr(0, ...bar)", r"foo.substr(0, ...bar)"),
     ];
 
     Tester::new(PreferStringSlice::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
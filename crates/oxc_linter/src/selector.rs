@@ -0,0 +1,608 @@
+//! A subset of [ESLint selectors](https://eslint.org/docs/latest/extend/selectors), which match
+//! nodes by their [ESTree](https://github.com/estree/estree) type and attributes.
+//!
+//! Supported syntax:
+//! * node types and the wildcard: `ForInStatement`, `*`
+//! * attributes, including nested attributes: `[async]`, `[name="foo"]`, `[callee.name!=bar]`,
+//!   `[value=/^http/i]`
+//! * descendant and child combinators: `FunctionDeclaration ReturnStatement`, `A > B`
+//! * selector lists: `ForInStatement, WithStatement`
+
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{MemberExpression, MethodDefinitionKind, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{AstNode, AstNodeId, AstNodes};
+use oxc_span::CompactStr;
+use regex::{Regex, RegexBuilder};
+
+#[derive(Debug, Clone)]
+pub struct Selector {
+    /// Alternatives of a selector list
+    complex: Vec<ComplexSelector>,
+}
+
+#[derive(Debug, Clone)]
+struct ComplexSelector {
+    /// Compound selectors from the outermost ancestor to the matched node, with the combinator
+    /// which joins each one to the previous compound selector. The first combinator is unused.
+    compounds: Vec<(Combinator, Compound)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone)]
+struct Compound {
+    /// `None` for the wildcard `*`
+    node_type: Option<CompactStr>,
+    attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone)]
+struct Attribute {
+    /// `["callee", "name"]` for `[callee.name]`
+    path: Vec<CompactStr>,
+    /// `None` if the attribute only needs to exist
+    condition: Option<(bool, AttributeValue)>,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    String(CompactStr),
+    Regex(Regex),
+}
+
+impl Selector {
+    pub fn parse(source: &str) -> Result<Self, OxcDiagnostic> {
+        let mut parser = Parser { source, pos: 0 };
+        let selector = parser.parse_selector_list().ok_or_else(|| {
+            OxcDiagnostic::error(format!("Invalid selector `{source}` at offset {}", parser.pos))
+        })?;
+        Ok(selector)
+    }
+
+    pub fn matches<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        self.complex.iter().any(|complex| complex.matches(node, nodes))
+    }
+}
+
+impl ComplexSelector {
+    fn matches<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        let last = self.compounds.len() - 1;
+        is_estree_node(node, nodes)
+            && self.compounds[last].1.matches(node.kind())
+            && self.matches_ancestors(last, node.id(), nodes)
+    }
+
+    /// Whether the ancestors of `node_id` match the compound selectors before `index`.
+    fn matches_ancestors(&self, index: usize, node_id: AstNodeId, nodes: &AstNodes) -> bool {
+        if index == 0 {
+            return true;
+        }
+        let (combinator, _) = self.compounds[index];
+        let compound = &self.compounds[index - 1].1;
+        // Nodes which do not exist in ESTree, e.g. `Argument`, are skipped
+        let mut ancestors =
+            nodes.iter_parents(node_id).skip(1).filter(|ancestor| is_estree_node(ancestor, nodes));
+        match combinator {
+            Combinator::Child => ancestors.next().is_some_and(|parent| {
+                compound.matches(parent.kind())
+                    && self.matches_ancestors(index - 1, parent.id(), nodes)
+            }),
+            Combinator::Descendant => ancestors.any(|ancestor| {
+                compound.matches(ancestor.kind())
+                    && self.matches_ancestors(index - 1, ancestor.id(), nodes)
+            }),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, kind: AstKind) -> bool {
+        let Some(estree_type) = estree_type(kind) else {
+            return false;
+        };
+        if self
+            .node_type
+            .as_ref()
+            .is_some_and(|node_type| !node_type.eq_ignore_ascii_case(estree_type))
+        {
+            return false;
+        }
+        self.attributes.iter().all(|attribute| attribute.matches(kind))
+    }
+}
+
+impl Attribute {
+    fn matches(&self, kind: AstKind) -> bool {
+        let (last, path) = self.path.split_last().unwrap();
+        let Some(kind) = path.iter().try_fold(kind, |kind, name| child(kind, name)) else {
+            return false;
+        };
+        let Some((negated, expected)) = &self.condition else {
+            return property(kind, last).is_some() || child(kind, last).is_some();
+        };
+        let Some(value) = property(kind, last) else {
+            return *negated;
+        };
+        let is_match = match expected {
+            AttributeValue::String(expected) => value == expected.as_str(),
+            AttributeValue::Regex(regex) => regex.is_match(&value),
+        };
+        is_match != *negated
+    }
+}
+
+/// Whether `node` exists in ESTree. The body of an expression arrow function, e.g. `() => 5`,
+/// is the expression itself in ESTree, so its `FunctionBody` and `ExpressionStatement` are not.
+fn is_estree_node<'a>(node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+    if estree_type(node.kind()).is_none() {
+        return false;
+    }
+    let is_expression_arrow = |node: Option<&AstNode<'a>>| {
+        node.is_some_and(|node| {
+            matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+        })
+    };
+    match node.kind() {
+        AstKind::FunctionBody(_) => !is_expression_arrow(nodes.parent_node(node.id())),
+        AstKind::ExpressionStatement(_) => !nodes
+            .parent_node(node.id())
+            .filter(|parent| matches!(parent.kind(), AstKind::FunctionBody(_)))
+            .is_some_and(|body| is_expression_arrow(nodes.parent_node(body.id()))),
+        _ => true,
+    }
+}
+
+/// The ESTree type of `kind`, or `None` for nodes which do not exist in ESTree.
+#[allow(clippy::match_same_arms)]
+pub fn estree_type(kind: AstKind) -> Option<&'static str> {
+    let estree_type = match kind {
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_)
+        | AstKind::StringLiteral(_) => "Literal",
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::Program(_) => "Program",
+        AstKind::ThisExpression(_) => "ThisExpression",
+        AstKind::ArrayExpression(_) => "ArrayExpression",
+        AstKind::ObjectExpression(_) => "ObjectExpression",
+        AstKind::ObjectProperty(_) => "Property",
+        AstKind::TemplateLiteral(_) => "TemplateLiteral",
+        AstKind::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+        AstKind::MemberExpression(_) => "MemberExpression",
+        AstKind::CallExpression(_) => "CallExpression",
+        AstKind::NewExpression(_) => "NewExpression",
+        AstKind::MetaProperty(_) => "MetaProperty",
+        AstKind::SpreadElement(_) => "SpreadElement",
+        AstKind::UpdateExpression(_) => "UpdateExpression",
+        AstKind::UnaryExpression(_) => "UnaryExpression",
+        AstKind::BinaryExpression(_) | AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::LogicalExpression(_) => "LogicalExpression",
+        AstKind::ConditionalExpression(_) => "ConditionalExpression",
+        AstKind::AssignmentExpression(_) => "AssignmentExpression",
+        AstKind::SequenceExpression(_) => "SequenceExpression",
+        AstKind::Super(_) => "Super",
+        AstKind::AwaitExpression(_) => "AwaitExpression",
+        AstKind::ChainExpression(_) => "ChainExpression",
+        AstKind::Directive(_) | AstKind::ExpressionStatement(_) => "ExpressionStatement",
+        AstKind::BlockStatement(_) | AstKind::FunctionBody(_) | AstKind::FinallyClause(_) => {
+            "BlockStatement"
+        }
+        AstKind::VariableDeclaration(_) => "VariableDeclaration",
+        AstKind::VariableDeclarator(_) => "VariableDeclarator",
+        AstKind::EmptyStatement(_) => "EmptyStatement",
+        AstKind::IfStatement(_) => "IfStatement",
+        AstKind::DoWhileStatement(_) => "DoWhileStatement",
+        AstKind::WhileStatement(_) => "WhileStatement",
+        AstKind::ForStatement(_) => "ForStatement",
+        AstKind::ForInStatement(_) => "ForInStatement",
+        AstKind::ForOfStatement(_) => "ForOfStatement",
+        AstKind::ContinueStatement(_) => "ContinueStatement",
+        AstKind::BreakStatement(_) => "BreakStatement",
+        AstKind::ReturnStatement(_) => "ReturnStatement",
+        AstKind::WithStatement(_) => "WithStatement",
+        AstKind::SwitchStatement(_) => "SwitchStatement",
+        AstKind::SwitchCase(_) => "SwitchCase",
+        AstKind::LabeledStatement(_) => "LabeledStatement",
+        AstKind::ThrowStatement(_) => "ThrowStatement",
+        AstKind::TryStatement(_) => "TryStatement",
+        AstKind::CatchClause(_) => "CatchClause",
+        AstKind::DebuggerStatement(_) => "DebuggerStatement",
+        AstKind::AssignmentPattern(_) | AstKind::AssignmentTargetWithDefault(_) => {
+            "AssignmentPattern"
+        }
+        AstKind::ObjectPattern(_) | AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::ArrayPattern(_) | AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::BindingRestElement(_) => "RestElement",
+        AstKind::Function(func) if func.is_declaration() => "FunctionDeclaration",
+        AstKind::Function(_) => "FunctionExpression",
+        AstKind::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+        AstKind::YieldExpression(_) => "YieldExpression",
+        AstKind::Class(class) if class.is_declaration() => "ClassDeclaration",
+        AstKind::Class(_) => "ClassExpression",
+        AstKind::ClassBody(_) => "ClassBody",
+        AstKind::MethodDefinition(_) => "MethodDefinition",
+        AstKind::PropertyDefinition(_) => "PropertyDefinition",
+        AstKind::PrivateIdentifier(_) => "PrivateIdentifier",
+        AstKind::StaticBlock(_) => "StaticBlock",
+        AstKind::ImportExpression(_) => "ImportExpression",
+        AstKind::ImportDeclaration(_) => "ImportDeclaration",
+        AstKind::ImportSpecifier(_) => "ImportSpecifier",
+        AstKind::ImportDefaultSpecifier(_) => "ImportDefaultSpecifier",
+        AstKind::ImportNamespaceSpecifier(_) => "ImportNamespaceSpecifier",
+        AstKind::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+        AstKind::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+        AstKind::ExportAllDeclaration(_) => "ExportAllDeclaration",
+        AstKind::ExportSpecifier(_) => "ExportSpecifier",
+        AstKind::Decorator(_) => "Decorator",
+        AstKind::JSXElement(_) => "JSXElement",
+        AstKind::JSXOpeningElement(_) => "JSXOpeningElement",
+        AstKind::JSXClosingElement(_) => "JSXClosingElement",
+        AstKind::JSXFragment(_) => "JSXFragment",
+        AstKind::JSXNamespacedName(_) => "JSXNamespacedName",
+        AstKind::JSXMemberExpression(_) => "JSXMemberExpression",
+        AstKind::JSXExpressionContainer(_) => "JSXExpressionContainer",
+        AstKind::JSXSpreadAttribute(_) => "JSXSpreadAttribute",
+        AstKind::JSXIdentifier(_) => "JSXIdentifier",
+        AstKind::JSXText(_) => "JSXText",
+        AstKind::TSEnumDeclaration(_) => "TSEnumDeclaration",
+        AstKind::TSEnumMember(_) => "TSEnumMember",
+        AstKind::TSTypeAnnotation(_) => "TSTypeAnnotation",
+        AstKind::TSTypeReference(_) => "TSTypeReference",
+        AstKind::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+        AstKind::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+        AstKind::TSModuleDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSAnyKeyword(_) => "TSAnyKeyword",
+        AstKind::TSUnknownKeyword(_) => "TSUnknownKeyword",
+        AstKind::TSAsExpression(_) => "TSAsExpression",
+        AstKind::TSSatisfiesExpression(_) => "TSSatisfiesExpression",
+        AstKind::TSTypeAssertion(_) => "TSTypeAssertion",
+        AstKind::TSNonNullExpression(_) => "TSNonNullExpression",
+        AstKind::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+        AstKind::TSExportAssignment(_) => "TSExportAssignment",
+        _ => return None,
+    };
+    Some(estree_type)
+}
+
+/// The child node named `name`, e.g. `callee` of a `CallExpression`.
+fn child<'a>(kind: AstKind<'a>, name: &str) -> Option<AstKind<'a>> {
+    let expression = match (kind, name) {
+        (AstKind::CallExpression(call), "callee") => &call.callee,
+        (AstKind::NewExpression(new), "callee") => &new.callee,
+        (AstKind::MemberExpression(member), "object") => member.object(),
+        (AstKind::MemberExpression(member), "property") => {
+            return Some(match member {
+                MemberExpression::StaticMemberExpression(member) => {
+                    AstKind::IdentifierName(&member.property)
+                }
+                MemberExpression::ComputedMemberExpression(member) => {
+                    AstKind::from_expression(&member.expression)
+                }
+                MemberExpression::PrivateFieldExpression(member) => {
+                    AstKind::PrivateIdentifier(&member.field)
+                }
+            });
+        }
+        (AstKind::UnaryExpression(unary), "argument") => &unary.argument,
+        (AstKind::AwaitExpression(expr), "argument") => &expr.argument,
+        (AstKind::SpreadElement(spread), "argument") => &spread.argument,
+        (AstKind::ThrowStatement(stmt), "argument") => &stmt.argument,
+        (AstKind::ReturnStatement(stmt), "argument") => stmt.argument.as_ref()?,
+        (AstKind::YieldExpression(expr), "argument") => expr.argument.as_ref()?,
+        (AstKind::BinaryExpression(expr), "left") => &expr.left,
+        (AstKind::BinaryExpression(expr), "right") => &expr.right,
+        (AstKind::LogicalExpression(expr), "left") => &expr.left,
+        (AstKind::LogicalExpression(expr), "right") => &expr.right,
+        (AstKind::AssignmentExpression(expr), "right") => &expr.right,
+        (AstKind::IfStatement(stmt), "test") => &stmt.test,
+        (AstKind::WhileStatement(stmt), "test") => &stmt.test,
+        (AstKind::DoWhileStatement(stmt), "test") => &stmt.test,
+        (AstKind::ConditionalExpression(expr), "test") => &expr.test,
+        (AstKind::ExpressionStatement(stmt), "expression") => &stmt.expression,
+        (AstKind::TaggedTemplateExpression(expr), "tag") => &expr.tag,
+        (AstKind::VariableDeclarator(declarator), "init") => declarator.init.as_ref()?,
+        (AstKind::ImportExpression(expr), "source") => &expr.source,
+        (AstKind::VariableDeclarator(declarator), "id") => {
+            return declarator.id.get_binding_identifier().map(AstKind::BindingIdentifier);
+        }
+        (AstKind::Function(func), "id") => return func.id.as_ref().map(AstKind::BindingIdentifier),
+        (AstKind::Class(class), "id") => return class.id.as_ref().map(AstKind::BindingIdentifier),
+        (AstKind::ImportDeclaration(decl), "source") => {
+            return Some(AstKind::StringLiteral(&decl.source));
+        }
+        (AstKind::ExportAllDeclaration(decl), "source") => {
+            return Some(AstKind::StringLiteral(&decl.source));
+        }
+        (AstKind::ExportNamedDeclaration(decl), "source") => {
+            return decl.source.as_ref().map(AstKind::StringLiteral);
+        }
+        _ => return None,
+    };
+    Some(AstKind::from_expression(expression.without_parentheses()))
+}
+
+/// The value of the property named `name`, e.g. `operator` of a `BinaryExpression`.
+fn property<'a>(kind: AstKind<'a>, name: &str) -> Option<Cow<'a, str>> {
+    let bool_value = |value: bool| Some(Cow::Borrowed(if value { "true" } else { "false" }));
+    match (kind, name) {
+        (_, "type") => estree_type(kind).map(Cow::Borrowed),
+        (AstKind::JSXIdentifier(ident), "name") => Some(Cow::Borrowed(ident.name.as_str())),
+        (AstKind::PrivateIdentifier(ident), "name") => Some(Cow::Borrowed(ident.name.as_str())),
+        (_, "name") => kind.identifier_name().map(|name| Cow::Borrowed(name.as_str())),
+        (AstKind::BinaryExpression(expr), "operator") => {
+            Some(Cow::Borrowed(expr.operator.as_str()))
+        }
+        (AstKind::PrivateInExpression(_), "operator") => Some(Cow::Borrowed("in")),
+        (AstKind::LogicalExpression(expr), "operator") => {
+            Some(Cow::Borrowed(expr.operator.as_str()))
+        }
+        (AstKind::UnaryExpression(expr), "operator") => Some(Cow::Borrowed(expr.operator.as_str())),
+        (AstKind::UpdateExpression(expr), "operator") => {
+            Some(Cow::Borrowed(expr.operator.as_str()))
+        }
+        (AstKind::AssignmentExpression(expr), "operator") => {
+            Some(Cow::Borrowed(expr.operator.as_str()))
+        }
+        (AstKind::VariableDeclaration(decl), "kind") => Some(Cow::Borrowed(decl.kind.as_str())),
+        (AstKind::MethodDefinition(method), "kind") => Some(Cow::Borrowed(match method.kind {
+            MethodDefinitionKind::Constructor => "constructor",
+            MethodDefinitionKind::Method => "method",
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+        })),
+        (AstKind::ObjectProperty(prop), "kind") => Some(Cow::Borrowed(match prop.kind {
+            PropertyKind::Init => "init",
+            PropertyKind::Get => "get",
+            PropertyKind::Set => "set",
+        })),
+        (AstKind::StringLiteral(lit), "value") => Some(Cow::Borrowed(lit.value.as_str())),
+        (AstKind::NumericLiteral(lit), "value") => Some(Cow::Borrowed(lit.raw)),
+        (AstKind::BigIntLiteral(lit), "value") => Some(Cow::Borrowed(lit.raw.as_str())),
+        (AstKind::BooleanLiteral(lit), "value") => bool_value(lit.value),
+        (AstKind::NullLiteral(_), "value") => Some(Cow::Borrowed("null")),
+        (AstKind::Function(func), "async") => bool_value(func.r#async),
+        (AstKind::ArrowFunctionExpression(func), "async") => bool_value(func.r#async),
+        (AstKind::Function(func), "generator") => bool_value(func.generator),
+        (AstKind::MemberExpression(member), "computed") => bool_value(member.is_computed()),
+        (AstKind::ObjectProperty(prop), "computed") => bool_value(prop.computed),
+        (AstKind::MemberExpression(member), "optional") => bool_value(member.optional()),
+        (AstKind::CallExpression(call), "optional") => bool_value(call.optional),
+        _ => None,
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skip whitespace, returning whether any was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+        self.pos > start
+    }
+
+    fn parse_selector_list(&mut self) -> Option<Selector> {
+        let mut complex = vec![];
+        loop {
+            self.skip_whitespace();
+            complex.push(self.parse_complex()?);
+            self.skip_whitespace();
+            if self.pos == self.source.len() {
+                return Some(Selector { complex });
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+
+    fn parse_complex(&mut self) -> Option<ComplexSelector> {
+        let mut compounds = vec![(Combinator::Descendant, self.parse_compound()?)];
+        loop {
+            let start = self.pos;
+            let has_whitespace = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if has_whitespace && !matches!(self.peek(), None | Some(',')) {
+                Combinator::Descendant
+            } else {
+                self.pos = start;
+                return Some(ComplexSelector { compounds });
+            };
+            compounds.push((combinator, self.parse_compound()?));
+        }
+    }
+
+    fn parse_compound(&mut self) -> Option<Compound> {
+        let is_wildcard = self.eat('*');
+        let node_type =
+            if is_wildcard { None } else { self.parse_identifier().map(CompactStr::from) };
+        let mut attributes = vec![];
+        while self.eat('[') {
+            attributes.push(self.parse_attribute()?);
+        }
+        if !is_wildcard && node_type.is_none() && attributes.is_empty() {
+            return None;
+        }
+        Some(Compound { node_type, attributes })
+    }
+
+    fn parse_identifier(&mut self) -> Option<&'s str> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-'))
+        {
+            self.pos += 1;
+        }
+        (self.pos > start).then(|| &self.source[start..self.pos])
+    }
+
+    /// Parse an attribute after the opening `[`.
+    fn parse_attribute(&mut self) -> Option<Attribute> {
+        self.skip_whitespace();
+        let mut path = vec![CompactStr::from(self.parse_identifier()?)];
+        while self.eat('.') {
+            path.push(CompactStr::from(self.parse_identifier()?));
+        }
+        self.skip_whitespace();
+        let condition = if self.eat(']') {
+            return Some(Attribute { path, condition: None });
+        } else if self.eat('!') {
+            self.eat('=').then_some(true)?
+        } else if self.eat('=') {
+            false
+        } else {
+            return None;
+        };
+        self.skip_whitespace();
+        let value = self.parse_attribute_value()?;
+        self.skip_whitespace();
+        self.eat(']').then_some(Attribute { path, condition: Some((condition, value)) })
+    }
+
+    fn parse_attribute_value(&mut self) -> Option<AttributeValue> {
+        match self.peek()? {
+            quote @ ('"' | '\'') => {
+                self.pos += 1;
+                let end = self.source[self.pos..].find(quote)? + self.pos;
+                let value = &self.source[self.pos..end];
+                self.pos = end + 1;
+                Some(AttributeValue::String(value.into()))
+            }
+            '/' => {
+                self.pos += 1;
+                let end = self.source[self.pos..].find('/')? + self.pos;
+                let pattern = &self.source[self.pos..end];
+                self.pos = end + 1;
+                let flags = self.parse_identifier().unwrap_or_default();
+                let regex = RegexBuilder::new(pattern)
+                    .case_insensitive(flags.contains('i'))
+                    .multi_line(flags.contains('m'))
+                    .dot_matches_new_line(flags.contains('s'))
+                    .build()
+                    .ok()?;
+                Some(AttributeValue::Regex(regex))
+            }
+            _ => {
+                let start = self.pos;
+                while let Some(c) = self.peek().filter(|c| *c != ']' && !c.is_whitespace()) {
+                    self.pos += c.len_utf8();
+                }
+                (self.pos > start)
+                    .then(|| AttributeValue::String(self.source[start..self.pos].into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::Selector;
+
+    /// Source text of the nodes matched by `selector`.
+    fn find(selector: &str, source_text: &str) -> Vec<String> {
+        let selector = Selector::parse(selector).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text).build(program).semantic;
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| selector.matches(node, semantic.nodes()))
+            .map(|node| node.kind().span().source_text(source_text).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn node_type() {
+        assert_eq!(find("WithStatement", "with (a) {}"), ["with (a) {}"]);
+        assert_eq!(
+            find("FunctionExpression", "function a() {} (function b() {})"),
+            ["function b() {}"]
+        );
+        assert_eq!(find("Literal", "a = 1 + 'b'"), ["1", "'b'"]);
+        assert_eq!(
+            find("ForInStatement, WithStatement", "for (a in b); with (a);"),
+            ["for (a in b);", "with (a);"]
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        assert_eq!(find("BinaryExpression[operator='==']", "a == b; a === b"), ["a == b"]);
+        assert_eq!(find("VariableDeclaration[kind!=const]", "var a; const b = 1;"), ["var a;"]);
+        assert_eq!(
+            find("CallExpression[callee.name=setTimeout]", "setTimeout(); a.setTimeout()"),
+            ["setTimeout()"]
+        );
+        assert_eq!(
+            find("CallExpression[callee.property.name=/^(log|warn)$/]", "console.log(); a.info()"),
+            ["console.log()"]
+        );
+        assert_eq!(
+            find("FunctionDeclaration[async=true]", "async function a() {} function b() {}"),
+            ["async function a() {}"]
+        );
+        assert_eq!(find("VariableDeclarator[init]", "let a = 1, b;"), ["a = 1"]);
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(find("CallExpression > Identifier", "a(b)"), ["a", "b"]);
+        assert_eq!(
+            find("FunctionDeclaration ReturnStatement", "function a() { if (b) { return; } }"),
+            ["return;"]
+        );
+        assert_eq!(
+            find("FunctionDeclaration > ReturnStatement", "function a() { if (b) { return; } }"),
+            Vec::<String>::new()
+        );
+        assert_eq!(find("ArrowFunctionExpression > *", "() => 5; () => {}"), ["5", "{}"]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Selector::parse("").is_err());
+        assert!(Selector::parse("A[").is_err());
+        assert!(Selector::parse("A >").is_err());
+        assert!(Selector::parse("A[name=/(/]").is_err());
+    }
+}
@@ -5,6 +5,7 @@ use std::{
     path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
+    time::Instant,
 };
 
 use dashmap::DashMap;
@@ -15,23 +16,76 @@ use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
+    config::{NodeResolverSettings, TypeScriptResolverSettings},
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
     utils::read_to_string,
-    Fixer, Linter, Message,
+    Fixer, LintStatistics, Linter, Message,
 };
 
+/// Extensions of the JSON files linted by the JSON plugin.
+pub const LINT_JSON_EXT: &[&str] = &["json", "jsonc"];
+
+pub(crate) fn is_json_path(path: &Path) -> bool {
+    path.extension().and_then(OsStr::to_str).is_some_and(|ext| LINT_JSON_EXT.contains(&ext))
+}
+
 pub struct LintServiceOptions {
     /// Current working directory
-    pub cwd: Box<Path>,
+    cwd: Box<Path>,
 
     /// All paths to lint
-    pub paths: Vec<Box<Path>>,
+    paths: Vec<Box<Path>>,
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
-    pub tsconfig: Option<PathBuf>,
+    tsconfig: Option<PathBuf>,
+
+    /// Keep symlinked paths as-is instead of resolving them to their real location,
+    /// the equivalent of Node.js's `--preserve-symlinks`.
+    preserve_symlinks: bool,
+
+    /// Source texts linted in place of reading the file at their path, e.g. from standard input.
+    source_texts: FxHashMap<Box<Path>, String>,
+}
+
+impl LintServiceOptions {
+    pub fn new(cwd: Box<Path>, paths: Vec<Box<Path>>) -> Self {
+        Self {
+            cwd,
+            paths,
+            tsconfig: None,
+            preserve_symlinks: false,
+            source_texts: FxHashMap::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_tsconfig<P: Into<PathBuf>>(mut self, tsconfig: P) -> Self {
+        self.tsconfig = Some(tsconfig.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_preserve_symlinks(mut self, yes: bool) -> Self {
+        self.preserve_symlinks = yes;
+        self
+    }
+
+    /// Lint `source_text` as the content of `path`, the file does not need to exist.
+    ///
+    /// `path` is still used for resolving imports and the source type.
+    /// Fixes are never written for in-memory source texts.
+    #[must_use]
+    pub fn with_source_text<P: Into<Box<Path>>>(mut self, path: P, source_text: String) -> Self {
+        self.source_texts.insert(path.into(), source_text);
+        self
+    }
+
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
 }
 
 #[derive(Clone)]
@@ -59,13 +113,23 @@ impl LintService {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// Statistics of the files linted by [`Self::run`].
+    ///
+    /// # Panics
+    pub fn statistics(&self) -> LintStatistics {
+        self.runtime.statistics.lock().unwrap().clone()
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
+        let now = Instant::now();
+        self.runtime.process_config(tx_error);
         self.runtime
             .paths
             .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        self.runtime.statistics.lock().unwrap().duration = now.elapsed();
         tx_error.send(None).unwrap();
     }
 
@@ -82,7 +146,7 @@ impl LintService {
             .paths
             .iter()
             .flat_map(|path| {
-                let source_type = SourceType::from_path(path).unwrap();
+                let source_type = SourceType::from_path(path).unwrap_or_default();
                 self.runtime.init_cache_state(path);
                 self.runtime.process_source(
                     path,
@@ -131,12 +195,25 @@ pub struct Runtime {
     resolver: Option<Resolver>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    source_texts: FxHashMap<Box<Path>, String>,
+    statistics: Mutex<LintStatistics>,
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let resolver = linter.options().plugins.import.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            let settings = &linter.settings().import_resolver;
+            let tsconfig = options
+                .tsconfig
+                .or_else(|| settings.typescript.project.as_ref().map(|path| options.cwd.join(path)))
+                .unwrap_or_else(|| options.cwd.join("tsconfig.json"));
+            Self::get_resolver(
+                &options.cwd,
+                Some(tsconfig),
+                options.preserve_symlinks,
+                &settings.node,
+                &settings.typescript,
+            )
         });
         Self {
             cwd: options.cwd,
@@ -145,10 +222,19 @@ impl Runtime {
             resolver,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            source_texts: options.source_texts,
+            statistics: Mutex::default(),
         }
     }
 
-    fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
+    #[cfg_attr(not(feature = "yarn_pnp"), allow(unused_variables))]
+    fn get_resolver(
+        cwd: &Path,
+        tsconfig: Option<PathBuf>,
+        preserve_symlinks: bool,
+        node: &NodeResolverSettings,
+        typescript: &TypeScriptResolverSettings,
+    ) -> Resolver {
         use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
         let tsconfig = tsconfig.and_then(|path| {
             if path.is_file() {
@@ -158,25 +244,78 @@ impl Runtime {
             }
         });
 
+        let extensions = if node.extensions.is_empty() {
+            VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect()
+        } else {
+            node.extensions.clone()
+        };
+        let modules = if node.module_directory.is_empty() {
+            vec!["node_modules".into()]
+        } else {
+            node.module_directory.clone()
+        };
+        let condition_names = if typescript.condition_names.is_empty() {
+            vec!["module".into(), "require".into()]
+        } else {
+            typescript.condition_names.clone()
+        };
+        let main_fields = if typescript.main_fields.is_empty() {
+            vec!["main".into()]
+        } else {
+            typescript.main_fields.clone()
+        };
+        let extension_alias = typescript
+            .extension_alias
+            .iter()
+            .map(|(ext, aliases)| (ext.clone(), aliases.clone()))
+            .collect();
+
         Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            condition_names: vec!["module".into(), "require".into()],
+            extensions,
+            modules,
+            condition_names,
+            extension_alias,
+            main_fields,
             tsconfig,
+            symlinks: !preserve_symlinks,
+            #[cfg(feature = "yarn_pnp")]
+            pnp_manifest: Self::find_pnp_manifest(cwd),
             ..ResolveOptions::default()
         })
     }
 
+    /// Find the yarn Plug'n'Play manifest for the project containing `cwd`.
+    ///
+    /// `.pnp.cjs` is preferred, `.pnp.data.json` is written instead when yarn's `pnpEnableInlining` is `false`.
+    #[cfg(feature = "yarn_pnp")]
+    fn find_pnp_manifest(cwd: &Path) -> Option<pnp::Manifest> {
+        if let Some(path) = pnp::find_closest_pnp_manifest_path(cwd) {
+            return pnp::load_pnp_manifest(path).ok();
+        }
+        let path = cwd.ancestors().map(|dir| dir.join(".pnp.data.json")).find(|p| p.is_file())?;
+        let source_text = fs::read_to_string(&path).ok()?;
+        let mut manifest = serde_json::from_str::<pnp::Manifest>(&source_text).ok()?;
+        pnp::init_pnp_manifest(&mut manifest, path);
+        Some(manifest)
+    }
+
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
         let source_type = SourceType::from_path(path);
-        let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext));
+        let is_json = self.linter.options().plugins.json && LINT_JSON_EXT.contains(&ext);
+        let not_supported_yet = source_type
+            .as_ref()
+            .is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext) && !is_json);
         if not_supported_yet {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
+        if let Some(source_text) = self.source_texts.get(path) {
+            return Some(Ok((source_type, source_text.clone())));
+        }
         let file_result = read_to_string(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {path:?} with error \"{e}\""
@@ -188,17 +327,42 @@ impl Runtime {
         })
     }
 
+    /// Report rules of the configuration file which are configured by a deprecated name, or
+    /// rename them if fixes are enabled.
+    fn process_config(&self, tx_error: &DiagnosticSender) {
+        let config = self.linter.eslint_config();
+        let mut messages = config.deprecated_rules();
+        if messages.is_empty() {
+            return;
+        }
+        let path = config.path();
+        let source_text = config.source_text();
+        if self.linter.options().fix.is_some() {
+            let fix_result = Fixer::new(source_text, messages).fix();
+            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+            messages = fix_result.messages;
+        }
+        if !messages.is_empty() {
+            let errors = messages.into_iter().map(Into::into).collect();
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
         }
 
+        let now = Instant::now();
+
         let Some(ext) = path.extension().and_then(OsStr::to_str) else {
             self.ignore_path(path);
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -212,35 +376,62 @@ impl Runtime {
             }
         };
 
-        let sources = PartialLoader::parse(ext, &source_text);
-        let is_processed_by_partial_loader = sources.is_some();
-        let sources =
-            sources.unwrap_or_else(|| vec![JavaScriptSource::new(&source_text, source_type, 0)]);
+        let sources = PartialLoader::parse(ext, &source_text)
+            .unwrap_or_else(|| vec![JavaScriptSource::new(&source_text, source_type, 0)]);
 
         if sources.is_empty() {
             self.ignore_path(path);
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+        // Messages of all sources are reported and fixed together, with spans in the whole file.
+        let allocators = sources.iter().map(|_| Allocator::default()).collect::<Vec<_>>();
+        let mut messages = vec![];
+        for (source, allocator) in sources.into_iter().zip(&allocators) {
+            let JavaScriptSource { source_text, source_type, start } = source;
+            messages.extend(
+                self.process_source(path, allocator, source_text, source_type, true, tx_error)
+                    .into_iter()
+                    .map(|message| message.with_offset(start)),
+            );
+        }
 
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix.is_some() {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+        let mut fixes = vec![];
+        if self.linter.options().fix.is_some() && !self.source_texts.contains_key(path) {
+            let mut fixed_codes = messages
+                .iter()
+                .filter(|message| message.fix.is_some())
+                .filter_map(LintStatistics::code)
+                .collect::<Vec<_>>();
+            let fix_result = Fixer::new(&source_text, messages).fix();
+            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+            messages = fix_result.messages;
+            // Fixes which overlap with other fixes are not applied
+            for code in messages
+                .iter()
+                .filter(|message| message.fix.is_some())
+                .filter_map(LintStatistics::code)
+            {
+                if let Some(index) = fixed_codes.iter().position(|fixed| *fixed == code) {
+                    fixed_codes.swap_remove(index);
+                }
             }
+            fixes.extend(fixed_codes);
+        }
+        let violations = messages.iter().filter_map(LintStatistics::code).collect::<Vec<_>>();
 
-            if !messages.is_empty() {
-                self.ignore_path(path);
-                let errors = messages.into_iter().map(Into::into).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
-                tx_error.send(Some(diagnostics)).unwrap();
-            }
+        if !messages.is_empty() {
+            self.ignore_path(path);
+            let errors = messages.into_iter().map(Into::into).collect();
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+
+        // Dependencies resolved by the import plugin are not linted
+        if self.paths.contains(path) {
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            self.statistics.lock().unwrap().record_file(path, now.elapsed(), violations, fixes);
         }
     }
 
@@ -254,13 +445,12 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+        let parser = Parser::new(allocator, source_text, source_type).with_options(ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function: true,
+            ..ParseOptions::default()
+        });
+        let ret = if is_json_path(path) { parser.parse_json() } else { parser.parse() };
 
         if !ret.errors.is_empty() {
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -288,7 +478,9 @@ impl Runtime {
             self.update_cache_state(path);
 
             // Retrieve all dependency modules from this module.
-            let dir = path.parent().unwrap();
+            // Resolve from an absolute directory so it can be matched against tsconfig `paths` and project references.
+            let dir = self.cwd.join(path.parent().unwrap());
+            let dir = dir.as_path();
             module_record
                 .requested_modules
                 .keys()
@@ -0,0 +1,101 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::Message;
+
+/// Number of the slowest files kept in [`LintStatistics::slowest_files`].
+const SLOWEST_FILES: usize = 10;
+
+/// Statistics of a [`crate::LintService`] run, for tracking lint debt over time.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintStatistics {
+    /// Number of linted files, dependencies resolved by the import plugin are not counted.
+    pub number_of_files: usize,
+
+    /// Time spent on linting, walking the paths is not included.
+    #[serde(rename = "durationMs", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+
+    /// Statistics of each rule which reported or fixed a diagnostic, keyed by diagnostic code,
+    /// e.g. `eslint(no-debugger)`.
+    pub rules: BTreeMap<String, RuleStatistics>,
+
+    /// The slowest files, slowest first.
+    pub slowest_files: Vec<FileStatistics>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RuleStatistics {
+    /// Number of reported diagnostics, fixed diagnostics are not reported.
+    pub violations: usize,
+
+    /// Number of applied fixes.
+    pub fixes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStatistics {
+    pub path: PathBuf,
+
+    /// Time spent on parsing, linting and fixing the file.
+    #[serde(rename = "durationMs", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+}
+
+impl LintStatistics {
+    /// Serialize the statistics as pretty printed JSON.
+    ///
+    /// # Panics
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn number_of_violations(&self) -> usize {
+        self.rules.values().map(|rule| rule.violations).sum()
+    }
+
+    pub fn number_of_fixes(&self) -> usize {
+        self.rules.values().map(|rule| rule.fixes).sum()
+    }
+
+    /// Record a linted file with the codes of its reported diagnostics and applied fixes.
+    pub(crate) fn record_file(
+        &mut self,
+        path: &Path,
+        duration: Duration,
+        violations: Vec<String>,
+        fixes: Vec<String>,
+    ) {
+        self.number_of_files += 1;
+        for code in violations {
+            self.rules.entry(code).or_default().violations += 1;
+        }
+        for code in fixes {
+            self.rules.entry(code).or_default().fixes += 1;
+        }
+
+        let index = self.slowest_files.partition_point(|file| file.duration >= duration);
+        if index < SLOWEST_FILES {
+            self.slowest_files.insert(index, FileStatistics { path: path.to_path_buf(), duration });
+            self.slowest_files.truncate(SLOWEST_FILES);
+        }
+    }
+
+    /// Diagnostic code of the rule which reported `message`, parse and semantic errors have no
+    /// code.
+    pub(crate) fn code(message: &Message<'_>) -> Option<String> {
+        message.error.code.is_some().then(|| message.error.code.to_string())
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // required by `serialize_with`
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
@@ -10,7 +10,7 @@ use serde_json::Value;
 
 use crate::{
     fixer::FixKind, options::LintPluginOptions, rules::RULES, AllowWarnDeny, Fixer, LintOptions,
-    LintService, LintServiceOptions, Linter, OxlintConfig, RuleEnum, RuleWithSeverity,
+    LintService, LintServiceOptions, Linter, OxlintConfig, RuleEnum, RuleExample, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -255,6 +255,11 @@ impl Tester {
         self
     }
 
+    pub fn with_json_plugin(mut self, yes: bool) -> Self {
+        self.plugins.json = yes;
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -293,6 +298,34 @@ impl Tester {
         self.snapshot();
     }
 
+    /// Lint the documentation examples of the rule, see [`RuleExample`].
+    ///
+    /// Returns the examples which are not reported as expected.
+    pub fn test_examples(&mut self, examples: &[RuleExample]) -> Vec<RuleExample> {
+        let rule_path = self.rule_path.clone();
+        let failures = examples
+            .iter()
+            .filter(|example| {
+                let extension = if self.plugins.jest || self.plugins.vitest {
+                    format!("test.{}", example.lang)
+                } else {
+                    example.lang.to_string()
+                };
+                self.rule_path = if self.plugins.import {
+                    // The import plugin resolves modules relative to the fixtures
+                    self.current_working_directory.join("index").with_extension(extension)
+                } else {
+                    rule_path.with_extension(extension)
+                };
+                let result = self.run(example.code, None, &None, None, ExpectFixKind::None);
+                (result == TestResult::Failed) != example.incorrect
+            })
+            .copied()
+            .collect();
+        self.rule_path = rule_path;
+        failures
+    }
+
     fn snapshot(&self) {
         let name = self.rule_name.replace('-', "_");
         let mut settings = insta::Settings::clone_current();
@@ -357,7 +390,8 @@ impl Tester {
             .with_jsx_a11y_plugin(self.plugins.jsx_a11y)
             .with_nextjs_plugin(self.plugins.nextjs)
             .with_react_perf_plugin(self.plugins.react_perf)
-            .with_node_plugin(self.plugins.node);
+            .with_node_plugin(self.plugins.node)
+            .with_json_plugin(self.plugins.json);
         let eslint_config = eslint_config
             .as_ref()
             .map_or_else(OxlintConfig::default, |v| OxlintConfig::deserialize(v).unwrap());
@@ -378,7 +412,7 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions::new(cwd, paths);
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
@@ -393,12 +427,11 @@ impl Tester {
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
 
-        let diagnostic_path = if self.plugins.import {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
+        let diagnostic_path = self
+            .rule_path
+            .strip_prefix(&self.current_working_directory)
+            .unwrap_or(&self.rule_path)
+            .to_string_lossy();
 
         let handler = GraphicalReportHandler::new()
             .with_links(false)
@@ -211,7 +211,7 @@ fn collect_ids_referenced_to_import<'a>(
                 };
                 let name = ctx.symbols().get_name(symbol_id);
 
-                if matches!(import_decl.source.value.as_str(), "@jest/globals" | "vitest") {
+                if ctx.settings().jest.is_global_package(import_decl.source.value.as_str()) {
                     let original = find_original_name(import_decl, name);
                     let mut ret = vec![];
                     for reference_id in reference_ids {
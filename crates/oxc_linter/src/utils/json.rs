@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use oxc_ast::{
+    ast::{
+        Expression, ObjectExpression, ObjectProperty, ObjectPropertyKind, PropertyKey, Statement,
+    },
+    AstKind,
+};
+
+use crate::LintContext;
+
+/// The value of a JSON file, which is the expression of the only statement of the program
+/// parsed by `Parser::parse_json`.
+pub fn json_value<'a>(ctx: &LintContext<'a>) -> Option<&'a Expression<'a>> {
+    let AstKind::Program(program) = ctx.nodes().root_node()?.kind() else { return None };
+    match program.body.first()? {
+        Statement::ExpressionStatement(statement) => Some(&statement.expression),
+        _ => None,
+    }
+}
+
+/// The properties of a JSON object with their keys, including duplicate keys.
+pub fn json_properties<'a>(
+    object: &'a ObjectExpression<'a>,
+) -> impl Iterator<Item = (&'a str, &'a ObjectProperty<'a>)> {
+    object.properties.iter().filter_map(|property| {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+        let PropertyKey::StringLiteral(key) = &property.key else { return None };
+        Some((key.value.as_str(), &**property))
+    })
+}
+
+pub fn is_package_json(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "package.json")
+}
+
+/// `tsconfig.json`, `jsconfig.json` and variants such as `tsconfig.build.json`.
+pub fn is_tsconfig_json(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json")
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("tsconfig") || name.starts_with("jsconfig"))
+}
@@ -2,6 +2,7 @@ mod config;
 mod express;
 mod jest;
 mod jsdoc;
+mod json;
 mod nextjs;
 mod promise;
 mod react;
@@ -13,8 +14,8 @@ mod vitest;
 use std::{io, path::Path};
 
 pub use self::{
-    config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*, react_perf::*,
-    tree_shaking::*, unicorn::*, vitest::*,
+    config::*, express::*, jest::*, jsdoc::*, json::*, nextjs::*, promise::*, react::*,
+    react_perf::*, tree_shaking::*, unicorn::*, vitest::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
@@ -9,7 +9,10 @@ use oxc_ast::{
 };
 use oxc_semantic::{AstNode, SymbolFlags};
 
-use crate::{LintContext, OxlintSettings};
+use crate::{
+    globals::{INTERACTIVE_ARIA_ROLES, NON_INTERACTIVE_HTML_ELEMENTS, VALID_ARIA_ROLES},
+    LintContext, OxlintSettings,
+};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     match &call_expr.callee {
@@ -143,6 +146,33 @@ pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningEle
     }
 }
 
+/// The first valid ARIA role in the `role` attribute, e.g. `button` for `role="foo button"`.
+pub fn get_explicit_role<'a>(jsx_opening_el: &'a JSXOpeningElement<'_>) -> Option<&'a str> {
+    let role = has_jsx_prop_ignore_case(jsx_opening_el, "role")?;
+    get_string_literal_prop_value(role)?
+        .split_whitespace()
+        .find(|role| VALID_ARIA_ROLES.contains(role))
+}
+
+// ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isInteractiveRole.js
+pub fn is_interactive_role(jsx_opening_el: &JSXOpeningElement) -> bool {
+    get_explicit_role(jsx_opening_el).is_some_and(|role| INTERACTIVE_ARIA_ROLES.contains(role))
+}
+
+// ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isNonInteractiveRole.js
+pub fn is_non_interactive_role(jsx_opening_el: &JSXOpeningElement) -> bool {
+    get_explicit_role(jsx_opening_el).is_some_and(|role| {
+        !INTERACTIVE_ARIA_ROLES.contains(role)
+            && !matches!(role, "presentation" | "none" | "generic")
+    })
+}
+
+// ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isNonInteractiveElement.js
+pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    NON_INTERACTIVE_HTML_ELEMENTS.contains(element_type)
+        && !is_interactive_element(element_type, jsx_opening_el)
+}
+
 const PRAGMA: &str = "React";
 const CREATE_CLASS: &str = "createReactClass";
 
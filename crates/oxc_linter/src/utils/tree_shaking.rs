@@ -203,7 +203,7 @@ fn flatten_member_expr_if_possible(function_name: &FunctionName) -> CompactStr {
         FunctionName::StaticMemberExpr(static_member_expr) => {
             let Some(parent_name) = FunctionName::from_expression(&static_member_expr.object)
             else {
-                return CompactStr::from("");
+                return CompactStr::default();
             };
             let flattened_parent = flatten_member_expr_if_possible(&parent_name);
             CompactStr::from(format!("{}.{}", flattened_parent, static_member_expr.property.name))
@@ -1,4 +1,4 @@
-use oxc_linter::{RuleCategory, RuleMeta};
+use oxc_linter::{FixKind, RuleCategory, RuleExample, RuleFixMeta, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -21,6 +21,27 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// debugger;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// let debug: boolean;
+    /// ```
+    ///
+    /// ```js ignore
+    /// debugger;
+    /// ```
+    TestRule3,
+    correctness,
+    fix = "suggestion"
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +52,17 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Fix kind given as a string
+    assert_eq!(TestRule3::FIX, RuleFixMeta::Fixable(FixKind::Suggestion));
+
+    // Examples are collected from documentation
+    assert!(TestRule::EXAMPLES.is_empty());
+    assert_eq!(
+        TestRule3::EXAMPLES,
+        &[
+            RuleExample { incorrect: true, lang: "js", code: "debugger;" },
+            RuleExample { incorrect: false, lang: "ts", code: "let debug: boolean;" },
+        ]
+    );
 }
@@ -11,7 +11,7 @@ pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
     /// Describes what auto-fixing capabilities the rule has
-    fix: Option<Ident>,
+    fix: Option<String>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -38,9 +38,19 @@ impl Parse for LintRuleMeta {
         // Parse FixMeta if it's specified. It will otherwise be excluded from
         // the RuleMeta impl, falling back on default set by RuleMeta itself.
         // Do not provide a default value here so that it can be set there instead.
-        let fix: Option<Ident> = if input.peek(Token!(,)) {
+        // Both `fix` and `fix = "fix"` are accepted.
+        let fix: Option<String> = if input.peek(Token!(,)) {
             input.parse::<Token!(,)>()?;
-            input.parse()?
+            if input.peek(Ident) && input.peek2(Token!(=)) {
+                let key = input.parse::<Ident>()?;
+                if key != "fix" {
+                    return Err(Error::new_spanned(key, "expected `fix = \"...\"`"));
+                }
+                input.parse::<Token!(=)>()?;
+                Some(input.parse::<LitStr>()?.value())
+            } else {
+                input.parse::<Option<Ident>>()?.as_ref().map(Ident::to_string)
+            }
         } else {
             None
         };
@@ -70,17 +80,29 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
-    let fix = fix.as_ref().map(Ident::to_string).map(|fix| {
+    let fix = fix.map(|fix| {
         let fix = parse_fix(&fix);
         quote! {
             const FIX: RuleFixMeta = #fix;
         }
     });
 
+    let examples = parse_examples(&documentation);
+    let examples = (!examples.is_empty()).then(|| {
+        let examples = examples.iter().map(|Example { incorrect, lang, code }| {
+            quote! { RuleExample { incorrect: #incorrect, lang: #lang, code: #code } }
+        });
+        quote! {
+            const EXAMPLES: &'static [RuleExample] = &[#(#examples),*];
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
-        Some(quote! { use crate::{rule::{RuleCategory, RuleMeta, RuleFixMeta}, fixer::FixKind}; })
+        Some(quote! {
+            use crate::{rule::{RuleCategory, RuleExample, RuleMeta, RuleFixMeta}, fixer::FixKind};
+        })
     };
 
     let output = quote! {
@@ -93,6 +115,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #examples
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -177,3 +201,51 @@ fn parse_fix_kind(s: &str) -> proc_macro2::TokenStream {
         _ => panic!("invalid fix kind: {s}. Valid fix kinds are fix, suggestion, or dangerous."),
     }
 }
+
+struct Example {
+    incorrect: bool,
+    lang: String,
+    code: String,
+}
+
+/// Languages of code blocks which are validated against the rule.
+const EXAMPLE_LANGS: [&str; 6] = ["js", "javascript", "jsx", "ts", "typescript", "tsx"];
+
+/// Collect the code blocks following "Examples of **incorrect** code" and
+/// "Examples of **correct** code" paragraphs in the documentation.
+///
+/// Like doctests, code blocks can opt out of validation with `ignore`, e.g. ` ```js ignore`.
+fn parse_examples(documentation: &str) -> Vec<Example> {
+    let mut examples = vec![];
+    let mut incorrect = None;
+    let mut lines = documentation.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        let lowercase = line.to_lowercase();
+        if line.starts_with('#') || lowercase.contains("option") || lowercase.contains("configured")
+        {
+            // Examples for non-default options can't be validated.
+            incorrect = None;
+        } else if line.contains("**incorrect**") {
+            incorrect = Some(true);
+        } else if line.contains("**correct**") {
+            incorrect = Some(false);
+        } else if let Some(info) = line.strip_prefix("```") {
+            let mut info = info.split([' ', ',']).filter(|s| !s.is_empty());
+            let lang = info.next().unwrap_or_default().to_string();
+            let ignore = info.any(|attr| attr == "ignore");
+            let code = lines.by_ref().take_while(|line| line.trim() != "```").join("\n");
+            if let Some(incorrect) = incorrect {
+                if !ignore && EXAMPLE_LANGS.contains(&lang.as_str()) {
+                    let lang = match lang.as_str() {
+                        "javascript" => "js".to_string(),
+                        "typescript" => "ts".to_string(),
+                        _ => lang,
+                    };
+                    examples.push(Example { incorrect, lang, code });
+                }
+            }
+        }
+    }
+    examples
+}
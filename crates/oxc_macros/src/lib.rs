@@ -1,8 +1,9 @@
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use syn::{parse_macro_input, DeriveInput};
 
 mod declare_all_lint_rules;
 mod declare_oxc_lint;
+mod rule_config;
 
 /// Macro used to declare an oxc lint rule
 ///
@@ -19,6 +20,13 @@ mod declare_oxc_lint;
 /// concise. Remember, end users will depend on it to understand the purpose of
 /// the lint and how to use it!
 ///
+/// Code blocks following a line with `**incorrect**` or `**correct**` are
+/// collected into `RuleMeta::EXAMPLES`, and are tested to be reported or not
+/// reported by the rule with its default configuration. Examples after a heading
+/// or a line mentioning options are not tested. Add `ignore` to the code block's
+/// info string (e.g. ` ```js ignore`) to skip an example which can't be checked on
+/// its own.
+///
 /// ## Category
 /// Please see the [rule category
 /// documentation](https://oxc.rs/docs/contribute/linter.html#rule-category) for
@@ -45,6 +53,9 @@ mod declare_oxc_lint;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// The fix kind can also be given as a string, e.g. `fix = "suggestion"`.
+///
 /// # Example
 ///
 /// ```
@@ -101,3 +112,33 @@ pub fn declare_all_lint_rules(input: TokenStream) -> TokenStream {
     let metadata = parse_macro_input!(input as declare_all_lint_rules::AllLintRulesMeta);
     declare_all_lint_rules::declare_all_lint_rules(metadata)
 }
+
+/// Derive `RuleConfig` and `JsonSchema` for the options of a lint rule.
+///
+/// Options are read from the first element of the rule's configuration, e.g.
+/// `{ "allowEmptyCatch": true }` in `"no-empty": ["error", { "allowEmptyCatch": true }]`.
+/// Each field is read from its `camelCase` key, and falls back to the field's value in the
+/// struct's `Default` impl when it is missing or invalid.
+///
+/// ## Field attributes
+/// - `#[rule_config(rename = "key")]`: read the field from `key` instead.
+/// - `#[rule_config(alias = "key")]`: also read the field from `key`, can be repeated.
+/// - `#[rule_config(shorthand)]`: set the field when the configuration is a single value instead
+///   of an object, e.g. `"max-params": ["error", 3]`.
+///
+/// Doc comments on fields are used as descriptions in the schema.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Debug, Default, Clone, RuleConfig)]
+/// pub struct NoEmpty {
+///     /// Allow empty `catch` clauses.
+///     allow_empty_catch: bool,
+/// }
+/// ```
+#[proc_macro_derive(RuleConfig, attributes(rule_config))]
+pub fn derive_rule_config(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    rule_config::derive_rule_config(&input)
+}
@@ -0,0 +1,164 @@
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Expr, Fields, Ident, Lit, LitStr, Meta, Result, Type};
+
+struct ConfigField {
+    ident: Ident,
+    ty: Type,
+    /// Key in the json configuration, `camelCase` field name unless renamed.
+    key: String,
+    aliases: Vec<String>,
+    /// Field which is set when the configuration is a single value instead of an object,
+    /// e.g. `"max-params": ["error", 3]`.
+    shorthand: bool,
+    documentation: Option<String>,
+}
+
+pub fn derive_rule_config(input: &DeriveInput) -> TokenStream {
+    match rule_config(input) {
+        Ok(output) => output.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn rule_config(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = parse_fields(input)?;
+
+    if fields.iter().filter(|field| field.shorthand).count() > 1 {
+        return Err(Error::new_spanned(name, "only one field can be `#[rule_config(shorthand)]`"));
+    }
+
+    let field_values = fields.iter().map(|field| {
+        let ConfigField { ident, key, aliases, .. } = field;
+        quote! {
+            #ident: [#key, #(#aliases),*]
+                .into_iter()
+                .find_map(|key| object.and_then(|object| object.get(key)))
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
+                .unwrap_or(default.#ident),
+        }
+    });
+
+    let shorthand = fields.iter().find(|field| field.shorthand).map(|field| {
+        let ident = &field.ident;
+        let rest = (fields.len() > 1).then(|| quote!(..default));
+        quote! {
+            if let Some(#ident) = config
+                .filter(|config| !config.is_object())
+                .and_then(|config| serde_json::from_value(config.clone()).ok())
+            {
+                return Self { #ident, #rest };
+            }
+        }
+    });
+
+    let properties = fields.iter().map(|field| {
+        let ConfigField { ty, key, documentation, .. } = field;
+        let description = documentation.as_ref().map(|documentation| {
+            quote! { schema.metadata().description = Some(#documentation.to_string()); }
+        });
+        quote! {
+            {
+                let mut schema = gen.subschema_for::<#ty>().into_object();
+                #description
+                object.properties.insert(#key.to_string(), schema.into());
+            }
+        }
+    });
+
+    let schema_name = name.to_string();
+
+    Ok(quote! {
+        impl crate::rule::RuleConfig for #name {
+            fn from_configuration(value: serde_json::Value) -> Self {
+                let default = Self::default();
+                let config = value.get(0);
+                #shorthand
+                let object = config.and_then(serde_json::Value::as_object);
+                Self { #(#field_values)* }
+            }
+        }
+
+        impl schemars::JsonSchema for #name {
+            fn schema_name() -> String {
+                #schema_name.to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                let mut schema = schemars::schema::SchemaObject {
+                    instance_type: Some(schemars::schema::InstanceType::Object.into()),
+                    ..Default::default()
+                };
+                let object = schema.object();
+                #(#properties)*
+                schema.into()
+            }
+        }
+    })
+}
+
+fn parse_fields(input: &DeriveInput) -> Result<Vec<ConfigField>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`RuleConfig` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`RuleConfig` can only be derived for structs with named fields",
+        ));
+    };
+
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let mut config_field = ConfigField {
+                key: ident.to_string().to_case(Case::Camel),
+                ident,
+                ty: field.ty.clone(),
+                aliases: vec![],
+                shorthand: false,
+                documentation: None,
+            };
+            for attr in &field.attrs {
+                if attr.path().is_ident("rule_config") {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("rename") {
+                            config_field.key = meta.value()?.parse::<LitStr>()?.value();
+                        } else if meta.path.is_ident("alias") {
+                            config_field.aliases.push(meta.value()?.parse::<LitStr>()?.value());
+                        } else if meta.path.is_ident("shorthand") {
+                            config_field.shorthand = true;
+                        } else {
+                            return Err(meta.error("unsupported `rule_config` attribute"));
+                        }
+                        Ok(())
+                    })?;
+                } else if let Some(line) = parse_doc(&attr.meta) {
+                    let documentation = config_field.documentation.get_or_insert_with(String::new);
+                    if !documentation.is_empty() {
+                        documentation.push('\n');
+                    }
+                    documentation.push_str(line.trim());
+                }
+            }
+            Ok(config_field)
+        })
+        .collect()
+}
+
+fn parse_doc(meta: &Meta) -> Option<String> {
+    let Meta::NameValue(name_value) = meta else { return None };
+    if !name_value.path.is_ident("doc") {
+        return None;
+    }
+    let Expr::Lit(expr) = &name_value.value else { return None };
+    let Lit::Str(lit) = &expr.lit else { return None };
+    Some(lit.value())
+}
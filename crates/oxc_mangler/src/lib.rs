@@ -1,14 +1,30 @@
 use itertools::Itertools;
 use oxc_ast::ast::Program;
 use oxc_index::{index_vec, Idx, IndexVec};
-use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{ReferenceId, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
 use oxc_span::CompactStr;
+use oxc_syntax::{es_version::ESVersion, keyword::is_reserved_word};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 type Slot = usize;
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone)]
 pub struct MangleOptions {
     pub debug: bool,
+
+    /// Previously assigned names for top level bindings, keyed by their original name.
+    ///
+    /// Top level bindings found in the cache keep their cached mangled name, and cached names
+    /// are never handed out to other bindings. This keeps names stable across separately
+    /// minified files that share a global scope. See [Mangler::name_cache].
+    pub name_cache: FxHashMap<CompactStr, CompactStr>,
+
+    /// Rename bindings even if they can be accessed by their names, i.e. bindings of scopes
+    /// containing a direct `eval` or a `with` statement, and top level bindings of scripts
+    /// calling `new Function` or an indirect `eval`.
+    ///
+    /// Only use this if the dynamically evaluated code does not access these bindings.
+    pub force: bool,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -57,6 +73,8 @@ pub struct Mangler {
     symbol_table: SymbolTable,
 
     options: MangleOptions,
+
+    name_cache: FxHashMap<CompactStr, CompactStr>,
 }
 
 impl Mangler {
@@ -75,6 +93,12 @@ impl Mangler {
         self.symbol_table.get_name(symbol_id)
     }
 
+    /// Mangled names of top level bindings keyed by their original name, merged with
+    /// [MangleOptions::name_cache]. Pass it to the next [Mangler] to reuse the names.
+    pub fn name_cache(&self) -> &FxHashMap<CompactStr, CompactStr> {
+        &self.name_cache
+    }
+
     pub fn get_reference_name(&self, reference_id: ReferenceId) -> Option<&str> {
         let symbol_id = self.symbol_table.get_reference(reference_id).symbol_id()?;
         Some(self.symbol_table.get_name(symbol_id))
@@ -88,6 +112,27 @@ impl Mangler {
         // A slot is the occurrence index of a binding identifier inside a scope.
         let (mut symbol_table, scope_tree) = semantic.into_symbol_table_and_scope_tree();
 
+        let cached_names: FxHashSet<&CompactStr> = self.options.name_cache.values().collect();
+
+        // Bindings of scopes containing a direct `eval` or a `with` statement, and top level
+        // bindings of scripts evaluating global code, can be accessed by their names, so they
+        // are not renamed and no other binding may take their names (unless forced).
+        // The same goes for imports and exports, which are not renamed either.
+        let unmangleable = if self.options.force {
+            index_vec![false; symbol_table.len()]
+        } else {
+            unmangleable_symbols(program, &symbol_table, &scope_tree)
+        };
+        let unmangled_names: FxHashSet<CompactStr> = symbol_table
+            .names
+            .iter_enumerated()
+            .filter(|(symbol_id, _)| {
+                let symbol_flags = symbol_table.get_flags(*symbol_id);
+                !symbol_flags.is_variable() || symbol_flags.is_export() || unmangleable[*symbol_id]
+            })
+            .map(|(_, name)| name.clone())
+            .collect();
+
         // Total number of slots for all scopes
         let mut total_number_of_slots: Slot = 0;
 
@@ -123,8 +168,12 @@ impl Mangler {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots);
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            &unmangleable,
+            total_number_of_slots,
+            &slots,
+        );
 
         let mut names = Vec::with_capacity(total_number_of_slots);
 
@@ -134,8 +183,12 @@ impl Mangler {
             names.push(loop {
                 let name = generate_name(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
+                // Do not mangle keywords, unresolved references and builtin globals, which code
+                // outside of the program (or added by later passes) may reference, e.g. `NaN`.
                 if !is_keyword(&name)
+                    && !oxc_globals::is_builtin(&name)
+                    && !cached_names.contains(&name)
+                    && !unmangled_names.contains(&name)
                     && !scope_tree.root_unresolved_references().contains_key(name.as_str())
                 {
                     break name;
@@ -187,12 +240,29 @@ impl Mangler {
             }
         }
 
+        // Top level bindings are the only occupants of their slots,
+        // so they can be renamed to their cached names without conflicts.
+        let mut name_cache = self.options.name_cache.clone();
+        for (name, symbol_id) in scope_tree.get_bindings(scope_tree.root_scope_id()) {
+            let symbol_flags = symbol_table.get_flags(*symbol_id);
+            if !symbol_flags.is_variable() || symbol_flags.is_export() || unmangleable[*symbol_id] {
+                continue;
+            }
+            if let Some(cached_name) = self.options.name_cache.get(name) {
+                symbol_table.set_name(*symbol_id, cached_name.clone());
+            } else {
+                name_cache.insert(name.clone(), symbol_table.get_name(*symbol_id).into());
+            }
+        }
+
         self.symbol_table = symbol_table;
+        self.name_cache = name_cache;
         self
     }
 
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
+        unmangleable: &IndexVec<SymbolId, bool>,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
     ) -> Vec<SlotFrequency> {
@@ -203,6 +273,10 @@ impl Mangler {
             if !symbol_flags.is_variable() || symbol_flags.is_export() {
                 continue;
             }
+            // omit renaming bindings which can be accessed by `eval` or `with`
+            if unmangleable[symbol_id] {
+                continue;
+            }
             let index = *slot;
             frequencies[index].slot = *slot;
             frequencies[index].frequency +=
@@ -214,6 +288,31 @@ impl Mangler {
     }
 }
 
+/// Symbols which can be accessed by their names, because they are declared in a scope
+/// containing a direct `eval` or a `with` statement.
+///
+/// Code evaluated by `new Function(code)` or an indirect `eval` runs in the global scope,
+/// which includes the top level bindings of scripts, but not those of modules.
+fn unmangleable_symbols(
+    program: &Program,
+    symbol_table: &SymbolTable,
+    scope_tree: &ScopeTree,
+) -> IndexVec<SymbolId, bool> {
+    let root_scope_id = scope_tree.root_scope_id();
+    let is_global_code_evaluated = !program.source_type.is_module()
+        && ["Function", "eval"]
+            .iter()
+            .any(|name| scope_tree.root_unresolved_references().contains_key(*name));
+    symbol_table
+        .scope_ids
+        .iter()
+        .map(|scope_id| {
+            scope_tree.get_flags(*scope_id).is_poisoned()
+                || (is_global_code_evaluated && *scope_id == root_scope_id)
+        })
+        .collect()
+}
+
 #[derive(Debug, Default, Clone)]
 struct SlotFrequency {
     pub slot: Slot,
@@ -223,17 +322,16 @@ struct SlotFrequency {
 
 #[rustfmt::skip]
 fn is_keyword(s: &str) -> bool {
-    matches!(s, "as" | "do" | "if" | "in" | "is" | "of" | "any" | "for" | "get"
-            | "let" | "new" | "out" | "set" | "try" | "var" | "case" | "else"
-            | "enum" | "from" | "meta" | "null" | "this" | "true" | "type"
-            | "void" | "with")
+    is_reserved_word(s, ESVersion::ESNext, true)
+        || matches!(s, "as" | "is" | "of" | "any" | "get" | "out" | "set" | "from" | "meta"
+            | "type" | "await")
 }
 
 const BASE54_CHARS: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
 
 /// Get the shortest mangled name for a given n.
 /// Code adapted from [terser](https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1041-L1051)
-fn base54(n: usize) -> CompactStr {
+pub fn base54(n: usize) -> CompactStr {
     let mut num = n;
     // Base 54 at first because these are the usable first characters in JavaScript identifiers
     // <https://tc39.es/ecma262/#prod-IdentifierStart>
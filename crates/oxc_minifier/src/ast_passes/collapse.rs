@@ -1,22 +1,44 @@
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
+use oxc_semantic::{Reference, SymbolId};
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::{CompressOptions, CompressorPass};
+use crate::{node_util::MayHaveSideEffects, CompressOptions, CompressorPass};
 
 /// Collapse variable declarations (TODO: and assignments).
 ///
 /// `var a; var b = 1; var c = 2` => `var a, b = 1; c = 2`
+/// `var a = foo(); return a.b` => `return foo().b`
 /// TODO: `a = null; b = null;` => `a = b = null`
 pub struct Collapse<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
+
+    /// Top level bindings of scripts are visible to other scripts, so they are never collapsed.
+    is_module: bool,
+}
+
+/// Where the use of a variable is evaluated in an expression, see [`Collapse::find_use`].
+enum Use<'b, 'a> {
+    /// The reference, evaluated before anything the initializer of the variable can affect.
+    Found(&'b mut Expression<'a>),
+    /// The expression does not use the variable, and is not affected by its initializer.
+    Pure,
+    /// The variable can not be collapsed into the expression.
+    Abort,
 }
 
 impl<'a> CompressorPass<'a> for Collapse<'a> {}
 
 impl<'a> Traverse<'a> for Collapse<'a> {
-    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+    }
+
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.collapse_vars {
+            self.collapse_vars(stmts, ctx);
+        }
         if self.options.join_vars {
             self.join_vars(stmts);
         }
@@ -25,7 +47,330 @@ impl<'a> Traverse<'a> for Collapse<'a> {
 
 impl<'a> Collapse<'a> {
     pub fn new(ast: AstBuilder<'a>, options: CompressOptions) -> Self {
-        Self { ast, options }
+        Self { ast, options, is_module: false }
+    }
+
+    /// Collapse variables which are only used once into their use in the next statement,
+    /// e.g. `var a = foo(); return a.b` => `return foo().b`.
+    ///
+    /// The use must be evaluated before anything which can observe or change the effects of the
+    /// initializer, which is moved past the evaluation of literals and variables which are never
+    /// reassigned. Like `pure_getters` of terser, reading properties is assumed to have no side
+    /// effects, but when the initializer has side effects, it is not moved past reads of
+    /// properties or globals, which it may change.
+    fn collapse_vars(&self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        // Statements are only removed before `i`, so the next pair is `i - 2` and the statement
+        // the variables were collapsed into.
+        for i in (1..stmts.len()).rev() {
+            while self.collapse_var(stmts, i, ctx) {}
+            if matches!(&stmts[i - 1], Statement::VariableDeclaration(decl) if decl.declarations.is_empty())
+            {
+                stmts.remove(i - 1);
+            }
+        }
+    }
+
+    /// Collapse the last declarator of `stmts[i - 1]` into `stmts[i]`.
+    fn collapse_var(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        i: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        let (prev, next) = stmts.split_at_mut(i);
+        let Statement::VariableDeclaration(decl) = &mut prev[i - 1] else { return false };
+        if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+        {
+            return false;
+        }
+        let Some(declarator) = decl.declarations.last() else { return false };
+        let (BindingPatternKind::BindingIdentifier(ident), Some(init)) =
+            (&declarator.id.kind, &declarator.init)
+        else {
+            return false;
+        };
+        let Some(symbol_id) = ident.symbol_id.get() else { return false };
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        if !self.is_module && scope_id == ctx.scopes().root_scope_id() {
+            return false;
+        }
+        // Bindings may be accessed by a direct `eval` or shadowed by a `with` statement
+        if ctx.scopes().get_flags(scope_id).is_poisoned() {
+            return false;
+        }
+        // Parameters may be accessed through `arguments`, `function f(a) { var a = b(); return a + arguments[0] }`
+        let function_flags = ctx
+            .scopes()
+            .ancestors(scope_id)
+            .map(|scope_id| ctx.scopes().get_flags(scope_id))
+            .find(|flags| flags.is_function() && !flags.is_arrow());
+        if function_flags.is_some_and(|flags| flags.has_mapped_arguments()) {
+            return false;
+        }
+        let &[reference_id] = ctx.symbols().get_resolved_reference_ids(symbol_id).as_slice() else {
+            return false;
+        };
+        if ctx.symbols().get_reference(reference_id).is_write() {
+            return false;
+        }
+        // `var f = a.b; f()` calls `f` without `this`
+        let is_member = init.without_parentheses().is_member_expression();
+        let side_effects = init.may_have_side_effects();
+        let Use::Found(expr) =
+            Self::find_use_in_statement(&mut next[0], symbol_id, is_member, side_effects, ctx)
+        else {
+            return false;
+        };
+
+        *expr = decl.declarations.pop().unwrap().init.unwrap();
+        ctx.symbols_mut().delete_resolved_reference(symbol_id, reference_id);
+        true
+    }
+
+    fn find_use_in_statement<'b>(
+        stmt: &'b mut Statement<'a>,
+        symbol_id: SymbolId,
+        is_member: bool,
+        side_effects: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Use<'b, 'a> {
+        let find = |expr| Self::find_use(expr, symbol_id, is_member, side_effects, ctx);
+        match stmt {
+            Statement::ExpressionStatement(stmt) => find(&mut stmt.expression),
+            Statement::ReturnStatement(stmt) => stmt.argument.as_mut().map_or(Use::Abort, find),
+            Statement::ThrowStatement(stmt) => find(&mut stmt.argument),
+            Statement::IfStatement(stmt) => find(&mut stmt.test),
+            Statement::SwitchStatement(stmt) => find(&mut stmt.discriminant),
+            Statement::VariableDeclaration(decl)
+                if !matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                ) =>
+            {
+                for declarator in decl.declarations.iter_mut() {
+                    if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+                        return Use::Abort;
+                    }
+                    match declarator.init.as_mut().map_or(Use::Pure, find) {
+                        Use::Pure => {}
+                        found_or_abort => return found_or_abort,
+                    }
+                }
+                Use::Abort
+            }
+            _ => Use::Abort,
+        }
+    }
+
+    /// Find the use of `symbol_id` in `expr`, following the order of evaluation.
+    ///
+    /// `side_effects` is whether the initializer of the variable may have side effects, which
+    /// reads of properties and globals can observe.
+    fn find_use<'b>(
+        expr: &'b mut Expression<'a>,
+        symbol_id: SymbolId,
+        is_member: bool,
+        side_effects: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Use<'b, 'a> {
+        let find = |expr| Self::find_use(expr, symbol_id, is_member, side_effects, ctx);
+        // Evaluate `exprs` in order, which has side effects after all of them are evaluated.
+        let find_all = |exprs: &mut dyn Iterator<Item = &'b mut Expression<'a>>, pure: bool| {
+            for expr in exprs {
+                match Self::find_use(expr, symbol_id, is_member, side_effects, ctx) {
+                    Use::Pure => {}
+                    found_or_abort => return found_or_abort,
+                }
+            }
+            if pure {
+                Use::Pure
+            } else {
+                Use::Abort
+            }
+        };
+
+        // Reads the property after evaluating the object.
+        let read = |object: Use<'b, 'a>| match object {
+            Use::Pure if side_effects => Use::Abort,
+            object => object,
+        };
+
+        if let Expression::Identifier(ident) = &*expr {
+            let resolved = ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+            return match resolved {
+                Some(id) if id == symbol_id => Use::Found(expr),
+                Some(id) if ctx.symbols().get_resolved_references(id).any(Reference::is_write) => {
+                    Use::Abort
+                }
+                // Globals may be reassigned by the initializer.
+                None if side_effects => Use::Abort,
+                _ => Use::Pure,
+            };
+        }
+
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::ThisExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => Use::Pure,
+            Expression::TemplateLiteral(lit) => find_all(&mut lit.expressions.iter_mut(), true),
+            Expression::ParenthesizedExpression(paren) => find(&mut paren.expression),
+            Expression::SequenceExpression(seq) => find_all(&mut seq.expressions.iter_mut(), true),
+            Expression::StaticMemberExpression(member) => read(find(&mut member.object)),
+            Expression::ComputedMemberExpression(member) => {
+                let member = &mut **member;
+                read(find_all(&mut [&mut member.object, &mut member.expression].into_iter(), true))
+            }
+            Expression::PrivateFieldExpression(member) => read(find(&mut member.object)),
+            Expression::CallExpression(call) => {
+                let call = &mut **call;
+                if is_member && Self::is_reference_to(&call.callee, symbol_id, ctx) {
+                    return Use::Abort;
+                }
+                // Arguments of optional calls are conditionally evaluated.
+                let optional = call.optional;
+                let arguments =
+                    Self::argument_expressions(&mut call.arguments).filter(|_| !optional);
+                match arguments {
+                    Some(arguments) => {
+                        find_all(&mut std::iter::once(&mut call.callee).chain(arguments), false)
+                    }
+                    None => Self::only_found(find(&mut call.callee)),
+                }
+            }
+            Expression::NewExpression(new) => {
+                let new = &mut **new;
+                match Self::argument_expressions(&mut new.arguments) {
+                    Some(arguments) => {
+                        find_all(&mut std::iter::once(&mut new.callee).chain(arguments), false)
+                    }
+                    None => Self::only_found(find(&mut new.callee)),
+                }
+            }
+            Expression::UnaryExpression(unary) if unary.operator != UnaryOperator::Delete => {
+                find(&mut unary.argument)
+            }
+            Expression::BinaryExpression(binary) => {
+                let binary = &mut **binary;
+                let pure =
+                    !matches!(binary.operator, BinaryOperator::In | BinaryOperator::Instanceof);
+                find_all(&mut [&mut binary.left, &mut binary.right].into_iter(), pure)
+            }
+            // The right side, consequent and alternate are conditionally evaluated.
+            Expression::LogicalExpression(logical) => {
+                let logical = &mut **logical;
+                match find(&mut logical.left) {
+                    Use::Pure => Self::only_pure(&find(&mut logical.right)),
+                    found_or_abort => found_or_abort,
+                }
+            }
+            Expression::ConditionalExpression(cond) => {
+                let cond = &mut **cond;
+                match find(&mut cond.test) {
+                    Use::Pure => match Self::only_pure(&find(&mut cond.consequent)) {
+                        Use::Pure => Self::only_pure(&find(&mut cond.alternate)),
+                        _ => Use::Abort,
+                    },
+                    found_or_abort => found_or_abort,
+                }
+            }
+            Expression::AssignmentExpression(assign) => {
+                let assign = &mut **assign;
+                let right = match &mut assign.left {
+                    // The variable is read before the right side by compound assignments.
+                    AssignmentTarget::AssignmentTargetIdentifier(_)
+                        if assign.operator == AssignmentOperator::Assign =>
+                    {
+                        &mut assign.right
+                    }
+                    AssignmentTarget::StaticMemberExpression(member)
+                        if !assign.operator.is_logical() =>
+                    {
+                        match find(&mut member.object) {
+                            Use::Pure => &mut assign.right,
+                            found_or_abort => return found_or_abort,
+                        }
+                    }
+                    _ => return Use::Abort,
+                };
+                Self::only_found(find(right))
+            }
+            Expression::ArrayExpression(array) => {
+                let mut elements = std::vec::Vec::with_capacity(array.elements.len());
+                for element in array.elements.iter_mut() {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(_) => return Use::Abort,
+                        ArrayExpressionElement::Elision(_) => {}
+                        element => elements.push(element.to_expression_mut()),
+                    }
+                }
+                find_all(&mut elements.into_iter(), true)
+            }
+            Expression::ObjectExpression(object) => {
+                let mut values = std::vec::Vec::with_capacity(object.properties.len());
+                for property in object.properties.iter_mut() {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        return Use::Abort;
+                    };
+                    let property = &mut **property;
+                    if property.computed {
+                        let Some(key) = property.key.as_expression_mut() else {
+                            return Use::Abort;
+                        };
+                        values.push(key);
+                    }
+                    values.push(&mut property.value);
+                }
+                find_all(&mut values.into_iter(), true)
+            }
+            Expression::TSAsExpression(ts) => find(&mut ts.expression),
+            Expression::TSSatisfiesExpression(ts) => find(&mut ts.expression),
+            Expression::TSNonNullExpression(ts) => find(&mut ts.expression),
+            _ => Use::Abort,
+        }
+    }
+
+    /// `found`, or [`Use::Abort`] if the expression was evaluated without finding the use, e.g.
+    /// the callee of a call with side effects.
+    fn only_found<'b>(found: Use<'b, 'a>) -> Use<'b, 'a> {
+        match found {
+            Use::Found(expr) => Use::Found(expr),
+            _ => Use::Abort,
+        }
+    }
+
+    /// `pure`, or [`Use::Abort`] if the use was found in a conditionally evaluated expression.
+    fn only_pure<'b>(pure: &Use<'b, 'a>) -> Use<'b, 'a> {
+        if matches!(pure, Use::Pure) {
+            Use::Pure
+        } else {
+            Use::Abort
+        }
+    }
+
+    /// The arguments of a call, or `None` if there are spread arguments.
+    fn argument_expressions<'b>(
+        arguments: &'b mut Vec<'a, Argument<'a>>,
+    ) -> Option<impl Iterator<Item = &'b mut Expression<'a>>> {
+        if arguments.iter().any(Argument::is_spread) {
+            return None;
+        }
+        Some(arguments.iter_mut().map(Argument::to_expression_mut))
+    }
+
+    fn is_reference_to(expr: &Expression<'a>, symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr.without_parentheses() else { return false };
+        ident.reference_id.get().is_some_and(|reference_id| {
+            ctx.symbols().get_reference(reference_id).symbol_id() == Some(symbol_id)
+        })
     }
 
     /// Join consecutive var statements
@@ -2,28 +2,32 @@
 //!
 //! <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/PeepholeFoldConstants.java>
 
-use std::cmp::Ordering;
+use std::{borrow::Cow, cmp::Ordering};
 
 use num_bigint::BigInt;
+use oxc_allocator::CloneIn;
 use oxc_ast::{ast::*, AstBuilder, Visit};
 use oxc_span::{GetSpan, Span, SPAN};
 use oxc_syntax::{
     number::NumberBase,
     operator::{BinaryOperator, LogicalOperator, UnaryOperator},
 };
-use oxc_traverse::{Traverse, TraverseCtx};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
 use crate::{
     keep_var::KeepVar,
-    node_util::{is_exact_int64, MayHaveSideEffects, NodeUtil, NumberValue},
+    node_util::{
+        is_exact_int64, ContainsUnlabeledBreak, MayHaveSideEffects, NodeUtil, NumberValue,
+    },
     tri::Tri,
     ty::Ty,
-    CompressorPass,
+    CompressorPass, KnownGlobals,
 };
 
 pub struct FoldConstants<'a> {
     ast: AstBuilder<'a>,
     evaluate: bool,
+    known_globals: KnownGlobals,
 }
 
 impl<'a> CompressorPass<'a> for FoldConstants<'a> {}
@@ -41,7 +45,7 @@ impl<'a> Traverse<'a> for FoldConstants<'a> {
 
 impl<'a> FoldConstants<'a> {
     pub fn new(ast: AstBuilder<'a>) -> Self {
-        Self { ast, evaluate: false }
+        Self { ast, evaluate: false, known_globals: KnownGlobals::default() }
     }
 
     pub fn with_evaluate(mut self, yes: bool) -> Self {
@@ -49,6 +53,11 @@ impl<'a> FoldConstants<'a> {
         self
     }
 
+    pub fn with_known_globals(mut self, known_globals: KnownGlobals) -> Self {
+        self.known_globals = known_globals;
+        self
+    }
+
     fn fold_expression_and_get_boolean_value(
         &mut self,
         expr: &mut Expression<'a>,
@@ -89,6 +98,82 @@ impl<'a> FoldConstants<'a> {
         }
     }
 
+    /// Replace a `switch` statement with a constant discriminant by the statements it executes.
+    ///
+    /// `switch (1) { case 1: a(); case 2: b(); break; default: c() }` -> `{ a(); b(); }`
+    fn fold_switch_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+        if switch_stmt.discriminant.may_have_side_effects() {
+            return;
+        }
+        // Declarations in cases are scoped to the whole `switch` statement
+        if switch_stmt.cases.iter().flat_map(|case| &case.consequent).any(|stmt| {
+            stmt.as_declaration().is_some_and(|decl| {
+                !matches!(decl, Declaration::VariableDeclaration(decl) if decl.kind.is_var())
+            })
+        }) {
+            return;
+        }
+
+        // Find the case which is jumped to
+        let mut target = None;
+        for (i, case) in switch_stmt.cases.iter().enumerate() {
+            let Some(test) = &case.test else { continue };
+            if test.may_have_side_effects() {
+                return;
+            }
+            match Self::try_strict_equality_comparison(&switch_stmt.discriminant, test, ctx) {
+                Tri::True => {
+                    target = Some(i);
+                    break;
+                }
+                Tri::False => {}
+                Tri::Unknown => return,
+            }
+        }
+        let target =
+            target.or_else(|| switch_stmt.cases.iter().position(|case| case.test.is_none()));
+
+        // Find the `break` which exits the `switch` statement, falling through the cases
+        let mut end = (switch_stmt.cases.len(), 0);
+        if let Some(target) = target {
+            'outer: for (i, case) in switch_stmt.cases.iter().enumerate().skip(target) {
+                for (j, stmt) in case.consequent.iter().enumerate() {
+                    if matches!(stmt, Statement::BreakStatement(break_stmt) if break_stmt.label.is_none())
+                    {
+                        end = (i, j);
+                        break 'outer;
+                    }
+                    // Conditional `break`s can not be expressed in a block
+                    if stmt.contains_unlabeled_break() {
+                        return;
+                    }
+                }
+            }
+        }
+
+        let target = target.unwrap_or(switch_stmt.cases.len());
+        let mut body = self.ast.vec();
+        let mut keep_var = KeepVar::new(self.ast);
+        for (i, case) in switch_stmt.cases.iter_mut().enumerate() {
+            for (j, stmt) in case.consequent.drain(..).enumerate() {
+                if i >= target && (i, j) < end {
+                    body.push(stmt);
+                } else {
+                    // Keep hoisted `vars` from the cases which are not executed.
+                    keep_var.visit_statement(&stmt);
+                }
+            }
+        }
+        if let Some(var_stmt) = keep_var.get_variable_declaration_statement() {
+            body.push(var_stmt);
+        }
+
+        let block = self.ast.block_statement(switch_stmt.span, body);
+        block.scope_id.set(switch_stmt.scope_id.get());
+        *stmt = Statement::BlockStatement(self.ast.alloc(block));
+    }
+
     fn fold_conditional_expression(
         &mut self,
         expr: &mut Expression<'a>,
@@ -143,17 +228,36 @@ impl<'a> FoldConstants<'a> {
                 // don't match (even though the produced code is valid). Additionally, We'll likely
                 // want to add `evaluate` checks for all constant folding, not just additions, but
                 // we're adding this here until a decision is made.
-                BinaryOperator::Addition if self.evaluate => self.try_fold_addition(
-                    binary_expr.span,
-                    &binary_expr.left,
-                    &binary_expr.right,
-                    ctx,
-                ),
+                BinaryOperator::Addition if self.evaluate => {
+                    match self.try_fold_left_child_addition(binary_expr, ctx) {
+                        Some(folded) => Some(folded),
+                        None => self.try_fold_addition(
+                            binary_expr.span,
+                            &binary_expr.left,
+                            &binary_expr.right,
+                            ctx,
+                        ),
+                    }
+                }
                 _ => None,
             },
             Expression::LogicalExpression(logic_expr) => {
                 self.try_fold_logical_expression(logic_expr, ctx)
             }
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::Typeof =>
+            {
+                self.try_fold_typeof(unary_expr, ctx)
+            }
+            Expression::TemplateLiteral(template_literal) if self.evaluate => {
+                self.try_fold_template_literal(template_literal, ctx)
+            }
+            Expression::StaticMemberExpression(member_expr) if self.evaluate => {
+                self.try_fold_static_member_expression(member_expr, ctx)
+            }
+            Expression::CallExpression(call_expr) if self.evaluate => {
+                self.try_fold_array_join(call_expr, ctx)
+            }
             _ => None,
         };
         if let Some(folded_expr) = folded_expr {
@@ -161,6 +265,81 @@ impl<'a> FoldConstants<'a> {
         }
     }
 
+    /// `typeof 1` => `"number"`, and `typeof window` => `"undefined"` for globals known not to
+    /// exist, see [`KnownGlobals`].
+    fn try_fold_typeof(
+        &self,
+        unary_expr: &UnaryExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let value = match &unary_expr.argument {
+            Expression::Identifier(ident) => {
+                if !Self::is_unassigned_global(ident, ctx) {
+                    return None;
+                }
+                match ident.name.as_str() {
+                    "undefined" => "undefined",
+                    name => self.known_globals.get_typeof(name)?,
+                }
+            }
+            argument if argument.may_have_side_effects() => return None,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => "string",
+            Expression::NumericLiteral(_) => "number",
+            Expression::BigIntLiteral(_) => "bigint",
+            Expression::BooleanLiteral(_) => "boolean",
+            Expression::NullLiteral(_)
+            | Expression::ObjectExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::RegExpLiteral(_) => "object",
+            Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+                "function"
+            }
+            Expression::UnaryExpression(argument) if argument.operator == UnaryOperator::Void => {
+                "undefined"
+            }
+            _ => return None,
+        };
+        Some(self.ast.expression_string_literal(unary_expr.span, self.ast.atom(value)))
+    }
+
+    /// Whether `ident` refers to a global variable which the program never assigns to.
+    fn is_unassigned_global(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ident.reference_id.get().is_some_and(|id| ctx.symbols().is_global_reference(id))
+            && ctx.scopes().root_unresolved_references().get(ident.name.as_str()).map_or(
+                true,
+                |reference_ids| {
+                    reference_ids.iter().all(|&id| !ctx.symbols().get_reference(id).is_write())
+                },
+            )
+    }
+
+    /// `a + "b" + "c"` -> `a + "bc"`
+    ///
+    /// `a` is moved rather than cloned, so that it keeps its references.
+    fn try_fold_left_child_addition(
+        &mut self,
+        binary_expr: &mut BinaryExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Expression::BinaryExpression(left_binary_expr) = &mut binary_expr.left else {
+            return None;
+        };
+        if left_binary_expr.operator != BinaryOperator::Addition
+            || Ty::from(&left_binary_expr.right) != Ty::Str
+        {
+            return None;
+        }
+        let folded = self.try_fold_addition(
+            binary_expr.span,
+            &left_binary_expr.right,
+            &binary_expr.right,
+            ctx,
+        )?;
+        left_binary_expr.span = binary_expr.span;
+        left_binary_expr.right = folded;
+        Some(self.ast.move_expression(&mut binary_expr.left))
+    }
+
     fn try_fold_addition<'b>(
         &mut self,
         span: Span,
@@ -205,6 +384,153 @@ impl<'a> FoldConstants<'a> {
         }
     }
 
+    /// `` `a${1}b` `` -> `"a1b"`
+    fn try_fold_template_literal(
+        &self,
+        template_literal: &TemplateLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if template_literal.expressions.iter().any(MayHaveSideEffects::may_have_side_effects) {
+            return None;
+        }
+        let value = ctx.get_template_literal_string_value(template_literal)?;
+        Some(self.ast.expression_string_literal(template_literal.span, value))
+    }
+
+    /// `"foo".length` -> `3`, `Object.freeze({ a: 1 }).a` -> `1`
+    fn try_fold_static_member_expression(
+        &self,
+        member_expr: &StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        // `delete "foo".length` is `false`
+        if matches!(
+            ctx.parent(),
+            Ancestor::UnaryExpressionArgument(unary) if *unary.operator() == UnaryOperator::Delete
+        ) {
+            return None;
+        }
+        let object = Self::unwrap_object_freeze(&member_expr.object, ctx);
+        match object {
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_)
+                if member_expr.property.name == "length" =>
+            {
+                let value = ctx.get_side_free_string_value(object)?;
+                // Strings are UTF-16
+                #[allow(clippy::cast_precision_loss)]
+                let length = value.encode_utf16().count() as f64;
+                Some(self.ast.expression_numeric_literal(
+                    member_expr.span,
+                    length,
+                    length.to_string(),
+                    NumberBase::Decimal,
+                ))
+            }
+            Expression::ArrayExpression(array_expr) if member_expr.property.name == "length" => {
+                if array_expr.elements.iter().any(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_) => true,
+                    ArrayExpressionElement::Elision(_) => false,
+                    element => element.to_expression().may_have_side_effects(),
+                }) {
+                    return None;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let length = array_expr.elements.len() as f64;
+                Some(self.ast.expression_numeric_literal(
+                    member_expr.span,
+                    length,
+                    length.to_string(),
+                    NumberBase::Decimal,
+                ))
+            }
+            Expression::ObjectExpression(object_expr) => {
+                let mut value = None;
+                for property in &object_expr.properties {
+                    // `{ ...a }` and `{ get a() {} }` may run code
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        return None;
+                    };
+                    if property.kind != PropertyKind::Init || property.value.may_have_side_effects()
+                    {
+                        return None;
+                    }
+                    let name = property.key.static_name()?;
+                    // `{ __proto__: a }` sets the prototype
+                    if name == "__proto__" && !property.computed && !property.shorthand {
+                        return None;
+                    }
+                    // Later properties override earlier ones
+                    if name == member_expr.property.name.as_str() {
+                        value = Some(&property.value);
+                    }
+                }
+                // Missing properties are looked up in `Object.prototype`
+                let value = value?;
+                (value.is_literal() && !matches!(value, Expression::RegExpLiteral(_)))
+                    .then(|| value.clone_in(self.ast.allocator))
+            }
+            _ => None,
+        }
+    }
+
+    /// `Object.freeze(a)` returns `a`.
+    fn unwrap_object_freeze<'b>(
+        expr: &'b Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> &'b Expression<'a> {
+        if let Expression::CallExpression(call_expr) = expr {
+            if let (Expression::StaticMemberExpression(callee), [Argument::ObjectExpression(_)]) =
+                (&call_expr.callee, call_expr.arguments.as_slice())
+            {
+                if callee.property.name == "freeze"
+                    && matches!(&callee.object, Expression::Identifier(ident)
+                    if ident.name == "Object"
+                        && ident.reference_id.get().is_some_and(|reference_id| {
+                            ctx.symbols().is_global_reference(reference_id)
+                        }))
+                {
+                    return call_expr.arguments[0].to_expression();
+                }
+            }
+        }
+        expr
+    }
+
+    /// `["a", "b"].join("-")` -> `"a-b"`
+    fn try_fold_array_join(
+        &self,
+        call_expr: &CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Expression::StaticMemberExpression(callee) = &call_expr.callee else { return None };
+        let Expression::ArrayExpression(array_expr) = &callee.object else { return None };
+        if callee.property.name != "join" || call_expr.optional {
+            return None;
+        }
+        let separator = match call_expr.arguments.as_slice() {
+            [] => Cow::Borrowed(","),
+            [Argument::SpreadElement(_)] => return None,
+            [separator] => {
+                let separator = separator.to_expression();
+                if separator.is_undefined() || separator.is_void_0() {
+                    Cow::Borrowed(",")
+                } else {
+                    ctx.get_side_free_string_value(separator)?
+                }
+            }
+            _ => return None,
+        };
+        if callee.object.may_have_side_effects() {
+            return None;
+        }
+        let values = array_expr
+            .elements
+            .iter()
+            .map(|element| ctx.get_array_element_string_value(element))
+            .collect::<Option<std::vec::Vec<_>>>()?;
+        Some(self.ast.expression_string_literal(call_expr.span, values.join(separator.as_ref())))
+    }
+
     fn try_fold_comparison<'b>(
         &mut self,
         span: Span,
@@ -680,6 +1006,9 @@ impl<'a> FoldConstants<'a> {
             Statement::IfStatement(_) => {
                 self.fold_if_statement(stmt, ctx);
             }
+            Statement::SwitchStatement(_) => {
+                self.fold_switch_statement(stmt, ctx);
+            }
             _ => {}
         };
     }
@@ -0,0 +1,201 @@
+use std::cell::Cell;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::SPAN;
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{
+    node_util::{is_lexical_declaration, negate_condition},
+    CompressorPass,
+};
+
+/// Simplify `if` statements which return, and blocks.
+///
+/// Terser option: `if_return: true`.
+///
+/// `function f() { if (a) return; b() }` => `function f() { if (!a) b() }`
+/// `if (a) { return b } else { c() }` => `if (a) return b; c()`
+/// `{ a(); { b() } }` => `{ a(); b() }`
+pub struct IfReturn<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> CompressorPass<'a> for IfReturn<'a> {}
+
+impl<'a> Traverse<'a> for IfReturn<'a> {
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.merge_blocks(stmts, ctx);
+        Self::remove_else_after_jump(stmts, ctx);
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Bail `cjs-module-lexer`, which detects re-exports by the `if (...) return;` statements
+        // before `Object.defineProperty(exports, key, ...)` in the callback of `forEach`.
+        if body.statements.iter().any(Self::is_object_define_property_exports) {
+            return;
+        }
+        self.if_return(&mut body.statements, ctx);
+    }
+}
+
+impl<'a> IfReturn<'a> {
+    pub fn new(ast: AstBuilder<'a>) -> Self {
+        Self { ast }
+    }
+
+    /// Merge nested blocks into the statements containing them,
+    /// `{ a(); { b() } }` => `{ a(); b() }`.
+    ///
+    /// Only blocks without lexical declarations are merged, `var` declarations are scoped to the
+    /// function either way. Child scopes of the merged blocks are moved to the enclosing scope.
+    fn merge_blocks(&self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !stmts.iter().any(Self::is_mergeable_block) {
+            return;
+        }
+        let mut new_stmts = self.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            match stmt {
+                Statement::BlockStatement(mut block) if Self::is_mergeable_block_body(&block) => {
+                    Self::remove_block_scope(&block, ctx);
+                    new_stmts.extend(block.body.drain(..));
+                }
+                stmt => new_stmts.push(stmt),
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `if (a) { return b } else { c() }` => `if (a) { return b } c()`
+    ///
+    /// Also for `throw`, `break` and `continue`.
+    fn remove_else_after_jump(stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            i += 1;
+            let Some(Statement::IfStatement(if_stmt)) = stmts.get_mut(i - 1) else { continue };
+            let Some(alternate) = &if_stmt.alternate else { continue };
+            let is_scoped = match alternate {
+                Statement::BlockStatement(block) => !Self::is_mergeable_block_body(block),
+                alternate => is_lexical_declaration(alternate),
+            };
+            if is_scoped || !Self::ends_with_jump(&if_stmt.consequent) {
+                continue;
+            }
+            match if_stmt.alternate.take().unwrap() {
+                Statement::BlockStatement(mut block) => {
+                    Self::remove_block_scope(&block, ctx);
+                    let len = block.body.len();
+                    stmts.splice(i..i, block.body.drain(..));
+                    i += len;
+                }
+                alternate => stmts.insert(i, alternate),
+            }
+        }
+    }
+
+    /// Move the statements following `if (a) return;` into the `if` statement when they are at
+    /// the end of a function, where falling off the end of the function also returns
+    /// `undefined`:
+    ///
+    /// `function f() { if (a) return; b(); c() }` => `function f() { if (!a) { b(); c() } }`
+    ///
+    /// Function declarations among the moved statements are hoisted above the `if` statement,
+    /// other lexical declarations prevent the transform.
+    fn if_return(&self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        // `function f() { a(); return; }` => `function f() { a() }`
+        if matches!(stmts.last(), Some(Statement::ReturnStatement(ret)) if ret.argument.is_none()) {
+            stmts.pop();
+        }
+
+        // Start at the end, so that nested `if` statements are moved first
+        for i in (0..stmts.len()).rev() {
+            let Statement::IfStatement(if_stmt) = &stmts[i] else { continue };
+            if if_stmt.alternate.is_some() || !Self::is_return_undefined(&if_stmt.consequent) {
+                continue;
+            }
+            if stmts.iter().skip(i + 1).any(|stmt| {
+                is_lexical_declaration(stmt) && !matches!(stmt, Statement::FunctionDeclaration(_))
+            }) {
+                continue;
+            }
+
+            let mut rest = self.ast.vec_with_capacity(stmts.len() - i - 1);
+            let mut functions = std::vec![];
+            for stmt in stmts.drain(i + 1..) {
+                if matches!(stmt, Statement::FunctionDeclaration(_)) {
+                    functions.push(stmt);
+                } else {
+                    rest.push(stmt);
+                }
+            }
+            let Statement::IfStatement(mut if_stmt) = stmts.pop().unwrap() else { unreachable!() };
+            stmts.extend(functions);
+
+            let test = self.ast.move_expression(&mut if_stmt.test);
+            if rest.is_empty() {
+                // `if (a) return;` => `a;`
+                stmts.push(self.ast.statement_expression(if_stmt.span, test));
+                continue;
+            }
+            let consequent = if rest.len() == 1 && !rest[0].is_declaration() {
+                rest.pop().unwrap()
+            } else {
+                let scope_id = ctx.insert_scope_below_statements(&rest, ScopeFlags::empty());
+                let block =
+                    BlockStatement { span: SPAN, body: rest, scope_id: Cell::new(Some(scope_id)) };
+                Statement::BlockStatement(self.ast.alloc(block))
+            };
+            if_stmt.test = negate_condition(self.ast, test);
+            if_stmt.consequent = consequent;
+            stmts.push(Statement::IfStatement(if_stmt));
+        }
+    }
+
+    /// `Object.defineProperty(exports, ...)`
+    fn is_object_define_property_exports(stmt: &Statement<'a>) -> bool {
+        let Statement::ExpressionStatement(stmt) = stmt else { return false };
+        let Expression::CallExpression(call_expr) = &stmt.expression else { return false };
+        call_expr
+            .object_define_property()
+            .is_some_and(|(target, _, _)| target.is_specific_id("exports"))
+    }
+
+    fn remove_block_scope(block: &BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(scope_id) = block.scope_id.get() {
+            ctx.remove_scope_above_statements(scope_id, &block.body);
+        }
+    }
+
+    fn is_mergeable_block(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::BlockStatement(block) if Self::is_mergeable_block_body(block))
+    }
+
+    fn is_mergeable_block_body(block: &BlockStatement<'a>) -> bool {
+        !block.body.iter().any(is_lexical_declaration)
+    }
+
+    /// `return;` or `{ return; }`
+    fn is_return_undefined(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ReturnStatement(ret) => ret.argument.is_none(),
+            Statement::BlockStatement(block) => {
+                block.body.len() == 1 && Self::is_return_undefined(&block.body[0])
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `stmt` never completes normally, e.g. `return a` or `{ b(); throw c }`.
+    fn ends_with_jump(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_) => true,
+            Statement::BlockStatement(block) => block.body.last().is_some_and(Self::ends_with_jump),
+            _ => false,
+        }
+    }
+}
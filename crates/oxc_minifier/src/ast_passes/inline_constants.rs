@@ -0,0 +1,192 @@
+//! Constant Propagation
+//!
+//! Inline `const` bindings of constant values into their uses, so that the uses can be folded by
+//! later passes.
+//!
+//! `const DEBUG = false; if (DEBUG) { foo() }` -> `if (false) { foo() }`
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_semantic::SymbolId;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::FxHashMap;
+
+use crate::CompressorPass;
+
+pub struct InlineConstants<'a> {
+    ast: AstBuilder<'a>,
+
+    /// Top level bindings of scripts are visible to other scripts, so they are never removed.
+    is_module: bool,
+
+    /// Values of the inlined `const` bindings, with the range in which their uses are inlined,
+    /// from the end of their declarations.
+    values: FxHashMap<SymbolId, (Span, Expression<'a>)>,
+}
+
+impl<'a> CompressorPass<'a> for InlineConstants<'a> {}
+
+impl<'a> Traverse<'a> for InlineConstants<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.is_module = program.source_type.is_module();
+    }
+
+    fn enter_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.kind != VariableDeclarationKind::Const {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+        let (Some(symbol_id), Some(init)) = (ident.symbol_id.get(), &decl.init) else { return };
+        // Bindings may be accessed by a direct `eval` or shadowed by a `with` statement
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        if ctx.scopes().get_flags(scope_id).is_poisoned() {
+            return;
+        }
+        // Declarations in a `case` are scoped to the whole `switch` statement, but are only
+        // initialized when the `case` runs, `switch (a) { case 0: const b = 1; break; case 1: b }`
+        let end = match ctx.ancestor(1) {
+            Ancestor::SwitchCaseConsequent(case) => case.span().end,
+            _ => u32::MAX,
+        };
+        let Some(value) = self.constant_value(init, ctx) else { return };
+
+        let mut references = 0;
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            // Assigning to a `const` throws a `TypeError`
+            if reference.is_write() {
+                return;
+            }
+            references += 1;
+        }
+        if references > 1 && !Self::is_small(&value) {
+            return;
+        }
+        self.values.insert(symbol_id, (Span::new(decl.span.end, end), value));
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(value) = self.inlined_value(ident, symbol_id, ctx) else { return };
+        // `delete DEBUG` is `false`, `delete false` is `true`
+        if matches!(
+            ctx.parent(),
+            Ancestor::UnaryExpressionArgument(unary) if *unary.operator() == UnaryOperator::Delete
+        ) {
+            return;
+        }
+        ctx.symbols_mut().delete_resolved_reference(symbol_id, reference_id);
+        *expr = value;
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.values.is_empty()
+            || (!self.is_module && ctx.current_scope_id() == ctx.scopes().root_scope_id())
+        {
+            return;
+        }
+        // Remove the declarations of bindings which are inlined into all of their uses
+        for stmt in stmts.iter_mut() {
+            let Statement::VariableDeclaration(decl) = stmt else { continue };
+            decl.declarations.retain(|declarator| {
+                let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                    return true;
+                };
+                ident.symbol_id.get().map_or(true, |symbol_id| {
+                    !self.values.contains_key(&symbol_id)
+                        || !ctx.symbols().get_resolved_reference_ids(symbol_id).is_empty()
+                })
+            });
+        }
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::VariableDeclaration(decl) if decl.declarations.is_empty())
+        });
+    }
+}
+
+impl<'a> InlineConstants<'a> {
+    pub fn new(ast: AstBuilder<'a>) -> Self {
+        Self { ast, is_module: false, values: FxHashMap::default() }
+    }
+
+    /// The value to inline for a reference to `symbol_id`.
+    fn inlined_value(
+        &self,
+        ident: &IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let (uses, value) = self.values.get(&symbol_id)?;
+        // Uses before the declaration throw a `ReferenceError`
+        if !uses.contains_inclusive(ident.span) {
+            return None;
+        }
+        let declaration_end = uses.start;
+        // So may uses in functions declared after it, which are hoisted and can be called before
+        // the declaration, `{ f(); const a = 1; function f() { return a } }`
+        let is_hoisted = |r#type: &FunctionType, span: &Span| {
+            *r#type == FunctionType::FunctionDeclaration && span.start >= declaration_end
+        };
+        let in_hoisted_function = ctx.ancestors().any(|ancestor| match ancestor {
+            Ancestor::FunctionParams(function) => is_hoisted(function.r#type(), function.span()),
+            Ancestor::FunctionBody(function) => is_hoisted(function.r#type(), function.span()),
+            _ => false,
+        });
+        (!in_hoisted_function).then(|| value.clone_in(self.ast.allocator))
+    }
+
+    /// The value of a `const` initializer which can be inlined, e.g. `1`, `-1`, `void 0` or
+    /// another inlined `const`.
+    fn constant_value(
+        &self,
+        expr: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_) => Some(expr.clone_in(self.ast.allocator)),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                Some(expr.clone_in(self.ast.allocator))
+            }
+            Expression::UnaryExpression(unary)
+                if matches!(
+                    unary.operator,
+                    UnaryOperator::UnaryNegation | UnaryOperator::LogicalNot | UnaryOperator::Void
+                ) && unary.argument.is_literal()
+                    && !matches!(unary.argument, Expression::RegExpLiteral(_)) =>
+            {
+                Some(expr.clone_in(self.ast.allocator))
+            }
+            // `const A = 1; const B = A;`
+            Expression::Identifier(ident) => {
+                let symbol_id =
+                    ctx.symbols().get_reference(ident.reference_id.get()?).symbol_id()?;
+                self.inlined_value(ident, symbol_id, ctx)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether `value` is small enough to be inlined into several uses.
+    fn is_small(value: &Expression<'a>) -> bool {
+        match value {
+            Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => true,
+            Expression::NumericLiteral(lit) => lit.value.fract() == 0.0 && lit.value < 1000.0,
+            Expression::StringLiteral(lit) => lit.value.len() <= 3,
+            Expression::UnaryExpression(unary) => Self::is_small(&unary.argument),
+            _ => false,
+        }
+    }
+}
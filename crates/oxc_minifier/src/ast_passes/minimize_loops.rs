@@ -0,0 +1,229 @@
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, Visit};
+use oxc_span::{Span, SPAN};
+use oxc_syntax::scope::{ScopeFlags, ScopeId};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{
+    keep_var::KeepVar,
+    node_util::{
+        is_lexical_declaration, negate_condition, ContainsUnlabeledBreak,
+        ContainsUnlabeledContinue, NodeUtil,
+    },
+    CompressorPass,
+};
+
+/// Minimize `for`, `while` and `do-while` loops.
+///
+/// Terser option: `loops: true`.
+///
+/// `while (a) b()` => `for (; a;) b()`
+/// `while (true) b()` => `for (;;) b()`
+/// `for (var i = 0; false; i++) b()` => `var i = 0`
+/// `for (;;) { if (a) break; b() }` => `for (; !a;) b()`
+/// `for (;;) { b(); if (a) break }` => `do b(); while (!a)`
+pub struct MinimizeLoops<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> CompressorPass<'a> for MinimizeLoops<'a> {}
+
+impl<'a> Traverse<'a> for MinimizeLoops<'a> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `continue label` may refer to labeled loops
+        let is_labeled = ctx.parent().is_labeled_statement();
+        match stmt {
+            Statement::WhileStatement(_) => self.compress_while(stmt, ctx),
+            Statement::DoWhileStatement(_) => self.compress_do_while(stmt, is_labeled, ctx),
+            _ => {}
+        }
+        if let Statement::ForStatement(_) = stmt {
+            self.compress_for(stmt, is_labeled, ctx);
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_empty_loops(stmts, ctx);
+    }
+}
+
+impl<'a> MinimizeLoops<'a> {
+    pub fn new(ast: AstBuilder<'a>) -> Self {
+        Self { ast }
+    }
+
+    /// `while (a) b()` => `for (; a;) b()`
+    fn compress_while(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        let Statement::WhileStatement(while_stmt) = stmt else { return };
+        let span = while_stmt.span;
+        let test = self.ast.move_expression(&mut while_stmt.test);
+        let body = self.ast.move_statement(&mut while_stmt.body);
+        *stmt = self.for_statement(span, Some(test), body, scope_id);
+    }
+
+    /// `do b(); while (true)` => `for (;;) b()`
+    /// `do b(); while (false)` => `b()`
+    fn compress_do_while(
+        &self,
+        stmt: &mut Statement<'a>,
+        is_labeled: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::DoWhileStatement(do_stmt) = stmt else { return };
+        match ctx.get_side_free_boolean_value(&do_stmt.test) {
+            Some(true) => {
+                let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+                let Statement::DoWhileStatement(do_stmt) = stmt else { return };
+                let span = do_stmt.span;
+                let body = self.ast.move_statement(&mut do_stmt.body);
+                *stmt = self.for_statement(span, None, body, scope_id);
+                self.compress_for(stmt, is_labeled, ctx);
+            }
+            // `break` and `continue` would exit the loop
+            Some(false)
+                if !is_labeled
+                    && !do_stmt.body.contains_unlabeled_break()
+                    && !do_stmt.body.contains_unlabeled_continue() =>
+            {
+                *stmt = self.ast.move_statement(&mut do_stmt.body);
+            }
+            _ => {}
+        }
+    }
+
+    fn compress_for(&self, stmt: &mut Statement<'a>, is_labeled: bool, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ForStatement(for_stmt) = stmt else { return };
+        // `for (; true;)` => `for (;;)`
+        if for_stmt
+            .test
+            .as_ref()
+            .is_some_and(|test| ctx.get_side_free_boolean_value(test) == Some(true))
+        {
+            for_stmt.test = None;
+        }
+        self.move_break_to_test(for_stmt);
+        if !is_labeled {
+            self.compress_for_to_do_while(stmt, ctx);
+        }
+    }
+
+    /// `for (;;) { if (a) break; b() }` => `for (; !a;) { b() }`
+    ///
+    /// The condition is evaluated after `update` either way, also when the loop continues.
+    fn move_break_to_test(&self, for_stmt: &mut ForStatement<'a>) {
+        if for_stmt.test.is_some() {
+            return;
+        }
+        let Statement::BlockStatement(block) = &mut for_stmt.body else { return };
+        // The condition can not refer to declarations in the block
+        if block.body.iter().any(is_lexical_declaration)
+            || !block.body.first().is_some_and(Self::is_break_if)
+        {
+            return;
+        }
+        let Statement::IfStatement(if_stmt) = block.body.remove(0) else { unreachable!() };
+        for_stmt.test = Some(negate_condition(self.ast, if_stmt.unbox().test));
+    }
+
+    /// `for (;;) { b(); if (a) break }` => `do { b() } while (!a)`
+    ///
+    /// `continue` in a `do-while` loop evaluates the condition, so it must not be used.
+    fn compress_for_to_do_while(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ForStatement(for_stmt) = stmt else { return };
+        if for_stmt.init.is_some()
+            || for_stmt.test.is_some()
+            || for_stmt.update.is_some()
+            || for_stmt.body.contains_unlabeled_continue()
+        {
+            return;
+        }
+        let Statement::BlockStatement(block) = &mut for_stmt.body else { return };
+        if block.body.iter().any(is_lexical_declaration)
+            || !block.body.last().is_some_and(Self::is_break_if)
+        {
+            return;
+        }
+        let Some(Statement::IfStatement(if_stmt)) = block.body.pop() else { unreachable!() };
+        let test = negate_condition(self.ast, if_stmt.unbox().test);
+        // `do-while` statements have no scope
+        if let Some(scope_id) = for_stmt.scope_id.get() {
+            ctx.remove_scope_above_statement(scope_id, &for_stmt.body);
+        }
+        let span = for_stmt.span;
+        let body = self.ast.move_statement(&mut for_stmt.body);
+        *stmt = self.ast.statement_do_while(span, body, test);
+    }
+
+    /// Remove loops which never run their body.
+    ///
+    /// `for (var i = 0; false; i++) { var a = b() }` => `var i = 0; var a`
+    fn remove_empty_loops(&self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Some(Statement::ForStatement(for_stmt)) = stmts.get_mut(i) else {
+                i += 1;
+                continue;
+            };
+            if for_stmt
+                .test
+                .as_ref()
+                .map_or(true, |test| ctx.get_side_free_boolean_value(test) != Some(false))
+                || for_stmt.init.as_ref().is_some_and(ForStatementInit::is_lexical_declaration)
+            {
+                i += 1;
+                continue;
+            }
+            let mut replacement = std::vec![];
+            match for_stmt.init.take() {
+                Some(ForStatementInit::VariableDeclaration(decl)) => {
+                    replacement.push(Statement::VariableDeclaration(decl));
+                }
+                Some(init) => {
+                    replacement.push(self.ast.statement_expression(SPAN, init.into_expression()));
+                }
+                None => {}
+            }
+            if let Some(scope_id) = for_stmt.scope_id.get() {
+                for stmt in &replacement {
+                    ctx.remove_scope_above_statement(scope_id, stmt);
+                }
+            }
+            // Keep the variables declared in the body
+            let mut keep_var = KeepVar::new(self.ast);
+            keep_var.visit_statement(&for_stmt.body);
+            replacement.extend(keep_var.get_variable_declaration_statement());
+            let len = replacement.len();
+            stmts.splice(i..=i, replacement);
+            i += len;
+        }
+    }
+
+    /// `if (a) break;` or `if (a) { break; }`
+    fn is_break_if(stmt: &Statement<'a>) -> bool {
+        let Statement::IfStatement(if_stmt) = stmt else { return false };
+        if if_stmt.alternate.is_some() {
+            return false;
+        }
+        match &if_stmt.consequent {
+            Statement::BreakStatement(break_stmt) => break_stmt.label.is_none(),
+            Statement::BlockStatement(block) => {
+                block.body.len() == 1
+                    && matches!(&block.body[0], Statement::BreakStatement(break_stmt) if break_stmt.label.is_none())
+            }
+            _ => false,
+        }
+    }
+
+    fn for_statement(
+        &self,
+        span: Span,
+        test: Option<Expression<'a>>,
+        body: Statement<'a>,
+        scope_id: ScopeId,
+    ) -> Statement<'a> {
+        let for_stmt = self.ast.for_statement(span, None, test, None, body);
+        for_stmt.scope_id.set(Some(scope_id));
+        Statement::ForStatement(self.ast.alloc(for_stmt))
+    }
+}
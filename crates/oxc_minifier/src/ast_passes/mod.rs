@@ -1,11 +1,17 @@
 mod collapse;
 mod fold_constants;
+mod if_return;
+mod inline_constants;
+mod minimize_loops;
 mod remove_dead_code;
 mod remove_syntax;
 mod substitute_alternate_syntax;
 
 pub use collapse::Collapse;
 pub use fold_constants::FoldConstants;
+pub use if_return::IfReturn;
+pub use inline_constants::InlineConstants;
+pub use minimize_loops::MinimizeLoops;
 use oxc_ast::ast::Program;
 use oxc_semantic::{ScopeTree, SymbolTable};
 use oxc_traverse::{walk_program, Traverse, TraverseCtx};
@@ -1,14 +1,16 @@
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
-use oxc_traverse::{Traverse, TraverseCtx};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
-use crate::{CompressOptions, CompressorPass};
+use crate::{node_util::MayHaveSideEffects, CompressOptions, CompressorPass};
 
 /// Remove syntax from the AST.
 ///
 /// * Parenthesized Expression
 /// * `debugger`
 /// * `console.log`
+/// * Calls to `compress.pure_funcs`
 pub struct RemoveSyntax<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
@@ -17,17 +19,24 @@ pub struct RemoveSyntax<'a> {
 impl<'a> CompressorPass<'a> for RemoveSyntax<'a> {}
 
 impl<'a> Traverse<'a> for RemoveSyntax<'a> {
-    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        // The value of `() => f()` is returned
+        let is_arrow_expression_body = matches!(
+            ctx.ancestor(1),
+            Ancestor::ArrowFunctionExpressionBody(arrow) if *arrow.expression()
+        );
+        for stmt in stmts.iter_mut() {
+            self.drop_call_statement(stmt, is_arrow_expression_body);
+        }
         stmts.retain(|stmt| {
-            !(matches!(stmt, Statement::EmptyStatement(_))
-                || self.drop_debugger(stmt)
-                || self.drop_console(stmt))
+            !(matches!(stmt, Statement::EmptyStatement(_)) || self.drop_debugger(stmt))
         });
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.strip_parenthesized_expression(expr);
         self.compress_console(expr);
+        self.drop_sequence_calls(expr);
     }
 
     fn exit_arrow_function_expression(
@@ -58,17 +67,84 @@ impl<'a> RemoveSyntax<'a> {
         matches!(stmt, Statement::DebuggerStatement(_)) && self.options.drop_debugger
     }
 
-    /// Drop `console.*` expressions.
+    /// Drop `console.*` and `compress.pure_funcs` call statements, keeping arguments with side
+    /// effects.
     ///
-    /// Enabled by `compress.drop_console
-    fn drop_console(&mut self, stmt: &Statement<'a>) -> bool {
-        self.options.drop_console
-            && matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_console(&expr.expression))
+    /// `console.log(a, b())` -> `b()`
+    fn drop_call_statement(&self, stmt: &mut Statement<'a>, is_arrow_expression_body: bool) {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return };
+        let Expression::CallExpression(call_expr) = &mut expr_stmt.expression else { return };
+        if is_arrow_expression_body {
+            // `() => console.log(a())` is left to `compress_console`, as it must return `undefined`
+            if !self.is_dropped_console(call_expr) || self.has_kept_arguments(call_expr) {
+                return;
+            }
+        } else if !self.is_dropped_console(call_expr) && !self.is_pure_func(call_expr) {
+            return;
+        }
+        match self.kept_arguments(call_expr) {
+            Some(expr) => expr_stmt.expression = expr,
+            None => *stmt = self.ast.statement_empty(SPAN),
+        }
     }
 
+    /// Drop `console.*` expressions.
+    ///
+    /// Enabled by `compress.drop_console`
+    ///
+    /// `x = console.log(a())` -> `x = (a(), void 0)`
     fn compress_console(&mut self, expr: &mut Expression<'a>) {
-        if self.options.drop_console && Self::is_console(expr) {
-            *expr = self.ast.void_0();
+        let Expression::CallExpression(call_expr) = expr else { return };
+        if !self.is_dropped_console(call_expr) {
+            return;
+        }
+        *expr = match self.kept_arguments(call_expr) {
+            Some(Expression::SequenceExpression(mut sequence_expr)) => {
+                sequence_expr.expressions.push(self.ast.void_0());
+                Expression::SequenceExpression(sequence_expr)
+            }
+            Some(kept) => self
+                .ast
+                .expression_sequence(SPAN, self.ast.vec_from_iter([kept, self.ast.void_0()])),
+            None => self.ast.void_0(),
+        };
+    }
+
+    /// Drop `compress.pure_funcs` calls whose value is unused in a sequence expression.
+    ///
+    /// `(invariant(a), b)` -> `b`
+    fn drop_sequence_calls(&self, expr: &mut Expression<'a>) {
+        let Expression::SequenceExpression(sequence_expr) = expr else { return };
+        let last = sequence_expr.expressions.len() - 1;
+        if !sequence_expr.expressions.iter().take(last).any(
+            |expr| matches!(expr, Expression::CallExpression(call_expr) if self.is_pure_func(call_expr)),
+        ) {
+            return;
+        }
+
+        let mut expressions = self.ast.vec();
+        for (i, mut expr) in sequence_expr.expressions.drain(..).enumerate() {
+            if i < last {
+                if let Expression::CallExpression(call_expr) = &mut expr {
+                    if self.is_pure_func(call_expr) {
+                        match self.kept_arguments(call_expr) {
+                            Some(Expression::SequenceExpression(kept)) => {
+                                expressions.extend(kept.unbox().expressions);
+                            }
+                            Some(kept) => expressions.push(kept),
+                            None => {}
+                        }
+                        continue;
+                    }
+                }
+            }
+            expressions.push(expr);
+        }
+
+        if expressions.len() == 1 {
+            *expr = expressions.pop().unwrap();
+        } else {
+            sequence_expr.expressions = expressions;
         }
     }
 
@@ -78,12 +154,72 @@ impl<'a> RemoveSyntax<'a> {
         }
     }
 
-    fn is_console(expr: &Expression<'_>) -> bool {
-        // let Statement::ExpressionStatement(expr) = stmt else { return false };
-        let Expression::CallExpression(call_expr) = &expr else { return false };
+    /// Whether any argument of a dropped call is kept for its side effects.
+    fn has_kept_arguments(&self, call_expr: &CallExpression<'a>) -> bool {
+        !self.options.unsafe_drop_args
+            && call_expr.arguments.iter().any(|arg| match arg {
+                Argument::SpreadElement(_) => true,
+                arg => arg.to_expression().may_have_side_effects(),
+            })
+    }
+
+    /// Take the arguments with side effects of a dropped call, or `None` if there are none.
+    ///
+    /// Enabled by `compress.unsafe_drop_args`, all arguments are dropped.
+    fn kept_arguments(&self, call_expr: &mut CallExpression<'a>) -> Option<Expression<'a>> {
+        if self.options.unsafe_drop_args {
+            return None;
+        }
+        let mut expressions = self.ast.vec();
+        for arg in call_expr.arguments.drain(..) {
+            match arg {
+                // `f(...a)` iterates `a`
+                Argument::SpreadElement(spread) => {
+                    let elements = self.ast.vec1(ArrayExpressionElement::SpreadElement(spread));
+                    expressions.push(self.ast.expression_array(SPAN, elements, None));
+                }
+                arg => {
+                    let expr = arg.into_expression();
+                    if expr.may_have_side_effects() {
+                        expressions.push(expr);
+                    }
+                }
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ast.expression_sequence(SPAN, expressions)),
+        }
+    }
+
+    fn is_dropped_console(&self, call_expr: &CallExpression<'a>) -> bool {
+        self.options.drop_console && Self::is_console(call_expr)
+    }
+
+    fn is_console(call_expr: &CallExpression<'_>) -> bool {
         let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
         let obj = member_expr.object();
         let Some(ident) = obj.get_identifier_reference() else { return false };
         ident.name == "console"
     }
+
+    /// Enabled by `compress.pure_funcs`
+    fn is_pure_func(&self, call_expr: &CallExpression<'a>) -> bool {
+        self.options.pure_funcs.iter().any(|path| Self::is_path(&call_expr.callee, path))
+    }
+
+    /// Whether `expr` is the identifier or static member expression `path`, e.g. `console.assert`.
+    fn is_path(expr: &Expression<'_>, path: &str) -> bool {
+        match expr {
+            Expression::Identifier(ident) => ident.name == path,
+            Expression::StaticMemberExpression(member_expr) => {
+                path.rsplit_once('.').is_some_and(|(object, property)| {
+                    member_expr.property.name == property
+                        && Self::is_path(&member_expr.object, object)
+                })
+            }
+            _ => false,
+        }
+    }
 }
@@ -1,12 +1,17 @@
+use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
-use oxc_span::SPAN;
+use oxc_span::{cmp::ContentEq, SPAN};
 use oxc_syntax::{
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::{CompressOptions, CompressorPass};
+use crate::{
+    node_util::{ContainsUnlabeledBreak, MayHaveSideEffects},
+    CompressOptions, CompressorPass,
+};
 
 /// A peephole optimization that minimizes code by simplifying conditional
 /// expressions, replacing IFs with HOOKs, replacing object constructors
@@ -20,9 +25,9 @@ pub struct SubstituteAlternateSyntax<'a> {
 impl<'a> CompressorPass<'a> for SubstituteAlternateSyntax<'a> {}
 
 impl<'a> Traverse<'a> for SubstituteAlternateSyntax<'a> {
-    fn enter_statement(&mut self, stmt: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {
-        self.compress_block(stmt);
-        // self.compress_while(stmt);
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.compress_switch(stmt, ctx);
+        self.compress_block(stmt, ctx);
     }
 
     fn exit_return_statement(
@@ -104,21 +109,9 @@ impl<'a> SubstituteAlternateSyntax<'a> {
 
     /// Test `Object.defineProperty(exports, ...)`
     fn is_object_define_property_exports(call_expr: &CallExpression<'a>) -> bool {
-        let Some(Argument::Identifier(ident)) = call_expr.arguments.first() else { return false };
-        if ident.name != "exports" {
-            return false;
-        }
-
-        // Use tighter check than `call_expr.callee.is_specific_member_access("Object", "defineProperty")`
-        // because we're looking for `Object.defineProperty` specifically, not e.g. `Object['defineProperty']`
-        if let Expression::StaticMemberExpression(callee) = &call_expr.callee {
-            if let Expression::Identifier(id) = &callee.object {
-                if id.name == "Object" && callee.property.name == "defineProperty" {
-                    return true;
-                }
-            }
-        }
-        false
+        call_expr
+            .object_define_property()
+            .is_some_and(|(target, _, _)| target.is_specific_id("exports"))
     }
 
     /* Statements */
@@ -126,27 +119,157 @@ impl<'a> SubstituteAlternateSyntax<'a> {
     /// Remove block from single line blocks
     /// `{ block } -> block`
     #[allow(clippy::only_used_in_recursion)] // `&self` is only used in recursion
-    fn compress_block(&self, stmt: &mut Statement<'a>) {
+    fn compress_block(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Statement::BlockStatement(block) = stmt {
             // Avoid compressing `if (x) { var x = 1 }` to `if (x) var x = 1` due to different
             // semantics according to AnnexB, which lead to different semantics.
             if block.body.len() == 1 && !block.body[0].is_declaration() {
+                if let Some(scope_id) = block.scope_id.get() {
+                    ctx.remove_scope_above_statement(scope_id, &block.body[0]);
+                }
                 *stmt = block.body.remove(0);
-                self.compress_block(stmt);
+                self.compress_block(stmt, ctx);
+            }
+        }
+    }
+
+    /// Compress `switch` statements.
+    /// Enabled by `compress.switches`.
+    ///
+    /// `switch (a) { case 1: b(); break; case 2: b(); break; }` -> `switch (a) { case 1: case 2: b(); }`
+    fn compress_switch(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.switches {
+            return;
+        }
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+        Self::merge_switch_cases(&mut switch_stmt.cases);
+        Self::remove_trailing_switch_cases(&mut switch_stmt.cases);
+        self.compress_switch_to_if(stmt, ctx);
+    }
+
+    /// `case 1: b(); break; case 2: b(); break;` -> `case 1: case 2: b(); break;`
+    fn merge_switch_cases(cases: &mut Vec<'a, SwitchCase<'a>>) {
+        for i in 1..cases.len() {
+            let (prev, next) = (&cases[i - 1], &cases[i]);
+            // The previous case must not fall through
+            if prev.consequent.last().is_some_and(Self::is_jump)
+                && prev.consequent.content_eq(&next.consequent)
+            {
+                cases.get_mut(i - 1).unwrap().consequent.clear();
+            }
+        }
+    }
+
+    /// `case 1: b(); break; case 2: }` -> `case 1: b();`
+    fn remove_trailing_switch_cases(cases: &mut Vec<'a, SwitchCase<'a>>) {
+        loop {
+            // Values matching no case jump to `default`
+            let has_default = cases.iter().any(|case| case.test.is_none());
+            let Some(last) = cases.last_mut() else { return };
+            if last.consequent.last().is_some_and(Self::is_unlabeled_break) {
+                last.consequent.pop();
+            } else if last.consequent.is_empty()
+                && last
+                    .test
+                    .as_ref()
+                    .map_or(true, |test| !has_default && !test.may_have_side_effects())
+            {
+                cases.pop();
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// `switch (a) { case 1: b(); break; default: c() }` -> `if (a === 1) { b() } else { c() }`
+    fn compress_switch_to_if(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+        let switch_stmt = &mut **switch_stmt;
+        let cases = &mut switch_stmt.cases;
+        let has_default = match cases.len() {
+            1 => false,
+            2 if cases[1].test.is_none() => true,
+            _ => return,
+        };
+        if cases[0].test.is_none() {
+            return;
+        }
+        let mut consequent_len = cases[0].consequent.len();
+        if has_default {
+            // The case must not fall through to `default`
+            match cases[0].consequent.last() {
+                Some(stmt) if Self::is_unlabeled_break(stmt) => consequent_len -= 1,
+                Some(stmt) if Self::is_jump(stmt) => {}
+                _ => return,
+            }
+        }
+        let statements = cases[0].consequent.as_slice()[..consequent_len]
+            .iter()
+            .chain(cases.iter().skip(1).flat_map(|case| &case.consequent));
+        for stmt in statements {
+            // Declarations in cases are scoped to the whole `switch` statement
+            if stmt.is_declaration()
+                && !matches!(stmt, Statement::VariableDeclaration(decl) if decl.kind.is_var())
+            {
+                return;
             }
+            if stmt.contains_unlabeled_break() {
+                return;
+            }
+        }
+
+        // The `switch` scope is kept for the consequent, the scopes in the test and the alternate
+        // are moved out of it
+        let switch_scope_id = switch_stmt.scope_id.get();
+        let (case, default_case) = cases.split_first_mut().unwrap();
+        case.consequent.truncate(consequent_len);
+        if let (Some(scope_id), Some(test)) = (switch_scope_id, &case.test) {
+            ctx.remove_scope_above_expression(scope_id, test);
         }
+        let alternate = default_case.first_mut().map(|case| {
+            let mut body = self.ast.move_vec(&mut case.consequent);
+            if body.len() == 1 {
+                let stmt = body.pop().unwrap();
+                if let Some(scope_id) = switch_scope_id {
+                    ctx.remove_scope_above_statement(scope_id, &stmt);
+                }
+                return stmt;
+            }
+            let scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::empty());
+            let block = self.ast.block_statement(SPAN, body);
+            block.scope_id.set(Some(scope_id));
+            Statement::BlockStatement(self.ast.alloc(block))
+        });
+        let consequent = self.ast.block_statement(SPAN, self.ast.move_vec(&mut case.consequent));
+        consequent.scope_id.set(switch_stmt.scope_id.get());
+        let test = self.ast.expression_binary(
+            SPAN,
+            self.ast.move_expression(&mut switch_stmt.discriminant),
+            BinaryOperator::StrictEquality,
+            case.test.take().unwrap(),
+        );
+        *stmt = self.ast.statement_if(
+            switch_stmt.span,
+            test,
+            Statement::BlockStatement(self.ast.alloc(consequent)),
+            alternate,
+        );
     }
 
-    // /// Transforms `while(expr)` to `for(;expr;)`
-    // fn compress_while(&mut self, stmt: &mut Statement<'a>) {
-    // let Statement::WhileStatement(while_stmt) = stmt else { return };
-    // if self.options.loops {
-    // let dummy_test = self.ast.expression_this(SPAN);
-    // let test = std::mem::replace(&mut while_stmt.test, dummy_test);
-    // let body = self.ast.move_statement(&mut while_stmt.body);
-    // *stmt = self.ast.statement_for(SPAN, None, Some(test), None, body);
-    // }
-    // }
+    fn is_unlabeled_break(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::BreakStatement(break_stmt) if break_stmt.label.is_none())
+    }
+
+    /// Whether control never continues after `stmt`.
+    fn is_jump(stmt: &Statement<'a>) -> bool {
+        matches!(
+            stmt,
+            Statement::BreakStatement(_)
+                | Statement::ContinueStatement(_)
+                | Statement::ReturnStatement(_)
+                | Statement::ThrowStatement(_)
+        )
+    }
 
     /* Expressions */
 
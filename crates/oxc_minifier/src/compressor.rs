@@ -5,7 +5,8 @@ use oxc_traverse::TraverseCtx;
 
 use crate::{
     ast_passes::{
-        Collapse, FoldConstants, RemoveDeadCode, RemoveSyntax, SubstituteAlternateSyntax,
+        Collapse, FoldConstants, IfReturn, InlineConstants, MinimizeLoops, RemoveDeadCode,
+        RemoveSyntax, SubstituteAlternateSyntax,
     },
     CompressOptions, CompressorPass,
 };
@@ -26,38 +27,57 @@ impl<'a> Compressor<'a> {
         self.build_with_symbols_and_scopes(symbols, scopes, program);
     }
 
+    /// Returns the symbol table and scope tree, updated for the changes made to `program`.
     pub fn build_with_symbols_and_scopes(
         self,
         symbols: SymbolTable,
         scopes: ScopeTree,
         program: &mut Program<'a>,
-    ) {
+    ) -> (SymbolTable, ScopeTree) {
         let mut ctx = TraverseCtx::new(scopes, symbols, self.allocator);
         // Run separate AST passes
-        // TODO: inline variables
         self.remove_syntax(program, &mut ctx);
+        self.inline_constants(program, &mut ctx);
         self.fold_constants(program, &mut ctx);
+        self.minimize_loops(program, &mut ctx);
         self.remove_dead_code(program, &mut ctx);
+        self.if_return(program, &mut ctx);
         // TODO: StatementFusion
         self.substitute_alternate_syntax(program, &mut ctx);
         self.collapse(program, &mut ctx);
+        ctx.scoping.into_symbol_table_and_scope_tree()
     }
 
     fn remove_syntax(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.remove_syntax {
-            RemoveSyntax::new(ctx.ast, self.options).build(program, ctx);
+            RemoveSyntax::new(ctx.ast, self.options.clone()).build(program, ctx);
+        }
+    }
+
+    fn inline_constants(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.inline_constants {
+            InlineConstants::new(ctx.ast).build(program, ctx);
         }
     }
 
     fn fold_constants(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.fold_constants {
-            FoldConstants::new(ctx.ast).with_evaluate(self.options.evaluate).build(program, ctx);
+            FoldConstants::new(ctx.ast)
+                .with_evaluate(self.options.evaluate)
+                .with_known_globals(self.options.known_globals.clone())
+                .build(program, ctx);
+        }
+    }
+
+    fn minimize_loops(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.loops {
+            MinimizeLoops::new(ctx.ast).build(program, ctx);
         }
     }
 
     fn substitute_alternate_syntax(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.substitute_alternate_syntax {
-            SubstituteAlternateSyntax::new(ctx.ast, self.options).build(program, ctx);
+            SubstituteAlternateSyntax::new(ctx.ast, self.options.clone()).build(program, ctx);
         }
     }
 
@@ -67,9 +87,15 @@ impl<'a> Compressor<'a> {
         }
     }
 
+    fn if_return(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.if_return {
+            IfReturn::new(ctx.ast).build(program, ctx);
+        }
+    }
+
     fn collapse(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.collapse {
-            Collapse::new(ctx.ast, self.options).build(program, ctx);
+            Collapse::new(ctx.ast, self.options.clone()).build(program, ctx);
         }
     }
 }
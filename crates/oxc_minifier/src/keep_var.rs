@@ -1,9 +1,10 @@
 use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, Visit};
+use oxc_semantic::SymbolId;
 use oxc_span::{Atom, Span, SPAN};
 
 pub struct KeepVar<'a> {
     ast: AstBuilder<'a>,
-    vars: std::vec::Vec<(Atom<'a>, Span)>,
+    vars: std::vec::Vec<(Atom<'a>, Span, Option<SymbolId>)>,
 }
 
 impl<'a> Visit<'a> for KeepVar<'a> {
@@ -35,7 +36,7 @@ impl<'a> Visit<'a> for KeepVar<'a> {
             Statement::VariableDeclaration(decl) => {
                 if decl.kind.is_var() {
                     decl.bound_names(&mut |ident| {
-                        self.vars.push((ident.name.clone(), ident.span));
+                        self.vars.push((ident.name.clone(), ident.span, ident.symbol_id.get()));
                     });
                 }
             }
@@ -55,8 +56,11 @@ impl<'a> KeepVar<'a> {
         }
 
         let kind = VariableDeclarationKind::Var;
-        let decls = self.ast.vec_from_iter(self.vars.into_iter().map(|(name, span)| {
-            let binding_kind = self.ast.binding_pattern_kind_binding_identifier(span, name);
+        let decls = self.ast.vec_from_iter(self.vars.into_iter().map(|(name, span, symbol_id)| {
+            // The declaration is moved, so it keeps the symbol of the removed one
+            let ident = self.ast.binding_identifier(span, name);
+            ident.symbol_id.set(symbol_id);
+            let binding_kind = self.ast.binding_pattern_kind_from_binding_identifier(ident);
             let id =
                 self.ast.binding_pattern::<Option<TSTypeAnnotation>>(binding_kind, None, false);
             self.ast.variable_declarator(span, kind, id, None, false)
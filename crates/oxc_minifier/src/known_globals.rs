@@ -0,0 +1,96 @@
+use oxc_span::CompactStr;
+use rustc_hash::FxHashMap;
+
+/// The global variables of the environment the minified code runs in, used to fold
+/// `typeof` checks of platforms, e.g. `typeof window !== "undefined"` is `false` in Node.js.
+///
+/// Only unresolved references to the globals are folded, variables declared in the program with
+/// the same name are left alone. Nothing is known by default.
+#[derive(Debug, Clone, Default)]
+pub struct KnownGlobals {
+    /// Global name => result of `typeof`
+    typeofs: FxHashMap<CompactStr, CompactStr>,
+}
+
+impl KnownGlobals {
+    /// Globals of browsers, both of windows and of Web Workers.
+    ///
+    /// `window` and `document` only exist in windows, so they are not known.
+    pub fn browser() -> Self {
+        Self::default()
+            .with_typeof("self", "object")
+            .with_typeof("navigator", "object")
+            .with_typeof("process", "undefined")
+            .with_typeof("global", "undefined")
+    }
+
+    /// Globals of Node.js.
+    pub fn node() -> Self {
+        Self::default()
+            .with_typeof("process", "object")
+            .with_typeof("global", "object")
+            .with_typeof("Buffer", "function")
+            .with_typeof("setImmediate", "function")
+            .with_typeof("window", "undefined")
+            .with_typeof("self", "undefined")
+            .with_typeof("document", "undefined")
+            .with_typeof("location", "undefined")
+    }
+
+    /// Globals of the platform all of `engines` run on, e.g. the names of the browsers in
+    /// browserslist targets. Nothing is known if the engines run on different platforms, or if
+    /// any of them is not a known browser or Node.js, e.g. Electron, which has the globals of
+    /// both.
+    pub fn for_engines<'s, I: IntoIterator<Item = &'s str>>(engines: I) -> Self {
+        let mut is_node = None;
+        for engine in engines {
+            let engine_is_node = match engine {
+                "node" => true,
+                "chrome" | "edge" | "firefox" | "safari" | "opera" | "ie" | "ios" | "samsung"
+                | "android" => false,
+                _ => return Self::default(),
+            };
+            if *is_node.get_or_insert(engine_is_node) != engine_is_node {
+                return Self::default();
+            }
+        }
+        match is_node {
+            Some(true) => Self::node(),
+            Some(false) => Self::browser(),
+            None => Self::default(),
+        }
+    }
+
+    /// Whether nothing is known.
+    pub fn is_empty(&self) -> bool {
+        self.typeofs.is_empty()
+    }
+
+    /// Set the result of `typeof name`, e.g. `"undefined"` for a global which does not exist.
+    #[must_use]
+    pub fn with_typeof<N: Into<CompactStr>, T: Into<CompactStr>>(
+        mut self,
+        name: N,
+        value: T,
+    ) -> Self {
+        let value = value.into();
+        debug_assert!(matches!(
+            value.as_str(),
+            "undefined"
+                | "object"
+                | "boolean"
+                | "number"
+                | "bigint"
+                | "string"
+                | "symbol"
+                | "function"
+        ));
+        self.typeofs.insert(name.into(), value);
+        self
+    }
+
+    /// The result of `typeof name`, if known.
+    pub fn get_typeof(&self, name: &str) -> Option<&str> {
+        self.typeofs.get(name).map(CompactStr::as_str)
+    }
+}
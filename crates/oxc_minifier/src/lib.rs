@@ -5,6 +5,7 @@
 mod ast_passes;
 mod compressor;
 mod keep_var;
+mod known_globals;
 mod node_util;
 mod options;
 mod plugins;
@@ -18,11 +19,12 @@ use oxc_mangler::Mangler;
 pub use crate::{
     ast_passes::{CompressorPass, RemoveDeadCode, RemoveSyntax},
     compressor::Compressor,
+    known_globals::KnownGlobals,
     options::CompressOptions,
     plugins::*,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct MinifierOptions {
     pub mangle: bool,
     pub compress: CompressOptions,
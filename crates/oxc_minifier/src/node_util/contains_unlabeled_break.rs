@@ -0,0 +1,48 @@
+use oxc_ast::{ast::*, Visit};
+use oxc_syntax::scope::ScopeFlags;
+
+/// Whether a statement contains a `break` without a label which exits the enclosing `switch`
+/// statement, e.g. `if (a) break;`.
+///
+/// Breaks in nested loops, `switch` statements and functions are not counted.
+pub trait ContainsUnlabeledBreak {
+    fn contains_unlabeled_break(&self) -> bool;
+}
+
+impl<'a> ContainsUnlabeledBreak for Statement<'a> {
+    fn contains_unlabeled_break(&self) -> bool {
+        let mut finder = UnlabeledBreakFinder { found: false };
+        finder.visit_statement(self);
+        finder.found
+    }
+}
+
+struct UnlabeledBreakFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for UnlabeledBreakFinder {
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_none() {
+            self.found = true;
+        }
+    }
+
+    fn visit_do_while_statement(&mut self, _it: &DoWhileStatement<'a>) {}
+
+    fn visit_while_statement(&mut self, _it: &WhileStatement<'a>) {}
+
+    fn visit_for_statement(&mut self, _it: &ForStatement<'a>) {}
+
+    fn visit_for_in_statement(&mut self, _it: &ForInStatement<'a>) {}
+
+    fn visit_for_of_statement(&mut self, _it: &ForOfStatement<'a>) {}
+
+    fn visit_switch_statement(&mut self, _it: &SwitchStatement<'a>) {}
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
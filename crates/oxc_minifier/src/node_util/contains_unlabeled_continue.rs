@@ -0,0 +1,47 @@
+use oxc_ast::{ast::*, Visit};
+use oxc_syntax::scope::ScopeFlags;
+
+/// Whether a statement contains a `continue` without a label which continues the enclosing loop,
+/// e.g. `if (a) continue;`.
+///
+/// Continues in nested loops and functions are not counted, continues in nested `switch`
+/// statements are.
+pub trait ContainsUnlabeledContinue {
+    fn contains_unlabeled_continue(&self) -> bool;
+}
+
+impl<'a> ContainsUnlabeledContinue for Statement<'a> {
+    fn contains_unlabeled_continue(&self) -> bool {
+        let mut finder = UnlabeledContinueFinder { found: false };
+        finder.visit_statement(self);
+        finder.found
+    }
+}
+
+struct UnlabeledContinueFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for UnlabeledContinueFinder {
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_none() {
+            self.found = true;
+        }
+    }
+
+    fn visit_do_while_statement(&mut self, _it: &DoWhileStatement<'a>) {}
+
+    fn visit_while_statement(&mut self, _it: &WhileStatement<'a>) {}
+
+    fn visit_for_statement(&mut self, _it: &ForStatement<'a>) {}
+
+    fn visit_for_in_statement(&mut self, _it: &ForInStatement<'a>) {}
+
+    fn visit_for_of_statement(&mut self, _it: &ForOfStatement<'a>) {}
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+}
@@ -1,4 +1,6 @@
 mod check_for_state_change;
+mod contains_unlabeled_break;
+mod contains_unlabeled_continue;
 mod is_literal_value;
 mod may_have_side_effects;
 mod number_value;
@@ -7,22 +9,55 @@ use std::borrow::Cow;
 
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, AstBuilder};
 use oxc_semantic::{ScopeTree, SymbolTable};
+use oxc_span::SPAN;
 use oxc_syntax::operator::{AssignmentOperator, LogicalOperator, UnaryOperator};
 
-pub use self::{may_have_side_effects::MayHaveSideEffects, number_value::NumberValue};
+pub use self::{
+    contains_unlabeled_break::ContainsUnlabeledBreak,
+    contains_unlabeled_continue::ContainsUnlabeledContinue,
+    may_have_side_effects::MayHaveSideEffects, number_value::NumberValue,
+};
 
 pub fn is_exact_int64(num: f64) -> bool {
     num.fract() == 0.0
 }
 
+/// `let`, `const` and `using` declarations, and the declarations of functions, classes and
+/// TypeScript, which are scoped to their block.
+pub fn is_lexical_declaration(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::VariableDeclaration(decl) => !decl.kind.is_var(),
+        stmt => stmt.is_declaration(),
+    }
+}
+
+/// Negate a condition, `a` => `!a`, `!a` => `a`.
+///
+/// Only for boolean contexts, e.g. the test of an `if` statement, as `!!a` is not `a`.
+pub fn negate_condition<'a>(ast: AstBuilder<'a>, expr: Expression<'a>) -> Expression<'a> {
+    match expr {
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+            unary.unbox().argument
+        }
+        expr => ast.expression_unary(SPAN, UnaryOperator::LogicalNot, expr),
+    }
+}
+
 pub trait NodeUtil {
     fn symbols(&self) -> &SymbolTable;
 
     #[allow(unused)]
     fn scopes(&self) -> &ScopeTree;
 
+    /// Whether `ident` refers to the builtin global variable of the same name, e.g. `NaN`, and
+    /// not to a variable declared in the program.
+    fn is_builtin_global_reference(&self, ident: &IdentifierReference) -> bool {
+        oxc_globals::is_builtin(&ident.name)
+            && ident.reference_id.get().is_some_and(|id| self.symbols().is_global_reference(id))
+    }
+
     /// port from [closure compiler](https://github.com/google/closure-compiler/blob/a4c880032fba961f7a6c06ef99daa3641810bfdd/src/com/google/javascript/jscomp/AbstractPeepholeOptimization.java#L104-L114)
     /// Returns the number value of the node if it has one and it cannot have side effects.
     fn get_side_free_number_value(&self, expr: &Expression) -> Option<NumberValue> {
@@ -68,6 +103,16 @@ pub trait NodeUtil {
         None
     }
 
+    /// Gets the boolean value of a node, or `None` if it cannot be determined or the node may
+    /// have side effects.
+    fn get_side_free_boolean_value(&self, expr: &Expression) -> Option<bool> {
+        let value = self.get_boolean_value(expr);
+        if value.is_some() && !expr.may_have_side_effects() {
+            return value;
+        }
+        None
+    }
+
     /// port from [closure compiler](https://github.com/google/closure-compiler/blob/a4c880032fba961f7a6c06ef99daa3641810bfdd/src/com/google/javascript/jscomp/NodeUtil.java#L109)
     /// Gets the boolean value of a node that represents an expression, or `None` if no
     /// such value can be determined by static analysis.
@@ -95,19 +140,13 @@ pub trait NodeUtil {
                     .and_then(|quasi| quasi.value.cooked.as_ref())
                     .map(|cooked| !cooked.is_empty())
             }
-            Expression::Identifier(ident) => match ident.name.as_str() {
-                "NaN" => Some(false),
-                "Infinity" => Some(true),
-                "undefined"
-                    if ident
-                        .reference_id
-                        .get()
-                        .is_some_and(|id| self.symbols().is_global_reference(id)) =>
-                {
-                    Some(false)
+            Expression::Identifier(ident) if self.is_builtin_global_reference(ident) => {
+                match ident.name.as_str() {
+                    "NaN" | "undefined" => Some(false),
+                    "Infinity" => Some(true),
+                    _ => None,
                 }
-                _ => None,
-            },
+            }
             Expression::AssignmentExpression(assign_expr) => {
                 match assign_expr.operator {
                     AssignmentOperator::LogicalAnd | AssignmentOperator::LogicalOr => None,
@@ -216,11 +255,13 @@ pub trait NodeUtil {
                 }
             }
             Expression::NullLiteral(_) => Some(NumberValue::Number(0.0)),
-            Expression::Identifier(ident) => match ident.name.as_str() {
-                "Infinity" => Some(NumberValue::PositiveInfinity),
-                "NaN" | "undefined" => Some(NumberValue::NaN),
-                _ => None,
-            },
+            Expression::Identifier(ident) if self.is_builtin_global_reference(ident) => {
+                match ident.name.as_str() {
+                    "Infinity" => Some(NumberValue::PositiveInfinity),
+                    "NaN" | "undefined" => Some(NumberValue::NaN),
+                    _ => None,
+                }
+            }
             // TODO: will be implemented in next PR, just for test pass now.
             Expression::StringLiteral(string_literal) => string_literal
                 .value
@@ -289,16 +330,9 @@ pub trait NodeUtil {
                 Some(Cow::Borrowed(string_literal.value.as_str()))
             }
             Expression::TemplateLiteral(template_literal) => {
-                // TODO: I don't know how to iterate children of TemplateLiteral in order,so only checkout string like `hi`.
-                // Closure-compiler do more: [case TEMPLATELIT](https://github.com/google/closure-compiler/blob/e13f5cd0a5d3d35f2db1e6c03fdf67ef02946009/src/com/google/javascript/jscomp/NodeUtil.java#L241-L256).
-                template_literal
-                    .quasis
-                    .first()
-                    .filter(|quasi| quasi.tail)
-                    .and_then(|quasi| quasi.value.cooked.as_ref())
-                    .map(|cooked| Cow::Borrowed(cooked.as_str()))
+                self.get_template_literal_string_value(template_literal)
             }
-            Expression::Identifier(ident) => {
+            Expression::Identifier(ident) if self.is_builtin_global_reference(ident) => {
                 let name = ident.name.as_str();
                 if matches!(name, "undefined" | "Infinity" | "NaN") {
                     Some(Cow::Borrowed(name))
@@ -336,15 +370,81 @@ pub trait NodeUtil {
                     _ => None,
                 }
             }
-            Expression::ArrayExpression(_) => {
-                // TODO: https://github.com/google/closure-compiler/blob/e13f5cd0a5d3d35f2db1e6c03fdf67ef02946009/src/com/google/javascript/jscomp/NodeUtil.java#L302-L303
-                None
-            }
-            Expression::ObjectExpression(_) => Some(Cow::Borrowed("[object Object]")),
+            // `String([1, null, [2, 3]])` is `"1,,2,3"`
+            Expression::ArrayExpression(array_expr) => array_expr
+                .elements
+                .iter()
+                .map(|element| self.get_array_element_string_value(element))
+                .collect::<Option<Vec<_>>>()
+                .map(|values| Cow::Owned(values.join(","))),
+            // `String({ a: 1 })` is `"[object Object]"`, unless the object may override its
+            // conversion, e.g. `{ toString() { return "x" } }`
+            Expression::ObjectExpression(object_expr) => object_expr
+                .properties
+                .iter()
+                .all(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        property.kind == PropertyKind::Init
+                            && !property.method
+                            && !property.computed
+                            && !matches!(
+                                property.key.static_name().as_deref(),
+                                Some("toString" | "valueOf" | "__proto__")
+                            )
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => false,
+                })
+                .then_some(Cow::Borrowed("[object Object]")),
             _ => None,
         }
     }
 
+    /// Gets the value of a template literal as a String, or `None` if it cannot be converted.
+    /// This method does not consider whether the expressions of `template_literal` may have side
+    /// effects.
+    fn get_template_literal_string_value<'a>(
+        &self,
+        template_literal: &'a TemplateLiteral,
+    ) -> Option<Cow<'a, str>> {
+        if template_literal.expressions.is_empty() {
+            return template_literal
+                .quasis
+                .first()
+                .and_then(|quasi| quasi.value.cooked.as_ref())
+                .map(|cooked| Cow::Borrowed(cooked.as_str()));
+        }
+        // `a${b}c` is `"a" + String(b) + "c"`
+        let mut value = String::new();
+        for (i, quasi) in template_literal.quasis.iter().enumerate() {
+            value.push_str(quasi.value.cooked.as_ref()?);
+            if let Some(expr) = template_literal.expressions.get(i) {
+                value.push_str(&self.get_string_value(expr)?);
+            }
+        }
+        Some(Cow::Owned(value))
+    }
+
+    /// Gets the value of an array element as a String when the array is joined, where `null` and
+    /// `undefined` are empty, or `None` if it cannot be converted.
+    /// This method does not consider whether `element` may have side effects.
+    fn get_array_element_string_value<'a>(
+        &self,
+        element: &'a ArrayExpressionElement,
+    ) -> Option<Cow<'a, str>> {
+        match element {
+            ArrayExpressionElement::SpreadElement(_) => None,
+            ArrayExpressionElement::Elision(_) => Some(Cow::Borrowed("")),
+            element => {
+                let expr = element.to_expression();
+                if expr.is_null() || expr.is_undefined() || expr.is_void() {
+                    Some(Cow::Borrowed(""))
+                } else {
+                    self.get_string_value(expr)
+                }
+            }
+        }
+    }
+
     /// port from [closure compiler](https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/NodeUtil.java#L540)
     fn get_string_bigint_value(&self, raw_string: &str) -> Option<BigInt> {
         if raw_string.contains('\u{000b}') {
@@ -1,7 +1,10 @@
-#[derive(Debug, Clone, Copy)]
+use crate::KnownGlobals;
+
+#[derive(Debug, Clone)]
 pub struct CompressOptions {
     pub remove_syntax: bool,
     pub substitute_alternate_syntax: bool,
+    pub inline_constants: bool,
     pub fold_constants: bool,
     pub remove_dead_code: bool,
     pub collapse: bool,
@@ -21,6 +24,17 @@ pub struct CompressOptions {
     /// Default `false`
     pub drop_console: bool,
 
+    /// Remove calls to these functions when their results are unused, e.g.
+    /// `["invariant", "console.assert"]`.
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
+    /// Also drop arguments with side effects of calls removed by `drop_console` and `pure_funcs`.
+    ///
+    /// Default `false`
+    pub unsafe_drop_args: bool,
+
     /// Attempt to evaluate constant expressions
     ///
     /// Default `true`
@@ -31,15 +45,45 @@ pub struct CompressOptions {
     /// Default `true`
     pub join_vars: bool,
 
-    /// Optimizations for do, while and for loops when we can statically determine the condition
+    /// Collapse variables which are only used once into their use in the next statement, e.g.
+    /// `var a = foo(); bar(a)` → `bar(foo())`.
+    ///
+    /// Default `true`
+    pub collapse_vars: bool,
+
+    /// Optimizations for `if` statements which return, e.g. `if (a) return; b()` → `if (!a) b()`
+    /// at the end of functions, and `if (a) return b; else c()` → `if (a) return b; c()`. Also
+    /// merges nested blocks without lexical declarations.
+    ///
+    /// Default `true`
+    pub if_return: bool,
+
+    /// Optimizations for do, while and for loops when we can statically determine the condition,
+    /// e.g. `while (a) b()` → `for (; a;) b()` and `for (;;) { b(); if (a) break }` →
+    /// `do b(); while (!a)`
     ///
     /// Default `true`
     pub loops: bool,
 
+    /// Remove duplicate and unreachable `switch` cases, and replace small `switch` statements
+    /// with `if` statements.
+    ///
+    /// Default `true`
+    pub switches: bool,
+
     /// Transforms `typeof foo == "undefined" into `foo === void 0`
     ///
     /// Default `true`
     pub typeofs: bool,
+
+    /// Global variables of the platform the code runs in, which fold `typeof` checks, e.g.
+    /// `typeof window !== "undefined"` → `false` with [`KnownGlobals::node`].
+    ///
+    /// When nothing is known, `oxc::Compiler` uses [`KnownGlobals::for_engines`] with the
+    /// targets of the transform.
+    ///
+    /// Default nothing is known
+    pub known_globals: KnownGlobals,
 }
 
 impl Default for CompressOptions {
@@ -47,16 +91,23 @@ impl Default for CompressOptions {
         Self {
             remove_syntax: true,
             substitute_alternate_syntax: true,
+            inline_constants: true,
             fold_constants: true,
             remove_dead_code: true,
             collapse: true,
             booleans: true,
             drop_debugger: true,
             drop_console: false,
+            pure_funcs: vec![],
+            unsafe_drop_args: false,
             evaluate: true,
             join_vars: true,
+            collapse_vars: true,
+            if_return: true,
             loops: true,
+            switches: true,
             typeofs: true,
+            known_globals: KnownGlobals::default(),
         }
     }
 }
@@ -69,7 +120,10 @@ impl CompressOptions {
             drop_console: true,
             evaluate: true,
             join_vars: true,
+            collapse_vars: true,
+            if_return: true,
             loops: true,
+            switches: true,
             typeofs: true,
             ..Self::default()
         }
@@ -79,16 +133,23 @@ impl CompressOptions {
         Self {
             remove_syntax: false,
             substitute_alternate_syntax: false,
+            inline_constants: false,
             fold_constants: false,
             remove_dead_code: false,
             collapse: false,
             booleans: false,
             drop_debugger: false,
             drop_console: false,
+            pure_funcs: vec![],
+            unsafe_drop_args: false,
             evaluate: false,
             join_vars: false,
+            collapse_vars: false,
+            if_return: false,
             loops: false,
+            switches: false,
             typeofs: false,
+            known_globals: KnownGlobals::default(),
         }
     }
 
@@ -0,0 +1,77 @@
+use crate::CompressOptions;
+
+fn test(source_text: &str, expected: &str) {
+    let options =
+        CompressOptions { collapse: true, collapse_vars: true, ..CompressOptions::all_false() };
+    crate::test(source_text, expected, options);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn collapse() {
+    test("function f(bar) { var a = foo(); bar(a) }", "function f(bar) { bar(foo()) }");
+    test("function f() { let a = foo(); return a.b }", "function f() { return foo().b }");
+    test("function f() { const a = foo(); if (a) bar() }", "function f() { if (foo()) bar() }");
+    test("function f() { var a = foo(); return 1 + a }", "function f() { return 1 + foo() }");
+    test("function f(b, bar) { var a = foo(); bar(b, a) }", "function f(b, bar) { bar(b, foo()) }");
+    test("function f(x) { var a = foo(); x = a }", "function f(x) { x = foo() }");
+    test("function f(bar) { { let a = foo(); bar(a) } }", "function f(bar) { { bar(foo()) } }");
+    // Initializers without side effects can be moved past reads of globals and properties
+    test("function f() { var a = b; console.log(a) }", "function f() { console.log(b) }");
+    test(
+        "function f() { var a = 1 + b; return o.count + a }",
+        "function f() { return o.count + (1 + b) }",
+    );
+}
+
+#[test]
+fn collapse_several() {
+    test(
+        "function f(bar) { const a = foo(), b = baz(); bar(a, b) }",
+        "function f(bar) { bar(foo(), baz()) }",
+    );
+    test(
+        "function f(bar) { var a = foo(); var b = baz(); bar(a, b) }",
+        "function f(bar) { bar(foo(), baz()) }",
+    );
+    test(
+        "function f(bar) { var c = 1, a = foo(); bar(a) }",
+        "function f(bar) { var c = 1; bar(foo()) }",
+    );
+}
+
+#[test]
+fn not_collapsed() {
+    // Top level variables of scripts are global.
+    test_same("var a = foo(); bar(a)");
+    test_same("{ var a = foo(); bar(a) }");
+    test_same("function f() { var a = foo(); bar(a, a) }");
+    test_same("function f() { var a; bar(a) }");
+    test_same("function f() { let a = foo(); a = 1; bar(a) }");
+    // Side effects between the declaration and the use
+    test_same("function f() { var a = foo(); baz(); bar(a) }");
+    test_same("function f() { var a = foo(); bar(baz(), a) }");
+    test_same("function f(b) { b = 2; var a = foo(); bar(b, a) }");
+    test_same("function f() { var a = foo(); bar(...b, a) }");
+    // Reads of globals and properties which the initializer may change
+    test_same("function f() { var a = o.inc(); return o.count + a }");
+    test_same("function f() { var a = foo(); bar(x, a) }");
+    test_same("function f() { var a = foo(); bar(a) }");
+    test_same("function f() { var a = foo(); x.y = a }");
+    // Conditionally evaluated uses
+    test_same("function f() { var a = foo(); b && bar(a) }");
+    test_same("function f() { var a = foo(); b ? bar(a) : 0 }");
+    test_same("function f() { var a = foo(); b?.(a) }");
+    test_same("function f() { var a = foo(); return () => a }");
+    // Parameters aliased by `arguments`
+    test_same("function f(a, foo) { var a = foo(); return a + arguments[0] }");
+    // Bindings accessed by `eval` or shadowed by `with`
+    test_same("function f(b) { var a = b; return a + eval('a') }");
+    test_same("function f(b, o) { var a = b; g(a); with (o) h() }");
+    // `this` of the call
+    test_same("function f() { var a = b.c; a() }");
+    test_same("function f() { using a = foo(); bar(a) }");
+}
@@ -1,5 +1,7 @@
 //! <https://github.com/google/closure-compiler/blob/master/test/com/google/javascript/jscomp/PeepholeFoldConstantsTest.java>
 
+use oxc_minifier::KnownGlobals;
+
 use crate::CompressOptions;
 
 fn test(source_text: &str, expected: &str) {
@@ -15,6 +17,20 @@ fn test_same(source_text: &str) {
     test(source_text, source_text);
 }
 
+fn test_evaluate(source_text: &str, expected: &str) {
+    let options = CompressOptions {
+        remove_syntax: true,
+        fold_constants: true,
+        evaluate: true,
+        ..CompressOptions::all_false()
+    };
+    crate::test(source_text, expected, options);
+}
+
+fn test_evaluate_same(source_text: &str) {
+    test_evaluate(source_text, source_text);
+}
+
 // Oxc
 
 #[test]
@@ -35,6 +51,97 @@ fn tagged_template() {
     test_same("(true ? o.f : false)``");
 }
 
+#[test]
+fn array_join() {
+    test_evaluate("x = ['a', 'b'].join('-')", "x = 'a-b'");
+    test_evaluate("x = ['a', 'b'].join()", "x = 'a,b'");
+    test_evaluate("x = ['a', 'b'].join(undefined)", "x = 'a,b'");
+    test_evaluate("x = [1, null, void 0, , 'a'].join()", "x = '1,,,,a'");
+    test_evaluate("x = [[1, 2], 3].join('-')", "x = '1,2-3'");
+    test_evaluate("x = [].join()", "x = ''");
+    test_evaluate("x = [{}, { a: 1 }].join()", "x = '[object Object],[object Object]'");
+    test_evaluate_same("x = [a].join()");
+    test_evaluate_same("x = [foo()].join()");
+    test_evaluate_same("x = [...a].join()");
+    test_evaluate_same("x = ['a'].join(b)");
+    test_evaluate_same("x = ['a'].join?.()");
+    test_evaluate_same("x = [{ toString() { return 'x' } }].join()");
+    test_evaluate_same("x = [{ toString: () => 'x' }].join()");
+    test_evaluate_same("x = [{ get a() { return 1 } }].join()");
+    test_evaluate_same("x = [{ [a]: 1 }].join()");
+    test_evaluate_same("x = [{ __proto__: null }].join()");
+    test_evaluate_same("x = [{ ...a }].join()");
+    test_same("x = ['a', 'b'].join('-')");
+}
+
+#[test]
+fn length() {
+    test_evaluate("x = 'foo'.length", "x = 3");
+    test_evaluate("x = '😀'.length", "x = 2");
+    test_evaluate("x = `foo`.length", "x = 3");
+    test_evaluate("x = [1, , 3].length", "x = 3");
+    test_evaluate("x = [].length", "x = 0");
+    test_evaluate_same("x = [foo()].length");
+    test_evaluate_same("x = [...a].length");
+    test_evaluate_same("delete 'foo'.length");
+    test_same("x = 'foo'.length");
+}
+
+#[test]
+fn template_literal() {
+    test_evaluate("x = `a${1}b${'c'}`", "x = 'a1bc'");
+    test_evaluate("x = `${[1, 2]}`", "x = '1,2'");
+    test_evaluate("x = `a${null}${true}`", "x = 'anulltrue'");
+    test_evaluate_same("x = `a${b}`");
+    test_evaluate_same("x = `a${foo()}`");
+    test_evaluate_same("x = tag`a${1}`");
+}
+
+#[test]
+fn string_concatenation() {
+    test_evaluate("x = 'a' + 'b' + 'c'", "x = 'abc'");
+    test_evaluate("x = a + 'b' + 'c'", "x = a + 'bc'");
+    test_evaluate("x = a + 'b' + 'c' + 'd'", "x = a + 'bcd'");
+    test_evaluate("x = a + 'b' + 1", "x = a + 'b1'");
+    test_evaluate_same("x = a + 1 + 'c'");
+    test_evaluate_same("x = a + b + 'c'");
+}
+
+#[test]
+fn object_property() {
+    test_evaluate("x = Object.freeze({ a: 1, b: 'c' }).b", "x = 'c'");
+    test_evaluate("x = ({ a: 1, a: 2 }).a", "x = 2");
+    test_evaluate("x = ({ 'a': 1 }).a", "x = 1");
+    test_evaluate_same("x = ({ a: 1 }).b");
+    test_evaluate_same("x = ({ a: {} }).a");
+    test_evaluate_same("x = Object.freeze({ a: foo() }).a");
+    test_evaluate_same("x = ({ get a() { return 1 } }).a");
+    test_evaluate_same("x = ({ ...b, a: 1 }).a");
+    test_evaluate_same("x = ({ __proto__: 1 }).__proto__");
+    test_evaluate_same("var Object; x = Object.freeze({ a: 1 }).a");
+}
+
+#[test]
+fn switch_statement() {
+    test("switch (1) { case 1: a(); break; case 2: b() }", "{ a() }");
+    test("switch ('a') { case 'a': a() }", "{ a() }");
+    test(
+        "switch (2) { case 1: a(); case 2: b(); case 3: c(); break; default: d() }",
+        "{ b(); c() }",
+    );
+    test("switch (3) { case 1: a(); break; default: d(); case 2: b(); break }", "{ d(); b() }");
+    test("switch (3) { case 1: a(); break; case 2: b() }", "{}");
+    test("switch (1) { case 2: var a = 1; break; case 1: b() }", "{ b(); var a }");
+    test("A: switch (1) { case 1: if (a) break A; b() }", "A: { if (a) break A; b() }");
+    test("switch (1) { case 1: for (;;) break; b() }", "{ for (;;) break; b() }");
+
+    test_same("switch (a) { case 1: b() }");
+    test_same("switch (1) { case 1: if (a) break; b() }");
+    test_same("switch (1) { case foo(): a(); case 1: b() }");
+    test_same("switch (1) { case a: a(); case 1: b() }");
+    test_same("switch (1) { case 1: let a; b(a) }");
+}
+
 // Google Closure Compiler
 
 #[test]
@@ -472,10 +579,12 @@ fn test_nan_comparison() {
     test("NaN === x", "false");
     test("x !== NaN", "true");
     test_same("NaN==foo()");
+
+    // `NaN` is not the builtin global
+    test_same("function f(NaN) { return NaN < 1 }");
 }
 
 #[test]
-#[ignore]
 fn js_typeof() {
     test("x = typeof 1", "x='number'");
     test("x = typeof 'foo'", "x='string'");
@@ -494,6 +603,68 @@ fn js_typeof() {
     test_same("x=typeof {bathwater:baby()}");
 }
 
+#[test]
+fn typeof_known_globals() {
+    let test = |source_text: &str, expected: &str, known_globals: KnownGlobals| {
+        let options = CompressOptions { known_globals, ..CompressOptions::dead_code_elimination() };
+        crate::test(source_text, expected, options);
+    };
+
+    test("x = typeof window", "x = 'undefined'", KnownGlobals::node());
+    test("x = typeof process", "x = 'object'", KnownGlobals::node());
+    test("x = typeof navigator", "x = 'object'", KnownGlobals::browser());
+    test("x = typeof process", "x = 'undefined'", KnownGlobals::browser());
+    // `window` and `document` do not exist in Web Workers
+    test("x = typeof window", "x = typeof window", KnownGlobals::browser());
+    test("x = typeof document", "x = typeof document", KnownGlobals::browser());
+    test("x = typeof window", "x = typeof window", KnownGlobals::default());
+    test("x = typeof foo", "x = typeof foo", KnownGlobals::node());
+    test("x = typeof foo", "x = 'number'", KnownGlobals::default().with_typeof("foo", "number"));
+
+    test(
+        "if (typeof window !== 'undefined') { browser() } else { node() }",
+        "{ node() }",
+        KnownGlobals::node(),
+    );
+    test(
+        "if (typeof process === 'undefined') { browser() } else { node() }",
+        "{ browser() }",
+        KnownGlobals::browser(),
+    );
+    test(
+        "x = typeof process !== 'undefined' && process.env",
+        "x = process.env",
+        KnownGlobals::node(),
+    );
+
+    // Not the global variable
+    test(
+        "function f(window) { return typeof window }",
+        "function f(window) { return typeof window }",
+        KnownGlobals::node(),
+    );
+    test("window = {}; x = typeof window", "window = {}; x = typeof window", KnownGlobals::node());
+}
+
+#[test]
+fn known_globals_for_engines() {
+    let test = |engines: &[&str], source_text: &str, expected: &str| {
+        let options = CompressOptions {
+            known_globals: KnownGlobals::for_engines(engines.iter().copied()),
+            ..CompressOptions::dead_code_elimination()
+        };
+        crate::test(source_text, expected, options);
+    };
+
+    test(&["node"], "x = typeof window", "x = 'undefined'");
+    test(&["chrome", "firefox"], "x = typeof process", "x = 'undefined'");
+    test(&["chrome", "node"], "x = typeof process", "x = typeof process");
+    test(&["electron"], "x = typeof process", "x = typeof process");
+    test(&["deno"], "x = typeof process", "x = typeof process");
+    test(&["chrome", "deno"], "x = typeof process", "x = typeof process");
+    test(&[], "x = typeof process", "x = typeof process");
+}
+
 #[test]
 #[ignore]
 fn unary_ops() {
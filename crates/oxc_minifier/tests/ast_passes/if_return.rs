@@ -0,0 +1,102 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{CompressOptions, Compressor};
+use oxc_parser::Parser;
+use oxc_semantic::{post_transform_checker::check_semantic_consistency, SemanticBuilder};
+use oxc_span::SourceType;
+
+fn test(source_text: &str, expected: &str) {
+    let options = CompressOptions { if_return: true, ..CompressOptions::all_false() };
+    crate::test(source_text, expected, options);
+}
+
+/// Like `test`, and also check that the scope tree is kept consistent with the AST.
+fn test_scopes(source_text: &str, expected: &str) {
+    test(source_text, expected);
+
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new("").build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = CompressOptions { if_return: true, ..CompressOptions::all_false() };
+    let (symbols, scopes) = Compressor::new(&allocator, options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    if let Some(errors) = check_semantic_consistency(&symbols, &scopes, &program) {
+        let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        panic!("\nfor source {source_text:?}\n{}", errors.join("\n"));
+    }
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn if_return() {
+    test("function f() { if (a) return; b() }", "function f() { if (!a) b() }");
+    test("function f() { if (a) return; b(); c() }", "function f() { if (!a) { b(); c() } }");
+    test("function f() { if (!a) return; b() }", "function f() { if (a) b() }");
+    test("function f() { if (a) { return } b() }", "function f() { if (!a) b() }");
+    test(
+        "function f() { if (a) return; if (b) return; c() }",
+        "function f() { if (!a) if (!b) c() }",
+    );
+    test(
+        "function f() { if (a) return; var b = c(); d(b) }",
+        "function f() { if (!a) { var b = c(); d(b) } }",
+    );
+    test("() => { if (a) return; b() }", "() => { if (!a) b() }");
+    test("function f() { b(); return }", "function f() { b() }");
+    test("function f() { if (a) return }", "function f() { a }");
+}
+
+#[test]
+fn if_return_hoists_functions() {
+    test(
+        "function f() { if (a) return; b(); function g() {} }",
+        "function f() { function g() {} if (!a) b() }",
+    );
+}
+
+#[test]
+fn if_return_not_applied() {
+    test_same("function f() { if (a) return 1; b() }");
+    test_same("function f() { if (a) return; let b = c(); d(b) }");
+    test_same("function f() { if (a) return; class B {} d(B) }");
+    test_same("function f() { for (;;) { if (a) return; b() } }");
+}
+
+#[test]
+fn remove_else_after_jump() {
+    test("function f() { if (a) return b; else c() }", "function f() { if (a) return b; c() }");
+    test(
+        "function f() { if (a) { b(); return } else { c(); d() } }",
+        "function f() { if (a) { b(); return } c(); d() }",
+    );
+    test("for (;;) { if (a) break; else b() }", "for (;;) { if (a) break; b() }");
+    test("for (;;) { if (a) continue; else b() }", "for (;;) { if (a) continue; b() }");
+    test("function f() { if (a) throw b; else c() }", "function f() { if (a) throw b; c() }");
+    test_same("function f() { if (a) throw b; else { let c = d(); e(c) } }");
+    test_same("if (a) b(); else c()");
+}
+
+#[test]
+fn merge_blocks() {
+    test("{ a(); { b() } }", "a(); b()");
+    test("{ let a; { b() } }", "{ let a; b() }");
+    test("{ var a = 1 }", "var a = 1");
+    test_same("{ let a = 1 } { let a = 2 }");
+    test_same("{ function a() {} }");
+}
+
+#[test]
+fn merge_blocks_reparents_scopes() {
+    test_scopes("{ a(); { b(() => c) } }", "a(); b(() => c)");
+    test_scopes("{ a(function () { { b() } }) }", "a(function () { b() })");
+    test_scopes(
+        "for (;;) { if (a) break; else { b(() => c) } }",
+        "for (;;) { if (a) break; b(() => c) }",
+    );
+}
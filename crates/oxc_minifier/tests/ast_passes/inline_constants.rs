@@ -0,0 +1,85 @@
+use crate::CompressOptions;
+
+fn test(source_text: &str, expected: &str) {
+    let options = CompressOptions { inline_constants: true, ..CompressOptions::all_false() };
+    crate::test(source_text, expected, options);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn inline_literals() {
+    test("{ const a = 1; foo(a, a) }", "{ foo(1, 1) }");
+    test("{ const a = true; foo(a) }", "{ foo(true) }");
+    test("{ const a = null; foo(a) }", "{ foo(null) }");
+    test("{ const a = -1; foo(a) }", "{ foo(-1) }");
+    test("{ const a = void 0; foo(a) }", "{ foo(void 0) }");
+    test("{ const a = 'str'; foo(a) }", "{ foo('str') }");
+    test("{ const a = `str`; foo(a) }", "{ foo(`str`) }");
+    test("{ const a = 1n; foo(a) }", "{ foo(1n) }");
+    test("{ const a = 1, b = a; foo(b) }", "{ foo(1) }");
+    test("{ const a = 1; f = function () { return a } }", "{ f = function () { return 1 } }");
+    test("{ const a = 1, b = foo(); bar(a, b) }", "{ const b = foo(); bar(1, b) }");
+}
+
+#[test]
+fn size_heuristics() {
+    test("{ const a = 'long string'; foo(a) }", "{ foo('long string') }");
+    test_same("{ const a = 'long string'; foo(a, a) }");
+    test_same("{ const a = 1.5; foo(a, a) }");
+    test("{ const a = 999; foo(a, a) }", "{ foo(999, 999) }");
+}
+
+#[test]
+fn not_inlined() {
+    test_same("{ let a = 1; foo(a) }");
+    test_same("{ var a = 1; foo(a) }");
+    test_same("{ const a = foo(); bar(a) }");
+    test_same("{ const a = {}; foo(a) }");
+    test_same("{ const a = /x/; foo(a) }");
+    test_same("{ const { a } = 1; foo(a) }");
+    test_same("{ const a = 1; a = 2 }");
+    test_same("{ const a = `${b}`; foo(a) }");
+    // Uses before the declaration throw
+    test_same("{ foo(a); const a = 1 }");
+    test("{ foo(a); const a = 1; bar(a) }", "{ foo(a); const a = 1; bar(1) }");
+    test("{ const a = 1; foo(a, delete a) }", "{ const a = 1; foo(1, delete a) }");
+    // Functions declared after the declaration are hoisted, and may be called before it
+    test_same("{ f(); const a = 1; function f() { return a } }");
+    test_same("{ f(); const a = 1; function f(b = a) { return b } }");
+    test_same("{ f(); const a = 1; function f() { return () => a } }");
+    test("{ const a = 1; g(() => a); function f() {} }", "{ g(() => 1); function f() {} }");
+    // Bindings may be accessed by `eval` or shadowed by `with`
+    test_same("function f() { const a = 1; return eval('a') }");
+    test_same("function f() { const a = 1; return () => eval('a') }");
+    test_same("function f(o) { const a = 1; with (o) return a }");
+    // Declarations in a `case` are only initialized when the `case` runs
+    test_same("function f(x) { switch (x) { case 0: const b = 1; break; case 1: return b } }");
+    test(
+        "function f(x) { switch (x) { case 0: const b = 1; return b } }",
+        "function f(x) { switch (x) { case 0: return 1 } }",
+    );
+}
+
+#[test]
+fn top_level() {
+    // Top level bindings of scripts are globals
+    test("const a = 1; foo(a)", "const a = 1; foo(1)");
+    test("function f() { const a = 1; foo(a) }", "function f() { foo(1) }");
+    test("export const a = 1; foo(a)", "export const a = 1; foo(1)");
+    test("const a = 1; foo(a); export {}", "foo(1); export {}");
+}
+
+#[test]
+fn fold() {
+    let options =
+        CompressOptions { inline_constants: true, ..CompressOptions::dead_code_elimination() };
+    crate::test(
+        "{ const DEBUG = false; if (DEBUG) { foo() } else { bar() } }",
+        "{ { bar() } }",
+        options.clone(),
+    );
+    crate::test("const DEBUG = false; DEBUG && foo()", "const DEBUG = false; false", options);
+}
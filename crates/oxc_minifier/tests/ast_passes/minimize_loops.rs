@@ -0,0 +1,66 @@
+use crate::CompressOptions;
+
+fn test(source_text: &str, expected: &str) {
+    let options = CompressOptions { loops: true, ..CompressOptions::all_false() };
+    crate::test(source_text, expected, options);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn while_to_for() {
+    test("while (a) b()", "for (; a;) b()");
+    test("while (a) { b(); c() }", "for (; a;) { b(); c() }");
+    test("l: while (a) { b(); continue l }", "l: for (; a;) { b(); continue l }");
+}
+
+#[test]
+fn constant_conditions() {
+    test("while (true) b()", "for (;;) b()");
+    test("for (; true;) b()", "for (;;) b()");
+    test("for (; 1;) b()", "for (;;) b()");
+    test("do b(); while (true)", "for (;;) b()");
+    test("do { if (a) continue; b() } while (true)", "for (;;) { if (a) continue; b() }");
+    test("do b(); while (false)", "b()");
+    test("do { b(); c() } while (false)", "{ b(); c() }");
+    test("do { for (;;) break } while (false)", "{ for (;;) break }");
+    test_same("for (; a();) b()");
+    test_same("do { if (a) break; b() } while (false)");
+    test_same("do { if (a) continue; b() } while (false)");
+    test_same("l: do { if (a) continue l; b() } while (false)");
+}
+
+#[test]
+fn remove_empty_loops() {
+    test("while (false) b()", "");
+    test("for (; 0;) b()", "");
+    test("for (var i = 0; false; i++) b()", "var i = 0");
+    test("for (a(); false;) { var b = c() }", "a(); var b");
+    test("if (a) { while (false) b() }", "if (a) {}");
+    test("for (var i = () => 0; false;) b()", "var i = () => 0");
+    test_same("for (let i = 0; false; i++) b()");
+}
+
+#[test]
+fn move_break_to_test() {
+    test("for (;;) { if (a) break; b() }", "for (; !a;) { b() }");
+    test("for (;;) { if (!a) break; b(); c() }", "for (; a;) { b(); c() }");
+    test("for (i = 0; ; i++) { if (i > 9) { break } b() }", "for (i = 0; !(i > 9); i++) { b() }");
+    test("while (true) { if (a) break; b() }", "for (; !a;) { b() }");
+    test_same("for (;;) { let a = b(); if (a) break; c() }");
+    test_same("for (;;) { if (a) break; else b() }");
+    test_same("l: for (;;) { for (;;) { if (a) break l; b() } }");
+}
+
+#[test]
+fn for_to_do_while() {
+    test("for (;;) { b(); if (a) break }", "do { b() } while (!a)");
+    test("while (true) { b(); c(); if (!a) break }", "do { b(); c() } while (a)");
+    test("for (;;) { b(() => c); if (a) break }", "do { b(() => c) } while (!a)");
+    test_same("for (;;) { if (a) continue; b(); if (c) break }");
+    test_same("for (;;) { let a = b(); if (a) break }");
+    test_same("for (i = 0; ; i++) { b(); if (a) break }");
+    test_same("l: for (;;) { b(); if (a) break }");
+}
@@ -1,6 +1,11 @@
 mod collapse_variable_declarations;
+mod collapse_vars;
 mod fold_conditions;
 mod fold_constants;
+mod if_return;
+mod inline_constants;
+mod minimize_loops;
 mod remove_dead_code;
+mod remove_syntax;
 mod reorder_constant_expression;
 mod substitute_alternate_syntax;
@@ -0,0 +1,56 @@
+use crate::CompressOptions;
+
+fn options() -> CompressOptions {
+    CompressOptions {
+        remove_syntax: true,
+        drop_console: true,
+        pure_funcs: vec!["invariant".into(), "console.assert".into(), "a.b.c".into()],
+        ..CompressOptions::all_false()
+    }
+}
+
+fn test(source_text: &str, expected: &str) {
+    crate::test(source_text, expected, options());
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn drop_console() {
+    test("console.log(a)", "");
+    test("console.log(a, 1, 'str')", "");
+    test("console.log(foo())", "foo()");
+    test("console.log(a, foo(), bar())", "foo(), bar()");
+    test("console.log(...a)", "[...a]");
+    test("x = console.log(a)", "x = void 0");
+    test("x = console.log(a())", "x = (a(), void 0)");
+    test("x = console.log(a(), b())", "x = (a(), b(), void 0)");
+    test("() => console.log(a)", "() => {}");
+    test("() => console.log(a())", "() => (a(), void 0)");
+}
+
+#[test]
+fn pure_funcs() {
+    test("invariant(a, 'msg')", "");
+    test("invariant(foo(), 'msg')", "foo()");
+    test("console.assert(a)", "");
+    test("a.b.c(d)", "");
+    test("(invariant(a), b)", "b");
+    test("(invariant(a()), b)", "a(), b");
+    test("(a, invariant(b), c)", "a, c");
+    test_same("x = invariant(a)");
+    test_same("(a, invariant(b))");
+    test_same("() => invariant(a)");
+    test_same("invariant.call(a)");
+    test_same("b.c(d)");
+}
+
+#[test]
+fn unsafe_drop_args() {
+    let options = CompressOptions { unsafe_drop_args: true, ..options() };
+    crate::test("console.log(foo())", "", options.clone());
+    crate::test("invariant(foo(), bar())", "", options.clone());
+    crate::test("x = console.log(foo())", "x = void 0", options);
+}
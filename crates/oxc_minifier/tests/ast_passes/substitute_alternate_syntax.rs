@@ -67,3 +67,45 @@ fn undefined() {
     test("undefined++", "undefined++");
     test("undefined += undefined", "undefined+=void 0");
 }
+
+#[test]
+fn switch_statement() {
+    test(
+        "switch (a) { case 1: b(); break; case 2: b(); break; case 3: c() }",
+        "switch (a) { case 1: case 2: b(); break; case 3: c() }",
+    );
+    test(
+        "switch (a) { case 1: b(); break; case 2: b(); break; case 3: b(); break; default: c() }",
+        "switch (a) { case 1: case 2: case 3: b(); break; default: c() }",
+    );
+    test(
+        "switch (a) { case 1: b(); case 2: c(); break; case 3: }",
+        "switch (a) { case 1: b(); case 2: c() }",
+    );
+    test("switch (a) { case 1: b(); break; default: }", "if (a === 1) b()");
+    test("switch (a) { case 1: b() }", "if (a === 1) b()");
+    test("switch (a) { case 1: b(); break; default: c() }", "if (a === 1) b(); else c()");
+    test(
+        "switch (a) { case 1: b(); c(); break; default: d(); e() }",
+        "if (a === 1) { b(); c() } else { d(); e() }",
+    );
+    test(
+        "function f() { switch (a) { case 1: return; default: c() } }",
+        "function f() { if (a === 1) return; else c() }",
+    );
+    test("for (;;) switch (a) { case 1: continue }", "for (;;) if (a === 1) continue");
+    test(
+        "switch (a) { case () => 1: b(); break; default: c(() => d) }",
+        "if (a === (() => 1)) b(); else c(() => d)",
+    );
+    test(
+        "switch (a) { case 1: b(() => 1); break; default: c(() => d); e(() => f) }",
+        "if (a === 1) b(() => 1); else { c(() => d); e(() => f) }",
+    );
+
+    test_same("switch (a) { case 1: b(); default: c() }");
+    test_same("switch (a) { case 1: if (b) break; c() }");
+    test_same("switch (a) { case 1: let b; c(b) }");
+    test_same("switch (a) { default: b(); break; case 1: }");
+    test_same("switch (a) { case 1: b(); break; case 2: c() ; break; default: d() }");
+}
@@ -2,16 +2,19 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::Mangler;
+use oxc_mangler::{MangleOptions, Mangler};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str) -> String {
+    mangle_with(source_text, SourceType::default().with_module(true), MangleOptions::default())
+}
+
+fn mangle_with(source_text: &str, source_type: SourceType, options: MangleOptions) -> String {
     let allocator = Allocator::default();
-    let source_type = SourceType::default().with_module(true);
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let mangler = Mangler::new().build(&program);
+    let mangler = Mangler::new().with_options(options).build(&program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&program).source_text
 }
 
@@ -22,6 +25,10 @@ fn mangler() {
         "function foo(a) { let _ = { x } }",
         "function foo(a) { let { x } = y }",
         "var x; function foo(a) { ({ x } = y) }",
+        // `eval` can access `foo`, `a` and `bar` by name
+        "function foo(a) { eval(a) } function bar(b) { b }",
+        // Imports keep their names, which must not be shadowed
+        "import { a } from 'mod'; function foo(b) { a(b) }",
     ];
 
     let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
@@ -33,3 +40,25 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn global_code() {
+    let source_text = "var x; function foo(a) { new Function('return x') }";
+    let module = SourceType::default().with_module(true);
+
+    // `new Function` can access top level bindings of scripts, but not of modules
+    assert_eq!(
+        mangle_with(source_text, SourceType::default(), MangleOptions::default()),
+        "var x;\nfunction foo(c) {\n\tnew Function(\"return x\");\n}\n"
+    );
+    assert_eq!(
+        mangle_with(source_text, module, MangleOptions::default()),
+        "var a;\nfunction b(c) {\n\tnew Function(\"return x\");\n}\n"
+    );
+
+    let force = MangleOptions { force: true, ..MangleOptions::default() };
+    assert_eq!(
+        mangle_with("function foo(a) { eval(a) }", module, force),
+        "function a(b) {\n\teval(b);\n}\n"
+    );
+}
@@ -0,0 +1,169 @@
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use globset::GlobBuilder;
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_resolver::{Resolution, ResolveOptions, Resolver};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_syntax::module_record::RequestedModule;
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::{Dependency, Module, ModuleGraph};
+
+pub struct ModuleGraphBuilder {
+    resolver: Resolver,
+}
+
+pub struct ModuleGraphReturn {
+    pub graph: ModuleGraph,
+    /// Modules which could not be read or parsed
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+impl Default for ModuleGraphBuilder {
+    fn default() -> Self {
+        Self::new(Resolver::new(ResolveOptions {
+            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            condition_names: vec!["module".into(), "import".into()],
+            ..ResolveOptions::default()
+        }))
+    }
+}
+
+impl ModuleGraphBuilder {
+    pub fn new(resolver: Resolver) -> Self {
+        Self { resolver }
+    }
+
+    /// Load `entries` and all the modules they (indirectly) import.
+    ///
+    /// Modules are loaded breadth first, and each level is parsed in parallel.
+    /// Relative entry paths are resolved against the current working directory.
+    pub fn build(&self, entries: &[PathBuf]) -> ModuleGraphReturn {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let entries = entries.iter().map(|path| cwd.join(path)).collect::<Vec<_>>();
+
+        let mut graph = ModuleGraph::default();
+        let mut errors = vec![];
+        let mut seen = FxHashSet::default();
+        let mut queue =
+            entries.iter().filter(|path| seen.insert((*path).clone())).cloned().collect::<Vec<_>>();
+
+        while !queue.is_empty() {
+            let results = queue.par_iter().map(|path| self.load(path)).collect::<Vec<_>>();
+            queue.clear();
+            for result in results {
+                let module = match result {
+                    Ok(module) => module,
+                    Err(error) => {
+                        errors.push(error);
+                        continue;
+                    }
+                };
+                for dependency in &module.dependencies {
+                    let Some(path) = &dependency.resolved_path else { continue };
+                    if Self::is_module(path) && seen.insert(path.clone()) {
+                        queue.push(path.clone());
+                    }
+                }
+                graph.add_module(module);
+            }
+        }
+
+        graph.link(&entries);
+        ModuleGraphReturn { graph, errors }
+    }
+
+    fn load(&self, path: &Path) -> Result<Module, OxcDiagnostic> {
+        let source_text = fs::read_to_string(path).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to read {}: {err}", path.display()))
+        })?;
+        let source_type = SourceType::from_path(path).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to load {}: {err}", path.display()))
+        })?;
+        let record = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            if let Some(error) = ret.errors.first() {
+                return Err(OxcDiagnostic::error(format!(
+                    "Failed to parse {}: {error}",
+                    path.display()
+                )));
+            }
+            SemanticBuilder::new(&source_text)
+                .build_module_record(path, &ret.program)
+                .module_record()
+        };
+
+        let dir = path.parent().unwrap_or(path);
+        let mut dependencies = record
+            .requested_modules
+            .iter()
+            .map(|(specifier, requested_modules)| {
+                let resolution = self.resolver.resolve(dir, specifier).ok();
+                Dependency {
+                    specifier: specifier.clone(),
+                    span: requested_modules[0].span(),
+                    is_type: requested_modules.iter().all(RequestedModule::is_type),
+                    side_effects: resolution.as_ref().map_or(true, Self::has_side_effects),
+                    resolved_path: resolution.map(|resolution| resolution.path().to_path_buf()),
+                    module_id: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable_by_key(|dependency| dependency.span.start);
+
+        Ok(Module { path: path.to_path_buf(), source_text, source_type, record, dependencies })
+    }
+
+    /// Whether the resolved module may have side effects, according to the `sideEffects` field of
+    /// its package.json: either `false`, or globs of the modules which have side effects.
+    /// <https://webpack.js.org/guides/tree-shaking/#mark-the-file-as-side-effect-free>
+    fn has_side_effects(resolution: &Resolution) -> bool {
+        let Some(package_json) = resolution.package_json() else { return true };
+        match &package_json.side_effects {
+            Some(serde_json::Value::Bool(side_effects)) => *side_effects,
+            Some(serde_json::Value::Array(globs)) => {
+                let Some(path) = package_json
+                    .path
+                    .parent()
+                    .and_then(|dir| resolution.path().strip_prefix(dir).ok())
+                else {
+                    return true;
+                };
+                globs
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .any(|glob| Self::matches_glob(glob, path))
+            }
+            _ => true,
+        }
+    }
+
+    /// Whether `path`, relative to the package, matches a glob of `sideEffects`.
+    /// Globs without a `/` match files in any directory, e.g. `*.css`.
+    /// Invalid globs match any file.
+    fn matches_glob(glob: &str, path: &Path) -> bool {
+        let glob = glob.strip_prefix("./").unwrap_or(glob);
+        let glob =
+            if glob.contains('/') { Cow::Borrowed(glob) } else { Cow::Owned(format!("**/{glob}")) };
+        GlobBuilder::new(&glob)
+            .literal_separator(true)
+            .build()
+            .map_or(true, |glob| glob.compile_matcher().is_match(path))
+    }
+
+    /// Whether `path` is a JavaScript or TypeScript file, as opposed to e.g. JSON or CSS.
+    fn is_module(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| VALID_EXTENSIONS.contains(&ext))
+    }
+}
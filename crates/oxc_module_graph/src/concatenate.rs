@@ -0,0 +1,687 @@
+use std::iter;
+
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, VisitMut};
+use oxc_codegen::CodeGenerator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{CompactStr, SPAN};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{ModuleGraph, ModuleId};
+
+pub struct ConcatenateReturn {
+    /// The concatenated module. Empty if there are errors.
+    pub source_text: String,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Experimental scope hoisting, i.e. Rollup style module concatenation.
+///
+/// Merges an entry point and all the modules it imports into a single module:
+///
+/// * Modules are placed in execution order.
+/// * Top level bindings are renamed when they conflict with each other or with globals, e.g. a
+///   second `helper` becomes `helper$1`. Renaming is deterministic.
+/// * Imports of concatenated modules are removed, and references to them are replaced with the
+///   bindings they resolve to.
+/// * Imports of modules outside the graph, e.g. `node:fs`, are hoisted to the top.
+/// * The entry point's exports are declared in a single `export { ... }` statement.
+///
+/// Modules other than the entry point must be free of side effects (only contain declarations),
+/// so that moving them into the same scope can not change the program's behavior.
+/// Cycles, CommonJS modules, namespace imports and re-exports of external modules are not
+/// supported.
+pub struct Concatenator<'g> {
+    graph: &'g ModuleGraph,
+    errors: Vec<OxcDiagnostic>,
+}
+
+enum Export {
+    /// A top level binding, which may be an import of another concatenated module
+    Local(SymbolId),
+    /// `export default expression`, declared as a new variable
+    Default,
+    /// `export { name } from 'module'`
+    Reexport(ModuleId, CompactStr),
+}
+
+struct ConcatenatedModule<'a> {
+    module_id: ModuleId,
+    program: Program<'a>,
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+    /// Bindings imported from other concatenated modules: `(local binding, module, import name)`
+    imports: Vec<(SymbolId, ModuleId, CompactStr)>,
+    exports: Vec<(CompactStr, Export)>,
+    /// `export * from 'module'`
+    star_exports: Vec<ModuleId>,
+    /// Names of all bindings which are not in the top level scope
+    nested_names: FxHashSet<CompactStr>,
+    /// Final names of top level bindings, including imports
+    names: FxHashMap<SymbolId, CompactStr>,
+    /// Name of the variable declared for `export default expression`
+    default_name: Option<CompactStr>,
+}
+
+impl<'g> Concatenator<'g> {
+    pub fn new(graph: &'g ModuleGraph) -> Self {
+        Self { graph, errors: vec![] }
+    }
+
+    pub fn build(mut self, entry: ModuleId) -> ConcatenateReturn {
+        let order = self.graph.post_order(iter::once(entry));
+        if let Some(cycle) = self.graph.cycles().into_iter().find(|cycle| order.contains(&cycle[0]))
+        {
+            let paths = cycle
+                .iter()
+                .map(|&module_id| self.graph.module(module_id).path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.errors.push(OxcDiagnostic::error(format!(
+                "Modules in an import cycle can not be concatenated: {paths}"
+            )));
+            return self.finish(String::new());
+        }
+
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+
+        let mut modules = order
+            .iter()
+            .map(|&module_id| self.analyze(&allocator, module_id, module_id == entry))
+            .collect::<Vec<_>>();
+        if !self.errors.is_empty() {
+            return self.finish(String::new());
+        }
+        let indices = modules
+            .iter()
+            .enumerate()
+            .map(|(index, module)| (module.module_id, index))
+            .collect::<FxHashMap<_, _>>();
+
+        self.assign_names(&mut modules);
+        self.resolve_imports(&mut modules, &indices);
+        let entry_exports = self.entry_exports(&modules, &indices, indices[&entry]);
+        if !self.errors.is_empty() {
+            return self.finish(String::new());
+        }
+
+        let mut body = ast.vec();
+        let mut statements = ast.vec();
+        for module in &mut modules {
+            let names =
+                module.names.iter().map(|(&symbol_id, name)| (symbol_id, ast.atom(name))).collect();
+            Renamer { symbols: &module.symbols, names: &names }.visit_program(&mut module.program);
+            let default_name = module.default_name.as_deref().map(|name| ast.atom(name));
+            let graph_module = self.graph.module(module.module_id);
+            let is_concatenated = |source: &StringLiteral| {
+                graph_module.dependencies.iter().any(|dependency| {
+                    dependency.specifier == source.value.as_str() && dependency.module_id.is_some()
+                })
+            };
+
+            for stmt in ast.move_vec(&mut module.program.body) {
+                match stmt {
+                    Statement::ImportDeclaration(decl) => {
+                        if !is_concatenated(&decl.source) {
+                            body.push(Statement::ImportDeclaration(decl));
+                        }
+                    }
+                    Statement::ExportNamedDeclaration(mut decl) => {
+                        if let Some(declaration) = decl.declaration.take() {
+                            statements.push(Statement::from(declaration));
+                        }
+                    }
+                    Statement::ExportDefaultDeclaration(decl) => {
+                        match decl.unbox().declaration {
+                            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                                if let Some(name) = &default_name {
+                                    func.id = Some(ast.binding_identifier(SPAN, name.clone()));
+                                }
+                                statements.push(Statement::FunctionDeclaration(func));
+                            }
+                            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                                if let Some(name) = &default_name {
+                                    class.id = Some(ast.binding_identifier(SPAN, name.clone()));
+                                }
+                                statements.push(Statement::ClassDeclaration(class));
+                            }
+                            ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+                                statements.push(Statement::TSInterfaceDeclaration(interface));
+                            }
+                            kind => {
+                                // `export default local` resolves to `local` itself
+                                let Some(name) = &default_name else { continue };
+                                let id = ast.binding_pattern(
+                                    ast.binding_pattern_kind_binding_identifier(SPAN, name.clone()),
+                                    None::<TSTypeAnnotation<'_>>,
+                                    false,
+                                );
+                                let declarator = ast.variable_declarator(
+                                    SPAN,
+                                    VariableDeclarationKind::Var,
+                                    id,
+                                    Some(kind.into_expression()),
+                                    false,
+                                );
+                                statements.push(Statement::from(ast.declaration_variable(
+                                    SPAN,
+                                    VariableDeclarationKind::Var,
+                                    ast.vec1(declarator),
+                                    false,
+                                )));
+                            }
+                        }
+                    }
+                    Statement::ExportAllDeclaration(_) => {}
+                    stmt => statements.push(stmt),
+                }
+            }
+        }
+
+        body.extend(statements);
+        if !entry_exports.is_empty() {
+            let specifiers = ast.vec_from_iter(entry_exports.iter().map(|(exported, local)| {
+                ast.export_specifier(
+                    SPAN,
+                    ast.module_export_name_identifier_reference(SPAN, local.as_str()),
+                    ast.module_export_name_identifier_name(SPAN, exported.as_str()),
+                    ImportOrExportKind::Value,
+                )
+            }));
+            body.push(Statement::from(ast.module_declaration_export_named_declaration(
+                SPAN,
+                None,
+                specifiers,
+                None,
+                ImportOrExportKind::Value,
+                None::<WithClause<'_>>,
+            )));
+        }
+
+        let source_type = self.graph.module(entry).source_type;
+        let program = ast.program(SPAN, source_type, None, ast.vec(), body);
+        let source_text = CodeGenerator::new().build(&program).source_text;
+        self.finish(source_text)
+    }
+
+    fn finish(self, source_text: String) -> ConcatenateReturn {
+        ConcatenateReturn { source_text, errors: self.errors }
+    }
+
+    /// Parse a module and collect its imports and exports.
+    fn analyze<'a>(
+        &mut self,
+        allocator: &'a Allocator,
+        module_id: ModuleId,
+        is_entry: bool,
+    ) -> ConcatenatedModule<'a>
+    where
+        'g: 'a,
+    {
+        let graph = self.graph;
+        let module = graph.module(module_id);
+        let path = module.path.display();
+        let program =
+            Parser::new(allocator, &module.source_text, module.source_type).parse().program;
+        let (symbols, scopes) = SemanticBuilder::new(&module.source_text)
+            .build(&program)
+            .semantic
+            .into_symbol_table_and_scope_tree();
+
+        let unresolved_references = scopes.root_unresolved_references();
+        if ["require", "module", "exports"]
+            .iter()
+            .any(|name| unresolved_references.contains_key(*name))
+        {
+            self.errors.push(OxcDiagnostic::error(format!(
+                "CommonJS module {path} can not be concatenated"
+            )));
+        }
+        if !is_entry && program.body.iter().any(has_side_effects) {
+            self.errors.push(OxcDiagnostic::error(format!(
+                "Module {path} has side effects, so it can not be concatenated"
+            )));
+        }
+
+        let concatenated = |source: &StringLiteral| {
+            module
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.specifier == source.value.as_str())
+                .and_then(|dependency| dependency.module_id)
+        };
+        let reference_symbol = |ident: &IdentifierReference| {
+            ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id())
+        };
+
+        let mut imports = vec![];
+        let mut exports = vec![];
+        let mut star_exports = vec![];
+        for decl in program.body.iter().filter_map(Statement::as_module_declaration) {
+            match decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    let Some(source_id) = concatenated(&decl.source) else { continue };
+                    for specifier in decl.specifiers.iter().flatten() {
+                        let (local, name) = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                (&specifier.local, specifier.imported.name().as_str().into())
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                (&specifier.local, CompactStr::new_const("default"))
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                self.errors.push(OxcDiagnostic::error(format!(
+                                    "Namespace import in {path} can not be concatenated"
+                                )));
+                                continue;
+                            }
+                        };
+                        imports.push((local.symbol_id.get().unwrap(), source_id, name));
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        declaration.bound_names(&mut |ident| {
+                            let export = Export::Local(ident.symbol_id.get().unwrap());
+                            exports.push((ident.name.as_str().into(), export));
+                        });
+                    }
+                    let source_id = decl.source.as_ref().map(concatenated);
+                    for specifier in &decl.specifiers {
+                        let exported = specifier.exported.name().as_str().into();
+                        let export = match (&decl.source, source_id) {
+                            (None, _) => {
+                                let ModuleExportName::IdentifierReference(ident) = &specifier.local
+                                else {
+                                    continue;
+                                };
+                                let Some(symbol_id) = reference_symbol(ident) else { continue };
+                                Export::Local(symbol_id)
+                            }
+                            (Some(_), Some(Some(source_id))) => {
+                                Export::Reexport(source_id, specifier.local.name().as_str().into())
+                            }
+                            (Some(source), _) => {
+                                self.errors.push(OxcDiagnostic::error(format!(
+                                    "Re-export of external module `{}` in {path} can not be concatenated",
+                                    source.value
+                                )));
+                                continue;
+                            }
+                        };
+                        exports.push((exported, export));
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let export = match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+                            Some(&interface.id)
+                        }
+                        _ => None,
+                    }
+                    .map(|ident| Export::Local(ident.symbol_id.get().unwrap()))
+                    .or_else(|| match &decl.declaration {
+                        ExportDefaultDeclarationKind::Identifier(ident) => {
+                            reference_symbol(ident).map(Export::Local)
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or(Export::Default);
+                    exports.push((CompactStr::new_const("default"), export));
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    match (decl.exported.is_some(), concatenated(&decl.source)) {
+                        (false, Some(source_id)) => star_exports.push(source_id),
+                        (true, _) => self.errors.push(OxcDiagnostic::error(format!(
+                            "Namespace export in {path} can not be concatenated"
+                        ))),
+                        (false, None) => self.errors.push(OxcDiagnostic::error(format!(
+                            "Re-export of external module `{}` in {path} can not be concatenated",
+                            decl.source.value
+                        ))),
+                    }
+                }
+                ModuleDeclaration::TSExportAssignment(_)
+                | ModuleDeclaration::TSNamespaceExportDeclaration(_) => {
+                    self.errors.push(OxcDiagnostic::error(format!(
+                        "TypeScript export assignment in {path} can not be concatenated"
+                    )));
+                }
+            }
+        }
+
+        let root_scope_id = program.scope_id.get().unwrap();
+        let nested_names = scopes
+            .iter_bindings()
+            .filter(|(scope_id, _, _)| *scope_id != root_scope_id)
+            .map(|(_, _, name)| name.clone())
+            .collect();
+
+        ConcatenatedModule {
+            module_id,
+            program,
+            symbols,
+            scopes,
+            imports,
+            exports,
+            star_exports,
+            nested_names,
+            names: FxHashMap::default(),
+            default_name: None,
+        }
+    }
+
+    /// Give every top level binding which is not an import of a concatenated module a unique name.
+    fn assign_names(&self, modules: &mut [ConcatenatedModule]) {
+        // Globals, and bindings in nested scopes which new names must not shadow
+        let mut taken = modules
+            .iter()
+            .flat_map(|module| module.scopes.root_unresolved_references().keys().cloned())
+            .collect::<FxHashSet<_>>();
+        let nested_names = modules
+            .iter()
+            .flat_map(|module| module.nested_names.iter().cloned())
+            .collect::<FxHashSet<_>>();
+
+        for module in modules {
+            let root_scope_id = module.program.scope_id.get().unwrap();
+            for symbol_id in module.scopes.iter_bindings_in(root_scope_id) {
+                if module.imports.iter().any(|(local, _, _)| *local == symbol_id) {
+                    continue;
+                }
+                let name = module.symbols.get_name(symbol_id);
+                let name = if taken.insert(name.into()) {
+                    name.into()
+                } else {
+                    Self::unique_name(name, &mut taken, &nested_names)
+                };
+                module.names.insert(symbol_id, name);
+            }
+            if module.exports.iter().any(|(_, export)| matches!(export, Export::Default)) {
+                let stem = self.graph.module(module.module_id).path.file_stem();
+                let stem = stem.map(|stem| stem.to_string_lossy()).unwrap_or_default();
+                let mut name = stem
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() || c == '$' { c } else { '_' })
+                    .collect::<String>();
+                if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '$') {
+                    name.insert(0, '_');
+                }
+                name.push_str("_default");
+                module.default_name = Some(if nested_names.contains(name.as_str()) {
+                    Self::unique_name(&name, &mut taken, &nested_names)
+                } else if taken.insert(name.as_str().into()) {
+                    name.into()
+                } else {
+                    Self::unique_name(&name, &mut taken, &nested_names)
+                });
+            }
+        }
+    }
+
+    /// `name$1`, `name$2`, ... whichever is free first.
+    fn unique_name(
+        name: &str,
+        taken: &mut FxHashSet<CompactStr>,
+        nested_names: &FxHashSet<CompactStr>,
+    ) -> CompactStr {
+        let mut i = 1;
+        loop {
+            let unique = CompactStr::from(format!("{name}${i}"));
+            if !nested_names.contains(&unique) && taken.insert(unique.clone()) {
+                return unique;
+            }
+            i += 1;
+        }
+    }
+
+    /// Rename imports of concatenated modules to the bindings they resolve to.
+    fn resolve_imports(
+        &mut self,
+        modules: &mut [ConcatenatedModule],
+        indices: &FxHashMap<ModuleId, usize>,
+    ) {
+        let mut resolved = vec![];
+        for (index, module) in modules.iter().enumerate() {
+            for (symbol_id, source_id, import_name) in &module.imports {
+                let name =
+                    match Self::resolve(self.graph, modules, indices, *source_id, import_name) {
+                        Ok(name) => name,
+                        Err(error) => {
+                            self.errors.push(error);
+                            continue;
+                        }
+                    };
+                let local_name = module.symbols.get_name(*symbol_id);
+                if local_name != name && module.nested_names.contains(&name) {
+                    self.errors.push(OxcDiagnostic::error(format!(
+                        "Import `{local_name}` in {} can not be renamed to `{name}`, as that name is shadowed",
+                        self.graph.module(module.module_id).path.display()
+                    )));
+                    continue;
+                }
+                resolved.push((index, *symbol_id, name));
+            }
+        }
+        for (index, symbol_id, name) in resolved {
+            modules[index].names.insert(symbol_id, name);
+        }
+    }
+
+    /// Final name of the binding exported as `export_name` by `module_id`.
+    fn resolve(
+        graph: &ModuleGraph,
+        modules: &[ConcatenatedModule],
+        indices: &FxHashMap<ModuleId, usize>,
+        module_id: ModuleId,
+        export_name: &str,
+    ) -> Result<CompactStr, OxcDiagnostic> {
+        let module = &modules[indices[&module_id]];
+        if let Some((_, export)) = module.exports.iter().find(|(name, _)| name == export_name) {
+            return match export {
+                Export::Local(symbol_id) => {
+                    match module.imports.iter().find(|(local, _, _)| local == symbol_id) {
+                        Some((_, source_id, import_name)) => {
+                            Self::resolve(graph, modules, indices, *source_id, import_name)
+                        }
+                        None => Ok(module.names[symbol_id].clone()),
+                    }
+                }
+                Export::Default => Ok(module.default_name.clone().unwrap()),
+                Export::Reexport(source_id, import_name) => {
+                    Self::resolve(graph, modules, indices, *source_id, import_name)
+                }
+            };
+        }
+        if export_name != "default" {
+            for source_id in &module.star_exports {
+                if let Ok(name) = Self::resolve(graph, modules, indices, *source_id, export_name) {
+                    return Ok(name);
+                }
+            }
+        }
+        Err(OxcDiagnostic::error(format!(
+            "`{export_name}` is not exported by {}",
+            graph.module(module_id).path.display()
+        )))
+    }
+
+    /// `(exported name, final local name)` of all exports of the entry point.
+    fn entry_exports(
+        &mut self,
+        modules: &[ConcatenatedModule],
+        indices: &FxHashMap<ModuleId, usize>,
+        entry: usize,
+    ) -> Vec<(CompactStr, CompactStr)> {
+        let mut export_names = vec![];
+        Self::export_names(modules, indices, entry, &mut export_names);
+        let entry_id = modules[entry].module_id;
+        export_names
+            .into_iter()
+            .filter_map(|export_name| {
+                match Self::resolve(self.graph, modules, indices, entry_id, &export_name) {
+                    Ok(name) => Some((export_name, name)),
+                    Err(error) => {
+                        self.errors.push(error);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn export_names(
+        modules: &[ConcatenatedModule],
+        indices: &FxHashMap<ModuleId, usize>,
+        index: usize,
+        export_names: &mut Vec<CompactStr>,
+    ) {
+        let module = &modules[index];
+        for (name, _) in &module.exports {
+            if !export_names.contains(name) {
+                export_names.push(name.clone());
+            }
+        }
+        for source_id in &module.star_exports {
+            let mut star_names = vec![];
+            Self::export_names(modules, indices, indices[source_id], &mut star_names);
+            for name in star_names {
+                if name != "default" && !export_names.contains(&name) {
+                    export_names.push(name);
+                }
+            }
+        }
+    }
+}
+
+/// Renames identifiers which refer to the given symbols.
+pub(crate) struct Renamer<'a, 's> {
+    pub symbols: &'s SymbolTable,
+    pub names: &'s FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 's> VisitMut<'a> for Renamer<'a, 's> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = ident.symbol_id.get().and_then(|symbol_id| self.names.get(&symbol_id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+        if let Some(name) = symbol_id.and_then(|symbol_id| self.names.get(&symbol_id)) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+fn has_side_effects(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::EmptyStatement(_)
+        | Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_) => false,
+        Statement::ExportNamedDeclaration(decl) => {
+            decl.declaration.as_ref().is_some_and(declaration_has_side_effects)
+        }
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(_)
+            | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => false,
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => class_has_side_effects(class),
+            kind => !is_pure(kind.to_expression()),
+        },
+        match_declaration!(Statement) => declaration_has_side_effects(stmt.to_declaration()),
+        _ => true,
+    }
+}
+
+fn declaration_has_side_effects(decl: &Declaration) -> bool {
+    match decl {
+        Declaration::VariableDeclaration(decl) => {
+            matches!(
+                decl.kind,
+                VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+            ) || decl
+                .declarations
+                .iter()
+                .any(|declarator| declarator.init.as_ref().is_some_and(|init| !is_pure(init)))
+        }
+        Declaration::ClassDeclaration(class) => class_has_side_effects(class),
+        Declaration::FunctionDeclaration(_)
+        | Declaration::TSTypeAliasDeclaration(_)
+        | Declaration::TSInterfaceDeclaration(_)
+        | Declaration::TSEnumDeclaration(_) => false,
+        Declaration::TSModuleDeclaration(decl) => !decl.declare,
+        Declaration::TSImportEqualsDeclaration(_) => true,
+    }
+}
+
+fn class_has_side_effects(class: &Class) -> bool {
+    let is_pure_key =
+        |computed: bool, key: &PropertyKey| !computed || key.as_expression().is_some_and(is_pure);
+    !class.decorators.is_empty()
+        || class.super_class.as_ref().is_some_and(|super_class| !is_pure(super_class))
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::StaticBlock(_) => true,
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty() || !is_pure_key(method.computed, &method.key)
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                !prop.decorators.is_empty()
+                    || !is_pure_key(prop.computed, &prop.key)
+                    || (prop.r#static && prop.value.as_ref().is_some_and(|value| !is_pure(value)))
+            }
+            ClassElement::AccessorProperty(prop) => {
+                !prop.decorators.is_empty()
+                    || !is_pure_key(prop.computed, &prop.key)
+                    || (prop.r#static && prop.value.as_ref().is_some_and(|value| !is_pure(value)))
+            }
+            ClassElement::TSIndexSignature(_) => false,
+        })
+}
+
+/// Evaluating `expr` has no side effects, other than possibly throwing a `ReferenceError`.
+fn is_pure(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::Identifier(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.iter().all(is_pure),
+        Expression::ArrayExpression(array) => array.elements.iter().all(|element| match element {
+            ArrayExpressionElement::SpreadElement(_) => false,
+            ArrayExpressionElement::Elision(_) => true,
+            element => is_pure(element.to_expression()),
+        }),
+        Expression::ObjectExpression(object) => {
+            object.properties.iter().all(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    (!property.computed || property.key.as_expression().is_some_and(is_pure))
+                        && is_pure(&property.value)
+                }
+                ObjectPropertyKind::SpreadProperty(_) => false,
+            })
+        }
+        Expression::ClassExpression(class) => !class_has_side_effects(class),
+        Expression::UnaryExpression(unary) => {
+            unary.operator != UnaryOperator::Delete && is_pure(&unary.argument)
+        }
+        _ => false,
+    }
+}
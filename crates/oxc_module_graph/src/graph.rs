@@ -0,0 +1,196 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_index::{define_index_type, IndexVec};
+use oxc_semantic::ModuleRecord;
+use oxc_span::{CompactStr, SourceType, Span};
+use petgraph::{
+    algo::tarjan_scc,
+    graph::{DiGraph, NodeIndex},
+    visit::EdgeRef,
+};
+use rustc_hash::FxHashMap;
+
+define_index_type! {
+    pub struct ModuleId = u32;
+}
+
+/// A module in a [`ModuleGraph`].
+#[derive(Debug)]
+pub struct Module {
+    /// Absolute path to the module
+    pub path: PathBuf,
+    pub source_text: String,
+    pub source_type: SourceType,
+    pub record: Arc<ModuleRecord>,
+    /// Modules requested by this module, in source text order
+    pub dependencies: Vec<Dependency>,
+}
+
+/// A module requested by an `import` or `export ... from` statement.
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    pub specifier: CompactStr,
+    /// Span of the first occurrence of the specifier
+    pub span: Span,
+    /// All the requests are type only, e.g. `import type { T } from 'mod'`
+    pub is_type: bool,
+    /// `None` if the specifier could not be resolved
+    pub resolved_path: Option<PathBuf>,
+    /// `false` if the resolved module is declared free of side effects by the `sideEffects`
+    /// field of its package.json, so that an import of it whose bindings are unused can be
+    /// removed
+    pub side_effects: bool,
+    /// `None` if the specifier could not be resolved, or the resolved file is not a JavaScript or
+    /// TypeScript module, or the module failed to load
+    pub module_id: Option<ModuleId>,
+}
+
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: IndexVec<ModuleId, Module>,
+    ids: FxHashMap<PathBuf, ModuleId>,
+    entries: Vec<ModuleId>,
+}
+
+impl ModuleGraph {
+    pub(crate) fn add_module(&mut self, module: Module) -> ModuleId {
+        let path = module.path.clone();
+        let module_id = self.modules.push(module);
+        self.ids.insert(path, module_id);
+        module_id
+    }
+
+    /// Set `module_id` of all dependencies and entries, once all modules have been added.
+    pub(crate) fn link(&mut self, entries: &[PathBuf]) {
+        let ids = &self.ids;
+        for module in &mut self.modules {
+            for dependency in &mut module.dependencies {
+                dependency.module_id =
+                    dependency.resolved_path.as_ref().and_then(|path| ids.get(path).copied());
+            }
+        }
+        self.entries = entries.iter().filter_map(|path| ids.get(path).copied()).collect();
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = (ModuleId, &Module)> + '_ {
+        self.modules.iter_enumerated()
+    }
+
+    pub fn module(&self, module_id: ModuleId) -> &Module {
+        &self.modules[module_id]
+    }
+
+    pub fn module_id(&self, path: &Path) -> Option<ModuleId> {
+        self.ids.get(path).copied()
+    }
+
+    /// Entry points which were loaded successfully.
+    pub fn entries(&self) -> &[ModuleId] {
+        &self.entries
+    }
+
+    /// Modules imported by `module_id`, in source text order.
+    pub fn dependencies(&self, module_id: ModuleId) -> impl Iterator<Item = ModuleId> + '_ {
+        self.modules[module_id].dependencies.iter().filter_map(|dependency| dependency.module_id)
+    }
+
+    /// Modules which import `module_id`.
+    pub fn dependents(&self, module_id: ModuleId) -> impl Iterator<Item = ModuleId> + '_ {
+        self.modules
+            .iter_enumerated()
+            .filter(move |(_, module)| {
+                module.dependencies.iter().any(|dependency| dependency.module_id == Some(module_id))
+            })
+            .map(|(id, _)| id)
+    }
+
+    /// Import cycles, as groups of modules which all (indirectly) import each other.
+    ///
+    /// Type only imports are included.
+    /// Modules in each cycle, and cycles themselves, are sorted by [`ModuleId`].
+    pub fn cycles(&self) -> Vec<Vec<ModuleId>> {
+        let graph = self.to_petgraph();
+        let mut cycles = tarjan_scc(&graph)
+            .into_iter()
+            .filter(|nodes| {
+                nodes.len() > 1 || graph.edges(nodes[0]).any(|edge| edge.target() == nodes[0])
+            })
+            .map(|nodes| {
+                let mut cycle =
+                    nodes.into_iter().map(|node| ModuleId::new(node.index())).collect::<Vec<_>>();
+                cycle.sort_unstable();
+                cycle
+            })
+            .collect::<Vec<_>>();
+        cycles.sort_unstable();
+        cycles
+    }
+
+    /// All modules, with each module placed after the modules it imports.
+    ///
+    /// This is the order in which ES modules are executed: a depth-first post-order traversal
+    /// from the entry points, following imports in source text order.
+    /// In a cycle, the module which is reached first is executed last.
+    pub fn topological_order(&self) -> Vec<ModuleId> {
+        // Visit entry points first, then any modules which are not reachable from them
+        self.post_order(self.entries.iter().copied().chain(self.modules.indices()))
+    }
+
+    /// Modules reachable from `roots`, in depth-first post-order.
+    pub(crate) fn post_order(&self, roots: impl Iterator<Item = ModuleId>) -> Vec<ModuleId> {
+        let mut order = Vec::with_capacity(self.modules.len());
+        let mut visited = vec![false; self.modules.len()];
+        for root in roots {
+            if visited[root.index()] {
+                continue;
+            }
+            visited[root.index()] = true;
+            // `(module, index of next dependency to visit)`
+            let mut stack = vec![(root, 0)];
+            while let Some(&(module_id, index)) = stack.last() {
+                let dependencies = &self.modules[module_id].dependencies;
+                let Some(dependency) = dependencies.get(index) else {
+                    order.push(module_id);
+                    stack.pop();
+                    continue;
+                };
+                stack.last_mut().unwrap().1 += 1;
+                if let Some(dependency_id) = dependency.module_id {
+                    if !visited[dependency_id.index()] {
+                        visited[dependency_id.index()] = true;
+                        stack.push((dependency_id, 0));
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    fn to_petgraph(&self) -> DiGraph<(), ()> {
+        let mut graph = DiGraph::with_capacity(self.modules.len(), 0);
+        for _ in self.modules.indices() {
+            graph.add_node(());
+        }
+        for (module_id, _) in self.modules.iter_enumerated() {
+            for dependency_id in self.dependencies(module_id) {
+                graph.add_edge(
+                    NodeIndex::new(module_id.index()),
+                    NodeIndex::new(dependency_id.index()),
+                    (),
+                );
+            }
+        }
+        graph
+    }
+}
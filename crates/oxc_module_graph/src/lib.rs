@@ -0,0 +1,27 @@
+//! Module graph of a JavaScript / TypeScript project.
+//!
+//! Starting from entry points, [`ModuleGraphBuilder`] parses modules in parallel, resolves their
+//! imports and exports with [`oxc_resolver`], and records each module's [`ModuleRecord`].
+//!
+//! The resulting [`ModuleGraph`] exposes the import / export edges between modules, cycles, and
+//! the order in which modules are executed.
+//!
+//! [`Concatenator`] is an experimental pass which merges modules of the graph into a single
+//! scope, and emits them as one file.
+//!
+//! [`GraphMinifier`] minifies all modules of the graph as a whole program, propagating constants
+//! across modules and renaming the exports of internal modules.
+//!
+//! [`ModuleRecord`]: oxc_semantic::ModuleRecord
+
+mod builder;
+mod concatenate;
+mod graph;
+mod minify;
+
+pub use crate::{
+    builder::{ModuleGraphBuilder, ModuleGraphReturn},
+    concatenate::{ConcatenateReturn, Concatenator},
+    graph::{Dependency, Module, ModuleGraph, ModuleId},
+    minify::{GraphMinifier, GraphMinifierReturn},
+};
@@ -0,0 +1,785 @@
+use oxc_allocator::{Allocator, CloneIn};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*, syntax_directed_operations::BoundNames, visit::walk_mut, AstBuilder, Visit, VisitMut,
+};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_index::IndexVec;
+use oxc_mangler::{base54, Mangler};
+use oxc_minifier::{Compressor, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{CompactStr, SPAN};
+use oxc_syntax::{es_version::ESVersion, keyword::is_reserved_word};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{concatenate::Renamer, ModuleGraph, ModuleId};
+
+pub struct GraphMinifierReturn {
+    /// Minified source text of each module of the graph
+    pub source_texts: IndexVec<ModuleId, String>,
+}
+
+/// Whole program minification, for applications whose modules are all part of the graph.
+///
+/// Every module is minified with [`oxc_minifier`], and the import / export edges between modules
+/// are used to minify them further:
+///
+/// * Imports of constants, e.g. `export const DEBUG = false`, are replaced with their values, so
+///   that they can be folded in the importing module. Dependencies are minified first, so their
+///   constants are folded before they are propagated.
+/// * Exports of internal modules which are not imported by any other module are removed.
+/// * Exports of internal modules are renamed to short names, along with the imports and
+///   re-exports of them.
+/// * Imports of modules which are declared free of side effects by the `sideEffects` field of
+///   their package.json are removed if none of their bindings are used.
+///
+/// Entry points are public, and so are modules which are imported as a namespace or re-exported
+/// with `export *`. Their exports keep their names.
+/// Modules loaded with `import()` or `require` are not part of the graph, so graphs which contain
+/// them only get constant propagation.
+pub struct GraphMinifier<'g> {
+    graph: &'g ModuleGraph,
+    options: MinifierOptions,
+}
+
+/// Exports of a minified module which are known to be constants.
+#[derive(Default)]
+struct ConstantExports<'a> {
+    /// Values of exported constants, by export name
+    values: FxHashMap<CompactStr, Expression<'a>>,
+    /// Exported imports and re-exports of graph modules: `(module, import name)` by export name
+    forwards: FxHashMap<CompactStr, (ModuleId, CompactStr)>,
+}
+
+impl<'g> GraphMinifier<'g> {
+    pub fn new(graph: &'g ModuleGraph, options: MinifierOptions) -> Self {
+        Self { graph, options }
+    }
+
+    pub fn build(self) -> GraphMinifierReturn {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+
+        let mut programs = self
+            .graph
+            .modules()
+            .map(|(_, module)| {
+                Parser::new(&allocator, &module.source_text, module.source_type).parse().program
+            })
+            .collect::<IndexVec<ModuleId, _>>();
+
+        let mut constants =
+            programs.iter().map(|_| ConstantExports::default()).collect::<IndexVec<ModuleId, _>>();
+        for module_id in self.graph.topological_order() {
+            let program = &mut programs[module_id];
+            self.inline_imported_constants(&allocator, module_id, program, &constants);
+            Compressor::new(&allocator, self.options.compress.clone()).build(program);
+            constants[module_id] = self.constant_exports(&allocator, module_id, program);
+        }
+
+        for (module_id, program) in programs.iter_mut_enumerated() {
+            self.remove_side_effect_free_imports(module_id, program);
+        }
+
+        if let Some(public) = self.public_modules(&programs) {
+            self.mangle_exports(ast, &mut programs, &public);
+        }
+
+        let source_texts = programs
+            .iter_mut()
+            .map(|program| {
+                // Exports which were removed may have left constants to inline
+                Compressor::new(&allocator, self.options.compress.clone()).build(program);
+                let mangler = self.options.mangle.then(|| Mangler::new().build(program));
+                CodeGenerator::new()
+                    .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+                    .with_mangler(mangler)
+                    .build(program)
+                    .source_text
+            })
+            .collect();
+        GraphMinifierReturn { source_texts }
+    }
+
+    /// The graph module which `source` of an import or export in `module_id` resolves to.
+    fn dependency(&self, module_id: ModuleId, source: &StringLiteral) -> Option<ModuleId> {
+        self.graph
+            .module(module_id)
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.specifier == source.value.as_str())
+            .and_then(|dependency| dependency.module_id)
+    }
+
+    /// Whether the module which `source` of an import in `module_id` resolves to may have side
+    /// effects.
+    fn has_side_effects(&self, module_id: ModuleId, source: &StringLiteral) -> bool {
+        self.graph
+            .module(module_id)
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.specifier == source.value.as_str())
+            .map_or(true, |dependency| dependency.side_effects)
+    }
+
+    /// Remove the unused bindings of imports of modules which are free of side effects, and the
+    /// imports which are left without bindings.
+    ///
+    /// `import { used, unused } from 'pure'` -> `import { used } from 'pure'`
+    fn remove_side_effect_free_imports(&self, module_id: ModuleId, program: &mut Program) {
+        let (symbols, _) = semantic(program);
+        for stmt in program.body.iter_mut() {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if self.has_side_effects(module_id, &decl.source) {
+                continue;
+            }
+            let Some(specifiers) = &mut decl.specifiers else { continue };
+            specifiers.retain(|specifier| {
+                specifier.local().symbol_id.get().map_or(true, |symbol_id| {
+                    !symbols.get_resolved_reference_ids(symbol_id).is_empty()
+                })
+            });
+        }
+        // Unlike `import './config'` of other modules, `import 'pure'` can be removed
+        program.body.retain(|stmt| {
+            !matches!(stmt, Statement::ImportDeclaration(decl)
+                if decl.specifiers.as_ref().map_or(true, |specifiers| specifiers.is_empty())
+                    && !self.has_side_effects(module_id, &decl.source))
+        });
+    }
+
+    /// Replace imports of constants exported by `constants` with their values.
+    ///
+    /// `import { DEBUG } from './config'; if (DEBUG) {}` -> `import './config'; if (false) {}`
+    fn inline_imported_constants<'a>(
+        &self,
+        allocator: &'a Allocator,
+        module_id: ModuleId,
+        program: &mut Program<'a>,
+        constants: &IndexVec<ModuleId, ConstantExports<'a>>,
+    ) {
+        let (symbols, _) = semantic(program);
+
+        let mut values = FxHashMap::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(source_id) = self.dependency(module_id, &decl.source) else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            for specifier in decl.specifiers.iter().flatten() {
+                let (local, import_name) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                        if !specifier.import_kind.is_type() =>
+                    {
+                        (&specifier.local, specifier.imported.name())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, Atom::from("default"))
+                    }
+                    _ => continue,
+                };
+                let Some(symbol_id) = local.symbol_id.get() else { continue };
+                let Some(value) = resolve_constant(constants, source_id, import_name.as_str(), 0)
+                else {
+                    continue;
+                };
+                let references = symbols.get_resolved_references(symbol_id).collect::<Vec<_>>();
+                // Assigning to an import throws a `TypeError`
+                if references.iter().any(|reference| reference.is_write())
+                    || (references.len() > 1 && !is_small(value))
+                {
+                    continue;
+                }
+                values.insert(symbol_id, value.clone_in(allocator));
+            }
+        }
+        if values.is_empty() {
+            return;
+        }
+
+        let mut inliner =
+            ConstantInliner { allocator, symbols: &symbols, values, inlined: FxHashMap::default() };
+        inliner.visit_program(program);
+
+        // Remove the imports which were inlined into all of their references, e.g. the ones which
+        // are not re-exported
+        for stmt in program.body.iter_mut() {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &mut decl.specifiers else { continue };
+            if specifiers.is_empty() {
+                continue;
+            }
+            specifiers.retain(|specifier| {
+                specifier.local().symbol_id.get().map_or(true, |symbol_id| {
+                    !inliner.values.contains_key(&symbol_id)
+                        || inliner.inlined.get(&symbol_id).copied().unwrap_or_default()
+                            != symbols.get_resolved_reference_ids(symbol_id).len()
+                })
+            });
+            // `import './config'` still executes the module
+            if specifiers.is_empty() {
+                decl.specifiers = None;
+            }
+        }
+    }
+
+    /// Exports of `program` which are constants, or which forward exports of other modules.
+    fn constant_exports<'a>(
+        &self,
+        allocator: &'a Allocator,
+        module_id: ModuleId,
+        program: &Program<'a>,
+    ) -> ConstantExports<'a> {
+        let (symbols, _) = semantic(program);
+
+        // Top level `const` bindings with constant values, and imports of graph modules
+        let mut values = FxHashMap::default();
+        let mut imports = FxHashMap::default();
+        for stmt in &program.body {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                Statement::ImportDeclaration(decl) => {
+                    let Some(source_id) = self.dependency(module_id, &decl.source) else {
+                        continue;
+                    };
+                    for specifier in decl.specifiers.iter().flatten() {
+                        let import_name = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                specifier.imported.name().as_str().into()
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                CompactStr::new_const("default")
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => continue,
+                        };
+                        if let Some(symbol_id) = specifier.local().symbol_id.get() {
+                            imports.insert(symbol_id, (source_id, import_name));
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            if decl.kind != VariableDeclarationKind::Const {
+                continue;
+            }
+            for declarator in &decl.declarations {
+                let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                    continue;
+                };
+                let (Some(symbol_id), Some(init)) = (ident.symbol_id.get(), &declarator.init)
+                else {
+                    continue;
+                };
+                if is_constant(init)
+                    && symbols
+                        .get_resolved_references(symbol_id)
+                        .all(|reference| !reference.is_write())
+                {
+                    values.insert(symbol_id, init);
+                }
+            }
+        }
+
+        let mut exports = ConstantExports::default();
+        let mut exported_symbols = vec![];
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        declaration.bound_names(&mut |ident| {
+                            if let Some(symbol_id) = ident.symbol_id.get() {
+                                exported_symbols.push((ident.name.as_str().into(), symbol_id));
+                            }
+                        });
+                    }
+                    let source_id =
+                        decl.source.as_ref().and_then(|source| self.dependency(module_id, source));
+                    for specifier in &decl.specifiers {
+                        let exported = CompactStr::from(specifier.exported.name().as_str());
+                        if decl.source.is_some() {
+                            if let Some(source_id) = source_id {
+                                let import_name = specifier.local.name().as_str().into();
+                                exports.forwards.insert(exported, (source_id, import_name));
+                            }
+                        } else if let ModuleExportName::IdentifierReference(ident) =
+                            &specifier.local
+                        {
+                            if let Some(symbol_id) = reference_symbol(&symbols, ident) {
+                                exported_symbols.push((exported, symbol_id));
+                            }
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                    ExportDefaultDeclarationKind::Identifier(ident) => {
+                        if let Some(symbol_id) = reference_symbol(&symbols, ident) {
+                            exported_symbols.push((CompactStr::new_const("default"), symbol_id));
+                        }
+                    }
+                    // `export default 1` exports a value, not a binding
+                    kind => {
+                        if let Some(expr) = kind.as_expression().filter(|expr| is_constant(expr)) {
+                            exports.values.insert("default".into(), expr.clone_in(allocator));
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+        for (exported, symbol_id) in exported_symbols {
+            if let Some(value) = values.get(&symbol_id) {
+                exports.values.insert(exported, value.clone_in(allocator));
+            } else if let Some(import) = imports.get(&symbol_id) {
+                exports.forwards.insert(exported, import.clone());
+            }
+        }
+        exports
+    }
+
+    /// Modules whose exports must keep their names, or `None` if the graph may be loaded by
+    /// modules outside of it.
+    fn public_modules(
+        &self,
+        programs: &IndexVec<ModuleId, Program>,
+    ) -> Option<IndexVec<ModuleId, bool>> {
+        let mut public = programs.iter().map(|_| false).collect::<IndexVec<ModuleId, _>>();
+        for &entry in self.graph.entries() {
+            public[entry] = true;
+        }
+        for (module_id, program) in programs.iter_enumerated() {
+            let mut finder = ModuleLoaderFinder::default();
+            finder.visit_program(program);
+            if finder.found {
+                return None;
+            }
+            for stmt in &program.body {
+                match stmt {
+                    Statement::ImportDeclaration(decl) => {
+                        let is_namespace = decl.specifiers.iter().flatten().any(|specifier| {
+                            matches!(
+                                specifier,
+                                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                            )
+                        });
+                        if let Some(source_id) = self.dependency(module_id, &decl.source) {
+                            public[source_id] |= is_namespace;
+                        }
+                    }
+                    // The names exported by `export *` depend on the exports of both modules
+                    Statement::ExportAllDeclaration(decl) => {
+                        public[module_id] = true;
+                        if let Some(source_id) = self.dependency(module_id, &decl.source) {
+                            public[source_id] = true;
+                        }
+                    }
+                    Statement::TSExportAssignment(_) => public[module_id] = true,
+                    _ => {}
+                }
+            }
+        }
+        Some(public)
+    }
+
+    /// Remove the unused exports of internal modules, and rename the others.
+    fn mangle_exports<'a>(
+        &self,
+        ast: AstBuilder<'a>,
+        programs: &mut IndexVec<ModuleId, Program<'a>>,
+        public: &IndexVec<ModuleId, bool>,
+    ) {
+        // Removing a re-export may leave the export it refers to unused
+        let used = loop {
+            let used = self.used_exports(programs);
+            let mut changed = false;
+            for (module_id, program) in programs.iter_mut_enumerated() {
+                if !public[module_id] {
+                    changed |= remove_unused_exports(program, &used[module_id]);
+                }
+            }
+            if !changed {
+                break used;
+            }
+        };
+
+        let renames = programs
+            .iter_enumerated()
+            .map(|(module_id, program)| {
+                if public[module_id] {
+                    FxHashMap::default()
+                } else {
+                    short_export_names(program, &used[module_id])
+                }
+            })
+            .collect::<IndexVec<ModuleId, _>>();
+        for (module_id, program) in programs.iter_mut_enumerated() {
+            self.rename_exports(ast, module_id, program, &renames);
+        }
+    }
+
+    /// Names imported or re-exported from each module by other modules of the graph.
+    fn used_exports(
+        &self,
+        programs: &IndexVec<ModuleId, Program>,
+    ) -> IndexVec<ModuleId, FxHashSet<CompactStr>> {
+        let mut used =
+            programs.iter().map(|_| FxHashSet::default()).collect::<IndexVec<ModuleId, _>>();
+        for (module_id, program) in programs.iter_enumerated() {
+            for stmt in &program.body {
+                match stmt {
+                    Statement::ImportDeclaration(decl) => {
+                        let Some(source_id) = self.dependency(module_id, &decl.source) else {
+                            continue;
+                        };
+                        for specifier in decl.specifiers.iter().flatten() {
+                            match specifier {
+                                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                    used[source_id]
+                                        .insert(specifier.imported.name().as_str().into());
+                                }
+                                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                    used[source_id].insert(CompactStr::new_const("default"));
+                                }
+                                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {}
+                            }
+                        }
+                    }
+                    Statement::ExportNamedDeclaration(decl) => {
+                        let Some(source_id) = decl
+                            .source
+                            .as_ref()
+                            .and_then(|source| self.dependency(module_id, source))
+                        else {
+                            continue;
+                        };
+                        for specifier in &decl.specifiers {
+                            used[source_id].insert(specifier.local.name().as_str().into());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        used
+    }
+
+    /// Apply the export names of `renames` to the exports of `module_id`, and to its imports and
+    /// re-exports of other modules.
+    fn rename_exports<'a>(
+        &self,
+        ast: AstBuilder<'a>,
+        module_id: ModuleId,
+        program: &mut Program<'a>,
+        renames: &IndexVec<ModuleId, FxHashMap<CompactStr, CompactStr>>,
+    ) {
+        let (symbols, scopes) = semantic(program);
+        let own_renames = &renames[module_id];
+
+        // Bindings, globals and the new export names, which imports can not be renamed to
+        let mut taken = scopes
+            .iter_bindings()
+            .map(|(_, _, name)| name.clone())
+            .chain(scopes.root_unresolved_references().keys().cloned())
+            .chain(own_renames.values().cloned())
+            .collect::<FxHashSet<_>>();
+        let mut names = FxHashMap::default();
+        let mut imports = vec![];
+
+        for stmt in program.body.iter_mut() {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    // `export const foo = 1` -> `export const a = 1`
+                    if let Some(declaration) = &decl.declaration {
+                        declaration.bound_names(&mut |ident| {
+                            let name = own_renames.get(ident.name.as_str());
+                            if let (Some(name), Some(symbol_id)) = (name, ident.symbol_id.get()) {
+                                names.insert(symbol_id, ast.atom(name));
+                            }
+                        });
+                    }
+                    let source_renames = decl
+                        .source
+                        .as_ref()
+                        .and_then(|source| self.dependency(module_id, source))
+                        .map(|source_id| &renames[source_id]);
+                    for specifier in decl.specifiers.iter_mut() {
+                        // `export { foo } from './module'` -> `export { a as foo } from './module'`
+                        if let Some(name) = source_renames
+                            .and_then(|renames| renames.get(specifier.local.name().as_str()))
+                        {
+                            specifier.local =
+                                ast.module_export_name_identifier_name(SPAN, name.as_str());
+                        }
+                        if let Some(name) = own_renames.get(specifier.exported.name().as_str()) {
+                            specifier.exported =
+                                ast.module_export_name_identifier_name(SPAN, name.as_str());
+                        }
+                    }
+                }
+                Statement::ImportDeclaration(decl) => {
+                    let Some(source_id) = self.dependency(module_id, &decl.source) else {
+                        continue;
+                    };
+                    for specifier in
+                        decl.specifiers.iter_mut().flat_map(|specifiers| specifiers.iter_mut())
+                    {
+                        let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier
+                        else {
+                            continue;
+                        };
+                        let Some(name) = renames[source_id].get(specifier.imported.name().as_str())
+                        else {
+                            continue;
+                        };
+                        specifier.imported =
+                            ast.module_export_name_identifier_name(SPAN, name.as_str());
+                        if let Some(symbol_id) = specifier.local.symbol_id.get() {
+                            imports.push((symbol_id, name));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // `import { a as foo }` -> `import { a }`
+        for (symbol_id, name) in imports {
+            if taken.insert(name.clone()) {
+                names.insert(symbol_id, ast.atom(name));
+            }
+        }
+        if !names.is_empty() {
+            Renamer { symbols: &symbols, names: &names }.visit_program(program);
+        }
+    }
+}
+
+/// Value of the export `name` of `module_id`, following re-exports.
+fn resolve_constant<'c, 'a>(
+    constants: &'c IndexVec<ModuleId, ConstantExports<'a>>,
+    module_id: ModuleId,
+    name: &str,
+    depth: usize,
+) -> Option<&'c Expression<'a>> {
+    // Re-exports may form a cycle
+    if depth > constants.len() {
+        return None;
+    }
+    let exports = &constants[module_id];
+    if let Some(value) = exports.values.get(name) {
+        return Some(value);
+    }
+    let (source_id, import_name) = exports.forwards.get(name)?;
+    resolve_constant(constants, *source_id, import_name, depth + 1)
+}
+
+/// Remove exports which are not in `used`, e.g. `export const a = 1` -> `const a = 1`.
+/// Returns whether any export was removed.
+fn remove_unused_exports(program: &mut Program, used: &FxHashSet<CompactStr>) -> bool {
+    let mut changed = false;
+    for stmt in program.body.iter_mut() {
+        let Statement::ExportNamedDeclaration(decl) = stmt else { continue };
+        if let Some(declaration) = &decl.declaration {
+            // TypeScript declarations are not bound names
+            if !matches!(
+                declaration,
+                Declaration::VariableDeclaration(_)
+                    | Declaration::FunctionDeclaration(_)
+                    | Declaration::ClassDeclaration(_)
+            ) {
+                continue;
+            }
+            let mut is_used = false;
+            declaration.bound_names(&mut |ident| is_used |= used.contains(ident.name.as_str()));
+            if !is_used {
+                let declaration = decl.declaration.take().unwrap();
+                *stmt = Statement::from(declaration);
+                changed = true;
+            }
+            continue;
+        }
+        let len = decl.specifiers.len();
+        decl.specifiers.retain(|specifier| used.contains(specifier.exported.name().as_str()));
+        changed |= decl.specifiers.len() != len;
+    }
+    // `export {}` exports nothing, but `export {} from './module'` still executes the module
+    program.body.retain(|stmt| {
+        !matches!(stmt, Statement::ExportNamedDeclaration(decl)
+            if decl.declaration.is_none() && decl.source.is_none() && decl.specifiers.is_empty())
+    });
+    changed
+}
+
+/// Short names for the `used` exports of an internal module, by their original names.
+///
+/// Names are taken in order of appearance, and never shadow other bindings of the module, so
+/// that exported declarations can be renamed along with their exports.
+fn short_export_names(
+    program: &Program,
+    used: &FxHashSet<CompactStr>,
+) -> FxHashMap<CompactStr, CompactStr> {
+    let (symbols, scopes) = semantic(program);
+
+    let mut export_names = vec![];
+    let mut declared_names = vec![];
+    let mut specifier_symbols = FxHashSet::default();
+    for stmt in &program.body {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    declaration.bound_names(&mut |ident| {
+                        if let Some(symbol_id) = ident.symbol_id.get() {
+                            declared_names.push((ident.name.as_str(), symbol_id));
+                        }
+                        export_names.push(ident.name.as_str());
+                    });
+                }
+                for specifier in &decl.specifiers {
+                    if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                        if decl.source.is_none() {
+                            specifier_symbols.extend(reference_symbol(&symbols, ident));
+                        }
+                    }
+                    export_names.push(specifier.exported.name().as_str());
+                }
+            }
+            Statement::ExportDefaultDeclaration(_) => export_names.push("default"),
+            _ => {}
+        }
+    }
+
+    // `export const foo = 1; export { foo as bar }` declares a binding which can not be renamed
+    // to two names
+    let fixed_names = declared_names
+        .iter()
+        .filter(|(_, symbol_id)| specifier_symbols.contains(symbol_id))
+        .map(|(name, _)| *name)
+        .collect::<FxHashSet<_>>();
+
+    let taken = scopes
+        .iter_bindings()
+        .map(|(_, _, name)| name.as_str())
+        .chain(scopes.root_unresolved_references().keys().map(CompactStr::as_str))
+        .chain(export_names.iter().copied())
+        .collect::<FxHashSet<_>>();
+    let mut short_names = (0..)
+        .map(base54)
+        .filter(|name| {
+            !is_reserved_word(name, ESVersion::ESNext, true) && !taken.contains(name.as_str())
+        })
+        .peekable();
+
+    let mut renames = FxHashMap::default();
+    for name in export_names {
+        if name == "default"
+            || !used.contains(name)
+            || fixed_names.contains(name)
+            || renames.contains_key(name)
+        {
+            continue;
+        }
+        if short_names.peek().is_some_and(|short_name| short_name.len() < name.len()) {
+            renames.insert(CompactStr::new(name), short_names.next().unwrap());
+        }
+    }
+    renames
+}
+
+fn semantic(program: &Program) -> (SymbolTable, ScopeTree) {
+    SemanticBuilder::new("").build(program).semantic.into_symbol_table_and_scope_tree()
+}
+
+fn reference_symbol(symbols: &SymbolTable, ident: &IdentifierReference) -> Option<SymbolId> {
+    ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id())
+}
+
+/// `1`, `"a"`, `-1`, `void 0` and other values which can be copied into other modules.
+fn is_constant(expr: &Expression) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(unary) => {
+            matches!(
+                unary.operator,
+                UnaryOperator::UnaryNegation | UnaryOperator::LogicalNot | UnaryOperator::Void
+            ) && is_constant(&unary.argument)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `value` is small enough to be copied into several references.
+fn is_small(value: &Expression) -> bool {
+    match value {
+        Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => true,
+        Expression::NumericLiteral(lit) => lit.value.fract() == 0.0 && lit.value < 1000.0,
+        Expression::StringLiteral(lit) => lit.value.len() <= 3,
+        Expression::UnaryExpression(unary) => is_small(&unary.argument),
+        _ => false,
+    }
+}
+
+/// Replaces references to the given symbols with their values.
+struct ConstantInliner<'a, 's> {
+    allocator: &'a Allocator,
+    symbols: &'s SymbolTable,
+    values: FxHashMap<SymbolId, Expression<'a>>,
+    /// Number of replaced references of each symbol
+    inlined: FxHashMap<SymbolId, usize>,
+}
+
+impl<'a, 's> VisitMut<'a> for ConstantInliner<'a, 's> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let symbol_id = match &*expr {
+            Expression::Identifier(ident) => reference_symbol(self.symbols, ident),
+            _ => None,
+        };
+        if let Some(symbol_id) = symbol_id {
+            if let Some(value) = self.values.get(&symbol_id) {
+                *expr = value.clone_in(self.allocator);
+                *self.inlined.entry(symbol_id).or_default() += 1;
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        // `{ DEBUG }` -> `{ DEBUG: false }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+/// Finds `import()` and `require`, which load modules the graph does not know about.
+#[derive(Default)]
+struct ModuleLoaderFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ModuleLoaderFinder {
+    fn visit_import_expression(&mut self, _it: &ImportExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "require" {
+            self.found = true;
+        }
+    }
+}
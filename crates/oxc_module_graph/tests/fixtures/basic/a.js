@@ -0,0 +1,5 @@
+import { b } from './b';
+import { c } from './c.js';
+import missing from './missing';
+
+export const a = b + c;
@@ -0,0 +1,4 @@
+import type { C } from './c';
+import { c } from './c';
+
+export const b: C = c;
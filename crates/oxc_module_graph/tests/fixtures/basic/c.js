@@ -0,0 +1,3 @@
+import fs from 'node:fs';
+
+export const c = fs.existsSync('.') ? 1 : 0;
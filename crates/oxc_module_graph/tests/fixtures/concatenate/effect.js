@@ -0,0 +1,3 @@
+console.log('loaded');
+
+export const value = 1;
@@ -0,0 +1,5 @@
+export default function (value) {
+  return `[${value}]`;
+}
+
+export const prefix = '>';
@@ -0,0 +1,13 @@
+import { join } from 'node:path';
+import { add, helper as mathHelper } from './math.js';
+import format from './format.js';
+
+export const total = add(1, 2);
+export { mathHelper };
+export * from './format.js';
+
+function helper() {
+  return format(join('a', mathHelper('b')));
+}
+
+console.log(helper());
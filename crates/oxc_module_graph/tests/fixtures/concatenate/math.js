@@ -0,0 +1,7 @@
+export function add(a, b) {
+  return helper(a) + helper(b);
+}
+
+export function helper(value) {
+  return value;
+}
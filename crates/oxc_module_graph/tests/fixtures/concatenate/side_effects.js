@@ -0,0 +1,3 @@
+import { value } from './effect.js';
+
+console.log(value);
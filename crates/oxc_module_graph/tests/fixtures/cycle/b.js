@@ -0,0 +1,4 @@
+export { a } from './a.js';
+import { c } from './c.js';
+
+export const b = () => c;
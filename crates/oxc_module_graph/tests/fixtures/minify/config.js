@@ -0,0 +1,7 @@
+export const DEBUG = false;
+export const VERSION = '1.0';
+export const unused = 'unused';
+
+export function formatMessage(message) {
+  return '> ' + message;
+}
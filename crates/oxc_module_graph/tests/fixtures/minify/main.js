@@ -0,0 +1,11 @@
+import { DEBUG, VERSION, formatMessage } from './config.js';
+import { log } from './logger.js';
+import * as math from './math.js';
+
+if (DEBUG) {
+  log('debug build');
+}
+
+export function greet(name) {
+  return log(formatMessage(name) + VERSION + math.add(1, 2));
+}
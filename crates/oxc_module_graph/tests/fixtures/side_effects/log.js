@@ -0,0 +1,5 @@
+console.log('loaded');
+
+export function log(message) {
+  console.log(message);
+}
@@ -0,0 +1,6 @@
+import { used, unused } from 'pure';
+import { helper } from 'pure/helper.js';
+import 'pure/polyfill.js';
+import { log } from './log.js';
+
+log(used());
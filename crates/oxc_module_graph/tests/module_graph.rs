@@ -0,0 +1,161 @@
+use std::path::PathBuf;
+
+use oxc_minifier::MinifierOptions;
+use oxc_module_graph::{Concatenator, GraphMinifier, ModuleGraph, ModuleGraphBuilder, ModuleId};
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn build(entry: &str) -> ModuleGraph {
+    let ret = ModuleGraphBuilder::default().build(&[fixture(entry)]);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    ret.graph
+}
+
+fn file_names(graph: &ModuleGraph, module_ids: &[ModuleId]) -> Vec<String> {
+    module_ids
+        .iter()
+        .map(|&module_id| {
+            graph.module(module_id).path.file_name().unwrap().to_string_lossy().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn basic() {
+    let graph = build("basic/a.js");
+    assert_eq!(graph.len(), 3);
+    assert_eq!(file_names(&graph, graph.entries()), ["a.js"]);
+    assert_eq!(file_names(&graph, &graph.topological_order()), ["c.js", "b.ts", "a.js"]);
+    assert!(graph.cycles().is_empty());
+
+    let a = graph.entries()[0];
+    let dependencies = &graph.module(a).dependencies;
+    assert_eq!(
+        dependencies.iter().map(|dependency| dependency.specifier.as_str()).collect::<Vec<_>>(),
+        ["./b", "./c.js", "./missing"]
+    );
+    assert!(dependencies[2].resolved_path.is_none());
+    assert_eq!(file_names(&graph, &graph.dependencies(a).collect::<Vec<_>>()), ["b.ts", "c.js"]);
+
+    let c = graph.dependencies(a).nth(1).unwrap();
+    assert_eq!(file_names(&graph, &graph.dependents(c).collect::<Vec<_>>()), ["a.js", "b.ts"]);
+    // `node:fs` is not part of the graph
+    assert_eq!(graph.dependencies(c).count(), 0);
+
+    let b = graph.dependencies(a).next().unwrap();
+    assert!(!graph.module(b).dependencies[0].is_type);
+    assert_eq!(graph.module(c).record.local_export_entries.len(), 1);
+}
+
+#[test]
+fn cycle() {
+    let graph = build("cycle/a.js");
+    assert_eq!(graph.len(), 3);
+    let cycles = graph.cycles();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(file_names(&graph, &cycles[0]), ["a.js", "b.js"]);
+    assert_eq!(file_names(&graph, &graph.topological_order()), ["c.js", "b.js", "a.js"]);
+}
+
+#[test]
+fn missing_entry() {
+    let ret = ModuleGraphBuilder::default().build(&[fixture("missing.js")]);
+    assert!(ret.graph.is_empty());
+    assert!(ret.graph.entries().is_empty());
+    assert_eq!(ret.errors.len(), 1);
+}
+
+#[test]
+fn concatenate() {
+    let graph = build("concatenate/main.js");
+    let ret = Concatenator::new(&graph).build(graph.entries()[0]);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let source_text = ret.source_text;
+
+    assert!(source_text.starts_with("import { join } from \"node:path\";\n"));
+    assert!(!source_text.contains("./math.js"));
+    assert!(source_text.contains("function helper(value) {"));
+    assert!(source_text.contains("function format_default(value) {"));
+    assert!(source_text.contains("function helper$1() {"));
+    assert!(source_text.contains("return format_default(join(\"a\", helper(\"b\")));"));
+    assert!(source_text.contains("console.log(helper$1());"));
+    assert!(source_text.ends_with("export { total, helper as mathHelper, prefix };\n"));
+    // Modules are concatenated in execution order
+    let math = source_text.find("function add").unwrap();
+    let format = source_text.find("function format_default").unwrap();
+    let main = source_text.find("const total").unwrap();
+    assert!(math < format && format < main);
+}
+
+#[test]
+fn concatenate_errors() {
+    let graph = build("concatenate/side_effects.js");
+    let ret = Concatenator::new(&graph).build(graph.entries()[0]);
+    assert!(ret.source_text.is_empty());
+    assert_eq!(ret.errors.len(), 1);
+    assert!(ret.errors[0].to_string().contains("has side effects"));
+
+    let graph = build("cycle/a.js");
+    let ret = Concatenator::new(&graph).build(graph.entries()[0]);
+    assert_eq!(ret.errors.len(), 1);
+    assert!(ret.errors[0].to_string().contains("import cycle"));
+}
+
+#[test]
+fn minify() {
+    let graph = build("minify/main.js");
+    let ret = GraphMinifier::new(&graph, MinifierOptions::default()).build();
+    let source_text = |file_name: &str| {
+        let (module_id, _) = graph
+            .modules()
+            .find(|(_, module)| module.path.file_name().unwrap() == file_name)
+            .unwrap();
+        ret.source_texts[module_id].as_str()
+    };
+
+    // Constants are inlined, and their imports removed
+    let main = source_text("main.js");
+    assert!(!main.contains("DEBUG"));
+    assert!(!main.contains("debug build"));
+    assert!(main.contains("\"1.0\""));
+    // Entry points keep their exports
+    assert!(main.contains("export function greet("));
+
+    // Unused exports are removed, and used ones are renamed
+    let config = source_text("config.js");
+    assert!(!config.contains("unused"));
+    assert!(!config.contains("formatMessage"));
+    assert!(config.contains("export function a("));
+    assert!(source_text("logger.js").contains("export function a("));
+    // Namespace imports keep the names of the exports
+    assert!(source_text("math.js").contains("export function add("));
+}
+
+#[test]
+fn minify_side_effects() {
+    let graph = build("side_effects/main.js");
+    let main = graph.module(graph.entries()[0]);
+    let side_effects = main
+        .dependencies
+        .iter()
+        .map(|dependency| (dependency.specifier.as_str(), dependency.side_effects));
+    assert_eq!(
+        side_effects.collect::<Vec<_>>(),
+        [
+            ("pure", false),
+            ("pure/helper.js", false),
+            ("pure/polyfill.js", true),
+            ("./log.js", true)
+        ]
+    );
+
+    let ret = GraphMinifier::new(&graph, MinifierOptions::default()).build();
+    let main = &ret.source_texts[graph.entries()[0]];
+    // Imports of side effect free modules are removed unless their bindings are used
+    assert!(main.contains("\"pure\""));
+    assert!(!main.contains("helper.js"));
+    assert!(main.contains("polyfill.js"));
+    assert!(main.contains("log.js"));
+}
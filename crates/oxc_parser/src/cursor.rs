@@ -8,7 +8,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     diagnostics,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
-    Context, ParserImpl,
+    token, Context, ParserImpl,
 };
 
 #[derive(Clone, Copy)]
@@ -17,6 +17,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_len: usize,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -124,6 +125,7 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.push_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -132,10 +134,21 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.push_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the current token, if collecting tokens
+    #[inline]
+    fn push_token(&mut self) {
+        if self.options.tokens {
+            if let Some(token) = token::Token::from_lexer_token(self.token) {
+                self.tokens.push(token);
+            }
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     pub(crate) fn eat(&mut self, kind: Kind) -> bool {
@@ -279,17 +292,24 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_len,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        self.tokens.truncate(tokens_len);
     }
 
     /// # Errors
@@ -14,100 +14,132 @@ where
     OxcDiagnostic::error(message).with_error_code("TS", code)
 }
 
+/// Create an error with a stable oxc code, e.g. `oxc(parse::unterminated-string)`.
+/// Every code must have an entry in [`explain`].
+#[inline]
+fn oxc_error<M>(code: &'static str, message: M) -> OxcDiagnostic
+where
+    M: Into<Cow<'static, str>>,
+{
+    OxcDiagnostic::error(message).with_error_code("oxc", code)
+}
+
 #[cold]
 pub fn redeclaration(x0: &str, declare_span: Span, redeclare_span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
-        declare_span.label(format!("`{x0}` has already been declared here")),
-        redeclare_span.label("It can not be redeclared here"),
-    ])
+    oxc_error("parse::redeclaration", format!("Identifier `{x0}` has already been declared"))
+        .with_labels([
+            declare_span.label(format!("`{x0}` has already been declared here")),
+            redeclare_span.label("It can not be redeclared here"),
+        ])
 }
 
 #[cold]
 pub fn overlong_source() -> OxcDiagnostic {
-    OxcDiagnostic::error("Source length exceeds 4 GiB limit")
+    oxc_error("parse::overlong-source", "Source length exceeds 4 GiB limit")
 }
 
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Flow is not supported").with_label(span)
+    oxc_error("parse::flow", "Flow is not supported").with_label(span)
+}
+
+#[cold]
+pub fn flow_syntax(span: Span, construct: &str) -> OxcDiagnostic {
+    oxc_error("parse::flow-syntax", "Flow is not supported")
+        .with_label(span.label(format!("Flow {construct}")))
+}
+
+#[cold]
+pub fn json_syntax(span: Span) -> OxcDiagnostic {
+    oxc_error("parse::json-syntax", "Unexpected syntax in JSON").with_label(span)
 }
 
 #[cold]
 pub fn unexpected_token(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected token").with_label(span)
+    oxc_error("parse::unexpected-token", "Unexpected token").with_label(span)
 }
 
 #[cold]
 pub fn expect_token(x0: &str, x1: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected `{x0}` but found `{x1}`"))
+    oxc_error("parse::expected-token", format!("Expected `{x0}` but found `{x1}`"))
         .with_label(span.label(format!("`{x0}` expected")))
 }
 
 #[cold]
 pub fn invalid_escape_sequence(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid escape sequence").with_label(span)
+    oxc_error("parse::invalid-escape-sequence", "Invalid escape sequence").with_label(span)
 }
 
 #[cold]
 pub fn unicode_escape_sequence(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid Unicode escape sequence").with_label(span)
+    oxc_error("parse::invalid-unicode-escape-sequence", "Invalid Unicode escape sequence")
+        .with_label(span)
 }
 
 #[cold]
 pub fn invalid_character(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Invalid Character `{x0}`")).with_label(span1)
+    oxc_error("parse::invalid-character", format!("Invalid Character `{x0}`")).with_label(span1)
 }
 
 #[cold]
 pub fn invalid_number_end(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid characters after number").with_label(span)
+    oxc_error("parse::invalid-number-end", "Invalid characters after number").with_label(span)
 }
 
 #[cold]
 pub fn unterminated_multi_line_comment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated multiline comment").with_label(span)
+    oxc_error("parse::unterminated-multiline-comment", "Unterminated multiline comment")
+        .with_label(span)
 }
 
 #[cold]
 pub fn unterminated_string(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated string").with_label(span)
+    oxc_error("parse::unterminated-string", "Unterminated string").with_label(span)
 }
 
 #[cold]
 pub fn reg_exp_flag(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Unexpected flag {x0} in regular expression literal"))
-        .with_label(span1)
+    oxc_error(
+        "parse::invalid-regexp-flag",
+        format!("Unexpected flag {x0} in regular expression literal"),
+    )
+    .with_label(span1)
 }
 
 #[cold]
 pub fn reg_exp_flag_twice(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Flag {x0} is mentioned twice in regular expression literal"))
-        .with_label(span1)
+    oxc_error(
+        "parse::duplicate-regexp-flag",
+        format!("Flag {x0} is mentioned twice in regular expression literal"),
+    )
+    .with_label(span1)
 }
 
 #[cold]
 pub fn unexpected_end(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected end of file").with_label(span)
+    oxc_error("parse::unexpected-end", "Unexpected end of file").with_label(span)
 }
 
 #[cold]
 pub fn unterminated_reg_exp(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated regular expression").with_label(span)
+    oxc_error("parse::unterminated-regexp", "Unterminated regular expression").with_label(span)
 }
 
 #[cold]
 pub fn invalid_number(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Invalid Number {x0}")).with_label(span1)
+    oxc_error("parse::invalid-number", format!("Invalid Number {x0}")).with_label(span1)
 }
 
 #[cold]
 pub fn escaped_keyword(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Keywords cannot contain escape characters").with_label(span)
+    oxc_error("parse::escaped-keyword", "Keywords cannot contain escape characters")
+        .with_label(span)
 }
 
 #[cold]
 pub fn auto_semicolon_insertion(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "parse::missing-semicolon",
         "Expected a semicolon or an implicit semicolon after a statement, but found none",
     )
     .with_help("Try insert a semicolon here")
@@ -116,41 +148,57 @@ pub fn auto_semicolon_insertion(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn lineterminator_before_arrow(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Line terminator not permitted before arrow").with_label(span)
+    oxc_error("parse::line-terminator-before-arrow", "Line terminator not permitted before arrow")
+        .with_label(span)
 }
 
 #[cold]
 pub fn invalid_destrucuring_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing initializer in destructuring declaration").with_label(span)
+    oxc_error(
+        "parse::missing-destructuring-initializer",
+        "Missing initializer in destructuring declaration",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn missinginitializer_in_const(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing initializer in const declaration").with_label(span)
+    oxc_error("parse::missing-const-initializer", "Missing initializer in const declaration")
+        .with_label(span)
 }
 
 #[cold]
 pub fn lexical_declaration_single_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Lexical declaration cannot appear in a single-statement context")
-        .with_help("Wrap this declaration in a block statement")
-        .with_label(span)
+    oxc_error(
+        "parse::lexical-declaration-single-statement",
+        "Lexical declaration cannot appear in a single-statement context",
+    )
+    .with_help("Wrap this declaration in a block statement")
+    .with_label(span)
 }
 
 #[cold]
 pub fn async_function_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Async functions can only be declared at the top level or inside a block")
-        .with_label(span)
+    oxc_error(
+        "parse::async-function-declaration",
+        "Async functions can only be declared at the top level or inside a block",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn generator_function_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Generators can only be declared at the top level or inside a block")
-        .with_label(span)
+    oxc_error(
+        "parse::generator-function-declaration",
+        "Generators can only be declared at the top level or inside a block",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn await_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "parse::await-outside-async",
         "`await` is only allowed within async functions and at the top levels of modules",
     )
     .with_label(span)
@@ -158,95 +206,120 @@ pub fn await_expression(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn yield_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'yield' expression is only allowed in a generator body.")
-        .with_label(span)
+    oxc_error(
+        "parse::yield-outside-generator",
+        "A 'yield' expression is only allowed in a generator body.",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn class_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid class declaration")
+    oxc_error("parse::class-declaration", "Invalid class declaration")
         .with_help("Classes can only be declared at top level or inside a block")
         .with_label(span)
 }
 
 #[cold]
 pub fn binding_rest_element_last(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest element must be last in a destructuring pattern").with_label(span)
+    oxc_error(
+        "parse::rest-element-not-last",
+        "A rest element must be last in a destructuring pattern",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn rest_parameter_last(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter must be last in a parameter list").with_label(span)
+    oxc_error("parse::rest-parameter-not-last", "A rest parameter must be last in a parameter list")
+        .with_label(span)
 }
 
 #[cold]
 pub fn spread_last_element(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Spread must be last element").with_label(span)
+    oxc_error("parse::spread-not-last", "Spread must be last element").with_label(span)
 }
 
 #[cold]
 pub fn binding_rest_element_trailing_comma(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected trailing comma after rest element").with_label(span)
+    oxc_error("parse::rest-element-trailing-comma", "Unexpected trailing comma after rest element")
+        .with_label(span)
 }
 
 #[cold]
 pub fn invalid_binding_rest_element(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid rest element")
+    oxc_error("parse::invalid-rest-element", "Invalid rest element")
         .with_help("Expected identifier in rest element")
         .with_label(span)
 }
 
 #[cold]
 pub fn a_rest_parameter_cannot_be_optional(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter cannot be optional").with_label(span)
+    oxc_error("parse::optional-rest-parameter", "A rest parameter cannot be optional")
+        .with_label(span)
 }
 
 #[cold]
 pub fn invalid_assignment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot assign to this expression").with_label(span)
+    oxc_error("parse::invalid-assignment", "Cannot assign to this expression").with_label(span)
 }
 
 #[cold]
 pub fn new_optional_chain(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Optional chaining cannot appear in the callee of new expressions")
-        .with_label(span)
+    oxc_error(
+        "parse::new-optional-chain",
+        "Optional chaining cannot appear in the callee of new expressions",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn for_loop_async_of(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The left-hand side of a `for...of` statement may not be `async`")
-        .with_label(span)
+    oxc_error(
+        "parse::for-loop-async-of",
+        "The left-hand side of a `for...of` statement may not be `async`",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn for_await(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("await can only be used in conjunction with `for...of` statements")
-        .with_label(span)
+    oxc_error(
+        "parse::invalid-for-await",
+        "await can only be used in conjunction with `for...of` statements",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn new_dynamic_import(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use new with dynamic import").with_label(span)
+    oxc_error("parse::new-dynamic-import", "Cannot use new with dynamic import").with_label(span)
 }
 
 #[cold]
 pub fn private_name_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes can't have an element named '#constructor'").with_label(span)
+    oxc_error(
+        "parse::private-name-constructor",
+        "Classes can't have an element named '#constructor'",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn static_prototype(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes may not have a static property named prototype").with_label(span)
+    oxc_error("parse::static-prototype", "Classes may not have a static property named prototype")
+        .with_label(span)
 }
 
 #[cold]
 pub fn constructor_getter_setter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't have get/set modifier").with_label(span)
+    oxc_error("parse::constructor-accessor", "Constructor can't have get/set modifier")
+        .with_label(span)
 }
 
 #[cold]
 pub fn constructor_async(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be an async method").with_label(span)
+    oxc_error("parse::constructor-async", "Constructor can't be an async method").with_label(span)
 }
 
 #[cold]
@@ -257,65 +330,87 @@ pub fn optional_accessor_property(span: Span) -> OxcDiagnostic {
 #[cold]
 pub fn optional_definite_property(span: Span) -> OxcDiagnostic {
     // NOTE: could not find an error code when tsc parses this; its parser panics.
-    OxcDiagnostic::error("A property cannot be both optional and definite.")
-        .with_label(span)
-        .with_help("Remove either the `?` or the `!`")
+    oxc_error(
+        "parse::optional-definite-property",
+        "A property cannot be both optional and definite.",
+    )
+    .with_label(span)
+    .with_help("Remove either the `?` or the `!`")
 }
 
 #[cold]
 pub fn identifier_async(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot use `{x0}` as an identifier in an async context"))
-        .with_label(span1)
+    oxc_error(
+        "parse::identifier-async",
+        format!("Cannot use `{x0}` as an identifier in an async context"),
+    )
+    .with_label(span1)
 }
 
 #[cold]
 pub fn identifier_generator(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot use `{x0}` as an identifier in a generator context"))
-        .with_label(span1)
+    oxc_error(
+        "parse::identifier-generator",
+        format!("Cannot use `{x0}` as an identifier in a generator context"),
+    )
+    .with_label(span1)
 }
 
 #[cold]
 pub fn constructor_generator(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be a generator").with_label(span)
+    oxc_error("parse::constructor-generator", "Constructor can't be a generator").with_label(span)
 }
 
 #[cold]
 pub fn field_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Classes can't have a field named 'constructor'").with_label(span)
+    oxc_error("parse::field-constructor", "Classes can't have a field named 'constructor'")
+        .with_label(span)
 }
 
 #[cold]
 pub fn export_lone_surrogate(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("An export name cannot include a unicode lone surrogate").with_label(span)
+    oxc_error(
+        "parse::export-lone-surrogate",
+        "An export name cannot include a unicode lone surrogate",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn export_named_string(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A string literal cannot be used as an exported binding without `from`")
-        .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
-        .with_label(span2)
+    oxc_error(
+        "parse::export-named-string",
+        "A string literal cannot be used as an exported binding without `from`",
+    )
+    .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
+    .with_label(span2)
 }
 
 #[cold]
 pub fn export_reserved_word(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A reserved word cannot be used as an exported binding without `from`")
-        .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
-        .with_label(span2)
+    oxc_error(
+        "parse::export-reserved-word",
+        "A reserved word cannot be used as an exported binding without `from`",
+    )
+    .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
+    .with_label(span2)
 }
 
 #[cold]
 pub fn template_literal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Bad escape sequence in untagged template literal").with_label(span)
+    oxc_error("parse::invalid-template-escape", "Bad escape sequence in untagged template literal")
+        .with_label(span)
 }
 
 #[cold]
 pub fn empty_parenthesized_expression(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Empty parenthesized expression").with_label(span)
+    oxc_error("parse::empty-parenthesized-expression", "Empty parenthesized expression")
+        .with_label(span)
 }
 
 #[cold]
 pub fn illegal_newline(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Illegal newline after {x0}")).with_labels([
+    oxc_error("parse::illegal-newline", format!("Illegal newline after {x0}")).with_labels([
         span1.label(format!("{x0} starts here")),
         span2.label("A newline is not expected here"),
     ])
@@ -323,8 +418,11 @@ pub fn illegal_newline(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn optional_chain_tagged_template(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Tagged template expressions are not permitted in an optional chain")
-        .with_label(span)
+    oxc_error(
+        "parse::optional-chain-tagged-template",
+        "Tagged template expressions are not permitted in an optional chain",
+    )
+    .with_label(span)
 }
 
 #[cold]
@@ -339,21 +437,24 @@ pub fn ts_arrow_function_this_parameter(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn unexpected_super(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be used with function calls or in property accesses")
-        .with_help("replace with `super()` or `super.prop` or `super[prop]`")
-        .with_label(span)
+    oxc_error(
+        "parse::unexpected-super",
+        "'super' can only be used with function calls or in property accesses",
+    )
+    .with_help("replace with `super()` or `super.prop` or `super[prop]`")
+    .with_label(span)
 }
 
 #[cold]
 pub fn expect_function_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Expected function name")
+    oxc_error("parse::missing-function-name", "Expected function name")
         .with_help("Function name is required in function declaration or named export")
         .with_label(span)
 }
 
 #[cold]
 pub fn expect_catch_finally(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing catch or finally clause").with_label(span)
+    oxc_error("parse::missing-catch-or-finally", "Missing catch or finally clause").with_label(span)
 }
 
 #[cold]
@@ -378,22 +479,32 @@ pub fn jsx_expressions_may_not_use_the_comma_operator(span: Span) -> OxcDiagnost
 
 #[cold]
 pub fn line_terminator_before_using_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Line terminator not permitted before using declaration.").with_label(span)
+    oxc_error(
+        "parse::line-terminator-before-using-declaration",
+        "Line terminator not permitted before using declaration.",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn await_in_using_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Await is not allowed in using declarations.").with_label(span)
+    oxc_error("parse::await-in-using-declaration", "Await is not allowed in using declarations.")
+        .with_label(span)
 }
 
 #[cold]
 pub fn invalid_identifier_in_using_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations may not have binding patterns.").with_label(span)
+    oxc_error(
+        "parse::using-declaration-binding-pattern",
+        "Using declarations may not have binding patterns.",
+    )
+    .with_label(span)
 }
 
 #[cold]
 pub fn await_using_declaration_not_allowed_in_for_in_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "parse::await-using-in-for-in",
         "The left-hand side of a for...in statement cannot be an await using declaration.",
     )
     .with_label(span)
@@ -401,7 +512,8 @@ pub fn await_using_declaration_not_allowed_in_for_in_statement(span: Span) -> Ox
 
 #[cold]
 pub fn using_declaration_not_allowed_in_for_in_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "parse::using-in-for-in",
         "The left-hand side of a for...in statement cannot be an using declaration.",
     )
     .with_label(span)
@@ -409,7 +521,8 @@ pub fn using_declaration_not_allowed_in_for_in_statement(span: Span) -> OxcDiagn
 
 #[cold]
 pub fn using_declarations_must_be_initialized(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations must have an initializer.").with_label(span)
+    oxc_error("parse::missing-using-initializer", "Using declarations must have an initializer.")
+        .with_label(span)
 }
 
 /// TS(1093)
@@ -421,16 +534,22 @@ pub fn static_constructor(span: Span) -> OxcDiagnostic {
 
 #[cold]
 pub fn jsx_element_no_match(span: Span, span1: Span, name: &str) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected corresponding JSX closing tag for '{name}'."))
-        .with_labels([span, span1])
+    oxc_error(
+        "parse::jsx-closing-tag-mismatch",
+        format!("Expected corresponding JSX closing tag for '{name}'."),
+    )
+    .with_labels([span, span1])
 }
 
 // ================================= MODIFIERS =================================
 
 #[cold]
 pub fn modifier_cannot_be_used_here(modifier: &Modifier) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("'{}' modifier cannot be used here.", modifier.kind))
-        .with_label(modifier.span)
+    oxc_error(
+        "parse::invalid-modifier",
+        format!("'{}' modifier cannot be used here.", modifier.kind),
+    )
+    .with_label(modifier.span)
 }
 
 /// TS(1030)
@@ -474,3 +593,79 @@ pub fn computed_property_names_not_allowed_in_enums(span: Span) -> OxcDiagnostic
 pub fn enum_member_cannot_have_numeric_name(span: Span) -> OxcDiagnostic {
     ts_error("2452", "An enum member cannot have a numeric name.").with_label(span)
 }
+
+/// Extended explanation of parser error codes, such as `parse::redeclaration`.
+/// Returns `None` for unknown codes.
+pub fn explain(code: &str) -> Option<&'static str> {
+    let explanation = match code {
+        "parse::redeclaration" => "A name was declared twice where duplicates are not allowed, such as two import attributes with the same key: `import a from './a.json' with { type: 'json', type: 'json' }`.",
+        "parse::overlong-source" => "Oxc stores spans as 32-bit offsets, so source texts larger than 4 GiB cannot be parsed.",
+        "parse::flow" => "The file contains a `// @flow` or `/* @flow */` pragma. Flow type annotations are not supported, strip them with a Flow-aware tool before parsing.",
+        "parse::flow-syntax" => "Flow syntax such as type annotations, `type` aliases and `import type` was found in a JavaScript file. Flow is not supported, the construct is skipped so the rest of the file can be parsed. Strip Flow types with a Flow-aware tool, or rename the file to `.ts` if it is valid TypeScript.",
+        "parse::json-syntax" => "JSON files only contain objects with double quoted keys, arrays, double quoted strings, numbers, `true`, `false` and `null`. Comments and trailing commas are allowed as in JSONC, other JavaScript syntax such as identifiers, single quoted strings and function calls is not.",
+        "parse::unexpected-token" => "The parser found a token that cannot start or continue the current construct. This is usually caused by a typo, a missing operator or an unbalanced bracket.",
+        "parse::expected-token" => "A specific token was required at this position, for example the closing `)` of a call expression or the `=>` of an arrow function.",
+        "parse::invalid-escape-sequence" => "A string literal contains a backslash escape that is not valid, for example a `\\x` escape that is not followed by two hexadecimal digits.",
+        "parse::invalid-unicode-escape-sequence" => "A `\\u` escape must be followed by exactly four hexadecimal digits (`\\u0041`) or a code point in braces (`\\u{1F600}`) no larger than `0x10FFFF`.",
+        "parse::invalid-character" => "The source text contains a character that cannot start any token, for example a stray `#` or `@` outside of a private name or decorator.",
+        "parse::invalid-number-end" => "A numeric literal must not be immediately followed by an identifier start or a digit, `3in x` and `1_` are both invalid.",
+        "parse::unterminated-multiline-comment" => "A `/*` comment was opened but the closing `*/` was never found before the end of the file.",
+        "parse::unterminated-string" => "A string literal was opened but its closing quote was not found before the end of the line. Use a template literal or `\\` line continuations for multi-line strings.",
+        "parse::invalid-regexp-flag" => "Regular expression literals only accept the flags `d`, `g`, `i`, `m`, `s`, `u`, `v` and `y`.",
+        "parse::duplicate-regexp-flag" => "Each regular expression flag may only appear once, `/a/gg` is a syntax error.",
+        "parse::unexpected-end" => "The file ended in the middle of a construct, usually because of an unclosed bracket, brace or template literal.",
+        "parse::unterminated-regexp" => "A regular expression literal was opened with `/` but its closing `/` was not found before the end of the line.",
+        "parse::invalid-number" => "The numeric literal is malformed, for example `0b12`, `0x` without digits, or a numeric separator in an invalid position such as `1__0`.",
+        "parse::escaped-keyword" => "Keywords written with unicode escapes, such as `\\u0069f` for `if`, are not allowed to be used as keywords.",
+        "parse::missing-semicolon" => "Two statements were written on the same line without a semicolon between them, and automatic semicolon insertion does not apply.",
+        "parse::line-terminator-before-arrow" => "No line break is allowed between the parameters of an arrow function and its `=>`.",
+        "parse::missing-destructuring-initializer" => "Destructuring declarations such as `let { a } ;` must have an initializer, outside of `for...in` and `for...of` heads.",
+        "parse::missing-const-initializer" => "A `const` declaration must be initialized, `const a;` is a syntax error.",
+        "parse::lexical-declaration-single-statement" => "`let`, `const`, `class` and `using` declarations cannot be the direct body of `if`, `while`, `for` or a label. Wrap the declaration in a block: `if (a) { let b = 1; }`.",
+        "parse::async-function-declaration" => "Async function declarations cannot be the direct body of `if`, loops or labels. Wrap the declaration in a block.",
+        "parse::generator-function-declaration" => "Generator function declarations cannot be the direct body of `if`, loops or labels. Wrap the declaration in a block.",
+        "parse::await-outside-async" => "`await` can only be used inside an async function, or at the top level of an ES module.",
+        "parse::yield-outside-generator" => "`yield` can only be used inside a generator function (`function*`).",
+        "parse::class-declaration" => "Class declarations cannot be the direct body of `if`, loops or labels. Wrap the declaration in a block.",
+        "parse::rest-element-not-last" => "A rest element collects all remaining values, so it must be the last element of a destructuring pattern: `const [a, ...rest] = list`.",
+        "parse::rest-parameter-not-last" => "A rest parameter collects all remaining arguments, so it must be the last parameter: `function f(a, ...rest) {}`.",
+        "parse::spread-not-last" => "A spread element used as an assignment target must be the last element: `[a, ...rest] = list`.",
+        "parse::rest-element-trailing-comma" => "A rest element cannot be followed by a trailing comma: `const [...rest,] = list` is invalid.",
+        "parse::invalid-rest-element" => "The rest element of an object pattern must be a plain identifier: `const { ...rest } = obj`.",
+        "parse::optional-rest-parameter" => "A rest parameter is already optional and cannot be marked with `?`.",
+        "parse::invalid-assignment" => "The left-hand side of an assignment or update expression must be an identifier, a member expression or a destructuring pattern.",
+        "parse::new-optional-chain" => "`new a?.b()` is not allowed, optional chaining cannot be used in the callee of a `new` expression.",
+        "parse::for-loop-async-of" => "`for (async of x)` is ambiguous with an async arrow function and is not allowed. Rename the binding or wrap it in parentheses.",
+        "parse::invalid-for-await" => "`for await` is only valid together with `for...of`, not with `for...in` or a plain `for` loop.",
+        "parse::new-dynamic-import" => "`import()` is not a constructor and cannot be used with `new`.",
+        "parse::private-name-constructor" => "`#constructor` is reserved and cannot be used as a private class element name.",
+        "parse::static-prototype" => "A class cannot define a static member named `prototype`, it would conflict with the constructor's own `prototype` property.",
+        "parse::constructor-accessor" => "A class constructor cannot be declared as a getter or a setter.",
+        "parse::constructor-async" => "A class constructor cannot be an async method.",
+        "parse::optional-definite-property" => "A class property cannot be marked both optional (`?`) and definitely assigned (`!`).",
+        "parse::identifier-async" => "`await` cannot be used as an identifier inside an async function or an ES module.",
+        "parse::identifier-generator" => "`yield` cannot be used as an identifier inside a generator function or in strict mode code.",
+        "parse::constructor-generator" => "A class constructor cannot be a generator method.",
+        "parse::field-constructor" => "A class field cannot be named `constructor`.",
+        "parse::export-lone-surrogate" => "String export names must be well-formed unicode, lone surrogates such as `'\\uD800'` are not allowed.",
+        "parse::export-named-string" => "A string literal can only name a local binding when re-exporting from another module: `export { 'a' as b } from 'mod'`.",
+        "parse::export-reserved-word" => "A reserved word can only be exported when re-exporting from another module, since it cannot be a local binding.",
+        "parse::invalid-template-escape" => "Untagged template literals must only contain valid escape sequences. Invalid escapes are only allowed in tagged templates, where they produce `undefined` cooked strings.",
+        "parse::empty-parenthesized-expression" => "`()` is only valid as the parameter list of an arrow function.",
+        "parse::illegal-newline" => "A line break is not allowed at this position, for example between `async` and `function`, or between `throw` and its argument.",
+        "parse::optional-chain-tagged-template" => "Tagged templates cannot be used in an optional chain, `a?.b`\\`\\`` is a syntax error.",
+        "parse::unexpected-super" => "`super` must be called (`super()`) or have a property accessed (`super.prop`, `super[prop]`).",
+        "parse::missing-function-name" => "Function declarations require a name, unless they are the default export of a module.",
+        "parse::missing-catch-or-finally" => "A `try` block must be followed by a `catch` clause, a `finally` clause, or both.",
+        "parse::line-terminator-before-using-declaration" => "No line break is allowed between `await` and `using` in an `await using` declaration.",
+        "parse::await-in-using-declaration" => "`await using` declarations are only allowed where `await` is allowed.",
+        "parse::using-declaration-binding-pattern" => "`using` declarations only accept plain identifiers, destructuring patterns are not allowed.",
+        "parse::await-using-in-for-in" => "`await using` declarations are not allowed in the head of a `for...in` statement.",
+        "parse::using-in-for-in" => "`using` declarations are not allowed in the head of a `for...in` statement.",
+        "parse::missing-using-initializer" => "`using` declarations must have an initializer, outside of `for...of` heads.",
+        "parse::jsx-closing-tag-mismatch" => "The closing tag of a JSX element must match its opening tag, `<a></b>` is a syntax error.",
+        "parse::invalid-modifier" => "The modifier is not allowed on this kind of declaration, for example `declare` on a class method.",
+        _ => return None,
+    };
+    Some(explanation)
+}
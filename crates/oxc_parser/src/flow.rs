@@ -0,0 +1,68 @@
+//! Recovery from [Flow](https://flow.org) syntax in JavaScript files.
+//!
+//! Flow is not supported, but most of its syntax is shared with TypeScript. The shared constructs
+//! are parsed with the TypeScript parser, reported with a `parse::flow-syntax` diagnostic and
+//! removed from the AST, so parsing can continue instead of reporting a cascade of errors.
+
+use oxc_ast::ast::Statement;
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+use crate::{diagnostics, lexer::Kind, modifiers::Modifiers, ParserImpl};
+
+impl<'a> ParserImpl<'a> {
+    /// Skip a Flow type annotation `: T` of a binding, parameter or function return type.
+    pub(crate) fn skip_flow_type_annotation(&mut self) -> Result<()> {
+        if !self.source_type.is_javascript() || !self.at(Kind::Colon) {
+            return Ok(());
+        }
+        let span = self.start_span();
+        self.bump_any(); // bump `:`
+        self.eat(Kind::Question); // maybe type `?T`
+        self.with_flow_types(span, "type annotation", Self::parse_ts_type)?;
+        Ok(())
+    }
+
+    /// `type A = T` and `interface A {}` of Flow.
+    pub(crate) fn at_flow_declaration(&mut self) -> bool {
+        self.source_type.is_javascript()
+            && matches!(self.cur_kind(), Kind::Type | Kind::Interface)
+            && self.at_start_of_ts_declaration()
+    }
+
+    /// Skip a Flow type alias or interface declaration, leaving an empty statement in its place.
+    pub(crate) fn skip_flow_declaration(&mut self, span: Span) -> Result<Statement<'a>> {
+        if self.at(Kind::Type) {
+            self.with_flow_types(span, "type alias", |p| {
+                p.parse_ts_type_alias_declaration(span, &Modifiers::empty())
+            })?;
+        } else {
+            self.with_flow_types(span, "interface", |p| {
+                p.parse_ts_interface_declaration(span, &Modifiers::empty())
+            })?;
+        }
+        Ok(self.ast.statement_empty(self.end_span(span)))
+    }
+
+    /// Parse a Flow construct which starts at `span` with TypeScript syntax enabled, and report
+    /// it. A construct which cannot be parsed as TypeScript is a fatal error.
+    fn with_flow_types<T>(
+        &mut self,
+        span: Span,
+        construct: &'static str,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let source_type = self.source_type;
+        self.source_type = source_type.with_typescript(true);
+        let result = parse(self);
+        self.source_type = source_type;
+        let error = diagnostics::flow_syntax(self.end_span(span), construct);
+        match result {
+            Ok(result) => {
+                self.error(error);
+                Ok(result)
+            }
+            Err(_) => Err(error),
+        }
+    }
+}
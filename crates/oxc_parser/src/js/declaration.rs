@@ -113,6 +113,8 @@ impl<'a> ParserImpl<'a> {
             }
             (self.ast.binding_pattern(binding_kind, type_annotation, optional), definite)
         } else {
+            // Flow type annotations are skipped
+            self.parse_ts_type_annotation()?;
             (self.ast.binding_pattern(binding_kind, Option::<TSTypeAnnotation>::None, false), false)
         };
 
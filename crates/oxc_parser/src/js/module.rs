@@ -228,7 +228,7 @@ impl<'a> ParserImpl<'a> {
             Kind::LCurly => self
                 .parse_export_named_specifiers(span)
                 .map(ModuleDeclaration::ExportNamedDeclaration),
-            Kind::Type if self.peek_at(Kind::LCurly) && self.ts_enabled() => self
+            Kind::Type if self.peek_at(Kind::LCurly) => self
                 .parse_export_named_specifiers(span)
                 .map(ModuleDeclaration::ExportNamedDeclaration),
             Kind::Type if self.peek_at(Kind::Star) => {
@@ -483,9 +483,6 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_import_or_export_kind(&mut self) -> ImportOrExportKind {
-        if !self.ts_enabled() {
-            return ImportOrExportKind::Value;
-        }
         // OK
         // import type { bar } from 'foo';
         // import type * as React from 'react';
@@ -502,8 +499,7 @@ impl<'a> ParserImpl<'a> {
         }
 
         if matches!(self.peek_kind(), Kind::LCurly | Kind::Star) {
-            self.bump_any();
-            return ImportOrExportKind::Type;
+            return self.bump_import_or_export_type();
         }
 
         if !self.peek_at(Kind::Ident) && !self.peek_kind().is_contextual_keyword() {
@@ -511,13 +507,24 @@ impl<'a> ParserImpl<'a> {
         }
 
         if !self.peek_at(Kind::From) || self.nth_at(2, Kind::From) {
-            self.bump_any();
-            return ImportOrExportKind::Type;
+            return self.bump_import_or_export_type();
         }
 
         ImportOrExportKind::Value
     }
 
+    fn bump_import_or_export_type(&mut self) -> ImportOrExportKind {
+        let span = self.cur_token().span();
+        self.bump_any(); // bump `type`
+        if self.ts_enabled() {
+            ImportOrExportKind::Type
+        } else {
+            // `import type` and `export type` of Flow, the specifiers are kept as values
+            self.error(diagnostics::flow_syntax(span, "type-only import or export"));
+            ImportOrExportKind::Value
+        }
+    }
+
     fn parse_export_named_specifier(&mut self) -> Result<ExportSpecifier<'a>> {
         let specifier_span = self.start_span();
         let peek_kind = self.peek_kind();
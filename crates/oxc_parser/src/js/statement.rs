@@ -119,6 +119,7 @@ impl<'a> ParserImpl<'a> {
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
             }
+            _ if self.at_flow_declaration() => self.skip_flow_declaration(start_span),
             _ => self.parse_expression_or_labeled_statement(),
         }
     }
@@ -0,0 +1,110 @@
+//! JSON parsing mode, see [`crate::Parser::parse_json`].
+//!
+//! JSON is a subset of JavaScript expressions, so the JSON value is parsed with the expression
+//! parser and then checked for syntax which is not allowed in JSON. Comments and trailing commas
+//! are allowed, as in JSONC files such as `tsconfig.json`.
+
+use oxc_ast::{
+    ast::{Expression, ObjectPropertyKind, Program, PropertyKey, PropertyKind, UnaryOperator},
+    Directives, Pragmas,
+};
+use oxc_diagnostics::Result;
+use oxc_span::{GetSpan, Span};
+
+use crate::{diagnostics, lexer::Kind, ParserImpl, ParserReturn};
+
+impl<'a> ParserImpl<'a> {
+    pub fn parse_json(mut self) -> ParserReturn<'a> {
+        let (program, panicked) = match self.parse_json_program() {
+            Ok(program) => (program, false),
+            Err(error) => {
+                self.error(error);
+                let program = self.ast.program(
+                    Span::default(),
+                    self.source_type,
+                    None,
+                    self.ast.vec(),
+                    self.ast.vec(),
+                );
+                (program, true)
+            }
+        };
+        let mut errors = self.lexer.errors;
+        errors.extend(self.errors);
+        let trivias = self.lexer.trivia_builder.build();
+        let pragmas = Pragmas::from_comments(&trivias, self.source_text);
+        ParserReturn {
+            program,
+            errors,
+            trivias,
+            directives: Directives::default(),
+            pragmas,
+            tokens: self.tokens,
+            panicked,
+        }
+    }
+
+    /// The JSON value is the expression of the only statement of the program.
+    fn parse_json_program(&mut self) -> Result<Program<'a>> {
+        let span = self.start_span();
+        self.bump_any();
+        let value = self.parse_assignment_expression_or_higher()?;
+        self.expect(Kind::Eof)?;
+        self.check_json_value(&value);
+        let statement = self.ast.statement_expression(value.span(), value);
+        Ok(self.ast.program(
+            self.end_span(span),
+            self.source_type,
+            None,
+            self.ast.vec(),
+            self.ast.vec1(statement),
+        ))
+    }
+
+    fn check_json_value(&mut self, value: &Expression<'a>) {
+        match value {
+            Expression::ObjectExpression(object) => {
+                for property in &object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if property.kind == PropertyKind::Init
+                                && !property.method
+                                && !property.shorthand
+                                && !property.computed =>
+                        {
+                            match &property.key {
+                                PropertyKey::StringLiteral(key) => self.check_json_string(key.span),
+                                key => self.error(diagnostics::json_syntax(key.span())),
+                            }
+                            self.check_json_value(&property.value);
+                        }
+                        property => self.error(diagnostics::json_syntax(property.span())),
+                    }
+                }
+            }
+            Expression::ArrayExpression(array) => {
+                for element in &array.elements {
+                    match element.as_expression() {
+                        Some(element) => self.check_json_value(element),
+                        None => self.error(diagnostics::json_syntax(element.span())),
+                    }
+                }
+            }
+            Expression::StringLiteral(string) => self.check_json_string(string.span),
+            Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => {}
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(unary.argument, Expression::NumericLiteral(_)) => {}
+            value => self.error(diagnostics::json_syntax(value.span())),
+        }
+    }
+
+    /// JSON strings are double quoted.
+    fn check_json_string(&mut self, span: Span) {
+        if !span.source_text(self.source_text).starts_with('"') {
+            self.error(diagnostics::json_syntax(span));
+        }
+    }
+}
@@ -49,12 +49,16 @@ mod cursor;
 mod modifiers;
 mod state;
 
+mod flow;
 mod js;
+mod json;
 mod jsx;
 mod ts;
 
 mod diagnostics;
 
+pub mod token;
+
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
 mod lexer;
@@ -66,12 +70,12 @@ use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
 use oxc_ast::{
     ast::{Expression, Program},
-    AstBuilder, Trivias,
+    AstBuilder, Directives, Pragmas, Trivias,
 };
 use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
-pub use crate::lexer::Kind; // re-export for codegen
+pub use crate::{diagnostics::explain as explain_error_code, lexer::Kind}; // re-export `Kind` for codegen
 use crate::{
     lexer::{Lexer, Token},
     state::ParserState,
@@ -100,6 +104,12 @@ pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<OxcDiagnostic>,
     pub trivias: Trivias,
+    /// Module level directives, e.g. `"use strict"` and `"use client"`.
+    pub directives: Directives,
+    /// JSX pragmas from comments, e.g. `@jsxImportSource`.
+    pub pragmas: Pragmas<'a>,
+    /// Tokens of the source text, only collected when [`ParseOptions::tokens`] is enabled.
+    pub tokens: Vec<token::Token>,
     pub panicked: bool,
 }
 
@@ -125,6 +135,11 @@ pub struct ParseOptions {
     ///
     /// Default: true
     pub preserve_parens: bool,
+
+    /// Collect tokens into [`ParserReturn::tokens`].
+    ///
+    /// Default: false
+    pub tokens: bool,
 }
 
 impl Default for ParseOptions {
@@ -133,6 +148,7 @@ impl Default for ParseOptions {
             parse_regular_expression: false,
             allow_return_outside_function: false,
             preserve_parens: true,
+            tokens: false,
         }
     }
 }
@@ -214,6 +230,24 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse the source text as a JSON value
+        ///
+        /// The value is the expression of the only statement of the returned `Program`, so
+        /// JSON files can be analyzed like JavaScript. Comments and trailing commas are
+        /// allowed as in JSONC files, other syntax which is not valid JSON is reported in
+        /// `errors`.
+        pub fn parse_json(self) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_json()
+        }
+
         /// Parse `Expression`
         ///
         /// # Errors
@@ -257,6 +291,9 @@ struct ParserImpl<'a> {
     /// The end range of the previous token
     prev_token_end: u32,
 
+    /// Tokens consumed so far, if `options.tokens` is enabled
+    tokens: Vec<token::Token>,
+
     /// Parser state
     state: ParserState<'a>,
 
@@ -288,6 +325,7 @@ impl<'a> ParserImpl<'a> {
             errors: vec![],
             token: Token::default(),
             prev_token_end: 0,
+            tokens: vec![],
             state: ParserState::default(),
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
@@ -327,7 +365,17 @@ impl<'a> ParserImpl<'a> {
             errors.extend(self.errors);
         }
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let directives = Directives::from_program(&program);
+        let pragmas = Pragmas::from_comments(&trivias, self.source_text);
+        ParserReturn {
+            program,
+            errors,
+            trivias,
+            directives,
+            pragmas,
+            tokens: self.tokens,
+            panicked,
+        }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
@@ -370,10 +418,19 @@ impl<'a> ParserImpl<'a> {
 
     /// Check for Flow declaration if the file cannot be parsed.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
+    ///
+    /// Not reported when Flow syntax was already reported where it was found, see the `flow` module.
     fn flow_error(&mut self) -> Option<OxcDiagnostic> {
         if !self.source_type.is_javascript() {
             return None;
         };
+        if self
+            .errors
+            .iter()
+            .any(|error| error.code.number.as_deref() == Some("parse::flow-syntax"))
+        {
+            return None;
+        }
         let span = self.lexer.trivia_builder.comments.first()?.span;
         if span.source_text(self.source_text).contains("@flow") {
             self.errors.clear();
@@ -436,7 +493,10 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::{ast::Expression, CommentKind};
+    use oxc_ast::{
+        ast::{Expression, Statement},
+        CommentKind,
+    };
 
     use super::*;
 
@@ -459,6 +519,29 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn parse_json() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "// comment\n{ \"a\": [1, -2.5, true, null, { \"b\": \"c\" },], }";
+        let ret = Parser::new(&allocator, source, source_type).parse_json();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.trivias.comments().count(), 1);
+        let Some(Statement::ExpressionStatement(statement)) = ret.program.body.first() else {
+            panic!("expected the JSON value to be an expression statement");
+        };
+        assert!(matches!(statement.expression, Expression::ObjectExpression(_)));
+
+        let sources = ["{ a: 1 }", "{ 'a': 1 }", "[1,,2]", "{ \"a\": b }", "{ \"a\": f() }"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse_json();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}");
+        }
+        let ret = Parser::new(&allocator, "{} {}", source_type).parse_json();
+        assert!(ret.panicked);
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
@@ -480,6 +563,54 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow_syntax() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = [
+            ("let a: number = 1;", vec!["a"]),
+            ("function foo(a: ?string, ...b: Array<number>): void {}", vec!["foo"]),
+            ("const foo = (a: string) => a;", vec!["foo"]),
+            ("type A = { a: number };\nlet a;", vec!["a"]),
+            ("interface A { a: number }\nlet a;", vec!["a"]),
+            ("import type { A } from 'a';\nlet a;", vec!["a"]),
+            ("// @flow\nexport type { A } from 'a';\nlet a;", vec!["a"]),
+        ];
+        for (source, names) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(!ret.errors.is_empty(), "{source}");
+            for error in &ret.errors {
+                assert_eq!(error.to_string(), "Flow is not supported");
+                assert_eq!(error.code.number.as_deref(), Some("parse::flow-syntax"));
+            }
+            let declared = ret
+                .program
+                .body
+                .iter()
+                .filter_map(|stmt| match stmt {
+                    Statement::VariableDeclaration(decl) => {
+                        decl.declarations[0].id.get_identifier()
+                    }
+                    Statement::FunctionDeclaration(func) => {
+                        func.id.as_ref().map(|id| id.name.clone())
+                    }
+                    _ => None,
+                })
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(declared, names, "{source}");
+        }
+
+        // Flow syntax which is not TypeScript syntax cannot be recovered from
+        let ret = Parser::new(&allocator, "let a: {| a: number |} = 1;", source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret
+            .errors
+            .iter()
+            .any(|error| error.code.number.as_deref() == Some("parse::flow-syntax")));
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
@@ -525,6 +656,67 @@ mod test {
         }
     }
 
+    #[test]
+    fn directives_and_pragmas() {
+        use oxc_ast::JsxRuntimePragma;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let source = "/** @jsxRuntime automatic */\n'use client';\n'use strict';";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.directives.use_client);
+        assert!(ret.directives.use_strict);
+        assert!(!ret.directives.use_server);
+        assert_eq!(ret.pragmas.jsx_runtime, Some(JsxRuntimePragma::Automatic));
+        assert_eq!(ret.pragmas.jsx_import_source, None);
+        assert_eq!(ret.pragmas.jsx, None);
+
+        let source = "// @jsxImportSource preact\n/* @jsx h */\n/* @jsxFrag Fragment */\nfoo();\n'use server';";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.directives.use_server);
+        assert_eq!(ret.pragmas.jsx_import_source, Some("preact"));
+        assert_eq!(ret.pragmas.jsx, Some("h"));
+        assert_eq!(ret.pragmas.jsx_frag, Some("Fragment"));
+    }
+
+    #[test]
+    fn tokens() {
+        use token::TokenType;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = /x/g.test(`${b}`) ?? null; // comment";
+        let options = ParseOptions { tokens: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.r#type, token.span.source_text(source)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenType::Identifier, "let"),
+                (TokenType::Identifier, "a"),
+                (TokenType::Punctuator, "="),
+                (TokenType::RegularExpression, "/x/g"),
+                (TokenType::Punctuator, "."),
+                (TokenType::Identifier, "test"),
+                (TokenType::Punctuator, "("),
+                (TokenType::Template, "`${"),
+                (TokenType::Identifier, "b"),
+                (TokenType::Template, "}`"),
+                (TokenType::Punctuator, ")"),
+                (TokenType::Punctuator, "??"),
+                (TokenType::Null, "null"),
+                (TokenType::Punctuator, ";"),
+            ]
+        );
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
     #[test]
     fn unambiguous() {
         let allocator = Allocator::default();
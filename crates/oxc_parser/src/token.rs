@@ -0,0 +1,87 @@
+//! Tokens collected when [`ParseOptions::tokens`] is enabled.
+//!
+//! [`ParseOptions::tokens`]: crate::ParseOptions::tokens
+
+use oxc_span::{GetSpan, Span};
+
+use crate::lexer::{self, Kind};
+
+/// A token in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub r#type: TokenType,
+    pub span: Span,
+}
+
+impl GetSpan for Token {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Token type, compatible with the token types used by ESTree parsers.
+///
+/// Reference: <https://github.com/typescript-eslint/typescript-eslint/blob/main/packages/types/src/ast-token-types.ts>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenType {
+    Boolean,
+    Identifier,
+    JSXText,
+    Keyword,
+    Null,
+    Numeric,
+    PrivateIdentifier,
+    Punctuator,
+    RegularExpression,
+    String,
+    Template,
+}
+
+impl TokenType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Boolean => "Boolean",
+            Self::Identifier => "Identifier",
+            Self::JSXText => "JSXText",
+            Self::Keyword => "Keyword",
+            Self::Null => "Null",
+            Self::Numeric => "Numeric",
+            Self::PrivateIdentifier => "PrivateIdentifier",
+            Self::Punctuator => "Punctuator",
+            Self::RegularExpression => "RegularExpression",
+            Self::String => "String",
+            Self::Template => "Template",
+        }
+    }
+
+    /// Get the `TokenType` of a lexer token `Kind`.
+    ///
+    /// Returns `None` for kinds which are not tokens, e.g. EOF and hashbang comments.
+    fn from_kind(kind: Kind) -> Option<Self> {
+        let r#type = match kind {
+            Kind::Undetermined | Kind::Eof | Kind::Skip | Kind::HashbangComment => return None,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Null => Self::Null,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegularExpression,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::JSXText => Self::JSXText,
+            kind if kind.is_number() => Self::Numeric,
+            kind if kind.is_reserved_keyword() => Self::Keyword,
+            kind if kind.is_identifier_name() => Self::Identifier,
+            _ => Self::Punctuator,
+        };
+        Some(r#type)
+    }
+}
+
+impl Token {
+    pub(crate) fn from_lexer_token(token: lexer::Token) -> Option<Self> {
+        let r#type = TokenType::from_kind(token.kind)?;
+        Some(Self { r#type, span: token.span() })
+    }
+}
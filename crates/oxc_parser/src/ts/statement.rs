@@ -117,6 +117,7 @@ impl<'a> ParserImpl<'a> {
         &mut self,
     ) -> Result<Option<Box<'a, TSTypeAnnotation<'a>>>> {
         if !self.ts_enabled() {
+            self.skip_flow_type_annotation()?;
             return Ok(None);
         }
         if !self.at(Kind::Colon) {
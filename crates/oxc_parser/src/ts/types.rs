@@ -1054,6 +1054,10 @@ impl<'a> ParserImpl<'a> {
         is_type: bool,
     ) -> Result<Option<Box<'a, TSTypeAnnotation<'a>>>> {
         if !self.ts_enabled() {
+            // Return types of arrow functions are ambiguous with conditional expressions
+            if kind == Kind::Colon {
+                self.skip_flow_type_annotation()?;
+            }
             return Ok(None);
         }
         if !self.at(Kind::Colon) {
@@ -9,15 +9,14 @@ use oxc_allocator::{Box, CloneIn, Vec};
 use oxc_ast_macros::ast;
 use oxc_span::{cmp::ContentEq, hash::ContentHash, Atom, Span};
 #[cfg(feature = "serialize")]
-use serde::Serialize;
-#[cfg(feature = "serialize")]
 use tsify::Tsify;
 
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct RegularExpression<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub pattern: Pattern<'a>,
     pub flags: Flags,
@@ -25,9 +24,10 @@ pub struct RegularExpression<'a> {
 
 #[ast]
 #[derive(Debug, Clone)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Flags {
+    #[content_hash(skip)]
     pub span: Span,
     pub global: bool,
     pub ignore_case: bool,
@@ -42,9 +42,10 @@ pub struct Flags {
 /// The root of the `PatternParser` result.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Pattern<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub body: Disjunction<'a>,
 }
@@ -52,9 +53,10 @@ pub struct Pattern<'a> {
 /// Pile of [`Alternative`]s separated by `|`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Disjunction<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub body: Vec<'a, Alternative<'a>>,
 }
@@ -62,9 +64,10 @@ pub struct Disjunction<'a> {
 /// Single unit of `|` separated alternatives.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Alternative<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub body: Vec<'a, Term<'a>>,
 }
@@ -72,8 +75,8 @@ pub struct Alternative<'a> {
 /// Single unit of [`Alternative`], containing various kinds.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum Term<'a> {
     // Assertion, QuantifiableAssertion
     BoundaryAssertion(BoundaryAssertion) = 0,
@@ -96,17 +99,18 @@ pub enum Term<'a> {
 /// e.g. `^`, `$`, `\b`, `\B`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct BoundaryAssertion {
+    #[content_hash(skip)]
     pub span: Span,
     pub kind: BoundaryAssertionKind,
 }
 
 #[ast]
 #[derive(Debug, Clone, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum BoundaryAssertionKind {
     Start = 0,
     End = 1,
@@ -118,9 +122,10 @@ pub enum BoundaryAssertionKind {
 /// e.g. `(?=...)`, `(?!...)`, `(?<=...)`, `(?<!...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct LookAroundAssertion<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub kind: LookAroundAssertionKind,
     pub body: Disjunction<'a>,
@@ -128,8 +133,8 @@ pub struct LookAroundAssertion<'a> {
 
 #[ast]
 #[derive(Debug, Clone, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum LookAroundAssertionKind {
     Lookahead = 0,
     NegativeLookahead = 1,
@@ -141,9 +146,10 @@ pub enum LookAroundAssertionKind {
 /// e.g. `a*`, `b+`, `c?`, `d{3}`, `e{4,}`, `f{5,6}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Quantifier<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub min: u64,
     /// `None` means no upper bound.
@@ -155,10 +161,11 @@ pub struct Quantifier<'a> {
 /// Single character.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Character {
     /// This will be invalid position when `UnicodeMode` is disabled and `value` is a surrogate pair.
+    #[content_hash(skip)]
     pub span: Span,
     pub kind: CharacterKind,
     /// Unicode code point or UTF-16 code unit.
@@ -167,8 +174,8 @@ pub struct Character {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum CharacterKind {
     ControlLetter = 0,
     HexadecimalEscape = 1,
@@ -184,17 +191,18 @@ pub enum CharacterKind {
 /// e.g. `\d`, `\D`, `\s`, `\S`, `\w`, `\W`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct CharacterClassEscape {
+    #[content_hash(skip)]
     pub span: Span,
     pub kind: CharacterClassEscapeKind,
 }
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum CharacterClassEscapeKind {
     D = 0,
     NegativeD = 1,
@@ -208,9 +216,10 @@ pub enum CharacterClassEscapeKind {
 /// e.g. `\p{ASCII}`, `\P{ASCII}`, `\p{sc=Hiragana}`, `\P{sc=Hiragana}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct UnicodePropertyEscape<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub negative: bool,
     /// `true` if `UnicodeSetsMode` and `name` matched unicode property of strings.
@@ -222,9 +231,10 @@ pub struct UnicodePropertyEscape<'a> {
 /// The `.`.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct Dot {
+    #[content_hash(skip)]
     pub span: Span,
 }
 
@@ -232,9 +242,10 @@ pub struct Dot {
 /// e.g. `[a-z]`, `[^A-Z]`, `[abc]`, `[a&&b&&c]`, `[[a-z]--x--y]`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct CharacterClass<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub negative: bool,
     pub kind: CharacterClassContentsKind,
@@ -243,8 +254,8 @@ pub struct CharacterClass<'a> {
 
 #[ast]
 #[derive(Debug, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum CharacterClassContentsKind {
     Union = 0,
     /// `UnicodeSetsMode` only.
@@ -255,8 +266,8 @@ pub enum CharacterClassContentsKind {
 
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum CharacterClassContents<'a> {
     CharacterClassRange(Box<'a, CharacterClassRange>) = 0,
     CharacterClassEscape(CharacterClassEscape) = 1,
@@ -272,9 +283,10 @@ pub enum CharacterClassContents<'a> {
 /// e.g. `a-z`, `A-Z`, `0-9`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct CharacterClassRange {
+    #[content_hash(skip)]
     pub span: Span,
     pub min: Character,
     pub max: Character,
@@ -283,9 +295,10 @@ pub struct CharacterClassRange {
 /// `|` separated string of characters wrapped by `\q{}`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct ClassStringDisjunction<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     /// `true` if body is empty or contain [`ClassString`] which `strings` is `true`
     pub strings: bool,
@@ -295,9 +308,10 @@ pub struct ClassStringDisjunction<'a> {
 /// Single unit of [`ClassStringDisjunction`].
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct ClassString<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     /// `true` if body is empty or contain 2 more characters.
     pub strings: bool,
@@ -308,9 +322,10 @@ pub struct ClassString<'a> {
 /// e.g. `(...)`, `(?<name>...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct CapturingGroup<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub name: Option<Atom<'a>>,
     pub body: Disjunction<'a>,
@@ -320,9 +335,10 @@ pub struct CapturingGroup<'a> {
 /// e.g. `(?:...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct IgnoreGroup<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub enabling_modifiers: Option<ModifierFlags>,
     pub disabling_modifiers: Option<ModifierFlags>,
@@ -331,8 +347,8 @@ pub struct IgnoreGroup<'a> {
 
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct ModifierFlags {
     pub ignore_case: bool,
     pub sticky: bool,
@@ -343,9 +359,10 @@ pub struct ModifierFlags {
 /// e.g. `\1`, `\2`, `\3`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct IndexedReference {
+    #[content_hash(skip)]
     pub span: Span,
     pub index: u32,
 }
@@ -354,9 +371,10 @@ pub struct IndexedReference {
 /// e.g. `\k<name>`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub struct NamedReference<'a> {
+    #[content_hash(skip)]
     pub span: Span,
     pub name: Atom<'a>,
 }
@@ -0,0 +1,396 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/estree.rs`
+
+#![allow(clippy::match_same_arms)]
+
+#[allow(unused_imports)]
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+impl<'a> Serialize for RegularExpression<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("pattern", &self.pattern)?;
+        map.serialize_entry("flags", &self.flags)?;
+        map.end()
+    }
+}
+
+impl Serialize for Flags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("global", &self.global)?;
+        map.serialize_entry("ignore_case", &self.ignore_case)?;
+        map.serialize_entry("multiline", &self.multiline)?;
+        map.serialize_entry("unicode", &self.unicode)?;
+        map.serialize_entry("sticky", &self.sticky)?;
+        map.serialize_entry("dot_all", &self.dot_all)?;
+        map.serialize_entry("has_indices", &self.has_indices)?;
+        map.serialize_entry("unicode_sets", &self.unicode_sets)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Pattern<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Disjunction<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Alternative<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for Term<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BoundaryAssertion(it) => {
+                serializer.serialize_newtype_variant("Term", 0, "BoundaryAssertion", it)
+            }
+            Self::LookAroundAssertion(it) => {
+                serializer.serialize_newtype_variant("Term", 1, "LookAroundAssertion", it)
+            }
+            Self::Quantifier(it) => {
+                serializer.serialize_newtype_variant("Term", 2, "Quantifier", it)
+            }
+            Self::Character(it) => serializer.serialize_newtype_variant("Term", 3, "Character", it),
+            Self::Dot(it) => serializer.serialize_newtype_variant("Term", 4, "Dot", it),
+            Self::CharacterClassEscape(it) => {
+                serializer.serialize_newtype_variant("Term", 5, "CharacterClassEscape", it)
+            }
+            Self::UnicodePropertyEscape(it) => {
+                serializer.serialize_newtype_variant("Term", 6, "UnicodePropertyEscape", it)
+            }
+            Self::CharacterClass(it) => {
+                serializer.serialize_newtype_variant("Term", 7, "CharacterClass", it)
+            }
+            Self::CapturingGroup(it) => {
+                serializer.serialize_newtype_variant("Term", 8, "CapturingGroup", it)
+            }
+            Self::IgnoreGroup(it) => {
+                serializer.serialize_newtype_variant("Term", 9, "IgnoreGroup", it)
+            }
+            Self::IndexedReference(it) => {
+                serializer.serialize_newtype_variant("Term", 10, "IndexedReference", it)
+            }
+            Self::NamedReference(it) => {
+                serializer.serialize_newtype_variant("Term", 11, "NamedReference", it)
+            }
+        }
+    }
+}
+
+impl Serialize for BoundaryAssertion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.end()
+    }
+}
+
+impl Serialize for BoundaryAssertionKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Start => serializer.serialize_unit_variant("BoundaryAssertionKind", 0, "Start"),
+            Self::End => serializer.serialize_unit_variant("BoundaryAssertionKind", 1, "End"),
+            Self::Boundary => {
+                serializer.serialize_unit_variant("BoundaryAssertionKind", 2, "Boundary")
+            }
+            Self::NegativeBoundary => {
+                serializer.serialize_unit_variant("BoundaryAssertionKind", 3, "NegativeBoundary")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for LookAroundAssertion<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for LookAroundAssertionKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Lookahead => {
+                serializer.serialize_unit_variant("LookAroundAssertionKind", 0, "Lookahead")
+            }
+            Self::NegativeLookahead => {
+                serializer.serialize_unit_variant("LookAroundAssertionKind", 1, "NegativeLookahead")
+            }
+            Self::Lookbehind => {
+                serializer.serialize_unit_variant("LookAroundAssertionKind", 2, "Lookbehind")
+            }
+            Self::NegativeLookbehind => serializer.serialize_unit_variant(
+                "LookAroundAssertionKind",
+                3,
+                "NegativeLookbehind",
+            ),
+        }
+    }
+}
+
+impl<'a> Serialize for Quantifier<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("min", &self.min)?;
+        map.serialize_entry("max", &self.max)?;
+        map.serialize_entry("greedy", &self.greedy)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for Character {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl Serialize for CharacterKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ControlLetter => {
+                serializer.serialize_unit_variant("CharacterKind", 0, "ControlLetter")
+            }
+            Self::HexadecimalEscape => {
+                serializer.serialize_unit_variant("CharacterKind", 1, "HexadecimalEscape")
+            }
+            Self::Identifier => serializer.serialize_unit_variant("CharacterKind", 2, "Identifier"),
+            Self::Null => serializer.serialize_unit_variant("CharacterKind", 3, "Null"),
+            Self::Octal => serializer.serialize_unit_variant("CharacterKind", 4, "Octal"),
+            Self::SingleEscape => {
+                serializer.serialize_unit_variant("CharacterKind", 5, "SingleEscape")
+            }
+            Self::Symbol => serializer.serialize_unit_variant("CharacterKind", 6, "Symbol"),
+            Self::UnicodeEscape => {
+                serializer.serialize_unit_variant("CharacterKind", 7, "UnicodeEscape")
+            }
+        }
+    }
+}
+
+impl Serialize for CharacterClassEscape {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.end()
+    }
+}
+
+impl Serialize for CharacterClassEscapeKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::D => serializer.serialize_unit_variant("CharacterClassEscapeKind", 0, "D"),
+            Self::NegativeD => {
+                serializer.serialize_unit_variant("CharacterClassEscapeKind", 1, "NegativeD")
+            }
+            Self::S => serializer.serialize_unit_variant("CharacterClassEscapeKind", 2, "S"),
+            Self::NegativeS => {
+                serializer.serialize_unit_variant("CharacterClassEscapeKind", 3, "NegativeS")
+            }
+            Self::W => serializer.serialize_unit_variant("CharacterClassEscapeKind", 4, "W"),
+            Self::NegativeW => {
+                serializer.serialize_unit_variant("CharacterClassEscapeKind", 5, "NegativeW")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for UnicodePropertyEscape<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("negative", &self.negative)?;
+        map.serialize_entry("strings", &self.strings)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("value", &self.value)?;
+        map.end()
+    }
+}
+
+impl Serialize for Dot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for CharacterClass<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("negative", &self.negative)?;
+        map.serialize_entry("kind", &self.kind)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for CharacterClassContentsKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Union => {
+                serializer.serialize_unit_variant("CharacterClassContentsKind", 0, "Union")
+            }
+            Self::Intersection => {
+                serializer.serialize_unit_variant("CharacterClassContentsKind", 1, "Intersection")
+            }
+            Self::Subtraction => {
+                serializer.serialize_unit_variant("CharacterClassContentsKind", 2, "Subtraction")
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for CharacterClassContents<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::CharacterClassRange(it) => serializer.serialize_newtype_variant(
+                "CharacterClassContents",
+                0,
+                "CharacterClassRange",
+                it,
+            ),
+            Self::CharacterClassEscape(it) => serializer.serialize_newtype_variant(
+                "CharacterClassContents",
+                1,
+                "CharacterClassEscape",
+                it,
+            ),
+            Self::UnicodePropertyEscape(it) => serializer.serialize_newtype_variant(
+                "CharacterClassContents",
+                2,
+                "UnicodePropertyEscape",
+                it,
+            ),
+            Self::Character(it) => {
+                serializer.serialize_newtype_variant("CharacterClassContents", 3, "Character", it)
+            }
+            Self::NestedCharacterClass(it) => serializer.serialize_newtype_variant(
+                "CharacterClassContents",
+                4,
+                "NestedCharacterClass",
+                it,
+            ),
+            Self::ClassStringDisjunction(it) => serializer.serialize_newtype_variant(
+                "CharacterClassContents",
+                5,
+                "ClassStringDisjunction",
+                it,
+            ),
+        }
+    }
+}
+
+impl Serialize for CharacterClassRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("min", &self.min)?;
+        map.serialize_entry("max", &self.max)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ClassStringDisjunction<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("strings", &self.strings)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for ClassString<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("strings", &self.strings)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for CapturingGroup<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for IgnoreGroup<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("enabling_modifiers", &self.enabling_modifiers)?;
+        map.serialize_entry("disabling_modifiers", &self.disabling_modifiers)?;
+        map.serialize_entry("body", &self.body)?;
+        map.end()
+    }
+}
+
+impl Serialize for ModifierFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("ignore_case", &self.ignore_case)?;
+        map.serialize_entry("sticky", &self.sticky)?;
+        map.serialize_entry("multiline", &self.multiline)?;
+        map.end()
+    }
+}
+
+impl Serialize for IndexedReference {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("index", &self.index)?;
+        map.end()
+    }
+}
+
+impl<'a> Serialize for NamedReference<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("span", &self.span)?;
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
@@ -14,6 +14,8 @@ mod generated {
     mod derive_clone_in;
     mod derive_content_eq;
     mod derive_content_hash;
+    #[cfg(feature = "serialize")]
+    mod derive_estree;
 }
 
 pub use crate::{
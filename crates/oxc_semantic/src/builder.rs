@@ -7,24 +7,27 @@ use std::{
 };
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, AstKind, Trivias, Visit};
+use oxc_ast::{ast::*, syntax_directed_operations::IsSimpleParameterList, AstKind, Trivias, Visit};
 use oxc_cfg::{
     ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
     IterationInstructionKind, ReturnInstructionKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, CompactStr, SourceType, Span};
-use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
+use oxc_syntax::{
+    module_record::ModuleRecord,
+    operator::{AssignmentOperator, UnaryOperator},
+};
 use rustc_hash::FxHashMap;
 
 use crate::{
     binder::Binder,
-    checker,
+    checker::{self, EarlyErrorChecker},
     class::ClassTableBuilder,
     counter::Counter,
     diagnostics::redeclaration,
     jsdoc::JSDocBuilder,
-    label::UnusedLabels,
+    label::LabelBuilder,
     module_record::ModuleRecordBuilder,
     node::{AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlags, ReferenceId},
@@ -94,14 +97,12 @@ pub struct SemanticBuilder<'a> {
 
     pub(crate) module_record: Arc<ModuleRecord>,
 
-    unused_labels: UnusedLabels<'a>,
+    pub(crate) labels: LabelBuilder<'a>,
     build_jsdoc: bool,
     jsdoc: JSDocBuilder<'a>,
 
-    /// Should additional syntax checks be performed?
-    ///
-    /// See: [`crate::checker::check`]
-    check_syntax_error: bool,
+    /// Checker of additional syntax errors, `None` if they are not checked.
+    early_error_checker: Option<EarlyErrorChecker>,
 
     pub(crate) cfg: Option<ControlFlowGraphBuilder<'a>>,
 
@@ -140,10 +141,10 @@ impl<'a> SemanticBuilder<'a> {
             symbols: SymbolTable::default(),
             unresolved_references: UnresolvedReferencesStack::new(),
             module_record: Arc::new(ModuleRecord::default()),
-            unused_labels: UnusedLabels::default(),
+            labels: LabelBuilder::default(),
             build_jsdoc: false,
             jsdoc: JSDocBuilder::new(source_text, trivias),
-            check_syntax_error: false,
+            early_error_checker: None,
             cfg: None,
             class_table_builder: ClassTableBuilder::new(),
             ast_node_records: Vec::new(),
@@ -166,7 +167,14 @@ impl<'a> SemanticBuilder<'a> {
     /// By default, this is `false`.
     #[must_use]
     pub fn with_check_syntax_error(mut self, yes: bool) -> Self {
-        self.check_syntax_error = yes;
+        self.early_error_checker = yes.then(EarlyErrorChecker::default);
+        self
+    }
+
+    /// Check additional syntax errors with `checker`, see [`EarlyErrorChecker`].
+    #[must_use]
+    pub fn with_early_error_checker(mut self, checker: EarlyErrorChecker) -> Self {
+        self.early_error_checker = Some(checker);
         self
     }
 
@@ -253,7 +261,7 @@ impl<'a> SemanticBuilder<'a> {
             debug_assert!(self.symbols.len() <= counter.symbols_count);
 
             // Checking syntax error on module record requires scope information from the previous AST pass
-            if self.check_syntax_error {
+            if self.early_error_checker.is_some() {
                 checker::check_module_record(&self);
             }
         }
@@ -278,10 +286,14 @@ impl<'a> SemanticBuilder<'a> {
             classes: self.class_table_builder.build(),
             module_record: Arc::clone(&self.module_record),
             jsdoc,
-            unused_labels: self.unused_labels.labels,
+            labels: self.labels.table,
             cfg: self.cfg.map(ControlFlowGraphBuilder::build),
         };
-        SemanticBuilderReturn { semantic, errors: self.errors.into_inner() }
+        let mut errors = self.errors.into_inner();
+        if let Some(checker) = &self.early_error_checker {
+            errors.retain(|error| checker.is_enabled(error));
+        }
+        SemanticBuilderReturn { semantic, errors }
     }
 
     /// Push a Syntax Error
@@ -352,6 +364,45 @@ impl<'a> SemanticBuilder<'a> {
         self.current_scope_flags().is_strict_mode()
     }
 
+    /// Add `flags` to the current scope and all of its ancestors.
+    fn add_scope_flags_to_ancestors(&mut self, flags: ScopeFlags) {
+        let mut scope_id = Some(self.current_scope_id);
+        while let Some(id) = scope_id {
+            let scope_flags = self.scope.get_flags_mut(id);
+            // Ancestors of a flagged scope are already flagged
+            if scope_flags.contains(flags) {
+                break;
+            }
+            *scope_flags |= flags;
+            scope_id = self.scope.get_parent_id(id);
+        }
+    }
+
+    /// Flag the function whose `arguments` object is referenced by `arguments`.
+    fn reference_arguments(&mut self) {
+        let Some(scope_id) = self.scope.ancestors(self.current_scope_id).find(|scope_id| {
+            let flags = self.scope.get_flags(*scope_id);
+            flags.is_function() && !flags.is_arrow()
+        }) else {
+            return;
+        };
+        // `function f(arguments) {}`
+        if self.scope.get_binding(scope_id, "arguments").is_some() {
+            return;
+        }
+        let mut flags = ScopeFlags::Arguments;
+        let scope_flags = self.scope.get_flags(scope_id);
+        if !scope_flags.is_strict_mode() {
+            let node_id = self.scope.get_node_id(scope_id);
+            if let AstKind::Function(func) = self.nodes.kind(node_id) {
+                if func.params.is_simple_parameter_list() {
+                    flags |= ScopeFlags::MappedArguments;
+                }
+            }
+        }
+        *self.scope.get_flags_mut(scope_id) |= flags;
+    }
+
     pub(crate) fn set_function_node_flags(&mut self, flags: NodeFlags) {
         if let Some(current_function) = self.function_stack.last() {
             *self.nodes.get_node_mut(*current_function).flags_mut() |= flags;
@@ -585,9 +636,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     }
 
     fn leave_node(&mut self, kind: AstKind<'a>) {
-        if self.check_syntax_error {
+        if let Some(checker) = &self.early_error_checker {
             let node = self.nodes.get_node(self.current_node_id);
-            checker::check(node, self);
+            checker.check_node(node, self);
         }
         self.leave_kind(kind);
         self.pop_ast_node();
@@ -1728,6 +1779,14 @@ impl<'a> SemanticBuilder<'a> {
                 } {
                     self.current_symbol_flags |= SymbolFlags::Export;
                 }
+                // export default a;
+                //                ^
+                if matches!(
+                    decl.declaration.as_expression().map(Expression::get_inner_expression),
+                    Some(Expression::Identifier(_))
+                ) {
+                    self.current_reference_flags |= ReferenceFlags::Export;
+                }
             }
             AstKind::ExportNamedDeclaration(decl) => {
                 self.current_symbol_flags |= SymbolFlags::Export;
@@ -1737,9 +1796,10 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::ExportSpecifier(s) => {
                 if self.current_reference_flags.is_type() || s.export_kind.is_type() {
-                    self.current_reference_flags = ReferenceFlags::Type;
+                    self.current_reference_flags = ReferenceFlags::Type | ReferenceFlags::Export;
                 } else {
-                    self.current_reference_flags = ReferenceFlags::Read | ReferenceFlags::Type;
+                    self.current_reference_flags =
+                        ReferenceFlags::Read | ReferenceFlags::Type | ReferenceFlags::Export;
                 }
             }
             AstKind::ImportSpecifier(specifier) => {
@@ -1760,6 +1820,7 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::Function(func) => {
                 self.function_stack.push(self.current_node_id);
+                self.labels.enter_function();
                 if func.is_declaration() {
                     func.bind(self);
                 }
@@ -1767,6 +1828,7 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::ArrowFunctionExpression(_) => {
                 self.function_stack.push(self.current_node_id);
+                self.labels.enter_function();
                 self.make_all_namespaces_valuelike();
             }
             AstKind::Class(class) => {
@@ -1870,19 +1932,38 @@ impl<'a> SemanticBuilder<'a> {
                 // export = a;
                 //          ^ can reference value or type
                 if export.expression.is_identifier_reference() {
-                    self.current_reference_flags = ReferenceFlags::Read | ReferenceFlags::Type;
+                    self.current_reference_flags =
+                        ReferenceFlags::Read | ReferenceFlags::Type | ReferenceFlags::Export;
                 }
             }
             AstKind::IdentifierReference(ident) => {
                 self.reference_identifier(ident);
             }
+            AstKind::CallExpression(call) => {
+                // `eval(code)` and `(eval)(code)` are direct, `eval?.(code)` is not
+                if !call.optional && call.callee.is_specific_id("eval") {
+                    self.add_scope_flags_to_ancestors(ScopeFlags::DirectEval);
+                }
+            }
+            AstKind::WithStatement(_) => {
+                self.add_scope_flags_to_ancestors(ScopeFlags::With);
+            }
             AstKind::UpdateExpression(_) => {
                 if !self.current_reference_flags.is_type()
                     && self.is_not_expression_statement_parent()
                 {
                     self.current_reference_flags |= ReferenceFlags::Read;
                 }
-                self.current_reference_flags |= ReferenceFlags::Write;
+                self.current_reference_flags |= ReferenceFlags::Write | ReferenceFlags::Compound;
+            }
+            AstKind::UnaryExpression(expr) => {
+                // delete a;
+                //        ^
+                if expr.operator == UnaryOperator::Delete
+                    && matches!(expr.argument.get_inner_expression(), Expression::Identifier(_))
+                {
+                    self.current_reference_flags |= ReferenceFlags::Delete;
+                }
             }
             AstKind::AssignmentExpression(expr) => {
                 if expr.operator != AssignmentOperator::Assign
@@ -1894,18 +1975,29 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::MemberExpression(_) => {
                 // A.B = 1;
                 // ^^^ we can't treat A as Write reference, because it's the property(B) of A that change
-                self.current_reference_flags -= ReferenceFlags::Write;
+                self.current_reference_flags -= ReferenceFlags::Write | ReferenceFlags::Compound;
             }
             AstKind::AssignmentTarget(_) => {
                 self.current_reference_flags |= ReferenceFlags::Write;
+                // a += 1;
+                // ^
+                if matches!(
+                    self.nodes.parent_kind(self.current_node_id),
+                    Some(AstKind::AssignmentExpression(expr)) if expr.operator != AssignmentOperator::Assign
+                ) {
+                    self.current_reference_flags |= ReferenceFlags::Compound;
+                }
+            }
+            AstKind::StaticBlock(_) => {
+                self.labels.enter_function();
             }
             AstKind::LabeledStatement(stmt) => {
-                self.unused_labels.add(stmt.label.name.as_str());
+                self.labels.enter_label(stmt.label.name.as_str(), self.current_node_id);
             }
             AstKind::ContinueStatement(ContinueStatement { label, .. })
             | AstKind::BreakStatement(BreakStatement { label, .. }) => {
                 if let Some(label) = &label {
-                    self.unused_labels.reference(&label.name);
+                    self.labels.add_jump(&label.name, self.current_node_id);
                 }
             }
             AstKind::YieldExpression(_) => {
@@ -1935,6 +2027,10 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
                 self.function_stack.pop();
+                self.labels.leave_function();
+            }
+            AstKind::StaticBlock(_) => {
+                self.labels.leave_function();
             }
             AstKind::FormalParameters(parameters) => {
                 if parameters.kind != FormalParameterKind::Signature && parameters.has_parameter() {
@@ -1958,7 +2054,13 @@ impl<'a> SemanticBuilder<'a> {
                 if self.is_not_expression_statement_parent() {
                     self.current_reference_flags -= ReferenceFlags::Read;
                 }
-                self.current_reference_flags -= ReferenceFlags::Write;
+                self.current_reference_flags -= ReferenceFlags::Write | ReferenceFlags::Compound;
+            }
+            AstKind::UnaryExpression(_) => {
+                self.current_reference_flags -= ReferenceFlags::Delete;
+            }
+            AstKind::ExportDefaultDeclaration(_) => {
+                self.current_reference_flags -= ReferenceFlags::Export;
             }
             AstKind::AssignmentExpression(expr) => {
                 if expr.operator != AssignmentOperator::Assign
@@ -1968,13 +2070,16 @@ impl<'a> SemanticBuilder<'a> {
                 }
             }
             AstKind::ExportNamedDeclaration(_)
+            | AstKind::TSExportAssignment(_)
             | AstKind::TSTypeQuery(_)
             // Clear the reference flags that are set in AstKind::PropertySignature
             | AstKind::PropertyKey(_) => {
                 self.current_reference_flags = ReferenceFlags::empty();
             }
-            AstKind::AssignmentTarget(_) => self.current_reference_flags -= ReferenceFlags::Write,
-            AstKind::LabeledStatement(_) => self.unused_labels.mark_unused(self.current_node_id),
+            AstKind::AssignmentTarget(_) => {
+                self.current_reference_flags -= ReferenceFlags::Write | ReferenceFlags::Compound;
+            }
+            AstKind::LabeledStatement(_) => self.labels.leave_label(),
             _ => {}
         }
     }
@@ -1990,6 +2095,9 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     fn reference_identifier(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.reference_arguments();
+        }
         let flags = self.resolve_reference_usages();
         let reference = Reference::new(self.current_node_id, flags);
         let reference_id = self.declare_reference(ident.name.clone(), reference);
@@ -1998,10 +2106,11 @@ impl<'a> SemanticBuilder<'a> {
 
     /// Resolve reference flags for the current ast node.
     fn resolve_reference_usages(&self) -> ReferenceFlags {
-        if self.current_reference_flags.is_empty() {
-            ReferenceFlags::Read
-        } else {
+        // `export default a` and `delete a` only set the context of the reference
+        if self.current_reference_flags.intersects(ReferenceFlags::Usage) {
             self.current_reference_flags
+        } else {
+            self.current_reference_flags | ReferenceFlags::Read
         }
     }
 
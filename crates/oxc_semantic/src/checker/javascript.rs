@@ -14,7 +14,12 @@ use oxc_syntax::{
 use phf::{phf_set, Set};
 use rustc_hash::FxHashMap;
 
-use crate::{builder::SemanticBuilder, diagnostics::redeclaration, scope::ScopeFlags, AstNode};
+use crate::{
+    builder::SemanticBuilder,
+    diagnostics::{oxc_error, redeclaration},
+    scope::ScopeFlags,
+    AstNode,
+};
 
 pub fn check_duplicate_class_elements(ctx: &SemanticBuilder<'_>) {
     let classes = &ctx.class_table_builder.classes;
@@ -53,11 +58,12 @@ pub fn check_duplicate_class_elements(ctx: &SemanticBuilder<'_>) {
 }
 
 fn undefined_export(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Export '{x0}' is not defined")).with_label(span1)
+    oxc_error("semantic::undefined-export", format!("Export '{x0}' is not defined"))
+        .with_label(span1)
 }
 
 fn duplicate_export(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Duplicated export '{x0}'")).with_labels([
+    oxc_error("semantic::duplicate-export", format!("Duplicated export '{x0}'")).with_labels([
         span1.label("Export has already been declared here"),
         span2.label("It cannot be redeclared here"),
     ])
@@ -108,11 +114,16 @@ pub fn check_module_record(ctx: &SemanticBuilder<'_>) {
 }
 
 fn class_static_block_await(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use await in class static initialization block").with_label(span)
+    oxc_error(
+        "semantic::class-static-block-await",
+        "Cannot use await in class static initialization block",
+    )
+    .with_label(span)
 }
 
 fn reserved_keyword(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("The keyword '{x0}' is reserved")).with_label(span1)
+    oxc_error("semantic::reserved-keyword", format!("The keyword '{x0}' is reserved"))
+        .with_label(span1)
 }
 
 pub const STRICT_MODE_NAMES: Set<&'static str> = phf_set! {
@@ -150,13 +161,15 @@ pub fn check_identifier<'a>(name: &str, span: Span, node: &AstNode<'a>, ctx: &Se
 }
 
 fn unexpected_identifier_assign(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot assign to '{x0}' in strict mode")).with_label(span1)
+    oxc_error("semantic::strict-mode-assignment", format!("Cannot assign to '{x0}' in strict mode"))
+        .with_label(span1)
 }
 
 fn invalid_let_declaration(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "`let` cannot be declared as a variable name inside of a `{x0}` declaration"
-    ))
+    oxc_error(
+        "semantic::invalid-let-declaration",
+        format!("`let` cannot be declared as a variable name inside of a `{x0}` declaration"),
+    )
     .with_label(span1)
 }
 
@@ -189,7 +202,8 @@ pub fn check_binding_identifier<'a>(
 }
 
 fn unexpected_arguments(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("'arguments' is not allowed in {x0}")).with_label(span1)
+    oxc_error("semantic::unexpected-arguments", format!("'arguments' is not allowed in {x0}"))
+        .with_label(span1)
 }
 
 pub fn check_identifier_reference<'a>(
@@ -234,8 +248,11 @@ pub fn check_identifier_reference<'a>(
 }
 
 fn private_not_in_class(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Private identifier '#{x0}' is not allowed outside class bodies"))
-        .with_label(span1)
+    oxc_error(
+        "semantic::private-identifier-outside-class",
+        format!("Private identifier '#{x0}' is not allowed outside class bodies"),
+    )
+    .with_label(span1)
 }
 
 pub fn check_private_identifier_outside_class(
@@ -248,8 +265,11 @@ pub fn check_private_identifier_outside_class(
 }
 
 fn private_field_undeclared(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Private field '{x0}' must be declared in an enclosing class"))
-        .with_label(span1)
+    oxc_error(
+        "semantic::private-field-undeclared",
+        format!("Private field '{x0}' must be declared in an enclosing class"),
+    )
+    .with_label(span1)
 }
 
 fn check_private_identifier(ctx: &SemanticBuilder<'_>) {
@@ -269,15 +289,21 @@ fn check_private_identifier(ctx: &SemanticBuilder<'_>) {
 }
 
 fn legacy_octal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'0'-prefixed octal literals and octal escape sequences are deprecated")
-        .with_help("for octal literals use the '0o' prefix instead")
-        .with_label(span)
+    oxc_error(
+        "semantic::legacy-octal",
+        "'0'-prefixed octal literals and octal escape sequences are deprecated",
+    )
+    .with_help("for octal literals use the '0o' prefix instead")
+    .with_label(span)
 }
 
 fn leading_zero_decimal(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Decimals with leading zeros are not allowed in strict mode")
-        .with_help("remove the leading zero")
-        .with_label(span)
+    oxc_error(
+        "semantic::leading-zero-decimal",
+        "Decimals with leading zeros are not allowed in strict mode",
+    )
+    .with_help("remove the leading zero")
+    .with_label(span)
 }
 
 pub fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
@@ -308,7 +334,7 @@ pub fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
 }
 
 fn non_octal_decimal_escape_sequence(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid escape sequence")
+    oxc_error("semantic::non-octal-decimal-escape-sequence", "Invalid escape sequence")
         .with_help("\\8 and \\9 are not allowed in strict mode")
         .with_label(span)
 }
@@ -344,7 +370,8 @@ pub fn check_string_literal(lit: &StringLiteral, ctx: &SemanticBuilder<'_>) {
 }
 
 fn illegal_use_strict(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "semantic::illegal-use-strict",
         "Illegal 'use strict' directive in function with non-simple parameter list",
     )
     .with_label(span)
@@ -371,14 +398,16 @@ pub fn check_directive(directive: &Directive, ctx: &SemanticBuilder<'_>) {
 }
 
 fn top_level(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "'{x0}' declaration can only be used at the top level of a module"
-    ))
+    oxc_error(
+        "semantic::module-declaration-not-top-level",
+        format!("'{x0}' declaration can only be used at the top level of a module"),
+    )
     .with_label(span1)
 }
 
 fn module_code(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot use {x0} outside a module")).with_label(span1)
+    oxc_error("semantic::module-syntax-in-script", format!("Cannot use {x0} outside a module"))
+        .with_label(span1)
 }
 
 pub fn check_module_declaration<'a>(
@@ -421,23 +450,31 @@ pub fn check_module_declaration<'a>(
 }
 
 fn new_target(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected new.target expression")
+    oxc_error("semantic::new-target", "Unexpected new.target expression")
 .with_help("new.target is only allowed in constructors and functions invoked using thew `new` operator")
 .with_label(span)
 }
 
 fn new_target_property(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The only valid meta property for new is new.target").with_label(span)
+    oxc_error(
+        "semantic::invalid-new-meta-property",
+        "The only valid meta property for new is new.target",
+    )
+    .with_label(span)
 }
 
 fn import_meta(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected import.meta expression")
+    oxc_error("semantic::import-meta", "Unexpected import.meta expression")
         .with_help("import.meta is only allowed in module code")
         .with_label(span)
 }
 
 fn import_meta_property(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("The only valid meta property for import is import.meta").with_label(span)
+    oxc_error(
+        "semantic::invalid-import-meta-property",
+        "The only valid meta property for import is import.meta",
+    )
+    .with_label(span)
 }
 
 pub fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
@@ -477,7 +514,7 @@ pub fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &Se
 }
 
 fn function_declaration_strict(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid function declaration")
+    oxc_error("semantic::invalid-function-declaration", "Invalid function declaration")
         .with_help(
             "In strict mode code, functions can only be declared at top level or inside a block",
         )
@@ -485,7 +522,7 @@ fn function_declaration_strict(span: Span) -> OxcDiagnostic {
 }
 
 fn function_declaration_non_strict(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid function declaration")
+    oxc_error("semantic::invalid-function-declaration", "Invalid function declaration")
 .with_help("In non-strict mode code, functions can only be declared at top level, inside a block, or as the body of an if statement")
 .with_label(span)
 }
@@ -506,7 +543,8 @@ pub fn check_function_declaration<'a>(
 }
 
 fn reg_exp_flag_u_and_v(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "semantic::regexp-u-and-v-flags",
         "The 'u' and 'v' regular expression flags cannot be enabled at the same time",
     )
     .with_label(span)
@@ -520,7 +558,7 @@ pub fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticBuilder<'_>) {
 }
 
 fn with_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'with' statements are not allowed").with_label(span)
+    oxc_error("semantic::with-statement", "'with' statements are not allowed").with_label(span)
 }
 
 pub fn check_with_statement(stmt: &WithStatement, ctx: &SemanticBuilder<'_>) {
@@ -543,15 +581,16 @@ pub fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticBuil
 }
 
 fn invalid_label_jump_target(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Jump target cannot cross function boundary.").with_label(span)
+    oxc_error("semantic::label-crosses-function", "Jump target cannot cross function boundary.")
+        .with_label(span)
 }
 
 fn invalid_label_target(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Use of undefined label").with_label(span)
+    oxc_error("semantic::undefined-label", "Use of undefined label").with_label(span)
 }
 
 fn invalid_label_non_iteration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("A `{x0}` statement can only jump to a label of an enclosing `for`, `while` or `do while` statement."))
+    oxc_error("semantic::non-iteration-label", format!("A `{x0}` statement can only jump to a label of an enclosing `for`, `while` or `do while` statement."))
         .with_labels([
             span1.label("This is an non-iteration statement"),
             span2.label("for this label")
@@ -559,7 +598,7 @@ fn invalid_label_non_iteration(x0: &str, span1: Span, span2: Span) -> OxcDiagnos
 }
 
 fn invalid_break(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Illegal break statement")
+    oxc_error("semantic::invalid-break", "Illegal break statement")
 .with_help("A `break` statement can only be used within an enclosing iteration or switch statement.")
 .with_label(span)
 }
@@ -569,33 +608,19 @@ pub fn check_break_statement<'a>(
     node: &AstNode<'a>,
     ctx: &SemanticBuilder<'a>,
 ) {
+    if let Some(label) = &stmt.label {
+        check_label_target(label, node, ctx);
+        return;
+    }
+
     // It is a Syntax Error if this BreakStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement or a SwitchStatement.
     for node_id in ctx.nodes.ancestors(node.id()).skip(1) {
         match ctx.nodes.kind(node_id) {
-            AstKind::Program(_) => {
-                return stmt.label.as_ref().map_or_else(
-                    || ctx.error(invalid_break(stmt.span)),
-                    |label| ctx.error(invalid_label_target(label.span)),
-                );
+            AstKind::Program(_) | AstKind::Function(_) | AstKind::StaticBlock(_) => {
+                return ctx.error(invalid_break(stmt.span));
             }
-            AstKind::Function(_) | AstKind::StaticBlock(_) => {
-                return stmt.label.as_ref().map_or_else(
-                    || ctx.error(invalid_break(stmt.span)),
-                    |label| ctx.error(invalid_label_jump_target(label.span)),
-                );
-            }
-            AstKind::LabeledStatement(labeled_statement) => {
-                if stmt
-                    .label
-                    .as_ref()
-                    .is_some_and(|label| label.name == labeled_statement.label.name)
-                {
-                    break;
-                }
-            }
-            kind if (kind.is_iteration_statement()
-                || matches!(kind, AstKind::SwitchStatement(_)))
-                && stmt.label.is_none() =>
+            kind if kind.is_iteration_statement()
+                || matches!(kind, AstKind::SwitchStatement(_)) =>
             {
                 break;
             }
@@ -604,10 +629,28 @@ pub fn check_break_statement<'a>(
     }
 }
 
+/// Check that a labeled `break` or `continue` statement jumps to a label of the current function,
+/// which has been resolved by [`crate::label::LabelBuilder`].
+fn check_label_target<'a>(label: &LabelIdentifier, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
+    if ctx.labels.table.get_target(node.id()).is_some() {
+        return;
+    }
+    if ctx.labels.is_in_function() {
+        ctx.error(invalid_label_jump_target(label.span));
+    } else {
+        ctx.error(invalid_label_target(label.span));
+    }
+}
+
 fn invalid_continue(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Illegal continue statement: no surrounding iteration statement")
-.with_help("A `continue` statement can only be used within an enclosing `for`, `while` or `do while` ")
-.with_label(span)
+    oxc_error(
+        "semantic::invalid-continue",
+        "Illegal continue statement: no surrounding iteration statement",
+    )
+    .with_help(
+        "A `continue` statement can only be used within an enclosing `for`, `while` or `do while` ",
+    )
+    .with_label(span)
 }
 
 pub fn check_continue_statement<'a>(
@@ -615,53 +658,50 @@ pub fn check_continue_statement<'a>(
     node: &AstNode<'a>,
     ctx: &SemanticBuilder<'a>,
 ) {
+    if let Some(label) = &stmt.label {
+        check_label_target(label, node, ctx);
+        let Some(label_id) = ctx.labels.table.get_target(node.id()) else {
+            return;
+        };
+        let AstKind::LabeledStatement(labeled_statement) = ctx.nodes.kind(label_id) else {
+            return;
+        };
+        if !matches!(
+            labeled_statement.body,
+            Statement::LabeledStatement(_)
+                | Statement::DoWhileStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::ForStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+        ) {
+            ctx.error(invalid_label_non_iteration(
+                "continue",
+                labeled_statement.label.span,
+                label.span,
+            ));
+        }
+        return;
+    }
+
     // It is a Syntax Error if this ContinueStatement is not nested, directly or indirectly (but not crossing function or static initialization block boundaries), within an IterationStatement.
     for node_id in ctx.nodes.ancestors(node.id()).skip(1) {
         match ctx.nodes.kind(node_id) {
-            AstKind::Program(_) => {
-                return stmt.label.as_ref().map_or_else(
-                    || ctx.error(invalid_continue(stmt.span)),
-                    |label| ctx.error(invalid_label_target(label.span)),
-                );
+            AstKind::Program(_) | AstKind::Function(_) | AstKind::StaticBlock(_) => {
+                return ctx.error(invalid_continue(stmt.span));
             }
-            AstKind::Function(_) | AstKind::StaticBlock(_) => {
-                return stmt.label.as_ref().map_or_else(
-                    || ctx.error(invalid_continue(stmt.span)),
-                    |label| ctx.error(invalid_label_jump_target(label.span)),
-                );
-            }
-            AstKind::LabeledStatement(labeled_statement) => match &stmt.label {
-                Some(label) if label.name == labeled_statement.label.name => {
-                    if matches!(
-                        labeled_statement.body,
-                        Statement::LabeledStatement(_)
-                            | Statement::DoWhileStatement(_)
-                            | Statement::WhileStatement(_)
-                            | Statement::ForStatement(_)
-                            | Statement::ForInStatement(_)
-                            | Statement::ForOfStatement(_)
-                    ) {
-                        break;
-                    }
-                    return ctx.error(invalid_label_non_iteration(
-                        "continue",
-                        labeled_statement.label.span,
-                        label.span,
-                    ));
-                }
-                _ => {}
-            },
-            kind if kind.is_iteration_statement() && stmt.label.is_none() => break,
+            kind if kind.is_iteration_statement() => break,
             _ => {}
         }
     }
 }
 
 fn label_redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Label `{x0}` has already been declared")).with_labels([
-        span1.label(format!("`{x0}` has already been declared here")),
-        span2.label("It can not be redeclared here"),
-    ])
+    oxc_error("semantic::label-redeclaration", format!("Label `{x0}` has already been declared"))
+        .with_labels([
+            span1.label(format!("`{x0}` has already been declared here")),
+            span2.label("It can not be redeclared here"),
+        ])
 }
 
 pub fn check_labeled_statement<'a>(
@@ -687,15 +727,19 @@ pub fn check_labeled_statement<'a>(
 }
 
 fn multiple_declaration_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "Only a single declaration is allowed in a `for...{x0}` statement"
-    ))
+    oxc_error(
+        "semantic::multiple-for-loop-head-declarations",
+        format!("Only a single declaration is allowed in a `for...{x0}` statement"),
+    )
     .with_label(span1)
 }
 
 fn unexpected_initializer_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("{x0} loop variable declaration may not have an initializer"))
-        .with_label(span1)
+    oxc_error(
+        "semantic::for-loop-head-initializer",
+        format!("{x0} loop variable declaration may not have an initializer"),
+    )
+    .with_label(span1)
 }
 
 pub fn check_for_statement_left<'a>(
@@ -731,14 +775,18 @@ pub fn check_for_statement_left<'a>(
 }
 
 fn duplicate_constructor(span: Span, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Multiple constructor implementations are not allowed.").with_labels([
+    oxc_error(
+        "semantic::duplicate-constructor",
+        "Multiple constructor implementations are not allowed.",
+    )
+    .with_labels([
         LabeledSpan::new_with_span(Some("constructor has already been declared here".into()), span),
         LabeledSpan::new_with_span(Some("it cannot be redeclared here".into()), span1),
     ])
 }
 
 fn require_class_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A class name is required.").with_label(span)
+    oxc_error("semantic::missing-class-name", "A class name is required.").with_label(span)
 }
 
 pub fn check_class(class: &Class, node: &AstNode<'_>, ctx: &SemanticBuilder<'_>) {
@@ -774,11 +822,16 @@ pub fn check_class(class: &Class, node: &AstNode<'_>, ctx: &SemanticBuilder<'_>)
 }
 
 fn setter_with_parameters(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'set' accessor must have exactly one parameter.").with_label(span)
+    oxc_error(
+        "semantic::setter-parameter-count",
+        "A 'set' accessor must have exactly one parameter.",
+    )
+    .with_label(span)
 }
 
 fn setter_with_rest_parameter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'set' accessor cannot have rest parameter.").with_label(span)
+    oxc_error("semantic::setter-rest-parameter", "A 'set' accessor cannot have rest parameter.")
+        .with_label(span)
 }
 
 fn check_setter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
@@ -795,7 +848,11 @@ fn check_setter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
 }
 
 fn getter_parameters(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'get' accessor must not have any formal parameters.").with_label(span)
+    oxc_error(
+        "semantic::getter-with-parameters",
+        "A 'get' accessor must not have any formal parameters.",
+    )
+    .with_label(span)
 }
 
 fn check_getter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
@@ -813,21 +870,24 @@ pub fn check_method_definition(method: &MethodDefinition<'_>, ctx: &SemanticBuil
 }
 
 fn super_without_derived_class(span: Span, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be referenced in a derived class.")
-        .with_help("either remove this super, or extend the class")
-        .with_labels([
-            span.into(),
-            LabeledSpan::new_with_span(Some("class does not have `extends`".into()), span1),
-        ])
+    oxc_error(
+        "semantic::super-without-derived-class",
+        "'super' can only be referenced in a derived class.",
+    )
+    .with_help("either remove this super, or extend the class")
+    .with_labels([
+        span.into(),
+        LabeledSpan::new_with_span(Some("class does not have `extends`".into()), span1),
+    ])
 }
 
 fn unexpected_super_call(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Super calls are not permitted outside constructors or in nested functions inside constructors.")
+    oxc_error("semantic::unexpected-super-call", "Super calls are not permitted outside constructors or in nested functions inside constructors.")
 .with_label(span)
 }
 
 fn unexpected_super_reference(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'super' can only be referenced in members of derived classes or object literal expressions.")
+    oxc_error("semantic::unexpected-super-reference", "'super' can only be referenced in members of derived classes or object literal expressions.")
 .with_label(span)
 }
 
@@ -917,7 +977,7 @@ pub fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &SemanticBuilder<'a
 }
 
 fn cover_initialized_name(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid assignment in object literal")
+    oxc_error("semantic::cover-initialized-name", "Invalid assignment in object literal")
 .with_help("Did you mean to use a ':'? An '=' can only follow a property name when the containing object literal is part of a destructuring pattern.")
 .with_label(span)
 }
@@ -939,7 +999,8 @@ pub fn check_object_property(prop: &ObjectProperty, ctx: &SemanticBuilder<'_>) {
 }
 
 fn a_rest_parameter_cannot_have_an_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter cannot have an initializer").with_label(span)
+    oxc_error("semantic::rest-parameter-initializer", "A rest parameter cannot have an initializer")
+        .with_label(span)
 }
 
 pub fn check_formal_parameters<'a>(
@@ -965,7 +1026,8 @@ pub fn check_array_pattern(pattern: &ArrayPattern, ctx: &SemanticBuilder<'_>) {
 }
 
 fn assignment_is_not_simple(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid left-hand side in assignment").with_label(span)
+    oxc_error("semantic::invalid-assignment-target", "Invalid left-hand side in assignment")
+        .with_label(span)
 }
 
 pub fn check_assignment_expression(assign_expr: &AssignmentExpression, ctx: &SemanticBuilder<'_>) {
@@ -999,7 +1061,7 @@ pub fn check_object_expression(obj_expr: &ObjectExpression, ctx: &SemanticBuilde
 }
 
 fn unexpected_exponential(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected exponentiation expression")
+    oxc_error("semantic::ambiguous-exponentiation", "Unexpected exponentiation expression")
         .with_help(format!("Wrap {x0} expression in parentheses to enforce operator precedence"))
         .with_label(span1)
 }
@@ -1022,9 +1084,12 @@ pub fn check_binary_expression(binary_expr: &BinaryExpression, ctx: &SemanticBui
 }
 
 fn mixed_coalesce(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Logical expressions and coalesce expressions cannot be mixed")
-        .with_help("Wrap either expression by parentheses")
-        .with_label(span)
+    oxc_error(
+        "semantic::mixed-coalesce",
+        "Logical expressions and coalesce expressions cannot be mixed",
+    )
+    .with_help("Wrap either expression by parentheses")
+    .with_label(span)
 }
 
 pub fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &SemanticBuilder<'_>) {
@@ -1049,7 +1114,8 @@ pub fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &Semantic
 }
 
 fn super_private(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Private fields cannot be accessed on super").with_label(span)
+    oxc_error("semantic::super-private", "Private fields cannot be accessed on super")
+        .with_label(span)
 }
 
 pub fn check_member_expression(member_expr: &MemberExpression, ctx: &SemanticBuilder<'_>) {
@@ -1062,11 +1128,16 @@ pub fn check_member_expression(member_expr: &MemberExpression, ctx: &SemanticBui
 }
 
 fn delete_of_unqualified(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Delete of an unqualified identifier in strict mode.").with_label(span)
+    oxc_error(
+        "semantic::strict-mode-delete-identifier",
+        "Delete of an unqualified identifier in strict mode.",
+    )
+    .with_label(span)
 }
 
 fn delete_private_field(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Private fields can not be deleted").with_label(span)
+    oxc_error("semantic::delete-private-field", "Private fields can not be deleted")
+        .with_label(span)
 }
 
 pub fn check_unary_expression<'a>(
@@ -1102,8 +1173,11 @@ fn is_in_formal_parameters<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) ->
 }
 
 fn await_or_yield_in_parameter(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("{x0} expression not allowed in formal parameter"))
-        .with_label(span1.label(format!("{x0} expression not allowed in formal parameter")))
+    oxc_error(
+        "semantic::await-or-yield-in-parameter",
+        format!("{x0} expression not allowed in formal parameter"),
+    )
+    .with_label(span1.label(format!("{x0} expression not allowed in formal parameter")))
 }
 
 pub fn check_await_expression<'a>(
@@ -4,14 +4,90 @@ mod typescript;
 use javascript as js;
 pub use javascript::check_module_record;
 use oxc_ast::{
-    ast::{DoWhileStatement, ForStatement, WhileStatement},
+    ast::{DoWhileStatement, ForStatement, Program, WhileStatement},
     AstKind,
 };
+use oxc_diagnostics::OxcDiagnostic;
+use rustc_hash::FxHashSet;
 use typescript as ts;
 
 use crate::{builder::SemanticBuilder, AstNode};
 
-pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
+/// Which syntax errors are reported by [`EarlyErrorChecker`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EarlyErrorLevel {
+    /// Only the [early errors](https://tc39.es/ecma262/#early-error) of the ECMAScript
+    /// specification.
+    Spec,
+    /// The early errors of the ECMAScript specification, and the syntax errors of the TypeScript
+    /// compiler for TypeScript specific syntax.
+    #[default]
+    TypeScript,
+}
+
+/// Checker of syntax errors which are not reported by the parser, such as duplicate `__proto__`
+/// properties or `delete` of an identifier in strict mode.
+///
+/// The checks require scope information, so they run while [`SemanticBuilder`] visits the AST.
+/// Enable them with [`SemanticBuilder::with_early_error_checker`], or run them alone with
+/// [`EarlyErrorChecker::check`].
+///
+/// Every error has a stable code, e.g. `semantic::with-statement`, which linters can map to
+/// their rules, and which can be disabled with [`EarlyErrorChecker::with_disabled_codes`].
+#[derive(Debug, Default, Clone)]
+pub struct EarlyErrorChecker {
+    level: EarlyErrorLevel,
+    disabled_codes: FxHashSet<String>,
+}
+
+impl EarlyErrorChecker {
+    pub fn new(level: EarlyErrorLevel) -> Self {
+        Self { level, disabled_codes: FxHashSet::default() }
+    }
+
+    /// Do not report errors with these codes, e.g. `semantic::with-statement`, or `1039` for
+    /// TypeScript's TS1039.
+    #[must_use]
+    pub fn with_disabled_codes<I, S>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.disabled_codes.extend(codes.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn level(&self) -> EarlyErrorLevel {
+        self.level
+    }
+
+    /// Check `program` for syntax errors which are not reported by the parser.
+    ///
+    /// This builds the semantic model of `program`, use the errors of [`SemanticBuilder`]
+    /// instead when the semantic model is needed as well.
+    pub fn check<'a>(&self, program: &Program<'a>, source_text: &'a str) -> Vec<OxcDiagnostic> {
+        SemanticBuilder::new(source_text)
+            .with_early_error_checker(self.clone())
+            .build(program)
+            .errors
+    }
+
+    /// Whether `error` is reported, i.e. its code is not disabled.
+    pub fn is_enabled(&self, error: &OxcDiagnostic) -> bool {
+        error.code.number.as_ref().map_or(true, |code| !self.disabled_codes.contains(code.as_ref()))
+    }
+
+    /// Check a node when [`SemanticBuilder`] leaves it.
+    pub(crate) fn check_node<'a>(&self, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
+        check_javascript(node, ctx);
+        if self.level == EarlyErrorLevel::TypeScript {
+            check_typescript(node, ctx);
+        }
+    }
+}
+
+/// Early errors of the ECMAScript specification.
+fn check_javascript<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
     let kind = node.kind();
 
     match kind {
@@ -68,29 +144,13 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
                 js::check_function_declaration(alternate, true, ctx);
             }
         }
-        AstKind::Class(class) => {
-            js::check_class(class, node, ctx);
-            ts::check_class(class, ctx);
-        }
-        AstKind::MethodDefinition(method) => {
-            js::check_method_definition(method, ctx);
-            ts::check_method_definition(method, ctx);
-        }
-        AstKind::PropertyDefinition(prop) => ts::check_property_definition(prop, ctx),
-        AstKind::ObjectProperty(prop) => {
-            js::check_object_property(prop, ctx);
-            ts::check_object_property(prop, ctx);
-        }
+        AstKind::Class(class) => js::check_class(class, node, ctx),
+        AstKind::MethodDefinition(method) => js::check_method_definition(method, ctx),
+        AstKind::ObjectProperty(prop) => js::check_object_property(prop, ctx),
         AstKind::Super(sup) => js::check_super(sup, node, ctx),
 
-        AstKind::FormalParameters(params) => {
-            js::check_formal_parameters(params, node, ctx);
-            ts::check_formal_parameters(params, ctx);
-        }
-        AstKind::ArrayPattern(pat) => {
-            js::check_array_pattern(pat, ctx);
-            ts::check_array_pattern(pat, ctx);
-        }
+        AstKind::FormalParameters(params) => js::check_formal_parameters(params, node, ctx),
+        AstKind::ArrayPattern(pat) => js::check_array_pattern(pat, ctx),
 
         AstKind::AssignmentExpression(expr) => js::check_assignment_expression(expr, ctx),
         AstKind::AwaitExpression(expr) => js::check_await_expression(expr, node, ctx),
@@ -100,6 +160,19 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         AstKind::ObjectExpression(expr) => js::check_object_expression(expr, ctx),
         AstKind::UnaryExpression(expr) => js::check_unary_expression(expr, node, ctx),
         AstKind::YieldExpression(expr) => js::check_yield_expression(expr, node, ctx),
+        _ => {}
+    }
+}
+
+/// Syntax errors of the TypeScript compiler.
+fn check_typescript<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
+    match node.kind() {
+        AstKind::Class(class) => ts::check_class(class, ctx),
+        AstKind::MethodDefinition(method) => ts::check_method_definition(method, ctx),
+        AstKind::PropertyDefinition(prop) => ts::check_property_definition(prop, ctx),
+        AstKind::ObjectProperty(prop) => ts::check_object_property(prop, ctx),
+        AstKind::FormalParameters(params) => ts::check_formal_parameters(params, ctx),
+        AstKind::ArrayPattern(pat) => ts::check_array_pattern(pat, ctx),
         AstKind::VariableDeclaration(decl) => ts::check_variable_declaration(decl, ctx),
         AstKind::VariableDeclarator(decl) => ts::check_variable_declarator(decl, ctx),
         AstKind::SimpleAssignmentTarget(target) => ts::check_simple_assignment_target(target, ctx),
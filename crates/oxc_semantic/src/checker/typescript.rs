@@ -7,14 +7,18 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, GetSpan, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{builder::SemanticBuilder, diagnostics::redeclaration};
+use crate::{
+    builder::SemanticBuilder,
+    diagnostics::{oxc_error, redeclaration},
+};
 
 fn ts_error<M: Into<Cow<'static, str>>>(code: &'static str, message: M) -> OxcDiagnostic {
     OxcDiagnostic::error(message).with_error_code("TS", code)
 }
 
 fn empty_type_parameter_list(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Type parameter list cannot be empty.").with_label(span)
+    oxc_error("semantic::empty-type-parameter-list", "Type parameter list cannot be empty.")
+        .with_label(span)
 }
 
 pub fn check_ts_type_parameter_declaration(
@@ -42,27 +46,40 @@ pub fn check_variable_declaration(decl: &VariableDeclaration, ctx: &SemanticBuil
 }
 
 fn unexpected_optional(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected `?` operator").with_label(span)
+    oxc_error("semantic::unexpected-optional", "Unexpected `?` operator").with_label(span)
 }
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn check_variable_declarator(decl: &VariableDeclarator, ctx: &SemanticBuilder<'_>) {
     if decl.id.optional {
-        let start = decl.id.span().end;
-        let Some(offset) = ctx.source_text[start as usize..].find('?') else { return };
-        let offset = start + offset as u32;
+        let offset = match &decl.id.type_annotation {
+            // `a?: T`, the span of `a` is extended to the end of the type annotation
+            Some(annotation) => ctx.source_text[..annotation.span.start as usize].rfind('?'),
+            None => {
+                let start = decl.id.span().end as usize;
+                ctx.source_text[start..].find('?').map(|offset| start + offset)
+            }
+        };
+        let Some(offset) = offset else { return };
+        let offset = offset as u32;
         ctx.error(unexpected_optional(Span::new(offset, offset)));
     }
 }
 
 fn required_parameter_after_optional_parameter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A required parameter cannot follow an optional parameter.")
-        .with_label(span)
+    oxc_error(
+        "semantic::required-parameter-after-optional",
+        "A required parameter cannot follow an optional parameter.",
+    )
+    .with_label(span)
 }
 
 fn parameter_property_outside_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A parameter property is only allowed in a constructor implementation.")
-        .with_label(span)
+    oxc_error(
+        "semantic::parameter-property-outside-constructor",
+        "A parameter property is only allowed in a constructor implementation.",
+    )
+    .with_label(span)
 }
 
 pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<'_>) {
@@ -100,7 +117,8 @@ fn check_duplicate_bound_names<'a, T: BoundNames<'a>>(bound_names: &T, ctx: &Sem
 }
 
 fn unexpected_assignment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "semantic::invalid-ts-assignment-target",
         "The left-hand side of an assignment expression must be a variable or a property access.",
     )
     .with_label(span)
@@ -123,7 +141,7 @@ pub fn check_simple_assignment_target<'a>(
 }
 
 fn unexpected_type_annotation(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected type annotation").with_label(span)
+    oxc_error("semantic::unexpected-type-annotation", "Unexpected type annotation").with_label(span)
 }
 
 pub fn check_array_pattern<'a>(pattern: &ArrayPattern<'a>, ctx: &SemanticBuilder<'a>) {
@@ -162,7 +180,8 @@ pub fn check_ts_interface_declaration<'a>(
 }
 
 fn not_allowed_namespace_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    oxc_error(
+        "semantic::namespace-not-top-level",
         "A namespace declaration is only allowed at the top level of a namespace or module.",
     )
     .with_label(span)
@@ -188,7 +207,8 @@ pub fn check_ts_module_declaration<'a>(decl: &TSModuleDeclaration<'a>, ctx: &Sem
 }
 
 fn enum_member_must_have_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Enum member must have initializer.").with_label(span)
+    oxc_error("semantic::enum-member-initializer", "Enum member must have initializer.")
+        .with_label(span)
 }
 
 pub fn check_ts_enum_declaration<'a>(decl: &TSEnumDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
@@ -311,7 +331,8 @@ fn parameter_property_only_in_constructor_impl(span: Span) -> OxcDiagnostic {
 /// Getter or setter without a body. There is no corresponding TS error code,
 /// since in TSC this is a parse error.
 fn accessor_without_body(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Getters and setters must have an implementation.").with_label(span)
+    oxc_error("semantic::accessor-without-body", "Getters and setters must have an implementation.")
+        .with_label(span)
 }
 
 pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &SemanticBuilder<'a>) {
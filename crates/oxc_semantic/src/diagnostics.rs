@@ -1,9 +1,85 @@
+use std::borrow::Cow;
+
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
+/// Create an error with a stable oxc code, e.g. `oxc(semantic::redeclaration)`.
+/// Every code must have an entry in [`explain`].
+#[inline]
+pub fn oxc_error<M: Into<Cow<'static, str>>>(code: &'static str, message: M) -> OxcDiagnostic {
+    OxcDiagnostic::error(message).with_error_code("oxc", code)
+}
+
 pub fn redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
-        span1.label(format!("`{x0}` has already been declared here")),
-        span2.label("It can not be redeclared here"),
-    ])
+    oxc_error("semantic::redeclaration", format!("Identifier `{x0}` has already been declared"))
+        .with_labels([
+            span1.label(format!("`{x0}` has already been declared here")),
+            span2.label("It can not be redeclared here"),
+        ])
+}
+
+/// Extended explanation of semantic analysis error codes, such as `semantic::undefined-export`.
+/// Returns `None` for unknown codes.
+pub fn explain(code: &str) -> Option<&'static str> {
+    let explanation = match code {
+        "semantic::undefined-export" => "Every local name in an `export { name }` clause must be declared in the module.",
+        "semantic::duplicate-export" => "A module cannot export the same name twice.",
+        "semantic::class-static-block-await" => "`await` cannot be used in a class static initialization block, even inside an async function or module.",
+        "semantic::reserved-keyword" => "The name is a reserved word in strict mode code (`implements`, `interface`, `let`, `package`, `private`, `protected`, `public`, `static`, `yield`) and cannot be used as an identifier.",
+        "semantic::strict-mode-assignment" => "`eval` and `arguments` cannot be assigned to, or declared, in strict mode code.",
+        "semantic::invalid-let-declaration" => "`let` cannot be used as a variable name in a `let` or `const` declaration.",
+        "semantic::unexpected-arguments" => "`arguments` cannot be referenced in class field initializers or class static blocks.",
+        "semantic::private-identifier-outside-class" => "A private identifier such as `#a` can only be used inside the body of the class that declares it.",
+        "semantic::private-field-undeclared" => "A private field must be declared in an enclosing class before it can be accessed.",
+        "semantic::legacy-octal" => "Legacy octal literals (`010`) and octal escape sequences (`'\\01'`) are not allowed in strict mode code. Use the `0o` prefix instead: `0o10`.",
+        "semantic::leading-zero-decimal" => "Decimal literals with leading zeros (`08`) are not allowed in strict mode code.",
+        "semantic::non-octal-decimal-escape-sequence" => "The escape sequences `\\8` and `\\9` are not allowed in strict mode code.",
+        "semantic::illegal-use-strict" => "A function with default values, destructuring or rest parameters cannot contain a `'use strict'` directive. Move the directive to the enclosing scope.",
+        "semantic::module-declaration-not-top-level" => "`import` and `export` declarations must appear at the top level of a module.",
+        "semantic::module-syntax-in-script" => "`import` and `export` declarations are only valid in ES modules. Change the source type to module, or use the `.mjs` / `.mts` extension.",
+        "semantic::new-target" => "`new.target` can only be used inside functions and class bodies.",
+        "semantic::invalid-new-meta-property" => "`new.target` is the only meta property available on `new`.",
+        "semantic::import-meta" => "`import.meta` can only be used in ES modules.",
+        "semantic::invalid-import-meta-property" => "`import.meta` is the only meta property available on `import`.",
+        "semantic::invalid-function-declaration" => "Function declarations cannot be the direct body of a loop or a label. In strict mode code they also cannot be the direct body of an `if` statement. Wrap the declaration in a block.",
+        "semantic::regexp-u-and-v-flags" => "The `u` and `v` regular expression flags are mutually exclusive, `v` is a superset of `u`.",
+        "semantic::with-statement" => "`with` statements are not allowed in strict mode code, which includes ES modules and classes.",
+        "semantic::label-crosses-function" => "`break` and `continue` cannot jump to a label outside of the current function.",
+        "semantic::undefined-label" => "The label used by `break` or `continue` is not declared by any enclosing labeled statement.",
+        "semantic::non-iteration-label" => "`continue` can only target the label of an enclosing loop statement.",
+        "semantic::invalid-break" => "`break` without a label can only be used inside a loop or a `switch` statement.",
+        "semantic::invalid-continue" => "`continue` can only be used inside a loop.",
+        "semantic::label-redeclaration" => "A label cannot be declared again inside a statement labeled with the same name.",
+        "semantic::multiple-for-loop-head-declarations" => "The head of a `for...in` or `for...of` statement can only declare a single binding.",
+        "semantic::for-loop-head-initializer" => "Variables declared in the head of a `for...in` or `for...of` statement cannot have an initializer, except for `var` in `for...in` in sloppy mode.",
+        "semantic::duplicate-constructor" => "A class can only have one constructor.",
+        "semantic::missing-class-name" => "Class declarations require a name, unless they are the default export of a module.",
+        "semantic::setter-parameter-count" => "A setter must declare exactly one parameter.",
+        "semantic::setter-rest-parameter" => "The parameter of a setter cannot be a rest parameter.",
+        "semantic::getter-with-parameters" => "A getter cannot declare any parameters.",
+        "semantic::super-without-derived-class" => "`super()` can only be called in the constructor of a class that `extends` another class.",
+        "semantic::unexpected-super-call" => "`super()` can only be called directly inside a class constructor, or inside arrow functions nested in it.",
+        "semantic::unexpected-super-reference" => "`super.prop` can only be used in methods of classes and object literals.",
+        "semantic::cover-initialized-name" => "`{ a = 1 }` is only valid as a destructuring pattern. In an object literal, use `{ a: 1 }`.",
+        "semantic::rest-parameter-initializer" => "A rest parameter cannot have a default value.",
+        "semantic::invalid-assignment-target" => "The left-hand side of a compound assignment (`+=`, `&&=`, ...) must be an identifier or a member expression.",
+        "semantic::ambiguous-exponentiation" => "A unary expression cannot be the base of `**`, write `(-a) ** b` or `-(a ** b)` to make the precedence explicit.",
+        "semantic::mixed-coalesce" => "`??` cannot be mixed with `&&` or `||` without parentheses: `(a ?? b) || c`.",
+        "semantic::super-private" => "Private fields are not inherited and cannot be accessed through `super`.",
+        "semantic::strict-mode-delete-identifier" => "`delete` cannot be applied to a plain identifier in strict mode code.",
+        "semantic::delete-private-field" => "Private fields cannot be removed with `delete`.",
+        "semantic::await-or-yield-in-parameter" => "`await` and `yield` expressions cannot be used in the parameter list of the function they belong to.",
+        "semantic::empty-type-parameter-list" => "A type parameter list `<>` must declare at least one type parameter.",
+        "semantic::unexpected-optional" => "Only parameters, properties and methods can be marked optional with `?`.",
+        "semantic::required-parameter-after-optional" => "Required parameters must come before optional parameters.",
+        "semantic::parameter-property-outside-constructor" => "Parameter properties such as `constructor(private a: string)` are only allowed in class constructors.",
+        "semantic::invalid-ts-assignment-target" => "The left-hand side of an assignment must be a variable or a property access, type assertions and non-null assertions are only allowed around them.",
+        "semantic::unexpected-type-annotation" => "Type annotations are not allowed at this position, for example on the elements of an array pattern.",
+        "semantic::namespace-not-top-level" => "A `namespace` declaration can only appear at the top level of a module or inside another namespace.",
+        "semantic::enum-member-initializer" => "An enum member following a member with a non-numeric initializer must have an initializer.",
+        "semantic::accessor-without-body" => "Getters and setters in non-ambient classes must have a body.",
+        "semantic::redeclaration" => "A variable, function, class or import binding was declared twice in the same scope. Only `var` and function declarations in sloppy mode may be redeclared.",
+        _ => return None,
+    };
+    Some(explanation)
 }
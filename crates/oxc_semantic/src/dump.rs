@@ -0,0 +1,186 @@
+//! Structured dump of semantic data for visualizing alongside the AST, see [`Semantic::debug_dump`].
+
+// Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
+#![allow(non_snake_case)]
+
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    ControlFlowGraph,
+};
+use oxc_index::Idx;
+use oxc_span::{GetSpan, Span};
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+#[cfg(feature = "serialize")]
+use tsify::Tsify;
+
+use crate::Semantic;
+
+/// Scopes, symbols, references and CFG basic blocks of a [`Semantic`], flattened into plain
+/// data. All ids are indexes into the corresponding list, and flags are rendered with their
+/// `Debug` representation.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct SemanticDump {
+    pub scopes: Vec<ScopeDump>,
+    pub symbols: Vec<SymbolDump>,
+    pub references: Vec<ReferenceDump>,
+    /// Empty if the control flow graph was not built.
+    pub basic_blocks: Vec<BasicBlockDump>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ScopeDump {
+    pub id: usize,
+    pub parent_id: Option<usize>,
+    pub flags: String,
+    /// Span of the AST node that creates the scope.
+    pub span: Span,
+    pub bindings: Vec<usize>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct SymbolDump {
+    pub id: usize,
+    pub name: String,
+    pub flags: String,
+    pub span: Span,
+    pub scope_id: usize,
+    pub redeclarations: Vec<Span>,
+    pub references: Vec<usize>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ReferenceDump {
+    pub id: usize,
+    pub name: String,
+    pub flags: String,
+    pub span: Span,
+    /// `None` for unresolved references, e.g. globals.
+    pub symbol_id: Option<usize>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct BasicBlockDump {
+    pub id: usize,
+    pub unreachable: bool,
+    pub instructions: Vec<InstructionDump>,
+    pub successors: Vec<EdgeDump>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct InstructionDump {
+    pub kind: String,
+    pub span: Option<Span>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct EdgeDump {
+    /// Id of the target basic block.
+    pub target: usize,
+    pub kind: String,
+}
+
+impl<'a> Semantic<'a> {
+    /// Dump scopes, symbols, references and CFG basic blocks with their spans.
+    ///
+    /// This is meant for debugging and visualization, e.g. in the playground.
+    pub fn debug_dump(&self) -> SemanticDump {
+        let scopes = self.scopes();
+        let symbols = self.symbols();
+
+        let scopes_dump = scopes
+            .descendants_from_root()
+            .map(|scope_id| ScopeDump {
+                id: scope_id.index(),
+                parent_id: scopes.get_parent_id(scope_id).map(Idx::index),
+                flags: format!("{:?}", scopes.get_flags(scope_id)),
+                span: self.nodes().kind(scopes.get_node_id(scope_id)).span(),
+                bindings: scopes.iter_bindings_in(scope_id).map(Idx::index).collect(),
+            })
+            .collect();
+
+        let symbols_dump = symbols
+            .iter()
+            .map(|symbol_id| SymbolDump {
+                id: symbol_id.index(),
+                name: symbols.get_name(symbol_id).to_string(),
+                flags: format!("{:?}", symbols.get_flags(symbol_id)),
+                span: symbols.get_span(symbol_id),
+                scope_id: symbols.get_scope_id(symbol_id).index(),
+                redeclarations: symbols.get_redeclarations(symbol_id).to_vec(),
+                references: symbols
+                    .get_resolved_reference_ids(symbol_id)
+                    .iter()
+                    .map(|reference_id| reference_id.index())
+                    .collect(),
+            })
+            .collect();
+
+        let references_dump = symbols
+            .references
+            .iter_enumerated()
+            .map(|(reference_id, reference)| ReferenceDump {
+                id: reference_id.index(),
+                name: self.reference_name(reference).to_string(),
+                flags: format!("{:?}", reference.flags()),
+                span: self.reference_span(reference),
+                symbol_id: reference.symbol_id().map(Idx::index),
+            })
+            .collect();
+
+        let basic_blocks_dump = self.cfg().map(|cfg| self.dump_cfg(cfg)).unwrap_or_default();
+
+        SemanticDump {
+            scopes: scopes_dump,
+            symbols: symbols_dump,
+            references: references_dump,
+            basic_blocks: basic_blocks_dump,
+        }
+    }
+
+    fn dump_cfg(&self, cfg: &ControlFlowGraph) -> Vec<BasicBlockDump> {
+        cfg.graph()
+            .node_indices()
+            .map(|node| {
+                let id = *cfg.graph().node_weight(node).unwrap();
+                let basic_block = cfg.basic_block(node);
+                BasicBlockDump {
+                    id,
+                    unreachable: basic_block.unreachable,
+                    instructions: basic_block
+                        .instructions()
+                        .iter()
+                        .map(|instruction| InstructionDump {
+                            kind: format!("{:?}", instruction.kind),
+                            span: instruction
+                                .node_id
+                                .map(|node_id| self.nodes().kind(node_id).span()),
+                        })
+                        .collect(),
+                    successors: cfg
+                        .graph()
+                        .edges_directed(node, Direction::Outgoing)
+                        .map(|edge| EdgeDump {
+                            target: *cfg.graph().node_weight(edge.target()).unwrap(),
+                            kind: format!("{:?}", edge.weight()),
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
@@ -1,37 +1,93 @@
+use rustc_hash::FxHashMap;
+
 use crate::AstNodeId;
 
-#[derive(Debug)]
-pub struct LabeledScope<'a> {
-    pub name: &'a str,
-    pub used: bool,
-    pub parent: usize,
+/// Resolution of `break label` and `continue label` statements to the
+/// `LabeledStatement`s they jump to.
+#[derive(Debug, Default)]
+pub struct LabelTable {
+    /// The `LabeledStatement` each labeled `break` or `continue` statement jumps to.
+    targets: FxHashMap<AstNodeId, AstNodeId>,
+    /// The labeled `break` and `continue` statements jumping to each `LabeledStatement`.
+    jumps: FxHashMap<AstNodeId, Vec<AstNodeId>>,
+    /// `LabeledStatement`s without any jumps, in the order they end.
+    unused: Vec<AstNodeId>,
+}
+
+impl LabelTable {
+    /// Get the `LabeledStatement` a `break label` or `continue label` statement jumps to.
+    ///
+    /// Returns `None` for unlabeled jumps, and for jumps to undefined labels or labels outside
+    /// of the enclosing function.
+    pub fn get_target(&self, jump_id: AstNodeId) -> Option<AstNodeId> {
+        self.targets.get(&jump_id).copied()
+    }
+
+    /// Get the `break` and `continue` statements jumping to a `LabeledStatement`.
+    pub fn get_jumps(&self, label_id: AstNodeId) -> &[AstNodeId] {
+        self.jumps.get(&label_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_used(&self, label_id: AstNodeId) -> bool {
+        self.jumps.contains_key(&label_id)
+    }
+
+    /// `LabeledStatement`s which are not jumped to by any `break` or `continue` statement.
+    pub fn unused_labels(&self) -> &Vec<AstNodeId> {
+        &self.unused
+    }
 }
 
+/// Labels enclosing the node being visited, used to build a [`LabelTable`].
 #[derive(Debug, Default)]
-pub struct UnusedLabels<'a> {
-    pub scopes: Vec<LabeledScope<'a>>,
-    pub curr_scope: usize,
-    pub labels: Vec<AstNodeId>,
+pub struct LabelBuilder<'a> {
+    pub table: LabelTable,
+    /// Enclosing labels, innermost last. `None` marks a function, arrow function or
+    /// class static block, which labels cannot cross.
+    stack: Vec<Option<(&'a str, AstNodeId)>>,
 }
 
-impl<'a> UnusedLabels<'a> {
-    pub fn add(&mut self, name: &'a str) {
-        self.scopes.push(LabeledScope { name, used: false, parent: self.curr_scope });
-        self.curr_scope = self.scopes.len() - 1;
+impl<'a> LabelBuilder<'a> {
+    pub fn enter_label(&mut self, name: &'a str, label_id: AstNodeId) {
+        self.stack.push(Some((name, label_id)));
     }
 
-    pub fn reference(&mut self, name: &'a str) {
-        let scope = self.scopes.iter_mut().rev().find(|x| x.name == name);
-        if let Some(scope) = scope {
-            scope.used = true;
+    pub fn leave_label(&mut self) {
+        if let Some(Some((_, label_id))) = self.stack.pop() {
+            if !self.table.is_used(label_id) {
+                self.table.unused.push(label_id);
+            }
         }
     }
 
-    pub fn mark_unused(&mut self, current_node_id: AstNodeId) {
-        let scope = &self.scopes[self.curr_scope];
-        if !scope.used {
-            self.labels.push(current_node_id);
-        }
-        self.curr_scope = scope.parent;
+    pub fn enter_function(&mut self) {
+        self.stack.push(None);
+    }
+
+    pub fn leave_function(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Resolve a `break label` or `continue label` statement to its `LabeledStatement`.
+    pub fn add_jump(&mut self, name: &str, jump_id: AstNodeId) -> Option<AstNodeId> {
+        let label_id = self.find(name)?;
+        self.table.targets.insert(jump_id, label_id);
+        self.table.jumps.entry(label_id).or_default().push(jump_id);
+        Some(label_id)
+    }
+
+    /// Find the innermost `LabeledStatement` named `name` in the current function.
+    pub fn find(&self, name: &str) -> Option<AstNodeId> {
+        self.stack
+            .iter()
+            .rev()
+            .map_while(|label| *label)
+            .find(|(n, _)| *n == name)
+            .map(|(_, id)| id)
+    }
+
+    /// Whether the current node is in a function or class static block.
+    pub fn is_in_function(&self) -> bool {
+        self.stack.iter().any(Option::is_none)
     }
 }
@@ -14,12 +14,15 @@ mod jsdoc;
 mod label;
 mod module_record;
 mod node;
+mod nullability;
+mod purity;
 mod reference;
 mod scope;
 mod symbol;
 mod unresolved_stack;
 
 pub mod dot;
+pub mod dump;
 pub mod post_transform_checker;
 
 use std::sync::Arc;
@@ -28,16 +31,21 @@ pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 use class::ClassTable;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
 pub use node::{AstNode, AstNodeId, AstNodes};
+pub use nullability::Nullability;
 use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
 use oxc_cfg::ControlFlowGraph;
 use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::module_record::ExportLocalName;
 pub use oxc_syntax::{
-    module_record::ModuleRecord,
+    module_record::{ExportEntry, ImportEntry, ModuleRecord},
     scope::{ScopeFlags, ScopeId},
     symbol::{SymbolFlags, SymbolId},
 };
 
 pub use crate::{
+    checker::{EarlyErrorChecker, EarlyErrorLevel},
+    diagnostics::explain as explain_error_code,
+    label::LabelTable,
     reference::{Reference, ReferenceFlags, ReferenceId},
     scope::ScopeTree,
     symbol::SymbolTable,
@@ -82,7 +90,7 @@ pub struct Semantic<'a> {
     /// Parsed JSDoc comments.
     jsdoc: JSDocFinder<'a>,
 
-    unused_labels: Vec<AstNodeId>,
+    labels: LabelTable,
 
     /// Control flow graph. Only present if [`Semantic`] is built with cfg
     /// creation enabled using [`SemanticBuilder::with_cfg`].
@@ -149,8 +157,13 @@ impl<'a> Semantic<'a> {
         &self.symbols
     }
 
+    /// [`LabelTable`] resolving labeled `break` and `continue` statements to their labels.
+    pub fn labels(&self) -> &LabelTable {
+        &self.labels
+    }
+
     pub fn unused_labels(&self) -> &Vec<AstNodeId> {
-        &self.unused_labels
+        self.labels.unused_labels()
     }
 
     /// Control flow graph.
@@ -199,6 +212,28 @@ impl<'a> Semantic<'a> {
         let node = self.nodes.get_node(reference.node_id());
         node.kind().span()
     }
+
+    /// Import entries whose local binding is never referenced.
+    ///
+    /// `import 'foo'` has no import entries, so is never reported.
+    pub fn unused_imports(&self) -> impl Iterator<Item = &ImportEntry> + '_ {
+        let (scopes, symbols) = (&self.scopes, &self.symbols);
+        self.module_record.import_entries.iter().filter(move |entry| {
+            scopes
+                .get_root_binding(entry.local_name.name())
+                .is_some_and(|symbol_id| symbols.get_resolved_reference_ids(symbol_id).is_empty())
+        })
+    }
+
+    /// Local export entries which export a name that is not declared in the module,
+    /// e.g. `export { foo }` where `foo` is not declared.
+    pub fn unresolved_exports(&self) -> impl Iterator<Item = &ExportEntry> + '_ {
+        let scopes = &self.scopes;
+        self.module_record.local_export_entries.iter().filter(move |entry| {
+            let ExportLocalName::Name(local_name) = &entry.local_name else { return false };
+            scopes.get_root_binding(local_name.name()).is_none()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -437,4 +472,85 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_early_error_checker() {
+        let alloc = Allocator::default();
+        let codes = |errors: Vec<oxc_diagnostics::OxcDiagnostic>| {
+            errors.iter().map(|error| error.code.number.clone().unwrap()).collect::<Vec<_>>()
+        };
+
+        let source = "'use strict'; with (a) {}";
+        let parse =
+            oxc_parser::Parser::new(&alloc, source, SourceType::default().with_script(true))
+                .parse();
+        assert!(parse.errors.is_empty());
+        let program = alloc.alloc(parse.program);
+        assert!(SemanticBuilder::new(source).build(program).errors.is_empty());
+        assert_eq!(
+            codes(EarlyErrorChecker::default().check(program, source)),
+            ["semantic::with-statement"]
+        );
+        let checker =
+            EarlyErrorChecker::default().with_disabled_codes(["semantic::with-statement"]);
+        assert!(checker.check(program, source).is_empty());
+
+        let source = "let a?: number = 1;";
+        let parse =
+            oxc_parser::Parser::new(&alloc, source, SourceType::default().with_typescript(true))
+                .parse();
+        assert!(parse.errors.is_empty());
+        let program = alloc.alloc(parse.program);
+        assert_eq!(
+            codes(EarlyErrorChecker::new(EarlyErrorLevel::TypeScript).check(program, source)),
+            ["semantic::unexpected-optional"]
+        );
+        assert!(EarlyErrorChecker::new(EarlyErrorLevel::Spec).check(program, source).is_empty());
+    }
+
+    #[test]
+    fn test_reference_context_flags() {
+        let alloc = Allocator::default();
+        let script = SourceType::default().with_script(true);
+        let module = SourceType::default().with_module(true);
+        let typescript = module.with_typescript(true);
+        let sources = [
+            (script, "var a; delete a", ReferenceFlags::Read | ReferenceFlags::Delete),
+            (script, "var a; delete (a)", ReferenceFlags::Read | ReferenceFlags::Delete),
+            (script, "var a = {}; delete a.b", ReferenceFlags::Read),
+            (
+                script,
+                "let a = 1; a += 1",
+                ReferenceFlags::Read | ReferenceFlags::Write | ReferenceFlags::Compound,
+            ),
+            (
+                script,
+                "let a = 1; a ??= 1",
+                ReferenceFlags::Read | ReferenceFlags::Write | ReferenceFlags::Compound,
+            ),
+            (script, "let a = 1; a++", ReferenceFlags::Write | ReferenceFlags::Compound),
+            (script, "let a = 1; a = 2", ReferenceFlags::Write),
+            (script, "let a = {}; a.b += 1", ReferenceFlags::Read),
+            (script, "let a = {}; a.b++", ReferenceFlags::Read),
+            (script, "let a = 1, b = {}; b[a] += 1", ReferenceFlags::Read),
+            (module, "let a = 1; export { a }", ReferenceFlags::Read | ReferenceFlags::Export),
+            (module, "let a = 1; export { a as b }", ReferenceFlags::Read | ReferenceFlags::Export),
+            (module, "let a = 1; export default a", ReferenceFlags::Read | ReferenceFlags::Export),
+            (module, "let a = 1; export default a + 1", ReferenceFlags::Read),
+            (
+                typescript,
+                "type a = 1; export type { a }",
+                ReferenceFlags::Type | ReferenceFlags::Export,
+            ),
+            (typescript, "type a = 1; export { a }", ReferenceFlags::Type | ReferenceFlags::Export),
+        ];
+
+        for (source_type, source, flags) in sources {
+            let semantic = get_semantic(&alloc, source, source_type);
+            let a_id = semantic.scopes().get_root_binding("a").unwrap();
+            let a_refs: Vec<_> = semantic.symbol_references(a_id).collect();
+            assert_eq!(a_refs.len(), 1, "{source}");
+            assert_eq!(a_refs[0].flags(), flags, "{source}");
+        }
+    }
 }
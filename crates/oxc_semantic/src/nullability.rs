@@ -0,0 +1,385 @@
+//! Lightweight nullability inference.
+//!
+//! Without type information, whether a value can be `null` or `undefined` is inferred from
+//! the shape of expressions, the initializers of variables which are never reassigned, and
+//! the conditions guarding a node, e.g. `if (foo != null)` or `typeof foo !== "undefined"`.
+//!
+//! Reassignments between a guard and the guarded code are not taken into account.
+
+use oxc_ast::{
+    ast::{
+        AssignmentOperator, BinaryOperator, BindingPatternKind, Expression, IdentifierReference,
+        LogicalOperator, UnaryOperator,
+    },
+    AstKind,
+};
+use oxc_span::GetSpan;
+
+use crate::{AstNodeId, Reference, Semantic};
+
+/// Maximum number of variable initializers followed, e.g. `const a = b; const b = {};`
+const MAX_DEPTH: u8 = 8;
+
+/// Whether an expression can evaluate to `null` or `undefined`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nullability {
+    /// Never `null` or `undefined`, e.g. `{}`, `"foo"` or `a + b`
+    NonNullish,
+    /// Always `null` or `undefined`, e.g. `null` or `void 0`
+    Nullish,
+    /// Either, e.g. `foo.bar` or `foo()`
+    Unknown,
+}
+
+impl Nullability {
+    pub fn is_non_nullish(self) -> bool {
+        self == Self::NonNullish
+    }
+
+    pub fn is_nullish(self) -> bool {
+        self == Self::Nullish
+    }
+
+    /// The nullability of an expression which evaluates to either `self` or `other`
+    fn union(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl<'a> Semantic<'a> {
+    /// Infer whether `expr` can evaluate to `null` or `undefined`, from its shape and the
+    /// initializers of the variables it refers to.
+    pub fn nullability(&self, expr: &Expression<'a>) -> Nullability {
+        self.nullability_impl(expr, 0)
+    }
+
+    /// Whether the variable `ident` refers to is known to be neither `null` nor `undefined`
+    /// where `node_id` is evaluated, because of its initializer or a guard such as
+    /// `if (foo)`, `foo != null && ...` or `typeof foo === "function" ? ... : ...`.
+    pub fn is_non_nullish_at(&self, node_id: AstNodeId, ident: &IdentifierReference<'a>) -> bool {
+        self.identifier_nullability(ident, 0).is_non_nullish()
+            || self.is_guarded_by(node_id, &|test, truthy| {
+                implies_non_nullish(test, ident.name.as_str(), truthy)
+            })
+    }
+
+    /// Whether the variable named `name` is known to be defined where `node_id` is evaluated,
+    /// because of a guard such as `typeof foo !== "undefined"`.
+    pub fn is_defined_at(&self, node_id: AstNodeId, name: &str) -> bool {
+        self.is_guarded_by(node_id, &|test, truthy| implies_defined(test, name, truthy))
+    }
+
+    fn nullability_impl(&self, expr: &Expression<'a>, depth: u8) -> Nullability {
+        match expr {
+            Expression::NullLiteral(_) => Nullability::Nullish,
+            Expression::BooleanLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::ObjectExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::NewExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::PrivateInExpression(_)
+            | Expression::UpdateExpression(_)
+            | Expression::TSNonNullExpression(_) => Nullability::NonNullish,
+            Expression::UnaryExpression(expr) => {
+                if expr.operator == UnaryOperator::Void {
+                    Nullability::Nullish
+                } else {
+                    Nullability::NonNullish
+                }
+            }
+            Expression::Identifier(ident) => self.identifier_nullability(ident, depth),
+            Expression::LogicalExpression(expr) => {
+                let left = self.nullability_impl(&expr.left, depth);
+                match expr.operator {
+                    // `a ?? b` is `b` only if `a` is nullish
+                    LogicalOperator::Coalesce => match left {
+                        Nullability::NonNullish => Nullability::NonNullish,
+                        Nullability::Nullish => self.nullability_impl(&expr.right, depth),
+                        Nullability::Unknown => match self.nullability_impl(&expr.right, depth) {
+                            Nullability::NonNullish => Nullability::NonNullish,
+                            _ => Nullability::Unknown,
+                        },
+                    },
+                    // `a || b` is `b` if `a` is nullish, `a` can still be a falsy value
+                    LogicalOperator::Or => match left {
+                        Nullability::Nullish => self.nullability_impl(&expr.right, depth),
+                        _ => match self.nullability_impl(&expr.right, depth) {
+                            Nullability::NonNullish => Nullability::NonNullish,
+                            _ => Nullability::Unknown,
+                        },
+                    },
+                    // `a && b` is `a` if `a` is nullish
+                    LogicalOperator::And => match left {
+                        Nullability::Nullish => Nullability::Nullish,
+                        _ => left.union(self.nullability_impl(&expr.right, depth)),
+                    },
+                }
+            }
+            Expression::ConditionalExpression(expr) => self
+                .nullability_impl(&expr.consequent, depth)
+                .union(self.nullability_impl(&expr.alternate, depth)),
+            Expression::SequenceExpression(expr) => expr
+                .expressions
+                .last()
+                .map_or(Nullability::Unknown, |expr| self.nullability_impl(expr, depth)),
+            Expression::AssignmentExpression(expr) => match expr.operator {
+                AssignmentOperator::Assign => self.nullability_impl(&expr.right, depth),
+                AssignmentOperator::LogicalAnd
+                | AssignmentOperator::LogicalOr
+                | AssignmentOperator::LogicalNullish => Nullability::Unknown,
+                _ => Nullability::NonNullish,
+            },
+            Expression::ParenthesizedExpression(expr) => {
+                self.nullability_impl(&expr.expression, depth)
+            }
+            Expression::TSAsExpression(expr) => self.nullability_impl(&expr.expression, depth),
+            Expression::TSSatisfiesExpression(expr) => {
+                self.nullability_impl(&expr.expression, depth)
+            }
+            Expression::TSTypeAssertion(expr) => self.nullability_impl(&expr.expression, depth),
+            _ => Nullability::Unknown,
+        }
+    }
+
+    fn identifier_nullability(&self, ident: &IdentifierReference<'a>, depth: u8) -> Nullability {
+        let Some(symbol_id) = ident
+            .reference_id()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id())
+        else {
+            return match ident.name.as_str() {
+                "undefined" => Nullability::Nullish,
+                "NaN" | "Infinity" => Nullability::NonNullish,
+                _ => Nullability::Unknown,
+            };
+        };
+        if depth >= MAX_DEPTH
+            || self.symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+        {
+            return Nullability::Unknown;
+        }
+        match self.symbol_declaration(symbol_id).kind() {
+            AstKind::VariableDeclarator(decl)
+                if matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_)) =>
+            {
+                decl.init
+                    .as_ref()
+                    .map_or(Nullability::Unknown, |init| self.nullability_impl(init, depth + 1))
+            }
+            AstKind::Function(_) | AstKind::Class(_) => Nullability::NonNullish,
+            _ => Nullability::Unknown,
+        }
+    }
+
+    /// Whether `node_id` is only evaluated when the test of an enclosing `if` statement,
+    /// conditional expression, logical expression or loop satisfies `implies`, which is called
+    /// with the test and whether the test is truthy or falsy for the node to be evaluated.
+    fn is_guarded_by(
+        &self,
+        node_id: AstNodeId,
+        implies: &dyn Fn(&Expression, bool) -> bool,
+    ) -> bool {
+        let mut child_span = self.nodes.get_node(node_id).kind().span();
+        for parent in self.nodes.iter_parents(node_id).skip(1) {
+            let guard = match parent.kind() {
+                AstKind::IfStatement(stmt) => {
+                    if stmt.consequent.span().contains_inclusive(child_span) {
+                        Some((&stmt.test, true))
+                    } else if stmt
+                        .alternate
+                        .as_ref()
+                        .is_some_and(|alternate| alternate.span().contains_inclusive(child_span))
+                    {
+                        Some((&stmt.test, false))
+                    } else {
+                        None
+                    }
+                }
+                AstKind::ConditionalExpression(expr) => {
+                    if expr.consequent.span().contains_inclusive(child_span) {
+                        Some((&expr.test, true))
+                    } else if expr.alternate.span().contains_inclusive(child_span) {
+                        Some((&expr.test, false))
+                    } else {
+                        None
+                    }
+                }
+                AstKind::LogicalExpression(expr)
+                    if expr.right.span().contains_inclusive(child_span) =>
+                {
+                    match expr.operator {
+                        LogicalOperator::And => Some((&expr.left, true)),
+                        LogicalOperator::Or => Some((&expr.left, false)),
+                        LogicalOperator::Coalesce => None,
+                    }
+                }
+                AstKind::WhileStatement(stmt)
+                    if stmt.body.span().contains_inclusive(child_span) =>
+                {
+                    Some((&stmt.test, true))
+                }
+                AstKind::ForStatement(stmt) if stmt.body.span().contains_inclusive(child_span) => {
+                    stmt.test.as_ref().map(|test| (test, true))
+                }
+                // Guards outside of a function do not apply when it is called
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => return false,
+                _ => None,
+            };
+            if let Some((test, truthy)) = guard {
+                if implies(test, truthy) {
+                    return true;
+                }
+            }
+            child_span = parent.kind().span();
+        }
+        false
+    }
+}
+
+/// Whether `test` evaluating to a truthy (or falsy) value implies that `name` is not nullish
+fn implies_non_nullish(test: &Expression, name: &str, truthy: bool) -> bool {
+    match test.without_parentheses() {
+        // `if (foo)`
+        Expression::Identifier(ident) => truthy && ident.name == name,
+        Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+            implies_non_nullish(&expr.argument, name, !truthy)
+        }
+        Expression::LogicalExpression(expr) => match (expr.operator, truthy) {
+            // Both operands are truthy, or both are falsy
+            (LogicalOperator::And, true) | (LogicalOperator::Or, false) => {
+                implies_non_nullish(&expr.left, name, truthy)
+                    || implies_non_nullish(&expr.right, name, truthy)
+            }
+            // Either operand is truthy, or either is falsy
+            (LogicalOperator::Or, true) | (LogicalOperator::And, false) => {
+                implies_non_nullish(&expr.left, name, truthy)
+                    && implies_non_nullish(&expr.right, name, truthy)
+            }
+            (LogicalOperator::Coalesce, _) => false,
+        },
+        Expression::BinaryExpression(expr) => {
+            // `foo instanceof Foo`
+            if expr.operator == BinaryOperator::Instanceof {
+                return truthy && is_identifier(&expr.left, name);
+            }
+            let other = if is_identifier(&expr.left, name) {
+                &expr.right
+            } else if is_identifier(&expr.right, name) {
+                &expr.left
+            } else if let Some(type_name) = typeof_comparison(&expr.left, &expr.right, name)
+                .or_else(|| typeof_comparison(&expr.right, &expr.left, name))
+            {
+                // `typeof foo === "function"`, `null` is an `"object"`
+                let is_equality = matches!(
+                    expr.operator,
+                    BinaryOperator::Equality | BinaryOperator::StrictEquality
+                );
+                let is_inequality = matches!(
+                    expr.operator,
+                    BinaryOperator::Inequality | BinaryOperator::StrictInequality
+                );
+                return ((is_equality && truthy) || (is_inequality && !truthy))
+                    && !matches!(type_name, "undefined" | "object");
+            } else {
+                return false;
+            };
+            // `foo != null` or `foo == undefined`
+            is_nullish_literal(other)
+                && match expr.operator {
+                    BinaryOperator::Inequality => truthy,
+                    BinaryOperator::Equality => !truthy,
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Whether `test` evaluating to a truthy (or falsy) value implies that the variable `name`
+/// is defined
+fn implies_defined(test: &Expression, name: &str, truthy: bool) -> bool {
+    match test.without_parentheses() {
+        Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+            implies_defined(&expr.argument, name, !truthy)
+        }
+        Expression::LogicalExpression(expr) => match (expr.operator, truthy) {
+            (LogicalOperator::And, true) | (LogicalOperator::Or, false) => {
+                implies_defined(&expr.left, name, truthy)
+                    || implies_defined(&expr.right, name, truthy)
+            }
+            (LogicalOperator::Or, true) | (LogicalOperator::And, false) => {
+                implies_defined(&expr.left, name, truthy)
+                    && implies_defined(&expr.right, name, truthy)
+            }
+            (LogicalOperator::Coalesce, _) => false,
+        },
+        // `typeof foo !== "undefined"` or `typeof foo === "function"`
+        Expression::BinaryExpression(expr) => {
+            let Some(type_name) = typeof_comparison(&expr.left, &expr.right, name)
+                .or_else(|| typeof_comparison(&expr.right, &expr.left, name))
+            else {
+                return false;
+            };
+            match expr.operator {
+                BinaryOperator::Equality | BinaryOperator::StrictEquality => {
+                    if type_name == "undefined" {
+                        !truthy
+                    } else {
+                        truthy
+                    }
+                }
+                BinaryOperator::Inequality | BinaryOperator::StrictInequality => {
+                    if type_name == "undefined" {
+                        truthy
+                    } else {
+                        !truthy
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// `null` or `undefined`
+fn is_nullish_literal(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::NullLiteral(_) => true,
+        Expression::Identifier(ident) => ident.name == "undefined",
+        _ => false,
+    }
+}
+
+fn is_identifier(expr: &Expression, name: &str) -> bool {
+    matches!(expr.without_parentheses(), Expression::Identifier(ident) if ident.name == name)
+}
+
+/// The type name `typeof name` is compared with, e.g. `"function"` for `typeof foo === "function"`
+fn typeof_comparison<'e>(
+    typeof_expr: &Expression,
+    type_name: &'e Expression,
+    name: &str,
+) -> Option<&'e str> {
+    let Expression::UnaryExpression(expr) = typeof_expr.without_parentheses() else {
+        return None;
+    };
+    if expr.operator != UnaryOperator::Typeof || !is_identifier(&expr.argument, name) {
+        return None;
+    }
+    match type_name.without_parentheses() {
+        Expression::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
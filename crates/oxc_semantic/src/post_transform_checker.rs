@@ -96,6 +96,7 @@ use oxc_allocator::{Allocator, CloneIn};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, visit::walk, Visit};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::Idx;
 use oxc_span::CompactStr;
 use oxc_syntax::{
     reference::ReferenceId,
@@ -167,6 +168,37 @@ pub fn check_semantic_ids(program: &Program<'_>) -> Option<Vec<OxcDiagnostic>> {
     errors.get()
 }
 
+/// Check `ScopeTree` and `SymbolTable` are internally consistent, and consistent with the AST.
+///
+/// Unlike [`check_semantic_after_transform`], this does not re-run semantic analysis, so is cheap
+/// enough to run after every transform pass. It checks that:
+///
+/// * Every `ScopeId`, `SymbolId` and `ReferenceId` in AST exists.
+/// * Parent of each scope in `ScopeTree` is the scope which encloses it in the AST.
+/// * Bindings, symbols and references all point back to each other.
+/// * `BindingIdentifier`s and `IdentifierReference`s have same name as the symbol they refer to.
+pub fn check_semantic_consistency(
+    symbols: &SymbolTable,
+    scopes: &ScopeTree,
+    program: &Program<'_>,
+) -> Option<Vec<OxcDiagnostic>> {
+    let mut errors = Errors::default();
+
+    let mut checker = ConsistencyChecker {
+        scoping: Scoping { symbols, scopes },
+        scope_stack: vec![],
+        errors: &mut errors,
+    };
+    if !program.source_type.is_typescript_definition() {
+        checker.visit_program(program);
+    }
+    checker.check_scopes();
+    checker.check_bindings();
+    checker.check_references();
+
+    errors.get()
+}
+
 struct PostTransformChecker<'s> {
     scoping_after_transform: Scoping<'s>,
     scoping_rebuilt: Scoping<'s>,
@@ -668,3 +700,184 @@ impl<'a, 'e> Visit<'a> for SemanticIdsCollector<'e> {
         /* noop */
     }
 }
+
+/// Checker for consistency of `ScopeTree` and `SymbolTable` with each other and with AST.
+///
+/// See [`check_semantic_consistency`].
+struct ConsistencyChecker<'s, 'e> {
+    scoping: Scoping<'s>,
+    /// Stack of scopes enclosing current AST node
+    scope_stack: Vec<ScopeId>,
+    errors: &'e mut Errors,
+}
+
+impl<'s, 'e> ConsistencyChecker<'s, 'e> {
+    fn check_scopes(&mut self) {
+        let scopes = self.scoping.scopes;
+        let mut parents_exist = true;
+        for scope_id in scopes.descendants_from_root() {
+            if let Some(parent_id) = scopes.get_parent_id(scope_id) {
+                if parent_id.index() >= scopes.len() {
+                    self.errors.push(format!(
+                        "Parent scope does not exist: {scope_id:?}: parent {parent_id:?}"
+                    ));
+                    parents_exist = false;
+                }
+            } else if scope_id != scopes.root_scope_id() {
+                self.errors.push(format!("Scope has no parent: {scope_id:?}"));
+            }
+        }
+
+        // Walking ancestors would panic if any parent does not exist
+        if !parents_exist {
+            return;
+        }
+        for scope_id in scopes.descendants_from_root() {
+            if scopes.ancestors(scope_id).skip(1).take(scopes.len()).any(|id| id == scope_id) {
+                self.errors.push(format!("Scope is its own ancestor: {scope_id:?}"));
+            }
+        }
+    }
+
+    fn check_bindings(&mut self) {
+        let Scoping { symbols, scopes } = self.scoping;
+        for (scope_id, symbol_id, name) in scopes.iter_bindings() {
+            if symbol_id.index() >= symbols.len() {
+                self.errors.push(format!(
+                    "Binding symbol does not exist: {scope_id:?}: {name} {symbol_id:?}"
+                ));
+                continue;
+            }
+            let symbol_scope_id = symbols.get_scope_id(symbol_id);
+            if symbol_scope_id != scope_id {
+                self.errors.push(format!(
+                    "Binding scope mismatch: {name} {symbol_id:?}: bound in {scope_id:?}, symbol in {symbol_scope_id:?}"
+                ));
+            }
+            let symbol_name = symbols.get_name(symbol_id);
+            if symbol_name != name.as_str() {
+                self.errors.push(format!(
+                    "Binding name mismatch: {scope_id:?} {symbol_id:?}: bound as {name}, symbol named {symbol_name}"
+                ));
+            }
+        }
+    }
+
+    fn check_references(&mut self) {
+        let symbols = self.scoping.symbols;
+        for symbol_id in symbols.iter() {
+            for &reference_id in symbols.get_resolved_reference_ids(symbol_id) {
+                if reference_id.index() >= symbols.references.len() {
+                    self.errors.push(format!(
+                        "Resolved reference does not exist: {symbol_id:?}: {reference_id:?}"
+                    ));
+                    continue;
+                }
+                let reference_symbol_id = symbols.get_reference(reference_id).symbol_id();
+                if reference_symbol_id != Some(symbol_id) {
+                    self.errors.push(format!(
+                        "Resolved reference mismatch: {symbol_id:?}: {reference_id:?} refers to {reference_symbol_id:?}"
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 's, 'e> Visit<'a> for ConsistencyChecker<'s, 'e> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scopes = self.scoping.scopes;
+        let parent_id = self.scope_stack.last().copied();
+        // Push something even if scope is invalid, so `leave_scope` pops the right entry
+        let scope_id = match scope_id.get() {
+            Some(scope_id) if scope_id.index() < scopes.len() => {
+                let found_parent_id = scopes.get_parent_id(scope_id);
+                if found_parent_id != parent_id {
+                    self.errors.push(format!(
+                        "Scope parent mismatch: {scope_id:?}: expected {parent_id:?}, found {found_parent_id:?}"
+                    ));
+                }
+                scope_id
+            }
+            Some(scope_id) => {
+                self.errors.push(format!("Scope does not exist: {scope_id:?}"));
+                parent_id.unwrap_or_else(|| scopes.root_scope_id())
+            }
+            None => {
+                self.errors.push("Missing ScopeId");
+                parent_id.unwrap_or_else(|| scopes.root_scope_id())
+            }
+        };
+        self.scope_stack.push(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbols = self.scoping.symbols;
+        let Some(reference_id) = ident.reference_id.get() else {
+            self.errors.push(format!("Missing ReferenceId: {}", ident.name));
+            return;
+        };
+        if reference_id.index() >= symbols.references.len() {
+            self.errors.push(format!("Reference does not exist: {} {reference_id:?}", ident.name));
+            return;
+        }
+        // References removed from the AST may keep their symbol, so they are only checked here
+        let Some(symbol_id) = symbols.get_reference(reference_id).symbol_id() else { return };
+        if symbol_id.index() >= symbols.len() {
+            self.errors
+                .push(format!("Reference symbol does not exist: {reference_id:?}: {symbol_id:?}"));
+            return;
+        }
+        if !symbols.get_resolved_reference_ids(symbol_id).contains(&reference_id) {
+            self.errors.push(format!(
+                "Reference missing from symbol's resolved references: {reference_id:?}: {symbol_id:?}"
+            ));
+        }
+        if symbols.get_name(symbol_id) != ident.name.as_str() {
+            self.errors.push(format!(
+                "Reference name mismatch: {} {reference_id:?}: refers to {} {symbol_id:?}",
+                ident.name,
+                symbols.get_name(symbol_id)
+            ));
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbols = self.scoping.symbols;
+        let Some(symbol_id) = ident.symbol_id.get() else {
+            self.errors.push(format!("Missing SymbolId: {}", ident.name));
+            return;
+        };
+        if symbol_id.index() >= symbols.len() {
+            self.errors.push(format!("Symbol does not exist: {} {symbol_id:?}", ident.name));
+        } else if symbols.get_name(symbol_id) != ident.name.as_str() {
+            self.errors.push(format!(
+                "Symbol name mismatch: {} {symbol_id:?}: symbol named {}",
+                ident.name,
+                symbols.get_name(symbol_id)
+            ));
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.is_ts_declare_function() {
+            return;
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_declaration(&mut self, it: &Declaration<'a>) {
+        if it.is_typescript_syntax() {
+            return;
+        }
+        walk::walk_declaration(self, it);
+    }
+
+    fn visit_ts_type(&mut self, _it: &TSType<'a>) {
+        /* noop */
+    }
+}
@@ -0,0 +1,222 @@
+//! Detection of immediately invoked functions and of functions without side effects.
+//!
+//! Shared by passes which inline or remove calls, and by lint rules which have to know how a
+//! function is called. Syntax-only patterns such as [`CallExpression::iife_function`] and
+//! [`CallExpression::object_define_property`] live in `oxc_ast`.
+//!
+//! [`CallExpression::iife_function`]: oxc_ast::ast::CallExpression::iife_function
+//! [`CallExpression::object_define_property`]: oxc_ast::ast::CallExpression::object_define_property
+
+use oxc_ast::{
+    ast::{
+        ArrayExpressionElement, AssignmentOperator, BinaryOperator, BindingPatternKind, Expression,
+        FormalParameters, FunctionBody, IdentifierReference, ObjectPropertyKind, PropertyKey,
+        SimpleAssignmentTarget, Statement, UnaryOperator, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_span::GetSpan;
+use oxc_syntax::scope::ScopeId;
+
+use crate::{AstNodeId, Semantic};
+
+impl<'a> Semantic<'a> {
+    /// Whether the function or arrow function `node_id` is called right where it is defined,
+    /// e.g. `(function () {})()`, `!function () {}()` or `(() => {}).call(this)`.
+    pub fn is_iife(&self, node_id: AstNodeId) -> bool {
+        let span = self.nodes.kind(node_id).span();
+        self.nodes
+            .iter_parents(node_id)
+            .skip(1)
+            .find(|parent| {
+                !matches!(
+                    parent.kind(),
+                    AstKind::ParenthesizedExpression(_) | AstKind::MemberExpression(_)
+                )
+            })
+            .is_some_and(|parent| match parent.kind() {
+                AstKind::CallExpression(call) => {
+                    call.iife_function().is_some_and(|function| function.span() == span)
+                }
+                _ => false,
+            })
+    }
+
+    /// Whether calling the function or arrow function `node_id` is known to have no side
+    /// effects, other than possibly throwing a `ReferenceError` for variables in their temporal
+    /// dead zone.
+    ///
+    /// The function must not be async or a generator, its parameters must be identifiers with
+    /// pure default values, and its body may only declare and assign its own variables, and
+    /// branch on and return pure expressions. Operators which may convert objects to
+    /// primitives, e.g. `a + b`, are not pure.
+    pub fn is_pure_function(&self, node_id: AstNodeId) -> bool {
+        match self.nodes.kind(node_id) {
+            AstKind::Function(func) => {
+                !func.r#async
+                    && !func.generator
+                    && func.body.as_ref().is_some_and(|body| {
+                        func.scope_id.get().is_some_and(|scope_id| {
+                            self.is_pure_callable(scope_id, &func.params, body)
+                        })
+                    })
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                !arrow.r#async
+                    && arrow.scope_id.get().is_some_and(|scope_id| {
+                        self.is_pure_callable(scope_id, &arrow.params, &arrow.body)
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    fn is_pure_callable(
+        &self,
+        scope_id: ScopeId,
+        params: &FormalParameters<'a>,
+        body: &FunctionBody<'a>,
+    ) -> bool {
+        let params_are_pure = params.items.iter().all(|param| match &param.pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => true,
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                matches!(pattern.left.kind, BindingPatternKind::BindingIdentifier(_))
+                    && self.is_pure_expression(scope_id, &pattern.right)
+            }
+            _ => false,
+        }) && params.rest.as_ref().map_or(true, |rest| {
+            matches!(rest.argument.kind, BindingPatternKind::BindingIdentifier(_))
+        });
+        params_are_pure && body.statements.iter().all(|stmt| self.is_pure_statement(scope_id, stmt))
+    }
+
+    fn is_pure_statement(&self, scope_id: ScopeId, stmt: &Statement<'a>) -> bool {
+        match stmt {
+            // Hoisted, declaring a function has no side effects.
+            Statement::EmptyStatement(_) | Statement::FunctionDeclaration(_) => true,
+            Statement::BlockStatement(block) => {
+                block.body.iter().all(|stmt| self.is_pure_statement(scope_id, stmt))
+            }
+            Statement::ExpressionStatement(stmt) => {
+                self.is_pure_expression(scope_id, &stmt.expression)
+            }
+            Statement::ReturnStatement(stmt) => stmt
+                .argument
+                .as_ref()
+                .map_or(true, |argument| self.is_pure_expression(scope_id, argument)),
+            Statement::IfStatement(stmt) => {
+                self.is_pure_expression(scope_id, &stmt.test)
+                    && self.is_pure_statement(scope_id, &stmt.consequent)
+                    && stmt
+                        .alternate
+                        .as_ref()
+                        .map_or(true, |alternate| self.is_pure_statement(scope_id, alternate))
+            }
+            // `using` declarations dispose of their values at the end of the block.
+            Statement::VariableDeclaration(decl) => {
+                matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Var
+                        | VariableDeclarationKind::Let
+                        | VariableDeclarationKind::Const
+                ) && decl.declarations.iter().all(|declarator| {
+                    matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+                        && declarator
+                            .init
+                            .as_ref()
+                            .map_or(true, |init| self.is_pure_expression(scope_id, init))
+                })
+            }
+            _ => false,
+        }
+    }
+
+    fn is_pure_expression(&self, scope_id: ScopeId, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => true,
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            Expression::Identifier(ident) => {
+                self.is_resolved(ident)
+                    || matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+            }
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().all(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_) => false,
+                    ArrayExpressionElement::Elision(_) => true,
+                    element => self.is_pure_expression(scope_id, element.to_expression()),
+                })
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().all(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        !property.computed
+                            && !matches!(property.key, PropertyKey::PrivateIdentifier(_))
+                            && self.is_pure_expression(scope_id, &property.value)
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => false,
+                })
+            }
+            Expression::UnaryExpression(unary) => {
+                matches!(
+                    unary.operator,
+                    UnaryOperator::LogicalNot | UnaryOperator::Typeof | UnaryOperator::Void
+                ) && self.is_pure_expression(scope_id, &unary.argument)
+            }
+            Expression::BinaryExpression(binary) => {
+                matches!(
+                    binary.operator,
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality
+                ) && self.is_pure_expression(scope_id, &binary.left)
+                    && self.is_pure_expression(scope_id, &binary.right)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_pure_expression(scope_id, &logical.left)
+                    && self.is_pure_expression(scope_id, &logical.right)
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.is_pure_expression(scope_id, &conditional.test)
+                    && self.is_pure_expression(scope_id, &conditional.consequent)
+                    && self.is_pure_expression(scope_id, &conditional.alternate)
+            }
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().all(|expr| self.is_pure_expression(scope_id, expr))
+            }
+            Expression::AssignmentExpression(assignment) => {
+                assignment.operator == AssignmentOperator::Assign
+                    && matches!(
+                        assignment.left.as_simple_assignment_target(),
+                        Some(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident))
+                            if self.is_declared_in(ident, scope_id)
+                    )
+                    && self.is_pure_expression(scope_id, &assignment.right)
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.is_pure_expression(scope_id, &expr.expression)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_resolved(&self, ident: &IdentifierReference<'a>) -> bool {
+        ident.reference_id().is_some_and(|reference_id| {
+            self.symbols.get_reference(reference_id).symbol_id().is_some()
+        })
+    }
+
+    /// Whether `ident` refers to a variable declared in `scope_id` or one of its descendants.
+    fn is_declared_in(&self, ident: &IdentifierReference<'a>, scope_id: ScopeId) -> bool {
+        ident
+            .reference_id()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id())
+            .is_some_and(|symbol_id| {
+                self.scopes.ancestors(self.symbols.get_scope_id(symbol_id)).any(|id| id == scope_id)
+            })
+    }
+}
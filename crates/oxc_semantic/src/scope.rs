@@ -172,6 +172,16 @@ impl ScopeTree {
         }
     }
 
+    /// Move a scope to a new parent, removing it from the child scopes of its old parent.
+    pub fn change_parent_id(&mut self, scope_id: ScopeId, new_parent_id: Option<ScopeId>) {
+        if self.build_child_ids {
+            if let Some(old_parent_id) = self.parent_ids[scope_id] {
+                self.child_ids[old_parent_id].retain(|&child_id| child_id != scope_id);
+            }
+        }
+        self.set_parent_id(scope_id, new_parent_id);
+    }
+
     /// Get a variable binding by name that was declared in the top-level scope
     #[inline]
     pub fn get_root_binding(&self, name: &str) -> Option<SymbolId> {
@@ -0,0 +1,32 @@
+use oxc_span::Span;
+
+use crate::util::SemanticTester;
+
+#[test]
+fn test_debug_dump() {
+    let tester =
+        SemanticTester::js("let a = 1; function f(b) { return a + b + c; }").with_cfg(true);
+    let dump = tester.build().debug_dump();
+
+    assert_eq!(dump.scopes.len(), 2);
+    assert_eq!(dump.scopes[0].parent_id, None);
+    assert_eq!(dump.scopes[1].parent_id, Some(dump.scopes[0].id));
+    assert_eq!(dump.scopes[1].span, Span::new(11, 46));
+
+    let names = dump.symbols.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["a", "f", "b"]);
+    let a = &dump.symbols[0];
+    assert_eq!(a.span, Span::new(4, 5));
+    assert_eq!(a.references.len(), 1);
+
+    let reference = &dump.references[a.references[0]];
+    assert_eq!(reference.name, "a");
+    assert_eq!(reference.span, Span::new(34, 35));
+    assert_eq!(reference.symbol_id, Some(a.id));
+
+    let c = dump.references.iter().find(|reference| reference.name == "c").unwrap();
+    assert_eq!(c.symbol_id, None);
+
+    assert!(!dump.basic_blocks.is_empty());
+    assert!(dump.basic_blocks.iter().any(|block| !block.successors.is_empty()));
+}
@@ -0,0 +1,72 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNodeId, Semantic};
+
+use crate::util::SemanticTester;
+
+/// Names of the labels jumped to by `break` and `continue` statements, in source order
+fn jump_targets<'a>(semantic: &Semantic<'a>) -> Vec<Option<&'a str>> {
+    semantic
+        .nodes()
+        .iter()
+        .filter(|node| {
+            matches!(node.kind(), AstKind::BreakStatement(_) | AstKind::ContinueStatement(_))
+        })
+        .map(|node| semantic.labels().get_target(node.id()).map(|id| label_name(semantic, id)))
+        .collect()
+}
+
+fn label_name<'a>(semantic: &Semantic<'a>, id: AstNodeId) -> &'a str {
+    let AstKind::LabeledStatement(stmt) = semantic.nodes().kind(id) else {
+        panic!("label target should be a LabeledStatement");
+    };
+    stmt.label.name.as_str()
+}
+
+#[test]
+fn test_label_targets() {
+    let tester = SemanticTester::js(
+        "
+        A: while (a) {
+            B: for (;;) {
+                break A;
+                continue B;
+                break;
+            }
+            A: { break A; }
+            function foo() { break A; }
+        }
+        ",
+    )
+    .expect_errors(true);
+    let semantic = tester.build_with_errors().semantic;
+    assert_eq!(jump_targets(&semantic), [Some("A"), Some("B"), None, Some("A"), None]);
+
+    // The inner `A` shadows the outer one
+    let labels = semantic
+        .nodes()
+        .iter()
+        .filter(|node| matches!(node.kind(), AstKind::LabeledStatement(_)))
+        .map(|node| semantic.labels().get_jumps(node.id()).len())
+        .collect::<Vec<_>>();
+    assert_eq!(labels, [1, 1, 1]);
+}
+
+#[test]
+fn test_unused_labels() {
+    let tester = SemanticTester::js("A: { B: { break B; } C: while (c) { D: break; } }");
+    let semantic = tester.build();
+    let unused = semantic
+        .labels()
+        .unused_labels()
+        .iter()
+        .map(|id| label_name(&semantic, *id))
+        .collect::<Vec<_>>();
+    assert_eq!(unused, ["D", "C", "A"]);
+}
+
+#[test]
+fn test_label_errors() {
+    SemanticTester::js("A: { function foo() { break A; } }").has_error("Jump target cannot cross");
+    SemanticTester::js("break A;").has_error("Use of undefined label");
+    SemanticTester::js("A: { continue A; }").has_error("can only jump to a label");
+}
@@ -2,7 +2,12 @@
 
 pub mod cfg;
 pub mod classes;
+pub mod dump;
+pub mod labels;
 pub mod modules;
+pub mod nullability;
+pub mod post_transform_checker;
+pub mod purity;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
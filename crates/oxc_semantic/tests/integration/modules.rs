@@ -1,4 +1,5 @@
 use oxc_semantic::{SemanticBuilderReturn, SymbolFlags};
+use oxc_span::CompactStr;
 
 use crate::util::SemanticTester;
 
@@ -250,3 +251,39 @@ fn test_import_assignment() {
         .contains_flags(SymbolFlags::Import)
         .test();
 }
+
+#[test]
+fn test_unused_imports() {
+    let test = SemanticTester::js(
+        "
+    import a, { b, c as d } from 'foo';
+    import * as ns from 'bar';
+    import 'side-effect';
+    a();
+    export { d };
+    ",
+    );
+    let semantic = test.build();
+    let unused =
+        semantic.unused_imports().map(|entry| entry.local_name.name().as_str()).collect::<Vec<_>>();
+    assert_eq!(unused, ["b", "ns"]);
+}
+
+#[test]
+fn test_unresolved_exports() {
+    let test = SemanticTester::js(
+        "
+    const foo = 1;
+    export { foo, bar };
+    export { baz } from 'baz';
+    export default qux;
+    ",
+    )
+    .expect_errors(true);
+    let SemanticBuilderReturn { semantic, .. } = test.build_with_errors();
+    let unresolved = semantic
+        .unresolved_exports()
+        .filter_map(|entry| entry.local_name.name().map(CompactStr::as_str))
+        .collect::<Vec<_>>();
+    assert_eq!(unresolved, ["bar"]);
+}
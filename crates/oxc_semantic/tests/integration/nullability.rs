@@ -0,0 +1,158 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Nullability;
+
+use crate::util::SemanticTester;
+
+/// Nullability of the initializer of the last `result` variable in `source`
+fn nullability(source: &'static str) -> Nullability {
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let init = semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::VariableDeclarator(decl)
+                if decl.id.get_identifier().is_some_and(|name| name == "result") =>
+            {
+                decl.init.as_ref()
+            }
+            _ => None,
+        })
+        .last()
+        .expect("`result` should be declared");
+    semantic.nullability(init)
+}
+
+/// Whether the last reference to `foo` in `source` is known to be non-nullish
+fn is_non_nullish(source: &'static str) -> bool {
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let (node, ident) = semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::IdentifierReference(ident) if ident.name == "foo" => Some((node, ident)),
+            _ => None,
+        })
+        .last()
+        .expect("`foo` should be referenced");
+    semantic.is_non_nullish_at(node.id(), ident)
+}
+
+/// Whether the last reference to `foo` in `source` is known to be defined
+fn is_defined(source: &'static str) -> bool {
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let node = semantic
+        .nodes()
+        .iter()
+        .filter(|node| matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "foo"))
+        .last()
+        .expect("`foo` should be referenced");
+    semantic.is_defined_at(node.id(), "foo")
+}
+
+#[test]
+fn test_expression_nullability() {
+    for source in [
+        "const result = {};",
+        "const result = 'foo';",
+        "const result = `foo${bar}`;",
+        "const result = a + b;",
+        "const result = typeof a;",
+        "const result = new Foo();",
+        "const result = () => {};",
+        "const result = a ?? {};",
+        "const result = a || [];",
+        "const result = a ? 1 : 2;",
+        "const result = (a, 1);",
+        "const foo = {}; const result = foo;",
+        "const foo = {}; const bar = foo; const result = bar;",
+        "function foo() {} const result = foo;",
+    ] {
+        assert_eq!(nullability(source), Nullability::NonNullish, "{source}");
+    }
+
+    for source in [
+        "const result = null;",
+        "const result = undefined;",
+        "const result = void 0;",
+        "const result = null ?? undefined;",
+        "const result = null && a;",
+        "let foo; const result = foo = null;",
+    ] {
+        assert_eq!(nullability(source), Nullability::Nullish, "{source}");
+    }
+
+    for source in [
+        "const result = a;",
+        "const result = a.b;",
+        "const result = a();",
+        "const result = a?.b;",
+        "const result = a && {};",
+        "const result = a ? 1 : null;",
+        "let foo = {}; foo = null; const result = foo;",
+        "let foo; const result = foo;",
+        "function f(foo) { const result = foo; }",
+    ] {
+        assert_eq!(nullability(source), Nullability::Unknown, "{source}");
+    }
+}
+
+#[test]
+fn test_guards() {
+    for source in [
+        "if (foo) { foo.bar; }",
+        "if (foo != null) { foo.bar; }",
+        "if (null != foo) { foo.bar; }",
+        "if (foo == undefined) {} else { foo.bar; }",
+        "if (!foo) {} else { foo.bar; }",
+        "if (a && foo) { foo.bar; }",
+        "if (!foo || a) {} else { foo.bar; }",
+        "foo && foo.bar;",
+        "!foo || foo.bar;",
+        "foo ? foo.bar : null;",
+        "typeof foo === 'function' && foo();",
+        "foo instanceof Foo && foo.bar;",
+        "while (foo) { foo.bar; }",
+        "const foo = {}; foo.bar;",
+    ] {
+        assert!(is_non_nullish(source), "{source}");
+    }
+
+    for source in [
+        "foo.bar;",
+        "if (foo) {} foo.bar;",
+        "if (foo) {} else { foo.bar; }",
+        "if (foo !== null) { foo.bar; }",
+        "if (a || foo) { foo.bar; }",
+        "typeof foo === 'object' && foo.bar;",
+        "typeof foo !== 'undefined' && foo.bar;",
+        "foo ?? foo.bar;",
+        "if (foo) { () => foo.bar; }",
+    ] {
+        assert!(!is_non_nullish(source), "{source}");
+    }
+}
+
+#[test]
+fn test_defined_guards() {
+    for source in [
+        "if (typeof foo !== 'undefined') { foo(); }",
+        "if (typeof foo === 'undefined') {} else { foo(); }",
+        "typeof foo === 'function' && foo();",
+        "'undefined' != typeof foo && foo();",
+        "typeof foo === 'undefined' || foo();",
+    ] {
+        assert!(is_defined(source), "{source}");
+    }
+
+    for source in [
+        "foo();",
+        "if (typeof foo === 'undefined') { foo(); }",
+        "if (typeof foo !== 'undefined') {} foo();",
+        "if (typeof bar !== 'undefined') { foo(); }",
+    ] {
+        assert!(!is_defined(source), "{source}");
+    }
+}
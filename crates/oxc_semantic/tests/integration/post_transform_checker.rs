@@ -0,0 +1,56 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{post_transform_checker::check_semantic_consistency, SemanticBuilder};
+use oxc_span::SourceType;
+
+#[test]
+fn test_check_semantic_consistency() {
+    let allocator = Allocator::default();
+    let source_text = "let a = 1; function f(b) { { let c = a + b; } }";
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let (mut symbols, mut scopes) = SemanticBuilder::new(source_text)
+        .build(&program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+
+    assert!(check_semantic_consistency(&symbols, &scopes, &program).is_none());
+
+    // Move block scope up to root
+    let block_scope_id = scopes.descendants_from_root().last().unwrap();
+    scopes.set_parent_id(block_scope_id, Some(scopes.root_scope_id()));
+    // Rename `a` without renaming its binding or identifiers
+    let a_symbol_id = scopes.get_root_binding("a").unwrap();
+    symbols.set_name(a_symbol_id, "x".into());
+
+    let errors = check_semantic_consistency(&symbols, &scopes, &program).unwrap();
+    let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert!(messages.iter().any(|message| message.starts_with("Scope parent mismatch")));
+    assert!(messages.iter().any(|message| message.starts_with("Binding name mismatch")));
+    assert!(messages.iter().any(|message| message.starts_with("Symbol name mismatch: a")));
+    assert!(messages.iter().any(|message| message.starts_with("Reference name mismatch: a")));
+}
+
+#[test]
+fn test_check_semantic_consistency_deleted_references() {
+    let allocator = Allocator::default();
+    let source_text = "let a = 1; a; a;";
+    let mut program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let (mut symbols, scopes) = SemanticBuilder::new(source_text)
+        .build(&program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+
+    // Remove the first `a;` from both AST and symbols, the second only from symbols
+    let a_symbol_id = scopes.get_root_binding("a").unwrap();
+    let reference_ids = symbols.get_resolved_reference_ids(a_symbol_id).clone();
+    program.body.remove(1);
+    symbols.delete_resolved_reference(a_symbol_id, reference_ids[0]);
+    assert!(check_semantic_consistency(&symbols, &scopes, &program).is_none());
+
+    symbols.delete_resolved_reference(a_symbol_id, reference_ids[1]);
+    let errors = check_semantic_consistency(&symbols, &scopes, &program).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .to_string()
+        .starts_with("Reference missing from symbol's resolved references"));
+}
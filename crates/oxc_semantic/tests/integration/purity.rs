@@ -0,0 +1,89 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNodeId, Semantic};
+
+use crate::util::SemanticTester;
+
+/// Run `check` on the first function or arrow function in `source`
+fn check_function(source: &'static str, check: fn(&Semantic, AstNodeId) -> bool) -> bool {
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let node = semantic
+        .nodes()
+        .iter()
+        .find(|node| {
+            matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .expect("a function should be declared");
+    check(&semantic, node.id())
+}
+
+// `Semantic` methods are not generic over its lifetime, they can not be passed as `fn` pointers
+#[allow(clippy::redundant_closure_for_method_calls)]
+fn is_iife(source: &'static str) -> bool {
+    check_function(source, |semantic, node_id| semantic.is_iife(node_id))
+}
+
+// `Semantic` methods are not generic over its lifetime, they can not be passed as `fn` pointers
+#[allow(clippy::redundant_closure_for_method_calls)]
+fn is_pure_function(source: &'static str) -> bool {
+    check_function(source, |semantic, node_id| semantic.is_pure_function(node_id))
+}
+
+#[test]
+fn test_iife() {
+    for source in [
+        "(function () {})();",
+        "(function () {}());",
+        "!function () {}();",
+        "(() => {})();",
+        "((() => {}))();",
+        "(function () {}).call(this);",
+        "(() => {}).apply(null, []);",
+    ] {
+        assert!(is_iife(source), "{source}");
+    }
+
+    for source in [
+        "function foo() {} foo();",
+        "foo(function () {});",
+        "foo(() => {})();",
+        "(function () {}).bind(this)();",
+        "const foo = () => {};",
+    ] {
+        assert!(!is_iife(source), "{source}");
+    }
+}
+
+#[test]
+fn test_pure_function() {
+    for source in [
+        "function foo() {}",
+        "function foo(a, b = 1, ...c) { return a; }",
+        "const foo = () => 1;",
+        "const foo = (a) => a === 1 ? [a] : { a };",
+        "function foo(a) { let b = a; if (!b) { b = null; } return typeof b; }",
+        "function foo() { return () => bar(); }",
+        "const bar = 1; function foo() { return bar; }",
+        "function foo() { return undefined; }",
+    ] {
+        assert!(is_pure_function(source), "{source}");
+    }
+
+    for source in [
+        "function foo() { bar(); }",
+        "function foo(a) { return a + 1; }",
+        "function foo(a) { return a.b; }",
+        "function foo({ a }) {}",
+        "function foo(a = bar()) {}",
+        "function foo() { return bar; }",
+        "let bar; function foo() { bar = 1; }",
+        "async function foo() {}",
+        "function* foo() {}",
+        "function foo() { throw 1; }",
+        "function foo() { return [...a]; }",
+        "function foo() { return `${a}`; }",
+        "function foo(a) { delete a.b; }",
+    ] {
+        assert!(!is_pure_function(source), "{source}");
+    }
+}
@@ -207,6 +207,36 @@ fn test_enums() {
     assert_eq!(enum_decl.members.len(), 3);
 }
 
+#[test]
+fn test_scope_flags() {
+    let tester = SemanticTester::ts(
+        "
+        class A {
+            static {
+                let block = 0;
+            }
+        }
+        enum B {
+            X,
+        }
+        namespace C {
+            let module = 0;
+        }
+        ",
+    );
+    tester
+        .has_some_symbol("block")
+        .is_in_scope(ScopeFlags::ClassStaticBlock)
+        .is_not_in_scope(ScopeFlags::Function)
+        .test();
+    tester.has_some_symbol("X").is_in_scope(ScopeFlags::TsEnum).test();
+    tester
+        .has_some_symbol("module")
+        .is_in_scope(ScopeFlags::TsModuleBlock)
+        .is_not_in_scope(ScopeFlags::TsEnum)
+        .test();
+}
+
 #[test]
 fn var_hoisting() {
     SemanticTester::js(
@@ -239,3 +269,72 @@ fn get_child_ids() {
     let child_scope_ids = scopes.get_child_ids(child_scope_ids[0]);
     assert!(child_scope_ids.is_empty());
 }
+
+#[test]
+fn test_direct_eval() {
+    let tester = SemanticTester::js(
+        "
+        function foo() {
+            let x;
+            { eval('x') }
+        }
+        function bar() {
+            let y;
+            eval?.('y');
+        }
+        ",
+    );
+
+    tester.has_some_symbol("x").is_in_scope(ScopeFlags::Function | ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("y").is_not_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_root_symbol("foo").is_in_scope(ScopeFlags::Top | ScopeFlags::DirectEval).test();
+}
+
+#[test]
+fn test_with() {
+    let tester = SemanticTester::js(
+        "
+        function foo() {
+            let x;
+            with (obj) { x }
+        }
+        function bar() {
+            let y;
+        }
+        ",
+    )
+    .with_module(false);
+
+    tester.has_some_symbol("x").is_in_scope(ScopeFlags::Function | ScopeFlags::With).test();
+    tester.has_some_symbol("y").is_not_in_scope(ScopeFlags::With).test();
+    tester.has_root_symbol("foo").is_in_scope(ScopeFlags::Top | ScopeFlags::With).test();
+}
+
+#[test]
+fn test_arguments() {
+    let tester = SemanticTester::js(
+        "
+        function foo(a) { return () => arguments[0] }
+        function bar(b) { 'use strict'; return arguments[0] }
+        function baz(c = 1) { return arguments[0] }
+        function qux(d) { return function () { return arguments[0] } }
+        function quux(arguments) { return arguments[0] }
+        ",
+    )
+    .with_module(false);
+
+    tester
+        .has_some_symbol("a")
+        .is_in_scope(ScopeFlags::Function | ScopeFlags::Arguments | ScopeFlags::MappedArguments)
+        .test();
+    for name in ["b", "c"] {
+        tester
+            .has_some_symbol(name)
+            .is_in_scope(ScopeFlags::Function | ScopeFlags::Arguments)
+            .is_not_in_scope(ScopeFlags::MappedArguments)
+            .test();
+    }
+    for name in ["d", "arguments"] {
+        tester.has_some_symbol(name).is_not_in_scope(ScopeFlags::Arguments).test();
+    }
+}
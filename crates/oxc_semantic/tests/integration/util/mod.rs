@@ -1,7 +1,7 @@
 mod class_tester;
 mod expect;
 mod symbol_tester;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 pub use class_tester::ClassTester;
 pub use expect::Expect;
@@ -172,6 +172,7 @@ impl<'a> SemanticTester<'a> {
             .with_trivias(parse.trivias)
             .with_cfg(self.cfg)
             .with_scope_tree_child_ids(self.scope_tree_child_ids)
+            .build_module_record(Path::new(""), program)
             .build(program)
     }
 
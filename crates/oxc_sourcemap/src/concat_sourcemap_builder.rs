@@ -12,6 +12,7 @@ pub struct ConcatSourceMapBuilder {
     /// The `token_chunks` is used for encode tokens to vlq mappings at parallel.
     pub(crate) token_chunks: Vec<TokenChunk>,
     pub(crate) token_chunk_prev_name_id: u32,
+    pub(crate) x_google_ignore_list: Option<Vec<u32>>,
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -35,6 +36,7 @@ impl ConcatSourceMapBuilder {
             tokens: Vec::with_capacity(tokens_len),
             token_chunks: Vec::with_capacity(token_chunks_len),
             token_chunk_prev_name_id: 0,
+            x_google_ignore_list: None,
         }
     }
 
@@ -117,6 +119,13 @@ impl ConcatSourceMapBuilder {
             self.source_contents.extend((0..sourcemap.sources.len()).map(|_| "".into()));
         }
 
+        // Extend `x_google_ignoreList`, offsetting source indices.
+        if let Some(x_google_ignore_list) = &sourcemap.x_google_ignore_list {
+            self.x_google_ignore_list
+                .get_or_insert_with(Vec::new)
+                .extend(x_google_ignore_list.iter().map(|id| id + source_offset));
+        }
+
         // Extend `names`.
         self.names.reserve(sourcemap.names.len());
         self.names.extend(sourcemap.get_names().map(Into::into));
@@ -140,7 +149,7 @@ impl ConcatSourceMapBuilder {
     }
 
     pub fn into_sourcemap(self) -> SourceMap {
-        SourceMap::new(
+        let mut sourcemap = SourceMap::new(
             None,
             self.names,
             None,
@@ -148,7 +157,11 @@ impl ConcatSourceMapBuilder {
             Some(self.source_contents),
             self.tokens,
             Some(self.token_chunks),
-        )
+        );
+        if let Some(x_google_ignore_list) = self.x_google_ignore_list {
+            sourcemap.set_x_google_ignore_list(x_google_ignore_list);
+        }
+        sourcemap
     }
 }
 
@@ -191,7 +204,7 @@ where
         vec![Token::new(1, 1, 1, 1, Some(0), Some(0))],
         None,
     );
-    let sm3 = SourceMap::new(
+    let mut sm3 = SourceMap::new(
         None,
         vec!["abc".into()],
         None,
@@ -200,6 +213,7 @@ where
         vec![Token::new(1, 2, 2, 2, Some(0), Some(0))],
         None,
     );
+    sm3.set_source_ignored(0);
 
     let builder = create_builder(&[(&sm1, 0), (&sm2, 2), (&sm3, 2)]);
 
@@ -221,6 +235,7 @@ where
     assert_eq!(concat_sm.tokens, sm.tokens);
     assert_eq!(concat_sm.sources, sm.sources);
     assert_eq!(concat_sm.names, sm.names);
+    assert_eq!(concat_sm.get_x_google_ignore_list(), Some(&[2][..]));
     assert_eq!(
         concat_sm.token_chunks,
         Some(vec![
@@ -18,6 +18,14 @@ pub struct JSONSourceMap {
     pub sources_content: Option<Vec<Option<String>>>,
     // A list of symbol names used by the “mappings” entry.
     pub names: Option<Vec<String>>,
+    // An optional list of indices into “sources” identifying third-party sources that should be ignore-listed.
+    pub ignore_list: Option<Vec<u32>>,
+    // The pre-standard name of “ignoreList”, still emitted and read by Chrome DevTools.
+    #[serde(rename = "x_google_ignoreList")]
+    pub x_google_ignore_list: Option<Vec<u32>>,
+    // An optional unique identifier linking the generated file and its sourcemap, see <https://github.com/tc39/source-map/blob/main/proposals/debug-id.md>.
+    #[serde(alias = "debug_id")]
+    pub debug_id: Option<String>,
 }
 
 pub fn decode(json: JSONSourceMap) -> Result<SourceMap> {
@@ -33,7 +41,16 @@ pub fn decode(json: JSONSourceMap) -> Result<SourceMap> {
         .sources_content
         .map(|v| v.into_iter().map(Option::unwrap_or_default).map(Into::into).collect::<Vec<_>>());
     let tokens = decode_mapping(&json.mappings.unwrap_or_default(), names.len(), sources.len())?;
-    Ok(SourceMap::new(file, names, source_root, sources, source_contents, tokens, None))
+    let mut sourcemap =
+        SourceMap::new(file, names, source_root, sources, source_contents, tokens, None);
+    // Prefer the standard `ignoreList`, fallback to `x_google_ignoreList`.
+    if let Some(ignore_list) = json.ignore_list.or(json.x_google_ignore_list) {
+        sourcemap.set_x_google_ignore_list(ignore_list);
+    }
+    if let Some(debug_id) = json.debug_id {
+        sourcemap.set_debug_id(&debug_id);
+    }
+    Ok(sourcemap)
 }
 
 pub fn decode_from_string(value: &str) -> Result<SourceMap> {
@@ -167,3 +184,30 @@ fn test_decode_sourcemap_optional_filed() {
     }"#;
     SourceMap::from_json_string(input).expect("should success");
 }
+
+#[test]
+fn test_decode_sourcemap_ignore_list_and_debug_id() {
+    let input = r#"{
+        "version": 3,
+        "sources": ["a.js", "node_modules/b.js"],
+        "x_google_ignoreList": [1],
+        "debugId": "85314830-023f-4cf1-a267-535f4e37bb17",
+        "mappings": ""
+    }"#;
+    let sm = SourceMap::from_json_string(input).unwrap();
+    assert_eq!(sm.get_x_google_ignore_list(), Some(&[1][..]));
+    assert!(!sm.is_source_ignored(0));
+    assert!(sm.is_source_ignored(1));
+    assert_eq!(sm.get_debug_id(), Some("85314830-023f-4cf1-a267-535f4e37bb17"));
+
+    // The standard `ignoreList` takes precedence.
+    let input = r#"{
+        "sources": ["a.js", "b.js"],
+        "ignoreList": [0],
+        "x_google_ignoreList": [1],
+        "debug_id": "85314830-023f-4cf1-a267-535f4e37bb17"
+    }"#;
+    let sm = SourceMap::from_json_string(input).unwrap();
+    assert_eq!(sm.get_x_google_ignore_list(), Some(&[0][..]));
+    assert_eq!(sm.get_debug_id(), Some("85314830-023f-4cf1-a267-535f4e37bb17"));
+}
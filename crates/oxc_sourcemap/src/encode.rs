@@ -21,6 +21,9 @@ pub fn encode(sourcemap: &SourceMap) -> JSONSourceMap {
             .as_ref()
             .map(|x| x.iter().map(ToString::to_string).map(Some).collect()),
         names: Some(sourcemap.names.iter().map(ToString::to_string).collect()),
+        ignore_list: sourcemap.x_google_ignore_list.clone(),
+        x_google_ignore_list: sourcemap.x_google_ignore_list.clone(),
+        debug_id: sourcemap.get_debug_id().map(ToString::to_string),
     }
 }
 
@@ -31,7 +34,8 @@ pub fn encode_to_string(sourcemap: &SourceMap) -> String {
         + sourcemap.names.len() * 2
         + sourcemap.sources.len() * 2
         + sourcemap.source_contents.as_ref().map_or(0, |sources| sources.len() * 2 + 1)
-        + sourcemap.x_google_ignore_list.as_ref().map_or(0, |x| x.len() * 2 + 1);
+        + sourcemap.x_google_ignore_list.as_ref().map_or(0, |x| x.len() * 4 + 2)
+        + sourcemap.debug_id.as_ref().map_or(0, |_| 2);
     let mut contents = PreAllocatedString::new(max_segments);
 
     contents.push("{\"version\":3,".into());
@@ -69,14 +73,23 @@ pub fn encode_to_string(sourcemap: &SourceMap) -> String {
         };
     }
 
+    // Write both the standard `ignoreList` and `x_google_ignoreList` for older consumers.
     if let Some(x_google_ignore_list) = &sourcemap.x_google_ignore_list {
+        contents.push("],\"ignoreList\":[".into());
+        contents.push_list(x_google_ignore_list.iter().map(ToString::to_string));
         contents.push("],\"x_google_ignoreList\":[".into());
         contents.push_list(x_google_ignore_list.iter().map(ToString::to_string));
     }
 
     contents.push("],\"mappings\":\"".into());
     contents.push(serialize_sourcemap_mappings(sourcemap).into());
-    contents.push("\"}".into());
+    if let Some(debug_id) = sourcemap.get_debug_id() {
+        contents.push("\",\"debugId\":".into());
+        contents.push(escape_json_string(debug_id).into());
+        contents.push("}".into());
+    } else {
+        contents.push("\"}".into());
+    }
 
     // Check we calculated number of segments required correctly
     debug_assert!(contents.num_segments() <= max_segments);
@@ -403,8 +416,23 @@ fn test_encode_escape_string() {
     sm.set_x_google_ignore_list(vec![0]);
     assert_eq!(
         sm.to_json_string(),
-        r#"{"version":3,"names":["name_length_greater_than_16_\u0000"],"sources":["\u0000"],"sourcesContent":["emoji-👀-\u0000"],"x_google_ignoreList":[0],"mappings":""}"#
+        r#"{"version":3,"names":["name_length_greater_than_16_\u0000"],"sources":["\u0000"],"sourcesContent":["emoji-👀-\u0000"],"ignoreList":[0],"x_google_ignoreList":[0],"mappings":""}"#
+    );
+}
+
+#[test]
+fn test_encode_debug_id() {
+    let mut sm = SourceMap::new(None, vec![], None, vec!["a.js".into()], None, vec![], None);
+    sm.set_debug_id("85314830-023f-4cf1-a267-535f4e37bb17");
+    assert_eq!(
+        sm.to_json_string(),
+        r#"{"version":3,"names":[],"sources":["a.js"],"mappings":"","debugId":"85314830-023f-4cf1-a267-535f4e37bb17"}"#
     );
+    let json = sm.to_json();
+    assert_eq!(json.debug_id.as_deref(), Some("85314830-023f-4cf1-a267-535f4e37bb17"));
+
+    let sm2 = SourceMap::from_json_string(&sm.to_json_string()).unwrap();
+    assert_eq!(sm2.get_debug_id(), sm.get_debug_id());
 }
 
 #[test]
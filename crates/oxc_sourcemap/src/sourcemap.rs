@@ -21,6 +21,9 @@ pub struct SourceMap {
     /// The `x_google_ignoreList` field refers to the `sources` array, and lists the indices of all the known third-party sources in that source map.
     /// When parsing the source map, developer tools can use this to determine sections of the code that the browser loads and runs that could be automatically ignore-listed.
     pub(crate) x_google_ignore_list: Option<Vec<u32>>,
+    /// A unique identifier shared by the generated file and its sourcemap, used by error monitoring services to associate them.
+    /// See <https://github.com/tc39/source-map/blob/main/proposals/debug-id.md>.
+    pub(crate) debug_id: Option<String>,
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -43,6 +46,7 @@ impl SourceMap {
             tokens,
             token_chunks,
             x_google_ignore_list: None,
+            debug_id: None,
         }
     }
 
@@ -91,10 +95,36 @@ impl SourceMap {
     }
 
     /// Set `x_google_ignoreList`.
+    /// It is serialized as both `ignoreList` and `x_google_ignoreList`.
     pub fn set_x_google_ignore_list(&mut self, x_google_ignore_list: Vec<u32>) {
         self.x_google_ignore_list = Some(x_google_ignore_list);
     }
 
+    pub fn get_x_google_ignore_list(&self) -> Option<&[u32]> {
+        self.x_google_ignore_list.as_deref()
+    }
+
+    /// Mark the source at `source_id` as ignore-listed, e.g. bundler-generated code or a dependency.
+    pub fn set_source_ignored(&mut self, source_id: u32) {
+        let ignore_list = self.x_google_ignore_list.get_or_insert_with(Vec::new);
+        if !ignore_list.contains(&source_id) {
+            ignore_list.push(source_id);
+        }
+    }
+
+    pub fn is_source_ignored(&self, source_id: u32) -> bool {
+        self.x_google_ignore_list.as_ref().is_some_and(|list| list.contains(&source_id))
+    }
+
+    pub fn get_debug_id(&self) -> Option<&str> {
+        self.debug_id.as_deref()
+    }
+
+    /// Set `debugId`.
+    pub fn set_debug_id(&mut self, debug_id: &str) {
+        self.debug_id = Some(debug_id.into());
+    }
+
     pub fn get_names(&self) -> impl Iterator<Item = &str> {
         self.names.iter().map(AsRef::as_ref)
     }
@@ -276,4 +306,10 @@ fn test_mut_sourcemap() {
     assert_eq!(sm.get_file(), Some("index.js"));
     assert_eq!(sm.get_source(0), Some("foo.js"));
     assert_eq!(sm.get_source_content(0), Some("foo"));
+
+    sm.set_source_ignored(0);
+    sm.set_source_ignored(0);
+    assert_eq!(sm.get_x_google_ignore_list(), Some(&[0][..]));
+    sm.set_debug_id("85314830-023f-4cf1-a267-535f4e37bb17");
+    assert_eq!(sm.get_debug_id(), Some("85314830-023f-4cf1-a267-535f4e37bb17"));
 }
@@ -18,8 +18,13 @@ export type Atom = string;
 export type CompactStr = string;
 "#;
 
-/// Maximum length for inline string, which can be created with [`CompactStr::new_const`].
-pub const MAX_INLINE_LEN: usize = 16;
+/// Maximum length of strings stored inline in a [`CompactStr`] without a heap allocation on 64-bit
+/// targets, which is also the maximum length of [`CompactStr::new_const`].
+pub const MAX_INLINE_LEN: usize = 24;
+
+// `CompactStr` is the size of a `String`, and uses all of its bytes for inline strings.
+#[cfg(target_pointer_width = "64")]
+const _: () = assert!(std::mem::size_of::<CompactStr>() == MAX_INLINE_LEN);
 
 /// An inlinable string for oxc_allocator.
 ///
@@ -211,6 +216,9 @@ impl<'a> fmt::Display for Atom<'a> {
 ///
 /// Currently implemented as just a wrapper around [`compact_str::CompactString`],
 /// but will be reduced in size with a custom implementation later.
+///
+/// Strings of up to [`MAX_INLINE_LEN`] bytes are stored inline, e.g. most identifier names, so
+/// creating them does not allocate.
 #[derive(Clone, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serialize", derive(serde::Deserialize))]
 pub struct CompactStr(CompactString);
@@ -284,6 +292,20 @@ impl CompactStr {
         self.0.len()
     }
 
+    /// Whether the string is stored on the heap, i.e. it is longer than [`MAX_INLINE_LEN`] bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_span::CompactStr;
+    ///
+    /// assert!(!CompactStr::new("a string of 24 bytes....").is_heap_allocated());
+    /// assert!(CompactStr::new("a string which is longer than 24 bytes").is_heap_allocated());
+    /// ```
+    #[inline]
+    pub fn is_heap_allocated(&self) -> bool {
+        self.0.is_heap_allocated()
+    }
+
     /// Check if a [`CompactStr`] is empty (0 length).
     ///
     /// # Examples
@@ -299,6 +321,12 @@ impl CompactStr {
     }
 }
 
+impl Default for CompactStr {
+    fn default() -> Self {
+        Self::new_const("")
+    }
+}
+
 impl From<&str> for CompactStr {
     fn from(s: &str) -> Self {
         Self(CompactString::from(s))
@@ -440,7 +468,7 @@ impl schemars::JsonSchema for CompactStr {
 mod test {
     use compact_str::CompactString;
 
-    use super::CompactStr;
+    use super::{CompactStr, MAX_INLINE_LEN};
 
     #[test]
     fn test_compactstr_eq() {
@@ -451,4 +479,16 @@ mod test {
         assert_eq!("foo", &foo);
         assert_eq!(foo.into_compact_string(), CompactString::new("foo"));
     }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_compactstr_inline() {
+        const LONGEST: CompactStr = CompactStr::new_const("abcdefghijklmnopqrstuvwx");
+        assert_eq!(LONGEST.len(), MAX_INLINE_LEN);
+        assert!(!LONGEST.is_heap_allocated());
+        assert!(!CompactStr::new(LONGEST.as_str()).is_heap_allocated());
+        assert!(!CompactStr::from(String::from(LONGEST.as_str())).is_heap_allocated());
+        assert!(CompactStr::new(&"a".repeat(MAX_INLINE_LEN + 1)).is_heap_allocated());
+        assert!(CompactStr::default().is_empty());
+    }
 }
@@ -0,0 +1,124 @@
+//! Global string interner of [`Atom::intern`].
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+    sync::{Mutex, OnceLock},
+};
+
+use crate::Atom;
+
+/// Number of independently locked shards, so threads interning different strings rarely wait for
+/// each other.
+const SHARDS: usize = 16;
+
+static INTERNER: OnceLock<Interner> = OnceLock::new();
+
+/// Statistics of the strings interned by [`Atom::intern`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InternerStatistics {
+    /// Number of distinct interned strings.
+    pub strings: usize,
+    /// Total length of the distinct interned strings in bytes.
+    pub bytes: usize,
+    /// Number of calls to [`Atom::intern`], including the ones which returned an existing string.
+    pub lookups: usize,
+}
+
+impl InternerStatistics {
+    /// Number of calls to [`Atom::intern`] which returned an existing string.
+    pub fn hits(&self) -> usize {
+        self.lookups - self.strings
+    }
+}
+
+struct Interner {
+    shards: [Mutex<Shard>; SHARDS],
+}
+
+#[derive(Default)]
+struct Shard {
+    strings: HashSet<&'static str>,
+    bytes: usize,
+    lookups: usize,
+}
+
+impl Interner {
+    fn get() -> &'static Self {
+        INTERNER.get_or_init(|| Self { shards: std::array::from_fn(|_| Mutex::default()) })
+    }
+
+    fn shard(&self, s: &str) -> &Mutex<Shard> {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        #[allow(clippy::cast_possible_truncation)]
+        &self.shards[hasher.finish() as usize % SHARDS]
+    }
+}
+
+impl Atom<'static> {
+    /// Intern `s` into a global table, so identical strings share a single allocation, e.g. the
+    /// same identifiers in thousands of files.
+    ///
+    /// Interned strings are never freed, only intern strings which are long-lived and repeated.
+    ///
+    /// # Panics
+    /// Panics if the interner was poisoned by a panic while interning.
+    ///
+    /// # Examples
+    /// ```
+    /// use oxc_span::Atom;
+    ///
+    /// let a = Atom::intern("useState");
+    /// let b = Atom::intern(&String::from("useState"));
+    /// assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    /// ```
+    pub fn intern(s: &str) -> Self {
+        let mut shard = Interner::get().shard(s).lock().unwrap();
+        shard.lookups += 1;
+        if let Some(interned) = shard.strings.get(s) {
+            return Atom::from(*interned);
+        }
+        let interned: &'static str = Box::leak(Box::from(s));
+        shard.strings.insert(interned);
+        shard.bytes += interned.len();
+        Atom::from(interned)
+    }
+
+    /// Statistics of the strings interned by [`Atom::intern`] so far.
+    ///
+    /// # Panics
+    /// Panics if the interner was poisoned by a panic while interning.
+    pub fn interner_statistics() -> InternerStatistics {
+        let mut statistics = InternerStatistics::default();
+        for shard in &Interner::get().shards {
+            let shard = shard.lock().unwrap();
+            statistics.strings += shard.strings.len();
+            statistics.bytes += shard.bytes;
+            statistics.lookups += shard.lookups;
+        }
+        statistics
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Atom;
+
+    #[test]
+    fn intern() {
+        let before = Atom::interner_statistics();
+        let a = Atom::intern("oxc_span::interner::test");
+        let b = Atom::intern(&String::from("oxc_span::interner::test"));
+        let c = Atom::intern("oxc_span::interner::test2");
+        assert_eq!(a, b);
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert_ne!(a, c);
+
+        // Other tests can intern strings concurrently
+        let after = Atom::interner_statistics();
+        assert!(after.strings >= before.strings + 2);
+        assert!(after.lookups >= before.lookups + 3);
+        assert!(after.hits() > before.hits());
+    }
+}
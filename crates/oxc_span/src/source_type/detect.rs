@@ -0,0 +1,201 @@
+use std::{ffi::OsStr, fs, path::Path};
+
+use super::{Language, LanguageVariant, ModuleKind, SourceType};
+
+impl SourceType {
+    /// Detect the [`SourceType`] of a file from its path, its source text and the `type` field
+    /// of the nearest `package.json`.
+    ///
+    /// The extension is used when it is unambiguous, e.g. `.mjs` is always a module and `.tsx`
+    /// is always TypeScript with JSX. The module kind of `.js` and `.jsx` files is:
+    /// * [`ModuleKind::Module`] if the source text contains `import` or `export` declarations,
+    /// * otherwise the `type` of the nearest `package.json`, `"module"` or `"commonjs"`,
+    /// * otherwise [`ModuleKind::Script`] if the source text uses `require` or `module.exports`,
+    /// * otherwise [`ModuleKind::Unambiguous`].
+    ///
+    /// Files with an unknown or missing extension are also sniffed for TypeScript declarations
+    /// and JSX.
+    ///
+    /// The source text is scanned line by line without parsing, so syntax inside strings and
+    /// template literals may be misdetected.
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let source_type = SourceType::detect("index.js", "import a from 'a';");
+    /// assert!(source_type.is_module());
+    ///
+    /// let source_type = SourceType::detect("stdin", "interface A {}");
+    /// assert!(source_type.is_typescript());
+    /// ```
+    pub fn detect<P: AsRef<Path>>(path: P, source_text: &str) -> Self {
+        let path = path.as_ref();
+        let syntax = Syntax::scan(source_text);
+        let (mut source_type, detect_module_kind) = if let Ok(source_type) = Self::from_path(path) {
+            let extension = path.extension().and_then(OsStr::to_str);
+            // `.jsx` is a module according to `from_path`, but it may be CommonJS
+            let detect = source_type.is_javascript() && !matches!(extension, Some("cjs" | "mjs"));
+            (source_type, detect)
+        } else {
+            let language =
+                if syntax.typescript { Language::TypeScript } else { Language::JavaScript };
+            let variant = if syntax.jsx { LanguageVariant::Jsx } else { LanguageVariant::Standard };
+            (Self { language, module_kind: ModuleKind::Unambiguous, variant }, true)
+        };
+        if detect_module_kind {
+            source_type.module_kind = if syntax.esm {
+                ModuleKind::Module
+            } else if let Some(module_kind) = package_json_module_kind(path) {
+                module_kind
+            } else if syntax.commonjs {
+                ModuleKind::Script
+            } else {
+                ModuleKind::Unambiguous
+            };
+        }
+        source_type
+    }
+}
+
+/// Syntax found by [`Syntax::scan`].
+#[derive(Debug, Default)]
+struct Syntax {
+    /// `import` or `export` declarations
+    esm: bool,
+    /// `require()`, `module.exports` or `exports.`
+    commonjs: bool,
+    /// JSX closing or self-closing tags
+    jsx: bool,
+    /// TypeScript declarations, e.g. `interface A {}` or `type A = B`
+    typescript: bool,
+}
+
+impl Syntax {
+    fn scan(source_text: &str) -> Self {
+        let mut syntax = Self::default();
+        let mut in_block_comment = false;
+        for line in source_text.lines() {
+            let mut line = line.trim_start();
+            if in_block_comment {
+                let Some(end) = line.find("*/") else { continue };
+                line = line[end + 2..].trim_start();
+                in_block_comment = false;
+            }
+            if line.starts_with("//") {
+                continue;
+            }
+            if let Some(start) = line.find("/*") {
+                in_block_comment = !line[start + 2..].contains("*/");
+            }
+
+            syntax.esm |= is_keyword_statement(line, "import", &[" ", "{", "*", "\"", "'"])
+                || is_keyword_statement(line, "export", &[" ", "{", "*"]);
+            syntax.commonjs |= line.contains("require(")
+                || line.starts_with("module.exports")
+                || line.starts_with("exports.");
+            syntax.jsx |= line.contains("</") || line.contains("/>");
+            let declaration = line.strip_prefix("export ").unwrap_or(line);
+            syntax.typescript |= declaration.starts_with("interface ")
+                || declaration.starts_with("declare ")
+                || declaration.starts_with("enum ")
+                || declaration.starts_with("abstract class ")
+                || (declaration.starts_with("type ") && declaration.contains('='))
+                || line.starts_with("import type ");
+        }
+        syntax
+    }
+}
+
+/// `line` starts with `keyword` followed by one of `next`, e.g. `import {` but not `import(`.
+fn is_keyword_statement(line: &str, keyword: &str, next: &[&str]) -> bool {
+    line.strip_prefix(keyword).is_some_and(|rest| next.iter().any(|next| rest.starts_with(next)))
+}
+
+/// Module kind of the `type` field of the nearest `package.json`.
+fn package_json_module_kind(path: &Path) -> Option<ModuleKind> {
+    let package_json = path
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("package.json"))
+        .find(|package_json| package_json.is_file())?;
+    let package_json = fs::read_to_string(package_json).ok()?;
+    // A full JSON parser is not needed, `type` is the only field whose value can be `"module"`
+    // or `"commonjs"`
+    package_json.match_indices("\"type\"").find_map(|(start, key)| {
+        let value = package_json[start + key.len()..].trim_start().strip_prefix(':')?.trim_start();
+        if value.starts_with("\"module\"") {
+            Some(ModuleKind::Module)
+        } else if value.starts_with("\"commonjs\"") {
+            Some(ModuleKind::Script)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::SourceType;
+
+    #[test]
+    fn detect_module_kind() {
+        let module = SourceType::detect("foo.js", "import a from 'a';\nexport default a;");
+        assert!(module.is_module());
+        assert!(module.is_jsx());
+        let module = SourceType::detect("foo.jsx", "export { a } from 'a';");
+        assert!(module.is_module());
+        let script = SourceType::detect("foo.js", "const a = require('a');\nimport('b');");
+        assert!(script.is_script());
+        let script = SourceType::detect("foo.jsx", "module.exports = <div />;");
+        assert!(script.is_script());
+        let unambiguous = SourceType::detect("foo.js", "// import a from 'a';\nfoo();");
+        assert!(unambiguous.is_unambiguous());
+        let unambiguous = SourceType::detect("foo.js", "/*\nexport default a;\n*/");
+        assert!(unambiguous.is_unambiguous());
+
+        // The extension takes precedence
+        assert!(SourceType::detect("foo.cjs", "import a from 'a';").is_script());
+        assert!(SourceType::detect("foo.mjs", "require('a');").is_module());
+        assert!(SourceType::detect("foo.ts", "require('a');").is_module());
+        assert!(SourceType::detect("foo.cts", "import a = require('a');").is_script());
+    }
+
+    #[test]
+    fn detect_package_json_type() {
+        let dir = std::env::temp_dir().join("oxc_span_detect_package_json_type");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("package.json"),
+            r#"{ "repository": { "type": "git" }, "type" : "module" }"#,
+        )
+        .unwrap();
+        assert!(SourceType::detect(dir.join("src/foo.js"), "foo();").is_module());
+        assert!(SourceType::detect(dir.join("src/foo.js"), "require('a');").is_module());
+
+        fs::write(dir.join("package.json"), r#"{ "type": "commonjs" }"#).unwrap();
+        assert!(SourceType::detect(dir.join("src/foo.js"), "foo();").is_script());
+        assert!(SourceType::detect(dir.join("src/foo.js"), "export {};").is_module());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn detect_unknown_extension() {
+        let js = SourceType::detect("stdin", "foo();");
+        assert!(js.is_javascript());
+        assert!(!js.is_jsx());
+        assert!(js.is_unambiguous());
+
+        let jsx = SourceType::detect("stdin", "export const a = <A>a</A>;");
+        assert!(jsx.is_javascript());
+        assert!(jsx.is_jsx());
+        assert!(jsx.is_module());
+
+        let ts = SourceType::detect("foo.vue.txt", "export interface A {}\ntype B = A;");
+        assert!(ts.is_typescript());
+        assert!(!ts.is_jsx());
+        assert!(ts.is_module());
+    }
+}
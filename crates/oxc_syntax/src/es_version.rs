@@ -0,0 +1,49 @@
+//! ECMAScript versions.
+
+use std::str::FromStr;
+
+/// ECMAScript language version.
+///
+/// Variants are ordered, so `ESVersion::ES2015 < ESVersion::ES2020`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ESVersion {
+    ES3,
+    ES5,
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    ES2023,
+    ES2024,
+    #[default]
+    ESNext,
+}
+
+/// Parse a version such as `es5`, `es2015`, `es6` or `esnext`. Case insensitive.
+impl FromStr for ESVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version = match s.to_ascii_lowercase().as_str() {
+            "es3" => Self::ES3,
+            "es5" => Self::ES5,
+            "es6" | "es2015" => Self::ES2015,
+            "es7" | "es2016" => Self::ES2016,
+            "es8" | "es2017" => Self::ES2017,
+            "es9" | "es2018" => Self::ES2018,
+            "es10" | "es2019" => Self::ES2019,
+            "es11" | "es2020" => Self::ES2020,
+            "es12" | "es2021" => Self::ES2021,
+            "es13" | "es2022" => Self::ES2022,
+            "es14" | "es2023" => Self::ES2023,
+            "es15" | "es2024" => Self::ES2024,
+            "esnext" => Self::ESNext,
+            _ => return Err(()),
+        };
+        Ok(version)
+    }
+}
@@ -0,0 +1,105 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/estree.rs`
+
+#![allow(clippy::match_same_arms)]
+
+#[allow(unused_imports)]
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+#[allow(clippy::wildcard_imports)]
+use crate::operator::*;
+
+impl Serialize for AssignmentOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Assign => serializer.serialize_unit_variant("AssignmentOperator", 0, "="),
+            Self::Addition => serializer.serialize_unit_variant("AssignmentOperator", 1, "+="),
+            Self::Subtraction => serializer.serialize_unit_variant("AssignmentOperator", 2, "-="),
+            Self::Multiplication => {
+                serializer.serialize_unit_variant("AssignmentOperator", 3, "*=")
+            }
+            Self::Division => serializer.serialize_unit_variant("AssignmentOperator", 4, "/="),
+            Self::Remainder => serializer.serialize_unit_variant("AssignmentOperator", 5, "%="),
+            Self::ShiftLeft => serializer.serialize_unit_variant("AssignmentOperator", 6, "<<="),
+            Self::ShiftRight => serializer.serialize_unit_variant("AssignmentOperator", 7, ">>="),
+            Self::ShiftRightZeroFill => {
+                serializer.serialize_unit_variant("AssignmentOperator", 8, ">>>=")
+            }
+            Self::BitwiseOR => serializer.serialize_unit_variant("AssignmentOperator", 9, "|="),
+            Self::BitwiseXOR => serializer.serialize_unit_variant("AssignmentOperator", 10, "^="),
+            Self::BitwiseAnd => serializer.serialize_unit_variant("AssignmentOperator", 11, "&="),
+            Self::LogicalAnd => serializer.serialize_unit_variant("AssignmentOperator", 12, "&&="),
+            Self::LogicalOr => serializer.serialize_unit_variant("AssignmentOperator", 13, "||="),
+            Self::LogicalNullish => {
+                serializer.serialize_unit_variant("AssignmentOperator", 14, "??=")
+            }
+            Self::Exponential => serializer.serialize_unit_variant("AssignmentOperator", 15, "**="),
+        }
+    }
+}
+
+impl Serialize for BinaryOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Equality => serializer.serialize_unit_variant("BinaryOperator", 0, "=="),
+            Self::Inequality => serializer.serialize_unit_variant("BinaryOperator", 1, "!="),
+            Self::StrictEquality => serializer.serialize_unit_variant("BinaryOperator", 2, "==="),
+            Self::StrictInequality => serializer.serialize_unit_variant("BinaryOperator", 3, "!=="),
+            Self::LessThan => serializer.serialize_unit_variant("BinaryOperator", 4, "<"),
+            Self::LessEqualThan => serializer.serialize_unit_variant("BinaryOperator", 5, "<="),
+            Self::GreaterThan => serializer.serialize_unit_variant("BinaryOperator", 6, ">"),
+            Self::GreaterEqualThan => serializer.serialize_unit_variant("BinaryOperator", 7, ">="),
+            Self::ShiftLeft => serializer.serialize_unit_variant("BinaryOperator", 8, "<<"),
+            Self::ShiftRight => serializer.serialize_unit_variant("BinaryOperator", 9, ">>"),
+            Self::ShiftRightZeroFill => {
+                serializer.serialize_unit_variant("BinaryOperator", 10, ">>>")
+            }
+            Self::Addition => serializer.serialize_unit_variant("BinaryOperator", 11, "+"),
+            Self::Subtraction => serializer.serialize_unit_variant("BinaryOperator", 12, "-"),
+            Self::Multiplication => serializer.serialize_unit_variant("BinaryOperator", 13, "*"),
+            Self::Division => serializer.serialize_unit_variant("BinaryOperator", 14, "/"),
+            Self::Remainder => serializer.serialize_unit_variant("BinaryOperator", 15, "%"),
+            Self::BitwiseOR => serializer.serialize_unit_variant("BinaryOperator", 16, "|"),
+            Self::BitwiseXOR => serializer.serialize_unit_variant("BinaryOperator", 17, "^"),
+            Self::BitwiseAnd => serializer.serialize_unit_variant("BinaryOperator", 18, "&"),
+            Self::In => serializer.serialize_unit_variant("BinaryOperator", 19, "in"),
+            Self::Instanceof => {
+                serializer.serialize_unit_variant("BinaryOperator", 20, "instanceof")
+            }
+            Self::Exponential => serializer.serialize_unit_variant("BinaryOperator", 21, "**"),
+        }
+    }
+}
+
+impl Serialize for LogicalOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Or => serializer.serialize_unit_variant("LogicalOperator", 0, "||"),
+            Self::And => serializer.serialize_unit_variant("LogicalOperator", 1, "&&"),
+            Self::Coalesce => serializer.serialize_unit_variant("LogicalOperator", 2, "??"),
+        }
+    }
+}
+
+impl Serialize for UnaryOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::UnaryNegation => serializer.serialize_unit_variant("UnaryOperator", 0, "-"),
+            Self::UnaryPlus => serializer.serialize_unit_variant("UnaryOperator", 1, "+"),
+            Self::LogicalNot => serializer.serialize_unit_variant("UnaryOperator", 2, "!"),
+            Self::BitwiseNot => serializer.serialize_unit_variant("UnaryOperator", 3, "~"),
+            Self::Typeof => serializer.serialize_unit_variant("UnaryOperator", 4, "typeof"),
+            Self::Void => serializer.serialize_unit_variant("UnaryOperator", 5, "void"),
+            Self::Delete => serializer.serialize_unit_variant("UnaryOperator", 6, "delete"),
+        }
+    }
+}
+
+impl Serialize for UpdateOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Increment => serializer.serialize_unit_variant("UpdateOperator", 0, "++"),
+            Self::Decrement => serializer.serialize_unit_variant("UpdateOperator", 1, "--"),
+        }
+    }
+}
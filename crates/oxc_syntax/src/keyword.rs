@@ -1,5 +1,7 @@
 use phf::{phf_set, Set};
 
+use crate::es_version::ESVersion;
+
 #[inline]
 pub fn is_reserved_keyword_or_global_object(s: &str) -> bool {
     is_reserved_keyword(s) || is_global_object(s)
@@ -10,6 +12,24 @@ pub fn is_reserved_keyword(s: &str) -> bool {
     RESERVED_KEYWORDS.contains(s)
 }
 
+/// Check if `s` is a reserved word in `version`, which cannot be used as an identifier.
+///
+/// `strict` is whether the code is strict mode. Module code is always strict mode.
+///
+/// `await` is only reserved in modules and async functions, and `yield` in generators, which must
+/// be checked by the caller. In strict mode, `yield` is always reserved.
+///
+/// Reference: <https://tc39.es/ecma262/#prod-ReservedWord>
+pub fn is_reserved_word(s: &str, version: ESVersion, strict: bool) -> bool {
+    if s == "await" {
+        return false;
+    }
+    if version == ESVersion::ES3 {
+        return ES3_RESERVED_WORDS.contains(s);
+    }
+    RESERVED_KEYWORDS.contains(s) && (strict || !STRICT_MODE_RESERVED_WORDS.contains(s))
+}
+
 /// Checks `Infinity`, `NaN`, `globalThis` and `undefined`
 #[inline]
 pub fn is_global_object(s: &str) -> bool {
@@ -80,3 +100,104 @@ pub const RESERVED_KEYWORDS: Set<&'static str> = phf_set! {
     "with",
     "yield",
 };
+
+/// Words which are only reserved in strict mode code.
+///
+/// Reference: <https://tc39.es/ecma262/#sec-keywords-and-reserved-words>
+pub const STRICT_MODE_RESERVED_WORDS: Set<&'static str> = phf_set! {
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+};
+
+/// Reserved words in ES3, which also reserved many Java keywords. ES3 had no strict mode.
+///
+/// Reference: <https://www-archive.mozilla.org/js/language/e262-3.pdf> (7.5.1 and 7.5.3)
+pub const ES3_RESERVED_WORDS: Set<&'static str> = phf_set! {
+    // keywords
+    "break",
+    "case",
+    "catch",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "in",
+    "instanceof",
+    "new",
+    "return",
+    "switch",
+    "this",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    // future reserved words
+    "abstract",
+    "boolean",
+    "byte",
+    "char",
+    "class",
+    "const",
+    "debugger",
+    "double",
+    "enum",
+    "export",
+    "extends",
+    "final",
+    "float",
+    "goto",
+    "implements",
+    "import",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "short",
+    "static",
+    "super",
+    "synchronized",
+    "throws",
+    "transient",
+    "volatile",
+    // literals
+    "null",
+    "true",
+    "false",
+};
+
+#[cfg(test)]
+mod test {
+    use super::is_reserved_word;
+    use crate::es_version::ESVersion;
+
+    #[test]
+    fn reserved_words() {
+        assert!(is_reserved_word("class", ESVersion::ES5, false));
+        assert!(!is_reserved_word("let", ESVersion::ES2015, false));
+        assert!(is_reserved_word("let", ESVersion::ES2015, true));
+        assert!(is_reserved_word("yield", ESVersion::ESNext, true));
+        assert!(!is_reserved_word("await", ESVersion::ESNext, true));
+        assert!(is_reserved_word("goto", ESVersion::ES3, false));
+        assert!(!is_reserved_word("goto", ESVersion::ES5, true));
+        assert!(!is_reserved_word("undefined", ESVersion::ESNext, true));
+    }
+}
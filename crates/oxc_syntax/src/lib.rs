@@ -1,6 +1,7 @@
 //! Common code for JavaScript Syntax
 
 pub mod class;
+pub mod es_version;
 pub mod identifier;
 pub mod keyword;
 pub mod module_graph_visitor;
@@ -12,9 +13,12 @@ pub mod precedence;
 pub mod reference;
 pub mod scope;
 pub mod symbol;
+pub mod unicode_id;
 pub mod xml_entities;
 mod generated {
     mod derive_clone_in;
     mod derive_content_eq;
     mod derive_content_hash;
+    #[cfg(feature = "serialize")]
+    mod derive_estree;
 }
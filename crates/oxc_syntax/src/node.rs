@@ -1,59 +1,11 @@
 use bitflags::bitflags;
-use nonmax::NonMaxU32;
-use oxc_index::Idx;
-#[cfg(feature = "serialize")]
-use serde::{Serialize, Serializer};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct AstNodeId(NonMaxU32);
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct AstNodeId;
+}
 
 impl AstNodeId {
     pub const DUMMY: Self = AstNodeId::new(0);
-
-    /// Create `AstNodeId` from `u32`.
-    ///
-    /// # Panics
-    /// Panics if `idx` is `u32::MAX`.
-    pub const fn new(idx: u32) -> Self {
-        // We could use `NonMaxU32::new(idx).unwrap()` but `Option::unwrap` is not a const function
-        // and we want this function to be
-        assert!(idx != u32::MAX);
-        // SAFETY: We have checked that `idx` is not `u32::MAX`
-        unsafe { Self::new_unchecked(idx) }
-    }
-
-    /// Create `AstNodeId` from `u32` unchecked.
-    ///
-    /// # SAFETY
-    /// `idx` must not be `u32::MAX`.
-    #[allow(clippy::missing_safety_doc, clippy::unnecessary_safety_comment)]
-    pub const unsafe fn new_unchecked(idx: u32) -> Self {
-        // SAFETY: Caller must ensure `idx` is not `u32::MAX`
-        Self(NonMaxU32::new_unchecked(idx))
-    }
-}
-
-impl Idx for AstNodeId {
-    #[allow(clippy::cast_possible_truncation)]
-    fn from_usize(idx: usize) -> Self {
-        assert!(idx < u32::MAX as usize);
-        // SAFETY: We just checked `idx` is valid for `NonMaxU32`
-        Self(unsafe { NonMaxU32::new_unchecked(idx as u32) })
-    }
-
-    fn index(self) -> usize {
-        self.0.get() as usize
-    }
-}
-
-#[cfg(feature = "serialize")]
-impl Serialize for AstNodeId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.0.get())
-    }
 }
 
 #[cfg(feature = "serialize")]
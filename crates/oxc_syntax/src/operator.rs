@@ -5,14 +5,18 @@ use oxc_allocator::CloneIn;
 use oxc_ast_macros::ast;
 use oxc_span::{cmp::ContentEq, hash::ContentHash};
 #[cfg(feature = "serialize")]
-use ::{serde::Serialize, tsify::Tsify};
+use tsify::Tsify;
 
 use crate::precedence::{GetPrecedence, Precedence};
 
+// Not an AST type, so it is defined outside of this file, which `oxc_ast_tools` reads as the
+// source of truth for AST types.
+pub use crate::precedence::BinaryishOperator;
+
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum AssignmentOperator {
     #[serde(rename = "=")]
     Assign = 0,
@@ -91,8 +95,8 @@ impl AssignmentOperator {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum BinaryOperator {
     #[serde(rename = "==")]
     Equality = 0,
@@ -280,8 +284,8 @@ impl GetPrecedence for BinaryOperator {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum LogicalOperator {
     #[serde(rename = "||")]
     Or = 0,
@@ -321,8 +325,8 @@ impl GetPrecedence for LogicalOperator {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum UnaryOperator {
     #[serde(rename = "-")]
     UnaryNegation = 0,
@@ -375,8 +379,8 @@ impl UnaryOperator {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+#[cfg_attr(feature = "serialize", derive(Tsify))]
 pub enum UpdateOperator {
     #[serde(rename = "++")]
     Increment = 0,
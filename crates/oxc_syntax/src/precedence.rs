@@ -1,7 +1,24 @@
+//! Operator precedence and associativity.
+//!
+//! Shared by the parser, code generators and minifiers to decide how expressions bind, and when
+//! they must be wrapped in parentheses to be printed correctly.
+
+use crate::operator::{BinaryOperator, LogicalOperator};
+
+/// Get the [`Precedence`] of an operator or expression.
 pub trait GetPrecedence {
     fn precedence(&self) -> Precedence;
 }
 
+/// Operator associativity.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`.
+    Right,
+}
+
 /// Operator Precedence
 ///
 /// The following values are meaningful relative position, not their individual values.
@@ -42,6 +59,22 @@ pub enum Precedence {
 }
 
 impl Precedence {
+    pub fn associativity(&self) -> Associativity {
+        if self.is_right_associative() {
+            Associativity::Right
+        } else {
+            Associativity::Left
+        }
+    }
+
+    /// Whether an expression with this precedence must be wrapped in parentheses when printed in a
+    /// position which requires an expression of higher precedence than `required`.
+    ///
+    /// e.g. `a + b` needs parentheses as the left operand of `*`, which requires `Precedence::Add`.
+    pub fn needs_parens(&self, required: Self) -> bool {
+        required >= *self
+    }
+
     pub fn is_right_associative(&self) -> bool {
         matches!(self, Self::Exponentiation | Self::Conditional | Self::Assign)
     }
@@ -72,3 +105,110 @@ impl Precedence {
         )
     }
 }
+
+/// Either a [`BinaryOperator`] or a [`LogicalOperator`].
+///
+/// Binary and logical expressions share precedence rules, so printers usually handle them together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryishOperator {
+    Binary(BinaryOperator),
+    Logical(LogicalOperator),
+}
+
+impl From<BinaryOperator> for BinaryishOperator {
+    fn from(op: BinaryOperator) -> Self {
+        Self::Binary(op)
+    }
+}
+
+impl From<LogicalOperator> for BinaryishOperator {
+    fn from(op: LogicalOperator) -> Self {
+        Self::Logical(op)
+    }
+}
+
+impl BinaryishOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Binary(op) => op.as_str(),
+            Self::Logical(op) => op.as_str(),
+        }
+    }
+
+    pub fn lower_precedence(&self) -> Precedence {
+        match self {
+            Self::Binary(op) => op.lower_precedence(),
+            Self::Logical(op) => op.lower_precedence(),
+        }
+    }
+
+    /// Precedences required of the left and right operands.
+    ///
+    /// An operand must be wrapped in parentheses if [`Precedence::needs_parens`] returns `true`
+    /// for its precedence. This does not account for `??` not being allowed to mix with `&&` and
+    /// `||` without parentheses.
+    pub fn operand_precedences(&self) -> (Precedence, Precedence) {
+        let precedence = self.precedence();
+        let lower = self.lower_precedence();
+        if precedence.is_right_associative() {
+            (precedence, lower)
+        } else {
+            (lower, precedence)
+        }
+    }
+}
+
+impl GetPrecedence for BinaryishOperator {
+    fn precedence(&self) -> Precedence {
+        match self {
+            Self::Binary(op) => op.precedence(),
+            Self::Logical(op) => op.precedence(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Associativity, GetPrecedence, Precedence};
+    use crate::operator::{BinaryOperator, BinaryishOperator, LogicalOperator};
+
+    #[test]
+    fn associativity() {
+        assert_eq!(Precedence::Add.associativity(), Associativity::Left);
+        assert_eq!(Precedence::Exponentiation.associativity(), Associativity::Right);
+        assert_eq!(Precedence::Assign.associativity(), Associativity::Right);
+    }
+
+    #[test]
+    fn operand_precedences() {
+        let needs_parens = |child: BinaryOperator, parent: BinaryOperator| {
+            let (left, right) = BinaryishOperator::Binary(parent).operand_precedences();
+            (child.precedence().needs_parens(left), child.precedence().needs_parens(right))
+        };
+
+        // `(a + b) * c`, `a * (b + c)`
+        assert_eq!(
+            needs_parens(BinaryOperator::Addition, BinaryOperator::Multiplication),
+            (true, true)
+        );
+        // `a * b + c`, `a + b * c`
+        assert_eq!(
+            needs_parens(BinaryOperator::Multiplication, BinaryOperator::Addition),
+            (false, false)
+        );
+        // `a - b - c`, `a - (b - c)`
+        assert_eq!(
+            needs_parens(BinaryOperator::Subtraction, BinaryOperator::Subtraction),
+            (false, true)
+        );
+        // `(a ** b) ** c`, `a ** b ** c`
+        assert_eq!(
+            needs_parens(BinaryOperator::Exponential, BinaryOperator::Exponential),
+            (true, false)
+        );
+
+        let (left, _) = BinaryishOperator::Logical(LogicalOperator::Or).operand_precedences();
+        assert!(!LogicalOperator::And.precedence().needs_parens(left));
+        assert!(LogicalOperator::Coalesce.precedence().needs_parens(left));
+    }
+}
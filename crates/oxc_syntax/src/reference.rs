@@ -1,34 +1,10 @@
 use bitflags::bitflags;
-use nonmax::NonMaxU32;
 use oxc_allocator::CloneIn;
-use oxc_index::Idx;
 #[cfg(feature = "serialize")]
-use serde::{Serialize, Serializer};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct ReferenceId(NonMaxU32);
-
-impl Idx for ReferenceId {
-    #[allow(clippy::cast_possible_truncation)]
-    fn from_usize(idx: usize) -> Self {
-        assert!(idx < u32::MAX as usize);
-        // SAFETY: We just checked `idx` is valid for `NonMaxU32`
-        Self(unsafe { NonMaxU32::new_unchecked(idx as u32) })
-    }
-
-    fn index(self) -> usize {
-        self.0.get() as usize
-    }
-}
-
-#[cfg(feature = "serialize")]
-impl Serialize for ReferenceId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.0.get())
-    }
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct ReferenceId;
 }
 
 #[cfg(feature = "serialize")]
@@ -40,7 +16,11 @@ export type ReferenceFlags = {
     Read: 0b1,
     Write: 0b10,
     Type: 0b100,
-    Value: 0b11
+    ValueAsType: 0b1000,
+    Value: 0b11,
+    Delete: 0b10000,
+    Compound: 0b100000,
+    Export: 0b1000000
 }
 "#;
 
@@ -67,10 +47,21 @@ bitflags! {
     /// type definitions and signatures. Types can never be re-assigned, so
     /// there is no read/write distinction for type references.
     ///
+    /// ## Contexts
+    /// [`Delete`], [`Compound`] and [`Export`] further describe the syntax a
+    /// reference appears in, on top of how it is used:
+    /// * `delete a` is [`Read`] and [`Delete`]
+    /// * `a += 1` and `a++` are [`Write`] and [`Compound`], and also [`Read`] when the
+    ///   result is used
+    /// * `export { a }`, `export default a` and `export = a` are [`Export`]
+    ///
     /// [`Read`]: ReferenceFlags::Read
     /// [`Write`]: ReferenceFlags::Write
     /// [`Type`]: ReferenceFlags::Type
     /// [`ValueAsType`]: ReferenceFlags::ValueAsType
+    /// [`Delete`]: ReferenceFlags::Delete
+    /// [`Compound`]: ReferenceFlags::Compound
+    /// [`Export`]: ReferenceFlags::Export
     #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
     #[cfg_attr(feature = "serialize", derive(Serialize))]
     pub struct ReferenceFlags: u8 {
@@ -90,6 +81,15 @@ bitflags! {
         ///
         /// [`Read`]: ReferenceFlags::Read
         const Value = Self::Read.bits() | Self::Write.bits();
+        /// The operand of a `delete` expression, e.g. `delete a`.
+        const Delete = 1 << 4;
+        /// The target of a compound assignment or an update expression, e.g. `a += 1` or `a++`,
+        /// which reads the old value even when the result is unused.
+        const Compound = 1 << 5;
+        /// The local name of an export, e.g. `export { a }`, `export default a` or `export = a`.
+        const Export = 1 << 6;
+        /// How the symbol is used, as opposed to the syntax the reference appears in.
+        const Usage = Self::Value.bits() | Self::Type.bits() | Self::ValueAsType.bits();
     }
 }
 
@@ -151,15 +151,35 @@ impl ReferenceFlags {
         self.contains(Self::Type)
     }
 
+    /// The identifier is only used in a type definition, e.g. `export type { T }`.
     #[inline]
     pub const fn is_type_only(self) -> bool {
-        matches!(self, Self::Type)
+        self.intersection(Self::Usage).bits() == Self::Type.bits()
     }
 
     #[inline]
     pub const fn is_value(&self) -> bool {
         self.intersects(Self::Value)
     }
+
+    /// The identifier is the operand of a `delete` expression, e.g. `delete a`.
+    #[inline]
+    pub const fn is_delete(&self) -> bool {
+        self.contains(Self::Delete)
+    }
+
+    /// The identifier is the target of a compound assignment or an update expression, e.g.
+    /// `a += 1` or `a++`.
+    #[inline]
+    pub const fn is_compound(&self) -> bool {
+        self.contains(Self::Compound)
+    }
+
+    /// The identifier is the local name of an export, e.g. `export { a }`.
+    #[inline]
+    pub const fn is_export(&self) -> bool {
+        self.contains(Self::Export)
+    }
 }
 
 impl<'alloc> CloneIn<'alloc> for ReferenceFlags {
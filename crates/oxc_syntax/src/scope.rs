@@ -1,58 +1,10 @@
 use bitflags::bitflags;
-use nonmax::NonMaxU32;
-use oxc_index::Idx;
-#[cfg(feature = "serialize")]
-use serde::{Serialize, Serializer};
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct ScopeId(NonMaxU32);
-
-impl ScopeId {
-    /// Create `ScopeId` from `u32`.
-    ///
-    /// # Panics
-    /// Panics if `idx` is `u32::MAX`.
-    pub const fn new(idx: u32) -> Self {
-        // We could use `NonMaxU32::new(idx).unwrap()` but `Option::unwrap` is not a const function
-        // and we want this function to be
-        assert!(idx != u32::MAX);
-        // SAFETY: We have checked that `idx` is not `u32::MAX`
-        unsafe { Self::new_unchecked(idx) }
-    }
-
-    /// Create `ScopeId` from `u32` unchecked.
-    ///
-    /// # SAFETY
-    /// `idx` must not be `u32::MAX`.
-    #[allow(clippy::missing_safety_doc, clippy::unnecessary_safety_comment)]
-    pub const unsafe fn new_unchecked(idx: u32) -> Self {
-        // SAFETY: Caller must ensure `idx` is not `u32::MAX`
-        Self(NonMaxU32::new_unchecked(idx))
-    }
-}
 
-impl Idx for ScopeId {
-    #[allow(clippy::cast_possible_truncation)]
-    fn from_usize(idx: usize) -> Self {
-        assert!(idx < u32::MAX as usize);
-        // SAFETY: We just checked `idx` is valid for `NonMaxU32`
-        Self(unsafe { NonMaxU32::new_unchecked(idx as u32) })
-    }
-
-    fn index(self) -> usize {
-        self.0.get() as usize
-    }
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct ScopeId;
 }
 
-#[cfg(feature = "serialize")]
-impl Serialize for ScopeId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.0.get())
-    }
-}
+impl ScopeId {}
 
 #[cfg(feature = "serialize")]
 #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
@@ -73,6 +25,20 @@ bitflags! {
         const GetAccessor      = 1 << 7;
         const SetAccessor      = 1 << 8;
         const CatchClause      = 1 << 9;
+        /// The scope or one of its descendants contains a direct `eval(...)` call,
+        /// which can access and declare bindings of this scope.
+        const DirectEval       = 1 << 10;
+        /// The scope or one of its descendants contains a `with` statement,
+        /// whose object can shadow bindings of this scope.
+        const With             = 1 << 11;
+        /// The function references its `arguments` object.
+        const Arguments        = 1 << 12;
+        /// The function references its `arguments` object, and its parameters are aliased by it
+        /// because the function is not strict and has simple parameters,
+        /// e.g. `function f(a) { arguments[0] = 1; return a }`.
+        const MappedArguments  = 1 << 13;
+        /// The members of a TypeScript `enum`, e.g. `enum A { X, Y = X }`.
+        const TsEnum           = 1 << 14;
         const Var = Self::Top.bits() | Self::Function.bits() | Self::ClassStaticBlock.bits() | Self::TsModuleBlock.bits();
         const Modifiers = Self::Constructor.bits() | Self::GetAccessor.bits() | Self::SetAccessor.bits();
     }
@@ -96,7 +62,7 @@ impl ScopeFlags {
 
     #[inline]
     pub fn is_block(&self) -> bool {
-        self.is_empty() || *self == Self::StrictMode
+        self.difference(Self::StrictMode | Self::DirectEval | Self::With).is_empty()
     }
 
     #[inline]
@@ -129,6 +95,11 @@ impl ScopeFlags {
         self.contains(Self::TsModuleBlock)
     }
 
+    #[inline]
+    pub fn is_ts_enum(&self) -> bool {
+        self.contains(Self::TsEnum)
+    }
+
     #[inline]
     pub fn is_var(&self) -> bool {
         self.intersects(Self::Var)
@@ -148,4 +119,31 @@ impl ScopeFlags {
     pub fn is_catch_clause(&self) -> bool {
         self.contains(Self::CatchClause)
     }
+
+    #[inline]
+    pub fn contains_direct_eval(&self) -> bool {
+        self.contains(Self::DirectEval)
+    }
+
+    #[inline]
+    pub fn contains_with(&self) -> bool {
+        self.contains(Self::With)
+    }
+
+    /// Bindings of this scope may be accessed dynamically by a direct `eval` or a `with` statement,
+    /// so they cannot be renamed or removed.
+    #[inline]
+    pub fn is_poisoned(&self) -> bool {
+        self.intersects(Self::DirectEval | Self::With)
+    }
+
+    #[inline]
+    pub fn uses_arguments(&self) -> bool {
+        self.contains(Self::Arguments)
+    }
+
+    #[inline]
+    pub fn has_mapped_arguments(&self) -> bool {
+        self.contains(Self::MappedArguments)
+    }
 }
@@ -1,57 +1,13 @@
 use bitflags::bitflags;
-use nonmax::NonMaxU32;
-use oxc_index::Idx;
 #[cfg(feature = "serialize")]
-use serde::{Serialize, Serializer};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct SymbolId(NonMaxU32);
-
-impl Idx for SymbolId {
-    #[allow(clippy::cast_possible_truncation)]
-    fn from_usize(idx: usize) -> Self {
-        assert!(idx < u32::MAX as usize);
-        // SAFETY: We just checked `idx` is valid for `NonMaxU32`
-        Self(unsafe { NonMaxU32::new_unchecked(idx as u32) })
-    }
-
-    fn index(self) -> usize {
-        self.0.get() as usize
-    }
-}
-
-#[cfg(feature = "serialize")]
-impl Serialize for SymbolId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.0.get())
-    }
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct SymbolId;
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct RedeclarationId(NonMaxU32);
-
-impl Idx for RedeclarationId {
-    #[allow(clippy::cast_possible_truncation)]
-    fn from_usize(idx: usize) -> Self {
-        Self(NonMaxU32::new(idx as u32).unwrap())
-    }
-
-    fn index(self) -> usize {
-        self.0.get() as usize
-    }
-}
-
-#[cfg(feature = "serialize")]
-impl Serialize for RedeclarationId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.0.get())
-    }
+oxc_index::define_nonmax_u32_index_type! {
+    pub struct RedeclarationId;
 }
 
 #[cfg(feature = "serialize")]
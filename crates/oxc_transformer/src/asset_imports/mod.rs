@@ -0,0 +1,146 @@
+//! Asset imports
+//!
+//! Rewrites or removes imports of files which are not JavaScript, such as `import './app.css'`
+//! or `import logo from './logo.svg?url'`, according to [`AssetImportsOptions`].
+//!
+//! Bundlers handle these imports, but test runners which use the transformer as their only
+//! compile step (e.g. Jest and Vitest transformers) can not load them.
+//!
+//! ## Example
+//!
+//! With `{ ".css": Remove, ".svg": Path }`:
+//!
+//! Input:
+//! ```js
+//! import './app.css';
+//! import styles from './button.module.css';
+//! import logo from './logo.svg';
+//! ```
+//!
+//! Output:
+//! ```js
+//! const styles = {};
+//! const logo = './logo.svg';
+//! ```
+
+use oxc_allocator::Box;
+use oxc_ast::ast::*;
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+mod options;
+pub use options::{AssetImportAction, AssetImportsOptions};
+
+pub struct AssetImports {
+    options: AssetImportsOptions,
+}
+
+impl AssetImports {
+    pub fn new(options: AssetImportsOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a> for AssetImports {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.rules.is_empty() {
+            return;
+        }
+        let body = ctx.ast.move_vec(&mut program.body);
+        for stmt in body {
+            let stmt = match stmt {
+                Statement::ImportDeclaration(mut decl) if !decl.import_kind.is_type() => {
+                    match self.options.action(&decl.source.value) {
+                        Some(AssetImportAction::Rewrite(specifier)) => {
+                            decl.source.value = ctx.ast.atom(specifier);
+                            Some(Statement::ImportDeclaration(decl))
+                        }
+                        Some(action) => Self::declare_bindings(decl, action, ctx),
+                        None => Some(Statement::ImportDeclaration(decl)),
+                    }
+                }
+                Statement::ExportNamedDeclaration(mut decl) if !decl.export_kind.is_type() => {
+                    if let Some(source) = &mut decl.source {
+                        self.rewrite_source(source, ctx);
+                    }
+                    Some(Statement::ExportNamedDeclaration(decl))
+                }
+                Statement::ExportAllDeclaration(mut decl) if !decl.export_kind.is_type() => {
+                    self.rewrite_source(&mut decl.source, ctx);
+                    Some(Statement::ExportAllDeclaration(decl))
+                }
+                stmt => Some(stmt),
+            };
+            program.body.extend(stmt);
+        }
+    }
+}
+
+impl AssetImports {
+    /// Re-exports can only be rewritten, as there are no bindings to declare.
+    fn rewrite_source<'a>(&self, source: &mut StringLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        if let Some(AssetImportAction::Rewrite(specifier)) = self.options.action(&source.value) {
+            source.value = ctx.ast.atom(specifier);
+        }
+    }
+
+    /// Replace `import a, { b } from './x.svg'` with `const a = './x.svg', b = './x.svg'`,
+    /// or `const a = {}, b = {}` when the import is removed.
+    ///
+    /// Returns `None` if the import has no bindings.
+    fn declare_bindings<'a>(
+        decl: Box<'a, ImportDeclaration<'a>>,
+        action: &AssetImportAction,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let ast = ctx.ast;
+        let ImportDeclaration { specifiers, source, .. } = decl.unbox();
+        let path = source.value.as_str().split('?').next().unwrap_or_default();
+        let declarations = ast.vec_from_iter(specifiers?.into_iter().filter_map(|specifier| {
+            let local = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        return None;
+                    }
+                    specifier.unbox().local
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    specifier.unbox().local
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    specifier.unbox().local
+                }
+            };
+            if let Some(symbol_id) = local.symbol_id.get() {
+                *ctx.symbols_mut().get_flags_mut(symbol_id) =
+                    SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+            }
+            let init = match action {
+                AssetImportAction::Path => ast.expression_string_literal(SPAN, path),
+                _ => ast.expression_object(SPAN, ast.vec(), None),
+            };
+            let id = ast.binding_pattern(
+                ast.binding_pattern_kind_from_binding_identifier(local),
+                None::<TSTypeAnnotation<'a>>,
+                false,
+            );
+            Some(ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Const,
+                id,
+                Some(init),
+                false,
+            ))
+        }));
+        if declarations.is_empty() {
+            return None;
+        }
+        Some(Statement::from(ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Const,
+            declarations,
+            false,
+        )))
+    }
+}
@@ -0,0 +1,46 @@
+use rustc_hash::FxHashMap;
+
+/// Rules for imports of files which are not JavaScript, such as stylesheets and images.
+///
+/// Keys are either extensions such as `.css` and `.module.css`, or query parameters such as
+/// `?url`. Query parameters take precedence over extensions, and longer extensions take
+/// precedence over shorter ones.
+#[derive(Debug, Default, Clone)]
+pub struct AssetImportsOptions {
+    pub rules: FxHashMap<String, AssetImportAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetImportAction {
+    /// Remove the import.
+    ///
+    /// Imported bindings are declared as empty objects, so that e.g. `styles.button` evaluates to
+    /// `undefined` instead of throwing.
+    Remove,
+    /// Replace imported bindings with the path of the imported file, without query,
+    /// e.g. `import logo from './logo.svg?url'` becomes `const logo = './logo.svg'`.
+    Path,
+    /// Import another module instead, e.g. a mock such as `identity-obj-proxy`.
+    Rewrite(String),
+}
+
+impl AssetImportsOptions {
+    /// The action of the rule which applies to `specifier`, if any.
+    pub fn action(&self, specifier: &str) -> Option<&AssetImportAction> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        let (path, query) = specifier.split_once('?').unwrap_or((specifier, ""));
+        let by_query = query
+            .split('&')
+            .filter(|param| !param.is_empty())
+            .find_map(|param| self.rules.get(&format!("?{}", param.split('=').next()?)));
+        by_query.or_else(|| {
+            self.rules
+                .iter()
+                .filter(|(pattern, _)| pattern.starts_with('.') && path.ends_with(pattern.as_str()))
+                .max_by_key(|(pattern, _)| pattern.len())
+                .map(|(_, action)| action)
+        })
+    }
+}
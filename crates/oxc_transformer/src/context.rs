@@ -6,7 +6,7 @@ use std::{
 };
 
 use oxc_allocator::Allocator;
-use oxc_ast::{AstBuilder, Trivias};
+use oxc_ast::{AstBuilder, Pragmas, Trivias};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::SourceType;
 
@@ -17,7 +17,8 @@ pub type Ctx<'a> = Rc<TransformCtx<'a>>;
 pub struct TransformCtx<'a> {
     errors: RefCell<Vec<OxcDiagnostic>>,
 
-    pub trivias: Trivias,
+    /// JSX pragmas from comments
+    pub pragmas: Pragmas<'a>,
 
     pub ast: AstBuilder<'a>,
 
@@ -42,7 +43,7 @@ impl<'a> TransformCtx<'a> {
         source_path: &Path,
         source_type: SourceType,
         source_text: &'a str,
-        trivias: Trivias,
+        trivias: &Trivias,
         options: &TransformOptions,
     ) -> Self {
         let filename = source_path
@@ -53,6 +54,8 @@ impl<'a> TransformCtx<'a> {
             .strip_prefix(&options.cwd)
             .map_or_else(|_| source_path.to_path_buf(), |p| Path::new("<CWD>").join(p));
 
+        let pragmas = Pragmas::from_comments(trivias, source_text);
+
         Self {
             errors: RefCell::new(vec![]),
             ast: AstBuilder::new(allocator),
@@ -60,7 +63,7 @@ impl<'a> TransformCtx<'a> {
             source_path,
             source_type,
             source_text,
-            trivias,
+            pragmas,
             module_imports: ModuleImports::new(allocator),
         }
     }
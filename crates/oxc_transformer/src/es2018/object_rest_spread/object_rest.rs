@@ -1,6 +1,7 @@
-//! ES2018 object spread transformation.
+//! ES2018 object rest transformation.
 //!
-//! PLACEHOLDER ONLY. NOT IMPLEMENTED YET. TODO.
+//! This plugin transforms rest properties in object destructuring of variable declarations and
+//! function parameters.
 //!
 //! > This plugin is included in `preset-env`, in ES2018
 //!
@@ -9,13 +10,23 @@
 //! Input:
 //! ```js
 //! var { a, ...b } = x;
+//! function f({ c, ...d }) {}
 //! ```
 //!
 //! Output:
 //! ```js
-//! // TBD
+//! var { a } = x, b = babelHelpers.objectWithoutProperties(x, ["a"]);
+//! function f(_ref) {
+//!   let { c } = _ref, d = babelHelpers.objectWithoutProperties(_ref, ["c"]);
+//! }
 //! ```
 //!
+//! Only a rest element of the outermost pattern is transformed, and only when the keys of all
+//! other properties are static. Rest elements in nested patterns and assignment expressions are
+//! left as is. Parameters are left as is when a later parameter refers to one of the destructured
+//! bindings, or when the body redeclares one of them with `var` or `function`, as moving them into
+//! a `let` declaration would change the meaning of the code.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-object-rest-spread](https://babeljs.io/docs/babel-plugin-transform-object-rest-spread).
@@ -24,6 +35,14 @@
 //! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-object-rest-spread>
 //! * Object rest/spread TC39 proposal: <https://github.com/tc39/proposal-object-rest-spread>
 
+use std::cell::Cell;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, Visit};
+use oxc_semantic::{ReferenceFlags, ScopeId, SymbolFlags, SymbolId, SymbolTable};
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
 use super::ObjectRestSpreadOptions;
 use crate::context::Ctx;
 
@@ -37,3 +56,257 @@ impl<'a> ObjectRest<'a> {
         Self { _ctx: ctx, _options: options }
     }
 }
+
+impl<'a> Traverse<'a> for ObjectRest<'a> {
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `for (const { a, ...b } of c)` has no initializer
+        if !decl.declarations.iter().any(|d| d.init.is_some() && Self::has_object_rest(&d.id)) {
+            return;
+        }
+
+        let mut declarations = ctx.ast.vec_with_capacity(decl.declarations.len() + 1);
+        for declarator in decl.declarations.drain(..) {
+            Self::transform_declarator(declarator, &mut declarations, ctx);
+        }
+        decl.declarations = declarations;
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (Some(body), Some(scope_id)) = (func.body.as_mut(), func.scope_id.get()) else {
+            return;
+        };
+        Self::transform_params(&mut func.params, body, scope_id, ctx);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(scope_id) = arrow.scope_id.get() else { return };
+        Self::transform_params(&mut arrow.params, &mut arrow.body, scope_id, ctx);
+    }
+}
+
+impl<'a> ObjectRest<'a> {
+    /// Whether `pattern` is an object pattern with a rest element which can be transformed.
+    fn has_object_rest(pattern: &BindingPattern<'a>) -> bool {
+        match &pattern.kind {
+            BindingPatternKind::ObjectPattern(pattern) => {
+                pattern.rest.is_some()
+                    && pattern.properties.iter().all(|prop| prop.key.static_name().is_some())
+            }
+            _ => false,
+        }
+    }
+
+    /// `var { a, ...b } = foo()` -> `var _foo = foo(), { a } = _foo, b = babelHelpers.objectWithoutProperties(_foo, ["a"])`
+    fn transform_declarator(
+        mut declarator: VariableDeclarator<'a>,
+        declarations: &mut Vec<'a, VariableDeclarator<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !Self::has_object_rest(&declarator.id) {
+            declarations.push(declarator);
+            return;
+        }
+        let Some(init) = declarator.init.take() else {
+            declarations.push(declarator);
+            return;
+        };
+
+        let kind = declarator.kind;
+        let is_static = ctx.is_static(&init);
+        let source = match init {
+            Expression::Identifier(ident) if is_static => ident.unbox(),
+            init => {
+                let flags = match kind {
+                    VariableDeclarationKind::Const => {
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+                    }
+                    VariableDeclarationKind::Let => SymbolFlags::BlockScopedVariable,
+                    _ => SymbolFlags::FunctionScopedVariable,
+                };
+                let symbol_id = ctx.generate_uid_in_current_scope_based_on_node(&init, flags);
+                let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+                let id = Self::create_binding_pattern(name.clone(), symbol_id, ctx);
+                declarations.push(ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false));
+                ctx.create_bound_reference_id(SPAN, name, symbol_id, ReferenceFlags::Read)
+            }
+        };
+
+        let BindingPatternKind::ObjectPattern(pattern) = &mut declarator.id.kind else {
+            unreachable!()
+        };
+        let rest = pattern.rest.take().unwrap().unbox();
+
+        let mut excluded = ctx.ast.vec_with_capacity(pattern.properties.len());
+        for prop in &pattern.properties {
+            let key = ctx.ast.atom(&prop.key.static_name().unwrap());
+            excluded
+                .push(ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, key)));
+        }
+
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(ctx.ast.expression_from_identifier_reference(
+            ctx.clone_identifier_reference(&source, ReferenceFlags::Read),
+        )));
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, excluded, None)));
+        let callee = Self::babel_external_helper(ctx);
+        let without_properties = ctx.ast.expression_call(
+            SPAN,
+            callee,
+            None::<TSTypeParameterInstantiation>,
+            arguments,
+            false,
+        );
+
+        declarator.init = Some(ctx.ast.expression_from_identifier_reference(source));
+        declarations.push(declarator);
+        declarations.push(ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            rest.argument,
+            Some(without_properties),
+            false,
+        ));
+    }
+
+    /// `function f({ a, ...b }) {}` -> `function f(_ref) { let { a, ...b } = _ref; }`
+    ///
+    /// The `let` declaration is transformed by [`Self::enter_variable_declaration`] when the body
+    /// is visited.
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !Self::can_transform_params(params, ctx) {
+            return;
+        }
+
+        let mut declarations = ctx.ast.vec();
+        for param in params.items.iter_mut() {
+            // `function f({ a, ...b } = {}) {}` -> `function f(_ref = {}) {}`
+            let pattern = match &mut param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign) => &mut assign.left,
+                _ => &mut param.pattern,
+            };
+            if !Self::has_object_rest(pattern) {
+                continue;
+            }
+
+            let symbol_id = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+            let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+            let binding = Self::create_binding_pattern(name.clone(), symbol_id, ctx);
+            let pattern = std::mem::replace(pattern, binding);
+            // Parameters become `let` bindings
+            pattern.bound_names(&mut |ident| {
+                if let Some(symbol_id) = ident.symbol_id.get() {
+                    *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::BlockScopedVariable;
+                }
+            });
+            let init = ctx.create_bound_reference_id(SPAN, name, symbol_id, ReferenceFlags::Read);
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Let,
+                pattern,
+                Some(ctx.ast.expression_from_identifier_reference(init)),
+                false,
+            ));
+        }
+
+        if declarations.is_empty() {
+            return;
+        }
+        let declaration =
+            ctx.ast.declaration_variable(SPAN, VariableDeclarationKind::Let, declarations, false);
+        body.statements.insert(0, ctx.ast.statement_declaration(declaration));
+    }
+
+    /// Whether the destructured parameters can be moved into a `let` declaration in the body.
+    ///
+    /// `function f({ a, ...b }, c = b) {}` would refer to a binding in the body, and
+    /// `function f({ a, ...b }) { var b; }` would redeclare a `let` binding.
+    fn can_transform_params(params: &FormalParameters<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let mut collector = ParameterReferenceCollector {
+            symbols: ctx.symbols(),
+            symbol_ids: vec![],
+            referenced: false,
+        };
+        for param in &params.items {
+            // References in this parameter are evaluated before the earlier ones are moved
+            collector.visit_formal_parameter(param);
+            if collector.referenced {
+                return false;
+            }
+            let pattern = match &param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign) => &assign.left,
+                _ => &param.pattern,
+            };
+            if !Self::has_object_rest(pattern) {
+                continue;
+            }
+            let mut redeclared = false;
+            pattern.bound_names(&mut |ident| {
+                if let Some(symbol_id) = ident.symbol_id.get() {
+                    redeclared |= !collector.symbols.get_redeclarations(symbol_id).is_empty();
+                    collector.symbol_ids.push(symbol_id);
+                }
+            });
+            if redeclared {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn create_binding_pattern(
+        name: Atom<'a>,
+        symbol_id: SymbolId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BindingPattern<'a> {
+        let binding_identifier =
+            BindingIdentifier { span: SPAN, name, symbol_id: Cell::new(Some(symbol_id)) };
+        let id = ctx.ast.binding_pattern_kind_from_binding_identifier(binding_identifier);
+        ctx.ast.binding_pattern(id, None::<TSTypeAnnotation>, false)
+    }
+
+    fn babel_external_helper(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "babelHelpers");
+        let ident = ctx.create_reference_id(
+            SPAN,
+            Atom::from("babelHelpers"),
+            symbol_id,
+            ReferenceFlags::Read,
+        );
+        let object = ctx.ast.expression_from_identifier_reference(ident);
+        let property = ctx.ast.identifier_name(SPAN, Atom::from("objectWithoutProperties"));
+
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+}
+
+/// Finds references to the bindings of destructured parameters.
+struct ParameterReferenceCollector<'b> {
+    symbols: &'b SymbolTable,
+    symbol_ids: std::vec::Vec<SymbolId>,
+    referenced: bool,
+}
+
+impl<'a, 'b> Visit<'a> for ParameterReferenceCollector<'b> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+        if symbol_id.is_some_and(|symbol_id| self.symbol_ids.contains(&symbol_id)) {
+            self.referenced = true;
+        }
+    }
+}
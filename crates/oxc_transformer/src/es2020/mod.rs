@@ -1,9 +1,11 @@
 mod nullish_coalescing_operator;
+mod optional_chaining;
 mod options;
 
 use std::rc::Rc;
 
 pub use nullish_coalescing_operator::NullishCoalescingOperator;
+pub use optional_chaining::OptionalChaining;
 pub use options::ES2020Options;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
@@ -18,12 +20,14 @@ pub struct ES2020<'a> {
 
     // Plugins
     nullish_coalescing_operator: NullishCoalescingOperator<'a>,
+    optional_chaining: OptionalChaining<'a>,
 }
 
 impl<'a> ES2020<'a> {
     pub fn new(options: ES2020Options, ctx: Ctx<'a>) -> Self {
         Self {
             nullish_coalescing_operator: NullishCoalescingOperator::new(Rc::clone(&ctx)),
+            optional_chaining: OptionalChaining::new(Rc::clone(&ctx)),
             ctx,
             options,
         }
@@ -39,6 +43,9 @@ impl<'a> Traverse<'a> for ES2020<'a> {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_statements(statements, ctx);
         }
+        if self.options.optional_chaining {
+            self.optional_chaining.enter_statements(statements, ctx);
+        }
     }
 
     fn exit_statements(
@@ -49,11 +56,17 @@ impl<'a> Traverse<'a> for ES2020<'a> {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.exit_statements(statements, ctx);
         }
+        if self.options.optional_chaining {
+            self.optional_chaining.exit_statements(statements, ctx);
+        }
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
         }
+        if self.options.optional_chaining {
+            self.optional_chaining.enter_expression(expr, ctx);
+        }
     }
 }
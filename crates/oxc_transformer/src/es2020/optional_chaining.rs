@@ -0,0 +1,517 @@
+//! ES2020: Optional Chaining
+//!
+//! This plugin transforms optional chains (`?.`) to a series of conditional expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2020
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const baz = obj?.foo?.bar;
+//! a.b?.();
+//! delete c?.d;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj, _obj$foo, _a$b, _a, _c;
+//! const baz =
+//!   (_obj = obj) === null || _obj === void 0 || (_obj$foo = _obj.foo) === null || _obj$foo === void 0
+//!     ? void 0
+//!     : _obj$foo.bar;
+//! (_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a);
+//! (_c = c) === null || _c === void 0 ? true : delete _c.d;
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-optional-chaining](https://babeljs.io/docs/babel-plugin-transform-optional-chaining).
+//!
+//! Links of a chain are lowered from the innermost optional link outwards. The value each optional
+//! link is read from is stored in a temporary variable, unless it is a binding which is never
+//! written to, and the temporary variable is then reused by the rest of the chain.
+//! Temporary variables of a chain in a parameter are parameters of an arrow function which is
+//! called in place, as the `var` declarations of the function body are not visible there.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+//! * Optional chaining TC39 proposal: <https://github.com/tc39/proposal-optional-chaining>
+
+use std::cell::Cell;
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::ast::*;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::context::Ctx;
+
+pub struct OptionalChaining<'a> {
+    _ctx: Ctx<'a>,
+    var_declarations: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+    /// Scope of the arrow function wrapping an optional chain in a parameter
+    temp_scope_id: Option<ScopeId>,
+}
+
+impl<'a> OptionalChaining<'a> {
+    pub fn new(ctx: Ctx<'a>) -> Self {
+        Self { _ctx: ctx, var_declarations: vec![], temp_scope_id: None }
+    }
+}
+
+impl<'a> Traverse<'a> for OptionalChaining<'a> {
+    fn enter_statements(&mut self, _stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.var_declarations.push(ctx.ast.vec());
+    }
+
+    fn exit_statements(
+        &mut self,
+        statements: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(declarations) = self.var_declarations.pop() {
+            if declarations.is_empty() {
+                return;
+            }
+            let variable = ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+            );
+            statements.insert(0, Statement::VariableDeclaration(variable));
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_optional_chain(expr) {
+            return;
+        }
+
+        if !Self::is_in_formal_parameter(ctx) {
+            self.transform_expression(expr, ctx);
+            return;
+        }
+
+        // Replace `function (a, x = a.b?.c) {}` with `function (a, x = ((_a$b) => ...)()) {}`
+        // so the temporary variables are declared in the correct scope
+        let scope_id =
+            ctx.insert_scope_below_expression(expr, ScopeFlags::Arrow | ScopeFlags::Function);
+        self.var_declarations.push(ctx.ast.vec());
+        self.temp_scope_id = Some(scope_id);
+        self.transform_expression(expr, ctx);
+        self.temp_scope_id = None;
+        let declarations = self.var_declarations.pop().unwrap();
+        if declarations.is_empty() {
+            // No temporary variables are needed, so the scope is not used
+            ctx.remove_scope_above_expression(scope_id, expr);
+            ctx.scopes_mut().change_parent_id(scope_id, None);
+            return;
+        }
+
+        let mut items = ctx.ast.vec_with_capacity(declarations.len());
+        for declarator in declarations {
+            items.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                declarator.id,
+                None,
+                false,
+                false,
+            ));
+        }
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            None::<BindingRestElement>,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, ctx.ast.move_expression(expr))),
+        );
+        let arrow_function = ctx.ast.arrow_function_expression(
+            SPAN,
+            true,
+            false,
+            None::<TSTypeParameterDeclaration>,
+            params,
+            None::<TSTypeAnnotation>,
+            body,
+        );
+        arrow_function.scope_id.set(Some(scope_id));
+        // `(x) => x;` -> `((x) => x)();`
+        *expr = ctx.ast.expression_call(
+            SPAN,
+            ctx.ast.expression_from_arrow_function(arrow_function),
+            None::<TSTypeParameterInstantiation>,
+            ctx.ast.vec(),
+            false,
+        );
+    }
+}
+
+impl<'a> OptionalChaining<'a> {
+    /// Whether `expr` is an optional chain which is transformed by [`Self::transform_expression`].
+    fn is_optional_chain(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::ChainExpression(_) => true,
+            Expression::UnaryExpression(unary_expr) => {
+                unary_expr.operator == UnaryOperator::Delete
+                    && matches!(unary_expr.argument, Expression::ChainExpression(_))
+            }
+            Expression::CallExpression(call_expr) => {
+                matches!(call_expr.callee, Expression::ParenthesizedExpression(_))
+                    && matches!(
+                        call_expr.callee.get_inner_expression(),
+                        Expression::ChainExpression(chain_expr) if chain_expr.expression.is_member_expression()
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the current expression is in a parameter of the nearest function, where a `var`
+    /// declaration in the function body is not visible.
+    fn is_in_formal_parameter(ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FormalParameterPattern(_) => return true,
+                Ancestor::FunctionBodyStatements(_) | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `delete a?.b` -> `(_a = a) === null || _a === void 0 ? true : delete _a.b`
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::Delete
+                    && matches!(unary_expr.argument, Expression::ChainExpression(_)) =>
+            {
+                let Expression::ChainExpression(chain_expr) =
+                    ctx.ast.move_expression(&mut unary_expr.argument)
+                else {
+                    unreachable!()
+                };
+                let (test, argument) = self.transform_chain(chain_expr.unbox().expression, ctx);
+                unary_expr.argument = argument;
+                let delete_expr = ctx.ast.move_expression(expr);
+                *expr = match test {
+                    Some(test) => ctx.ast.expression_conditional(
+                        SPAN,
+                        test,
+                        ctx.ast.expression_boolean_literal(SPAN, true),
+                        delete_expr,
+                    ),
+                    None => delete_expr,
+                };
+            }
+            // `a?.b` -> `(_a = a) === null || _a === void 0 ? void 0 : _a.b`
+            Expression::ChainExpression(_) => {
+                let Expression::ChainExpression(chain_expr) = ctx.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                let (test, chain) = self.transform_chain(chain_expr.unbox().expression, ctx);
+                *expr = match test {
+                    Some(test) => {
+                        ctx.ast.expression_conditional(SPAN, test, ctx.ast.void_0(), chain)
+                    }
+                    None => chain,
+                };
+            }
+            // `(a?.b)()` -> `((_a = a) === null || _a === void 0 ? void 0 : _a.b).call(_a)`
+            Expression::CallExpression(call_expr) => {
+                let Expression::ChainExpression(chain_expr) =
+                    ctx.ast.move_expression(call_expr.callee.get_inner_expression_mut())
+                else {
+                    unreachable!()
+                };
+                let (test, mut chain) = self.transform_chain(chain_expr.unbox().expression, ctx);
+                let Some(test) = test else {
+                    call_expr.callee = chain;
+                    return;
+                };
+                let object = Self::object_mut(chain.to_member_expression_mut());
+                // An identifier is either a temporary variable or a binding which is never
+                // written to, so it does not need to be stored again
+                let this_arg = match object {
+                    Expression::Identifier(ident) => Self::read_reference(ident, ctx),
+                    object => self.memoise_this_arg(object, ctx),
+                };
+                let callee = ctx.ast.expression_conditional(SPAN, test, ctx.ast.void_0(), chain);
+                call_expr.callee = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    callee,
+                    ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                ));
+                call_expr.arguments.insert(0, Argument::from(this_arg));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Remove the optional links of a chain.
+    ///
+    /// Returns the null checks of the optional links, joined with `||`, and the chain without
+    /// them. The chain must only be evaluated if none of the checks is `true`.
+    fn transform_chain(
+        &mut self,
+        element: ChainElement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Option<Expression<'a>>, Expression<'a>) {
+        let mut chain = match element {
+            ChainElement::CallExpression(call_expr) => Expression::CallExpression(call_expr),
+            element @ match_member_expression!(ChainElement) => {
+                Expression::from(element.into_member_expression())
+            }
+        };
+
+        let mut test: Option<Expression<'a>> = None;
+        while let Some(depth) = Self::innermost_optional_link(&chain) {
+            let check = match Self::chain_link_mut(&mut chain, depth) {
+                Expression::CallExpression(call_expr) => {
+                    self.transform_optional_call(call_expr, ctx)
+                }
+                link @ match_member_expression!(Expression) => {
+                    let member_expr = link.to_member_expression_mut();
+                    Self::set_optional(member_expr, false);
+                    self.memoise_with_null_check(Self::object_mut(member_expr), ctx)
+                }
+                _ => unreachable!(),
+            };
+            test = Some(match test {
+                // `a || b` and `c || d` -> `a || b || c || d`
+                Some(test) => {
+                    let Expression::LogicalExpression(check) = check else { unreachable!() };
+                    let LogicalExpression { left, right, .. } = check.unbox();
+                    let left = ctx.ast.expression_logical(SPAN, test, LogicalOperator::Or, left);
+                    ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right)
+                }
+                None => check,
+            });
+        }
+
+        (test, chain)
+    }
+
+    /// `a.b?.()` -> `(_a$b = a.b) === null || _a$b === void 0`, with the call rewritten to
+    /// `_a$b.call(a)` so `a` is still the `this` value of the call.
+    fn transform_optional_call(
+        &mut self,
+        call_expr: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        call_expr.optional = false;
+        if !call_expr.callee.is_member_expression() {
+            return self.memoise_with_null_check(&mut call_expr.callee, ctx);
+        }
+
+        let callee_ident = self.generate_memoised(&call_expr.callee, ctx);
+
+        let object = Self::object_mut(call_expr.callee.to_member_expression_mut());
+        let this_arg = self.memoise_this_arg(object, ctx);
+
+        // `_a$b.call(a)`
+        let callee = ctx.ast.move_expression(&mut call_expr.callee);
+        call_expr.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            Self::read_reference(&callee_ident, ctx),
+            ctx.ast.identifier_name(SPAN, "call"),
+            false,
+        ));
+        call_expr.arguments.insert(0, Argument::from(this_arg));
+
+        let reference = Self::read_reference(&callee_ident, ctx);
+        Self::create_null_check(Self::create_assignment(callee_ident, callee, ctx), reference, ctx)
+    }
+
+    /// Return the `this` value of a call of a member of `object`, storing `object` in a temporary
+    /// variable if needed.
+    ///
+    /// `a.b` -> `(_a = a).b`, with `_a` as the `this` value
+    fn memoise_this_arg(
+        &mut self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if matches!(object, Expression::Super(_)) {
+            ctx.ast.expression_this(SPAN)
+        } else if let Some(ident) = self.maybe_generate_memoised(object, ctx) {
+            let value = ctx.ast.move_expression(object);
+            let this_arg = Self::read_reference(&ident, ctx);
+            *object = Self::create_assignment(ident, value, ctx);
+            this_arg
+        } else {
+            Self::clone_expression(object, ctx)
+        }
+    }
+
+    /// Replace `value` with a reference to it, and return a check whether it is nullish.
+    ///
+    /// `a.b` -> `(_a$b = a.b) === null || _a$b === void 0`
+    fn memoise_with_null_check(
+        &mut self,
+        value: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let expr = ctx.ast.move_expression(value);
+        if let Some(ident) = self.maybe_generate_memoised(&expr, ctx) {
+            *value = Self::read_reference(&ident, ctx);
+            let reference = Self::read_reference(&ident, ctx);
+            Self::create_null_check(Self::create_assignment(ident, expr, ctx), reference, ctx)
+        } else {
+            *value = Self::clone_expression(&expr, ctx);
+            let reference = Self::clone_expression(&expr, ctx);
+            Self::create_null_check(expr, reference, ctx)
+        }
+    }
+
+    /// Depth of the innermost optional link of a chain, `0` being the outermost member or
+    /// call expression.
+    fn innermost_optional_link(chain: &Expression<'a>) -> Option<usize> {
+        let mut innermost = None;
+        let mut depth = 0;
+        let mut expr = chain;
+        loop {
+            let (optional, next) = match expr {
+                Expression::CallExpression(call_expr) => (call_expr.optional, &call_expr.callee),
+                match_member_expression!(Expression) => {
+                    let member_expr = expr.to_member_expression();
+                    (member_expr.optional(), member_expr.object())
+                }
+                // Parentheses end a chain: `(a?.b).c`
+                _ => return innermost,
+            };
+            if optional {
+                innermost = Some(depth);
+            }
+            depth += 1;
+            expr = next;
+        }
+    }
+
+    fn chain_link_mut<'b>(chain: &'b mut Expression<'a>, depth: usize) -> &'b mut Expression<'a> {
+        let mut expr = chain;
+        for _ in 0..depth {
+            expr = match expr {
+                Expression::CallExpression(call_expr) => &mut call_expr.callee,
+                link @ match_member_expression!(Expression) => {
+                    Self::object_mut(link.to_member_expression_mut())
+                }
+                _ => unreachable!(),
+            };
+        }
+        expr
+    }
+
+    fn object_mut<'b>(member_expr: &'b mut MemberExpression<'a>) -> &'b mut Expression<'a> {
+        match member_expr {
+            MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+            MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+            MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+        }
+    }
+
+    fn set_optional(member_expr: &mut MemberExpression<'a>, optional: bool) {
+        match member_expr {
+            MemberExpression::ComputedMemberExpression(expr) => expr.optional = optional,
+            MemberExpression::StaticMemberExpression(expr) => expr.optional = optional,
+            MemberExpression::PrivateFieldExpression(expr) => expr.optional = optional,
+        }
+    }
+
+    /// Clone an expression
+    ///
+    /// If it is an identifier, clone the identifier by [TraverseCtx::clone_identifier_reference], otherwise, use [CloneIn].
+    ///
+    /// TODO: remove this until <https://github.com/oxc-project/oxc/issues/4804> is resolved.
+    fn clone_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => ctx.ast.expression_from_identifier_reference(
+                ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+            ),
+            _ => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    fn read_reference(
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_from_identifier_reference(
+            ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+        )
+    }
+
+    /// `_a = value`
+    fn create_assignment(
+        ident: IdentifierReference<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let target = AssignmentTarget::from(
+            ctx.ast.simple_assignment_target_from_identifier_reference(ident),
+        );
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }
+
+    /// `left === null || right === void 0`
+    fn create_null_check(
+        left: Expression<'a>,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let op = BinaryOperator::StrictEquality;
+        let null = ctx.ast.expression_null_literal(SPAN);
+        let left = ctx.ast.expression_binary(SPAN, left, op, null);
+        let right = ctx.ast.expression_binary(SPAN, right, op, ctx.ast.void_0());
+        ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right)
+    }
+
+    fn maybe_generate_memoised(
+        &mut self,
+        expr: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<IdentifierReference<'a>> {
+        if ctx.is_static(expr) {
+            return None;
+        }
+        Some(self.generate_memoised(expr, ctx))
+    }
+
+    /// Declare `var _name` for storing the value of `expr`, and return a reference to assign to it.
+    fn generate_memoised(
+        &mut self,
+        expr: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> IdentifierReference<'a> {
+        let scope_id = self.temp_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+        let symbol_id =
+            ctx.generate_uid_based_on_node(expr, scope_id, SymbolFlags::FunctionScopedVariable);
+        let symbol_name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+
+        // var _name;
+        let binding_identifier = BindingIdentifier {
+            span: SPAN,
+            name: symbol_name.clone(),
+            symbol_id: Cell::new(Some(symbol_id)),
+        };
+        let kind = VariableDeclarationKind::Var;
+        let id = ctx.ast.binding_pattern_kind_from_binding_identifier(binding_identifier);
+        let id = ctx.ast.binding_pattern(id, None::<TSTypeAnnotation>, false);
+        self.var_declarations
+            .last_mut()
+            .unwrap()
+            .push(ctx.ast.variable_declarator(SPAN, kind, id, None, false));
+
+        ctx.create_reference_id(SPAN, symbol_name, Some(symbol_id), ReferenceFlags::read_write())
+    }
+}
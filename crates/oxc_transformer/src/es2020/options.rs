@@ -7,6 +7,9 @@ use crate::env::{can_enable_plugin, Versions};
 pub struct ES2020Options {
     #[serde(skip)]
     pub nullish_coalescing_operator: bool,
+
+    #[serde(skip)]
+    pub optional_chaining: bool,
 }
 
 impl ES2020Options {
@@ -15,6 +18,11 @@ impl ES2020Options {
         self
     }
 
+    pub fn with_optional_chaining(&mut self, enable: bool) -> &mut Self {
+        self.optional_chaining = enable;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
@@ -23,6 +31,7 @@ impl ES2020Options {
                 targets,
                 bugfixes,
             ),
+            optional_chaining: can_enable_plugin("transform-optional-chaining", targets, bugfixes),
         }
     }
 }
@@ -13,6 +13,7 @@ mod compiler_assumptions;
 mod context;
 mod options;
 // Presets: <https://babel.dev/docs/presets>
+mod asset_imports;
 mod env;
 mod es2015;
 mod es2016;
@@ -31,6 +32,7 @@ mod helpers {
 
 use std::{path::Path, rc::Rc};
 
+use asset_imports::AssetImports;
 use es2016::ES2016;
 use es2018::ES2018;
 use es2019::ES2019;
@@ -45,12 +47,13 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 use regexp::RegExp;
 
 pub use crate::{
+    asset_imports::{AssetImportAction, AssetImportsOptions},
     compiler_assumptions::CompilerAssumptions,
-    env::{EnvOptions, Targets},
+    env::{EnvOptions, Targets, Versions},
     es2015::{ArrowFunctionsOptions, ES2015Options},
     options::{BabelOptions, TransformOptions},
-    react::{ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    react::{ReactJsxChildren, ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
+    typescript::{ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions},
 };
 use crate::{
     context::{Ctx, TransformCtx},
@@ -68,6 +71,7 @@ pub struct TransformerReturn {
 pub struct Transformer<'a> {
     ctx: Ctx<'a>,
     // NOTE: all callbacks must run in order.
+    x0_asset_imports: AssetImports,
     x0_typescript: TypeScript<'a>,
     x1_react: React<'a>,
     x2_es2021: ES2021<'a>,
@@ -80,6 +84,7 @@ pub struct Transformer<'a> {
 }
 
 impl<'a> Transformer<'a> {
+    #[allow(clippy::needless_pass_by_value)]
     pub fn new(
         allocator: &'a Allocator,
         source_path: &Path,
@@ -93,11 +98,12 @@ impl<'a> Transformer<'a> {
             source_path,
             source_type,
             source_text,
-            trivias,
+            &trivias,
             &options,
         ));
         Self {
             ctx: Rc::clone(&ctx),
+            x0_asset_imports: AssetImports::new(options.asset_imports),
             x0_typescript: TypeScript::new(options.typescript, Rc::clone(&ctx)),
             x1_react: React::new(options.react, Rc::clone(&ctx)),
             x2_es2021: ES2021::new(options.es2021, Rc::clone(&ctx)),
@@ -124,6 +130,7 @@ impl<'a> Transformer<'a> {
 
 impl<'a> Traverse<'a> for Transformer<'a> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_asset_imports.enter_program(program, ctx);
         self.x0_typescript.enter_program(program, ctx);
         self.x1_react.enter_program(program, ctx);
     }
@@ -142,6 +149,7 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.enter_arrow_function_expression(expr, ctx);
+        self.x2_es2018.enter_arrow_function_expression(expr, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -215,6 +223,7 @@ impl<'a> Traverse<'a> for Transformer<'a> {
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2018.enter_function(func, ctx);
         self.x3_es2015.enter_function(func, ctx);
     }
 
@@ -370,11 +379,20 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         self.x2_es2019.enter_catch_clause(clause, ctx);
     }
 
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x2_es2018.enter_variable_declaration(decl, ctx);
+    }
+
     fn enter_variable_declarator(
         &mut self,
         node: &mut VariableDeclarator<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        self.x0_typescript.enter_variable_declarator(node, ctx);
         self.x3_es2015.enter_variable_declarator(node, ctx);
     }
 
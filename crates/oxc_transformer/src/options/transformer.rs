@@ -4,6 +4,7 @@ use oxc_diagnostics::{Error, OxcDiagnostic};
 use serde_json::{from_value, json, Value};
 
 use crate::{
+    asset_imports::AssetImportsOptions,
     compiler_assumptions::CompilerAssumptions,
     env::{can_enable_plugin, EnvOptions, Versions},
     es2015::{ArrowFunctionsOptions, ES2015Options},
@@ -52,6 +53,15 @@ pub struct TransformOptions {
     pub es2020: ES2020Options,
 
     pub es2021: ES2021Options,
+
+    /// Rewrite or remove imports of files which are not JavaScript, e.g. `.css` and `.svg`.
+    pub asset_imports: AssetImportsOptions,
+
+    /// The engines the code runs on, from the `targets` of `preset-env`.
+    ///
+    /// The transforms are enabled from them when the options are created, this is kept for
+    /// later stages, e.g. the compressor of `oxc::Compiler` knows the globals of the platform.
+    pub targets: Option<Versions>,
 }
 
 impl TransformOptions {
@@ -79,8 +89,10 @@ impl TransformOptions {
             es2016: ES2016Options { exponentiation_operator: true },
             es2018: ES2018Options { object_rest_spread: Some(ObjectRestSpreadOptions::default()) },
             es2019: ES2019Options { optional_catch_binding: true },
-            es2020: ES2020Options { nullish_coalescing_operator: true },
+            es2020: ES2020Options { nullish_coalescing_operator: true, optional_chaining: true },
             es2021: ES2021Options { logical_assignment_operators: true },
+            asset_imports: AssetImportsOptions::default(),
+            targets: None,
         }
     }
 
@@ -93,6 +105,7 @@ impl TransformOptions {
             es2020: ES2020Options::from_targets_and_bugfixes(targets, bugfixes),
             es2021: ES2021Options::from_targets_and_bugfixes(targets, bugfixes),
             regexp: RegExpOptions::from_targets_and_bugfixes(targets, bugfixes),
+            targets: targets.cloned(),
             ..Default::default()
         }
     }
@@ -110,6 +123,9 @@ impl TransformOptions {
                 None
             }
         };
+        if !errors.is_empty() {
+            return Err(errors);
+        }
         let bugfixes = env_options.bugfixes;
         Ok(Self::from_targets_and_bugfixes(targets.as_ref(), bugfixes))
     }
@@ -223,6 +239,11 @@ impl TransformOptions {
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2020.with_optional_chaining({
+            let plugin_name = "transform-optional-chaining";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
         transformer_options.es2021.with_logical_assignment_operators({
             let plugin_name = "transform-logical-assignment-operators";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
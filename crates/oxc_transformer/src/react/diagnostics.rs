@@ -7,8 +7,10 @@ pub fn pragma_and_pragma_frag_cannot_be_set() -> OxcDiagnostic {
 }
 
 pub fn invalid_pragma() -> OxcDiagnostic {
-    OxcDiagnostic::warn("pragma and pragmaFrag must be of the form `foo` or `foo.bar`.")
-        .with_help("Fix `pragma` and `pragmaFrag` options.")
+    OxcDiagnostic::warn(
+        "pragma and pragmaFrag must be an identifier or a member expression, e.g. `h`, `React.createElement` or `this.$createElement`.",
+    )
+    .with_help("Fix `pragma` and `pragmaFrag` options.")
 }
 
 pub fn import_source_cannot_be_set() -> OxcDiagnostic {
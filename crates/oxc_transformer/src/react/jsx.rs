@@ -42,6 +42,9 @@ pub struct ReactJsx<'a> {
 
     // States
     bindings: Bindings<'a>,
+
+    /// Whether the import source takes children as arguments of `createElement`
+    children_as_arguments: bool,
 }
 
 /// Bindings for different import options
@@ -232,10 +235,12 @@ fn get_import_source(jsx_runtime_importer: &str, react_importer_len: u32) -> Ato
     Atom::from(&jsx_runtime_importer[..react_importer_len as usize])
 }
 
-/// Pragma used in classic mode
+/// Pragma used in classic mode, e.g. `React.createElement`, `h` for Preact,
+/// or `this.$createElement` for Vue.
 struct Pragma<'a> {
     object: Atom<'a>,
-    property: Option<Atom<'a>>,
+    /// Properties accessed on `object` in order, e.g. `["createElement"]`.
+    properties: std::vec::Vec<Atom<'a>>,
 }
 
 impl<'a> Pragma<'a> {
@@ -251,22 +256,15 @@ impl<'a> Pragma<'a> {
             let mut parts = pragma.split('.');
 
             let object_name = parts.next().unwrap();
-            if object_name.is_empty() {
+            let properties = parts.collect::<std::vec::Vec<_>>();
+            if object_name.is_empty() || properties.iter().any(|property| property.is_empty()) {
                 return Self::invalid(default_property_name, ctx);
             }
 
-            let property = match parts.next() {
-                Some(property_name) => {
-                    if property_name.is_empty() || parts.next().is_some() {
-                        return Self::invalid(default_property_name, ctx);
-                    }
-                    Some(ctx.ast.atom(property_name))
-                }
-                None => None,
-            };
-
             let object = ctx.ast.atom(object_name);
-            Self { object, property }
+            let properties =
+                properties.into_iter().map(|property| ctx.ast.atom(property)).collect();
+            Self { object, properties }
         } else {
             Self::default(default_property_name)
         }
@@ -278,21 +276,30 @@ impl<'a> Pragma<'a> {
     }
 
     fn default(default_property_name: &'static str) -> Self {
-        Self { object: Atom::from("React"), property: Some(Atom::from(default_property_name)) }
+        Self { object: Atom::from("React"), properties: vec![Atom::from(default_property_name)] }
     }
 
     fn create_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
-        let object = get_read_identifier_reference(SPAN, self.object.clone(), ctx);
-        if let Some(property) = self.property.as_ref() {
-            create_static_member_expression(object, property.clone(), ctx)
+        let object = if self.object == "this" {
+            ctx.ast.expression_this(SPAN)
         } else {
+            let object = get_read_identifier_reference(SPAN, self.object.clone(), ctx);
             ctx.ast.expression_from_identifier_reference(object)
-        }
+        };
+        self.properties.iter().fold(object, |object, property| {
+            let property = ctx.ast.identifier_name(SPAN, property.clone());
+            ctx.ast.member_expression_static(SPAN, object, property, false).into()
+        })
     }
 }
 
 impl<'a> ReactJsx<'a> {
     pub fn new(options: ReactOptions, ctx: Ctx<'a>) -> Self {
+        let children_as_arguments = options.runtime.is_automatic()
+            && options
+                .import_source_children
+                .get(options.import_source.as_deref().unwrap_or("react"))
+                .is_some_and(|children| children.is_arguments());
         let bindings = match options.runtime {
             ReactJsxRuntime::Classic => {
                 if options.import_source.is_some() {
@@ -361,6 +368,7 @@ impl<'a> ReactJsx<'a> {
             jsx_self: ReactJsxSelf::new(Rc::clone(&ctx)),
             jsx_source: ReactJsxSource::new(ctx),
             bindings,
+            children_as_arguments,
         }
     }
 }
@@ -426,7 +434,7 @@ impl<'a> ReactJsx<'a> {
     /// ### Fragment
     /// Builds JSX Fragment <></> into
     /// - Production: React.jsx(type, arguments)
-    /// - Development: React.jsxDEV(type, { children })
+    /// - Development: React.jsxDEV(type, { children }, undefined, isStaticChildren)
     ///
     /// ## Classic
     /// ### Element
@@ -442,9 +450,10 @@ impl<'a> ReactJsx<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         let is_fragment = e.is_fragment();
-        let has_key_after_props_spread = e.has_key_after_props_spread();
-        // If has_key_after_props_spread is true, we need to fallback to `createElement` same behavior as classic runtime
-        let is_classic = self.bindings.is_classic() || has_key_after_props_spread;
+        // If has_key_after_props_spread is true, we need to fallback to `createElement` same behavior as classic runtime.
+        // The import source may also take children as arguments of `createElement`.
+        let use_create_element = e.has_key_after_props_spread() || self.children_as_arguments;
+        let is_classic = self.bindings.is_classic() || use_create_element;
         let is_automatic = !is_classic;
         let is_development = self.options.development;
 
@@ -599,17 +608,16 @@ impl<'a> ReactJsx<'a> {
                 arguments.push(Argument::from(self.ctx.ast.void_0()));
             }
 
-            // isStaticChildren
+            // isStaticChildren, whether there is more than one child after removing whitespace
             if is_development {
-                arguments.push(Argument::from(self.ctx.ast.expression_boolean_literal(
-                    SPAN,
-                    if is_fragment { false } else { children.len() > 1 },
-                )));
+                arguments
+                    .push(Argument::from(self.ctx.ast.expression_boolean_literal(SPAN, need_jsxs)));
             }
 
             // Fragment doesn't have source and self
             if !is_fragment {
                 // { __source: { fileName, lineNumber, columnNumber } }
+                let mut has_source = false;
                 if self.options.jsx_source_plugin {
                     if let Some(span) = source_attr_span {
                         self.jsx_source.report_error(span);
@@ -617,6 +625,7 @@ impl<'a> ReactJsx<'a> {
                         let (line, column) = self.jsx_source.get_line_column(e.span().start);
                         let expr = self.jsx_source.get_source_object(line, column, ctx);
                         arguments.push(Argument::from(expr));
+                        has_source = true;
                     }
                 }
 
@@ -625,6 +634,10 @@ impl<'a> ReactJsx<'a> {
                     if let Some(span) = self_attr_span {
                         self.jsx_self.report_error(span);
                     } else {
+                        // `self` is the 6th argument, so `source` can not be omitted
+                        if !has_source {
+                            arguments.push(Argument::from(self.ctx.ast.void_0()));
+                        }
                         arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
                     }
                 }
@@ -640,7 +653,7 @@ impl<'a> ReactJsx<'a> {
             );
         }
 
-        let callee = self.get_create_element(has_key_after_props_spread, need_jsxs, ctx);
+        let callee = self.get_create_element(use_create_element, need_jsxs, ctx);
         self.ast().expression_call(
             e.span(),
             callee,
@@ -688,14 +701,14 @@ impl<'a> ReactJsx<'a> {
 
     fn get_create_element(
         &mut self,
-        has_key_after_props_spread: bool,
+        use_create_element: bool,
         jsxs: bool,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         match &mut self.bindings {
             Bindings::Classic(bindings) => bindings.pragma.create_expression(ctx),
             Bindings::AutomaticScript(bindings) => {
-                let (ident, property_name) = if has_key_after_props_spread {
+                let (ident, property_name) = if use_create_element {
                     (bindings.require_create_element(ctx), Atom::from("createElement"))
                 } else {
                     let property_name = if bindings.is_development {
@@ -710,7 +723,7 @@ impl<'a> ReactJsx<'a> {
                 create_static_member_expression(ident, property_name, ctx)
             }
             Bindings::AutomaticModule(bindings) => {
-                let ident = if has_key_after_props_spread {
+                let ident = if use_create_element {
                     bindings.import_create_element(ctx)
                 } else if jsxs {
                     bindings.import_jsxs(ctx)
@@ -41,14 +41,21 @@ impl<'a> ReactJsxSelf<'a> {
         self.ctx.error(error);
     }
 
+    /// Whether `this` can be used where the JSX element is.
+    ///
+    /// `this` can not be used in a TypeScript namespace, and may not be initialized yet in the
+    /// constructor of a derived class.
     #[allow(clippy::unused_self)]
-    fn is_inside_constructor(&self, ctx: &TraverseCtx<'a>) -> bool {
+    fn is_this_allowed(&self, ctx: &TraverseCtx<'a>) -> bool {
         for scope_id in ctx.ancestor_scopes() {
             let flags = ctx.scopes().get_flags(scope_id);
+            if flags.is_ts_module_block() {
+                return false;
+            }
             if flags.is_block() || flags.is_arrow() {
                 continue;
             }
-            return flags.is_constructor();
+            return !flags.is_constructor() || Self::has_no_super_class(ctx);
         }
         unreachable!(); // Always hit `Program` and exit before loop ends
     }
@@ -72,7 +79,7 @@ impl<'a> ReactJsxSelf<'a> {
     }
 
     pub fn can_add_self_attribute(&self, ctx: &TraverseCtx<'a>) -> bool {
-        !self.is_inside_constructor(ctx) || Self::has_no_super_class(ctx)
+        self.is_this_allowed(ctx)
     }
 
     /// `<div __self={this} />`
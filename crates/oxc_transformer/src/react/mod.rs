@@ -17,7 +17,7 @@ use refresh::ReactRefresh;
 pub use self::{
     display_name::ReactDisplayName,
     jsx::ReactJsx,
-    options::{ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
+    options::{ReactJsxChildren, ReactJsxRuntime, ReactOptions, ReactRefreshOptions},
 };
 use crate::context::Ctx;
 
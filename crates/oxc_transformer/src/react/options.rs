@@ -1,3 +1,5 @@
+use oxc_ast::JsxRuntimePragma;
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use crate::TransformCtx;
@@ -30,6 +32,24 @@ impl ReactJsxRuntime {
     }
 }
 
+/// Decides how children are passed to the functions imported from an import source.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReactJsxChildren {
+    /// `jsx("div", { children: [a, b] })`, with `jsxs` for more than one child, as
+    /// `react/jsx-runtime` expects.
+    #[default]
+    Props,
+    /// `createElement("div", null, a, b)`, with `createElement` imported from the import source.
+    Arguments,
+}
+
+impl ReactJsxChildren {
+    pub fn is_arguments(self) -> bool {
+        self == Self::Arguments
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ReactOptions {
@@ -73,10 +93,18 @@ pub struct ReactOptions {
     //
     /// Replaces the import source when importing functions.
     ///
+    /// Children are passed as configured by `import_source_children`.
+    ///
     /// Defaults to `react`.
     #[serde(default)]
     pub import_source: Option<String>,
 
+    /// Decides how children are passed for each import source, e.g. `{ "mithril": "arguments" }`.
+    ///
+    /// Import sources which are not listed pass children in `props.children`.
+    #[serde(default)]
+    pub import_source_children: FxHashMap<String, ReactJsxChildren>,
+
     // React Classic Runtime
     //
     /// Replace the function used when compiling JSX expressions.
@@ -121,6 +149,7 @@ impl Default for ReactOptions {
             throw_if_namespace: default_as_true(),
             pure: default_as_true(),
             import_source: None,
+            import_source_children: FxHashMap::default(),
             pragma: None,
             pragma_frag: None,
             use_built_ins: None,
@@ -139,54 +168,23 @@ impl ReactOptions {
         }
     }
 
-    /// Scan through all comments and find the following pragmas
-    ///
-    /// * @jsxRuntime classic / automatic
-    ///
-    /// The comment does not need to be a jsdoc,
-    /// otherwise `JSDoc` could be used instead.
-    ///
-    /// This behavior is aligned with babel.
+    /// Override options with the `@jsxRuntime`, `@jsxImportSource`, `@jsx` and `@jsxFrag`
+    /// pragmas found in comments. See [`oxc_ast::Pragmas`].
     pub(crate) fn update_with_comments(&mut self, ctx: &TransformCtx) {
-        for comment in ctx.trivias.comments() {
-            let mut comment = comment.span.source_text(ctx.source_text).trim_start();
-            // strip leading jsdoc comment `*` and then whitespaces
-            while let Some(cur_comment) = comment.strip_prefix('*') {
-                comment = cur_comment.trim_start();
-            }
-            // strip leading `@`
-            let Some(comment) = comment.strip_prefix('@') else { continue };
-
-            // read jsxRuntime
-            match comment.strip_prefix("jsxRuntime").map(str::trim) {
-                Some("classic") => {
-                    self.runtime = ReactJsxRuntime::Classic;
-                    continue;
-                }
-                Some("automatic") => {
-                    self.runtime = ReactJsxRuntime::Automatic;
-                    continue;
-                }
-                _ => {}
-            }
-
-            // read jsxImportSource
-            if let Some(import_source) = comment.strip_prefix("jsxImportSource").map(str::trim) {
-                self.import_source = Some(import_source.to_string());
-                continue;
-            }
-
-            // read jsxFrag
-            if let Some(pragma_frag) = comment.strip_prefix("jsxFrag").map(str::trim) {
-                self.pragma_frag = Some(pragma_frag.to_string());
-                continue;
-            }
-
-            // Put this condition at the end to avoid breaking @jsxXX
-            // read jsx
-            if let Some(pragma) = comment.strip_prefix("jsx").map(str::trim) {
-                self.pragma = Some(pragma.to_string());
-            }
+        let pragmas = &ctx.pragmas;
+        match pragmas.jsx_runtime {
+            Some(JsxRuntimePragma::Classic) => self.runtime = ReactJsxRuntime::Classic,
+            Some(JsxRuntimePragma::Automatic) => self.runtime = ReactJsxRuntime::Automatic,
+            None => {}
+        }
+        if let Some(import_source) = pragmas.jsx_import_source {
+            self.import_source = Some(import_source.to_string());
+        }
+        if let Some(pragma_frag) = pragmas.jsx_frag {
+            self.pragma_frag = Some(pragma_frag.to_string());
+        }
+        if let Some(pragma) = pragmas.jsx {
+            self.pragma = Some(pragma.to_string());
         }
     }
 }
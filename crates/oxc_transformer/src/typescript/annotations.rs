@@ -16,10 +16,9 @@ use oxc_syntax::{
 use oxc_traverse::{Traverse, TraverseCtx};
 use rustc_hash::FxHashSet;
 
-use crate::{context::Ctx, TypeScriptOptions};
+use crate::{context::Ctx, ImportsNotUsedAsValues, TypeScriptOptions};
 
 pub struct TypeScriptAnnotations<'a> {
-    #[allow(dead_code)]
     options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
     /// Assignments to be added to the constructor body
@@ -107,6 +106,8 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                             decl.specifiers = None;
                             true
                         } else {
+                            let keep_value_imports = self.options.verbatim_module_syntax
+                                || self.options.preserve_value_imports;
                             specifiers.retain(|specifier| {
                                 let id = match specifier {
                                     ImportDeclarationSpecifier::ImportSpecifier(s) => {
@@ -122,9 +123,27 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
                                         &s.local
                                     }
                                 };
-                                self.has_value_reference(&id.name, ctx)
+                                keep_value_imports || self.has_value_reference(&id.name, ctx)
                             });
-                            !specifiers.is_empty()
+                            if !specifiers.is_empty() {
+                                true
+                            } else if self.options.verbatim_module_syntax
+                                || !self.options.imports_not_used_as_values.is_remove()
+                            {
+                                if self.options.imports_not_used_as_values
+                                    == ImportsNotUsedAsValues::Error
+                                    && !self.options.verbatim_module_syntax
+                                {
+                                    self.ctx.error(super::diagnostics::import_never_used_as_value(
+                                        decl.span,
+                                    ));
+                                }
+                                // import { T } from 'mod' -> import 'mod'
+                                decl.specifiers = None;
+                                true
+                            } else {
+                                false
+                            }
                         }
                     } else {
                         true
@@ -161,11 +180,17 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         expr: &mut ArrowFunctionExpression<'a>,
         _ctx: &mut TraverseCtx<'a>,
     ) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         expr.type_parameters = None;
         expr.return_type = None;
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         pat.type_annotation = None;
 
         if pat.kind.is_binding_identifier() {
@@ -174,14 +199,20 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
     }
 
     fn enter_call_expression(&mut self, expr: &mut CallExpression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         expr.type_parameters = None;
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        class.r#abstract = false;
+        if self.options.preserve_type_annotations {
+            return;
+        }
         class.type_parameters = None;
         class.super_type_parameters = None;
         class.implements = None;
-        class.r#abstract = false;
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -266,6 +297,9 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         func.this_param = None;
         func.type_parameters = None;
         func.return_type = None;
@@ -276,6 +310,9 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         elem: &mut JSXOpeningElement<'a>,
         _ctx: &mut TraverseCtx<'a>,
     ) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         elem.type_parameters = None;
     }
 
@@ -341,6 +378,9 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
     }
 
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         expr.type_parameters = None;
     }
 
@@ -365,7 +405,9 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         def.r#override = false;
         def.optional = false;
         def.readonly = false;
-        def.type_annotation = None;
+        if !self.options.preserve_type_annotations {
+            def.type_annotation = None;
+        }
     }
 
     fn enter_accessor_property(
@@ -375,7 +417,17 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
     ) {
         def.accessibility = None;
         def.definite = false;
-        def.type_annotation = None;
+        if !self.options.preserve_type_annotations {
+            def.type_annotation = None;
+        }
+    }
+
+    fn enter_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        decl.definite = false;
     }
 
     fn enter_statements(
@@ -517,6 +569,9 @@ impl<'a> Traverse<'a> for TypeScriptAnnotations<'a> {
         expr: &mut TaggedTemplateExpression<'a>,
         _ctx: &mut TraverseCtx<'a>,
     ) {
+        if self.options.preserve_type_annotations {
+            return;
+        }
         expr.type_parameters = None;
     }
 
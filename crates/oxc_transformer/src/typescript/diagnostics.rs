@@ -25,3 +25,8 @@ pub fn namespace_not_supported(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace not marked type-only declare. Non-declarative namespaces are only supported experimentally in Babel. To enable and review caveats see: https://babeljs.io/docs/en/babel-plugin-transform-typescript")
         .with_label(span)
 }
+
+pub fn import_never_used_as_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This import is never used as a value and must use 'import type' because 'importsNotUsedAsValues' is set to 'error'.")
+        .with_label(span)
+}
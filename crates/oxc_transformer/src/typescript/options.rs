@@ -38,6 +38,24 @@ pub struct TypeScriptOptions {
     /// This should only be used if you are using TypeScript >= 3.8.
     pub only_remove_type_imports: bool,
 
+    /// TypeScript's [`verbatimModuleSyntax`](https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax).
+    ///
+    /// Only `import type` declarations and `type` specifiers are removed, all other imports are
+    /// kept as written. `import { type A } from 'mod'` becomes `import 'mod'`.
+    /// Takes precedence over `imports_not_used_as_values` and `preserve_value_imports`.
+    pub verbatim_module_syntax: bool,
+
+    /// TypeScript's [`importsNotUsedAsValues`](https://www.typescriptlang.org/tsconfig#importsNotUsedAsValues).
+    ///
+    /// What to do with imports which are never used as values. Defaults to `remove`.
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+
+    /// TypeScript's [`preserveValueImports`](https://www.typescriptlang.org/tsconfig#preserveValueImports).
+    ///
+    /// Keep all imports which are not marked with `type`, even if they are unused or only
+    /// used as types.
+    pub preserve_value_imports: bool,
+
     // Enables compilation of TypeScript namespaces.
     #[serde(default = "default_as_true")]
     pub allow_namespaces: bool,
@@ -49,6 +67,13 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Keep type annotations, type parameters, type arguments and `this` parameters of values
+    /// instead of removing them, e.g. for analyzing types after transforming. Print the program
+    /// with codegen's `strip_types` option to elide them from the JavaScript output.
+    ///
+    /// TypeScript declarations, such as interfaces, are removed regardless.
+    pub preserve_type_annotations: bool,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -61,38 +86,15 @@ pub struct TypeScriptOptions {
 }
 
 impl TypeScriptOptions {
-    /// Scan through all comments and find the following pragmas
-    ///
-    /// * @jsx React.createElement
-    /// * @jsxFrag React.Fragment
-    ///
-    /// The comment does not need to be a jsdoc,
-    /// otherwise `JSDoc` could be used instead.
-    ///
-    /// This behavior is aligned with babel.
+    /// Override `jsx_pragma` and `jsx_pragma_frag` with the `@jsx` and `@jsxFrag` pragmas found
+    /// in comments. See [`oxc_ast::Pragmas`].
     pub(crate) fn update_with_comments(mut self, ctx: &TransformCtx) -> Self {
-        for comment in ctx.trivias.comments() {
-            let mut comment = comment.span.source_text(ctx.source_text).trim_start();
-            // strip leading jsdoc comment `*` and then whitespaces
-            while let Some(cur_comment) = comment.strip_prefix('*') {
-                comment = cur_comment.trim_start();
-            }
-            // strip leading `@`
-            let Some(comment) = comment.strip_prefix('@') else { continue };
-
-            // read jsxFrag
-            if let Some(pragma_frag) = comment.strip_prefix("jsxFrag").map(str::trim) {
-                self.jsx_pragma_frag = Cow::from(pragma_frag.to_string());
-                continue;
-            }
-
-            // Put this condition at the end to avoid breaking @jsxXX
-            // read jsx
-            if let Some(pragma) = comment.strip_prefix("jsx").map(str::trim) {
-                self.jsx_pragma = Cow::from(pragma.to_string());
-            }
+        if let Some(pragma_frag) = ctx.pragmas.jsx_frag {
+            self.jsx_pragma_frag = Cow::from(pragma_frag.to_string());
+        }
+        if let Some(pragma) = ctx.pragmas.jsx {
+            self.jsx_pragma = Cow::from(pragma.to_string());
         }
-
         self
     }
 }
@@ -103,14 +105,38 @@ impl Default for TypeScriptOptions {
             jsx_pragma: default_for_jsx_pragma(),
             jsx_pragma_frag: default_for_jsx_pragma_frag(),
             only_remove_type_imports: false,
+            verbatim_module_syntax: false,
+            imports_not_used_as_values: ImportsNotUsedAsValues::default(),
+            preserve_value_imports: false,
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             optimize_const_enums: false,
+            preserve_type_annotations: false,
             rewrite_import_extensions: None,
         }
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportsNotUsedAsValues {
+    /// Remove imports which are only used as types.
+    #[default]
+    Remove,
+    /// Keep imports which are only used as types for their side effects,
+    /// `import { T } from 'mod'` becomes `import 'mod'`.
+    Preserve,
+    /// Same as `Preserve`, but also report an error for imports which are only used as types and
+    /// should be written with `import type`.
+    Error,
+}
+
+impl ImportsNotUsedAsValues {
+    pub fn is_remove(self) -> bool {
+        self == Self::Remove
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum RewriteExtensionsMode {
     /// Rewrite `.ts`/`.mts`/`.cts` extensions in import/export declarations to `.js`/`.mjs`/`.cjs`.
@@ -168,3 +194,21 @@ where
 
     deserializer.deserialize_any(RewriteExtensionsModeVisitor)
 }
+
+#[test]
+fn test_import_elision_options() {
+    let options = serde_json::json!({
+        "verbatimModuleSyntax": true,
+        "importsNotUsedAsValues": "error",
+        "preserveValueImports": true,
+    });
+    let options = serde_json::from_value::<TypeScriptOptions>(options).unwrap();
+    assert!(options.verbatim_module_syntax);
+    assert_eq!(options.imports_not_used_as_values, ImportsNotUsedAsValues::Error);
+    assert!(options.preserve_value_imports);
+
+    let options = serde_json::from_value::<TypeScriptOptions>(serde_json::json!({})).unwrap();
+    assert!(!options.verbatim_module_syntax);
+    assert!(options.imports_not_used_as_values.is_remove());
+    assert!(!options.preserve_value_imports);
+}
@@ -5,6 +5,7 @@
  * - `src/traverse.rs`
  * - `src/ancestor.rs`
  * - `src/walk.rs`
+ * - `src/replace.rs`
  *
  * This is a quick-and-dirty version written in JS for speed of implementation.
  * We should do this properly with a Rust build script using `syn` etc.
@@ -20,6 +21,7 @@ import generateTraverseTraitCode from './lib/traverse.mjs';
 import generateAncestorsCode from './lib/ancestor.mjs';
 import generateWalkFunctionsCode from './lib/walk.mjs';
 import generateScopesCollectorCode from './lib/scopes_collector.mjs';
+import generateReplaceCode from './lib/replace.mjs';
 
 const execAsync = promisify(exec);
 
@@ -34,6 +36,7 @@ await writeToFile('traverse.rs', generateTraverseTraitCode(types));
 await writeToFile('ancestor.rs', generateAncestorsCode(types));
 await writeToFile('walk.rs', generateWalkFunctionsCode(types));
 await writeToFile('scopes_collector.rs', generateScopesCollectorCode(types));
+await writeToFile('replace.rs', generateReplaceCode(types));
 
 async function writeToFile(filename, code) {
     code = `${PREAMBLE}${code}`;
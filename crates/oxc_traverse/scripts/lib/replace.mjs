@@ -0,0 +1,70 @@
+import assert from 'assert';
+import {camelToSnake} from './utils.mjs';
+
+/**
+ * Get the enum slots which a struct can be held in.
+ * Returns a map of struct name to array of `{enumType, variant}`.
+ * Only covers enums' own variants. Inherited variants are replaced via the slot of the enum
+ * they are inherited from.
+ */
+export function getSlots(types) {
+    const slots = {};
+    for (const type of Object.values(types)) {
+        if (type.kind !== 'enum') continue;
+        for (const variant of type.variants) {
+            const variantType = types[variant.innerTypeName];
+            if (variantType.kind !== 'struct') continue;
+            const structSlots = slots[variantType.name] ||= [];
+            assert(
+                !structSlots.some(slot => slot.enumType === type),
+                `Cannot handle enum with multiple variants of same type: ${type.name}`,
+            );
+            structSlots.push({enumType: type, variant});
+        }
+    }
+    return slots;
+}
+
+export default function generateReplaceCode(types) {
+    let code = '';
+    for (const [structName, slots] of Object.entries(getSlots(types))) {
+        const structType = types[structName],
+            slotName = `${structName}Slot`;
+
+        const slotVariants = slots.map(({enumType}) => `${enumType.name}(&'s mut ${enumType.rawName}),`),
+            nodeMatches = slots.map(
+                ({enumType, variant}) => `Self::${enumType.name}(${enumType.name}::${variant.name}(node)) => node,`,
+            );
+
+        code += `
+            /// Slot holding a [\`${structName}\`], passed to [\`Traverse::replace_${camelToSnake(structName)}\`].
+            ///
+            /// [\`Traverse::replace_${camelToSnake(structName)}\`]: crate::Traverse::replace_${camelToSnake(structName)}
+            pub enum ${slotName}<'s, 'a> {
+                ${slotVariants.join('\n')}
+            }
+
+            impl<'s, 'a> ${slotName}<'s, 'a> {
+                /// Get the node held in this slot.
+                ///
+                /// # Panics
+                /// Panics if the slot does not hold a [\`${structName}\`].
+                pub fn node(&mut self) -> &mut ${structType.rawName} {
+                    match self {
+                        ${nodeMatches.join('\n')}
+                        ${slots.map(({enumType}) => `Self::${enumType.name}(_) => unreachable!(),`).join('\n')}
+                    }
+                }
+            }
+        `;
+    }
+
+    return `
+        #![allow(clippy::match_same_arms)]
+
+        #[allow(clippy::wildcard_imports)]
+        use oxc_ast::ast::*;
+
+        ${code}
+    `;
+}
@@ -1,3 +1,4 @@
+import {getSlots} from './replace.mjs';
 import {camelToSnake} from './utils.mjs';
 
 export default function generateTraverseTraitCode(types) {
@@ -15,12 +16,25 @@ export default function generateTraverseTraitCode(types) {
         `;
     }
 
+    for (const structName of Object.keys(getSlots(types))) {
+        traverseMethods += `
+            /// Called before walking into a \`${structName}\` in an enum slot.
+            /// The node can be replaced by assigning to the slot.
+            #[inline]
+            fn replace_${camelToSnake(structName)}(
+                &mut self, slot: ${structName}Slot<'_, 'a>, ctx: &mut TraverseCtx<'a>
+            ) {}
+        `;
+    }
+
     return `
         use oxc_allocator::Vec;
         #[allow(clippy::wildcard_imports)]
         use oxc_ast::ast::*;
 
         use crate::TraverseCtx;
+        #[allow(clippy::wildcard_imports)]
+        use crate::replace::*;
 
         #[allow(unused_variables)]
         pub trait Traverse<'a> {
@@ -1,13 +1,15 @@
 import assert from 'assert';
+import {getSlots} from './replace.mjs';
 import {camelToSnake, snakeToCamel} from './utils.mjs';
 
 export default function generateWalkFunctionsCode(types) {
+    const slots = getSlots(types);
     let walkMethods = '';
     for (const type of Object.values(types)) {
         if (type.kind === 'struct') {
             walkMethods += generateWalkForStruct(type, types);
         } else {
-            walkMethods += generateWalkForEnum(type, types);
+            walkMethods += generateWalkForEnum(type, types, slots);
         }
     }
 
@@ -18,7 +20,8 @@ export default function generateWalkFunctionsCode(types) {
             clippy::missing_panics_doc,
             clippy::undocumented_unsafe_blocks,
             clippy::semicolon_if_nothing_returned,
-            clippy::cast_ptr_alignment
+            clippy::cast_ptr_alignment,
+            clippy::single_match
         )]
 
         use std::{cell::Cell, marker::PhantomData};
@@ -29,6 +32,8 @@ export default function generateWalkFunctionsCode(types) {
         use oxc_syntax::scope::ScopeId;
 
         use crate::{ancestor::{self, AncestorType}, Ancestor, Traverse, TraverseCtx};
+        #[allow(clippy::wildcard_imports)]
+        use crate::replace::*;
 
         ${walkMethods}
 
@@ -201,7 +206,19 @@ function makeFieldCode(field) {
     return `node.cast::<u8>().add(ancestor::${field.offsetVarName}).cast::<${field.typeName}>()`;
 }
 
-function generateWalkForEnum(type, types) {
+function generateWalkForEnum(type, types, slots) {
+    const replaceCodes = type.variants.filter(variant => slots[variant.innerTypeName]).map(variant => (
+        `${type.name}::${variant.name}(_) => traverser.replace_${camelToSnake(variant.innerTypeName)}(`
+        + `${variant.innerTypeName}Slot::${type.name}(&mut *node), ctx),`
+    ));
+    const hasReplaceCodes = replaceCodes.length > 0;
+    if (replaceCodes.length < type.variants.length || type.inherits.length > 0) replaceCodes.push('_ => {}');
+    const replaceCode = hasReplaceCodes
+        ? `match &*node {
+            ${replaceCodes.join('\n')}
+        }`
+        : '';
+
     const variantCodes = type.variants.map((variant) => {
         const variantType = types[variant.innerTypeName];
         assert(variantType, `Cannot handle enum variant with type: ${variant.type}`);
@@ -251,6 +268,7 @@ function generateWalkForEnum(type, types) {
             ctx: &mut TraverseCtx<'a>
         ) {
             traverser.enter_${typeSnakeName}(&mut *node, ctx);
+            ${replaceCode}
             match &mut *node {
                 ${variantCodes.join('\n')}
             }
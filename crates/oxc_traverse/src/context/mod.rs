@@ -262,6 +262,56 @@ impl<'a> TraverseCtx<'a> {
         self.scoping.insert_scope_below_statement(stmt, flags)
     }
 
+    /// Insert a scope into scope tree below a list of statements.
+    ///
+    /// Statements must be in current scope.
+    /// New scope is created as child of current scope.
+    /// All child scopes of the statements are reassigned to be children of the new scope.
+    ///
+    /// `flags` provided are amended to inherit from parent scope's flags.
+    ///
+    /// This is a shortcut for `ctx.scoping.insert_scope_below_statements`.
+    #[inline]
+    pub fn insert_scope_below_statements(
+        &mut self,
+        stmts: &[Statement],
+        flags: ScopeFlags,
+    ) -> ScopeId {
+        self.scoping.insert_scope_below_statements(stmts, flags)
+    }
+
+    /// Remove a scope from scope tree above an expression.
+    ///
+    /// All child scopes of the expression are reassigned to be children of the removed scope's parent.
+    ///
+    /// This is a shortcut for `ctx.scoping.remove_scope_above_expression`.
+    #[inline]
+    pub fn remove_scope_above_expression(&mut self, scope_id: ScopeId, expr: &Expression) {
+        self.scoping.remove_scope_above_expression(scope_id, expr);
+    }
+
+    /// Remove a scope from scope tree above a statement.
+    ///
+    /// All child scopes of the statement are reassigned to be children of the removed scope's parent.
+    /// The removed scope must not contain any bindings.
+    ///
+    /// This is a shortcut for `ctx.scoping.remove_scope_above_statement`.
+    #[inline]
+    pub fn remove_scope_above_statement(&mut self, scope_id: ScopeId, stmt: &Statement) {
+        self.scoping.remove_scope_above_statement(scope_id, stmt);
+    }
+
+    /// Remove a scope from scope tree above a list of statements.
+    ///
+    /// All child scopes of the statements are reassigned to be children of the removed scope's parent.
+    /// The removed scope must not contain any bindings.
+    ///
+    /// This is a shortcut for `ctx.scoping.remove_scope_above_statements`.
+    #[inline]
+    pub fn remove_scope_above_statements(&mut self, scope_id: ScopeId, stmts: &[Statement]) {
+        self.scoping.remove_scope_above_statements(scope_id, stmts);
+    }
+
     /// Insert a scope into scope tree below an expression.
     ///
     /// Expression must be in current scope.
@@ -290,6 +340,9 @@ impl<'a> TraverseCtx<'a> {
 
     /// Generate UID in current scope.
     ///
+    /// Equivalent to Babel's `scope.generateUid(name)`. The new symbol is added to the current
+    /// scope, so it will not clash with any existing binding, or with any UID generated later.
+    ///
     /// This is a shortcut for `ctx.scoping.generate_uid_in_current_scope`.
     #[inline]
     pub fn generate_uid_in_current_scope(&mut self, name: &str, flags: SymbolFlags) -> SymbolId {
@@ -306,6 +359,8 @@ impl<'a> TraverseCtx<'a> {
 
     /// Generate UID based on node.
     ///
+    /// Equivalent to Babel's `scope.generateUidBasedOnNode(node)`, e.g. `foo.bar` produces `_foo$bar`.
+    ///
     /// This is a shortcut for `ctx.scoping.generate_uid_based_on_node`.
     #[inline]
     pub fn generate_uid_based_on_node(
@@ -101,6 +101,25 @@ impl TraverseScoping {
         self.insert_scope_below(&collector.scope_ids, flags)
     }
 
+    /// Insert a scope into scope tree below a list of statements.
+    ///
+    /// Statements must be in current scope.
+    /// New scope is created as child of current scope.
+    /// All child scopes of the statements are reassigned to be children of the new scope.
+    ///
+    /// `flags` provided are amended to inherit from parent scope's flags.
+    pub fn insert_scope_below_statements(
+        &mut self,
+        stmts: &[Statement],
+        flags: ScopeFlags,
+    ) -> ScopeId {
+        let mut collector = ChildScopeCollector::new();
+        for stmt in stmts {
+            collector.visit_statement(stmt);
+        }
+        self.insert_scope_below(&collector.scope_ids, flags)
+    }
+
     /// Insert a scope into scope tree below an expression.
     ///
     /// Expression must be in current scope.
@@ -118,24 +137,60 @@ impl TraverseScoping {
         self.insert_scope_below(&collector.scope_ids, flags)
     }
 
-    fn insert_scope_below(&mut self, child_scope_ids: &[ScopeId], flags: ScopeFlags) -> ScopeId {
-        // Remove these scopes from parent's children
-        if self.scopes.has_child_ids() {
-            let current_child_scope_ids = self.scopes.get_child_ids_mut(self.current_scope_id);
-            current_child_scope_ids.retain(|scope_id| !child_scope_ids.contains(scope_id));
+    /// Remove a scope from scope tree above a statement.
+    ///
+    /// This is the reverse of `insert_scope_below_statement`.
+    /// All child scopes of the statement are reassigned to be children of the removed scope's parent.
+    /// The removed scope must not contain any bindings.
+    pub fn remove_scope_above_statement(&mut self, scope_id: ScopeId, stmt: &Statement) {
+        let mut collector = ChildScopeCollector::new();
+        collector.visit_statement(stmt);
+        self.reparent_child_scopes(scope_id, &collector.scope_ids);
+    }
+
+    /// Remove a scope from scope tree above a list of statements.
+    ///
+    /// This is the reverse of `insert_scope_below_statements`, for when the statements of a block
+    /// are moved into the enclosing scope.
+    /// All child scopes of the statements are reassigned to be children of the removed scope's parent.
+    /// The removed scope must not contain any bindings.
+    pub fn remove_scope_above_statements(&mut self, scope_id: ScopeId, stmts: &[Statement]) {
+        let mut collector = ChildScopeCollector::new();
+        for stmt in stmts {
+            collector.visit_statement(stmt);
         }
+        self.reparent_child_scopes(scope_id, &collector.scope_ids);
+    }
 
+    /// Remove a scope from scope tree above an expression.
+    ///
+    /// This is the reverse of `insert_scope_below_expression`.
+    /// All child scopes of the expression are reassigned to be children of the removed scope's parent.
+    pub fn remove_scope_above_expression(&mut self, scope_id: ScopeId, expr: &Expression) {
+        let mut collector = ChildScopeCollector::new();
+        collector.visit_expression(expr);
+        self.reparent_child_scopes(scope_id, &collector.scope_ids);
+    }
+
+    fn insert_scope_below(&mut self, child_scope_ids: &[ScopeId], flags: ScopeFlags) -> ScopeId {
         // Create new scope as child of parent
         let new_scope_id = self.create_child_scope_of_current(flags);
 
-        // Set scopes as children of new scope instead
+        // Set scopes as children of new scope instead, removing them from their parent's children
         for &child_id in child_scope_ids {
-            self.scopes.set_parent_id(child_id, Some(new_scope_id));
+            self.scopes.change_parent_id(child_id, Some(new_scope_id));
         }
 
         new_scope_id
     }
 
+    fn reparent_child_scopes(&mut self, scope_id: ScopeId, child_scope_ids: &[ScopeId]) {
+        let parent_id = self.scopes.get_parent_id(scope_id);
+        for &child_id in child_scope_ids {
+            self.scopes.change_parent_id(child_id, parent_id);
+        }
+    }
+
     /// Generate UID.
     ///
     /// Finds a unique variable name which does clash with any other variables used in the program.
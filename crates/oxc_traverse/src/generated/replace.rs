@@ -0,0 +1,3195 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// Generated by `oxc_traverse/scripts/build.mjs`.
+// To alter this generated file you have to edit the codegen.
+
+#![allow(clippy::match_same_arms)]
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+/// Slot holding a [`BooleanLiteral`], passed to [`Traverse::replace_boolean_literal`].
+///
+/// [`Traverse::replace_boolean_literal`]: crate::Traverse::replace_boolean_literal
+pub enum BooleanLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> BooleanLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BooleanLiteral`].
+    pub fn node(&mut self) -> &mut BooleanLiteral {
+        match self {
+            Self::Expression(Expression::BooleanLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::BooleanLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`NullLiteral`], passed to [`Traverse::replace_null_literal`].
+///
+/// [`Traverse::replace_null_literal`]: crate::Traverse::replace_null_literal
+pub enum NullLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> NullLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`NullLiteral`].
+    pub fn node(&mut self) -> &mut NullLiteral {
+        match self {
+            Self::Expression(Expression::NullLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::NullLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`NumericLiteral`], passed to [`Traverse::replace_numeric_literal`].
+///
+/// [`Traverse::replace_numeric_literal`]: crate::Traverse::replace_numeric_literal
+pub enum NumericLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSEnumMemberName(&'s mut TSEnumMemberName<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> NumericLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`NumericLiteral`].
+    pub fn node(&mut self) -> &mut NumericLiteral<'a> {
+        match self {
+            Self::Expression(Expression::NumericLiteral(node)) => node,
+            Self::TSEnumMemberName(TSEnumMemberName::StaticNumericLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::NumericLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSEnumMemberName(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`BigIntLiteral`], passed to [`Traverse::replace_big_int_literal`].
+///
+/// [`Traverse::replace_big_int_literal`]: crate::Traverse::replace_big_int_literal
+pub enum BigIntLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> BigIntLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BigIntLiteral`].
+    pub fn node(&mut self) -> &mut BigIntLiteral<'a> {
+        match self {
+            Self::Expression(Expression::BigIntLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::BigIntLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`RegExpLiteral`], passed to [`Traverse::replace_reg_exp_literal`].
+///
+/// [`Traverse::replace_reg_exp_literal`]: crate::Traverse::replace_reg_exp_literal
+pub enum RegExpLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> RegExpLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`RegExpLiteral`].
+    pub fn node(&mut self) -> &mut RegExpLiteral<'a> {
+        match self {
+            Self::Expression(Expression::RegExpLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::RegExpLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`StringLiteral`], passed to [`Traverse::replace_string_literal`].
+///
+/// [`Traverse::replace_string_literal`]: crate::Traverse::replace_string_literal
+pub enum StringLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    ImportAttributeKey(&'s mut ImportAttributeKey<'a>),
+    ModuleExportName(&'s mut ModuleExportName<'a>),
+    JSXAttributeValue(&'s mut JSXAttributeValue<'a>),
+    TSEnumMemberName(&'s mut TSEnumMemberName<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+    TSModuleDeclarationName(&'s mut TSModuleDeclarationName<'a>),
+    TSImportAttributeName(&'s mut TSImportAttributeName<'a>),
+}
+
+impl<'s, 'a> StringLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`StringLiteral`].
+    pub fn node(&mut self) -> &mut StringLiteral<'a> {
+        match self {
+            Self::Expression(Expression::StringLiteral(node)) => node,
+            Self::ImportAttributeKey(ImportAttributeKey::StringLiteral(node)) => node,
+            Self::ModuleExportName(ModuleExportName::StringLiteral(node)) => node,
+            Self::JSXAttributeValue(JSXAttributeValue::StringLiteral(node)) => node,
+            Self::TSEnumMemberName(TSEnumMemberName::StaticStringLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::StringLiteral(node)) => node,
+            Self::TSModuleDeclarationName(TSModuleDeclarationName::StringLiteral(node)) => node,
+            Self::TSImportAttributeName(TSImportAttributeName::StringLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::ImportAttributeKey(_) => unreachable!(),
+            Self::ModuleExportName(_) => unreachable!(),
+            Self::JSXAttributeValue(_) => unreachable!(),
+            Self::TSEnumMemberName(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+            Self::TSModuleDeclarationName(_) => unreachable!(),
+            Self::TSImportAttributeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TemplateLiteral`], passed to [`Traverse::replace_template_literal`].
+///
+/// [`Traverse::replace_template_literal`]: crate::Traverse::replace_template_literal
+pub enum TemplateLiteralSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSEnumMemberName(&'s mut TSEnumMemberName<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> TemplateLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TemplateLiteral`].
+    pub fn node(&mut self) -> &mut TemplateLiteral<'a> {
+        match self {
+            Self::Expression(Expression::TemplateLiteral(node)) => node,
+            Self::TSEnumMemberName(TSEnumMemberName::StaticTemplateLiteral(node)) => node,
+            Self::TSLiteral(TSLiteral::TemplateLiteral(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSEnumMemberName(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`IdentifierReference`], passed to [`Traverse::replace_identifier_reference`].
+///
+/// [`Traverse::replace_identifier_reference`]: crate::Traverse::replace_identifier_reference
+pub enum IdentifierReferenceSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+    ModuleExportName(&'s mut ModuleExportName<'a>),
+    JSXElementName(&'s mut JSXElementName<'a>),
+    JSXMemberExpressionObject(&'s mut JSXMemberExpressionObject<'a>),
+    TSTypeName(&'s mut TSTypeName<'a>),
+}
+
+impl<'s, 'a> IdentifierReferenceSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`IdentifierReference`].
+    pub fn node(&mut self) -> &mut IdentifierReference<'a> {
+        match self {
+            Self::Expression(Expression::Identifier(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                node,
+            )) => node,
+            Self::ModuleExportName(ModuleExportName::IdentifierReference(node)) => node,
+            Self::JSXElementName(JSXElementName::IdentifierReference(node)) => node,
+            Self::JSXMemberExpressionObject(JSXMemberExpressionObject::IdentifierReference(
+                node,
+            )) => node,
+            Self::TSTypeName(TSTypeName::IdentifierReference(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+            Self::ModuleExportName(_) => unreachable!(),
+            Self::JSXElementName(_) => unreachable!(),
+            Self::JSXMemberExpressionObject(_) => unreachable!(),
+            Self::TSTypeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`MetaProperty`], passed to [`Traverse::replace_meta_property`].
+///
+/// [`Traverse::replace_meta_property`]: crate::Traverse::replace_meta_property
+pub enum MetaPropertySlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> MetaPropertySlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`MetaProperty`].
+    pub fn node(&mut self) -> &mut MetaProperty<'a> {
+        match self {
+            Self::Expression(Expression::MetaProperty(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`Super`], passed to [`Traverse::replace_super`].
+///
+/// [`Traverse::replace_super`]: crate::Traverse::replace_super
+pub enum SuperSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> SuperSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`Super`].
+    pub fn node(&mut self) -> &mut Super {
+        match self {
+            Self::Expression(Expression::Super(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ArrayExpression`], passed to [`Traverse::replace_array_expression`].
+///
+/// [`Traverse::replace_array_expression`]: crate::Traverse::replace_array_expression
+pub enum ArrayExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ArrayExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ArrayExpression`].
+    pub fn node(&mut self) -> &mut ArrayExpression<'a> {
+        match self {
+            Self::Expression(Expression::ArrayExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ArrowFunctionExpression`], passed to [`Traverse::replace_arrow_function_expression`].
+///
+/// [`Traverse::replace_arrow_function_expression`]: crate::Traverse::replace_arrow_function_expression
+pub enum ArrowFunctionExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ArrowFunctionExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ArrowFunctionExpression`].
+    pub fn node(&mut self) -> &mut ArrowFunctionExpression<'a> {
+        match self {
+            Self::Expression(Expression::ArrowFunctionExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AssignmentExpression`], passed to [`Traverse::replace_assignment_expression`].
+///
+/// [`Traverse::replace_assignment_expression`]: crate::Traverse::replace_assignment_expression
+pub enum AssignmentExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> AssignmentExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AssignmentExpression`].
+    pub fn node(&mut self) -> &mut AssignmentExpression<'a> {
+        match self {
+            Self::Expression(Expression::AssignmentExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AwaitExpression`], passed to [`Traverse::replace_await_expression`].
+///
+/// [`Traverse::replace_await_expression`]: crate::Traverse::replace_await_expression
+pub enum AwaitExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> AwaitExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AwaitExpression`].
+    pub fn node(&mut self) -> &mut AwaitExpression<'a> {
+        match self {
+            Self::Expression(Expression::AwaitExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`BinaryExpression`], passed to [`Traverse::replace_binary_expression`].
+///
+/// [`Traverse::replace_binary_expression`]: crate::Traverse::replace_binary_expression
+pub enum BinaryExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> BinaryExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BinaryExpression`].
+    pub fn node(&mut self) -> &mut BinaryExpression<'a> {
+        match self {
+            Self::Expression(Expression::BinaryExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`CallExpression`], passed to [`Traverse::replace_call_expression`].
+///
+/// [`Traverse::replace_call_expression`]: crate::Traverse::replace_call_expression
+pub enum CallExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    ChainElement(&'s mut ChainElement<'a>),
+}
+
+impl<'s, 'a> CallExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`CallExpression`].
+    pub fn node(&mut self) -> &mut CallExpression<'a> {
+        match self {
+            Self::Expression(Expression::CallExpression(node)) => node,
+            Self::ChainElement(ChainElement::CallExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::ChainElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ChainExpression`], passed to [`Traverse::replace_chain_expression`].
+///
+/// [`Traverse::replace_chain_expression`]: crate::Traverse::replace_chain_expression
+pub enum ChainExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ChainExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ChainExpression`].
+    pub fn node(&mut self) -> &mut ChainExpression<'a> {
+        match self {
+            Self::Expression(Expression::ChainExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`Class`], passed to [`Traverse::replace_class`].
+///
+/// [`Traverse::replace_class`]: crate::Traverse::replace_class
+pub enum ClassSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    Declaration(&'s mut Declaration<'a>),
+    ExportDefaultDeclarationKind(&'s mut ExportDefaultDeclarationKind<'a>),
+}
+
+impl<'s, 'a> ClassSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`Class`].
+    pub fn node(&mut self) -> &mut Class<'a> {
+        match self {
+            Self::Expression(Expression::ClassExpression(node)) => node,
+            Self::Declaration(Declaration::ClassDeclaration(node)) => node,
+            Self::ExportDefaultDeclarationKind(ExportDefaultDeclarationKind::ClassDeclaration(
+                node,
+            )) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::Declaration(_) => unreachable!(),
+            Self::ExportDefaultDeclarationKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ConditionalExpression`], passed to [`Traverse::replace_conditional_expression`].
+///
+/// [`Traverse::replace_conditional_expression`]: crate::Traverse::replace_conditional_expression
+pub enum ConditionalExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ConditionalExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ConditionalExpression`].
+    pub fn node(&mut self) -> &mut ConditionalExpression<'a> {
+        match self {
+            Self::Expression(Expression::ConditionalExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`Function`], passed to [`Traverse::replace_function`].
+///
+/// [`Traverse::replace_function`]: crate::Traverse::replace_function
+pub enum FunctionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    Declaration(&'s mut Declaration<'a>),
+    ExportDefaultDeclarationKind(&'s mut ExportDefaultDeclarationKind<'a>),
+}
+
+impl<'s, 'a> FunctionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`Function`].
+    pub fn node(&mut self) -> &mut Function<'a> {
+        match self {
+            Self::Expression(Expression::FunctionExpression(node)) => node,
+            Self::Declaration(Declaration::FunctionDeclaration(node)) => node,
+            Self::ExportDefaultDeclarationKind(
+                ExportDefaultDeclarationKind::FunctionDeclaration(node),
+            ) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::Declaration(_) => unreachable!(),
+            Self::ExportDefaultDeclarationKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ImportExpression`], passed to [`Traverse::replace_import_expression`].
+///
+/// [`Traverse::replace_import_expression`]: crate::Traverse::replace_import_expression
+pub enum ImportExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ImportExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ImportExpression`].
+    pub fn node(&mut self) -> &mut ImportExpression<'a> {
+        match self {
+            Self::Expression(Expression::ImportExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`LogicalExpression`], passed to [`Traverse::replace_logical_expression`].
+///
+/// [`Traverse::replace_logical_expression`]: crate::Traverse::replace_logical_expression
+pub enum LogicalExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> LogicalExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`LogicalExpression`].
+    pub fn node(&mut self) -> &mut LogicalExpression<'a> {
+        match self {
+            Self::Expression(Expression::LogicalExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`NewExpression`], passed to [`Traverse::replace_new_expression`].
+///
+/// [`Traverse::replace_new_expression`]: crate::Traverse::replace_new_expression
+pub enum NewExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> NewExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`NewExpression`].
+    pub fn node(&mut self) -> &mut NewExpression<'a> {
+        match self {
+            Self::Expression(Expression::NewExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ObjectExpression`], passed to [`Traverse::replace_object_expression`].
+///
+/// [`Traverse::replace_object_expression`]: crate::Traverse::replace_object_expression
+pub enum ObjectExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ObjectExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ObjectExpression`].
+    pub fn node(&mut self) -> &mut ObjectExpression<'a> {
+        match self {
+            Self::Expression(Expression::ObjectExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ParenthesizedExpression`], passed to [`Traverse::replace_parenthesized_expression`].
+///
+/// [`Traverse::replace_parenthesized_expression`]: crate::Traverse::replace_parenthesized_expression
+pub enum ParenthesizedExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> ParenthesizedExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ParenthesizedExpression`].
+    pub fn node(&mut self) -> &mut ParenthesizedExpression<'a> {
+        match self {
+            Self::Expression(Expression::ParenthesizedExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`SequenceExpression`], passed to [`Traverse::replace_sequence_expression`].
+///
+/// [`Traverse::replace_sequence_expression`]: crate::Traverse::replace_sequence_expression
+pub enum SequenceExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> SequenceExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`SequenceExpression`].
+    pub fn node(&mut self) -> &mut SequenceExpression<'a> {
+        match self {
+            Self::Expression(Expression::SequenceExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TaggedTemplateExpression`], passed to [`Traverse::replace_tagged_template_expression`].
+///
+/// [`Traverse::replace_tagged_template_expression`]: crate::Traverse::replace_tagged_template_expression
+pub enum TaggedTemplateExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> TaggedTemplateExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TaggedTemplateExpression`].
+    pub fn node(&mut self) -> &mut TaggedTemplateExpression<'a> {
+        match self {
+            Self::Expression(Expression::TaggedTemplateExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ThisExpression`], passed to [`Traverse::replace_this_expression`].
+///
+/// [`Traverse::replace_this_expression`]: crate::Traverse::replace_this_expression
+pub enum ThisExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    JSXElementName(&'s mut JSXElementName<'a>),
+    JSXMemberExpressionObject(&'s mut JSXMemberExpressionObject<'a>),
+}
+
+impl<'s, 'a> ThisExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ThisExpression`].
+    pub fn node(&mut self) -> &mut ThisExpression {
+        match self {
+            Self::Expression(Expression::ThisExpression(node)) => node,
+            Self::JSXElementName(JSXElementName::ThisExpression(node)) => node,
+            Self::JSXMemberExpressionObject(JSXMemberExpressionObject::ThisExpression(node)) => {
+                node
+            }
+            Self::Expression(_) => unreachable!(),
+            Self::JSXElementName(_) => unreachable!(),
+            Self::JSXMemberExpressionObject(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`UnaryExpression`], passed to [`Traverse::replace_unary_expression`].
+///
+/// [`Traverse::replace_unary_expression`]: crate::Traverse::replace_unary_expression
+pub enum UnaryExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    TSLiteral(&'s mut TSLiteral<'a>),
+}
+
+impl<'s, 'a> UnaryExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`UnaryExpression`].
+    pub fn node(&mut self) -> &mut UnaryExpression<'a> {
+        match self {
+            Self::Expression(Expression::UnaryExpression(node)) => node,
+            Self::TSLiteral(TSLiteral::UnaryExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::TSLiteral(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`UpdateExpression`], passed to [`Traverse::replace_update_expression`].
+///
+/// [`Traverse::replace_update_expression`]: crate::Traverse::replace_update_expression
+pub enum UpdateExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> UpdateExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`UpdateExpression`].
+    pub fn node(&mut self) -> &mut UpdateExpression<'a> {
+        match self {
+            Self::Expression(Expression::UpdateExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`YieldExpression`], passed to [`Traverse::replace_yield_expression`].
+///
+/// [`Traverse::replace_yield_expression`]: crate::Traverse::replace_yield_expression
+pub enum YieldExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> YieldExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`YieldExpression`].
+    pub fn node(&mut self) -> &mut YieldExpression<'a> {
+        match self {
+            Self::Expression(Expression::YieldExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`PrivateInExpression`], passed to [`Traverse::replace_private_in_expression`].
+///
+/// [`Traverse::replace_private_in_expression`]: crate::Traverse::replace_private_in_expression
+pub enum PrivateInExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+}
+
+impl<'s, 'a> PrivateInExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`PrivateInExpression`].
+    pub fn node(&mut self) -> &mut PrivateInExpression<'a> {
+        match self {
+            Self::Expression(Expression::PrivateInExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXElement`], passed to [`Traverse::replace_jsx_element`].
+///
+/// [`Traverse::replace_jsx_element`]: crate::Traverse::replace_jsx_element
+pub enum JSXElementSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    JSXAttributeValue(&'s mut JSXAttributeValue<'a>),
+    JSXChild(&'s mut JSXChild<'a>),
+}
+
+impl<'s, 'a> JSXElementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXElement`].
+    pub fn node(&mut self) -> &mut JSXElement<'a> {
+        match self {
+            Self::Expression(Expression::JSXElement(node)) => node,
+            Self::JSXAttributeValue(JSXAttributeValue::Element(node)) => node,
+            Self::JSXChild(JSXChild::Element(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::JSXAttributeValue(_) => unreachable!(),
+            Self::JSXChild(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXFragment`], passed to [`Traverse::replace_jsx_fragment`].
+///
+/// [`Traverse::replace_jsx_fragment`]: crate::Traverse::replace_jsx_fragment
+pub enum JSXFragmentSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    JSXAttributeValue(&'s mut JSXAttributeValue<'a>),
+    JSXChild(&'s mut JSXChild<'a>),
+}
+
+impl<'s, 'a> JSXFragmentSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXFragment`].
+    pub fn node(&mut self) -> &mut JSXFragment<'a> {
+        match self {
+            Self::Expression(Expression::JSXFragment(node)) => node,
+            Self::JSXAttributeValue(JSXAttributeValue::Fragment(node)) => node,
+            Self::JSXChild(JSXChild::Fragment(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::JSXAttributeValue(_) => unreachable!(),
+            Self::JSXChild(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSAsExpression`], passed to [`Traverse::replace_ts_as_expression`].
+///
+/// [`Traverse::replace_ts_as_expression`]: crate::Traverse::replace_ts_as_expression
+pub enum TSAsExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+}
+
+impl<'s, 'a> TSAsExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSAsExpression`].
+    pub fn node(&mut self) -> &mut TSAsExpression<'a> {
+        match self {
+            Self::Expression(Expression::TSAsExpression(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::TSAsExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSSatisfiesExpression`], passed to [`Traverse::replace_ts_satisfies_expression`].
+///
+/// [`Traverse::replace_ts_satisfies_expression`]: crate::Traverse::replace_ts_satisfies_expression
+pub enum TSSatisfiesExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+}
+
+impl<'s, 'a> TSSatisfiesExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSSatisfiesExpression`].
+    pub fn node(&mut self) -> &mut TSSatisfiesExpression<'a> {
+        match self {
+            Self::Expression(Expression::TSSatisfiesExpression(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::TSSatisfiesExpression(node)) => {
+                node
+            }
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeAssertion`], passed to [`Traverse::replace_ts_type_assertion`].
+///
+/// [`Traverse::replace_ts_type_assertion`]: crate::Traverse::replace_ts_type_assertion
+pub enum TSTypeAssertionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+}
+
+impl<'s, 'a> TSTypeAssertionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeAssertion`].
+    pub fn node(&mut self) -> &mut TSTypeAssertion<'a> {
+        match self {
+            Self::Expression(Expression::TSTypeAssertion(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::TSTypeAssertion(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNonNullExpression`], passed to [`Traverse::replace_ts_non_null_expression`].
+///
+/// [`Traverse::replace_ts_non_null_expression`]: crate::Traverse::replace_ts_non_null_expression
+pub enum TSNonNullExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+}
+
+impl<'s, 'a> TSNonNullExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNonNullExpression`].
+    pub fn node(&mut self) -> &mut TSNonNullExpression<'a> {
+        match self {
+            Self::Expression(Expression::TSNonNullExpression(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::TSNonNullExpression(node)) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSInstantiationExpression`], passed to [`Traverse::replace_ts_instantiation_expression`].
+///
+/// [`Traverse::replace_ts_instantiation_expression`]: crate::Traverse::replace_ts_instantiation_expression
+pub enum TSInstantiationExpressionSlot<'s, 'a> {
+    Expression(&'s mut Expression<'a>),
+    SimpleAssignmentTarget(&'s mut SimpleAssignmentTarget<'a>),
+}
+
+impl<'s, 'a> TSInstantiationExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSInstantiationExpression`].
+    pub fn node(&mut self) -> &mut TSInstantiationExpression<'a> {
+        match self {
+            Self::Expression(Expression::TSInstantiationExpression(node)) => node,
+            Self::SimpleAssignmentTarget(SimpleAssignmentTarget::TSInstantiationExpression(
+                node,
+            )) => node,
+            Self::Expression(_) => unreachable!(),
+            Self::SimpleAssignmentTarget(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`SpreadElement`], passed to [`Traverse::replace_spread_element`].
+///
+/// [`Traverse::replace_spread_element`]: crate::Traverse::replace_spread_element
+pub enum SpreadElementSlot<'s, 'a> {
+    ArrayExpressionElement(&'s mut ArrayExpressionElement<'a>),
+    ObjectPropertyKind(&'s mut ObjectPropertyKind<'a>),
+    Argument(&'s mut Argument<'a>),
+}
+
+impl<'s, 'a> SpreadElementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`SpreadElement`].
+    pub fn node(&mut self) -> &mut SpreadElement<'a> {
+        match self {
+            Self::ArrayExpressionElement(ArrayExpressionElement::SpreadElement(node)) => node,
+            Self::ObjectPropertyKind(ObjectPropertyKind::SpreadProperty(node)) => node,
+            Self::Argument(Argument::SpreadElement(node)) => node,
+            Self::ArrayExpressionElement(_) => unreachable!(),
+            Self::ObjectPropertyKind(_) => unreachable!(),
+            Self::Argument(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`Elision`], passed to [`Traverse::replace_elision`].
+///
+/// [`Traverse::replace_elision`]: crate::Traverse::replace_elision
+pub enum ElisionSlot<'s, 'a> {
+    ArrayExpressionElement(&'s mut ArrayExpressionElement<'a>),
+}
+
+impl<'s, 'a> ElisionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`Elision`].
+    pub fn node(&mut self) -> &mut Elision {
+        match self {
+            Self::ArrayExpressionElement(ArrayExpressionElement::Elision(node)) => node,
+            Self::ArrayExpressionElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ObjectProperty`], passed to [`Traverse::replace_object_property`].
+///
+/// [`Traverse::replace_object_property`]: crate::Traverse::replace_object_property
+pub enum ObjectPropertySlot<'s, 'a> {
+    ObjectPropertyKind(&'s mut ObjectPropertyKind<'a>),
+}
+
+impl<'s, 'a> ObjectPropertySlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ObjectProperty`].
+    pub fn node(&mut self) -> &mut ObjectProperty<'a> {
+        match self {
+            Self::ObjectPropertyKind(ObjectPropertyKind::ObjectProperty(node)) => node,
+            Self::ObjectPropertyKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`IdentifierName`], passed to [`Traverse::replace_identifier_name`].
+///
+/// [`Traverse::replace_identifier_name`]: crate::Traverse::replace_identifier_name
+pub enum IdentifierNameSlot<'s, 'a> {
+    PropertyKey(&'s mut PropertyKey<'a>),
+    ImportAttributeKey(&'s mut ImportAttributeKey<'a>),
+    ModuleExportName(&'s mut ModuleExportName<'a>),
+    TSEnumMemberName(&'s mut TSEnumMemberName<'a>),
+    TSTypePredicateName(&'s mut TSTypePredicateName<'a>),
+    TSModuleDeclarationName(&'s mut TSModuleDeclarationName<'a>),
+    TSImportAttributeName(&'s mut TSImportAttributeName<'a>),
+}
+
+impl<'s, 'a> IdentifierNameSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`IdentifierName`].
+    pub fn node(&mut self) -> &mut IdentifierName<'a> {
+        match self {
+            Self::PropertyKey(PropertyKey::StaticIdentifier(node)) => node,
+            Self::ImportAttributeKey(ImportAttributeKey::Identifier(node)) => node,
+            Self::ModuleExportName(ModuleExportName::IdentifierName(node)) => node,
+            Self::TSEnumMemberName(TSEnumMemberName::StaticIdentifier(node)) => node,
+            Self::TSTypePredicateName(TSTypePredicateName::Identifier(node)) => node,
+            Self::TSModuleDeclarationName(TSModuleDeclarationName::Identifier(node)) => node,
+            Self::TSImportAttributeName(TSImportAttributeName::Identifier(node)) => node,
+            Self::PropertyKey(_) => unreachable!(),
+            Self::ImportAttributeKey(_) => unreachable!(),
+            Self::ModuleExportName(_) => unreachable!(),
+            Self::TSEnumMemberName(_) => unreachable!(),
+            Self::TSTypePredicateName(_) => unreachable!(),
+            Self::TSModuleDeclarationName(_) => unreachable!(),
+            Self::TSImportAttributeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`PrivateIdentifier`], passed to [`Traverse::replace_private_identifier`].
+///
+/// [`Traverse::replace_private_identifier`]: crate::Traverse::replace_private_identifier
+pub enum PrivateIdentifierSlot<'s, 'a> {
+    PropertyKey(&'s mut PropertyKey<'a>),
+}
+
+impl<'s, 'a> PrivateIdentifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`PrivateIdentifier`].
+    pub fn node(&mut self) -> &mut PrivateIdentifier<'a> {
+        match self {
+            Self::PropertyKey(PropertyKey::PrivateIdentifier(node)) => node,
+            Self::PropertyKey(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ComputedMemberExpression`], passed to [`Traverse::replace_computed_member_expression`].
+///
+/// [`Traverse::replace_computed_member_expression`]: crate::Traverse::replace_computed_member_expression
+pub enum ComputedMemberExpressionSlot<'s, 'a> {
+    MemberExpression(&'s mut MemberExpression<'a>),
+}
+
+impl<'s, 'a> ComputedMemberExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ComputedMemberExpression`].
+    pub fn node(&mut self) -> &mut ComputedMemberExpression<'a> {
+        match self {
+            Self::MemberExpression(MemberExpression::ComputedMemberExpression(node)) => node,
+            Self::MemberExpression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`StaticMemberExpression`], passed to [`Traverse::replace_static_member_expression`].
+///
+/// [`Traverse::replace_static_member_expression`]: crate::Traverse::replace_static_member_expression
+pub enum StaticMemberExpressionSlot<'s, 'a> {
+    MemberExpression(&'s mut MemberExpression<'a>),
+}
+
+impl<'s, 'a> StaticMemberExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`StaticMemberExpression`].
+    pub fn node(&mut self) -> &mut StaticMemberExpression<'a> {
+        match self {
+            Self::MemberExpression(MemberExpression::StaticMemberExpression(node)) => node,
+            Self::MemberExpression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`PrivateFieldExpression`], passed to [`Traverse::replace_private_field_expression`].
+///
+/// [`Traverse::replace_private_field_expression`]: crate::Traverse::replace_private_field_expression
+pub enum PrivateFieldExpressionSlot<'s, 'a> {
+    MemberExpression(&'s mut MemberExpression<'a>),
+}
+
+impl<'s, 'a> PrivateFieldExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`PrivateFieldExpression`].
+    pub fn node(&mut self) -> &mut PrivateFieldExpression<'a> {
+        match self {
+            Self::MemberExpression(MemberExpression::PrivateFieldExpression(node)) => node,
+            Self::MemberExpression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ArrayAssignmentTarget`], passed to [`Traverse::replace_array_assignment_target`].
+///
+/// [`Traverse::replace_array_assignment_target`]: crate::Traverse::replace_array_assignment_target
+pub enum ArrayAssignmentTargetSlot<'s, 'a> {
+    AssignmentTargetPattern(&'s mut AssignmentTargetPattern<'a>),
+}
+
+impl<'s, 'a> ArrayAssignmentTargetSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ArrayAssignmentTarget`].
+    pub fn node(&mut self) -> &mut ArrayAssignmentTarget<'a> {
+        match self {
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(node)) => {
+                node
+            }
+            Self::AssignmentTargetPattern(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ObjectAssignmentTarget`], passed to [`Traverse::replace_object_assignment_target`].
+///
+/// [`Traverse::replace_object_assignment_target`]: crate::Traverse::replace_object_assignment_target
+pub enum ObjectAssignmentTargetSlot<'s, 'a> {
+    AssignmentTargetPattern(&'s mut AssignmentTargetPattern<'a>),
+}
+
+impl<'s, 'a> ObjectAssignmentTargetSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ObjectAssignmentTarget`].
+    pub fn node(&mut self) -> &mut ObjectAssignmentTarget<'a> {
+        match self {
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ObjectAssignmentTarget(
+                node,
+            )) => node,
+            Self::AssignmentTargetPattern(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AssignmentTargetWithDefault`], passed to [`Traverse::replace_assignment_target_with_default`].
+///
+/// [`Traverse::replace_assignment_target_with_default`]: crate::Traverse::replace_assignment_target_with_default
+pub enum AssignmentTargetWithDefaultSlot<'s, 'a> {
+    AssignmentTargetMaybeDefault(&'s mut AssignmentTargetMaybeDefault<'a>),
+}
+
+impl<'s, 'a> AssignmentTargetWithDefaultSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AssignmentTargetWithDefault`].
+    pub fn node(&mut self) -> &mut AssignmentTargetWithDefault<'a> {
+        match self {
+            Self::AssignmentTargetMaybeDefault(
+                AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(node),
+            ) => node,
+            Self::AssignmentTargetMaybeDefault(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AssignmentTargetPropertyIdentifier`], passed to [`Traverse::replace_assignment_target_property_identifier`].
+///
+/// [`Traverse::replace_assignment_target_property_identifier`]: crate::Traverse::replace_assignment_target_property_identifier
+pub enum AssignmentTargetPropertyIdentifierSlot<'s, 'a> {
+    AssignmentTargetProperty(&'s mut AssignmentTargetProperty<'a>),
+}
+
+impl<'s, 'a> AssignmentTargetPropertyIdentifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AssignmentTargetPropertyIdentifier`].
+    pub fn node(&mut self) -> &mut AssignmentTargetPropertyIdentifier<'a> {
+        match self {
+            Self::AssignmentTargetProperty(
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(node),
+            ) => node,
+            Self::AssignmentTargetProperty(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AssignmentTargetPropertyProperty`], passed to [`Traverse::replace_assignment_target_property_property`].
+///
+/// [`Traverse::replace_assignment_target_property_property`]: crate::Traverse::replace_assignment_target_property_property
+pub enum AssignmentTargetPropertyPropertySlot<'s, 'a> {
+    AssignmentTargetProperty(&'s mut AssignmentTargetProperty<'a>),
+}
+
+impl<'s, 'a> AssignmentTargetPropertyPropertySlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AssignmentTargetPropertyProperty`].
+    pub fn node(&mut self) -> &mut AssignmentTargetPropertyProperty<'a> {
+        match self {
+            Self::AssignmentTargetProperty(
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(node),
+            ) => node,
+            Self::AssignmentTargetProperty(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`BlockStatement`], passed to [`Traverse::replace_block_statement`].
+///
+/// [`Traverse::replace_block_statement`]: crate::Traverse::replace_block_statement
+pub enum BlockStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> BlockStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BlockStatement`].
+    pub fn node(&mut self) -> &mut BlockStatement<'a> {
+        match self {
+            Self::Statement(Statement::BlockStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`BreakStatement`], passed to [`Traverse::replace_break_statement`].
+///
+/// [`Traverse::replace_break_statement`]: crate::Traverse::replace_break_statement
+pub enum BreakStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> BreakStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BreakStatement`].
+    pub fn node(&mut self) -> &mut BreakStatement<'a> {
+        match self {
+            Self::Statement(Statement::BreakStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ContinueStatement`], passed to [`Traverse::replace_continue_statement`].
+///
+/// [`Traverse::replace_continue_statement`]: crate::Traverse::replace_continue_statement
+pub enum ContinueStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ContinueStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ContinueStatement`].
+    pub fn node(&mut self) -> &mut ContinueStatement<'a> {
+        match self {
+            Self::Statement(Statement::ContinueStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`DebuggerStatement`], passed to [`Traverse::replace_debugger_statement`].
+///
+/// [`Traverse::replace_debugger_statement`]: crate::Traverse::replace_debugger_statement
+pub enum DebuggerStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> DebuggerStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`DebuggerStatement`].
+    pub fn node(&mut self) -> &mut DebuggerStatement {
+        match self {
+            Self::Statement(Statement::DebuggerStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`DoWhileStatement`], passed to [`Traverse::replace_do_while_statement`].
+///
+/// [`Traverse::replace_do_while_statement`]: crate::Traverse::replace_do_while_statement
+pub enum DoWhileStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> DoWhileStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`DoWhileStatement`].
+    pub fn node(&mut self) -> &mut DoWhileStatement<'a> {
+        match self {
+            Self::Statement(Statement::DoWhileStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`EmptyStatement`], passed to [`Traverse::replace_empty_statement`].
+///
+/// [`Traverse::replace_empty_statement`]: crate::Traverse::replace_empty_statement
+pub enum EmptyStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> EmptyStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`EmptyStatement`].
+    pub fn node(&mut self) -> &mut EmptyStatement {
+        match self {
+            Self::Statement(Statement::EmptyStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ExpressionStatement`], passed to [`Traverse::replace_expression_statement`].
+///
+/// [`Traverse::replace_expression_statement`]: crate::Traverse::replace_expression_statement
+pub enum ExpressionStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ExpressionStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ExpressionStatement`].
+    pub fn node(&mut self) -> &mut ExpressionStatement<'a> {
+        match self {
+            Self::Statement(Statement::ExpressionStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ForInStatement`], passed to [`Traverse::replace_for_in_statement`].
+///
+/// [`Traverse::replace_for_in_statement`]: crate::Traverse::replace_for_in_statement
+pub enum ForInStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ForInStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ForInStatement`].
+    pub fn node(&mut self) -> &mut ForInStatement<'a> {
+        match self {
+            Self::Statement(Statement::ForInStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ForOfStatement`], passed to [`Traverse::replace_for_of_statement`].
+///
+/// [`Traverse::replace_for_of_statement`]: crate::Traverse::replace_for_of_statement
+pub enum ForOfStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ForOfStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ForOfStatement`].
+    pub fn node(&mut self) -> &mut ForOfStatement<'a> {
+        match self {
+            Self::Statement(Statement::ForOfStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ForStatement`], passed to [`Traverse::replace_for_statement`].
+///
+/// [`Traverse::replace_for_statement`]: crate::Traverse::replace_for_statement
+pub enum ForStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ForStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ForStatement`].
+    pub fn node(&mut self) -> &mut ForStatement<'a> {
+        match self {
+            Self::Statement(Statement::ForStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`IfStatement`], passed to [`Traverse::replace_if_statement`].
+///
+/// [`Traverse::replace_if_statement`]: crate::Traverse::replace_if_statement
+pub enum IfStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> IfStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`IfStatement`].
+    pub fn node(&mut self) -> &mut IfStatement<'a> {
+        match self {
+            Self::Statement(Statement::IfStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`LabeledStatement`], passed to [`Traverse::replace_labeled_statement`].
+///
+/// [`Traverse::replace_labeled_statement`]: crate::Traverse::replace_labeled_statement
+pub enum LabeledStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> LabeledStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`LabeledStatement`].
+    pub fn node(&mut self) -> &mut LabeledStatement<'a> {
+        match self {
+            Self::Statement(Statement::LabeledStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ReturnStatement`], passed to [`Traverse::replace_return_statement`].
+///
+/// [`Traverse::replace_return_statement`]: crate::Traverse::replace_return_statement
+pub enum ReturnStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ReturnStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ReturnStatement`].
+    pub fn node(&mut self) -> &mut ReturnStatement<'a> {
+        match self {
+            Self::Statement(Statement::ReturnStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`SwitchStatement`], passed to [`Traverse::replace_switch_statement`].
+///
+/// [`Traverse::replace_switch_statement`]: crate::Traverse::replace_switch_statement
+pub enum SwitchStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> SwitchStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`SwitchStatement`].
+    pub fn node(&mut self) -> &mut SwitchStatement<'a> {
+        match self {
+            Self::Statement(Statement::SwitchStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ThrowStatement`], passed to [`Traverse::replace_throw_statement`].
+///
+/// [`Traverse::replace_throw_statement`]: crate::Traverse::replace_throw_statement
+pub enum ThrowStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> ThrowStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ThrowStatement`].
+    pub fn node(&mut self) -> &mut ThrowStatement<'a> {
+        match self {
+            Self::Statement(Statement::ThrowStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TryStatement`], passed to [`Traverse::replace_try_statement`].
+///
+/// [`Traverse::replace_try_statement`]: crate::Traverse::replace_try_statement
+pub enum TryStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> TryStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TryStatement`].
+    pub fn node(&mut self) -> &mut TryStatement<'a> {
+        match self {
+            Self::Statement(Statement::TryStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`WhileStatement`], passed to [`Traverse::replace_while_statement`].
+///
+/// [`Traverse::replace_while_statement`]: crate::Traverse::replace_while_statement
+pub enum WhileStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> WhileStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`WhileStatement`].
+    pub fn node(&mut self) -> &mut WhileStatement<'a> {
+        match self {
+            Self::Statement(Statement::WhileStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`WithStatement`], passed to [`Traverse::replace_with_statement`].
+///
+/// [`Traverse::replace_with_statement`]: crate::Traverse::replace_with_statement
+pub enum WithStatementSlot<'s, 'a> {
+    Statement(&'s mut Statement<'a>),
+}
+
+impl<'s, 'a> WithStatementSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`WithStatement`].
+    pub fn node(&mut self) -> &mut WithStatement<'a> {
+        match self {
+            Self::Statement(Statement::WithStatement(node)) => node,
+            Self::Statement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`VariableDeclaration`], passed to [`Traverse::replace_variable_declaration`].
+///
+/// [`Traverse::replace_variable_declaration`]: crate::Traverse::replace_variable_declaration
+pub enum VariableDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+    ForStatementInit(&'s mut ForStatementInit<'a>),
+    ForStatementLeft(&'s mut ForStatementLeft<'a>),
+}
+
+impl<'s, 'a> VariableDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`VariableDeclaration`].
+    pub fn node(&mut self) -> &mut VariableDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::VariableDeclaration(node)) => node,
+            Self::ForStatementInit(ForStatementInit::VariableDeclaration(node)) => node,
+            Self::ForStatementLeft(ForStatementLeft::VariableDeclaration(node)) => node,
+            Self::Declaration(_) => unreachable!(),
+            Self::ForStatementInit(_) => unreachable!(),
+            Self::ForStatementLeft(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeAliasDeclaration`], passed to [`Traverse::replace_ts_type_alias_declaration`].
+///
+/// [`Traverse::replace_ts_type_alias_declaration`]: crate::Traverse::replace_ts_type_alias_declaration
+pub enum TSTypeAliasDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+}
+
+impl<'s, 'a> TSTypeAliasDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeAliasDeclaration`].
+    pub fn node(&mut self) -> &mut TSTypeAliasDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::TSTypeAliasDeclaration(node)) => node,
+            Self::Declaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSInterfaceDeclaration`], passed to [`Traverse::replace_ts_interface_declaration`].
+///
+/// [`Traverse::replace_ts_interface_declaration`]: crate::Traverse::replace_ts_interface_declaration
+pub enum TSInterfaceDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+    ExportDefaultDeclarationKind(&'s mut ExportDefaultDeclarationKind<'a>),
+}
+
+impl<'s, 'a> TSInterfaceDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSInterfaceDeclaration`].
+    pub fn node(&mut self) -> &mut TSInterfaceDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::TSInterfaceDeclaration(node)) => node,
+            Self::ExportDefaultDeclarationKind(
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(node),
+            ) => node,
+            Self::Declaration(_) => unreachable!(),
+            Self::ExportDefaultDeclarationKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSEnumDeclaration`], passed to [`Traverse::replace_ts_enum_declaration`].
+///
+/// [`Traverse::replace_ts_enum_declaration`]: crate::Traverse::replace_ts_enum_declaration
+pub enum TSEnumDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+}
+
+impl<'s, 'a> TSEnumDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSEnumDeclaration`].
+    pub fn node(&mut self) -> &mut TSEnumDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::TSEnumDeclaration(node)) => node,
+            Self::Declaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSModuleDeclaration`], passed to [`Traverse::replace_ts_module_declaration`].
+///
+/// [`Traverse::replace_ts_module_declaration`]: crate::Traverse::replace_ts_module_declaration
+pub enum TSModuleDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+    TSModuleDeclarationBody(&'s mut TSModuleDeclarationBody<'a>),
+}
+
+impl<'s, 'a> TSModuleDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSModuleDeclaration`].
+    pub fn node(&mut self) -> &mut TSModuleDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::TSModuleDeclaration(node)) => node,
+            Self::TSModuleDeclarationBody(TSModuleDeclarationBody::TSModuleDeclaration(node)) => {
+                node
+            }
+            Self::Declaration(_) => unreachable!(),
+            Self::TSModuleDeclarationBody(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSImportEqualsDeclaration`], passed to [`Traverse::replace_ts_import_equals_declaration`].
+///
+/// [`Traverse::replace_ts_import_equals_declaration`]: crate::Traverse::replace_ts_import_equals_declaration
+pub enum TSImportEqualsDeclarationSlot<'s, 'a> {
+    Declaration(&'s mut Declaration<'a>),
+}
+
+impl<'s, 'a> TSImportEqualsDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSImportEqualsDeclaration`].
+    pub fn node(&mut self) -> &mut TSImportEqualsDeclaration<'a> {
+        match self {
+            Self::Declaration(Declaration::TSImportEqualsDeclaration(node)) => node,
+            Self::Declaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`BindingIdentifier`], passed to [`Traverse::replace_binding_identifier`].
+///
+/// [`Traverse::replace_binding_identifier`]: crate::Traverse::replace_binding_identifier
+pub enum BindingIdentifierSlot<'s, 'a> {
+    BindingPatternKind(&'s mut BindingPatternKind<'a>),
+}
+
+impl<'s, 'a> BindingIdentifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`BindingIdentifier`].
+    pub fn node(&mut self) -> &mut BindingIdentifier<'a> {
+        match self {
+            Self::BindingPatternKind(BindingPatternKind::BindingIdentifier(node)) => node,
+            Self::BindingPatternKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ObjectPattern`], passed to [`Traverse::replace_object_pattern`].
+///
+/// [`Traverse::replace_object_pattern`]: crate::Traverse::replace_object_pattern
+pub enum ObjectPatternSlot<'s, 'a> {
+    BindingPatternKind(&'s mut BindingPatternKind<'a>),
+}
+
+impl<'s, 'a> ObjectPatternSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ObjectPattern`].
+    pub fn node(&mut self) -> &mut ObjectPattern<'a> {
+        match self {
+            Self::BindingPatternKind(BindingPatternKind::ObjectPattern(node)) => node,
+            Self::BindingPatternKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ArrayPattern`], passed to [`Traverse::replace_array_pattern`].
+///
+/// [`Traverse::replace_array_pattern`]: crate::Traverse::replace_array_pattern
+pub enum ArrayPatternSlot<'s, 'a> {
+    BindingPatternKind(&'s mut BindingPatternKind<'a>),
+}
+
+impl<'s, 'a> ArrayPatternSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ArrayPattern`].
+    pub fn node(&mut self) -> &mut ArrayPattern<'a> {
+        match self {
+            Self::BindingPatternKind(BindingPatternKind::ArrayPattern(node)) => node,
+            Self::BindingPatternKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AssignmentPattern`], passed to [`Traverse::replace_assignment_pattern`].
+///
+/// [`Traverse::replace_assignment_pattern`]: crate::Traverse::replace_assignment_pattern
+pub enum AssignmentPatternSlot<'s, 'a> {
+    BindingPatternKind(&'s mut BindingPatternKind<'a>),
+}
+
+impl<'s, 'a> AssignmentPatternSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AssignmentPattern`].
+    pub fn node(&mut self) -> &mut AssignmentPattern<'a> {
+        match self {
+            Self::BindingPatternKind(BindingPatternKind::AssignmentPattern(node)) => node,
+            Self::BindingPatternKind(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`StaticBlock`], passed to [`Traverse::replace_static_block`].
+///
+/// [`Traverse::replace_static_block`]: crate::Traverse::replace_static_block
+pub enum StaticBlockSlot<'s, 'a> {
+    ClassElement(&'s mut ClassElement<'a>),
+}
+
+impl<'s, 'a> StaticBlockSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`StaticBlock`].
+    pub fn node(&mut self) -> &mut StaticBlock<'a> {
+        match self {
+            Self::ClassElement(ClassElement::StaticBlock(node)) => node,
+            Self::ClassElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`MethodDefinition`], passed to [`Traverse::replace_method_definition`].
+///
+/// [`Traverse::replace_method_definition`]: crate::Traverse::replace_method_definition
+pub enum MethodDefinitionSlot<'s, 'a> {
+    ClassElement(&'s mut ClassElement<'a>),
+}
+
+impl<'s, 'a> MethodDefinitionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`MethodDefinition`].
+    pub fn node(&mut self) -> &mut MethodDefinition<'a> {
+        match self {
+            Self::ClassElement(ClassElement::MethodDefinition(node)) => node,
+            Self::ClassElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`PropertyDefinition`], passed to [`Traverse::replace_property_definition`].
+///
+/// [`Traverse::replace_property_definition`]: crate::Traverse::replace_property_definition
+pub enum PropertyDefinitionSlot<'s, 'a> {
+    ClassElement(&'s mut ClassElement<'a>),
+}
+
+impl<'s, 'a> PropertyDefinitionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`PropertyDefinition`].
+    pub fn node(&mut self) -> &mut PropertyDefinition<'a> {
+        match self {
+            Self::ClassElement(ClassElement::PropertyDefinition(node)) => node,
+            Self::ClassElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`AccessorProperty`], passed to [`Traverse::replace_accessor_property`].
+///
+/// [`Traverse::replace_accessor_property`]: crate::Traverse::replace_accessor_property
+pub enum AccessorPropertySlot<'s, 'a> {
+    ClassElement(&'s mut ClassElement<'a>),
+}
+
+impl<'s, 'a> AccessorPropertySlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`AccessorProperty`].
+    pub fn node(&mut self) -> &mut AccessorProperty<'a> {
+        match self {
+            Self::ClassElement(ClassElement::AccessorProperty(node)) => node,
+            Self::ClassElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSIndexSignature`], passed to [`Traverse::replace_ts_index_signature`].
+///
+/// [`Traverse::replace_ts_index_signature`]: crate::Traverse::replace_ts_index_signature
+pub enum TSIndexSignatureSlot<'s, 'a> {
+    ClassElement(&'s mut ClassElement<'a>),
+    TSSignature(&'s mut TSSignature<'a>),
+}
+
+impl<'s, 'a> TSIndexSignatureSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSIndexSignature`].
+    pub fn node(&mut self) -> &mut TSIndexSignature<'a> {
+        match self {
+            Self::ClassElement(ClassElement::TSIndexSignature(node)) => node,
+            Self::TSSignature(TSSignature::TSIndexSignature(node)) => node,
+            Self::ClassElement(_) => unreachable!(),
+            Self::TSSignature(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ImportDeclaration`], passed to [`Traverse::replace_import_declaration`].
+///
+/// [`Traverse::replace_import_declaration`]: crate::Traverse::replace_import_declaration
+pub enum ImportDeclarationSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> ImportDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ImportDeclaration`].
+    pub fn node(&mut self) -> &mut ImportDeclaration<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ExportAllDeclaration`], passed to [`Traverse::replace_export_all_declaration`].
+///
+/// [`Traverse::replace_export_all_declaration`]: crate::Traverse::replace_export_all_declaration
+pub enum ExportAllDeclarationSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> ExportAllDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ExportAllDeclaration`].
+    pub fn node(&mut self) -> &mut ExportAllDeclaration<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::ExportAllDeclaration(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ExportDefaultDeclaration`], passed to [`Traverse::replace_export_default_declaration`].
+///
+/// [`Traverse::replace_export_default_declaration`]: crate::Traverse::replace_export_default_declaration
+pub enum ExportDefaultDeclarationSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> ExportDefaultDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ExportDefaultDeclaration`].
+    pub fn node(&mut self) -> &mut ExportDefaultDeclaration<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ExportNamedDeclaration`], passed to [`Traverse::replace_export_named_declaration`].
+///
+/// [`Traverse::replace_export_named_declaration`]: crate::Traverse::replace_export_named_declaration
+pub enum ExportNamedDeclarationSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> ExportNamedDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ExportNamedDeclaration`].
+    pub fn node(&mut self) -> &mut ExportNamedDeclaration<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::ExportNamedDeclaration(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSExportAssignment`], passed to [`Traverse::replace_ts_export_assignment`].
+///
+/// [`Traverse::replace_ts_export_assignment`]: crate::Traverse::replace_ts_export_assignment
+pub enum TSExportAssignmentSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> TSExportAssignmentSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSExportAssignment`].
+    pub fn node(&mut self) -> &mut TSExportAssignment<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::TSExportAssignment(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNamespaceExportDeclaration`], passed to [`Traverse::replace_ts_namespace_export_declaration`].
+///
+/// [`Traverse::replace_ts_namespace_export_declaration`]: crate::Traverse::replace_ts_namespace_export_declaration
+pub enum TSNamespaceExportDeclarationSlot<'s, 'a> {
+    ModuleDeclaration(&'s mut ModuleDeclaration<'a>),
+}
+
+impl<'s, 'a> TSNamespaceExportDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNamespaceExportDeclaration`].
+    pub fn node(&mut self) -> &mut TSNamespaceExportDeclaration<'a> {
+        match self {
+            Self::ModuleDeclaration(ModuleDeclaration::TSNamespaceExportDeclaration(node)) => node,
+            Self::ModuleDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ImportSpecifier`], passed to [`Traverse::replace_import_specifier`].
+///
+/// [`Traverse::replace_import_specifier`]: crate::Traverse::replace_import_specifier
+pub enum ImportSpecifierSlot<'s, 'a> {
+    ImportDeclarationSpecifier(&'s mut ImportDeclarationSpecifier<'a>),
+}
+
+impl<'s, 'a> ImportSpecifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ImportSpecifier`].
+    pub fn node(&mut self) -> &mut ImportSpecifier<'a> {
+        match self {
+            Self::ImportDeclarationSpecifier(ImportDeclarationSpecifier::ImportSpecifier(node)) => {
+                node
+            }
+            Self::ImportDeclarationSpecifier(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ImportDefaultSpecifier`], passed to [`Traverse::replace_import_default_specifier`].
+///
+/// [`Traverse::replace_import_default_specifier`]: crate::Traverse::replace_import_default_specifier
+pub enum ImportDefaultSpecifierSlot<'s, 'a> {
+    ImportDeclarationSpecifier(&'s mut ImportDeclarationSpecifier<'a>),
+}
+
+impl<'s, 'a> ImportDefaultSpecifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ImportDefaultSpecifier`].
+    pub fn node(&mut self) -> &mut ImportDefaultSpecifier<'a> {
+        match self {
+            Self::ImportDeclarationSpecifier(
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(node),
+            ) => node,
+            Self::ImportDeclarationSpecifier(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`ImportNamespaceSpecifier`], passed to [`Traverse::replace_import_namespace_specifier`].
+///
+/// [`Traverse::replace_import_namespace_specifier`]: crate::Traverse::replace_import_namespace_specifier
+pub enum ImportNamespaceSpecifierSlot<'s, 'a> {
+    ImportDeclarationSpecifier(&'s mut ImportDeclarationSpecifier<'a>),
+}
+
+impl<'s, 'a> ImportNamespaceSpecifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`ImportNamespaceSpecifier`].
+    pub fn node(&mut self) -> &mut ImportNamespaceSpecifier<'a> {
+        match self {
+            Self::ImportDeclarationSpecifier(
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(node),
+            ) => node,
+            Self::ImportDeclarationSpecifier(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXIdentifier`], passed to [`Traverse::replace_jsx_identifier`].
+///
+/// [`Traverse::replace_jsx_identifier`]: crate::Traverse::replace_jsx_identifier
+pub enum JSXIdentifierSlot<'s, 'a> {
+    JSXElementName(&'s mut JSXElementName<'a>),
+    JSXAttributeName(&'s mut JSXAttributeName<'a>),
+}
+
+impl<'s, 'a> JSXIdentifierSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXIdentifier`].
+    pub fn node(&mut self) -> &mut JSXIdentifier<'a> {
+        match self {
+            Self::JSXElementName(JSXElementName::Identifier(node)) => node,
+            Self::JSXAttributeName(JSXAttributeName::Identifier(node)) => node,
+            Self::JSXElementName(_) => unreachable!(),
+            Self::JSXAttributeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXNamespacedName`], passed to [`Traverse::replace_jsx_namespaced_name`].
+///
+/// [`Traverse::replace_jsx_namespaced_name`]: crate::Traverse::replace_jsx_namespaced_name
+pub enum JSXNamespacedNameSlot<'s, 'a> {
+    JSXElementName(&'s mut JSXElementName<'a>),
+    JSXAttributeName(&'s mut JSXAttributeName<'a>),
+}
+
+impl<'s, 'a> JSXNamespacedNameSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXNamespacedName`].
+    pub fn node(&mut self) -> &mut JSXNamespacedName<'a> {
+        match self {
+            Self::JSXElementName(JSXElementName::NamespacedName(node)) => node,
+            Self::JSXAttributeName(JSXAttributeName::NamespacedName(node)) => node,
+            Self::JSXElementName(_) => unreachable!(),
+            Self::JSXAttributeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXMemberExpression`], passed to [`Traverse::replace_jsx_member_expression`].
+///
+/// [`Traverse::replace_jsx_member_expression`]: crate::Traverse::replace_jsx_member_expression
+pub enum JSXMemberExpressionSlot<'s, 'a> {
+    JSXElementName(&'s mut JSXElementName<'a>),
+    JSXMemberExpressionObject(&'s mut JSXMemberExpressionObject<'a>),
+}
+
+impl<'s, 'a> JSXMemberExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXMemberExpression`].
+    pub fn node(&mut self) -> &mut JSXMemberExpression<'a> {
+        match self {
+            Self::JSXElementName(JSXElementName::MemberExpression(node)) => node,
+            Self::JSXMemberExpressionObject(JSXMemberExpressionObject::MemberExpression(node)) => {
+                node
+            }
+            Self::JSXElementName(_) => unreachable!(),
+            Self::JSXMemberExpressionObject(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXEmptyExpression`], passed to [`Traverse::replace_jsx_empty_expression`].
+///
+/// [`Traverse::replace_jsx_empty_expression`]: crate::Traverse::replace_jsx_empty_expression
+pub enum JSXEmptyExpressionSlot<'s, 'a> {
+    JSXExpression(&'s mut JSXExpression<'a>),
+}
+
+impl<'s, 'a> JSXEmptyExpressionSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXEmptyExpression`].
+    pub fn node(&mut self) -> &mut JSXEmptyExpression {
+        match self {
+            Self::JSXExpression(JSXExpression::EmptyExpression(node)) => node,
+            Self::JSXExpression(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXAttribute`], passed to [`Traverse::replace_jsx_attribute`].
+///
+/// [`Traverse::replace_jsx_attribute`]: crate::Traverse::replace_jsx_attribute
+pub enum JSXAttributeSlot<'s, 'a> {
+    JSXAttributeItem(&'s mut JSXAttributeItem<'a>),
+}
+
+impl<'s, 'a> JSXAttributeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXAttribute`].
+    pub fn node(&mut self) -> &mut JSXAttribute<'a> {
+        match self {
+            Self::JSXAttributeItem(JSXAttributeItem::Attribute(node)) => node,
+            Self::JSXAttributeItem(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXSpreadAttribute`], passed to [`Traverse::replace_jsx_spread_attribute`].
+///
+/// [`Traverse::replace_jsx_spread_attribute`]: crate::Traverse::replace_jsx_spread_attribute
+pub enum JSXSpreadAttributeSlot<'s, 'a> {
+    JSXAttributeItem(&'s mut JSXAttributeItem<'a>),
+}
+
+impl<'s, 'a> JSXSpreadAttributeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXSpreadAttribute`].
+    pub fn node(&mut self) -> &mut JSXSpreadAttribute<'a> {
+        match self {
+            Self::JSXAttributeItem(JSXAttributeItem::SpreadAttribute(node)) => node,
+            Self::JSXAttributeItem(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXExpressionContainer`], passed to [`Traverse::replace_jsx_expression_container`].
+///
+/// [`Traverse::replace_jsx_expression_container`]: crate::Traverse::replace_jsx_expression_container
+pub enum JSXExpressionContainerSlot<'s, 'a> {
+    JSXAttributeValue(&'s mut JSXAttributeValue<'a>),
+    JSXChild(&'s mut JSXChild<'a>),
+}
+
+impl<'s, 'a> JSXExpressionContainerSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXExpressionContainer`].
+    pub fn node(&mut self) -> &mut JSXExpressionContainer<'a> {
+        match self {
+            Self::JSXAttributeValue(JSXAttributeValue::ExpressionContainer(node)) => node,
+            Self::JSXChild(JSXChild::ExpressionContainer(node)) => node,
+            Self::JSXAttributeValue(_) => unreachable!(),
+            Self::JSXChild(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXText`], passed to [`Traverse::replace_jsx_text`].
+///
+/// [`Traverse::replace_jsx_text`]: crate::Traverse::replace_jsx_text
+pub enum JSXTextSlot<'s, 'a> {
+    JSXChild(&'s mut JSXChild<'a>),
+}
+
+impl<'s, 'a> JSXTextSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXText`].
+    pub fn node(&mut self) -> &mut JSXText<'a> {
+        match self {
+            Self::JSXChild(JSXChild::Text(node)) => node,
+            Self::JSXChild(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSXSpreadChild`], passed to [`Traverse::replace_jsx_spread_child`].
+///
+/// [`Traverse::replace_jsx_spread_child`]: crate::Traverse::replace_jsx_spread_child
+pub enum JSXSpreadChildSlot<'s, 'a> {
+    JSXChild(&'s mut JSXChild<'a>),
+}
+
+impl<'s, 'a> JSXSpreadChildSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSXSpreadChild`].
+    pub fn node(&mut self) -> &mut JSXSpreadChild<'a> {
+        match self {
+            Self::JSXChild(JSXChild::Spread(node)) => node,
+            Self::JSXChild(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSAnyKeyword`], passed to [`Traverse::replace_ts_any_keyword`].
+///
+/// [`Traverse::replace_ts_any_keyword`]: crate::Traverse::replace_ts_any_keyword
+pub enum TSAnyKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSAnyKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSAnyKeyword`].
+    pub fn node(&mut self) -> &mut TSAnyKeyword {
+        match self {
+            Self::TSType(TSType::TSAnyKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSBigIntKeyword`], passed to [`Traverse::replace_ts_big_int_keyword`].
+///
+/// [`Traverse::replace_ts_big_int_keyword`]: crate::Traverse::replace_ts_big_int_keyword
+pub enum TSBigIntKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSBigIntKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSBigIntKeyword`].
+    pub fn node(&mut self) -> &mut TSBigIntKeyword {
+        match self {
+            Self::TSType(TSType::TSBigIntKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSBooleanKeyword`], passed to [`Traverse::replace_ts_boolean_keyword`].
+///
+/// [`Traverse::replace_ts_boolean_keyword`]: crate::Traverse::replace_ts_boolean_keyword
+pub enum TSBooleanKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSBooleanKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSBooleanKeyword`].
+    pub fn node(&mut self) -> &mut TSBooleanKeyword {
+        match self {
+            Self::TSType(TSType::TSBooleanKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSIntrinsicKeyword`], passed to [`Traverse::replace_ts_intrinsic_keyword`].
+///
+/// [`Traverse::replace_ts_intrinsic_keyword`]: crate::Traverse::replace_ts_intrinsic_keyword
+pub enum TSIntrinsicKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSIntrinsicKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSIntrinsicKeyword`].
+    pub fn node(&mut self) -> &mut TSIntrinsicKeyword {
+        match self {
+            Self::TSType(TSType::TSIntrinsicKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNeverKeyword`], passed to [`Traverse::replace_ts_never_keyword`].
+///
+/// [`Traverse::replace_ts_never_keyword`]: crate::Traverse::replace_ts_never_keyword
+pub enum TSNeverKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSNeverKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNeverKeyword`].
+    pub fn node(&mut self) -> &mut TSNeverKeyword {
+        match self {
+            Self::TSType(TSType::TSNeverKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNullKeyword`], passed to [`Traverse::replace_ts_null_keyword`].
+///
+/// [`Traverse::replace_ts_null_keyword`]: crate::Traverse::replace_ts_null_keyword
+pub enum TSNullKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSNullKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNullKeyword`].
+    pub fn node(&mut self) -> &mut TSNullKeyword {
+        match self {
+            Self::TSType(TSType::TSNullKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNumberKeyword`], passed to [`Traverse::replace_ts_number_keyword`].
+///
+/// [`Traverse::replace_ts_number_keyword`]: crate::Traverse::replace_ts_number_keyword
+pub enum TSNumberKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSNumberKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNumberKeyword`].
+    pub fn node(&mut self) -> &mut TSNumberKeyword {
+        match self {
+            Self::TSType(TSType::TSNumberKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSObjectKeyword`], passed to [`Traverse::replace_ts_object_keyword`].
+///
+/// [`Traverse::replace_ts_object_keyword`]: crate::Traverse::replace_ts_object_keyword
+pub enum TSObjectKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSObjectKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSObjectKeyword`].
+    pub fn node(&mut self) -> &mut TSObjectKeyword {
+        match self {
+            Self::TSType(TSType::TSObjectKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSStringKeyword`], passed to [`Traverse::replace_ts_string_keyword`].
+///
+/// [`Traverse::replace_ts_string_keyword`]: crate::Traverse::replace_ts_string_keyword
+pub enum TSStringKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSStringKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSStringKeyword`].
+    pub fn node(&mut self) -> &mut TSStringKeyword {
+        match self {
+            Self::TSType(TSType::TSStringKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSSymbolKeyword`], passed to [`Traverse::replace_ts_symbol_keyword`].
+///
+/// [`Traverse::replace_ts_symbol_keyword`]: crate::Traverse::replace_ts_symbol_keyword
+pub enum TSSymbolKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSSymbolKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSSymbolKeyword`].
+    pub fn node(&mut self) -> &mut TSSymbolKeyword {
+        match self {
+            Self::TSType(TSType::TSSymbolKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSUndefinedKeyword`], passed to [`Traverse::replace_ts_undefined_keyword`].
+///
+/// [`Traverse::replace_ts_undefined_keyword`]: crate::Traverse::replace_ts_undefined_keyword
+pub enum TSUndefinedKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSUndefinedKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSUndefinedKeyword`].
+    pub fn node(&mut self) -> &mut TSUndefinedKeyword {
+        match self {
+            Self::TSType(TSType::TSUndefinedKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSUnknownKeyword`], passed to [`Traverse::replace_ts_unknown_keyword`].
+///
+/// [`Traverse::replace_ts_unknown_keyword`]: crate::Traverse::replace_ts_unknown_keyword
+pub enum TSUnknownKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSUnknownKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSUnknownKeyword`].
+    pub fn node(&mut self) -> &mut TSUnknownKeyword {
+        match self {
+            Self::TSType(TSType::TSUnknownKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSVoidKeyword`], passed to [`Traverse::replace_ts_void_keyword`].
+///
+/// [`Traverse::replace_ts_void_keyword`]: crate::Traverse::replace_ts_void_keyword
+pub enum TSVoidKeywordSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSVoidKeywordSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSVoidKeyword`].
+    pub fn node(&mut self) -> &mut TSVoidKeyword {
+        match self {
+            Self::TSType(TSType::TSVoidKeyword(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSArrayType`], passed to [`Traverse::replace_ts_array_type`].
+///
+/// [`Traverse::replace_ts_array_type`]: crate::Traverse::replace_ts_array_type
+pub enum TSArrayTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSArrayTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSArrayType`].
+    pub fn node(&mut self) -> &mut TSArrayType<'a> {
+        match self {
+            Self::TSType(TSType::TSArrayType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSConditionalType`], passed to [`Traverse::replace_ts_conditional_type`].
+///
+/// [`Traverse::replace_ts_conditional_type`]: crate::Traverse::replace_ts_conditional_type
+pub enum TSConditionalTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSConditionalTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSConditionalType`].
+    pub fn node(&mut self) -> &mut TSConditionalType<'a> {
+        match self {
+            Self::TSType(TSType::TSConditionalType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSConstructorType`], passed to [`Traverse::replace_ts_constructor_type`].
+///
+/// [`Traverse::replace_ts_constructor_type`]: crate::Traverse::replace_ts_constructor_type
+pub enum TSConstructorTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSConstructorTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSConstructorType`].
+    pub fn node(&mut self) -> &mut TSConstructorType<'a> {
+        match self {
+            Self::TSType(TSType::TSConstructorType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSFunctionType`], passed to [`Traverse::replace_ts_function_type`].
+///
+/// [`Traverse::replace_ts_function_type`]: crate::Traverse::replace_ts_function_type
+pub enum TSFunctionTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSFunctionTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSFunctionType`].
+    pub fn node(&mut self) -> &mut TSFunctionType<'a> {
+        match self {
+            Self::TSType(TSType::TSFunctionType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSImportType`], passed to [`Traverse::replace_ts_import_type`].
+///
+/// [`Traverse::replace_ts_import_type`]: crate::Traverse::replace_ts_import_type
+pub enum TSImportTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+    TSTypeQueryExprName(&'s mut TSTypeQueryExprName<'a>),
+}
+
+impl<'s, 'a> TSImportTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSImportType`].
+    pub fn node(&mut self) -> &mut TSImportType<'a> {
+        match self {
+            Self::TSType(TSType::TSImportType(node)) => node,
+            Self::TSTypeQueryExprName(TSTypeQueryExprName::TSImportType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+            Self::TSTypeQueryExprName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSIndexedAccessType`], passed to [`Traverse::replace_ts_indexed_access_type`].
+///
+/// [`Traverse::replace_ts_indexed_access_type`]: crate::Traverse::replace_ts_indexed_access_type
+pub enum TSIndexedAccessTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSIndexedAccessTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSIndexedAccessType`].
+    pub fn node(&mut self) -> &mut TSIndexedAccessType<'a> {
+        match self {
+            Self::TSType(TSType::TSIndexedAccessType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSInferType`], passed to [`Traverse::replace_ts_infer_type`].
+///
+/// [`Traverse::replace_ts_infer_type`]: crate::Traverse::replace_ts_infer_type
+pub enum TSInferTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSInferTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSInferType`].
+    pub fn node(&mut self) -> &mut TSInferType<'a> {
+        match self {
+            Self::TSType(TSType::TSInferType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSIntersectionType`], passed to [`Traverse::replace_ts_intersection_type`].
+///
+/// [`Traverse::replace_ts_intersection_type`]: crate::Traverse::replace_ts_intersection_type
+pub enum TSIntersectionTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSIntersectionTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSIntersectionType`].
+    pub fn node(&mut self) -> &mut TSIntersectionType<'a> {
+        match self {
+            Self::TSType(TSType::TSIntersectionType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSLiteralType`], passed to [`Traverse::replace_ts_literal_type`].
+///
+/// [`Traverse::replace_ts_literal_type`]: crate::Traverse::replace_ts_literal_type
+pub enum TSLiteralTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSLiteralTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSLiteralType`].
+    pub fn node(&mut self) -> &mut TSLiteralType<'a> {
+        match self {
+            Self::TSType(TSType::TSLiteralType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSMappedType`], passed to [`Traverse::replace_ts_mapped_type`].
+///
+/// [`Traverse::replace_ts_mapped_type`]: crate::Traverse::replace_ts_mapped_type
+pub enum TSMappedTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSMappedTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSMappedType`].
+    pub fn node(&mut self) -> &mut TSMappedType<'a> {
+        match self {
+            Self::TSType(TSType::TSMappedType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSNamedTupleMember`], passed to [`Traverse::replace_ts_named_tuple_member`].
+///
+/// [`Traverse::replace_ts_named_tuple_member`]: crate::Traverse::replace_ts_named_tuple_member
+pub enum TSNamedTupleMemberSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSNamedTupleMemberSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSNamedTupleMember`].
+    pub fn node(&mut self) -> &mut TSNamedTupleMember<'a> {
+        match self {
+            Self::TSType(TSType::TSNamedTupleMember(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSQualifiedName`], passed to [`Traverse::replace_ts_qualified_name`].
+///
+/// [`Traverse::replace_ts_qualified_name`]: crate::Traverse::replace_ts_qualified_name
+pub enum TSQualifiedNameSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+    TSTypeName(&'s mut TSTypeName<'a>),
+}
+
+impl<'s, 'a> TSQualifiedNameSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSQualifiedName`].
+    pub fn node(&mut self) -> &mut TSQualifiedName<'a> {
+        match self {
+            Self::TSType(TSType::TSQualifiedName(node)) => node,
+            Self::TSTypeName(TSTypeName::QualifiedName(node)) => node,
+            Self::TSType(_) => unreachable!(),
+            Self::TSTypeName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTemplateLiteralType`], passed to [`Traverse::replace_ts_template_literal_type`].
+///
+/// [`Traverse::replace_ts_template_literal_type`]: crate::Traverse::replace_ts_template_literal_type
+pub enum TSTemplateLiteralTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTemplateLiteralTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTemplateLiteralType`].
+    pub fn node(&mut self) -> &mut TSTemplateLiteralType<'a> {
+        match self {
+            Self::TSType(TSType::TSTemplateLiteralType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSThisType`], passed to [`Traverse::replace_ts_this_type`].
+///
+/// [`Traverse::replace_ts_this_type`]: crate::Traverse::replace_ts_this_type
+pub enum TSThisTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+    TSTypePredicateName(&'s mut TSTypePredicateName<'a>),
+}
+
+impl<'s, 'a> TSThisTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSThisType`].
+    pub fn node(&mut self) -> &mut TSThisType {
+        match self {
+            Self::TSType(TSType::TSThisType(node)) => node,
+            Self::TSTypePredicateName(TSTypePredicateName::This(node)) => node,
+            Self::TSType(_) => unreachable!(),
+            Self::TSTypePredicateName(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTupleType`], passed to [`Traverse::replace_ts_tuple_type`].
+///
+/// [`Traverse::replace_ts_tuple_type`]: crate::Traverse::replace_ts_tuple_type
+pub enum TSTupleTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTupleTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTupleType`].
+    pub fn node(&mut self) -> &mut TSTupleType<'a> {
+        match self {
+            Self::TSType(TSType::TSTupleType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeLiteral`], passed to [`Traverse::replace_ts_type_literal`].
+///
+/// [`Traverse::replace_ts_type_literal`]: crate::Traverse::replace_ts_type_literal
+pub enum TSTypeLiteralSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTypeLiteralSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeLiteral`].
+    pub fn node(&mut self) -> &mut TSTypeLiteral<'a> {
+        match self {
+            Self::TSType(TSType::TSTypeLiteral(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeOperator`], passed to [`Traverse::replace_ts_type_operator`].
+///
+/// [`Traverse::replace_ts_type_operator`]: crate::Traverse::replace_ts_type_operator
+pub enum TSTypeOperatorSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTypeOperatorSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeOperator`].
+    pub fn node(&mut self) -> &mut TSTypeOperator<'a> {
+        match self {
+            Self::TSType(TSType::TSTypeOperatorType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypePredicate`], passed to [`Traverse::replace_ts_type_predicate`].
+///
+/// [`Traverse::replace_ts_type_predicate`]: crate::Traverse::replace_ts_type_predicate
+pub enum TSTypePredicateSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTypePredicateSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypePredicate`].
+    pub fn node(&mut self) -> &mut TSTypePredicate<'a> {
+        match self {
+            Self::TSType(TSType::TSTypePredicate(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeQuery`], passed to [`Traverse::replace_ts_type_query`].
+///
+/// [`Traverse::replace_ts_type_query`]: crate::Traverse::replace_ts_type_query
+pub enum TSTypeQuerySlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTypeQuerySlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeQuery`].
+    pub fn node(&mut self) -> &mut TSTypeQuery<'a> {
+        match self {
+            Self::TSType(TSType::TSTypeQuery(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSTypeReference`], passed to [`Traverse::replace_ts_type_reference`].
+///
+/// [`Traverse::replace_ts_type_reference`]: crate::Traverse::replace_ts_type_reference
+pub enum TSTypeReferenceSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSTypeReferenceSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSTypeReference`].
+    pub fn node(&mut self) -> &mut TSTypeReference<'a> {
+        match self {
+            Self::TSType(TSType::TSTypeReference(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSUnionType`], passed to [`Traverse::replace_ts_union_type`].
+///
+/// [`Traverse::replace_ts_union_type`]: crate::Traverse::replace_ts_union_type
+pub enum TSUnionTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSUnionTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSUnionType`].
+    pub fn node(&mut self) -> &mut TSUnionType<'a> {
+        match self {
+            Self::TSType(TSType::TSUnionType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSParenthesizedType`], passed to [`Traverse::replace_ts_parenthesized_type`].
+///
+/// [`Traverse::replace_ts_parenthesized_type`]: crate::Traverse::replace_ts_parenthesized_type
+pub enum TSParenthesizedTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> TSParenthesizedTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSParenthesizedType`].
+    pub fn node(&mut self) -> &mut TSParenthesizedType<'a> {
+        match self {
+            Self::TSType(TSType::TSParenthesizedType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSDocNullableType`], passed to [`Traverse::replace_js_doc_nullable_type`].
+///
+/// [`Traverse::replace_js_doc_nullable_type`]: crate::Traverse::replace_js_doc_nullable_type
+pub enum JSDocNullableTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> JSDocNullableTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSDocNullableType`].
+    pub fn node(&mut self) -> &mut JSDocNullableType<'a> {
+        match self {
+            Self::TSType(TSType::JSDocNullableType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSDocNonNullableType`], passed to [`Traverse::replace_js_doc_non_nullable_type`].
+///
+/// [`Traverse::replace_js_doc_non_nullable_type`]: crate::Traverse::replace_js_doc_non_nullable_type
+pub enum JSDocNonNullableTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> JSDocNonNullableTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSDocNonNullableType`].
+    pub fn node(&mut self) -> &mut JSDocNonNullableType<'a> {
+        match self {
+            Self::TSType(TSType::JSDocNonNullableType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`JSDocUnknownType`], passed to [`Traverse::replace_js_doc_unknown_type`].
+///
+/// [`Traverse::replace_js_doc_unknown_type`]: crate::Traverse::replace_js_doc_unknown_type
+pub enum JSDocUnknownTypeSlot<'s, 'a> {
+    TSType(&'s mut TSType<'a>),
+}
+
+impl<'s, 'a> JSDocUnknownTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`JSDocUnknownType`].
+    pub fn node(&mut self) -> &mut JSDocUnknownType {
+        match self {
+            Self::TSType(TSType::JSDocUnknownType(node)) => node,
+            Self::TSType(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSOptionalType`], passed to [`Traverse::replace_ts_optional_type`].
+///
+/// [`Traverse::replace_ts_optional_type`]: crate::Traverse::replace_ts_optional_type
+pub enum TSOptionalTypeSlot<'s, 'a> {
+    TSTupleElement(&'s mut TSTupleElement<'a>),
+}
+
+impl<'s, 'a> TSOptionalTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSOptionalType`].
+    pub fn node(&mut self) -> &mut TSOptionalType<'a> {
+        match self {
+            Self::TSTupleElement(TSTupleElement::TSOptionalType(node)) => node,
+            Self::TSTupleElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSRestType`], passed to [`Traverse::replace_ts_rest_type`].
+///
+/// [`Traverse::replace_ts_rest_type`]: crate::Traverse::replace_ts_rest_type
+pub enum TSRestTypeSlot<'s, 'a> {
+    TSTupleElement(&'s mut TSTupleElement<'a>),
+}
+
+impl<'s, 'a> TSRestTypeSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSRestType`].
+    pub fn node(&mut self) -> &mut TSRestType<'a> {
+        match self {
+            Self::TSTupleElement(TSTupleElement::TSRestType(node)) => node,
+            Self::TSTupleElement(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSPropertySignature`], passed to [`Traverse::replace_ts_property_signature`].
+///
+/// [`Traverse::replace_ts_property_signature`]: crate::Traverse::replace_ts_property_signature
+pub enum TSPropertySignatureSlot<'s, 'a> {
+    TSSignature(&'s mut TSSignature<'a>),
+}
+
+impl<'s, 'a> TSPropertySignatureSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSPropertySignature`].
+    pub fn node(&mut self) -> &mut TSPropertySignature<'a> {
+        match self {
+            Self::TSSignature(TSSignature::TSPropertySignature(node)) => node,
+            Self::TSSignature(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSCallSignatureDeclaration`], passed to [`Traverse::replace_ts_call_signature_declaration`].
+///
+/// [`Traverse::replace_ts_call_signature_declaration`]: crate::Traverse::replace_ts_call_signature_declaration
+pub enum TSCallSignatureDeclarationSlot<'s, 'a> {
+    TSSignature(&'s mut TSSignature<'a>),
+}
+
+impl<'s, 'a> TSCallSignatureDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSCallSignatureDeclaration`].
+    pub fn node(&mut self) -> &mut TSCallSignatureDeclaration<'a> {
+        match self {
+            Self::TSSignature(TSSignature::TSCallSignatureDeclaration(node)) => node,
+            Self::TSSignature(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSConstructSignatureDeclaration`], passed to [`Traverse::replace_ts_construct_signature_declaration`].
+///
+/// [`Traverse::replace_ts_construct_signature_declaration`]: crate::Traverse::replace_ts_construct_signature_declaration
+pub enum TSConstructSignatureDeclarationSlot<'s, 'a> {
+    TSSignature(&'s mut TSSignature<'a>),
+}
+
+impl<'s, 'a> TSConstructSignatureDeclarationSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSConstructSignatureDeclaration`].
+    pub fn node(&mut self) -> &mut TSConstructSignatureDeclaration<'a> {
+        match self {
+            Self::TSSignature(TSSignature::TSConstructSignatureDeclaration(node)) => node,
+            Self::TSSignature(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSMethodSignature`], passed to [`Traverse::replace_ts_method_signature`].
+///
+/// [`Traverse::replace_ts_method_signature`]: crate::Traverse::replace_ts_method_signature
+pub enum TSMethodSignatureSlot<'s, 'a> {
+    TSSignature(&'s mut TSSignature<'a>),
+}
+
+impl<'s, 'a> TSMethodSignatureSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSMethodSignature`].
+    pub fn node(&mut self) -> &mut TSMethodSignature<'a> {
+        match self {
+            Self::TSSignature(TSSignature::TSMethodSignature(node)) => node,
+            Self::TSSignature(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSModuleBlock`], passed to [`Traverse::replace_ts_module_block`].
+///
+/// [`Traverse::replace_ts_module_block`]: crate::Traverse::replace_ts_module_block
+pub enum TSModuleBlockSlot<'s, 'a> {
+    TSModuleDeclarationBody(&'s mut TSModuleDeclarationBody<'a>),
+}
+
+impl<'s, 'a> TSModuleBlockSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSModuleBlock`].
+    pub fn node(&mut self) -> &mut TSModuleBlock<'a> {
+        match self {
+            Self::TSModuleDeclarationBody(TSModuleDeclarationBody::TSModuleBlock(node)) => node,
+            Self::TSModuleDeclarationBody(_) => unreachable!(),
+        }
+    }
+}
+
+/// Slot holding a [`TSExternalModuleReference`], passed to [`Traverse::replace_ts_external_module_reference`].
+///
+/// [`Traverse::replace_ts_external_module_reference`]: crate::Traverse::replace_ts_external_module_reference
+pub enum TSExternalModuleReferenceSlot<'s, 'a> {
+    TSModuleReference(&'s mut TSModuleReference<'a>),
+}
+
+impl<'s, 'a> TSExternalModuleReferenceSlot<'s, 'a> {
+    /// Get the node held in this slot.
+    ///
+    /// # Panics
+    /// Panics if the slot does not hold a [`TSExternalModuleReference`].
+    pub fn node(&mut self) -> &mut TSExternalModuleReference<'a> {
+        match self {
+            Self::TSModuleReference(TSModuleReference::ExternalModuleReference(node)) => node,
+            Self::TSModuleReference(_) => unreachable!(),
+        }
+    }
+}
@@ -6,6 +6,8 @@ use oxc_allocator::Vec;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 
+#[allow(clippy::wildcard_imports)]
+use crate::replace::*;
 use crate::TraverseCtx;
 
 #[allow(unused_variables)]
@@ -2437,4 +2439,1389 @@ pub trait Traverse<'a> {
     fn enter_statements(&mut self, node: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {}
     #[inline]
     fn exit_statements(&mut self, node: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `BooleanLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_boolean_literal(
+        &mut self,
+        slot: BooleanLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `NullLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_null_literal(&mut self, slot: NullLiteralSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `NumericLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_numeric_literal(
+        &mut self,
+        slot: NumericLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `BigIntLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_big_int_literal(
+        &mut self,
+        slot: BigIntLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `RegExpLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_reg_exp_literal(
+        &mut self,
+        slot: RegExpLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `StringLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_string_literal(
+        &mut self,
+        slot: StringLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TemplateLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_template_literal(
+        &mut self,
+        slot: TemplateLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `IdentifierReference` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_identifier_reference(
+        &mut self,
+        slot: IdentifierReferenceSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `MetaProperty` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_meta_property(&mut self, slot: MetaPropertySlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    /// Called before walking into a `Super` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_super(&mut self, slot: SuperSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `ArrayExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_array_expression(
+        &mut self,
+        slot: ArrayExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ArrowFunctionExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_arrow_function_expression(
+        &mut self,
+        slot: ArrowFunctionExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AssignmentExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_assignment_expression(
+        &mut self,
+        slot: AssignmentExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AwaitExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_await_expression(
+        &mut self,
+        slot: AwaitExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `BinaryExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_binary_expression(
+        &mut self,
+        slot: BinaryExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `CallExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_call_expression(
+        &mut self,
+        slot: CallExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ChainExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_chain_expression(
+        &mut self,
+        slot: ChainExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `Class` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_class(&mut self, slot: ClassSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `ConditionalExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_conditional_expression(
+        &mut self,
+        slot: ConditionalExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `Function` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_function(&mut self, slot: FunctionSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `ImportExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_import_expression(
+        &mut self,
+        slot: ImportExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `LogicalExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_logical_expression(
+        &mut self,
+        slot: LogicalExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `NewExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_new_expression(
+        &mut self,
+        slot: NewExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ObjectExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_object_expression(
+        &mut self,
+        slot: ObjectExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ParenthesizedExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_parenthesized_expression(
+        &mut self,
+        slot: ParenthesizedExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `SequenceExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_sequence_expression(
+        &mut self,
+        slot: SequenceExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TaggedTemplateExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_tagged_template_expression(
+        &mut self,
+        slot: TaggedTemplateExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ThisExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_this_expression(
+        &mut self,
+        slot: ThisExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `UnaryExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_unary_expression(
+        &mut self,
+        slot: UnaryExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `UpdateExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_update_expression(
+        &mut self,
+        slot: UpdateExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `YieldExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_yield_expression(
+        &mut self,
+        slot: YieldExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `PrivateInExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_private_in_expression(
+        &mut self,
+        slot: PrivateInExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXElement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_element(&mut self, slot: JSXElementSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `JSXFragment` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_fragment(&mut self, slot: JSXFragmentSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSAsExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_as_expression(
+        &mut self,
+        slot: TSAsExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSSatisfiesExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_satisfies_expression(
+        &mut self,
+        slot: TSSatisfiesExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTypeAssertion` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_assertion(
+        &mut self,
+        slot: TSTypeAssertionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNonNullExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_non_null_expression(
+        &mut self,
+        slot: TSNonNullExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSInstantiationExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_instantiation_expression(
+        &mut self,
+        slot: TSInstantiationExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `SpreadElement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_spread_element(
+        &mut self,
+        slot: SpreadElementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `Elision` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_elision(&mut self, slot: ElisionSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `ObjectProperty` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_object_property(
+        &mut self,
+        slot: ObjectPropertySlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `IdentifierName` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_identifier_name(
+        &mut self,
+        slot: IdentifierNameSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `PrivateIdentifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_private_identifier(
+        &mut self,
+        slot: PrivateIdentifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ComputedMemberExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_computed_member_expression(
+        &mut self,
+        slot: ComputedMemberExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `StaticMemberExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_static_member_expression(
+        &mut self,
+        slot: StaticMemberExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `PrivateFieldExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_private_field_expression(
+        &mut self,
+        slot: PrivateFieldExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ArrayAssignmentTarget` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_array_assignment_target(
+        &mut self,
+        slot: ArrayAssignmentTargetSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ObjectAssignmentTarget` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_object_assignment_target(
+        &mut self,
+        slot: ObjectAssignmentTargetSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AssignmentTargetWithDefault` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_assignment_target_with_default(
+        &mut self,
+        slot: AssignmentTargetWithDefaultSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AssignmentTargetPropertyIdentifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_assignment_target_property_identifier(
+        &mut self,
+        slot: AssignmentTargetPropertyIdentifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AssignmentTargetPropertyProperty` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_assignment_target_property_property(
+        &mut self,
+        slot: AssignmentTargetPropertyPropertySlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `BlockStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_block_statement(
+        &mut self,
+        slot: BlockStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `BreakStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_break_statement(
+        &mut self,
+        slot: BreakStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ContinueStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_continue_statement(
+        &mut self,
+        slot: ContinueStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `DebuggerStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_debugger_statement(
+        &mut self,
+        slot: DebuggerStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `DoWhileStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_do_while_statement(
+        &mut self,
+        slot: DoWhileStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `EmptyStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_empty_statement(
+        &mut self,
+        slot: EmptyStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ExpressionStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_expression_statement(
+        &mut self,
+        slot: ExpressionStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ForInStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_for_in_statement(
+        &mut self,
+        slot: ForInStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ForOfStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_for_of_statement(
+        &mut self,
+        slot: ForOfStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ForStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_for_statement(&mut self, slot: ForStatementSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    /// Called before walking into a `IfStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_if_statement(&mut self, slot: IfStatementSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `LabeledStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_labeled_statement(
+        &mut self,
+        slot: LabeledStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ReturnStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_return_statement(
+        &mut self,
+        slot: ReturnStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `SwitchStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_switch_statement(
+        &mut self,
+        slot: SwitchStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ThrowStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_throw_statement(
+        &mut self,
+        slot: ThrowStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TryStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_try_statement(&mut self, slot: TryStatementSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    /// Called before walking into a `WhileStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_while_statement(
+        &mut self,
+        slot: WhileStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `WithStatement` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_with_statement(
+        &mut self,
+        slot: WithStatementSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `VariableDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_variable_declaration(
+        &mut self,
+        slot: VariableDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTypeAliasDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_alias_declaration(
+        &mut self,
+        slot: TSTypeAliasDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSInterfaceDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_interface_declaration(
+        &mut self,
+        slot: TSInterfaceDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSEnumDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_enum_declaration(
+        &mut self,
+        slot: TSEnumDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSModuleDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_module_declaration(
+        &mut self,
+        slot: TSModuleDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSImportEqualsDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_import_equals_declaration(
+        &mut self,
+        slot: TSImportEqualsDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `BindingIdentifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_binding_identifier(
+        &mut self,
+        slot: BindingIdentifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ObjectPattern` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_object_pattern(
+        &mut self,
+        slot: ObjectPatternSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ArrayPattern` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_array_pattern(&mut self, slot: ArrayPatternSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    /// Called before walking into a `AssignmentPattern` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_assignment_pattern(
+        &mut self,
+        slot: AssignmentPatternSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `StaticBlock` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_static_block(&mut self, slot: StaticBlockSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `MethodDefinition` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_method_definition(
+        &mut self,
+        slot: MethodDefinitionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `PropertyDefinition` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_property_definition(
+        &mut self,
+        slot: PropertyDefinitionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `AccessorProperty` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_accessor_property(
+        &mut self,
+        slot: AccessorPropertySlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSIndexSignature` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_index_signature(
+        &mut self,
+        slot: TSIndexSignatureSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ImportDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_import_declaration(
+        &mut self,
+        slot: ImportDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ExportAllDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_export_all_declaration(
+        &mut self,
+        slot: ExportAllDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ExportDefaultDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_export_default_declaration(
+        &mut self,
+        slot: ExportDefaultDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ExportNamedDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_export_named_declaration(
+        &mut self,
+        slot: ExportNamedDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSExportAssignment` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_export_assignment(
+        &mut self,
+        slot: TSExportAssignmentSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNamespaceExportDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_namespace_export_declaration(
+        &mut self,
+        slot: TSNamespaceExportDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ImportSpecifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_import_specifier(
+        &mut self,
+        slot: ImportSpecifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ImportDefaultSpecifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_import_default_specifier(
+        &mut self,
+        slot: ImportDefaultSpecifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `ImportNamespaceSpecifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_import_namespace_specifier(
+        &mut self,
+        slot: ImportNamespaceSpecifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXIdentifier` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_identifier(
+        &mut self,
+        slot: JSXIdentifierSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXNamespacedName` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_namespaced_name(
+        &mut self,
+        slot: JSXNamespacedNameSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXMemberExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_member_expression(
+        &mut self,
+        slot: JSXMemberExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXEmptyExpression` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_empty_expression(
+        &mut self,
+        slot: JSXEmptyExpressionSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXAttribute` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_attribute(&mut self, slot: JSXAttributeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    /// Called before walking into a `JSXSpreadAttribute` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_spread_attribute(
+        &mut self,
+        slot: JSXSpreadAttributeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXExpressionContainer` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_expression_container(
+        &mut self,
+        slot: JSXExpressionContainerSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSXText` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_text(&mut self, slot: JSXTextSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `JSXSpreadChild` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_jsx_spread_child(
+        &mut self,
+        slot: JSXSpreadChildSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSAnyKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_any_keyword(
+        &mut self,
+        slot: TSAnyKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSBigIntKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_big_int_keyword(
+        &mut self,
+        slot: TSBigIntKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSBooleanKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_boolean_keyword(
+        &mut self,
+        slot: TSBooleanKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSIntrinsicKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_intrinsic_keyword(
+        &mut self,
+        slot: TSIntrinsicKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNeverKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_never_keyword(
+        &mut self,
+        slot: TSNeverKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNullKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_null_keyword(
+        &mut self,
+        slot: TSNullKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNumberKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_number_keyword(
+        &mut self,
+        slot: TSNumberKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSObjectKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_object_keyword(
+        &mut self,
+        slot: TSObjectKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSStringKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_string_keyword(
+        &mut self,
+        slot: TSStringKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSSymbolKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_symbol_keyword(
+        &mut self,
+        slot: TSSymbolKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSUndefinedKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_undefined_keyword(
+        &mut self,
+        slot: TSUndefinedKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSUnknownKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_unknown_keyword(
+        &mut self,
+        slot: TSUnknownKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSVoidKeyword` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_void_keyword(
+        &mut self,
+        slot: TSVoidKeywordSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSArrayType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_array_type(&mut self, slot: TSArrayTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSConditionalType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_conditional_type(
+        &mut self,
+        slot: TSConditionalTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSConstructorType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_constructor_type(
+        &mut self,
+        slot: TSConstructorTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSFunctionType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_function_type(
+        &mut self,
+        slot: TSFunctionTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSImportType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_import_type(
+        &mut self,
+        slot: TSImportTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSIndexedAccessType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_indexed_access_type(
+        &mut self,
+        slot: TSIndexedAccessTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSInferType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_infer_type(&mut self, slot: TSInferTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSIntersectionType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_intersection_type(
+        &mut self,
+        slot: TSIntersectionTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSLiteralType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_literal_type(
+        &mut self,
+        slot: TSLiteralTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSMappedType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_mapped_type(
+        &mut self,
+        slot: TSMappedTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSNamedTupleMember` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_named_tuple_member(
+        &mut self,
+        slot: TSNamedTupleMemberSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSQualifiedName` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_qualified_name(
+        &mut self,
+        slot: TSQualifiedNameSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTemplateLiteralType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_template_literal_type(
+        &mut self,
+        slot: TSTemplateLiteralTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSThisType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_this_type(&mut self, slot: TSThisTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSTupleType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_tuple_type(&mut self, slot: TSTupleTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSTypeLiteral` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_literal(
+        &mut self,
+        slot: TSTypeLiteralSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTypeOperator` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_operator(
+        &mut self,
+        slot: TSTypeOperatorSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTypePredicate` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_predicate(
+        &mut self,
+        slot: TSTypePredicateSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSTypeQuery` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_query(&mut self, slot: TSTypeQuerySlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSTypeReference` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_type_reference(
+        &mut self,
+        slot: TSTypeReferenceSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSUnionType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_union_type(&mut self, slot: TSUnionTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSParenthesizedType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_parenthesized_type(
+        &mut self,
+        slot: TSParenthesizedTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSDocNullableType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_js_doc_nullable_type(
+        &mut self,
+        slot: JSDocNullableTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSDocNonNullableType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_js_doc_non_nullable_type(
+        &mut self,
+        slot: JSDocNonNullableTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `JSDocUnknownType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_js_doc_unknown_type(
+        &mut self,
+        slot: JSDocUnknownTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSOptionalType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_optional_type(
+        &mut self,
+        slot: TSOptionalTypeSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSRestType` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_rest_type(&mut self, slot: TSRestTypeSlot<'_, 'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    /// Called before walking into a `TSPropertySignature` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_property_signature(
+        &mut self,
+        slot: TSPropertySignatureSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSCallSignatureDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_call_signature_declaration(
+        &mut self,
+        slot: TSCallSignatureDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSConstructSignatureDeclaration` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_construct_signature_declaration(
+        &mut self,
+        slot: TSConstructSignatureDeclarationSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSMethodSignature` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_method_signature(
+        &mut self,
+        slot: TSMethodSignatureSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSModuleBlock` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_module_block(
+        &mut self,
+        slot: TSModuleBlockSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    /// Called before walking into a `TSExternalModuleReference` in an enum slot.
+    /// The node can be replaced by assigning to the slot.
+    #[inline]
+    fn replace_ts_external_module_reference(
+        &mut self,
+        slot: TSExternalModuleReferenceSlot<'_, 'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
 }
@@ -8,7 +8,8 @@
     clippy::missing_panics_doc,
     clippy::undocumented_unsafe_blocks,
     clippy::semicolon_if_nothing_returned,
-    clippy::cast_ptr_alignment
+    clippy::cast_ptr_alignment,
+    clippy::single_match
 )]
 
 use std::{cell::Cell, marker::PhantomData};
@@ -18,6 +19,8 @@ use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_syntax::scope::ScopeId;
 
+#[allow(clippy::wildcard_imports)]
+use crate::replace::*;
 use crate::{
     ancestor::{self, AncestorType},
     Ancestor, Traverse, TraverseCtx,
@@ -69,6 +72,123 @@ pub(crate) unsafe fn walk_expression<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_expression(&mut *node, ctx);
+    match &*node {
+        Expression::BooleanLiteral(_) => {
+            traverser.replace_boolean_literal(BooleanLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::NullLiteral(_) => {
+            traverser.replace_null_literal(NullLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::NumericLiteral(_) => {
+            traverser.replace_numeric_literal(NumericLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::BigIntLiteral(_) => {
+            traverser.replace_big_int_literal(BigIntLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::RegExpLiteral(_) => {
+            traverser.replace_reg_exp_literal(RegExpLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::StringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::TemplateLiteral(_) => {
+            traverser.replace_template_literal(TemplateLiteralSlot::Expression(&mut *node), ctx)
+        }
+        Expression::Identifier(_) => traverser
+            .replace_identifier_reference(IdentifierReferenceSlot::Expression(&mut *node), ctx),
+        Expression::MetaProperty(_) => {
+            traverser.replace_meta_property(MetaPropertySlot::Expression(&mut *node), ctx)
+        }
+        Expression::Super(_) => traverser.replace_super(SuperSlot::Expression(&mut *node), ctx),
+        Expression::ArrayExpression(_) => {
+            traverser.replace_array_expression(ArrayExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ArrowFunctionExpression(_) => traverser.replace_arrow_function_expression(
+            ArrowFunctionExpressionSlot::Expression(&mut *node),
+            ctx,
+        ),
+        Expression::AssignmentExpression(_) => traverser
+            .replace_assignment_expression(AssignmentExpressionSlot::Expression(&mut *node), ctx),
+        Expression::AwaitExpression(_) => {
+            traverser.replace_await_expression(AwaitExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::BinaryExpression(_) => {
+            traverser.replace_binary_expression(BinaryExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::CallExpression(_) => {
+            traverser.replace_call_expression(CallExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ChainExpression(_) => {
+            traverser.replace_chain_expression(ChainExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ClassExpression(_) => {
+            traverser.replace_class(ClassSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ConditionalExpression(_) => traverser
+            .replace_conditional_expression(ConditionalExpressionSlot::Expression(&mut *node), ctx),
+        Expression::FunctionExpression(_) => {
+            traverser.replace_function(FunctionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ImportExpression(_) => {
+            traverser.replace_import_expression(ImportExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::LogicalExpression(_) => {
+            traverser.replace_logical_expression(LogicalExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::NewExpression(_) => {
+            traverser.replace_new_expression(NewExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ObjectExpression(_) => {
+            traverser.replace_object_expression(ObjectExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::ParenthesizedExpression(_) => traverser.replace_parenthesized_expression(
+            ParenthesizedExpressionSlot::Expression(&mut *node),
+            ctx,
+        ),
+        Expression::SequenceExpression(_) => traverser
+            .replace_sequence_expression(SequenceExpressionSlot::Expression(&mut *node), ctx),
+        Expression::TaggedTemplateExpression(_) => traverser.replace_tagged_template_expression(
+            TaggedTemplateExpressionSlot::Expression(&mut *node),
+            ctx,
+        ),
+        Expression::ThisExpression(_) => {
+            traverser.replace_this_expression(ThisExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::UnaryExpression(_) => {
+            traverser.replace_unary_expression(UnaryExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::UpdateExpression(_) => {
+            traverser.replace_update_expression(UpdateExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::YieldExpression(_) => {
+            traverser.replace_yield_expression(YieldExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::PrivateInExpression(_) => traverser
+            .replace_private_in_expression(PrivateInExpressionSlot::Expression(&mut *node), ctx),
+        Expression::JSXElement(_) => {
+            traverser.replace_jsx_element(JSXElementSlot::Expression(&mut *node), ctx)
+        }
+        Expression::JSXFragment(_) => {
+            traverser.replace_jsx_fragment(JSXFragmentSlot::Expression(&mut *node), ctx)
+        }
+        Expression::TSAsExpression(_) => {
+            traverser.replace_ts_as_expression(TSAsExpressionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::TSSatisfiesExpression(_) => traverser.replace_ts_satisfies_expression(
+            TSSatisfiesExpressionSlot::Expression(&mut *node),
+            ctx,
+        ),
+        Expression::TSTypeAssertion(_) => {
+            traverser.replace_ts_type_assertion(TSTypeAssertionSlot::Expression(&mut *node), ctx)
+        }
+        Expression::TSNonNullExpression(_) => traverser
+            .replace_ts_non_null_expression(TSNonNullExpressionSlot::Expression(&mut *node), ctx),
+        Expression::TSInstantiationExpression(_) => traverser.replace_ts_instantiation_expression(
+            TSInstantiationExpressionSlot::Expression(&mut *node),
+            ctx,
+        ),
+        _ => {}
+    }
     match &mut *node {
         Expression::BooleanLiteral(node) => {
             walk_boolean_literal(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -266,6 +386,14 @@ pub(crate) unsafe fn walk_array_expression_element<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_array_expression_element(&mut *node, ctx);
+    match &*node {
+        ArrayExpressionElement::SpreadElement(_) => traverser
+            .replace_spread_element(SpreadElementSlot::ArrayExpressionElement(&mut *node), ctx),
+        ArrayExpressionElement::Elision(_) => {
+            traverser.replace_elision(ElisionSlot::ArrayExpressionElement(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         ArrayExpressionElement::SpreadElement(node) => {
             walk_spread_element(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -357,6 +485,13 @@ pub(crate) unsafe fn walk_object_property_kind<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_object_property_kind(&mut *node, ctx);
+    match &*node {
+        ObjectPropertyKind::ObjectProperty(_) => traverser
+            .replace_object_property(ObjectPropertySlot::ObjectPropertyKind(&mut *node), ctx),
+        ObjectPropertyKind::SpreadProperty(_) => {
+            traverser.replace_spread_element(SpreadElementSlot::ObjectPropertyKind(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         ObjectPropertyKind::ObjectProperty(node) => {
             walk_object_property(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -406,6 +541,14 @@ pub(crate) unsafe fn walk_property_key<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_property_key(&mut *node, ctx);
+    match &*node {
+        PropertyKey::StaticIdentifier(_) => {
+            traverser.replace_identifier_name(IdentifierNameSlot::PropertyKey(&mut *node), ctx)
+        }
+        PropertyKey::PrivateIdentifier(_) => traverser
+            .replace_private_identifier(PrivateIdentifierSlot::PropertyKey(&mut *node), ctx),
+        _ => {}
+    }
     match &mut *node {
         PropertyKey::StaticIdentifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -538,6 +681,21 @@ pub(crate) unsafe fn walk_member_expression<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_member_expression(&mut *node, ctx);
+    match &*node {
+        MemberExpression::ComputedMemberExpression(_) => traverser
+            .replace_computed_member_expression(
+                ComputedMemberExpressionSlot::MemberExpression(&mut *node),
+                ctx,
+            ),
+        MemberExpression::StaticMemberExpression(_) => traverser.replace_static_member_expression(
+            StaticMemberExpressionSlot::MemberExpression(&mut *node),
+            ctx,
+        ),
+        MemberExpression::PrivateFieldExpression(_) => traverser.replace_private_field_expression(
+            PrivateFieldExpressionSlot::MemberExpression(&mut *node),
+            ctx,
+        ),
+    }
     match &mut *node {
         MemberExpression::ComputedMemberExpression(node) => {
             walk_computed_member_expression(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -755,6 +913,12 @@ pub(crate) unsafe fn walk_argument<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_argument(&mut *node, ctx);
+    match &*node {
+        Argument::SpreadElement(_) => {
+            traverser.replace_spread_element(SpreadElementSlot::Argument(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         Argument::SpreadElement(node) => {
             walk_spread_element(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -983,6 +1147,7 @@ pub(crate) unsafe fn walk_assignment_target<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_assignment_target(&mut *node, ctx);
+
     match &mut *node {
         AssignmentTarget::AssignmentTargetIdentifier(_)
         | AssignmentTarget::TSAsExpression(_)
@@ -1009,6 +1174,34 @@ pub(crate) unsafe fn walk_simple_assignment_target<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_simple_assignment_target(&mut *node, ctx);
+    match &*node {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(_) => traverser
+            .replace_identifier_reference(
+                IdentifierReferenceSlot::SimpleAssignmentTarget(&mut *node),
+                ctx,
+            ),
+        SimpleAssignmentTarget::TSAsExpression(_) => traverser
+            .replace_ts_as_expression(TSAsExpressionSlot::SimpleAssignmentTarget(&mut *node), ctx),
+        SimpleAssignmentTarget::TSSatisfiesExpression(_) => traverser
+            .replace_ts_satisfies_expression(
+                TSSatisfiesExpressionSlot::SimpleAssignmentTarget(&mut *node),
+                ctx,
+            ),
+        SimpleAssignmentTarget::TSNonNullExpression(_) => traverser.replace_ts_non_null_expression(
+            TSNonNullExpressionSlot::SimpleAssignmentTarget(&mut *node),
+            ctx,
+        ),
+        SimpleAssignmentTarget::TSTypeAssertion(_) => traverser.replace_ts_type_assertion(
+            TSTypeAssertionSlot::SimpleAssignmentTarget(&mut *node),
+            ctx,
+        ),
+        SimpleAssignmentTarget::TSInstantiationExpression(_) => traverser
+            .replace_ts_instantiation_expression(
+                TSInstantiationExpressionSlot::SimpleAssignmentTarget(&mut *node),
+                ctx,
+            ),
+        _ => {}
+    }
     match &mut *node {
         SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
             walk_identifier_reference(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1043,6 +1236,18 @@ pub(crate) unsafe fn walk_assignment_target_pattern<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_assignment_target_pattern(&mut *node, ctx);
+    match &*node {
+        AssignmentTargetPattern::ArrayAssignmentTarget(_) => traverser
+            .replace_array_assignment_target(
+                ArrayAssignmentTargetSlot::AssignmentTargetPattern(&mut *node),
+                ctx,
+            ),
+        AssignmentTargetPattern::ObjectAssignmentTarget(_) => traverser
+            .replace_object_assignment_target(
+                ObjectAssignmentTargetSlot::AssignmentTargetPattern(&mut *node),
+                ctx,
+            ),
+    }
     match &mut *node {
         AssignmentTargetPattern::ArrayAssignmentTarget(node) => {
             walk_array_assignment_target(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1139,6 +1344,14 @@ pub(crate) unsafe fn walk_assignment_target_maybe_default<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_assignment_target_maybe_default(&mut *node, ctx);
+    match &*node {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(_) => traverser
+            .replace_assignment_target_with_default(
+                AssignmentTargetWithDefaultSlot::AssignmentTargetMaybeDefault(&mut *node),
+                ctx,
+            ),
+        _ => {}
+    }
     match &mut *node {
         AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(node) => {
             walk_assignment_target_with_default(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1194,6 +1407,18 @@ pub(crate) unsafe fn walk_assignment_target_property<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_assignment_target_property(&mut *node, ctx);
+    match &*node {
+        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(_) => traverser
+            .replace_assignment_target_property_identifier(
+                AssignmentTargetPropertyIdentifierSlot::AssignmentTargetProperty(&mut *node),
+                ctx,
+            ),
+        AssignmentTargetProperty::AssignmentTargetPropertyProperty(_) => traverser
+            .replace_assignment_target_property_property(
+                AssignmentTargetPropertyPropertySlot::AssignmentTargetProperty(&mut *node),
+                ctx,
+            ),
+    }
     match &mut *node {
         AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(node) => {
             walk_assignment_target_property_identifier(
@@ -1341,6 +1566,12 @@ pub(crate) unsafe fn walk_chain_element<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_chain_element(&mut *node, ctx);
+    match &*node {
+        ChainElement::CallExpression(_) => {
+            traverser.replace_call_expression(CallExpressionSlot::ChainElement(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         ChainElement::CallExpression(node) => {
             walk_call_expression(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1380,6 +1611,62 @@ pub(crate) unsafe fn walk_statement<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_statement(&mut *node, ctx);
+    match &*node {
+        Statement::BlockStatement(_) => {
+            traverser.replace_block_statement(BlockStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::BreakStatement(_) => {
+            traverser.replace_break_statement(BreakStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ContinueStatement(_) => {
+            traverser.replace_continue_statement(ContinueStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::DebuggerStatement(_) => {
+            traverser.replace_debugger_statement(DebuggerStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::DoWhileStatement(_) => {
+            traverser.replace_do_while_statement(DoWhileStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::EmptyStatement(_) => {
+            traverser.replace_empty_statement(EmptyStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ExpressionStatement(_) => traverser
+            .replace_expression_statement(ExpressionStatementSlot::Statement(&mut *node), ctx),
+        Statement::ForInStatement(_) => {
+            traverser.replace_for_in_statement(ForInStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ForOfStatement(_) => {
+            traverser.replace_for_of_statement(ForOfStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ForStatement(_) => {
+            traverser.replace_for_statement(ForStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::IfStatement(_) => {
+            traverser.replace_if_statement(IfStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::LabeledStatement(_) => {
+            traverser.replace_labeled_statement(LabeledStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ReturnStatement(_) => {
+            traverser.replace_return_statement(ReturnStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::SwitchStatement(_) => {
+            traverser.replace_switch_statement(SwitchStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::ThrowStatement(_) => {
+            traverser.replace_throw_statement(ThrowStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::TryStatement(_) => {
+            traverser.replace_try_statement(TryStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::WhileStatement(_) => {
+            traverser.replace_while_statement(WhileStatementSlot::Statement(&mut *node), ctx)
+        }
+        Statement::WithStatement(_) => {
+            traverser.replace_with_statement(WithStatementSlot::Statement(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         Statement::BlockStatement(node) => {
             walk_block_statement(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1516,6 +1803,33 @@ pub(crate) unsafe fn walk_declaration<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_declaration(&mut *node, ctx);
+    match &*node {
+        Declaration::VariableDeclaration(_) => traverser
+            .replace_variable_declaration(VariableDeclarationSlot::Declaration(&mut *node), ctx),
+        Declaration::FunctionDeclaration(_) => {
+            traverser.replace_function(FunctionSlot::Declaration(&mut *node), ctx)
+        }
+        Declaration::ClassDeclaration(_) => {
+            traverser.replace_class(ClassSlot::Declaration(&mut *node), ctx)
+        }
+        Declaration::TSTypeAliasDeclaration(_) => traverser.replace_ts_type_alias_declaration(
+            TSTypeAliasDeclarationSlot::Declaration(&mut *node),
+            ctx,
+        ),
+        Declaration::TSInterfaceDeclaration(_) => traverser.replace_ts_interface_declaration(
+            TSInterfaceDeclarationSlot::Declaration(&mut *node),
+            ctx,
+        ),
+        Declaration::TSEnumDeclaration(_) => traverser
+            .replace_ts_enum_declaration(TSEnumDeclarationSlot::Declaration(&mut *node), ctx),
+        Declaration::TSModuleDeclaration(_) => traverser
+            .replace_ts_module_declaration(TSModuleDeclarationSlot::Declaration(&mut *node), ctx),
+        Declaration::TSImportEqualsDeclaration(_) => traverser
+            .replace_ts_import_equals_declaration(
+                TSImportEqualsDeclarationSlot::Declaration(&mut *node),
+                ctx,
+            ),
+    }
     match &mut *node {
         Declaration::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1759,6 +2073,13 @@ pub(crate) unsafe fn walk_for_statement_init<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_for_statement_init(&mut *node, ctx);
+    match &*node {
+        ForStatementInit::VariableDeclaration(_) => traverser.replace_variable_declaration(
+            VariableDeclarationSlot::ForStatementInit(&mut *node),
+            ctx,
+        ),
+        _ => {}
+    }
     match &mut *node {
         ForStatementInit::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -1857,6 +2178,13 @@ pub(crate) unsafe fn walk_for_statement_left<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_for_statement_left(&mut *node, ctx);
+    match &*node {
+        ForStatementLeft::VariableDeclaration(_) => traverser.replace_variable_declaration(
+            VariableDeclarationSlot::ForStatementLeft(&mut *node),
+            ctx,
+        ),
+        _ => {}
+    }
     match &mut *node {
         ForStatementLeft::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -2246,6 +2574,18 @@ pub(crate) unsafe fn walk_binding_pattern_kind<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_binding_pattern_kind(&mut *node, ctx);
+    match &*node {
+        BindingPatternKind::BindingIdentifier(_) => traverser
+            .replace_binding_identifier(BindingIdentifierSlot::BindingPatternKind(&mut *node), ctx),
+        BindingPatternKind::ObjectPattern(_) => {
+            traverser.replace_object_pattern(ObjectPatternSlot::BindingPatternKind(&mut *node), ctx)
+        }
+        BindingPatternKind::ArrayPattern(_) => {
+            traverser.replace_array_pattern(ArrayPatternSlot::BindingPatternKind(&mut *node), ctx)
+        }
+        BindingPatternKind::AssignmentPattern(_) => traverser
+            .replace_assignment_pattern(AssignmentPatternSlot::BindingPatternKind(&mut *node), ctx),
+    }
     match &mut *node {
         BindingPatternKind::BindingIdentifier(node) => {
             walk_binding_identifier(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -2722,6 +3062,21 @@ pub(crate) unsafe fn walk_class_element<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_class_element(&mut *node, ctx);
+    match &*node {
+        ClassElement::StaticBlock(_) => {
+            traverser.replace_static_block(StaticBlockSlot::ClassElement(&mut *node), ctx)
+        }
+        ClassElement::MethodDefinition(_) => {
+            traverser.replace_method_definition(MethodDefinitionSlot::ClassElement(&mut *node), ctx)
+        }
+        ClassElement::PropertyDefinition(_) => traverser
+            .replace_property_definition(PropertyDefinitionSlot::ClassElement(&mut *node), ctx),
+        ClassElement::AccessorProperty(_) => {
+            traverser.replace_accessor_property(AccessorPropertySlot::ClassElement(&mut *node), ctx)
+        }
+        ClassElement::TSIndexSignature(_) => traverser
+            .replace_ts_index_signature(TSIndexSignatureSlot::ClassElement(&mut *node), ctx),
+    }
     match &mut *node {
         ClassElement::StaticBlock(node) => {
             walk_static_block(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -2865,6 +3220,32 @@ pub(crate) unsafe fn walk_module_declaration<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_module_declaration(&mut *node, ctx);
+    match &*node {
+        ModuleDeclaration::ImportDeclaration(_) => traverser
+            .replace_import_declaration(ImportDeclarationSlot::ModuleDeclaration(&mut *node), ctx),
+        ModuleDeclaration::ExportAllDeclaration(_) => traverser.replace_export_all_declaration(
+            ExportAllDeclarationSlot::ModuleDeclaration(&mut *node),
+            ctx,
+        ),
+        ModuleDeclaration::ExportDefaultDeclaration(_) => traverser
+            .replace_export_default_declaration(
+                ExportDefaultDeclarationSlot::ModuleDeclaration(&mut *node),
+                ctx,
+            ),
+        ModuleDeclaration::ExportNamedDeclaration(_) => traverser.replace_export_named_declaration(
+            ExportNamedDeclarationSlot::ModuleDeclaration(&mut *node),
+            ctx,
+        ),
+        ModuleDeclaration::TSExportAssignment(_) => traverser.replace_ts_export_assignment(
+            TSExportAssignmentSlot::ModuleDeclaration(&mut *node),
+            ctx,
+        ),
+        ModuleDeclaration::TSNamespaceExportDeclaration(_) => traverser
+            .replace_ts_namespace_export_declaration(
+                TSNamespaceExportDeclarationSlot::ModuleDeclaration(&mut *node),
+                ctx,
+            ),
+    }
     match &mut *node {
         ModuleDeclaration::ImportDeclaration(node) => {
             walk_import_declaration(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3000,6 +3381,22 @@ pub(crate) unsafe fn walk_import_declaration_specifier<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_import_declaration_specifier(&mut *node, ctx);
+    match &*node {
+        ImportDeclarationSpecifier::ImportSpecifier(_) => traverser.replace_import_specifier(
+            ImportSpecifierSlot::ImportDeclarationSpecifier(&mut *node),
+            ctx,
+        ),
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => traverser
+            .replace_import_default_specifier(
+                ImportDefaultSpecifierSlot::ImportDeclarationSpecifier(&mut *node),
+                ctx,
+            ),
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => traverser
+            .replace_import_namespace_specifier(
+                ImportNamespaceSpecifierSlot::ImportDeclarationSpecifier(&mut *node),
+                ctx,
+            ),
+    }
     match &mut *node {
         ImportDeclarationSpecifier::ImportSpecifier(node) => {
             walk_import_specifier(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3139,6 +3536,13 @@ pub(crate) unsafe fn walk_import_attribute_key<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_import_attribute_key(&mut *node, ctx);
+    match &*node {
+        ImportAttributeKey::Identifier(_) => traverser
+            .replace_identifier_name(IdentifierNameSlot::ImportAttributeKey(&mut *node), ctx),
+        ImportAttributeKey::StringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::ImportAttributeKey(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         ImportAttributeKey::Identifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(node), ctx)
@@ -3291,6 +3695,20 @@ pub(crate) unsafe fn walk_export_default_declaration_kind<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_export_default_declaration_kind(&mut *node, ctx);
+    match &*node {
+        ExportDefaultDeclarationKind::FunctionDeclaration(_) => {
+            traverser.replace_function(FunctionSlot::ExportDefaultDeclarationKind(&mut *node), ctx)
+        }
+        ExportDefaultDeclarationKind::ClassDeclaration(_) => {
+            traverser.replace_class(ClassSlot::ExportDefaultDeclarationKind(&mut *node), ctx)
+        }
+        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => traverser
+            .replace_ts_interface_declaration(
+                TSInterfaceDeclarationSlot::ExportDefaultDeclarationKind(&mut *node),
+                ctx,
+            ),
+        _ => {}
+    }
     match &mut *node {
         ExportDefaultDeclarationKind::FunctionDeclaration(node) => {
             walk_function(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3355,6 +3773,18 @@ pub(crate) unsafe fn walk_module_export_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_module_export_name(&mut *node, ctx);
+    match &*node {
+        ModuleExportName::IdentifierName(_) => {
+            traverser.replace_identifier_name(IdentifierNameSlot::ModuleExportName(&mut *node), ctx)
+        }
+        ModuleExportName::IdentifierReference(_) => traverser.replace_identifier_reference(
+            IdentifierReferenceSlot::ModuleExportName(&mut *node),
+            ctx,
+        ),
+        ModuleExportName::StringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::ModuleExportName(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         ModuleExportName::IdentifierName(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(node), ctx)
@@ -3485,6 +3915,22 @@ pub(crate) unsafe fn walk_jsx_element_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_element_name(&mut *node, ctx);
+    match &*node {
+        JSXElementName::Identifier(_) => {
+            traverser.replace_jsx_identifier(JSXIdentifierSlot::JSXElementName(&mut *node), ctx)
+        }
+        JSXElementName::IdentifierReference(_) => traverser
+            .replace_identifier_reference(IdentifierReferenceSlot::JSXElementName(&mut *node), ctx),
+        JSXElementName::NamespacedName(_) => traverser
+            .replace_jsx_namespaced_name(JSXNamespacedNameSlot::JSXElementName(&mut *node), ctx),
+        JSXElementName::MemberExpression(_) => traverser.replace_jsx_member_expression(
+            JSXMemberExpressionSlot::JSXElementName(&mut *node),
+            ctx,
+        ),
+        JSXElementName::ThisExpression(_) => {
+            traverser.replace_this_expression(ThisExpressionSlot::JSXElementName(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         JSXElementName::Identifier(node) => {
             walk_jsx_identifier(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3567,6 +4013,21 @@ pub(crate) unsafe fn walk_jsx_member_expression_object<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_member_expression_object(&mut *node, ctx);
+    match &*node {
+        JSXMemberExpressionObject::IdentifierReference(_) => traverser
+            .replace_identifier_reference(
+                IdentifierReferenceSlot::JSXMemberExpressionObject(&mut *node),
+                ctx,
+            ),
+        JSXMemberExpressionObject::MemberExpression(_) => traverser.replace_jsx_member_expression(
+            JSXMemberExpressionSlot::JSXMemberExpressionObject(&mut *node),
+            ctx,
+        ),
+        JSXMemberExpressionObject::ThisExpression(_) => traverser.replace_this_expression(
+            ThisExpressionSlot::JSXMemberExpressionObject(&mut *node),
+            ctx,
+        ),
+    }
     match &mut *node {
         JSXMemberExpressionObject::IdentifierReference(node) => {
             walk_identifier_reference(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3607,6 +4068,11 @@ pub(crate) unsafe fn walk_jsx_expression<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_expression(&mut *node, ctx);
+    match &*node {
+        JSXExpression::EmptyExpression(_) => traverser
+            .replace_jsx_empty_expression(JSXEmptyExpressionSlot::JSXExpression(&mut *node), ctx),
+        _ => {}
+    }
     match &mut *node {
         JSXExpression::EmptyExpression(node) => {
             walk_jsx_empty_expression(traverser, std::ptr::from_mut(node), ctx)
@@ -3672,6 +4138,15 @@ pub(crate) unsafe fn walk_jsx_attribute_item<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_attribute_item(&mut *node, ctx);
+    match &*node {
+        JSXAttributeItem::Attribute(_) => {
+            traverser.replace_jsx_attribute(JSXAttributeSlot::JSXAttributeItem(&mut *node), ctx)
+        }
+        JSXAttributeItem::SpreadAttribute(_) => traverser.replace_jsx_spread_attribute(
+            JSXSpreadAttributeSlot::JSXAttributeItem(&mut *node),
+            ctx,
+        ),
+    }
     match &mut *node {
         JSXAttributeItem::Attribute(node) => {
             walk_jsx_attribute(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3734,6 +4209,13 @@ pub(crate) unsafe fn walk_jsx_attribute_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_attribute_name(&mut *node, ctx);
+    match &*node {
+        JSXAttributeName::Identifier(_) => {
+            traverser.replace_jsx_identifier(JSXIdentifierSlot::JSXAttributeName(&mut *node), ctx)
+        }
+        JSXAttributeName::NamespacedName(_) => traverser
+            .replace_jsx_namespaced_name(JSXNamespacedNameSlot::JSXAttributeName(&mut *node), ctx),
+    }
     match &mut *node {
         JSXAttributeName::Identifier(node) => {
             walk_jsx_identifier(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3751,6 +4233,21 @@ pub(crate) unsafe fn walk_jsx_attribute_value<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_attribute_value(&mut *node, ctx);
+    match &*node {
+        JSXAttributeValue::StringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::JSXAttributeValue(&mut *node), ctx)
+        }
+        JSXAttributeValue::ExpressionContainer(_) => traverser.replace_jsx_expression_container(
+            JSXExpressionContainerSlot::JSXAttributeValue(&mut *node),
+            ctx,
+        ),
+        JSXAttributeValue::Element(_) => {
+            traverser.replace_jsx_element(JSXElementSlot::JSXAttributeValue(&mut *node), ctx)
+        }
+        JSXAttributeValue::Fragment(_) => {
+            traverser.replace_jsx_fragment(JSXFragmentSlot::JSXAttributeValue(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         JSXAttributeValue::StringLiteral(node) => {
             walk_string_literal(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -3783,6 +4280,22 @@ pub(crate) unsafe fn walk_jsx_child<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_jsx_child(&mut *node, ctx);
+    match &*node {
+        JSXChild::Text(_) => traverser.replace_jsx_text(JSXTextSlot::JSXChild(&mut *node), ctx),
+        JSXChild::Element(_) => {
+            traverser.replace_jsx_element(JSXElementSlot::JSXChild(&mut *node), ctx)
+        }
+        JSXChild::Fragment(_) => {
+            traverser.replace_jsx_fragment(JSXFragmentSlot::JSXChild(&mut *node), ctx)
+        }
+        JSXChild::ExpressionContainer(_) => traverser.replace_jsx_expression_container(
+            JSXExpressionContainerSlot::JSXChild(&mut *node),
+            ctx,
+        ),
+        JSXChild::Spread(_) => {
+            traverser.replace_jsx_spread_child(JSXSpreadChildSlot::JSXChild(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         JSXChild::Text(node) => walk_jsx_text(traverser, std::ptr::from_mut(&mut **node), ctx),
         JSXChild::Element(node) => {
@@ -3970,6 +4483,20 @@ pub(crate) unsafe fn walk_ts_enum_member_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_enum_member_name(&mut *node, ctx);
+    match &*node {
+        TSEnumMemberName::StaticIdentifier(_) => {
+            traverser.replace_identifier_name(IdentifierNameSlot::TSEnumMemberName(&mut *node), ctx)
+        }
+        TSEnumMemberName::StaticStringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::TSEnumMemberName(&mut *node), ctx)
+        }
+        TSEnumMemberName::StaticTemplateLiteral(_) => traverser
+            .replace_template_literal(TemplateLiteralSlot::TSEnumMemberName(&mut *node), ctx),
+        TSEnumMemberName::StaticNumericLiteral(_) => {
+            traverser.replace_numeric_literal(NumericLiteralSlot::TSEnumMemberName(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         TSEnumMemberName::StaticIdentifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -4073,6 +4600,32 @@ pub(crate) unsafe fn walk_ts_literal<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_literal(&mut *node, ctx);
+    match &*node {
+        TSLiteral::BooleanLiteral(_) => {
+            traverser.replace_boolean_literal(BooleanLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::NullLiteral(_) => {
+            traverser.replace_null_literal(NullLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::NumericLiteral(_) => {
+            traverser.replace_numeric_literal(NumericLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::BigIntLiteral(_) => {
+            traverser.replace_big_int_literal(BigIntLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::RegExpLiteral(_) => {
+            traverser.replace_reg_exp_literal(RegExpLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::StringLiteral(_) => {
+            traverser.replace_string_literal(StringLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::TemplateLiteral(_) => {
+            traverser.replace_template_literal(TemplateLiteralSlot::TSLiteral(&mut *node), ctx)
+        }
+        TSLiteral::UnaryExpression(_) => {
+            traverser.replace_unary_expression(UnaryExpressionSlot::TSLiteral(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         TSLiteral::BooleanLiteral(node) => {
             walk_boolean_literal(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -4108,6 +4661,118 @@ pub(crate) unsafe fn walk_ts_type<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_type(&mut *node, ctx);
+    match &*node {
+        TSType::TSAnyKeyword(_) => {
+            traverser.replace_ts_any_keyword(TSAnyKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSBigIntKeyword(_) => {
+            traverser.replace_ts_big_int_keyword(TSBigIntKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSBooleanKeyword(_) => {
+            traverser.replace_ts_boolean_keyword(TSBooleanKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSIntrinsicKeyword(_) => {
+            traverser.replace_ts_intrinsic_keyword(TSIntrinsicKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSNeverKeyword(_) => {
+            traverser.replace_ts_never_keyword(TSNeverKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSNullKeyword(_) => {
+            traverser.replace_ts_null_keyword(TSNullKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSNumberKeyword(_) => {
+            traverser.replace_ts_number_keyword(TSNumberKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSObjectKeyword(_) => {
+            traverser.replace_ts_object_keyword(TSObjectKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSStringKeyword(_) => {
+            traverser.replace_ts_string_keyword(TSStringKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSSymbolKeyword(_) => {
+            traverser.replace_ts_symbol_keyword(TSSymbolKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSUndefinedKeyword(_) => {
+            traverser.replace_ts_undefined_keyword(TSUndefinedKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSUnknownKeyword(_) => {
+            traverser.replace_ts_unknown_keyword(TSUnknownKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSVoidKeyword(_) => {
+            traverser.replace_ts_void_keyword(TSVoidKeywordSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSArrayType(_) => {
+            traverser.replace_ts_array_type(TSArrayTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSConditionalType(_) => {
+            traverser.replace_ts_conditional_type(TSConditionalTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSConstructorType(_) => {
+            traverser.replace_ts_constructor_type(TSConstructorTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSFunctionType(_) => {
+            traverser.replace_ts_function_type(TSFunctionTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSImportType(_) => {
+            traverser.replace_ts_import_type(TSImportTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSIndexedAccessType(_) => traverser
+            .replace_ts_indexed_access_type(TSIndexedAccessTypeSlot::TSType(&mut *node), ctx),
+        TSType::TSInferType(_) => {
+            traverser.replace_ts_infer_type(TSInferTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSIntersectionType(_) => {
+            traverser.replace_ts_intersection_type(TSIntersectionTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSLiteralType(_) => {
+            traverser.replace_ts_literal_type(TSLiteralTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSMappedType(_) => {
+            traverser.replace_ts_mapped_type(TSMappedTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSNamedTupleMember(_) => {
+            traverser.replace_ts_named_tuple_member(TSNamedTupleMemberSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSQualifiedName(_) => {
+            traverser.replace_ts_qualified_name(TSQualifiedNameSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTemplateLiteralType(_) => traverser
+            .replace_ts_template_literal_type(TSTemplateLiteralTypeSlot::TSType(&mut *node), ctx),
+        TSType::TSThisType(_) => {
+            traverser.replace_ts_this_type(TSThisTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTupleType(_) => {
+            traverser.replace_ts_tuple_type(TSTupleTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTypeLiteral(_) => {
+            traverser.replace_ts_type_literal(TSTypeLiteralSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTypeOperatorType(_) => {
+            traverser.replace_ts_type_operator(TSTypeOperatorSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTypePredicate(_) => {
+            traverser.replace_ts_type_predicate(TSTypePredicateSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTypeQuery(_) => {
+            traverser.replace_ts_type_query(TSTypeQuerySlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSTypeReference(_) => {
+            traverser.replace_ts_type_reference(TSTypeReferenceSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSUnionType(_) => {
+            traverser.replace_ts_union_type(TSUnionTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::TSParenthesizedType(_) => traverser
+            .replace_ts_parenthesized_type(TSParenthesizedTypeSlot::TSType(&mut *node), ctx),
+        TSType::JSDocNullableType(_) => {
+            traverser.replace_js_doc_nullable_type(JSDocNullableTypeSlot::TSType(&mut *node), ctx)
+        }
+        TSType::JSDocNonNullableType(_) => traverser
+            .replace_js_doc_non_nullable_type(JSDocNonNullableTypeSlot::TSType(&mut *node), ctx),
+        TSType::JSDocUnknownType(_) => {
+            traverser.replace_js_doc_unknown_type(JSDocUnknownTypeSlot::TSType(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         TSType::TSAnyKeyword(node) => {
             walk_ts_any_keyword(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -4485,6 +5150,15 @@ pub(crate) unsafe fn walk_ts_tuple_element<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_tuple_element(&mut *node, ctx);
+    match &*node {
+        TSTupleElement::TSOptionalType(_) => {
+            traverser.replace_ts_optional_type(TSOptionalTypeSlot::TSTupleElement(&mut *node), ctx)
+        }
+        TSTupleElement::TSRestType(_) => {
+            traverser.replace_ts_rest_type(TSRestTypeSlot::TSTupleElement(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         TSTupleElement::TSOptionalType(node) => {
             walk_ts_optional_type(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -4692,6 +5366,13 @@ pub(crate) unsafe fn walk_ts_type_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_type_name(&mut *node, ctx);
+    match &*node {
+        TSTypeName::IdentifierReference(_) => traverser
+            .replace_identifier_reference(IdentifierReferenceSlot::TSTypeName(&mut *node), ctx),
+        TSTypeName::QualifiedName(_) => {
+            traverser.replace_ts_qualified_name(TSQualifiedNameSlot::TSTypeName(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         TSTypeName::IdentifierReference(node) => {
             walk_identifier_reference(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -4987,6 +5668,25 @@ pub(crate) unsafe fn walk_ts_signature<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_signature(&mut *node, ctx);
+    match &*node {
+        TSSignature::TSIndexSignature(_) => {
+            traverser.replace_ts_index_signature(TSIndexSignatureSlot::TSSignature(&mut *node), ctx)
+        }
+        TSSignature::TSPropertySignature(_) => traverser
+            .replace_ts_property_signature(TSPropertySignatureSlot::TSSignature(&mut *node), ctx),
+        TSSignature::TSCallSignatureDeclaration(_) => traverser
+            .replace_ts_call_signature_declaration(
+                TSCallSignatureDeclarationSlot::TSSignature(&mut *node),
+                ctx,
+            ),
+        TSSignature::TSConstructSignatureDeclaration(_) => traverser
+            .replace_ts_construct_signature_declaration(
+                TSConstructSignatureDeclarationSlot::TSSignature(&mut *node),
+                ctx,
+            ),
+        TSSignature::TSMethodSignature(_) => traverser
+            .replace_ts_method_signature(TSMethodSignatureSlot::TSSignature(&mut *node), ctx),
+    }
     match &mut *node {
         TSSignature::TSIndexSignature(node) => {
             walk_ts_index_signature(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -5283,6 +5983,13 @@ pub(crate) unsafe fn walk_ts_type_predicate_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_type_predicate_name(&mut *node, ctx);
+    match &*node {
+        TSTypePredicateName::Identifier(_) => traverser
+            .replace_identifier_name(IdentifierNameSlot::TSTypePredicateName(&mut *node), ctx),
+        TSTypePredicateName::This(_) => {
+            traverser.replace_ts_this_type(TSThisTypeSlot::TSTypePredicateName(&mut *node), ctx)
+        }
+    }
     match &mut *node {
         TSTypePredicateName::Identifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -5338,6 +6045,12 @@ pub(crate) unsafe fn walk_ts_module_declaration_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_module_declaration_name(&mut *node, ctx);
+    match &*node {
+        TSModuleDeclarationName::Identifier(_) => traverser
+            .replace_identifier_name(IdentifierNameSlot::TSModuleDeclarationName(&mut *node), ctx),
+        TSModuleDeclarationName::StringLiteral(_) => traverser
+            .replace_string_literal(StringLiteralSlot::TSModuleDeclarationName(&mut *node), ctx),
+    }
     match &mut *node {
         TSModuleDeclarationName::Identifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(node), ctx)
@@ -5355,6 +6068,14 @@ pub(crate) unsafe fn walk_ts_module_declaration_body<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_module_declaration_body(&mut *node, ctx);
+    match &*node {
+        TSModuleDeclarationBody::TSModuleDeclaration(_) => traverser.replace_ts_module_declaration(
+            TSModuleDeclarationSlot::TSModuleDeclarationBody(&mut *node),
+            ctx,
+        ),
+        TSModuleDeclarationBody::TSModuleBlock(_) => traverser
+            .replace_ts_module_block(TSModuleBlockSlot::TSModuleDeclarationBody(&mut *node), ctx),
+    }
     match &mut *node {
         TSModuleDeclarationBody::TSModuleDeclaration(node) => {
             walk_ts_module_declaration(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -5471,6 +6192,12 @@ pub(crate) unsafe fn walk_ts_type_query_expr_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_type_query_expr_name(&mut *node, ctx);
+    match &*node {
+        TSTypeQueryExprName::TSImportType(_) => {
+            traverser.replace_ts_import_type(TSImportTypeSlot::TSTypeQueryExprName(&mut *node), ctx)
+        }
+        _ => {}
+    }
     match &mut *node {
         TSTypeQueryExprName::TSImportType(node) => {
             walk_ts_import_type(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -5585,6 +6312,12 @@ pub(crate) unsafe fn walk_ts_import_attribute_name<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_import_attribute_name(&mut *node, ctx);
+    match &*node {
+        TSImportAttributeName::Identifier(_) => traverser
+            .replace_identifier_name(IdentifierNameSlot::TSImportAttributeName(&mut *node), ctx),
+        TSImportAttributeName::StringLiteral(_) => traverser
+            .replace_string_literal(StringLiteralSlot::TSImportAttributeName(&mut *node), ctx),
+    }
     match &mut *node {
         TSImportAttributeName::Identifier(node) => {
             walk_identifier_name(traverser, std::ptr::from_mut(node), ctx)
@@ -5877,6 +6610,14 @@ pub(crate) unsafe fn walk_ts_module_reference<'a, Tr: Traverse<'a>>(
     ctx: &mut TraverseCtx<'a>,
 ) {
     traverser.enter_ts_module_reference(&mut *node, ctx);
+    match &*node {
+        TSModuleReference::ExternalModuleReference(_) => traverser
+            .replace_ts_external_module_reference(
+                TSExternalModuleReferenceSlot::TSModuleReference(&mut *node),
+                ctx,
+            ),
+        _ => {}
+    }
     match &mut *node {
         TSModuleReference::ExternalModuleReference(node) => {
             walk_ts_external_module_reference(traverser, std::ptr::from_mut(&mut **node), ctx)
@@ -69,15 +69,19 @@ pub use context::{TraverseAncestry, TraverseCtx, TraverseScoping};
 
 mod generated {
     pub mod ancestor;
+    pub mod replace;
     pub(super) mod scopes_collector;
     pub mod traverse;
     pub(super) mod walk;
 }
-pub use generated::{ancestor, ancestor::Ancestor, traverse::Traverse};
+pub use generated::{ancestor, ancestor::Ancestor, replace, traverse::Traverse};
 use generated::{scopes_collector, walk};
 
 mod compile_fail_tests;
 
+#[cfg(feature = "verify")]
+mod verify;
+
 /// Traverse AST with a [`Traverse`] impl.
 ///
 /// This allows:
@@ -141,6 +145,47 @@ mod compile_fail_tests;
 ///     }
 /// }
 /// ```
+///
+/// # Replacing nodes
+///
+/// A struct node can't replace itself from its `enter_*` method, as it only has a `&mut` ref to the
+/// struct, not to the enum which holds it. Instead of matching on the parent enum in e.g.
+/// `enter_expression`, implement `replace_*`, which is called with a typed [`replace`] slot for
+/// every enum which can hold the node, before the node is walked:
+///
+/// ```
+/// use oxc_ast::ast::*;
+/// use oxc_traverse::{replace::UnaryExpressionSlot, Traverse, TraverseCtx};
+///
+/// struct VoidToUndefined;
+///
+/// impl<'a> Traverse<'a> for VoidToUndefined {
+///     fn replace_unary_expression(
+///         &mut self,
+///         mut slot: UnaryExpressionSlot<'_, 'a>,
+///         ctx: &mut TraverseCtx<'a>,
+///     ) {
+///         let node = slot.node();
+///         if node.operator != UnaryOperator::Void {
+///             return;
+///         }
+///         let span = node.span;
+///         // `UnaryExpression` can also be held in a `TSLiteral`, which can't be replaced with
+///         // an identifier
+///         if let UnaryExpressionSlot::Expression(expr) = slot {
+///             *expr = ctx.ast.expression_identifier_reference(span, "undefined");
+///         }
+///     }
+/// }
+/// ```
+///
+/// The replacement node is then walked as usual, starting with its `enter_*` method.
+///
+/// # Verifying scopes and symbols
+///
+/// With the `verify` Cargo feature enabled, [`walk_program`] checks after each pass that
+/// `ScopeTree` and `SymbolTable` are still consistent with each other and with the AST,
+/// and panics with the name of the [`Traverse`] type which broke them.
 
 pub fn traverse_mut<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
@@ -162,4 +207,7 @@ pub fn walk_program<'a, Tr: Traverse<'a>>(
 ) {
     // SAFETY: Walk functions are constructed to avoid unsoundness
     unsafe { walk::walk_program(traverser, std::ptr::from_mut::<Program>(program), ctx) };
+
+    #[cfg(feature = "verify")]
+    verify::verify_semantic::<Tr>(program, ctx);
 }
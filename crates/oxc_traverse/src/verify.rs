@@ -0,0 +1,25 @@
+//! Verification of `ScopeTree` and `SymbolTable` after a traversal pass.
+//!
+//! Only compiled with `verify` feature enabled.
+
+use std::{any::type_name, fmt::Write};
+
+use oxc_ast::ast::Program;
+use oxc_semantic::post_transform_checker::check_semantic_consistency;
+
+use crate::TraverseCtx;
+
+/// Panic if traversal pass `Tr` has left `ScopeTree` or `SymbolTable` in an inconsistent state.
+pub(crate) fn verify_semantic<'a, Tr>(program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+    let Some(errors) =
+        check_semantic_consistency(ctx.scoping.symbols(), ctx.scoping.scopes(), program)
+    else {
+        return;
+    };
+
+    let mut message = format!("Scopes or symbols are inconsistent after `{}`:", type_name::<Tr>());
+    for error in errors {
+        write!(message, "\n* {error}").unwrap();
+    }
+    panic!("{message}");
+}
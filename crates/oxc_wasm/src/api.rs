@@ -0,0 +1,175 @@
+//! Standalone `parse`, `lint` and `format` functions.
+//!
+//! Unlike [crate::Oxc], these do not keep any state between calls, and everything they return is
+//! JSON compatible so results can be posted between workers as is.
+
+#![allow(clippy::needless_pass_by_value)]
+
+use std::rc::Rc;
+
+use oxc::{
+    allocator::Allocator,
+    codegen::{CodeGenerator, CommentOptions},
+    diagnostics::Error,
+    parser::{Parser, ParserReturn},
+    semantic::SemanticBuilder,
+};
+use oxc_linter::Linter;
+use serde::Serialize;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    options::{OxcFormatOptions, OxcParserOptions},
+    serialize_diagnostics, Oxc,
+};
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct ParseResult {
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Program")]
+    pub program: JsValue,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Comment[]")]
+    pub comments: Vec<JsValue>,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "OxcDiagnostic[]")]
+    pub errors: Vec<JsValue>,
+}
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct LintResult {
+    /// Syntax errors, or lint diagnostics if there are none.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "OxcDiagnostic[]")]
+    pub diagnostics: Vec<JsValue>,
+}
+
+#[derive(Default, Tsify)]
+#[wasm_bindgen(getter_with_clone)]
+pub struct FormatResult {
+    #[wasm_bindgen(readonly)]
+    pub code: String,
+
+    /// Source map as a JSON string, set when `sourcemap` is enabled.
+    #[wasm_bindgen(readonly)]
+    pub map: Option<String>,
+
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "OxcDiagnostic[]")]
+    pub errors: Vec<JsValue>,
+}
+
+/// Parse `source_text` into an ESTree compatible AST.
+///
+/// # Errors
+///
+/// * wasm bindgen serialization failed
+#[wasm_bindgen]
+pub fn parse(
+    source_text: String,
+    options: Option<OxcParserOptions>,
+) -> Result<ParseResult, serde_wasm_bindgen::Error> {
+    let options = options.unwrap_or_default();
+    let allocator = Allocator::default();
+    let ParserReturn { program, errors, trivias, .. } =
+        Parser::new(&allocator, &source_text, options.source_type())
+            .with_options(options.parse_options())
+            .parse();
+
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    let errors = errors.into_iter().map(Error::from).collect::<Vec<_>>();
+    Ok(ParseResult {
+        program: program.serialize(&serializer)?,
+        comments: Oxc::map_comments(&source_text, &trivias)
+            .iter()
+            .map(|comment| comment.serialize(&serializer))
+            .collect::<Result<_, _>>()?,
+        errors: serialize_diagnostics(&errors, &serializer),
+    })
+}
+
+/// Lint `source_text` with the default rules.
+///
+/// Syntax errors are returned instead of lint diagnostics when the source text is invalid.
+#[wasm_bindgen]
+pub fn lint(source_text: String, options: Option<OxcParserOptions>) -> LintResult {
+    let options = options.unwrap_or_default();
+    let path = options.path();
+    let allocator = Allocator::default();
+    let ParserReturn { program, errors, trivias, .. } =
+        Parser::new(&allocator, &source_text, options.source_type())
+            .with_options(options.parse_options())
+            .parse();
+
+    let semantic_ret = SemanticBuilder::new(&source_text)
+        .with_trivias(trivias)
+        .with_check_syntax_error(true)
+        .with_cfg(true)
+        .build_module_record(&path, &program)
+        .build(&program);
+
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    let syntax_errors =
+        errors.into_iter().chain(semantic_ret.errors).map(Error::from).collect::<Vec<_>>();
+    if !syntax_errors.is_empty() {
+        return LintResult { diagnostics: serialize_diagnostics(&syntax_errors, &serializer) };
+    }
+
+    let messages = Linter::default().run(&path, Rc::new(semantic_ret.semantic));
+    let diagnostics =
+        messages.into_iter().map(|message| Error::from(message.error)).collect::<Vec<_>>();
+    LintResult { diagnostics: serialize_diagnostics(&diagnostics, &serializer) }
+}
+
+/// Print `source_text` with the oxc code generator.
+///
+/// The source text is returned unchanged when it has syntax errors.
+#[wasm_bindgen]
+pub fn format(
+    source_text: String,
+    parser_options: Option<OxcParserOptions>,
+    format_options: Option<OxcFormatOptions>,
+) -> FormatResult {
+    let parser_options = parser_options.unwrap_or_default();
+    let format_options = format_options.unwrap_or_default();
+    let allocator = Allocator::default();
+    let ParserReturn { program, errors, trivias, .. } =
+        Parser::new(&allocator, &source_text, parser_options.source_type())
+            .with_options(parser_options.parse_options())
+            .parse();
+
+    if !errors.is_empty() {
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        let errors = errors.into_iter().map(Error::from).collect::<Vec<_>>();
+        return FormatResult {
+            code: source_text.clone(),
+            map: None,
+            errors: serialize_diagnostics(&errors, &serializer),
+        };
+    }
+
+    let mut codegen = CodeGenerator::new();
+    if format_options.comments.unwrap_or(true) {
+        codegen = codegen.enable_comment(
+            &source_text,
+            trivias,
+            CommentOptions { preserve_annotate_comments: true },
+        );
+    }
+    if format_options.sourcemap.unwrap_or_default() {
+        let path = parser_options.path();
+        codegen = codegen.enable_source_map(&path.to_string_lossy(), &source_text);
+    }
+    let ret = codegen.build(&program);
+
+    FormatResult {
+        code: ret.source_text,
+        map: ret.source_map.map(|map| map.to_json_string()),
+        errors: vec![],
+    }
+}
@@ -1,11 +1,12 @@
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
 
+mod api;
 mod options;
 
 use std::{
     cell::{Cell, RefCell},
-    path::{Path, PathBuf},
+    path::Path,
     rc::Rc,
 };
 
@@ -27,6 +28,7 @@ use serde::Serialize;
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
+pub use crate::api::{format, lint, parse, FormatResult, LintResult, ParseResult};
 use crate::options::{OxcOptions, OxcRunOptions};
 
 #[wasm_bindgen(getter_with_clone)]
@@ -47,6 +49,10 @@ pub struct Oxc {
     #[tsify(type = "SymbolTable")]
     pub symbols: JsValue,
 
+    #[wasm_bindgen(readonly, skip_typescript, js_name = "semanticDump")]
+    #[tsify(type = "SemanticDump")]
+    pub semantic_dump: JsValue,
+
     #[wasm_bindgen(readonly, skip_typescript, js_name = "scopeText")]
     pub scope_text: String,
 
@@ -107,26 +113,7 @@ impl Oxc {
     /// # Panics
     #[wasm_bindgen(js_name = getDiagnostics)]
     pub fn get_diagnostics(&self) -> Result<Vec<JsValue>, serde_wasm_bindgen::Error> {
-        Ok(self
-            .diagnostics
-            .borrow()
-            .iter()
-            .flat_map(|error| {
-                let Some(labels) = error.labels() else { return vec![] };
-                labels
-                    .map(|label| {
-                        OxcDiagnostic {
-                            start: label.offset(),
-                            end: label.offset() + label.len(),
-                            severity: format!("{:?}", error.severity().unwrap_or_default()),
-                            message: format!("{error}"),
-                        }
-                        .serialize(&self.serializer)
-                        .unwrap()
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>())
+        Ok(serialize_diagnostics(&self.diagnostics.borrow(), &self.serializer))
     }
 
     /// Returns comments
@@ -163,29 +150,11 @@ impl Oxc {
 
         let allocator = Allocator::default();
 
-        let path = PathBuf::from(
-            parser_options.source_filename.clone().unwrap_or_else(|| "test.tsx".to_string()),
-        );
-        let source_type = SourceType::from_path(&path).unwrap_or_default();
-        let source_type = match parser_options.source_type.as_deref() {
-            Some("script") => source_type.with_script(true),
-            Some("module") => source_type.with_module(true),
-            _ => source_type,
-        };
-
-        let default_parser_options = ParseOptions::default();
-        let oxc_parser_options = ParseOptions {
-            parse_regular_expression: true,
-            allow_return_outside_function: parser_options
-                .allow_return_outside_function
-                .unwrap_or(default_parser_options.allow_return_outside_function),
-            preserve_parens: parser_options
-                .preserve_parens
-                .unwrap_or(default_parser_options.preserve_parens),
-        };
+        let path = parser_options.path();
+        let source_type = parser_options.source_type();
         let ParserReturn { mut program, errors, trivias, .. } =
             Parser::new(&allocator, source_text, source_type)
-                .with_options(oxc_parser_options)
+                .with_options(parser_options.parse_options())
                 .parse();
 
         self.comments = Self::map_comments(source_text, &trivias);
@@ -212,6 +181,10 @@ impl Oxc {
 
         self.run_prettier(&run_options, source_text, source_type);
 
+        if run_options.semantic_dump.unwrap_or_default() {
+            self.semantic_dump = semantic_ret.semantic.debug_dump().serialize(&self.serializer)?;
+        }
+
         let (symbols, scopes) = semantic_ret.semantic.into_symbol_table_and_scope_tree();
 
         if !source_type.is_typescript_definition() {
@@ -258,7 +231,10 @@ impl Oxc {
                         drop_debugger: compress_options.drop_debugger,
                         evaluate: compress_options.evaluate,
                         join_vars: compress_options.join_vars,
+                        collapse_vars: compress_options.collapse_vars,
+                        if_return: compress_options.if_return,
                         loops: compress_options.loops,
+                        switches: compress_options.switches,
                         typeofs: compress_options.typeofs,
                         ..CompressOptions::default()
                     }
@@ -425,3 +401,28 @@ impl Oxc {
             .collect()
     }
 }
+
+/// Flatten diagnostics into one [OxcDiagnostic] per label.
+fn serialize_diagnostics(
+    diagnostics: &[Error],
+    serializer: &serde_wasm_bindgen::Serializer,
+) -> Vec<JsValue> {
+    diagnostics
+        .iter()
+        .flat_map(|error| {
+            let Some(labels) = error.labels() else { return vec![] };
+            labels
+                .map(|label| {
+                    OxcDiagnostic {
+                        start: label.offset(),
+                        end: label.offset() + label.len(),
+                        severity: format!("{:?}", error.severity().unwrap_or_default()),
+                        message: format!("{error}"),
+                    }
+                    .serialize(serializer)
+                    .unwrap()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+}
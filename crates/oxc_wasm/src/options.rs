@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use oxc::{parser::ParseOptions, span::SourceType};
 use serde::Deserialize;
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
@@ -42,6 +45,8 @@ pub struct OxcRunOptions {
     pub scope: Option<bool>,
     #[tsify(optional)]
     pub symbol: Option<bool>,
+    #[tsify(optional)]
+    pub semantic_dump: Option<bool>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Tsify)]
@@ -58,6 +63,35 @@ pub struct OxcParserOptions {
     pub source_filename: Option<String>,
 }
 
+impl OxcParserOptions {
+    /// Path of the source file, defaults to `test.tsx`.
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(self.source_filename.clone().unwrap_or_else(|| "test.tsx".to_string()))
+    }
+
+    /// Source type inferred from [Self::path], with `script` or `module` forced by `sourceType`.
+    pub fn source_type(&self) -> SourceType {
+        let source_type = SourceType::from_path(self.path()).unwrap_or_default();
+        match self.source_type.as_deref() {
+            Some("script") => source_type.with_script(true),
+            Some("module") => source_type.with_module(true),
+            _ => source_type,
+        }
+    }
+
+    pub fn parse_options(&self) -> ParseOptions {
+        let default_parser_options = ParseOptions::default();
+        ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function: self
+                .allow_return_outside_function
+                .unwrap_or(default_parser_options.allow_return_outside_function),
+            preserve_parens: self.preserve_parens.unwrap_or(default_parser_options.preserve_parens),
+            tokens: default_parser_options.tokens,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Tsify)]
 #[tsify(from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
@@ -82,6 +116,18 @@ pub struct OxcCodegenOptions {
     pub enable_typescript: Option<bool>,
 }
 
+#[derive(Debug, Default, Clone, Deserialize, Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct OxcFormatOptions {
+    /// Generate a source map, returned as a JSON string.
+    #[tsify(optional)]
+    pub sourcemap: Option<bool>,
+    /// Keep annotation comments such as `/* #__PURE__ */` in the output.
+    #[tsify(optional)]
+    pub comments: Option<bool>,
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Tsify)]
 #[tsify(from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
@@ -104,7 +150,10 @@ pub struct OxcCompressOptions {
     pub drop_console: bool,
     pub evaluate: bool,
     pub join_vars: bool,
+    pub collapse_vars: bool,
+    pub if_return: bool,
     pub loops: bool,
+    pub switches: bool,
     pub typeofs: bool,
 }
 
@@ -117,7 +166,10 @@ impl Default for OxcCompressOptions {
             drop_console: false,
             evaluate: true,
             join_vars: true,
+            collapse_vars: true,
+            if_return: true,
             loops: true,
+            switches: true,
             typeofs: true,
         }
     }
@@ -0,0 +1,118 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+/**
+ * Minify JavaScript code.
+ *
+ * @param sourceText the source code itself
+ * @param options The options for minification. See {@link MinifyOptions}
+ * for more information.
+ *
+ * @returns an object containing the minified code, source map, name cache
+ * and any errors that occurred during parsing or in the options.
+ */
+export declare function minifySync(sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
+
+export interface MinifyOptions {
+  /**
+   * The name of the file being minified, used for source maps and error
+   * messages. The extension also determines how the code is parsed.
+   *
+   * @default 'input.js'
+   */
+  filename?: string
+  sourceType?: 'script' | 'module' | 'unambiguous' | undefined
+  /**
+   * Enable dead code elimination, constant folding and syntax compression.
+   *
+   * @default true
+   */
+  compress?: boolean
+  /**
+   * Shorten the names of local variables.
+   *
+   * @default true
+   */
+  mangle?: boolean
+  /**
+   * Enable source map generation.
+   *
+   * When `true`, the `map` field of {@link MinifyResult} will be set.
+   *
+   * @default false
+   */
+  sourcemap?: boolean
+  /**
+   * Mangled names of top level bindings from a previous call, keyed by
+   * their original name. Pass {@link MinifyResult#nameCache} from the
+   * previous call to keep top level names consistent between files that
+   * share a global scope.
+   *
+   * Only used when `mangle` is enabled.
+   */
+  nameCache?: Record<string, string>
+  /**
+   * Also shorten the names of variables which can be accessed by code
+   * evaluated at runtime, e.g. variables of functions calling `eval`.
+   *
+   * Only used when `mangle` is enabled.
+   *
+   * @default false
+   */
+  forceMangle?: boolean
+  /**
+   * Replace global identifiers and member expressions with constant
+   * expressions before compressing, e.g.
+   * `{ "process.env.NODE_ENV": "\"production\"" }`.
+   *
+   * Values are parsed as JavaScript expressions.
+   */
+  define?: Record<string, string>
+  /**
+   * The environments the code runs in, as a [browserslist query](https://github.com/browserslist/browserslist#queries),
+   * e.g. `"node 18"`. When all targets are Node.js, or none of them are,
+   * `typeof` checks of their globals are folded, e.g.
+   * `typeof window !== "undefined"` is `false` for Node.js.
+   *
+   * Only used when `compress` is enabled.
+   */
+  targets?: string
+}
+
+export interface MinifyResult {
+  /**
+   * The minified code.
+   *
+   * If parsing failed, this will be an empty string.
+   */
+  code: string
+  /**
+   * The source map for the minified code.
+   *
+   * This will be set if {@link MinifyOptions#sourcemap} is `true`.
+   */
+  map?: SourceMap
+  /**
+   * Mangled names of top level bindings, including the ones passed in
+   * {@link MinifyOptions#nameCache}. Pass it to the next call to reuse them.
+   *
+   * This will be set if {@link MinifyOptions#mangle} is enabled.
+   */
+  nameCache?: Record<string, string>
+  /**
+   * Parse errors and invalid {@link MinifyOptions#define} or
+   * {@link MinifyOptions#targets}.
+   *
+   * Code is not minified if there are any errors.
+   */
+  errors: Array<string>
+}
+
+export interface SourceMap {
+  file?: string
+  mappings?: string
+  sourceRoot?: string
+  sources?: Array<string | undefined | null>
+  sourcesContent?: Array<string | undefined | null>
+  names?: Array<string>
+}
+
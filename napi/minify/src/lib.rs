@@ -0,0 +1,238 @@
+use std::{collections::HashMap, sync::Arc};
+
+use napi_derive::napi;
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_diagnostics::{Error, NamedSource};
+use oxc_mangler::{MangleOptions, Mangler};
+use oxc_minifier::{
+    CompressOptions, Compressor, KnownGlobals, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::Targets;
+
+// NOTE: Use JSDoc syntax for all doc comments, not rustdoc.
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MinifyOptions {
+    /// The name of the file being minified, used for source maps and error
+    /// messages. The extension also determines how the code is parsed.
+    ///
+    /// @default 'input.js'
+    pub filename: Option<String>,
+
+    #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
+    pub source_type: Option<String>,
+
+    /// Enable dead code elimination, constant folding and syntax compression.
+    ///
+    /// @default true
+    pub compress: Option<bool>,
+
+    /// Shorten the names of local variables.
+    ///
+    /// @default true
+    pub mangle: Option<bool>,
+
+    /// Enable source map generation.
+    ///
+    /// When `true`, the `map` field of {@link MinifyResult} will be set.
+    ///
+    /// @default false
+    pub sourcemap: Option<bool>,
+
+    /// Mangled names of top level bindings from a previous call, keyed by
+    /// their original name. Pass {@link MinifyResult#nameCache} from the
+    /// previous call to keep top level names consistent between files that
+    /// share a global scope.
+    ///
+    /// Only used when `mangle` is enabled.
+    pub name_cache: Option<HashMap<String, String>>,
+
+    /// Also shorten the names of variables which can be accessed by code
+    /// evaluated at runtime, e.g. variables of functions calling `eval`.
+    ///
+    /// Only used when `mangle` is enabled.
+    ///
+    /// @default false
+    pub force_mangle: Option<bool>,
+
+    /// Replace global identifiers and member expressions with constant
+    /// expressions before compressing, e.g.
+    /// `{ "process.env.NODE_ENV": "\"production\"" }`.
+    ///
+    /// Values are parsed as JavaScript expressions.
+    pub define: Option<HashMap<String, String>>,
+
+    /// The environments the code runs in, as a [browserslist query](https://github.com/browserslist/browserslist#queries),
+    /// e.g. `"node 18"`. When all targets are Node.js, or none of them are,
+    /// `typeof` checks of their globals are folded, e.g.
+    /// `typeof window !== "undefined"` is `false` for Node.js.
+    ///
+    /// Only used when `compress` is enabled.
+    pub targets: Option<String>,
+}
+
+#[napi(object)]
+pub struct SourceMap {
+    pub file: Option<String>,
+    pub mappings: Option<String>,
+    pub source_root: Option<String>,
+    pub sources: Option<Vec<Option<String>>>,
+    pub sources_content: Option<Vec<Option<String>>>,
+    pub names: Option<Vec<String>>,
+}
+
+impl From<oxc_sourcemap::SourceMap> for SourceMap {
+    fn from(source_map: oxc_sourcemap::SourceMap) -> Self {
+        let json = source_map.to_json();
+        Self {
+            file: json.file,
+            mappings: json.mappings,
+            source_root: json.source_root,
+            sources: json.sources,
+            sources_content: json.sources_content,
+            names: json.names,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct MinifyResult {
+    /// The minified code.
+    ///
+    /// If parsing failed, this will be an empty string.
+    pub code: String,
+
+    /// The source map for the minified code.
+    ///
+    /// This will be set if {@link MinifyOptions#sourcemap} is `true`.
+    pub map: Option<SourceMap>,
+
+    /// Mangled names of top level bindings, including the ones passed in
+    /// {@link MinifyOptions#nameCache}. Pass it to the next call to reuse them.
+    ///
+    /// This will be set if {@link MinifyOptions#mangle} is enabled.
+    pub name_cache: Option<HashMap<String, String>>,
+
+    /// Parse errors and invalid {@link MinifyOptions#define} or
+    /// {@link MinifyOptions#targets}.
+    ///
+    /// Code is not minified if there are any errors.
+    pub errors: Vec<String>,
+}
+
+/// Minify JavaScript code.
+///
+/// @param sourceText the source code itself
+/// @param options The options for minification. See {@link MinifyOptions}
+/// for more information.
+///
+/// @returns an object containing the minified code, source map, name cache
+/// and any errors that occurred during parsing or in the options.
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn minify_sync(source_text: String, options: Option<MinifyOptions>) -> MinifyResult {
+    let options = options.unwrap_or_default();
+    let filename = options.filename.as_deref().unwrap_or("input.js");
+
+    let source_type = {
+        let mut source_type = SourceType::from_path(filename).unwrap_or_default();
+        // Force `script` or `module`
+        match options.source_type.as_deref() {
+            Some("script") => source_type = source_type.with_script(true),
+            Some("module") => source_type = source_type.with_module(true),
+            _ => {}
+        }
+        source_type
+    };
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        let source = Arc::new(NamedSource::new(filename, source_text.clone()));
+        let errors = ret
+            .errors
+            .into_iter()
+            .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
+            .map(|error| format!("{error:?}"))
+            .collect();
+        return MinifyResult { code: String::new(), map: None, name_cache: None, errors };
+    }
+
+    let mut errors = vec![];
+    let define = options.define.as_ref().and_then(|define| {
+        let define = define.iter().collect::<Vec<_>>();
+        ReplaceGlobalDefinesConfig::new(&define)
+            .map_err(|diagnostics| {
+                errors.extend(
+                    diagnostics
+                        .into_iter()
+                        .map(|diagnostic| format!("{:?}", Error::from(diagnostic))),
+                );
+            })
+            .ok()
+    });
+    let known_globals =
+        match options.targets.as_deref().map(|query| Targets::from_query(query).get_targets()) {
+            Some(Ok(versions)) => KnownGlobals::for_engines(versions.keys().map(String::as_str)),
+            Some(Err(error)) => {
+                errors.push(error.to_string());
+                KnownGlobals::default()
+            }
+            None => KnownGlobals::default(),
+        };
+    if !errors.is_empty() {
+        return MinifyResult { code: String::new(), map: None, name_cache: None, errors };
+    }
+
+    let mut program = ret.program;
+
+    if let Some(config) = define {
+        ReplaceGlobalDefines::new(&allocator, config).build(&mut program);
+    }
+
+    if options.compress.unwrap_or(true) {
+        let options = CompressOptions { known_globals, ..CompressOptions::default() };
+        Compressor::new(&allocator, options).build(&mut program);
+    }
+
+    let mangler = options.mangle.unwrap_or(true).then(|| {
+        let name_cache = options
+            .name_cache
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, mangled)| (name.into(), mangled.into()))
+            .collect();
+        Mangler::new()
+            .with_options(MangleOptions {
+                name_cache,
+                force: options.force_mangle.unwrap_or(false),
+                ..MangleOptions::default()
+            })
+            .build(&program)
+    });
+    let name_cache = mangler.as_ref().map(|mangler| {
+        mangler
+            .name_cache()
+            .iter()
+            .map(|(name, mangled)| (name.to_string(), mangled.to_string()))
+            .collect()
+    });
+
+    let mut codegen = CodeGenerator::new()
+        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() });
+    if options.sourcemap == Some(true) {
+        codegen = codegen.enable_source_map(filename, &source_text);
+    }
+    let ret = codegen.with_mangler(mangler).build(&program);
+
+    MinifyResult {
+        code: ret.source_text,
+        map: ret.source_map.map(Into::into),
+        name_cache,
+        errors: vec![],
+    }
+}
@@ -0,0 +1,40 @@
+import oxc from "./index.js";
+import assert from "assert";
+
+console.log(`Testing on ${process.platform}-${process.arch}`);
+
+{
+  const ret = oxc.minifySync("function foo(bar) { debugger; return bar + 1 }\nfoo(1);", {
+    sourcemap: true,
+  });
+  console.log(ret.code);
+  assert(ret.errors.length == 0);
+  assert(ret.map);
+  assert(!ret.code.includes("debugger"));
+  assert(!ret.code.includes("bar"));
+}
+
+{
+  const first = oxc.minifySync("var foo = 1; bar(foo);");
+  const second = oxc.minifySync("var baz = 1; var foo = 2; bar(foo, baz);", {
+    nameCache: first.nameCache,
+  });
+  assert.equal(second.nameCache.foo, first.nameCache.foo);
+  assert.notEqual(second.nameCache.baz, first.nameCache.foo);
+}
+
+{
+  const ret = oxc.minifySync("var;");
+  assert.equal(ret.code, "");
+  assert(ret.errors.length == 1);
+}
+
+{
+  const ret = oxc.minifySync(
+    "if (typeof window !== 'undefined') { foo() } if (process.env.NODE_ENV !== 'production') { bar() }",
+    { define: { "process.env.NODE_ENV": '"production"' }, targets: "node 18", mangle: false },
+  );
+  assert(ret.errors.length == 0);
+  assert(!ret.code.includes("foo"));
+  assert(!ret.code.includes("bar"));
+}
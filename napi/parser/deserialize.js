@@ -0,0 +1,437 @@
+'use strict';
+
+// Deserializer of the AST returned by `parseSyncRaw`.
+//
+// The buffer contains the memory of the arena the AST is allocated in, see
+// `napi/parser/src/raw_transfer.rs` for its format. Nodes are read out of it with the memory
+// layout of the AST types in `layout.json`, and fields holding other nodes are only read when
+// they are accessed. The result has the same shape as `JSON.parse(parseSync(...).program)`.
+
+const { types } = require('./layout.json');
+
+const HEADER_SIZE = 32;
+const REGION_SIZE = 16;
+
+const typesByName = new Map(types.map((type) => [type.name, type]));
+
+/**
+ * @param {Buffer} buffer `program` of the result of `parseSyncRaw`
+ * @returns {import('./ast').Program}
+ */
+function deserialize(buffer) {
+  return new Deserializer(buffer).readProgram();
+}
+
+class Deserializer {
+  constructor(buffer) {
+    const view = new DataView(buffer.buffer, buffer.byteOffset, buffer.byteLength);
+    this.buffer = buffer;
+    this.view = view;
+    this.pointerSize = view.getUint32(0, true);
+    this.vecPtrOffset = view.getUint32(4, true);
+    this.vecLenOffset = view.getUint32(8, true);
+    this.strPtrOffset = view.getUint32(12, true);
+    this.strLenOffset = view.getUint32(16, true);
+    this.regions = [];
+    const regionCount = view.getUint32(20, true);
+    for (let i = 0; i < regionCount; i++) {
+      const pos = HEADER_SIZE + i * REGION_SIZE;
+      this.regions.push({
+        address: this.u64(pos),
+        offset: view.getUint32(pos + 8, true),
+        len: view.getUint32(pos + 12, true),
+      });
+    }
+    this.programAddress = this.u64(24);
+    this.suffix = this.pointerSize === 8 ? '64' : '32';
+    this.readers = new Map();
+  }
+
+  readProgram() {
+    return this.reader('Program')(this.position(this.programAddress));
+  }
+
+  u64(pos) {
+    return this.view.getUint32(pos, true) + this.view.getUint32(pos + 4, true) * 0x100000000;
+  }
+
+  pointer(pos) {
+    return this.pointerSize === 8 ? this.u64(pos) : this.view.getUint32(pos, true);
+  }
+
+  /** Position in the buffer of the memory at `address`. */
+  position(address) {
+    for (const region of this.regions) {
+      if (address >= region.address && address <= region.address + region.len) {
+        return region.offset + address - region.address;
+      }
+    }
+    throw new Error(`Address 0x${address.toString(16)} is outside of the AST buffer`);
+  }
+
+  str(pos) {
+    const len = this.pointer(pos + this.strLenOffset);
+    if (len === 0) return '';
+    const start = this.position(this.pointer(pos + this.strPtrOffset));
+    return this.buffer.toString('utf8', start, start + len);
+  }
+
+  /** Function reading a value of the type `type` at a position of the buffer. */
+  reader(type) {
+    let reader = this.readers.get(type);
+    if (reader === undefined) {
+      // Set before compiling to allow recursive types.
+      this.readers.set(type, (pos) => reader(pos));
+      reader = this.compile(parseType(type));
+      this.readers.set(type, reader);
+    }
+    return reader;
+  }
+
+  compile({ name, args }) {
+    const { view } = this;
+    switch (name) {
+      case 'bool':
+        return (pos) => view.getUint8(pos) === 1;
+      case 'u8':
+        return (pos) => view.getUint8(pos);
+      case 'u32':
+        return (pos) => view.getUint32(pos, true);
+      case 'u64':
+        return (pos) => this.u64(pos);
+      case 'f64':
+        return (pos) => view.getFloat64(pos, true);
+      case 'Atom':
+      case '&str':
+        return (pos) => this.str(pos);
+      case 'RegExpFlags':
+        return (pos) => regExpFlags(view.getUint8(pos));
+      case 'Box': {
+        const read = this.reader(args[0]);
+        return (pos) => read(this.position(this.pointer(pos)));
+      }
+      case 'Vec': {
+        const read = this.reader(args[0]);
+        const size = this.sizeOf(parseType(args[0]));
+        return (pos) => {
+          const len = this.pointer(pos + this.vecLenOffset);
+          if (len === 0) return [];
+          const start = this.position(this.pointer(pos + this.vecPtrOffset));
+          const elements = new Array(len);
+          for (let i = 0; i < len; i++) elements[i] = read(start + i * size);
+          return elements;
+        };
+      }
+      case 'Option': {
+        const inner = parseType(args[0]);
+        const read = this.reader(args[0]);
+        // `Option<u64>` has no niche, the discriminant precedes the value.
+        if (inner.name === 'u64') {
+          return (pos) => (view.getUint8(pos) === 0 ? null : read(pos + 8));
+        }
+        const isNone = this.invalid(inner);
+        return (pos) => (isNone(pos) ? null : read(pos));
+      }
+    }
+
+    const type = typesByName.get(name);
+    if (type === undefined) throw new Error(`Unknown type \`${name}\``);
+    if (Object.prototype.hasOwnProperty.call(CUSTOM, name)) return CUSTOM[name](this, type);
+    return type.kind === 'struct' ? this.compileStruct(type) : this.compileEnum(type);
+  }
+
+  compileStruct(type) {
+    if (type.fields.length === 0) return () => null;
+
+    const fields = [];
+    for (const field of type.fields) {
+      if (field.serializedName === null && !field.flatten) continue;
+      const read = this.reader(field.type);
+      const offset = field[`offset${this.suffix}`];
+      if (field.flatten) {
+        fields.push((node, pos) => {
+          Object.defineProperties(node, Object.getOwnPropertyDescriptors(read(pos + offset)));
+        });
+      } else if (isScalar(parseType(field.type))) {
+        fields.push((node, pos) => (node[field.serializedName] = read(pos + offset)));
+      } else {
+        fields.push((node, pos) => lazy(node, field.serializedName, () => read(pos + offset)));
+      }
+    }
+
+    const { tag, serializedType } = type;
+    return (pos) => {
+      const node = {};
+      if (tag !== undefined) node[tag] = serializedType;
+      for (const field of fields) field(node, pos);
+      return node;
+    };
+  }
+
+  compileEnum(type) {
+    const payloadOffset = type[`align${this.suffix}`];
+    const variants = new Map();
+    for (const variant of type.variants) {
+      let read;
+      if (variant.type === null) {
+        read = type.untagged ? () => null : () => variant.serializedName;
+      } else {
+        const readPayload = this.reader(variant.type);
+        read = type.untagged
+          ? (pos) => readPayload(pos + payloadOffset)
+          : (pos) => ({ [variant.serializedName]: readPayload(pos + payloadOffset) });
+      }
+      variants.set(variant.discriminant, read);
+    }
+    const { view } = this;
+    return (pos) => variants.get(view.getUint8(pos))(pos);
+  }
+
+  /** Size of a value of the type in memory. */
+  sizeOf({ name, args }) {
+    switch (name) {
+      case 'bool':
+      case 'u8':
+      case 'RegExpFlags':
+        return 1;
+      case 'u32':
+        return 4;
+      case 'u64':
+      case 'f64':
+        return 8;
+      case 'Box':
+        return this.pointerSize;
+      case 'Atom':
+      case '&str':
+        return this.pointerSize * 2;
+      case 'Vec':
+        return this.pointerSize * 4;
+      case 'Option': {
+        const inner = parseType(args[0]);
+        return inner.name === 'u64' ? 16 : this.sizeOf(inner);
+      }
+    }
+    return typesByName.get(name)[`size${this.suffix}`];
+  }
+
+  /**
+   * Function returning whether a value of the type at a position holds an invalid value, i.e.
+   * whether the `Option` containing it is `None`.
+   *
+   * `None` is represented by an invalid value of one of the fields with a niche, e.g. a null
+   * pointer or an unknown enum discriminant. Which field is used is up to the compiler, but all
+   * fields of a valid value are valid.
+   */
+  invalid({ name, args }) {
+    const { view } = this;
+    switch (name) {
+      case 'bool':
+        return (pos) => view.getUint8(pos) > 1;
+      case 'Box':
+        return (pos) => this.pointer(pos) === 0;
+      case 'Atom':
+      case '&str':
+        return (pos) => this.pointer(pos + this.strPtrOffset) === 0;
+      case 'Vec':
+        return (pos) => this.pointer(pos + this.vecPtrOffset) === 0;
+      case 'Option': {
+        const inner = typesByName.get(parseType(args[0]).name);
+        if (inner === undefined || inner.kind !== 'enum') return () => false;
+        // The discriminant after the last one is the `None` of the `Option`.
+        const discriminants = new Set(inner.variants.map((variant) => variant.discriminant));
+        const none = Math.max(...discriminants) + 1;
+        return (pos) => {
+          const discriminant = view.getUint8(pos);
+          return discriminant !== none && !discriminants.has(discriminant);
+        };
+      }
+    }
+
+    const type = typesByName.get(name);
+    if (type === undefined) return () => false;
+    if (type.kind === 'enum') {
+      const discriminants = new Set(type.variants.map((variant) => variant.discriminant));
+      return (pos) => !discriminants.has(view.getUint8(pos));
+    }
+    const fields = type.fields.map((field) => {
+      const invalid = this.invalid(parseType(field.type));
+      const offset = field[`offset${this.suffix}`];
+      return (pos) => invalid(pos + offset);
+    });
+    return (pos) => fields.some((invalid) => invalid(pos));
+  }
+
+  /** Function reading the field `fieldName` of the struct `typeName`. */
+  field(typeName, fieldName) {
+    const field = findField(typeName, fieldName);
+    const read = this.reader(field.type);
+    const offset = field[`offset${this.suffix}`];
+    return (pos) => read(pos + offset);
+  }
+
+  /** Offset of the field `fieldName` in the struct `typeName`. */
+  offset(typeName, fieldName) {
+    return findField(typeName, fieldName)[`offset${this.suffix}`];
+  }
+}
+
+/**
+ * Types with a hand written `Serialize` implementation in `crates/oxc_ast/src/serialize.rs`.
+ */
+const CUSTOM = {
+  Elision: () => () => null,
+  ArrayAssignmentTarget: elementsAndRest('elements'),
+  ObjectAssignmentTarget: elementsAndRest('properties'),
+  ObjectPattern: elementsAndRest('properties'),
+  ArrayPattern: elementsAndRest('elements'),
+  FormalParameters(deserializer) {
+    const span = deserializer.field('FormalParameters', 'span');
+    const kind = deserializer.field('FormalParameters', 'kind');
+    const items = deserializer.field('FormalParameters', 'items');
+    const restOffset = deserializer.offset('FormalParameters', 'rest');
+    const restSpan = deserializer.field('BindingRestElement', 'span');
+    const argumentOffset = deserializer.offset('BindingRestElement', 'argument');
+    const argument = deserializer.field('BindingPattern', 'kind');
+    const typeAnnotation = deserializer.field('BindingPattern', 'type_annotation');
+    const optional = deserializer.field('BindingPattern', 'optional');
+    return (pos) => {
+      const node = { type: 'FormalParameters', ...span(pos), kind: kind(pos) };
+      lazy(node, 'items', () => {
+        const elements = items(pos);
+        const restPointer = deserializer.pointer(pos + restOffset);
+        if (restPointer !== 0) {
+          const restPos = deserializer.position(restPointer);
+          const argumentPos = restPos + argumentOffset;
+          elements.push({
+            type: 'RestElement',
+            ...restSpan(restPos),
+            argument: argument(argumentPos),
+            typeAnnotation: typeAnnotation(argumentPos),
+            optional: optional(argumentPos),
+          });
+        }
+        return elements;
+      });
+      return node;
+    };
+  },
+  TSModuleBlock(deserializer) {
+    const span = deserializer.field('TSModuleBlock', 'span');
+    const directives = deserializer.field('TSModuleBlock', 'directives');
+    const body = deserializer.field('TSModuleBlock', 'body');
+    return (pos) => {
+      const node = { type: 'TSModuleBlock', ...span(pos) };
+      lazy(node, 'body', () => {
+        const statements = directives(pos).map((directive) => ({
+          type: 'ExpressionStatement',
+          start: directive.start,
+          end: directive.end,
+          expression: directive.expression,
+        }));
+        return statements.concat(body(pos));
+      });
+      return node;
+    };
+  },
+};
+
+function findField(typeName, fieldName) {
+  return typesByName.get(typeName).fields.find((field) => field.name === fieldName);
+}
+
+/** Nodes with `rest` serialized as the last element of `elements` or `properties`. */
+function elementsAndRest(fieldName) {
+  return (deserializer, { name }) => {
+    const span = deserializer.field(name, 'span');
+    const elements = deserializer.field(name, fieldName);
+    const rest = deserializer.field(name, 'rest');
+    return (pos) => {
+      const node = { type: name, ...span(pos) };
+      lazy(node, fieldName, () => {
+        const restElement = rest(pos);
+        return restElement === null ? elements(pos) : [...elements(pos), restElement];
+      });
+      return node;
+    };
+  };
+}
+
+/** Define `node[key]` as `read()`, which is only called when the property is accessed. */
+function lazy(node, key, read) {
+  Object.defineProperty(node, key, {
+    configurable: true,
+    enumerable: true,
+    get() {
+      const value = read();
+      Object.defineProperty(node, key, {
+        value,
+        configurable: true,
+        enumerable: true,
+        writable: true,
+      });
+      return value;
+    },
+    set(value) {
+      Object.defineProperty(node, key, {
+        value,
+        configurable: true,
+        enumerable: true,
+        writable: true,
+      });
+    },
+  });
+}
+
+/** Whether values of the type are cheaper to read than to read lazily. */
+function isScalar({ name, args }) {
+  if (name === 'Option') return isScalar(parseType(args[0]));
+  const type = typesByName.get(name);
+  if (type === undefined) return name !== 'Box' && name !== 'Vec';
+  return type.kind === 'enum' && type.variants.every((variant) => variant.type === null);
+}
+
+const REGEXP_FLAGS = ['g', 'i', 'm', 's', 'u', 'y', 'd', 'v'];
+
+function regExpFlags(bits) {
+  let flags = '';
+  for (let i = 0; i < REGEXP_FLAGS.length; i++) {
+    if (bits & (1 << i)) flags += REGEXP_FLAGS[i];
+  }
+  return flags;
+}
+
+const parsedTypes = new Map();
+
+/**
+ * Parse a type of `layout.json`, e.g. `Vec<'a,Option<Box<'a,Expression<'a>>>>` to
+ * `{ name: 'Vec', args: ["Option<Box<'a,Expression<'a>>>"] }`.
+ */
+function parseType(type) {
+  let parsed = parsedTypes.get(type);
+  if (parsed !== undefined) return parsed;
+
+  const normalized = type.replace("&'astr", '&str').replace(/'a,?/g, '').replace(/<>/g, '');
+  const open = normalized.indexOf('<');
+  if (open === -1) {
+    parsed = { name: normalized, args: [] };
+  } else {
+    const args = [];
+    let depth = 0;
+    let start = open + 1;
+    for (let i = start; i < normalized.length - 1; i++) {
+      const c = normalized[i];
+      if (c === '<') depth++;
+      else if (c === '>') depth--;
+      else if (c === ',' && depth === 0) {
+        args.push(normalized.slice(start, i));
+        start = i + 1;
+      }
+    }
+    args.push(normalized.slice(start, normalized.length - 1));
+    parsed = { name: normalized.slice(0, open), args };
+  }
+  parsedTypes.set(type, parsed);
+  return parsed;
+}
+
+module.exports = { deserialize };
@@ -84,6 +84,8 @@ export declare function moduleLexerAsync(sourceText: string, options?: ParserOpt
 export declare function moduleLexerSync(sourceText: string, options?: ParserOptions | undefined | null): ModuleLexer
 
 /**
+ * Same as [`parse_sync`], but parses on the libuv thread pool without blocking the main thread.
+ *
  * # Panics
  *
  * * Tokio crashes
@@ -91,6 +93,7 @@ export declare function moduleLexerSync(sourceText: string, options?: ParserOpti
 export declare function parseAsync(sourceText: string, options?: ParserOptions | undefined | null): Promise<ParseResult>
 
 export interface ParseResult {
+  /** JSON serialized AST, its type is `Program` in `ast.d.ts`. */
   program: string
   comments: Array<Comment>
   errors: Array<string>
@@ -104,6 +107,20 @@ export interface ParseResult {
 export interface ParserOptions {
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
   sourceFilename?: string
+  /** Treat the source text as `lang`, overriding the language inferred from `sourceFilename`. */
+  lang?: 'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | undefined
+  /**
+   * Parse JSX syntax, e.g. for `.js` files which contain JSX.
+   *
+   * Default: inferred from `lang` or `sourceFilename`
+   */
+  jsx?: boolean
+  /**
+   * Allow return outside of function.
+   *
+   * Default: false
+   */
+  allowReturnOutsideFunction?: boolean
   /**
    * Emit `ParenthesizedExpression` in AST.
    *
@@ -114,6 +131,17 @@ export interface ParserOptions {
    * Default: true
    */
   preserveParens?: boolean
+  /**
+   * Shape of the serialized AST.
+   *
+   * `typescript-estree` matches the output of `@typescript-eslint/typescript-estree`:
+   * nodes have `range` and `loc` in UTF-16 offsets instead of `start` and `end`,
+   * literals are `Literal` nodes with `raw`, and `Program` has `comments` and `tokens`.
+   * `preserveParens` is ignored.
+   *
+   * Default: 'oxc'
+   */
+  astType?: 'oxc' | 'typescript-estree' | undefined
 }
 
 /**
@@ -124,6 +152,19 @@ export interface ParserOptions {
  */
 export declare function parseSync(sourceText: string, options?: ParserOptions | undefined | null): ParseResult
 
+/**
+ * Raw transfer mode of [`parse_sync`], returning the memory of the AST instead of JSON.
+ * See [`RawParseResult`].
+ *
+ * `astType` is not supported, the AST always has the `oxc` shape.
+ *
+ * # Panics
+ *
+ * * File extension is invalid
+ * * AST is larger than 4 GiB
+ */
+export declare function parseSyncRaw(sourceText: string, options?: ParserOptions | undefined | null): RawParseResult
+
 /**
  * Parse without returning anything.
  * This is for benchmark purposes such as measuring napi communication overhead.
@@ -135,3 +176,15 @@ export declare function parseSync(sourceText: string, options?: ParserOptions |
  */
 export declare function parseWithoutReturn(sourceText: string, options?: ParserOptions | undefined | null): void
 
+/**
+ * Same as [`ParseResult`], with `program` as the memory of the AST.
+ *
+ * Call `deserialize(program)` of `deserialize.js` to read the AST, its type is `Program` in
+ * `ast.d.ts`. Nodes are only read out of the buffer when they are accessed, which is an order of
+ * magnitude faster than `JSON.parse` for large files.
+ */
+export interface RawParseResult {
+  program: Buffer
+  comments: Array<Comment>
+  errors: Array<string>
+}
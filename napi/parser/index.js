@@ -365,4 +365,5 @@ module.exports.moduleLexerAsync = nativeBinding.moduleLexerAsync
 module.exports.moduleLexerSync = nativeBinding.moduleLexerSync
 module.exports.parseAsync = nativeBinding.parseAsync
 module.exports.parseSync = nativeBinding.parseSync
+module.exports.parseSyncRaw = nativeBinding.parseSyncRaw
 module.exports.parseWithoutReturn = nativeBinding.parseWithoutReturn
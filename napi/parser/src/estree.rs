@@ -0,0 +1,183 @@
+//! Convert the serialized AST to the shape produced by `@typescript-eslint/typescript-estree`.
+//!
+//! * `start` and `end` are replaced by `range` and `loc`, in UTF-16 code units.
+//! * `StringLiteral`, `NumericLiteral` etc. become `Literal` nodes with a `raw` field.
+//! * Comments and tokens are added to `Program` as `comments` and `tokens`.
+//!
+//! Reference: <https://typescript-eslint.io/packages/typescript-estree>
+
+use oxc_ast::{CommentKind, Trivias};
+use oxc_parser::token::{Token, TokenType};
+use serde_json::{json, Map, Value};
+
+/// Rewrite `program` in place.
+pub fn to_typescript_estree(
+    program: &mut Value,
+    source_text: &str,
+    trivias: &Trivias,
+    tokens: &[Token],
+) {
+    let offsets = Utf16Offsets::new(source_text);
+
+    if let Value::Object(object) = program {
+        let comments = trivias
+            .comments()
+            .map(|comment| {
+                let r#type = match comment.kind {
+                    CommentKind::SingleLine => "Line",
+                    CommentKind::MultiLine => "Block",
+                };
+                json!({
+                    "type": r#type,
+                    "value": comment.span.source_text(source_text),
+                    "start": comment.real_span_start(),
+                    "end": comment.real_span_end(),
+                })
+            })
+            .collect();
+        object.insert("comments".into(), Value::Array(comments));
+
+        let tokens = tokens
+            .iter()
+            .map(|token| {
+                let value = token.span.source_text(source_text);
+                let mut object = json!({
+                    "type": token.r#type.as_str(),
+                    "value": value,
+                    "start": token.span.start,
+                    "end": token.span.end,
+                });
+                if token.r#type == TokenType::RegularExpression {
+                    let (pattern, flags) = value[1..].rsplit_once('/').unwrap_or_default();
+                    object["regex"] = json!({ "pattern": pattern, "flags": flags });
+                }
+                object
+            })
+            .collect();
+        object.insert("tokens".into(), Value::Array(tokens));
+    }
+
+    convert(program, source_text, &offsets);
+}
+
+fn convert(value: &mut Value, source_text: &str, offsets: &Utf16Offsets) {
+    match value {
+        Value::Array(array) => {
+            for value in array {
+                convert(value, source_text, offsets);
+            }
+        }
+        Value::Object(object) => {
+            for value in object.values_mut() {
+                convert(value, source_text, offsets);
+            }
+            convert_node(object, source_text, offsets);
+        }
+        _ => {}
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn convert_node(object: &mut Map<String, Value>, source_text: &str, offsets: &Utf16Offsets) {
+    let (Some(start), Some(end)) =
+        (object.get("start").and_then(Value::as_u64), object.get("end").and_then(Value::as_u64))
+    else {
+        return;
+    };
+    let (start, end) = (start as u32, end as u32);
+
+    let ty = object.get("type").and_then(Value::as_str).unwrap_or_default();
+    let is_literal = matches!(
+        ty,
+        "StringLiteral"
+            | "NumericLiteral"
+            | "BooleanLiteral"
+            | "NullLiteral"
+            | "BigIntLiteral"
+            | "RegExpLiteral"
+    );
+    if is_literal {
+        let raw = &source_text[start as usize..end as usize];
+        // `null` can be serialized, but `BigInt`s and `RegExp`s can't, so their `value` is `null`
+        if matches!(ty, "NullLiteral" | "BigIntLiteral" | "RegExpLiteral") {
+            if ty == "BigIntLiteral" {
+                let bigint = raw.trim_end_matches('n').replace('_', "");
+                object.insert("bigint".into(), bigint.into());
+            }
+            object.insert("value".into(), Value::Null);
+        }
+        object.insert("type".into(), "Literal".into());
+        object.insert("raw".into(), raw.into());
+    }
+
+    object.remove("start");
+    object.remove("end");
+    let (start, end) = (offsets.position(start), offsets.position(end));
+    object.insert("range".into(), json!([start.offset, end.offset]));
+    object.insert(
+        "loc".into(),
+        json!({
+            "start": { "line": start.line, "column": start.column },
+            "end": { "line": end.line, "column": end.column },
+        }),
+    );
+}
+
+struct Position {
+    /// UTF-16 offset from start of file
+    offset: u32,
+    /// 1-indexed
+    line: u32,
+    /// 0-indexed, in UTF-16 code units
+    column: u32,
+}
+
+/// Translates UTF-8 byte offsets to UTF-16 offsets, lines and columns.
+struct Utf16Offsets {
+    /// UTF-8 offsets of the start of each line
+    line_starts: Vec<u32>,
+    /// `(utf8_offset, utf16_offset)` for the end of every non-ASCII char
+    non_ascii: Vec<(u32, u32)>,
+}
+
+impl Utf16Offsets {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut non_ascii = vec![];
+        let mut utf16_offset = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let offset = offset as u32;
+            let len = c.len_utf8() as u32;
+            utf16_offset += c.len_utf16() as u32;
+            if !c.is_ascii() {
+                non_ascii.push((offset + len, utf16_offset));
+            }
+            match c {
+                '\r' if chars.peek().is_some_and(|&(_, c)| c == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(offset + len),
+                _ => {}
+            }
+        }
+        Self { line_starts, non_ascii }
+    }
+
+    fn utf16_offset(&self, utf8_offset: u32) -> u32 {
+        let index = self.non_ascii.partition_point(|&(utf8, _)| utf8 <= utf8_offset);
+        if index == 0 {
+            return utf8_offset;
+        }
+        let (utf8, utf16) = self.non_ascii[index - 1];
+        // Everything after the last non-ASCII char is ASCII
+        utf16 + (utf8_offset - utf8)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn position(&self, utf8_offset: u32) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= utf8_offset);
+        let line_start = self.line_starts[line - 1];
+        let offset = self.utf16_offset(utf8_offset);
+        Position { offset, line: line as u32, column: offset - self.utf16_offset(line_start) }
+    }
+}
@@ -1,13 +1,18 @@
+mod estree;
 mod module_lexer;
+mod raw_transfer;
 
 use std::sync::Arc;
 
-use napi::{bindgen_prelude::AsyncTask, Task};
+use napi::{
+    bindgen_prelude::{AsyncTask, Buffer},
+    Task,
+};
 use napi_derive::napi;
 use oxc_allocator::Allocator;
 pub use oxc_ast::ast::Program;
-use oxc_ast::CommentKind;
-use oxc_diagnostics::{Error, NamedSource};
+use oxc_ast::{CommentKind, Trivias};
+use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_span::SourceType;
 
@@ -22,6 +27,17 @@ pub struct ParserOptions {
     #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
     pub source_type: Option<String>,
     pub source_filename: Option<String>,
+    /// Treat the source text as `lang`, overriding the language inferred from `sourceFilename`.
+    #[napi(ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'dts' | undefined")]
+    pub lang: Option<String>,
+    /// Parse JSX syntax, e.g. for `.js` files which contain JSX.
+    ///
+    /// Default: inferred from `lang` or `sourceFilename`
+    pub jsx: Option<bool>,
+    /// Allow return outside of function.
+    ///
+    /// Default: false
+    pub allow_return_outside_function: Option<bool>,
     /// Emit `ParenthesizedExpression` in AST.
     ///
     /// If this option is true, parenthesized expressions are represented by
@@ -30,15 +46,44 @@ pub struct ParserOptions {
     ///
     /// Default: true
     pub preserve_parens: Option<bool>,
+    /// Shape of the serialized AST.
+    ///
+    /// `typescript-estree` matches the output of `@typescript-eslint/typescript-estree`:
+    /// nodes have `range` and `loc` in UTF-16 offsets instead of `start` and `end`,
+    /// literals are `Literal` nodes with `raw`, and `Program` has `comments` and `tokens`.
+    /// `preserveParens` is ignored.
+    ///
+    /// Default: 'oxc'
+    #[napi(ts_type = "'oxc' | 'typescript-estree' | undefined")]
+    pub ast_type: Option<String>,
+}
+
+impl ParserOptions {
+    fn is_typescript_estree(&self) -> bool {
+        self.ast_type.as_deref() == Some("typescript-estree")
+    }
 }
 
 #[napi(object)]
 pub struct ParseResult {
+    /// JSON serialized AST, its type is `Program` in `ast.d.ts`.
     pub program: String,
     pub comments: Vec<Comment>,
     pub errors: Vec<String>,
 }
 
+/// Same as [`ParseResult`], with `program` as the memory of the AST.
+///
+/// Call `deserialize(program)` of `deserialize.js` to read the AST, its type is `Program` in
+/// `ast.d.ts`. Nodes are only read out of the buffer when they are accessed, which is an order of
+/// magnitude faster than `JSON.parse` for large files.
+#[napi(object)]
+pub struct RawParseResult {
+    pub program: Buffer,
+    pub comments: Vec<Comment>,
+    pub errors: Vec<String>,
+}
+
 #[napi(object)]
 pub struct Comment {
     #[napi(ts_type = "'Line' | 'Block'")]
@@ -53,19 +98,34 @@ fn parse<'a>(
     source_text: &'a str,
     options: &ParserOptions,
 ) -> ParserReturn<'a> {
-    let source_type = options
-        .source_filename
-        .as_ref()
-        .and_then(|name| SourceType::from_path(name).ok())
-        .unwrap_or_default();
+    let source_type = match options.lang.as_deref() {
+        Some("js") => SourceType::js(),
+        Some("jsx") => SourceType::jsx(),
+        Some("ts") => SourceType::ts(),
+        Some("tsx") => SourceType::tsx(),
+        Some("dts") => SourceType::d_ts(),
+        _ => options
+            .source_filename
+            .as_ref()
+            .and_then(|name| SourceType::from_path(name).ok())
+            .unwrap_or_default(),
+    };
     let source_type = match options.source_type.as_deref() {
         Some("script") => source_type.with_script(true),
         Some("module") => source_type.with_module(true),
+        Some("unambiguous") => source_type.with_unambiguous(true),
         _ => source_type,
     };
+    let source_type = match options.jsx {
+        Some(jsx) => source_type.with_jsx(jsx),
+        None => source_type,
+    };
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
-            preserve_parens: options.preserve_parens.unwrap_or(true),
+            allow_return_outside_function: options.allow_return_outside_function.unwrap_or(false),
+            preserve_parens: !options.is_typescript_estree()
+                && options.preserve_parens.unwrap_or(true),
+            tokens: options.is_typescript_estree(),
             ..ParseOptions::default()
         })
         .parse()
@@ -88,24 +148,33 @@ pub fn parse_without_return(source_text: String, options: Option<ParserOptions>)
 
 #[allow(clippy::needless_lifetimes)]
 fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> ParseResult {
+    let (program, comments, errors) = parse_and_serialize(source_text, options);
+    // `serde_json` only produces valid UTF-8
+    let program = String::from_utf8(program).unwrap();
+    ParseResult { program, comments, errors }
+}
+
+fn parse_and_serialize(
+    source_text: &str,
+    options: &ParserOptions,
+) -> (Vec<u8>, Vec<Comment>, Vec<String>) {
     let allocator = Allocator::default();
     let ret = parse(&allocator, source_text, options);
-    let program = serde_json::to_string(&ret.program).unwrap();
-
-    let errors = if ret.errors.is_empty() {
-        vec![]
+    let program = if options.is_typescript_estree() {
+        let mut program = serde_json::to_value(&ret.program).unwrap();
+        estree::to_typescript_estree(&mut program, source_text, &ret.trivias, &ret.tokens);
+        serde_json::to_vec(&program)
     } else {
-        let file_name = options.source_filename.clone().unwrap_or_default();
-        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
-        ret.errors
-            .into_iter()
-            .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
-            .map(|error| format!("{error:?}"))
-            .collect()
-    };
+        serde_json::to_vec(&ret.program)
+    }
+    .unwrap();
+    let comments = comments(&ret.trivias, source_text);
+    let errors = errors(ret.errors, source_text, options);
+    (program, comments, errors)
+}
 
-    let comments = ret
-        .trivias
+fn comments(trivias: &Trivias, source_text: &str) -> Vec<Comment> {
+    trivias
         .comments()
         .map(|comment| Comment {
             r#type: match comment.kind {
@@ -116,9 +185,20 @@ fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> Parse
             start: comment.span.start,
             end: comment.span.end,
         })
-        .collect::<Vec<Comment>>();
+        .collect()
+}
 
-    ParseResult { program, comments, errors }
+fn errors(errors: Vec<OxcDiagnostic>, source_text: &str, options: &ParserOptions) -> Vec<String> {
+    if errors.is_empty() {
+        return vec![];
+    }
+    let file_name = options.source_filename.clone().unwrap_or_default();
+    let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
+    errors
+        .into_iter()
+        .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
+        .map(|error| format!("{error:?}"))
+        .collect()
 }
 
 /// # Panics
@@ -132,6 +212,28 @@ pub fn parse_sync(source_text: String, options: Option<ParserOptions>) -> ParseR
     parse_with_return(&source_text, &options)
 }
 
+/// Raw transfer mode of [`parse_sync`], returning the memory of the AST instead of JSON.
+/// See [`RawParseResult`].
+///
+/// `astType` is not supported, the AST always has the `oxc` shape.
+///
+/// # Panics
+///
+/// * File extension is invalid
+/// * AST is larger than 4 GiB
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn parse_sync_raw(source_text: String, options: Option<ParserOptions>) -> RawParseResult {
+    let options = options.unwrap_or_default();
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, &source_text, &options);
+    let program = allocator.alloc(ret.program);
+    let buffer = raw_transfer::serialize(&allocator, program, &source_text);
+    let comments = comments(&ret.trivias, &source_text);
+    let errors = errors(ret.errors, &source_text, &options);
+    RawParseResult { program: buffer.into(), comments, errors }
+}
+
 pub struct ResolveTask {
     source_text: String,
     options: ParserOptions,
@@ -151,6 +253,8 @@ impl Task for ResolveTask {
     }
 }
 
+/// Same as [`parse_sync`], but parses on the libuv thread pool without blocking the main thread.
+///
 /// # Panics
 ///
 /// * Tokio crashes
@@ -0,0 +1,101 @@
+//! Raw transfer of the AST to JavaScript.
+//!
+//! Instead of serializing the AST to JSON, the memory of the arena the AST is allocated in is
+//! copied into a single buffer, together with the source text which string slices of the AST
+//! point into. `deserialize.js` reads the AST out of the buffer lazily, using the memory layout
+//! of the AST types in `layout.json`.
+//!
+//! Buffer format, all integers are little-endian:
+//!
+//! * Header of [`HEADER_SIZE`] bytes, as `u32`s unless noted:
+//!   size of pointers, offsets of the pointer and the length in `Vec` and `&str`,
+//!   number of regions, and the address of `Program` as `u64`.
+//! * Regions, [`REGION_SIZE`] bytes each: address as `u64`, offset in the buffer and length.
+//! * The memory of the regions.
+
+use std::mem::size_of;
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::ast::Program;
+
+const HEADER_SIZE: usize = 32;
+const REGION_SIZE: usize = 16;
+
+/// Copy the memory of `allocator` and `source_text` into a buffer to be read by `deserialize.js`.
+///
+/// `program` must be allocated in `allocator`, and every string of the AST must be allocated in
+/// `allocator` or be a slice of `source_text`.
+pub fn serialize(allocator: &Allocator, program: &Program, source_text: &str) -> std::vec::Vec<u8> {
+    let (vec_ptr_offset, vec_len_offset) = vec_layout(allocator);
+    let (str_ptr_offset, str_len_offset) = str_layout();
+
+    // SAFETY: Nothing is allocated in `allocator` while the chunks are read.
+    let mut regions = unsafe { allocator.iter_allocated_chunks_raw() }
+        .map(|(ptr, len)| (ptr.cast_const(), len))
+        .collect::<std::vec::Vec<_>>();
+    regions.push((source_text.as_ptr(), source_text.len()));
+
+    let data_len = regions.iter().map(|(_, len)| len.next_multiple_of(8)).sum::<usize>();
+    let mut buffer =
+        std::vec::Vec::with_capacity(HEADER_SIZE + regions.len() * REGION_SIZE + data_len);
+    for value in [
+        size_of::<usize>(),
+        vec_ptr_offset,
+        vec_len_offset,
+        str_ptr_offset,
+        str_len_offset,
+        regions.len(),
+    ] {
+        push_u32(&mut buffer, value);
+    }
+    buffer.extend_from_slice(&(std::ptr::from_ref(program) as u64).to_le_bytes());
+
+    let mut offset = HEADER_SIZE + regions.len() * REGION_SIZE;
+    for &(ptr, len) in &regions {
+        buffer.extend_from_slice(&(ptr as u64).to_le_bytes());
+        push_u32(&mut buffer, offset);
+        push_u32(&mut buffer, len);
+        offset += len.next_multiple_of(8);
+    }
+
+    for &(ptr, len) in &regions {
+        // SAFETY: Every region is a live allocation of `len` bytes.
+        // Chunks of the arena may contain padding between allocations, which is copied as is and
+        // never read by `deserialize.js`.
+        buffer.extend_from_slice(unsafe { std::slice::from_raw_parts(ptr, len) });
+        buffer.resize(buffer.len().next_multiple_of(8), 0);
+    }
+    buffer
+}
+
+fn push_u32(buffer: &mut std::vec::Vec<u8>, value: usize) {
+    let value = u32::try_from(value).expect("AST buffer is larger than 4 GiB");
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Offsets of the pointer and the length in the memory of `Vec`, which has no stable layout.
+/// Found by looking for a known pointer and length in an actual `Vec`.
+fn vec_layout(allocator: &Allocator) -> (usize, usize) {
+    let mut vec = Vec::with_capacity_in(2, allocator);
+    vec.push(0u8);
+    let words = size_of::<Vec<u8>>() / size_of::<usize>();
+    // SAFETY: `Vec` consists of `usize` sized fields: the pointer, the capacity, the length
+    // and a reference to the allocator.
+    let fields =
+        unsafe { std::slice::from_raw_parts(std::ptr::addr_of!(vec).cast::<usize>(), words) };
+    let ptr_index = fields.iter().position(|&field| field == vec.as_ptr() as usize).unwrap();
+    let len_index = fields.iter().position(|&field| field == 1).unwrap();
+    (ptr_index * size_of::<usize>(), len_index * size_of::<usize>())
+}
+
+/// Offsets of the pointer and the length in the memory of `&str`, e.g. of `Atom`.
+fn str_layout() -> (usize, usize) {
+    let s = "a";
+    // SAFETY: `&str` consists of a pointer and a length.
+    let fields: [usize; 2] = unsafe { std::mem::transmute(s) };
+    if fields[0] == s.as_ptr() as usize {
+        (0, size_of::<usize>())
+    } else {
+        (size_of::<usize>(), 0)
+    }
+}
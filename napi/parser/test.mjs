@@ -1,4 +1,5 @@
 import oxc from './index.js';
+import raw from './deserialize.js';
 import assert from 'assert';
 
 console.log(`Testing on ${process.platform}-${process.arch}`)
@@ -14,6 +15,36 @@ const sourceText = "/* comment */ foo";
 
 test(oxc.parseSync(sourceText));
 
+// Raw transfer, `program` is only deserialized when needed.
+const rawRet = oxc.parseSyncRaw(sourceText);
+assert(Buffer.isBuffer(rawRet.program));
+test({ ...rawRet, program: JSON.stringify(raw.deserialize(rawRet.program)) });
+for (const [source, lang] of [
+  ["const { a, b: [c, , ...d], ...e } = f; [g.h = 1, ...i] = j;", "js"],
+  ["function f(this: T, a?: string, ...b: number[]) { return /a/gu.test(`${a}`) }", "ts"],
+  ["namespace A { 'use strict'; export let x = 1n, y = null, z = 0.5 }", "ts"],
+  ["<div a='b'>{c}</div>", "tsx"],
+]) {
+  const expected = JSON.parse(oxc.parseSync(source, { lang }).program);
+  assert.deepEqual(raw.deserialize(oxc.parseSyncRaw(source, { lang }).program), expected);
+}
+
+// Language override
+const ts = oxc.parseSync("let x: number = 1", { lang: "ts" });
+assert(ts.errors.length == 0);
+assert(oxc.parseSync("let x: number = 1", { lang: "js" }).errors.length > 0);
+assert(oxc.parseSync("<div />", { jsx: true }).errors.length == 0);
+assert(oxc.parseSync("return 1", { allowReturnOutsideFunction: true }).errors.length == 0);
+
+// typescript-estree compatible AST
+const estree = JSON.parse(oxc.parseSync("// 😀\nlet x = (1n);", { astType: "typescript-estree" }).program);
+const declarator = estree.body[0].declarations[0];
+assert.deepEqual(estree.body[0].range, [6, 19]);
+assert.deepEqual(estree.body[0].loc, { start: { line: 2, column: 0 }, end: { line: 2, column: 13 } });
+assert(declarator.init.type == "Literal" && declarator.init.raw == "1n" && declarator.init.bigint == "1");
+assert.deepEqual(estree.comments[0].range, [0, 5]);
+assert.deepEqual(estree.tokens.map((token) => token.value), ["let", "x", "=", "(", "1n", ")", ";"]);
+
 async function main() {
   test(await oxc.parseAsync(sourceText));
 }
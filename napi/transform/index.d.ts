@@ -72,15 +72,31 @@ export interface ReactBindingOptions {
    */
   pure?: boolean
   /**
-   * Replaces the import source when importing functions.
+   * Replaces the import source when importing functions, e.g. `preact`
+   * imports `jsx` from `preact/jsx-runtime`.
+   *
+   * Children are passed as configured by {@link importSourceChildren}.
+   * Libraries which compile JSX themselves, such as Solid, are not supported.
    *
    * @default 'react'
    */
   importSource?: string
+  /**
+   * Decides how children are passed for each import source.
+   *
+   * - 'props' - in `props.children` of `jsx` and `jsxs`, as `jsx-runtime` modules expect
+   * - 'arguments' - as arguments of `createElement` imported from the import source,
+   *   e.g. `createElement('div', null, a, b)`
+   *
+   * Import sources which are not listed use 'props'.
+   *
+   * Only used for `automatic` {@link runtime}.
+   */
+  importSourceChildren?: Record<string, 'props' | 'arguments'>
   /**
    * Replace the function used when compiling JSX expressions. It should be a
-   * qualified name (e.g. `React.createElement`) or an identifier (e.g.
-   * `createElement`).
+   * qualified name (e.g. `React.createElement` or `this.$createElement`) or
+   * an identifier (e.g. `h` for Preact).
    *
    * Only used for `classic` {@link runtime}.
    *
@@ -162,6 +178,32 @@ export interface TransformOptions {
   react?: ReactBindingOptions
   /** Enable ES2015 transformations. */
   es2015?: Es2015BindingOptions
+  /**
+   * The environments to support, as a [browserslist query](https://github.com/browserslist/browserslist#queries),
+   * e.g. `"chrome 87, safari 14"`. Syntax which is not supported by all
+   * targets is transformed.
+   *
+   * Explicitly configured transformations such as {@link es2015} take
+   * precedence.
+   */
+  targets?: string
+  /**
+   * Replace global identifiers and member expressions with constant
+   * expressions, e.g. `{ "process.env.NODE_ENV": "\"production\"" }`.
+   *
+   * Values are parsed as JavaScript expressions.
+   */
+  define?: Record<string, string>
+  /**
+   * Rewrite or remove imports of files which are not JavaScript, e.g.
+   * `{ ".css": "remove", ".svg": "path", "?url": "path" }`.
+   *
+   * Keys are extensions or query parameters. Values are one of:
+   * - `"remove"`: remove the import, and declare imported bindings as empty objects.
+   * - `"path"`: declare imported bindings as the path of the imported file.
+   * - any other value: import this module instead, e.g. `"identity-obj-proxy"`.
+   */
+  assetImports?: Record<string, string>
   /**
    * Enable source map generation.
    *
@@ -214,10 +256,38 @@ export interface TransformResult {
   errors: Array<string>
 }
 
+/**
+ * Alias of {@link transform}, for drop-in compatibility with
+ * `@swc/core`'s `transformSync`.
+ */
+export declare function transformSync(filename: string, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
+
 export interface TypeScriptBindingOptions {
   jsxPragma?: string
   jsxPragmaFrag?: string
   onlyRemoveTypeImports?: boolean
+  /**
+   * Only remove `import type` declarations and `type` specifiers, keep all other imports.
+   *
+   * @default false
+   */
+  verbatimModuleSyntax?: boolean
+  /**
+   * What to do with imports which are never used as values.
+   *
+   * - `remove`: remove them.
+   * - `preserve`: keep them for their side effects, e.g. `import 'mod'`.
+   * - `error`: same as `preserve`, and report an error.
+   *
+   * @default 'remove'
+   */
+  importsNotUsedAsValues?: 'remove' | 'preserve' | 'error'
+  /**
+   * Keep all imports which are not marked with `type`, even if they are only used as types.
+   *
+   * @default false
+   */
+  preserveValueImports?: boolean
   allowNamespaces?: boolean
   allowDeclareFields?: boolean
   /**
@@ -363,3 +363,4 @@ if (!nativeBinding) {
 
 module.exports.isolatedDeclaration = nativeBinding.isolatedDeclaration
 module.exports.transform = nativeBinding.transform
+module.exports.transformSync = nativeBinding.transformSync
@@ -0,0 +1,31 @@
+import type { SourceMap, TransformOptions } from "./index";
+
+export interface JestTransformResult {
+  /** The transformed code, with an inline source map. */
+  code: string
+  map: SourceMap & { version: number }
+}
+
+/**
+ * A Jest transformer.
+ *
+ * @see [Jest code transformation](https://jestjs.io/docs/code-transformation)
+ */
+export interface JestTransformer {
+  canInstrument: false
+  /** @throws if the file has syntax errors. */
+  process(sourceText: string, sourcePath: string, options?: unknown): JestTransformResult
+  getCacheKey(sourceText: string, sourcePath: string, options?: { configString?: string }): string
+}
+
+/**
+ * Create a transformer with the given options. Jest calls this with the options
+ * from the `transform` config, e.g. `["oxc-transform/jest", { targets: "node 18" }]`.
+ *
+ * `sourcemap` is always enabled.
+ */
+export declare function createTransformer(config?: TransformOptions): JestTransformer
+
+export declare const canInstrument: false
+export declare const process: JestTransformer["process"]
+export declare const getCacheKey: JestTransformer["getCacheKey"]
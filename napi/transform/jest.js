@@ -0,0 +1,54 @@
+// Jest transformer, see <https://jestjs.io/docs/code-transformation>.
+//
+// ```js
+// // jest.config.js
+// module.exports = {
+//   transform: {
+//     "\\.[cm]?[jt]sx?$": ["oxc-transform/jest", { /* TransformOptions */ }],
+//   },
+// };
+// ```
+//
+// TypeScript and JSX are transformed based on the file extension, and the source map is inlined
+// into the transformed code. The output keeps `import` / `export` statements, so ES modules
+// require Jest's ESM support (`--experimental-vm-modules`).
+
+"use strict";
+
+const { createHash } = require("crypto");
+const { transformSync } = require("./index.js");
+
+const version = require("./package.json").version || "";
+
+function createTransformer(transformerConfig = {}) {
+  return {
+    canInstrument: false,
+
+    process(sourceText, sourcePath) {
+      const ret = transformSync(sourcePath, sourceText, { ...transformerConfig, sourcemap: true });
+      if (ret.errors.length > 0) {
+        throw new Error(`Failed to transform ${sourcePath}:\n${ret.errors.join("\n")}`);
+      }
+      const map = { version: 3, ...ret.map };
+      const base64 = Buffer.from(JSON.stringify(map)).toString("base64");
+      const code = `${ret.code}\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,${base64}\n`;
+      return { code, map };
+    },
+
+    getCacheKey(sourceText, sourcePath, options) {
+      return createHash("sha1")
+        .update(version)
+        .update("\0")
+        .update(JSON.stringify(transformerConfig))
+        .update("\0")
+        .update((options && options.configString) || "")
+        .update("\0")
+        .update(sourcePath)
+        .update("\0")
+        .update(sourceText)
+        .digest("hex");
+    },
+  };
+}
+
+module.exports = { ...createTransformer(), createTransformer };
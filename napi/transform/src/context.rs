@@ -8,8 +8,10 @@ use oxc_allocator::Allocator;
 use oxc_ast::{ast::Program, Trivias};
 use oxc_codegen::Codegen;
 use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+use oxc_minifier::{ReplaceGlobalDefines, ReplaceGlobalDefinesConfig};
 use oxc_parser::{Parser, ParserReturn};
 use oxc_span::SourceType;
+use oxc_transformer::Targets;
 
 use crate::TransformOptions;
 
@@ -28,6 +30,8 @@ pub(crate) struct TransformContext<'a> {
     ///
     /// Used by [`crate::transform`].
     declarations: bool,
+    /// Global defines, replaced before transformation.
+    define: Option<ReplaceGlobalDefinesConfig>,
 
     /// Path to the file being transformed.
     filename: &'a str,
@@ -47,7 +51,7 @@ impl<'a> TransformContext<'a> {
         source_type: SourceType,
         options: Option<TransformOptions>,
     ) -> Self {
-        let ParserReturn { errors, program, trivias, .. } =
+        let ParserReturn { mut errors, program, trivias, .. } =
             Parser::new(allocator, source_text, source_type).parse();
 
         // Options that are added by this napi crates and don't exist in
@@ -58,6 +62,15 @@ impl<'a> TransformContext<'a> {
             .and_then(|o| o.typescript.as_ref())
             .and_then(|t| t.declaration)
             .unwrap_or_default();
+        let define = options.as_ref().and_then(|o| o.define.as_ref()).and_then(|define| {
+            let define = define.iter().collect::<Vec<_>>();
+            ReplaceGlobalDefinesConfig::new(&define).map_err(|e| errors.extend(e)).ok()
+        });
+        if let Some(query) = options.as_ref().and_then(|o| o.targets.as_deref()) {
+            if let Err(error) = Targets::from_query(query).get_targets() {
+                errors.push(OxcDiagnostic::error(error.to_string()));
+            }
+        }
 
         // Insert options into the cell if provided. Otherwise they will be
         // initialized to default when first accessed.
@@ -74,6 +87,7 @@ impl<'a> TransformContext<'a> {
             options: options_cell,
             source_map,
             declarations,
+            define,
 
             filename,
             source_text,
@@ -122,6 +136,14 @@ impl<'a> TransformContext<'a> {
         self.program.borrow_mut()
     }
 
+    /// Replace global defines in the program, if any are configured.
+    pub fn replace_global_defines(&self) {
+        if let Some(config) = &self.define {
+            ReplaceGlobalDefines::new(self.allocator, config.clone())
+                .build(&mut self.program_mut());
+        }
+    }
+
     pub fn codegen(&self) -> Codegen<'a> {
         let codegen = Codegen::new();
         if self.source_map {
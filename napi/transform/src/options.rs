@@ -1,10 +1,11 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use napi::Either;
 use napi_derive::napi;
 use oxc_transformer::{
-    ArrowFunctionsOptions, ES2015Options, ReactJsxRuntime, ReactOptions, RewriteExtensionsMode,
-    TypeScriptOptions,
+    ArrowFunctionsOptions, AssetImportAction, AssetImportsOptions, ES2015Options, EnvOptions,
+    ImportsNotUsedAsValues, ReactJsxChildren, ReactJsxRuntime, ReactOptions, RewriteExtensionsMode,
+    Targets, TypeScriptOptions,
 };
 
 #[napi(object)]
@@ -13,6 +14,23 @@ pub struct TypeScriptBindingOptions {
     pub jsx_pragma: Option<String>,
     pub jsx_pragma_frag: Option<String>,
     pub only_remove_type_imports: Option<bool>,
+    /// Only remove `import type` declarations and `type` specifiers, keep all other imports.
+    ///
+    /// @default false
+    pub verbatim_module_syntax: Option<bool>,
+    /// What to do with imports which are never used as values.
+    ///
+    /// - `remove`: remove them.
+    /// - `preserve`: keep them for their side effects, e.g. `import 'mod'`.
+    /// - `error`: same as `preserve`, and report an error.
+    ///
+    /// @default 'remove'
+    #[napi(ts_type = "'remove' | 'preserve' | 'error'")]
+    pub imports_not_used_as_values: Option<String>,
+    /// Keep all imports which are not marked with `type`, even if they are only used as types.
+    ///
+    /// @default false
+    pub preserve_value_imports: Option<bool>,
     pub allow_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
@@ -44,9 +62,21 @@ impl From<TypeScriptBindingOptions> for TypeScriptOptions {
             only_remove_type_imports: options
                 .only_remove_type_imports
                 .unwrap_or(ops.only_remove_type_imports),
+            verbatim_module_syntax: options
+                .verbatim_module_syntax
+                .unwrap_or(ops.verbatim_module_syntax),
+            imports_not_used_as_values: match options.imports_not_used_as_values.as_deref() {
+                Some("preserve") => ImportsNotUsedAsValues::Preserve,
+                Some("error") => ImportsNotUsedAsValues::Error,
+                _ => ops.imports_not_used_as_values,
+            },
+            preserve_value_imports: options
+                .preserve_value_imports
+                .unwrap_or(ops.preserve_value_imports),
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            preserve_type_annotations: false,
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -104,14 +134,30 @@ pub struct ReactBindingOptions {
     /// @default true
     pub pure: Option<bool>,
 
-    /// Replaces the import source when importing functions.
+    /// Replaces the import source when importing functions, e.g. `preact`
+    /// imports `jsx` from `preact/jsx-runtime`.
+    ///
+    /// Children are passed as configured by {@link importSourceChildren}.
+    /// Libraries which compile JSX themselves, such as Solid, are not supported.
     ///
     /// @default 'react'
     pub import_source: Option<String>,
 
+    /// Decides how children are passed for each import source.
+    ///
+    /// - 'props' - in `props.children` of `jsx` and `jsxs`, as `jsx-runtime` modules expect
+    /// - 'arguments' - as arguments of `createElement` imported from the import source,
+    ///   e.g. `createElement('div', null, a, b)`
+    ///
+    /// Import sources which are not listed use 'props'.
+    ///
+    /// Only used for `automatic` {@link runtime}.
+    #[napi(ts_type = "Record<string, 'props' | 'arguments'>")]
+    pub import_source_children: Option<HashMap<String, String>>,
+
     /// Replace the function used when compiling JSX expressions. It should be a
-    /// qualified name (e.g. `React.createElement`) or an identifier (e.g.
-    /// `createElement`).
+    /// qualified name (e.g. `React.createElement` or `this.$createElement`) or
+    /// an identifier (e.g. `h` for Preact).
     ///
     /// Only used for `classic` {@link runtime}.
     ///
@@ -154,6 +200,18 @@ impl From<ReactBindingOptions> for ReactOptions {
             throw_if_namespace: options.throw_if_namespace.unwrap_or(ops.throw_if_namespace),
             pure: options.pure.unwrap_or(ops.pure),
             import_source: options.import_source,
+            import_source_children: options
+                .import_source_children
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(import_source, children)| {
+                    let children = match children.as_str() {
+                        "arguments" => ReactJsxChildren::Arguments,
+                        /* "props" */ _ => ReactJsxChildren::Props,
+                    };
+                    (import_source, children)
+                })
+                .collect(),
             pragma: options.pragma,
             pragma_frag: options.pragma_frag,
             use_built_ins: options.use_built_ins,
@@ -219,6 +277,29 @@ pub struct TransformOptions {
     /// Enable ES2015 transformations.
     pub es2015: Option<ES2015BindingOptions>,
 
+    /// The environments to support, as a [browserslist query](https://github.com/browserslist/browserslist#queries),
+    /// e.g. `"chrome 87, safari 14"`. Syntax which is not supported by all
+    /// targets is transformed.
+    ///
+    /// Explicitly configured transformations such as {@link es2015} take
+    /// precedence.
+    pub targets: Option<String>,
+
+    /// Replace global identifiers and member expressions with constant
+    /// expressions, e.g. `{ "process.env.NODE_ENV": "\"production\"" }`.
+    ///
+    /// Values are parsed as JavaScript expressions.
+    pub define: Option<HashMap<String, String>>,
+
+    /// Rewrite or remove imports of files which are not JavaScript, e.g.
+    /// `{ ".css": "remove", ".svg": "path", "?url": "path" }`.
+    ///
+    /// Keys are extensions or query parameters. Values are one of:
+    /// - `"remove"`: remove the import, and declare imported bindings as empty objects.
+    /// - `"path"`: declare imported bindings as the path of the imported file.
+    /// - any other value: import this module instead, e.g. `"identity-obj-proxy"`.
+    pub asset_imports: Option<HashMap<String, String>>,
+
     /// Enable source map generation.
     ///
     /// When `true`, the `sourceMap` field of transform result objects will be populated.
@@ -231,12 +312,39 @@ pub struct TransformOptions {
 
 impl From<TransformOptions> for oxc_transformer::TransformOptions {
     fn from(options: TransformOptions) -> Self {
+        // Invalid targets are reported by `TransformContext`.
+        let env = options
+            .targets
+            .as_deref()
+            .and_then(|query| {
+                Self::from_preset_env(&EnvOptions {
+                    targets: Targets::from_query(query),
+                    ..EnvOptions::default()
+                })
+                .ok()
+            })
+            .unwrap_or_default();
         Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             typescript: options.typescript.map(Into::into).unwrap_or_default(),
             react: options.react.map(Into::into).unwrap_or_default(),
-            es2015: options.es2015.map(Into::into).unwrap_or_default(),
-            ..Self::default()
+            es2015: options.es2015.map_or(env.es2015, Into::into),
+            asset_imports: AssetImportsOptions {
+                rules: options
+                    .asset_imports
+                    .into_iter()
+                    .flatten()
+                    .map(|(pattern, action)| {
+                        let action = match action.as_str() {
+                            "remove" => AssetImportAction::Remove,
+                            "path" => AssetImportAction::Path,
+                            _ => AssetImportAction::Rewrite(action),
+                        };
+                        (pattern, action)
+                    })
+                    .collect(),
+            },
+            ..env
         }
     }
 }
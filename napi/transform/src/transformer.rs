@@ -98,7 +98,20 @@ pub fn transform(
     }
 }
 
+/// Alias of {@link transform}, for drop-in compatibility with
+/// `@swc/core`'s `transformSync`.
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn transform_sync(
+    filename: String,
+    source_text: String,
+    options: Option<TransformOptions>,
+) -> TransformResult {
+    transform(filename, source_text, options)
+}
+
 fn transpile(ctx: &TransformContext<'_>) -> CodegenReturn {
+    ctx.replace_global_defines();
     let (symbols, scopes) = SemanticBuilder::new(ctx.source_text())
         .build(&ctx.program())
         .semantic
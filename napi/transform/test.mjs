@@ -1,4 +1,5 @@
 import oxc from "./index.js";
+import jest from "./jest.js";
 import assert from "assert";
 
 console.log(`Testing on ${process.platform}-${process.arch}`);
@@ -13,6 +14,47 @@ test(oxc.isolatedDeclaration("test.ts", "class A {}", { sourcemap: true }), {
   },
 });
 
+{
+  const ret = oxc.transformSync("test.ts", "const a: number = process.env.NODE_ENV ?? 1;", {
+    sourcemap: true,
+    targets: "chrome 70",
+    define: { "process.env.NODE_ENV": '"production"' },
+  });
+  assert(ret.errors.length == 0);
+  assert(ret.map);
+  assert(!ret.code.includes("??"));
+  assert(ret.code.includes('"production"'));
+  assert(!ret.code.includes("number"));
+}
+
+{
+  const ret = oxc.transformSync(
+    "test.js",
+    "import './app.css';\nimport styles from './button.module.css';\nimport logo from './logo.svg?url';\n",
+    { assetImports: { ".css": "remove", ".module.css": "identity-obj-proxy", "?url": "path" } },
+  );
+  assert(ret.errors.length == 0);
+  assert.equal(
+    ret.code,
+    'import styles from "identity-obj-proxy";\nconst logo = "./logo.svg";\n',
+  );
+}
+
+{
+  const transformer = jest.createTransformer({ targets: "node 12" });
+  const { code, map } = transformer.process("const a = <div>{b ?? 1}</div>;", "test.tsx");
+  assert(!code.includes("??"));
+  assert(!code.includes("<div>"));
+  assert(code.includes("//# sourceMappingURL=data:application/json;charset=utf-8;base64,"));
+  assert.equal(map.version, 3);
+  assert.deepEqual(map.sources, ["test.tsx"]);
+  assert.throws(() => transformer.process("const = 1;", "error.js"), /Failed to transform error\.js/);
+  assert.notEqual(
+    transformer.getCacheKey("a", "a.js", { configString: "" }),
+    transformer.getCacheKey("b", "a.js", { configString: "" }),
+  );
+}
+
 function test(ret, expected) {
   console.log(ret.code);
   console.log(ret.map);
@@ -5,6 +5,7 @@ use quote::quote;
 use super::{define_derive, Derive, DeriveOutput};
 use crate::{
     codegen::LateCtx,
+    markers::ContentHashAttribute,
     schema::{EnumDef, GetGenerics, StructDef, ToType, TypeDef},
     util::ToIdent,
 };
@@ -13,13 +14,6 @@ define_derive! {
     pub struct DeriveContentHash;
 }
 
-const IGNORE_FIELD_TYPES: [/* type name */ &str; 4] = [
-    "Span",
-    "ScopeId",
-    "SymbolId",
-    "ReferenceId",
-];
-
 impl Derive for DeriveContentHash {
     fn trait_name() -> &'static str {
         "ContentHash"
@@ -88,7 +82,7 @@ fn derive_struct(def: &StructDef) -> (&str, TokenStream) {
             .fields
             .iter()
             .filter(|field| {
-                !IGNORE_FIELD_TYPES.iter().any(|it| field.typ.name().inner_name() == *it)
+                !matches!(field.markers.derive_attributes.content_hash, ContentHashAttribute::Skip)
             })
             .map(|field| {
                 let ident = field.ident();
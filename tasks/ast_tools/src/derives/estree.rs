@@ -0,0 +1,130 @@
+use itertools::Itertools;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::{define_derive, Derive, DeriveOutput};
+use crate::{
+    codegen::LateCtx,
+    markers::{rename_field, rename_variant},
+    schema::{EnumDef, FieldDef, GetGenerics, StructDef, ToType, TypeDef},
+};
+
+define_derive! {
+    pub struct DeriveESTree;
+}
+
+/// Generates `Serialize` implementations from the `#[serde(...)]` attributes of the AST, with
+/// the same output as `serde_derive`: `tag` adds the ESTree `type` of nodes, `rename_all` and
+/// `rename` set the names of fields and variants, `flatten` inlines spans and node kinds, and
+/// `skip` omits semantic IDs.
+impl Derive for DeriveESTree {
+    fn trait_name() -> &'static str {
+        "ESTree"
+    }
+
+    fn snake_name() -> String {
+        "estree".to_string()
+    }
+
+    fn derive(&mut self, def: &TypeDef, _: &LateCtx) -> TokenStream {
+        let body = match def {
+            TypeDef::Enum(it) => serialize_enum(it),
+            TypeDef::Struct(it) => serialize_struct(it),
+        };
+        let ty = def.to_type();
+        let generics = def.generics();
+
+        quote! {
+            impl #generics Serialize for #ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #body
+                }
+            }
+        }
+    }
+
+    fn prelude() -> TokenStream {
+        quote! {
+            #![allow(clippy::match_same_arms)]
+
+            ///@@line_break
+            #[allow(unused_imports)]
+            use serde::{ser::SerializeMap, Serialize, Serializer};
+        }
+    }
+}
+
+fn serialize_struct(def: &StructDef) -> TokenStream {
+    let serde = &def.markers.serde;
+    match def.fields.as_slice() {
+        [] => {
+            let name = &def.name;
+            return quote!(serializer.serialize_unit_struct(#name));
+        }
+        // Newtype structs are serialized as their inner value.
+        [FieldDef { name: None, .. }] => return quote!(self.0.serialize(serializer)),
+        _ => {}
+    }
+
+    let tag = serde.tag.as_ref().map(|tag| {
+        let tag_value = serde.rename.as_ref().unwrap_or(&def.name);
+        quote!(map.serialize_entry(#tag, #tag_value)?;)
+    });
+    let fields = def.fields.iter().filter(|field| !field.markers.serde.skip).map(|field| {
+        let ident = field.ident();
+        if field.markers.serde.flatten {
+            return quote! {
+                self.#ident.serialize(serde::__private::ser::FlatMapSerializer(&mut map))?;
+            };
+        }
+        let name = field.markers.serde.rename.clone().unwrap_or_else(|| {
+            rename_field(field.name.as_deref().unwrap(), serde.rename_all.as_deref())
+        });
+        quote!(map.serialize_entry(#name, &self.#ident)?;)
+    });
+
+    quote! {
+        let mut map = serializer.serialize_map(None)?;
+        #tag
+        #(#fields)*
+        map.end()
+    }
+}
+
+fn serialize_enum(def: &EnumDef) -> TokenStream {
+    let serde = &def.markers.serde;
+    assert!(serde.tag.is_none(), "Internally tagged enums are not supported by the ESTree derive.");
+    let enum_name = &def.name;
+    let matches = def
+        .all_variants()
+        .enumerate()
+        .map(|(index, variant)| {
+            let ident = variant.ident();
+            let index = Literal::u32_unsuffixed(u32::try_from(index).unwrap());
+            let name = variant
+                .markers
+                .serde
+                .rename
+                .clone()
+                .unwrap_or_else(|| rename_variant(&variant.name, serde.rename_all.as_deref()));
+            match (variant.is_unit(), serde.untagged) {
+                (true, true) => quote!(Self :: #ident => serializer.serialize_unit()),
+                (true, false) => quote! {
+                    Self :: #ident => serializer.serialize_unit_variant(#enum_name, #index, #name)
+                },
+                (false, true) => quote!(Self :: #ident(it) => it.serialize(serializer)),
+                (false, false) => quote! {
+                    Self :: #ident(it) => {
+                        serializer.serialize_newtype_variant(#enum_name, #index, #name, it)
+                    }
+                },
+            }
+        })
+        .collect_vec();
+
+    quote! {
+        match self {
+            #(#matches),*
+        }
+    }
+}
@@ -7,15 +7,22 @@ use crate::{codegen::LateCtx, schema::TypeDef};
 mod clone_in;
 mod content_eq;
 mod content_hash;
+mod estree;
 mod get_span;
 
 pub use clone_in::DeriveCloneIn;
 pub use content_eq::DeriveContentEq;
 pub use content_hash::DeriveContentHash;
+pub use estree::DeriveESTree;
 pub use get_span::{DeriveGetSpan, DeriveGetSpanMut};
 
 pub trait Derive {
     fn trait_name() -> &'static str;
+    /// Name of the generated `derive_{snake_name}.rs` files.
+    fn snake_name() -> String {
+        use convert_case::{Case, Casing};
+        Self::trait_name().to_case(Case::Snake)
+    }
     fn derive(&mut self, def: &TypeDef, ctx: &LateCtx) -> TokenStream;
     fn prelude() -> TokenStream {
         TokenStream::default()
@@ -79,7 +86,6 @@ macro_rules! define_derive {
             fn run(&mut self, ctx: &$crate::codegen::LateCtx) -> $crate::Result<Self::Output> {
                 use std::collections::{HashMap, HashSet};
                 use std::vec::Vec;
-                use convert_case::{Case, Casing};
                 use itertools::Itertools;
 
                 use $crate::derives::DeriveTemplate;
@@ -109,7 +115,7 @@ macro_rules! define_derive {
                         acc.push((
                             $crate::output(
                                 format!("crates/{}", path.split("::").next().unwrap()).as_str(),
-                                format!("derive_{}.rs", Self::trait_name().to_case(Case::Snake)).as_str()
+                                format!("derive_{}.rs", Self::snake_name()).as_str()
                             ),
                             Self::template(
                                 modules,
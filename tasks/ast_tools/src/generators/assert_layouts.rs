@@ -6,7 +6,7 @@ use super::define_generator;
 use crate::{
     codegen::{generated_header, LateCtx},
     output,
-    schema::{FieldDef, ToType, TypeDef},
+    schema::{EnumDef, FieldDef, ToType, TypeDef, VariantDef},
     util::ToIdent,
     Generator, GeneratorOutput,
 };
@@ -28,7 +28,7 @@ impl Generator for AssertLayouts {
 
         let header = generated_header!();
 
-        GeneratorOutput(
+        GeneratorOutput::Rust(
             output(crate::AST_CRATE, "assert_layouts.rs"),
             quote! {
                 #header
@@ -75,11 +75,30 @@ fn assert_type(ty: &Type, def: &TypeDef) -> (TokenStream, TokenStream) {
                 def.offsets_32.as_deref(),
             ),
         ),
-        TypeDef::Enum(def) => (
-            assert_size_align(ty, def.size_64, def.align_64),
-            assert_size_align(ty, def.size_32, def.align_32),
-        ),
+        TypeDef::Enum(def) => {
+            let discriminants = assert_discriminants(ty, def);
+            let mut assertions_64 = assert_size_align(ty, def.size_64, def.align_64);
+            assertions_64.extend(discriminants.clone());
+            let mut assertions_32 = assert_size_align(ty, def.size_32, def.align_32);
+            assertions_32.extend(discriminants);
+            (assertions_64, assertions_32)
+        }
+    }
+}
+
+/// Discriminants of fieldless enums are read as plain numbers by the raw transfer deserializer.
+fn assert_discriminants(ty: &Type, def: &EnumDef) -> TokenStream {
+    if !def.inherits.is_empty() || !def.variants.iter().all(VariantDef::is_unit) {
+        return TokenStream::new();
     }
+    let assertions = def.variants.iter().map(|variant| {
+        let ident = variant.ident();
+        let discriminant = variant.discriminant;
+        quote! {
+            assert!(#ty::#ident as u8 == #discriminant);
+        }
+    });
+    quote!(#(#assertions)*)
 }
 
 fn assert_size_align(ty: &Type, size: usize, align: usize) -> TokenStream {
@@ -33,7 +33,7 @@ impl Generator for AstBuilderGenerator {
 
         let header = generated_header!();
 
-        GeneratorOutput(
+        GeneratorOutput::Rust(
             output(crate::AST_CRATE, "ast_builder.rs"),
             quote! {
                 #header
@@ -0,0 +1,180 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use super::define_generator;
+use crate::{
+    codegen::LateCtx,
+    markers::{rename_field, rename_variant},
+    schema::{EnumDef, FieldDef, StructDef, TypeDef},
+    Generator, GeneratorOutput,
+};
+
+/// Shipped with the `oxc-parser` npm package, next to `ast.d.ts`.
+const OUTPUT_PATH: &str = "napi/parser/layout.json";
+
+/// Memory layout of every AST type, for reading the AST directly out of the arena buffer
+/// (raw transfer) instead of deserializing JSON.
+///
+/// The same sizes and offsets are asserted at compile time in `assert_layouts.rs`, so this file
+/// is guaranteed to match the Rust types on both 64-bit and 32-bit targets.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LayoutSchema<'d> {
+    #[serde(rename = "$comment")]
+    comment: String,
+    types: Vec<TypeLayout<'d>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+enum TypeLayout<'d> {
+    Struct {
+        name: &'d str,
+        #[serde(flatten)]
+        layout: Layout,
+        /// Key of the type of the node in the serialized AST, e.g. `"type"`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<&'d str>,
+        /// Value of `tag` in the serialized AST, e.g. `"Identifier"` for `IdentifierReference`.
+        #[serde(rename = "serializedType", skip_serializing_if = "Option::is_none")]
+        serialized_type: Option<&'d str>,
+        fields: Vec<FieldLayout<'d>>,
+    },
+    Enum {
+        name: &'d str,
+        #[serde(flatten)]
+        layout: Layout,
+        /// Variants are serialized as their payload, instead of `{ "Variant": payload }`.
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        untagged: bool,
+        variants: Vec<VariantLayout<'d>>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Layout {
+    size64: usize,
+    align64: usize,
+    size32: usize,
+    align32: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldLayout<'d> {
+    /// `None` for tuple struct fields.
+    name: Option<&'d str>,
+    /// Key of the field in the serialized AST, `None` if it is skipped or flattened.
+    serialized_name: Option<String>,
+    /// The fields of this field are serialized into the parent object.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    flatten: bool,
+    #[serde(rename = "type")]
+    typ: &'d str,
+    offset64: usize,
+    offset32: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VariantLayout<'d> {
+    name: &'d str,
+    /// Name of the variant in the serialized AST.
+    serialized_name: String,
+    discriminant: u8,
+    /// Type of the variant's payload, `None` for fieldless variants.
+    #[serde(rename = "type")]
+    typ: Option<&'d str>,
+}
+
+define_generator! {
+    pub struct LayoutSchemaGenerator;
+}
+
+impl Generator for LayoutSchemaGenerator {
+    fn generate(&mut self, ctx: &LateCtx) -> GeneratorOutput {
+        let file = file!().replace('\\', "/");
+        let schema = LayoutSchema {
+            comment: format!(
+                "Auto-generated code, DO NOT EDIT DIRECTLY! \
+                 To edit this generated file you have to edit `{file}`"
+            ),
+            types: ctx
+                .schema()
+                .into_iter()
+                .map(|def| match def {
+                    TypeDef::Struct(def) => struct_layout(def),
+                    TypeDef::Enum(def) => enum_layout(def),
+                })
+                .collect(),
+        };
+
+        let mut json = serde_json::to_string_pretty(&schema).unwrap();
+        json.push('\n');
+        GeneratorOutput::Text(PathBuf::from(OUTPUT_PATH), json)
+    }
+}
+
+fn struct_layout(def: &StructDef) -> TypeLayout {
+    let offsets_64 = def.offsets_64.as_deref().expect("struct offsets are calculated");
+    let offsets_32 = def.offsets_32.as_deref().expect("struct offsets are calculated");
+    let fields = def
+        .fields
+        .iter()
+        .zip(offsets_64.iter().zip(offsets_32))
+        .map(|(field, (&offset64, &offset32))| FieldLayout {
+            name: field.name.as_deref(),
+            serialized_name: serialized_name(field, def),
+            flatten: field.markers.serde.flatten,
+            typ: field.typ.raw(),
+            offset64,
+            offset32,
+        })
+        .collect();
+    let layout = Layout {
+        size64: def.size_64,
+        align64: def.align_64,
+        size32: def.size_32,
+        align32: def.align_32,
+    };
+    let tag = def.markers.serde.tag.as_deref();
+    let serialized_type = tag.map(|_| def.markers.serde.rename.as_deref().unwrap_or(&def.name));
+    TypeLayout::Struct { name: &def.name, layout, tag, serialized_type, fields }
+}
+
+fn enum_layout(def: &EnumDef) -> TypeLayout {
+    let variants = def
+        .all_variants()
+        .map(|variant| VariantLayout {
+            name: &variant.name,
+            serialized_name: variant.markers.serde.rename.clone().unwrap_or_else(|| {
+                rename_variant(&variant.name, def.markers.serde.rename_all.as_deref())
+            }),
+            discriminant: variant.discriminant,
+            typ: variant.fields.first().map(|field| field.typ.raw()),
+        })
+        .collect();
+    let layout = Layout {
+        size64: def.size_64,
+        align64: def.align_64,
+        size32: def.size_32,
+        align32: def.align_32,
+    };
+    TypeLayout::Enum { name: &def.name, layout, untagged: def.markers.serde.untagged, variants }
+}
+
+fn serialized_name(field: &FieldDef, def: &StructDef) -> Option<String> {
+    let serde = &field.markers.serde;
+    if serde.skip || serde.flatten {
+        return None;
+    }
+    let name = field.name.as_deref()?;
+    Some(
+        serde
+            .rename
+            .clone()
+            .unwrap_or_else(|| rename_field(name, def.markers.serde.rename_all.as_deref())),
+    )
+}
@@ -0,0 +1,197 @@
+use std::path::PathBuf;
+
+use itertools::Itertools;
+
+use super::define_generator;
+use crate::{
+    codegen::LateCtx,
+    markers::{rename_field, rename_variant, SerdeMarkers},
+    schema::{EnumDef, FieldDef, StructDef, TypeDef, TypeName, VariantDef},
+    Generator, GeneratorOutput,
+};
+
+/// Shipped with the `oxc-parser` npm package.
+const OUTPUT_PATH: &str = "napi/parser/ast.d.ts";
+
+/// Types with a hand written `Serialize` implementation in `crates/oxc_ast/src/serialize.rs`.
+const CUSTOM_SERIALIZED: &str = r#"
+export interface ArrayAssignmentTarget extends Span {
+  type: "ArrayAssignmentTarget";
+  elements: Array<AssignmentTargetMaybeDefault | AssignmentTargetRest | null>;
+}
+
+export interface ObjectAssignmentTarget extends Span {
+  type: "ObjectAssignmentTarget";
+  properties: Array<AssignmentTargetProperty | AssignmentTargetRest>;
+}
+
+export interface ObjectPattern extends Span {
+  type: "ObjectPattern";
+  properties: Array<BindingProperty | BindingRestElement>;
+}
+
+export interface ArrayPattern extends Span {
+  type: "ArrayPattern";
+  elements: Array<BindingPattern | BindingRestElement | null>;
+}
+
+export interface FormalParameters extends Span {
+  type: "FormalParameters";
+  kind: FormalParameterKind;
+  items: Array<FormalParameter | FormalParameterRest>;
+}
+
+export interface FormalParameterRest extends Span {
+  type: "RestElement";
+  argument: BindingPatternKind;
+  typeAnnotation: TSTypeAnnotation | null;
+  optional: boolean;
+}
+
+export interface TSModuleBlock extends Span {
+  type: "TSModuleBlock";
+  body: Array<Statement>;
+}
+
+export type Elision = null;
+
+export type RegExpFlags = string;
+"#;
+
+define_generator! {
+    pub struct TypescriptGenerator;
+}
+
+impl Generator for TypescriptGenerator {
+    fn generate(&mut self, ctx: &LateCtx) -> GeneratorOutput {
+        let file = file!().replace('\\', "/");
+        let mut code = format!(
+            "// Auto-generated code, DO NOT EDIT DIRECTLY!\n\
+             // To edit this generated file you have to edit `{file}`\n"
+        );
+
+        for def in ctx.schema() {
+            let ts = match def {
+                TypeDef::Struct(def) => typescript_struct(def, ctx),
+                TypeDef::Enum(def) => typescript_enum(def, ctx),
+            };
+            if let Some(ts) = ts {
+                code.push('\n');
+                code.push_str(&ts);
+                code.push('\n');
+            }
+        }
+        code.push_str(CUSTOM_SERIALIZED);
+
+        GeneratorOutput::Text(PathBuf::from(OUTPUT_PATH), code)
+    }
+}
+
+fn typescript_struct(def: &StructDef, ctx: &LateCtx) -> Option<String> {
+    let serde = &def.markers.serde;
+    if !serde.derive_serialize {
+        return None;
+    }
+    let name = &def.name;
+
+    // Newtype structs are serialized as their inner value.
+    if let [field @ FieldDef { name: None, .. }] = def.fields.as_slice() {
+        return Some(format!("export type {name} = {};", field_type(field, ctx)));
+    }
+
+    let mut extends = vec![];
+    let mut fields = vec![];
+    if let Some(tag) = &serde.tag {
+        let tag_value = serde.rename.as_ref().unwrap_or(name);
+        fields.push(format!("  {tag}: \"{tag_value}\";"));
+    }
+    for field in &def.fields {
+        let field_serde = &field.markers.serde;
+        if field_serde.skip {
+            continue;
+        }
+        if field_serde.flatten {
+            extends.push(field_type(field, ctx));
+            continue;
+        }
+        let field_name = field_serde.rename.clone().unwrap_or_else(|| {
+            rename_field(field.name.as_deref().unwrap(), serde.rename_all.as_deref())
+        });
+        fields.push(format!("  {field_name}: {};", field_type(field, ctx)));
+    }
+
+    let body =
+        if fields.is_empty() { "{}".to_string() } else { format!("{{\n{}\n}}", fields.join("\n")) };
+    // Interfaces can only extend object types, flattened enums need an intersection.
+    let extends_interfaces = extends.iter().all(|ty| {
+        ctx.schema()
+            .into_iter()
+            .find(|def| def.name() == ty)
+            .is_some_and(|def| matches!(def, TypeDef::Struct(_)))
+    });
+    if extends.is_empty() {
+        Some(format!("export interface {name} {body}"))
+    } else if extends_interfaces {
+        Some(format!("export interface {name} extends {} {body}", extends.join(", ")))
+    } else {
+        Some(format!("export type {name} = {body} & {};", extends.join(" & ")))
+    }
+}
+
+fn typescript_enum(def: &EnumDef, ctx: &LateCtx) -> Option<String> {
+    let serde = &def.markers.serde;
+    if !serde.derive_serialize {
+        return None;
+    }
+    let variants = def.all_variants().map(|variant| variant_type(variant, serde, ctx)).join(" | ");
+    Some(format!("export type {} = {variants};", def.name))
+}
+
+fn variant_type(variant: &VariantDef, serde: &SerdeMarkers, ctx: &LateCtx) -> String {
+    let name = variant
+        .markers
+        .serde
+        .rename
+        .clone()
+        .unwrap_or_else(|| rename_variant(&variant.name, serde.rename_all.as_deref()));
+    match variant.fields.as_slice() {
+        [] if serde.untagged => "null".to_string(),
+        [] => format!("\"{name}\""),
+        [field] if serde.untagged => field_type(field, ctx),
+        [field] => match &serde.tag {
+            Some(tag) => format!("({{ {tag}: \"{name}\" }} & {})", field_type(field, ctx)),
+            None => format!("{{ {name}: {} }}", field_type(field, ctx)),
+        },
+        _ => panic!("Tuple variants are not supported by the TypeScript generator."),
+    }
+}
+
+fn field_type(field: &FieldDef, ctx: &LateCtx) -> String {
+    if let Some(ts_type) = &field.markers.serde.ts_type {
+        return ts_type.clone();
+    }
+    type_name(field.typ.name(), ctx)
+}
+
+fn type_name(name: &TypeName, ctx: &LateCtx) -> String {
+    match name {
+        TypeName::Ident(ident) => ident_type(ident, ctx),
+        TypeName::Vec(inner) => format!("Array<{}>", type_name(inner, ctx)),
+        TypeName::Opt(inner) => format!("{} | null", type_name(inner, ctx)),
+        TypeName::Box(inner) | TypeName::Ref(inner) | TypeName::Complex(inner) => {
+            type_name(inner, ctx)
+        }
+    }
+}
+
+fn ident_type(ident: &str, ctx: &LateCtx) -> String {
+    match ident {
+        "bool" => "boolean".to_string(),
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" | "f32"
+        | "f64" => "number".to_string(),
+        "str" | "String" | "Atom" | "CompactStr" | "Cow" => "string".to_string(),
+        "RegExpFlags" => ident.to_string(),
+        _ if ctx.schema().into_iter().any(|def| def.name() == ident) => ident.to_string(),
+        _ => "unknown".to_string(),
+    }
+}
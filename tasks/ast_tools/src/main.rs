@@ -16,11 +16,14 @@ mod rust_ast;
 mod schema;
 mod util;
 
-use derives::{DeriveCloneIn, DeriveContentEq, DeriveContentHash, DeriveGetSpan, DeriveGetSpanMut};
+use derives::{
+    DeriveCloneIn, DeriveContentEq, DeriveContentHash, DeriveESTree, DeriveGetSpan,
+    DeriveGetSpanMut,
+};
 use fmt::cargo_fmt;
 use generators::{
     AssertLayouts, AstBuilderGenerator, AstKindGenerator, Generator, GeneratorOutput,
-    VisitGenerator, VisitMutGenerator,
+    LayoutSchemaGenerator, TypescriptGenerator, VisitGenerator, VisitMutGenerator,
 };
 use passes::{CalcLayout, Linker};
 use util::{write_all_to, NormalizeError};
@@ -52,7 +55,7 @@ pub struct CliOptions {
     no_fmt: bool,
     /// Prints no logs.
     quiet: bool,
-    /// Path of output `schema.json`.
+    /// Path of output `schema.json`, the versioned JSON export of the AST definition.
     schema: Option<std::path::PathBuf>,
 }
 
@@ -73,11 +76,14 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         .derive(DeriveGetSpanMut)
         .derive(DeriveContentEq)
         .derive(DeriveContentHash)
+        .derive(DeriveESTree)
         .generate(AssertLayouts)
         .generate(AstKindGenerator)
         .generate(AstBuilderGenerator)
         .generate(VisitGenerator)
         .generate(VisitMutGenerator)
+        .generate(TypescriptGenerator)
+        .generate(LayoutSchemaGenerator)
         .run()?;
 
     if !cli_options.dry_run {
@@ -100,8 +106,7 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     if let CliOptions { schema: Some(schema_path), dry_run: false, .. } = cli_options {
         let path = schema_path.to_str().expect("invalid path for schema output.");
-        let schema = serde_json::to_string_pretty(&schema.defs).normalize()?;
-        write_all_to(schema.as_bytes(), path)?;
+        write_all_to(schema.to_json()?.as_bytes(), path)?;
     }
 
     Ok(())
@@ -1,4 +1,5 @@
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 use serde::Serialize;
 use syn::{
     ext::IdentExt,
@@ -7,7 +8,7 @@ use syn::{
     parse2,
     punctuated::Punctuated,
     spanned::Spanned,
-    token, Attribute, Expr, Ident, Meta, MetaNameValue, Token,
+    token, Attribute, Expr, Ident, LitStr, Meta, MetaNameValue, Token,
 };
 
 use crate::util::NormalizeError;
@@ -67,10 +68,28 @@ pub struct ScopeMarkers {
     pub enter_before: bool,
 }
 
+/// A struct representing the `#[serde(...)]` and `#[tsify(...)]` attributes of a type, field or
+/// variant, which are reflected in the generated TypeScript definitions.
+#[derive(Debug, Default)]
+pub struct SerdeMarkers {
+    /// The type derives `Serialize`, or generates it with `#[generate_derive(ESTree)]`. Types
+    /// without it are either serialized by hand or not at all.
+    pub derive_serialize: bool,
+    pub rename: Option<String>,
+    pub rename_all: Option<String>,
+    pub tag: Option<String>,
+    pub untagged: bool,
+    pub flatten: bool,
+    pub skip: bool,
+    /// `#[tsify(type = "...")]`
+    pub ts_type: Option<String>,
+}
+
 /// A struct representing all the helper attributes that might be used with `#[generate_derive(...)]`
 #[derive(Debug, Default, Serialize)]
 pub struct DeriveAttributes {
     pub clone_in: CloneInAttribute,
+    pub content_hash: ContentHashAttribute,
 }
 
 /// A enum representing the value passed in `#[clone_in(...)]` derive helper attribute.
@@ -91,6 +110,24 @@ impl From<&Ident> for CloneInAttribute {
     }
 }
 
+/// A enum representing the value passed in `#[content_hash(...)]` derive helper attribute.
+#[derive(Debug, Default, Serialize)]
+pub enum ContentHashAttribute {
+    #[default]
+    None,
+    Skip,
+}
+
+impl From<&Ident> for ContentHashAttribute {
+    fn from(ident: &Ident) -> Self {
+        if ident == "skip" {
+            Self::Skip
+        } else {
+            panic!("Invalid argument used in `#[content_hash(...)]` attribute.");
+        }
+    }
+}
+
 /// A struct representing the `#[scope(...)]` attribute.
 #[derive(Debug, Default)]
 pub struct ScopeAttribute {
@@ -222,13 +259,31 @@ where
             Ok(None)
         }
     }
+    fn try_parse_content_hash(attr: &Attribute) -> crate::Result<Option<ContentHashAttribute>> {
+        if attr.path().is_ident("content_hash") {
+            let arg = attr.parse_args_with(Ident::parse).normalize()?;
+            Ok(Some(ContentHashAttribute::from(&arg)))
+        } else {
+            Ok(None)
+        }
+    }
     let mut clone_in = None;
+    let mut content_hash = None;
     for attr in attrs {
         if let Some(attr) = try_parse_clone_in(attr)? {
             assert!(clone_in.replace(attr).is_none(), "Duplicate `#[clone_in(...)]` attribute.");
         }
+        if let Some(attr) = try_parse_content_hash(attr)? {
+            assert!(
+                content_hash.replace(attr).is_none(),
+                "Duplicate `#[content_hash(...)]` attribute."
+            );
+        }
     }
-    Ok(DeriveAttributes { clone_in: clone_in.unwrap_or_default() })
+    Ok(DeriveAttributes {
+        clone_in: clone_in.unwrap_or_default(),
+        content_hash: content_hash.unwrap_or_default(),
+    })
 }
 
 pub fn get_scope_attribute<'a, I>(attrs: I) -> Option<crate::Result<ScopeAttribute>>
@@ -248,3 +303,87 @@ where
         result.normalize()
     })
 }
+
+pub fn get_serde_markers<'a, I>(attrs: I) -> crate::Result<SerdeMarkers>
+where
+    I: IntoIterator<Item = &'a Attribute>,
+{
+    let mut markers = SerdeMarkers::default();
+    for attr in attrs {
+        if attr.path().is_ident("cfg_attr")
+            || attr.path().is_ident("derive")
+            || attr.path().is_ident("generate_derive")
+        {
+            let tokens = attr.meta.to_token_stream().to_string();
+            if tokens.contains("derive")
+                && tokens.split([' ', '(', ',', ')']).any(|it| it == "Serialize" || it == "ESTree")
+            {
+                markers.derive_serialize = true;
+            }
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                let ident = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+                match ident.as_str() {
+                    "rename" => markers.rename = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "rename_all" => {
+                        markers.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+                    }
+                    "tag" => markers.tag = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "untagged" => markers.untagged = true,
+                    "flatten" => markers.flatten = true,
+                    "skip" => markers.skip = true,
+                    _ => {
+                        if meta.input.peek(Token![=]) {
+                            meta.value()?.parse::<Expr>()?;
+                        }
+                    }
+                }
+                Ok(())
+            })
+            .normalize()?;
+        } else if attr.path().is_ident("tsify") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("type") {
+                    markers.ts_type = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                }
+                Ok(())
+            })
+            .normalize()?;
+        }
+    }
+    Ok(markers)
+}
+
+/// `#[serde(rename_all = "...")]` applied to a `snake_case` field name.
+pub fn rename_field(name: &str, rename_all: Option<&str>) -> String {
+    match rename_all {
+        Some("camelCase") => {
+            let mut parts = name.split('_');
+            let first = parts.next().unwrap_or_default().to_string();
+            parts.fold(first, |mut acc, part| {
+                let mut chars = part.chars();
+                if let Some(c) = chars.next() {
+                    acc.extend(c.to_uppercase());
+                    acc.push_str(chars.as_str());
+                }
+                acc
+            })
+        }
+        Some("lowercase") => name.replace('_', ""),
+        _ => name.to_string(),
+    }
+}
+
+/// `#[serde(rename_all = "...")]` applied to a `PascalCase` variant name.
+pub fn rename_variant(name: &str, rename_all: Option<&str>) -> String {
+    match rename_all {
+        Some("camelCase") => {
+            let mut chars = name.chars();
+            chars.next().map_or_else(String::new, |c| c.to_lowercase().chain(chars).collect())
+        }
+        Some("lowercase") => name.to_lowercase(),
+        _ => name.to_string(),
+    }
+}
@@ -2,7 +2,7 @@ use serde::Serialize;
 
 use super::{with_either, TypeName};
 use crate::{
-    markers::{DeriveAttributes, ScopeAttribute, ScopeMarkers, VisitMarkers},
+    markers::{DeriveAttributes, ScopeAttribute, ScopeMarkers, SerdeMarkers, VisitMarkers},
     util::{ToIdent, TypeAnalysis, TypeWrapper},
     TypeId,
 };
@@ -46,10 +46,8 @@ impl TypeDef {
 pub struct StructDef {
     pub id: TypeId,
     pub name: String,
-    #[serde(skip)]
     pub visitable: bool,
     pub fields: Vec<FieldDef>,
-    #[serde(skip)]
     pub has_lifetime: bool,
     pub size_64: usize,
     pub align_64: usize,
@@ -57,7 +55,6 @@ pub struct StructDef {
     pub size_32: usize,
     pub align_32: usize,
     pub offsets_32: Option<Vec<usize>>,
-    #[serde(skip)]
     pub generated_derives: Vec<String>,
     #[serde(skip)]
     pub markers: OuterMarkers,
@@ -83,6 +80,8 @@ pub struct EnumDef {
     pub offsets_32: Option<Vec<usize>>,
     pub generated_derives: Vec<String>,
     #[serde(skip)]
+    pub markers: OuterMarkers,
+    #[serde(skip)]
     pub module_path: String,
 }
 
@@ -110,7 +109,6 @@ pub struct VariantDef {
     pub name: String,
     pub fields: Vec<FieldDef>,
     pub discriminant: u8,
-    #[serde(skip)]
     pub markers: InnerMarkers,
 }
 
@@ -139,7 +137,6 @@ pub struct FieldDef {
     pub vis: Visibility,
     #[serde(rename = "type")]
     pub typ: TypeRef,
-    #[serde(skip)]
     pub markers: InnerMarkers,
     #[serde(skip)]
     pub docs: Vec<String>,
@@ -228,6 +225,7 @@ impl TypeRef {
 #[derive(Debug)]
 pub struct OuterMarkers {
     pub scope: Option<ScopeAttribute>,
+    pub serde: SerdeMarkers,
 }
 
 #[derive(Debug, Serialize)]
@@ -239,4 +237,6 @@ pub struct InnerMarkers {
     pub visit: VisitMarkers,
     #[serde(skip)]
     pub scope: ScopeMarkers,
+    #[serde(skip)]
+    pub serde: SerdeMarkers,
 }
@@ -4,9 +4,12 @@ use serde::Serialize;
 use crate::{
     codegen,
     layout::KnownLayout,
-    markers::{get_derive_attributes, get_scope_attribute, get_scope_markers, get_visit_markers},
+    markers::{
+        get_derive_attributes, get_scope_attribute, get_scope_markers, get_serde_markers,
+        get_visit_markers,
+    },
     rust_ast as rust,
-    util::{unexpanded_macro_err, TypeExt},
+    util::{unexpanded_macro_err, NormalizeError, TypeExt},
     Result, TypeId,
 };
 
@@ -82,6 +85,10 @@ impl<'a> From<crate::util::TypeIdentResult<'a>> for TypeName {
     }
 }
 
+/// Version of the JSON format of [`Schema::to_json`].
+/// Bump it on every change of the format which isn't backward compatible.
+pub const SCHEMA_VERSION: u32 = 1;
+
 #[derive(Debug, Default, serde::Serialize)]
 pub struct Schema {
     pub defs: Vec<TypeDef>,
@@ -91,6 +98,20 @@ impl Schema {
     pub fn get(&self, id: TypeId) -> Option<&TypeDef> {
         self.defs.get(id)
     }
+
+    /// Serializes the AST definition for tools outside of this repository, e.g. documentation or
+    /// bindings generators. Types refer to each other by their `id`, which is the index of the type
+    /// in `defs`, and fields marked with `#[span]` have `"span": true` in their `markers`.
+    pub fn to_json(&self) -> Result<String> {
+        #[derive(Serialize)]
+        struct VersionedSchema<'s> {
+            version: u32,
+            defs: &'s [TypeDef],
+        }
+
+        serde_json::to_string_pretty(&VersionedSchema { version: SCHEMA_VERSION, defs: &self.defs })
+            .normalize()
+    }
 }
 
 impl<'a> IntoIterator for &'a Schema {
@@ -103,7 +124,10 @@ impl<'a> IntoIterator for &'a Schema {
 }
 
 fn parse_outer_markers(attrs: &Vec<syn::Attribute>) -> Result<OuterMarkers> {
-    Ok(OuterMarkers { scope: get_scope_attribute(attrs).transpose()? })
+    Ok(OuterMarkers {
+        scope: get_scope_attribute(attrs).transpose()?,
+        serde: get_serde_markers(attrs)?,
+    })
 }
 
 fn parse_inner_markers(attrs: &Vec<syn::Attribute>) -> Result<InnerMarkers> {
@@ -112,6 +136,7 @@ fn parse_inner_markers(attrs: &Vec<syn::Attribute>) -> Result<InnerMarkers> {
         visit: get_visit_markers(attrs)?,
         scope: get_scope_markers(attrs)?,
         derive_attributes: get_derive_attributes(attrs)?,
+        serde: get_serde_markers(attrs)?,
     })
 }
 
@@ -166,6 +191,7 @@ fn lower_ast_enum(it @ rust::Enum { item, meta }: &rust::Enum, ctx: &codegen::Ea
         align_32,
         offsets_32,
 
+        markers: parse_outer_markers(&item.attrs).unwrap(),
         generated_derives: parse_generate_derive(&item.attrs),
 
         module_path: meta.module_path.clone(),
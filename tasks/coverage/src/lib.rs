@@ -9,6 +9,7 @@ mod test262;
 mod typescript;
 
 mod driver;
+mod report;
 mod tools;
 
 use std::{fs, path::PathBuf, process::Command, time::Duration};
@@ -17,6 +18,7 @@ use oxc_tasks_common::agent;
 use runtime::{CodegenRuntimeTest262Case, V8_TEST_262_FAILED_TESTS_PATH};
 use similar::DiffableStr;
 
+pub use crate::report::{compare_reports, CaseFilter, SuiteDelta, SuiteReport};
 use crate::{
     babel::{BabelCase, BabelSuite},
     driver::Driver,
@@ -54,6 +56,8 @@ pub struct AppArgs {
     pub detail: bool,
     /// Print mismatch diff
     pub diff: bool,
+    /// Only run the test cases accepted by this filter, for using the runner as a library.
+    pub case_filter: Option<CaseFilter>,
 }
 
 impl AppArgs {
@@ -61,59 +65,76 @@ impl AppArgs {
         self.filter.is_some() || self.detail
     }
 
-    pub fn run_all(&self) {
-        self.run_parser();
-        self.run_semantic();
-        self.run_codegen();
-        // self.run_prettier();
-        self.run_transformer();
-        self.run_transpiler();
-        // self.run_codegen_runtime();
-        self.run_minifier();
+    /// Snapshots are not updated for filtered runs, since they only contain some of the cases.
+    fn is_filtered(&self) -> bool {
+        self.filter.is_some() || self.case_filter.is_some()
     }
 
-    pub fn run_parser(&self) {
-        Test262Suite::<Test262Case>::new().run("parser_test262", self);
-        BabelSuite::<BabelCase>::new().run("parser_babel", self);
-        TypeScriptSuite::<TypeScriptCase>::new().run("parser_typescript", self);
-        MiscSuite::<MiscCase>::new().run("parser_misc", self);
+    pub fn run_all(&self) -> Vec<SuiteReport> {
+        let mut reports = vec![];
+        reports.extend(self.run_parser());
+        reports.extend(self.run_semantic());
+        reports.extend(self.run_codegen());
+        // reports.extend(self.run_prettier());
+        reports.extend(self.run_transformer());
+        reports.extend(self.run_transpiler());
+        // reports.extend(self.run_codegen_runtime());
+        reports.extend(self.run_minifier());
+        reports
     }
 
-    pub fn run_semantic(&self) {
-        Test262Suite::<SemanticTest262Case>::new().run("semantic_test262", self);
-        BabelSuite::<SemanticBabelCase>::new().run("semantic_babel", self);
-        TypeScriptSuite::<SemanticTypeScriptCase>::new().run("semantic_typescript", self);
-        MiscSuite::<SemanticMiscCase>::new().run("semantic_misc", self);
+    pub fn run_parser(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<Test262Case>::new().run("parser_test262", self),
+            BabelSuite::<BabelCase>::new().run("parser_babel", self),
+            TypeScriptSuite::<TypeScriptCase>::new().run("parser_typescript", self),
+            MiscSuite::<MiscCase>::new().run("parser_misc", self),
+        ]
     }
 
-    pub fn run_codegen(&self) {
-        Test262Suite::<CodegenTest262Case>::new().run("codegen_test262", self);
-        BabelSuite::<CodegenBabelCase>::new().run("codegen_babel", self);
-        TypeScriptSuite::<CodegenTypeScriptCase>::new().run("codegen_typescript", self);
-        MiscSuite::<CodegenMiscCase>::new().run("codegen_misc", self);
-        SourcemapSuite::<SourcemapCase>::new().run("codegen_sourcemap", self);
+    pub fn run_semantic(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<SemanticTest262Case>::new().run("semantic_test262", self),
+            BabelSuite::<SemanticBabelCase>::new().run("semantic_babel", self),
+            TypeScriptSuite::<SemanticTypeScriptCase>::new().run("semantic_typescript", self),
+            MiscSuite::<SemanticMiscCase>::new().run("semantic_misc", self),
+        ]
     }
 
-    pub fn run_prettier(&self) {
-        Test262Suite::<PrettierTest262Case>::new().run("prettier_test262", self);
-        BabelSuite::<PrettierBabelCase>::new().run("prettier_babel", self);
-        TypeScriptSuite::<PrettierTypeScriptCase>::new().run("prettier_typescript", self);
-        MiscSuite::<PrettierMiscCase>::new().run("prettier_misc", self);
+    pub fn run_codegen(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<CodegenTest262Case>::new().run("codegen_test262", self),
+            BabelSuite::<CodegenBabelCase>::new().run("codegen_babel", self),
+            TypeScriptSuite::<CodegenTypeScriptCase>::new().run("codegen_typescript", self),
+            MiscSuite::<CodegenMiscCase>::new().run("codegen_misc", self),
+            SourcemapSuite::<SourcemapCase>::new().run("codegen_sourcemap", self),
+        ]
     }
 
-    pub fn run_transformer(&self) {
-        Test262Suite::<TransformerTest262Case>::new().run("transformer_test262", self);
-        BabelSuite::<TransformerBabelCase>::new().run("transformer_babel", self);
-        TypeScriptSuite::<TransformerTypeScriptCase>::new().run("transformer_typescript", self);
-        MiscSuite::<TransformerMiscCase>::new().run("transformer_misc", self);
+    pub fn run_prettier(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<PrettierTest262Case>::new().run("prettier_test262", self),
+            BabelSuite::<PrettierBabelCase>::new().run("prettier_babel", self),
+            TypeScriptSuite::<PrettierTypeScriptCase>::new().run("prettier_typescript", self),
+            MiscSuite::<PrettierMiscCase>::new().run("prettier_misc", self),
+        ]
     }
 
-    pub fn run_transpiler(&self) {
-        TranspileRunner::<TypeScriptTranspileCase>::new().run("transpile", self);
+    pub fn run_transformer(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<TransformerTest262Case>::new().run("transformer_test262", self),
+            BabelSuite::<TransformerBabelCase>::new().run("transformer_babel", self),
+            TypeScriptSuite::<TransformerTypeScriptCase>::new().run("transformer_typescript", self),
+            MiscSuite::<TransformerMiscCase>::new().run("transformer_misc", self),
+        ]
+    }
+
+    pub fn run_transpiler(&self) -> Vec<SuiteReport> {
+        vec![TranspileRunner::<TypeScriptTranspileCase>::new().run("transpile", self)]
     }
 
     /// # Panics
-    pub fn run_codegen_runtime(&self) {
+    pub fn run_codegen_runtime(&self) -> Vec<SuiteReport> {
         // Run runtime.js to test codegen runtime
         let mut runtime_process = Command::new("node")
             .args([
@@ -126,8 +147,10 @@ impl AppArgs {
             ])
             .spawn()
             .expect("Run runtime.js failed");
-        Test262Suite::<CodegenRuntimeTest262Case>::new().run_async("codegen_runtime_test262", self);
+        let report = Test262Suite::<CodegenRuntimeTest262Case>::new()
+            .run_async("codegen_runtime_test262", self);
         let _ = runtime_process.kill();
+        vec![report]
     }
 
     // Generate v8 test262 status file, which is used to skip failed tests
@@ -158,15 +181,17 @@ impl AppArgs {
             .expect("Write v8 test262 status failed");
     }
 
-    pub fn run_minifier(&self) {
-        Test262Suite::<MinifierTest262Case>::new().run("minifier_test262", self);
-        BabelSuite::<MinifierBabelCase>::new().run("minifier_babel", self);
+    pub fn run_minifier(&self) -> Vec<SuiteReport> {
+        vec![
+            Test262Suite::<MinifierTest262Case>::new().run("minifier_test262", self),
+            BabelSuite::<MinifierBabelCase>::new().run("minifier_babel", self),
+        ]
     }
 }
 
 #[test]
 #[cfg(any(coverage, coverage_nightly))]
 fn test() {
-    let args = AppArgs { debug: false, filter: None, detail: false, diff: false };
-    args.run_all()
+    let args = AppArgs::default();
+    args.run_all();
 }
@@ -1,3 +1,5 @@
+use std::{fs, path::PathBuf};
+
 use oxc_coverage::AppArgs;
 use pico_args::Arguments;
 use rayon::ThreadPoolBuilder;
@@ -5,12 +7,14 @@ use rayon::ThreadPoolBuilder;
 fn main() {
     let mut args = Arguments::from_env();
     let command = args.subcommand().expect("subcommands");
+    let json: Option<PathBuf> = args.opt_value_from_str("--json").unwrap();
 
     let args = AppArgs {
         debug: args.contains("--debug"),
         filter: args.opt_value_from_str("--filter").unwrap(),
         detail: args.contains("--detail"),
         diff: args.contains("--diff"),
+        case_filter: None,
     };
 
     if args.debug {
@@ -18,7 +22,7 @@ fn main() {
     }
 
     let task = command.as_deref().unwrap_or("default");
-    match task {
+    let reports = match task {
         "parser" => args.run_parser(),
         "semantic" => args.run_semantic(),
         "codegen" => args.run_codegen(),
@@ -27,12 +31,21 @@ fn main() {
         "transformer" => args.run_transformer(),
         "transpiler" => args.run_transpiler(),
         "minifier" => args.run_minifier(),
-        "v8_test262_status" => args.run_sync_v8_test262_status(),
+        "v8_test262_status" => {
+            args.run_sync_v8_test262_status();
+            vec![]
+        }
         "all" => {
-            args.run_all();
-            args.run_codegen_runtime();
+            let mut reports = args.run_all();
+            reports.extend(args.run_codegen_runtime());
             args.run_sync_v8_test262_status();
+            reports
         }
         _ => args.run_all(),
     };
+
+    // Machine readable reports, see `oxc_coverage::compare_reports`.
+    if let Some(path) = json {
+        fs::write(path, serde_json::to_string_pretty(&reports).unwrap()).unwrap();
+    }
 }
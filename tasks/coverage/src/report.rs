@@ -0,0 +1,113 @@
+use std::{fmt, path::Path, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+/// Conformance result of a single suite run, e.g. `parser_test262`.
+///
+/// Reports are serializable so CI can store them per commit and compare them with
+/// [SuiteReport::delta], instead of diffing the snapshot files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiteReport {
+    pub name: String,
+    pub all_positives: usize,
+    pub parsed_positives: usize,
+    pub passed_positives: usize,
+    pub all_negatives: usize,
+    pub passed_negatives: usize,
+    /// Paths of the failed cases which are expected to pass, relative to `tasks/coverage`.
+    pub failed_positives: Vec<String>,
+    /// Paths of the failed cases which are expected to fail, relative to `tasks/coverage`.
+    pub failed_negatives: Vec<String>,
+}
+
+/// Cases which changed between two runs of the same suite.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiteDelta {
+    pub name: String,
+    pub newly_failing: Vec<String>,
+    pub newly_passing: Vec<String>,
+}
+
+impl SuiteDelta {
+    pub fn is_empty(&self) -> bool {
+        self.newly_failing.is_empty() && self.newly_passing.is_empty()
+    }
+}
+
+impl SuiteReport {
+    /// Compare with a `base` run of the same suite.
+    pub fn delta(&self, base: &Self) -> SuiteDelta {
+        let failed = |report: &Self| {
+            report.failed_positives.iter().chain(&report.failed_negatives).cloned().collect()
+        };
+        let head_failed: Vec<String> = failed(self);
+        let base_failed: Vec<String> = failed(base);
+        let newly_failing =
+            head_failed.iter().filter(|path| !base_failed.contains(path)).cloned().collect();
+        let newly_passing =
+            base_failed.iter().filter(|path| !head_failed.contains(path)).cloned().collect();
+        SuiteDelta { name: self.name.clone(), newly_failing, newly_passing }
+    }
+}
+
+/// Compare every suite in `head` with the suite of the same name in `base`.
+pub fn compare_reports(base: &[SuiteReport], head: &[SuiteReport]) -> Vec<SuiteDelta> {
+    head.iter()
+        .filter_map(|head| {
+            let base = base.iter().find(|base| base.name == head.name)?;
+            Some(head.delta(base))
+        })
+        .collect()
+}
+
+/// Programmatic filter for test cases, called with the path of each case relative to
+/// `tasks/coverage`, e.g. `test262/test/language/types/null/S8.2_A1_T1.js`.
+#[derive(Clone)]
+pub struct CaseFilter(Arc<dyn Fn(&Path) -> bool + Send + Sync>);
+
+impl CaseFilter {
+    pub fn new<F: Fn(&Path) -> bool + Send + Sync + 'static>(filter: F) -> Self {
+        Self(Arc::new(filter))
+    }
+
+    pub fn matches(&self, path: &Path) -> bool {
+        (self.0)(path)
+    }
+}
+
+impl fmt::Debug for CaseFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CaseFilter")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compare_reports, SuiteReport};
+
+    fn report(failed_positives: &[&str]) -> SuiteReport {
+        SuiteReport {
+            name: "parser_test262".to_string(),
+            all_positives: 3,
+            parsed_positives: 3,
+            passed_positives: 3 - failed_positives.len(),
+            all_negatives: 0,
+            passed_negatives: 0,
+            failed_positives: failed_positives.iter().map(ToString::to_string).collect(),
+            failed_negatives: vec![],
+        }
+    }
+
+    #[test]
+    fn delta() {
+        let base = [report(&["a.js", "b.js"])];
+        let head = [report(&["b.js", "c.js"])];
+        let deltas = compare_reports(&base, &head);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].newly_failing, ["c.js"]);
+        assert_eq!(deltas[0].newly_passing, ["a.js"]);
+        assert!(compare_reports(&base, &base).iter().all(super::SuiteDelta::is_empty));
+    }
+}
@@ -21,7 +21,7 @@ use similar::{ChangeTag, TextDiff};
 use tokio::runtime::Runtime;
 use walkdir::WalkDir;
 
-use crate::{workspace_root, AppArgs, Driver};
+use crate::{report::SuiteReport, workspace_root, AppArgs, Driver};
 
 #[derive(Debug, PartialEq)]
 pub enum TestResult {
@@ -45,9 +45,26 @@ pub struct CoverageReport<'a, T> {
     all_negatives: usize,
 }
 
+impl<'a, T: Case> CoverageReport<'a, T> {
+    fn to_suite_report(&self, name: &str) -> SuiteReport {
+        let paths =
+            |cases: &[&T]| cases.iter().map(|case| normalize_path(case.path())).collect::<Vec<_>>();
+        SuiteReport {
+            name: name.to_string(),
+            all_positives: self.all_positives,
+            parsed_positives: self.parsed_positives,
+            passed_positives: self.passed_positives,
+            all_negatives: self.all_negatives,
+            passed_negatives: self.passed_negatives,
+            failed_positives: paths(&self.failed_positives),
+            failed_negatives: paths(&self.failed_negatives),
+        }
+    }
+}
+
 /// A Test Suite is responsible for reading code from a repository
 pub trait Suite<T: Case> {
-    fn run(&mut self, name: &str, args: &AppArgs) {
+    fn run(&mut self, name: &str, args: &AppArgs) -> SuiteReport {
         self.read_test_cases(name, args);
         self.get_test_cases_mut().par_iter_mut().for_each(|case| {
             if args.debug {
@@ -56,9 +73,10 @@ pub trait Suite<T: Case> {
             case.run();
         });
         self.run_coverage(name, args);
+        self.coverage_report().to_suite_report(name)
     }
 
-    fn run_async(&mut self, name: &str, args: &AppArgs) {
+    fn run_async(&mut self, name: &str, args: &AppArgs) -> SuiteReport {
         let rt = Runtime::new().unwrap();
         self.read_test_cases(name, args);
 
@@ -69,6 +87,7 @@ pub trait Suite<T: Case> {
         });
 
         self.run_coverage(name, args);
+        self.coverage_report().to_suite_report(name)
     }
 
     fn run_coverage(&self, name: &str, args: &AppArgs) {
@@ -78,7 +97,7 @@ pub trait Suite<T: Case> {
 
         self.print_coverage(name, args, &report, &mut out).unwrap();
 
-        if args.filter.is_none() {
+        if !args.is_filtered() {
             self.snapshot_errors(name, &report).unwrap();
         }
     }
@@ -105,13 +124,18 @@ pub trait Suite<T: Case> {
                 .map(|e| e.path().to_owned())
                 .filter(|path| !self.skip_test_path(path))
                 .filter(|path| filter.map_or(true, |query| path.to_string_lossy().contains(query)))
+                .filter(|path| {
+                    args.case_filter.as_ref().map_or(true, |case_filter| {
+                        case_filter.matches(path.strip_prefix(&test_path).unwrap_or(path))
+                    })
+                })
                 .collect::<Vec<_>>()
         };
 
         let mut paths = get_paths();
 
         // Initialize git submodule if it is empty and no filter is provided
-        if paths.is_empty() && args.filter.is_none() {
+        if paths.is_empty() && !args.is_filtered() {
             println!("-------------------------------------------------------");
             println!("git submodule is empty for {name}");
             println!("Running `just submodules` to clone the submodules");
@@ -151,7 +175,7 @@ pub trait Suite<T: Case> {
             .collect::<Vec<_>>();
 
         self.save_test_cases(cases);
-        if args.filter.is_none() {
+        if !args.is_filtered() {
             self.save_extra_test_cases();
         }
     }
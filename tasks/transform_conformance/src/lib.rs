@@ -26,6 +26,12 @@ fn test() {
 pub struct TestRunnerOptions {
     pub filter: Option<String>,
     pub exec: bool,
+    /// Run the fixtures of this directory instead of the Babel and oxc test suites.
+    ///
+    /// Every directory in it is a test suite, with Babel's fixture layout: `input.[ext]` and
+    /// `output.[ext]` or `exec.[ext]` files, configured by `options.json` files.
+    /// The snapshots are saved to `conformance.snap.md` and `exec.snap.md` in this directory.
+    pub fixtures: Option<PathBuf>,
 }
 
 /// The test runner which walks the babel repository and searches for transformation tests.
@@ -34,6 +40,52 @@ pub struct TestRunner {
     snapshot: Snapshot,
 }
 
+/// A directory of test suites, and the snapshots of their results.
+struct SuiteRoot {
+    root: PathBuf,
+    /// Names of the test suites, which are directories in `root`.
+    suites: Vec<String>,
+    /// Directory of the fixtures in a test suite, `test/fixtures` for Babel packages.
+    fixtures_dir: &'static str,
+    snapshot: PathBuf,
+    exec_snapshot: PathBuf,
+}
+
+impl SuiteRoot {
+    fn babel(root: PathBuf, snapshot: &str, exec_snapshot: &str) -> Self {
+        Self {
+            root,
+            suites: PLUGINS.iter().map(ToString::to_string).collect(),
+            fixtures_dir: "test/fixtures",
+            snapshot: snap_root().join(snapshot),
+            exec_snapshot: snap_root().join(exec_snapshot),
+        }
+    }
+
+    /// # Panics
+    /// Panics if `root` cannot be read.
+    fn custom(root: &Path) -> Self {
+        let mut suites = fs::read_dir(root)
+            .unwrap_or_else(|err| panic!("Failed to read fixtures {root:?}: {err}"))
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        suites.sort_unstable();
+        Self {
+            root: root.to_path_buf(),
+            suites,
+            fixtures_dir: "",
+            snapshot: root.join(CUSTOM_SNAPSHOT),
+            exec_snapshot: root.join(CUSTOM_EXEC_SNAPSHOT),
+        }
+    }
+
+    fn fixtures(&self, suite: &str) -> PathBuf {
+        self.root.join(suite).join(self.fixtures_dir)
+    }
+}
+
 fn babel_root() -> PathBuf {
     project_root().join("tasks").join("coverage").join("babel")
 }
@@ -58,47 +110,45 @@ const CONFORMANCE_SNAPSHOT: &str = "babel.snap.md";
 const OXC_CONFORMANCE_SNAPSHOT: &str = "oxc.snap.md";
 const EXEC_SNAPSHOT: &str = "babel_exec.snap.md";
 const OXC_EXEC_SNAPSHOT: &str = "oxc_exec.snap.md";
-
-struct SnapshotOption {
-    paths: IndexMap<String, Vec<TestCaseKind>>,
-    dest: PathBuf,
-}
-
-impl SnapshotOption {
-    fn new(paths: IndexMap<String, Vec<TestCaseKind>>, file_name: &'static str) -> Self {
-        Self { paths, dest: snap_root().join(file_name) }
-    }
-}
+const CUSTOM_SNAPSHOT: &str = "conformance.snap.md";
+const CUSTOM_EXEC_SNAPSHOT: &str = "exec.snap.md";
 
 impl TestRunner {
     pub fn new(options: TestRunnerOptions) -> Self {
-        let snapshot = Snapshot::new(&babel_root(), /* show_commit */ true);
+        let snapshot = match &options.fixtures {
+            Some(fixtures) => Snapshot::new(fixtures, /* show_commit */ false),
+            None => Snapshot::new(&babel_root(), /* show_commit */ true),
+        };
         Self { options, snapshot }
     }
 
     /// # Panics
     pub fn run(self) {
-        for (root, snapshot, exec_snapshot) in &[
-            (packages_root(), CONFORMANCE_SNAPSHOT, EXEC_SNAPSHOT),
-            (oxc_test_root(), OXC_CONFORMANCE_SNAPSHOT, OXC_EXEC_SNAPSHOT),
-        ] {
+        let roots = match &self.options.fixtures {
+            Some(fixtures) => vec![SuiteRoot::custom(fixtures)],
+            None => vec![
+                SuiteRoot::babel(packages_root(), CONFORMANCE_SNAPSHOT, EXEC_SNAPSHOT),
+                SuiteRoot::babel(oxc_test_root(), OXC_CONFORMANCE_SNAPSHOT, OXC_EXEC_SNAPSHOT),
+            ],
+        };
+        for root in &roots {
             let (transform_paths, exec_files) =
                 Self::glob_files(root, self.options.filter.as_ref());
-            self.generate_snapshot(root, SnapshotOption::new(transform_paths, snapshot));
+            self.generate_snapshot(root, transform_paths, &root.snapshot);
 
             if self.options.exec {
                 let fixture_root = fixture_root();
                 if !fixture_root.exists() {
                     fs::create_dir(&fixture_root).unwrap();
                 }
-                self.generate_snapshot(root, SnapshotOption::new(exec_files, exec_snapshot));
+                self.generate_snapshot(root, exec_files, &root.exec_snapshot);
                 let _ = fs::remove_dir_all(fixture_root);
             }
         }
     }
 
     fn glob_files(
-        root: &Path,
+        root: &SuiteRoot,
         filter: Option<&String>,
     ) -> (IndexMap<String, Vec<TestCaseKind>>, IndexMap<String, Vec<TestCaseKind>>) {
         let cwd = babel_root();
@@ -106,8 +156,8 @@ impl TestRunner {
         let mut transform_files = IndexMap::<String, Vec<TestCaseKind>>::new();
         let mut exec_files = IndexMap::<String, Vec<TestCaseKind>>::new();
 
-        for case in PLUGINS {
-            let root = root.join(case).join("test/fixtures");
+        for case in &root.suites {
+            let root = root.fixtures(case);
             let (mut transform_paths, mut exec_paths): (Vec<TestCaseKind>, Vec<TestCaseKind>) =
                 WalkDir::new(root)
                     .into_iter()
@@ -128,25 +178,29 @@ impl TestRunner {
             exec_paths.sort_unstable_by(|a, b| a.path().cmp(b.path()));
 
             if !transform_paths.is_empty() {
-                transform_files.insert((*case).to_string(), transform_paths);
+                transform_files.insert(case.clone(), transform_paths);
             }
             if !exec_paths.is_empty() {
-                exec_files.insert((*case).to_string(), exec_paths);
+                exec_files.insert(case.clone(), exec_paths);
             }
         }
 
         (transform_files, exec_files)
     }
 
-    fn generate_snapshot(&self, root: &Path, option: SnapshotOption) {
-        let SnapshotOption { paths, dest } = option;
+    fn generate_snapshot(
+        &self,
+        root: &SuiteRoot,
+        paths: IndexMap<String, Vec<TestCaseKind>>,
+        dest: &Path,
+    ) {
         let mut snapshot = String::new();
         let mut total = 0;
         let mut all_passed = vec![];
         let mut all_passed_count = 0;
 
         for (case, test_cases) in paths {
-            let case_root = root.join(&case).join("test/fixtures");
+            let case_root = root.fixtures(&case);
             let num_of_tests = test_cases.len();
             total += num_of_tests;
 
@@ -192,7 +246,7 @@ impl TestRunner {
             let snapshot = format!(
                 "Passed: {all_passed_count}/{total}\n\n# All Passed:\n{all_passed}\n\n\n{snapshot}"
             );
-            self.snapshot.save(&dest, &snapshot);
+            self.snapshot.save(dest, &snapshot);
         }
     }
 }
@@ -7,6 +7,7 @@ fn main() {
     let options = TestRunnerOptions {
         filter: args.opt_value_from_str("--filter").unwrap(),
         exec: args.contains("--exec"),
+        fixtures: args.opt_value_from_str("--fixtures").unwrap(),
     };
 
     TestRunner::new(options.clone()).run();
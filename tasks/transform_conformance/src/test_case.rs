@@ -270,7 +270,10 @@ impl TestCase for ConformanceTestCase {
                 let errors = driver.errors();
                 if !errors.is_empty() {
                     let source = NamedSource::new(
-                        self.path.strip_prefix(project_root).unwrap().to_string_lossy(),
+                        self.path
+                            .strip_prefix(project_root)
+                            .unwrap_or(&self.path)
+                            .to_string_lossy(),
                         input.to_string(),
                     );
                     let error = errors
@@ -374,11 +377,17 @@ impl ExecTestCase {
 
     fn write_to_test_files(&self, content: &str) -> PathBuf {
         let allocator = Allocator::default();
-        let new_file_name: String =
-            normalize_path(self.path.strip_prefix(packages_root()).unwrap())
-                .split('/')
-                .collect::<Vec<&str>>()
-                .join("-");
+        // Custom fixtures are outside of the Babel packages
+        let path = self
+            .path
+            .strip_prefix(packages_root())
+            .or_else(|_| self.path.strip_prefix(project_root()))
+            .unwrap_or(&self.path);
+        let new_file_name: String = normalize_path(path)
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<&str>>()
+            .join("-");
 
         let mut target_path = fixture_root().join(new_file_name);
         target_path.set_extension("test.js");
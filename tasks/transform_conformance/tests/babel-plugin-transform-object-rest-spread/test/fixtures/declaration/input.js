@@ -0,0 +1,5 @@
+function f(x) {
+  var { a, ...b } = x;
+  const { c, "d": d, ...e } = foo();
+  let { ...g } = x;
+}
@@ -0,0 +1,5 @@
+function f(x) {
+  var { a } = x, b = babelHelpers.objectWithoutProperties(x, ["a"]);
+  const _foo = foo(), { c, "d": d } = _foo, e = babelHelpers.objectWithoutProperties(_foo, ["c", "d"]);
+  let {} = x, g = babelHelpers.objectWithoutProperties(x, []);
+}
@@ -0,0 +1,8 @@
+function h({ a, ...b }, c = b) {}
+function k({ a, ...b }) {
+  var b;
+}
+function l({ a, ...b }) {
+  function b() {}
+}
+const m = ({ a, ...b }, c = () => b) => c;
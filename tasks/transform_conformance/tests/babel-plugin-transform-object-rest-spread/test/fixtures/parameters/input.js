@@ -0,0 +1,4 @@
+function f({ a, ...b }, c) {
+  return b;
+}
+const g = ({ d, ...e } = {}) => e;
@@ -0,0 +1,8 @@
+function f(_ref, c) {
+  let { a } = _ref, b = babelHelpers.objectWithoutProperties(_ref, ["a"]);
+  return b;
+}
+const g = (_ref2 = {}) => {
+  let { d } = _ref2, e = babelHelpers.objectWithoutProperties(_ref2, ["d"]);
+  return e;
+};
@@ -0,0 +1,4 @@
+var _foo, _foo$bar, _foo2, _foo3;
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo();
+(_foo$bar = (_foo2 = foo).bar) === null || _foo$bar === void 0 ? void 0 : _foo$bar.call(_foo2);
+(_foo3 = foo) === null || _foo3 === void 0 ? void 0 : _foo3.bar();
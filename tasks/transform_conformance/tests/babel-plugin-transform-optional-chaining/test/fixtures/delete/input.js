@@ -0,0 +1 @@
+delete a?.b;
@@ -0,0 +1,2 @@
+var _a;
+(_a = a) === null || _a === void 0 ? true : delete _a.b;
@@ -0,0 +1,4 @@
+foo?.bar;
+a?.b.c?.d;
+this?.x;
+(obj?.y).z;
@@ -0,0 +1,5 @@
+var _foo, _a, _a$b$c, _obj;
+(_foo = foo) === null || _foo === void 0 ? void 0 : _foo.bar;
+(_a = a) === null || _a === void 0 || (_a$b$c = _a.b.c) === null || _a$b$c === void 0 ? void 0 : _a$b$c.d;
+this === null || this === void 0 ? void 0 : this.x;
+((_obj = obj) === null || _obj === void 0 ? void 0 : _obj.y).z;
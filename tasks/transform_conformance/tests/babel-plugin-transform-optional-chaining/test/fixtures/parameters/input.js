@@ -0,0 +1,2 @@
+function f(o, x = o.q?.p) {}
+const g = (o, { x } = o?.q.p()) => {};
@@ -0,0 +1,2 @@
+function f(o, x = ((_o$q) => (_o$q = o.q) === null || _o$q === void 0 ? void 0 : _o$q.p)()) {}
+const g = (o, { x } = o === null || o === void 0 ? void 0 : o.q.p()) => {};
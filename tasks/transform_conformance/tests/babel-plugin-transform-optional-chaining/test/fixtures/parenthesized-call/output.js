@@ -0,0 +1,3 @@
+var _a, _a$b, _a$b$c;
+((_a = a) === null || _a === void 0 ? void 0 : _a.b).call(_a);
+((_a$b = a.b) === null || _a$b === void 0 ? void 0 : (_a$b$c = _a$b.c).d).call(_a$b$c, 1);
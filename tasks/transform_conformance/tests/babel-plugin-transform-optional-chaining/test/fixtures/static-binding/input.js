@@ -0,0 +1,3 @@
+function f(a) {
+  return a?.b?.c;
+}
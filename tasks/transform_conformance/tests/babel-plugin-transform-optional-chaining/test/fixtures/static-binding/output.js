@@ -0,0 +1,4 @@
+function f(a) {
+  var _a$b;
+  return a === null || a === void 0 || (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.c;
+}
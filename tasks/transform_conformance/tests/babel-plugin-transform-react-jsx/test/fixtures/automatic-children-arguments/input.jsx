@@ -0,0 +1,2 @@
+<div id="a">{a}{b}</div>;
+<><b /></>;
@@ -0,0 +1,4 @@
+import { createElement as _createElement } from "mithril";
+import { Fragment as _Fragment } from "mithril/jsx-runtime";
+_createElement("div", { id: "a" }, a, b);
+_createElement(_Fragment, null, _createElement("b", null));
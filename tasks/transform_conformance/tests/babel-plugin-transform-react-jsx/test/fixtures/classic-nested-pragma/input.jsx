@@ -0,0 +1,2 @@
+<div>{a}</div>;
+<><b /></>;
@@ -0,0 +1,2 @@
+this.h('div', null, a);
+this.h(preact.default.Fragment, null, this.h('b', null));
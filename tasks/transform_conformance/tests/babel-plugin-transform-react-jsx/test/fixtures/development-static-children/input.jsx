@@ -0,0 +1,7 @@
+<>
+  a
+  {b}
+</>;
+<>
+  {b}
+</>;
@@ -0,0 +1,3 @@
+import { Fragment as _Fragment, jsxDEV as _jsxDEV } from 'react/jsx-dev-runtime';
+_jsxDEV(_Fragment, { children: ['a', b] }, void 0, true);
+_jsxDEV(_Fragment, { children: b }, void 0, false);